@@ -3,6 +3,50 @@
 // Unified error handling for ROCm-related operations
 
 use std::fmt;
+use std::panic::Location;
+
+/// Broad class of a failure
+///
+/// Lets callers match on what went wrong (out of memory, bad argument,
+/// unsupported feature, ...) without knowing each library's status codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A device or host allocation failed
+    OutOfMemory,
+    /// An argument was invalid or out of range
+    InvalidValue,
+    /// The device is missing or invalid
+    InvalidDevice,
+    /// A handle, context or resource was invalid or already destroyed
+    InvalidHandle,
+    /// The runtime or library was not initialized
+    NotInitialized,
+    /// Asynchronous work has not completed yet
+    NotReady,
+    /// The operation is not supported or not implemented
+    NotSupported,
+    /// A named symbol, function or file was not found
+    NotFound,
+    /// A kernel failed to launch or faulted while running
+    LaunchFailure,
+    /// Kernel source or a code object failed to compile or load
+    KernelCompilation,
+    /// A kernel accessed an illegal address
+    IllegalAddress,
+    /// An operation timed out
+    Timeout,
+    /// An I/O operation failed
+    Io,
+    /// Input could not be parsed
+    Parse,
+    /// The operation is not valid in the current state
+    InvalidOperation,
+    /// A library reported an internal error
+    Internal,
+    /// Any other failure
+    Other,
+}
 
 /// A unified error type for ROCm operations that can represent
 /// errors from different subsystems (HIP, rocRAND, etc)
@@ -23,6 +67,11 @@ pub enum Error {
 
     /// rocBLAS-related error (if you have this module)
     RocBLAS(crate::rocblas::Error),
+
+    #[cfg(feature = "rocsolver")]
+    /// rocSOLVER-related error (if you have this module)
+    RocSOLVER(crate::rocsolver::Error),
+
     /// Custom error with a message
     Custom(String),
 
@@ -55,6 +104,76 @@ pub enum Error {
 
     /// Synchronization error
     SynchronizationError(String),
+
+    /// An error with a message describing what was being done
+    Context {
+        message: String,
+        source: Box<Error>,
+        location: &'static Location<'static>,
+    },
+}
+
+impl Error {
+    /// Get the class of failure this error belongs to
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::Hip(e) => e.kind(),
+            Error::RocRand(e) => e.kind(),
+            #[cfg(feature = "miopen")]
+            Error::MIOpen(e) => e.kind(),
+            Error::RocFFT(e) => e.kind(),
+            Error::RocBLAS(e) => e.kind(),
+            #[cfg(feature = "rocsolver")]
+            Error::RocSOLVER(e) => e.kind(),
+            Error::Custom(_) | Error::DeviceError(_) | Error::SynchronizationError(_) => {
+                ErrorKind::Other
+            }
+            Error::InvalidOperation(_) => ErrorKind::InvalidOperation,
+            Error::OutOfMemory(_) => ErrorKind::OutOfMemory,
+            Error::InvalidArgument(_) => ErrorKind::InvalidValue,
+            Error::NotImplemented(_) => ErrorKind::NotSupported,
+            Error::Io(_) => ErrorKind::Io,
+            Error::Parse(_) => ErrorKind::Parse,
+            Error::Timeout(_) => ErrorKind::Timeout,
+            Error::KernelCompilation(_) => ErrorKind::KernelCompilation,
+            Error::Context { source, .. } => source.kind(),
+        }
+    }
+
+    /// Get the raw status code returned by the underlying library, if any
+    pub fn status(&self) -> Option<u32> {
+        match self {
+            Error::Hip(e) => Some(e.code()),
+            #[cfg(feature = "miopen")]
+            Error::MIOpen(e) => Some(e.code()),
+            Error::RocBLAS(e) => Some(e.code()),
+            #[cfg(feature = "rocsolver")]
+            Error::RocSOLVER(e) => Some(e.code()),
+            Error::RocRand(crate::rocrand::Error::Unknown(code)) => Some(*code),
+            Error::RocFFT(crate::rocfft::error::Error::Unknown(code)) => Some(*code),
+            Error::Context { source, .. } => source.status(),
+            _ => None,
+        }
+    }
+
+    /// Get the API call that failed, if known
+    pub fn api(&self) -> Option<&'static str> {
+        match self {
+            Error::Hip(e) => e.api(),
+            Error::Context { source, .. } => source.api(),
+            _ => None,
+        }
+    }
+
+    /// Get the source location where this error was raised or given
+    /// context, if known
+    pub fn location(&self) -> Option<&'static Location<'static>> {
+        match self {
+            Error::Hip(e) => Some(e.location()),
+            Error::Context { location, .. } => Some(location),
+            _ => None,
+        }
+    }
 }
 
 // Automatic conversion from HIP errors
@@ -86,6 +205,21 @@ impl From<crate::rocfft::error::Error> for Error {
     }
 }
 
+// Automatic conversion from rocBLAS errors
+impl From<crate::rocblas::Error> for Error {
+    fn from(error: crate::rocblas::Error) -> Self {
+        Error::RocBLAS(error)
+    }
+}
+
+// Automatic conversion from rocSOLVER errors (if feature is enabled)
+#[cfg(feature = "rocsolver")]
+impl From<crate::rocsolver::Error> for Error {
+    fn from(error: crate::rocsolver::Error) -> Self {
+        Error::RocSOLVER(error)
+    }
+}
+
 // Automatic conversion from I/O errors
 impl From<std::io::Error> for Error {
     fn from(error: std::io::Error) -> Self {
@@ -103,6 +237,8 @@ impl fmt::Display for Error {
             Error::MIOpen(e) => write!(f, "MIOpen error: {}", e),
             Error::RocFFT(e) => write!(f, "rocFFT error: {}", e),
             Error::RocBLAS(e) => write!(f, "rocBLAS error: {}", e),
+            #[cfg(feature = "rocsolver")]
+            Error::RocSOLVER(e) => write!(f, "rocSOLVER error: {}", e),
             Error::Custom(msg) => write!(f, "Error: {}", msg),
             Error::InvalidOperation(msg) => write!(f, "Invalid operation: {}", msg),
             Error::OutOfMemory(msg) => write!(f, "Out of memory: {}", msg),
@@ -114,6 +250,9 @@ impl fmt::Display for Error {
             Error::DeviceError(msg) => write!(f, "Device error: {}", msg),
             Error::KernelCompilation(msg) => write!(f, "Kernel compilation error: {}", msg),
             Error::SynchronizationError(msg) => write!(f, "Synchronization error: {}", msg),
+            Error::Context {
+                message, source, ..
+            } => write!(f, "{}: {}", message, source),
         }
     }
 }
@@ -127,7 +266,11 @@ impl std::error::Error for Error {
             #[cfg(feature = "miopen")]
             Error::MIOpen(e) => Some(e),
             Error::RocFFT(e) => Some(e),
+            Error::RocBLAS(e) => Some(e),
+            #[cfg(feature = "rocsolver")]
+            Error::RocSOLVER(e) => Some(e),
             Error::Io(e) => Some(e),
+            Error::Context { source, .. } => Some(source.as_ref()),
             _ => None,
         }
     }
@@ -220,15 +363,27 @@ pub trait ErrorContext<T> {
 }
 
 impl<T> ErrorContext<T> for Result<T> {
+    #[track_caller]
     fn with_context<F>(self, f: F) -> Result<T>
     where
         F: FnOnce() -> String,
     {
-        self.map_err(|e| Error::Custom(format!("{}: {}", f(), e)))
+        let location = Location::caller();
+        self.map_err(|e| Error::Context {
+            message: f(),
+            source: Box::new(e),
+            location,
+        })
     }
 
+    #[track_caller]
     fn context<S: Into<String>>(self, msg: S) -> Result<T> {
-        self.map_err(|e| Error::Custom(format!("{}: {}", msg.into(), e)))
+        let location = Location::caller();
+        self.map_err(|e| Error::Context {
+            message: msg.into(),
+            source: Box::new(e),
+            location,
+        })
     }
 }
 /// Error code constants for common error types
@@ -283,6 +438,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_error_context_chain() {
+        use std::error::Error as _;
+
+        let result: Result<()> = Err(invalid_argument("bad shape"));
+        let err = result.context("loading weights").unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "loading weights: Invalid argument: bad shape"
+        );
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert!(err.location().unwrap().file().ends_with("error.rs"));
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            "Invalid argument: bad shape"
+        );
+    }
+
     #[test]
     fn test_error_macros() {
         let err = rocm_error!(InvalidOperation, "Test {} error", "formatted");
@@ -0,0 +1,275 @@
+// src/gpu.rs
+//
+// Vendor-neutral device, buffer and stream traits
+
+//! Backend-agnostic GPU traits
+//!
+//! Applications that ship on both AMD and NVIDIA hardware can write their
+//! host-side code against [`GpuDevice`], [`GpuBuffer`] and [`GpuStream`]
+//! and pick the backend at compile time. This crate implements the traits
+//! for [`hip::Device`](crate::hip::Device),
+//! [`hip::DeviceMemory`](crate::hip::DeviceMemory) and
+//! [`hip::Stream`](crate::hip::Stream); a CUDA backend (for example a thin
+//! wrapper over cudarc's device and slice types) implements them the same
+//! way. Method names follow cudarc (`htod_copy`, `dtoh_sync_copy`,
+//! `alloc_zeros`) so porting between the two is mechanical.
+//!
+//! ```no_run
+//! use rocm_rs::gpu::{GpuBuffer, GpuDevice};
+//!
+//! fn round_trip<D: GpuDevice>(data: &[f32]) -> Result<Vec<f32>, D::Error> {
+//!     let device = D::new(0)?;
+//!     let buffer = device.htod_copy(data)?;
+//!     assert_eq!(buffer.len(), data.len());
+//!     device.dtoh_sync_copy(&buffer)
+//! }
+//!
+//! let back = round_trip::<rocm_rs::hip::Device>(&[1.0, 2.0, 3.0]).unwrap();
+//! ```
+
+use crate::hip::{self, DeviceMemory, Stream, ffi};
+
+/// A GPU that can allocate buffers and create streams
+///
+/// Device-affine operations make the device current on the calling thread,
+/// as CUDA backends do when they bind their context.
+pub trait GpuDevice: Sized {
+    /// Error returned by every fallible operation of the backend
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// The backend's stream type
+    type Stream: GpuStream<Error = Self::Error>;
+
+    /// The backend's typed device allocation
+    type Buffer<T: Copy + Default + 'static>: GpuBuffer<T, Error = Self::Error, Stream = Self::Stream>;
+
+    /// Number of devices visible to the backend
+    fn count() -> Result<usize, Self::Error>;
+
+    /// Open the device with the given ordinal
+    fn new(ordinal: usize) -> Result<Self, Self::Error>;
+
+    /// Ordinal the device was opened with
+    fn ordinal(&self) -> usize;
+
+    /// Marketing name of the device
+    fn name(&self) -> Result<String, Self::Error>;
+
+    /// Total device memory in bytes
+    fn total_memory(&self) -> Result<usize, Self::Error>;
+
+    /// Wait for all work on the device to finish
+    fn synchronize(&self) -> Result<(), Self::Error>;
+
+    /// Create a new stream on the device
+    fn new_stream(&self) -> Result<Self::Stream, Self::Error>;
+
+    /// Allocate `len` elements set to zero bytes
+    fn alloc_zeros<T: Copy + Default + 'static>(
+        &self,
+        len: usize,
+    ) -> Result<Self::Buffer<T>, Self::Error>;
+
+    /// Allocate a buffer holding a copy of `data`
+    fn htod_copy<T: Copy + Default + 'static>(
+        &self,
+        data: &[T],
+    ) -> Result<Self::Buffer<T>, Self::Error> {
+        let mut buffer = self.alloc_zeros(data.len())?;
+        buffer.copy_from_host(data)?;
+        Ok(buffer)
+    }
+
+    /// Copy a whole buffer back to the host
+    fn dtoh_sync_copy<T: Copy + Default + 'static>(
+        &self,
+        buffer: &Self::Buffer<T>,
+    ) -> Result<Vec<T>, Self::Error> {
+        let mut data = vec![T::default(); buffer.len()];
+        buffer.copy_to_host(&mut data)?;
+        Ok(data)
+    }
+}
+
+/// A typed allocation in device memory
+///
+/// Host slices passed to the copies must have exactly [`len`](Self::len)
+/// elements.
+pub trait GpuBuffer<T> {
+    /// Error returned by the copies
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Stream type accepted by the asynchronous copies
+    type Stream;
+
+    /// Number of elements
+    fn len(&self) -> usize;
+
+    /// Whether the buffer has no elements
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Blocking copy from the host
+    fn copy_from_host(&mut self, data: &[T]) -> Result<(), Self::Error>;
+
+    /// Blocking copy to the host
+    fn copy_to_host(&self, data: &mut [T]) -> Result<(), Self::Error>;
+
+    /// Copy from the host on `stream`, keeping `data` alive until the copy
+    /// has finished
+    fn copy_from_host_async(
+        &mut self,
+        data: Vec<T>,
+        stream: &Self::Stream,
+    ) -> Result<(), Self::Error>;
+
+    /// Set every byte to zero
+    fn zero(&mut self) -> Result<(), Self::Error>;
+}
+
+/// An in-order queue of device work
+pub trait GpuStream {
+    /// Error returned by stream operations
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Wait for all work queued so far to finish
+    fn synchronize(&self) -> Result<(), Self::Error>;
+
+    /// Whether all work queued so far has finished, without blocking
+    fn is_idle(&self) -> Result<bool, Self::Error>;
+}
+
+fn check_len(expected: usize, actual: usize) -> hip::Result<()> {
+    if expected != actual {
+        return Err(hip::Error::with_context(
+            ffi::hipError_t_hipErrorInvalidValue,
+            format!("host slice has {actual} elements, buffer has {expected}"),
+        ));
+    }
+    Ok(())
+}
+
+/// Run `f` with `device` current, then restore the previous device
+fn on_device<R>(device: &hip::Device, f: impl FnOnce() -> hip::Result<R>) -> hip::Result<R> {
+    let current = hip::Device::current()?;
+    device.set_current()?;
+    let result = f();
+    current.set_current()?;
+    result
+}
+
+impl GpuDevice for hip::Device {
+    type Error = hip::Error;
+    type Stream = Stream;
+    type Buffer<T: Copy + Default + 'static> = DeviceMemory<T>;
+
+    fn count() -> hip::Result<usize> {
+        Ok(hip::get_device_count()? as usize)
+    }
+
+    fn new(ordinal: usize) -> hip::Result<Self> {
+        let ordinal = i32::try_from(ordinal)
+            .map_err(|_| hip::Error::new(ffi::hipError_t_hipErrorInvalidDevice))?;
+        hip::Device::new(ordinal)
+    }
+
+    fn ordinal(&self) -> usize {
+        self.id() as usize
+    }
+
+    fn name(&self) -> hip::Result<String> {
+        Ok(self.properties()?.name)
+    }
+
+    fn total_memory(&self) -> hip::Result<usize> {
+        Ok(self.properties()?.total_global_mem)
+    }
+
+    fn synchronize(&self) -> hip::Result<()> {
+        hip::Device::synchronize(self)
+    }
+
+    fn new_stream(&self) -> hip::Result<Stream> {
+        on_device(self, Stream::new)
+    }
+
+    fn alloc_zeros<T: Copy + Default + 'static>(&self, len: usize) -> hip::Result<DeviceMemory<T>> {
+        on_device(self, || {
+            let mut buffer = DeviceMemory::new(len)?;
+            buffer.memset(0)?;
+            Ok(buffer)
+        })
+    }
+}
+
+impl<T: Copy + Default + 'static> GpuBuffer<T> for DeviceMemory<T> {
+    type Error = hip::Error;
+    type Stream = Stream;
+
+    fn len(&self) -> usize {
+        self.count()
+    }
+
+    fn copy_from_host(&mut self, data: &[T]) -> hip::Result<()> {
+        check_len(self.count(), data.len())?;
+        DeviceMemory::copy_from_host(self, data)
+    }
+
+    fn copy_to_host(&self, data: &mut [T]) -> hip::Result<()> {
+        check_len(self.count(), data.len())?;
+        DeviceMemory::copy_to_host(self, data)
+    }
+
+    fn copy_from_host_async(&mut self, data: Vec<T>, stream: &Stream) -> hip::Result<()> {
+        check_len(self.count(), data.len())?;
+        DeviceMemory::copy_from_host_async(self, data, stream)
+    }
+
+    fn zero(&mut self) -> hip::Result<()> {
+        self.memset(0)
+    }
+}
+
+impl GpuStream for Stream {
+    type Error = hip::Error;
+
+    fn synchronize(&self) -> hip::Result<()> {
+        Stream::synchronize(self)
+    }
+
+    fn is_idle(&self) -> hip::Result<bool> {
+        match self.query() {
+            Ok(()) => Ok(true),
+            Err(e) if e.is_not_ready() => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Written only against the traits, as a dual-backend application would
+    fn round_trip<D: GpuDevice>(device: &D) -> Result<Vec<u32>, D::Error> {
+        let stream = device.new_stream()?;
+        let mut buffer = device.alloc_zeros::<u32>(4)?;
+        buffer.copy_from_host_async(vec![1, 2, 3, 4], &stream)?;
+        stream.synchronize()?;
+        device.dtoh_sync_copy(&buffer)
+    }
+
+    #[test]
+    fn test_hip_backend_round_trip() {
+        let device = <hip::Device as GpuDevice>::new(0).unwrap();
+        assert_eq!(round_trip(&device).unwrap(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_length_mismatch_is_an_error() {
+        let device = <hip::Device as GpuDevice>::new(0).unwrap();
+        let mut buffer = device.alloc_zeros::<f32>(4).unwrap();
+        assert!(GpuBuffer::copy_from_host(&mut buffer, &[1.0, 2.0]).is_err());
+    }
+}
@@ -0,0 +1,185 @@
+// src/hip/arch.rs
+//
+// GPU architecture queries for per-target kernel dispatch
+
+use crate::hip::device::Device;
+use crate::hip::error::Result;
+
+/// Architecture features of a device, for picking specialized kernels
+/// per gfx target at runtime
+///
+/// Throughput ratios are peak vector ALU rates relative to FP32 on the same
+/// device (e.g. `fp64_ratio == 0.5` means half-rate FP64). They come from a
+/// static table keyed by gfx target and default to `1.0` for unknown targets.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceArch {
+    /// Full architecture name as reported by HIP, e.g. `gfx90a:sramecc+:xnack-`
+    pub gcn_arch_name: String,
+    /// Base gfx target without feature flags, e.g. `gfx90a`
+    pub gfx_target: String,
+    /// Number of threads in a wavefront (32 or 64)
+    pub wavefront_size: u32,
+    /// Whether the device has matrix cores (MFMA on CDNA, WMMA on RDNA3+)
+    pub has_matrix_cores: bool,
+    /// FP16 throughput relative to FP32
+    pub fp16_ratio: f32,
+    /// BF16 throughput relative to FP32
+    pub bf16_ratio: f32,
+    /// FP64 throughput relative to FP32
+    pub fp64_ratio: f32,
+}
+
+/// A capability that higher-level code may need to choose an implementation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feature {
+    /// Native FP16 arithmetic instructions (GCN3 and later)
+    Fp16Arith,
+    /// Matrix cores (MFMA on CDNA, WMMA on RDNA3+)
+    MatrixCores,
+    /// Cooperative kernel launches with grid-wide synchronization
+    CoopLaunch,
+    /// Native atomics on host memory over PCIe
+    PcieAtomics,
+}
+
+/// Static per-family features: (matrix cores, fp16, bf16, fp64)
+fn family_features(gfx_target: &str) -> (bool, f32, f32, f32) {
+    match gfx_target {
+        // CDNA3/CDNA4 (MI300, MI350)
+        t if t.starts_with("gfx94") || t.starts_with("gfx95") => (true, 2.0, 2.0, 1.0),
+        // CDNA2 (MI200)
+        "gfx90a" => (true, 2.0, 2.0, 1.0),
+        // CDNA1 (MI100)
+        "gfx908" => (true, 2.0, 1.0, 0.5),
+        // Vega20 (MI50, Radeon VII)
+        "gfx906" => (false, 2.0, 1.0, 0.5),
+        // Vega10 and other GCN5
+        t if t.starts_with("gfx90") => (false, 2.0, 1.0, 1.0 / 16.0),
+        // RDNA4
+        t if t.starts_with("gfx12") => (true, 2.0, 2.0, 1.0 / 32.0),
+        // RDNA3
+        t if t.starts_with("gfx11") => (true, 2.0, 2.0, 1.0 / 32.0),
+        // RDNA1/RDNA2
+        t if t.starts_with("gfx10") => (false, 2.0, 1.0, 1.0 / 16.0),
+        // GCN3/GCN4
+        t if t.starts_with("gfx8") => (false, 1.0, 1.0, 1.0 / 16.0),
+        _ => (false, 1.0, 1.0, 1.0),
+    }
+}
+
+/// Major version of a gfx target, the digits before the two-character
+/// minor and stepping suffix (`gfx90a` is 9, `gfx1100` is 11)
+fn gfx_major(gfx_target: &str) -> Option<u32> {
+    let version = gfx_target.strip_prefix("gfx")?;
+    let major = version.get(..version.len().checked_sub(2)?)?;
+    major.parse().ok()
+}
+
+impl DeviceArch {
+    /// Build architecture information from a HIP arch name and wavefront size
+    pub fn from_arch_name(gcn_arch_name: &str, wavefront_size: u32) -> Self {
+        let gfx_target = gcn_arch_name
+            .split(':')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let (has_matrix_cores, fp16_ratio, bf16_ratio, fp64_ratio) = family_features(&gfx_target);
+
+        Self {
+            gcn_arch_name: gcn_arch_name.to_string(),
+            gfx_target,
+            wavefront_size,
+            has_matrix_cores,
+            fp16_ratio,
+            bf16_ratio,
+            fp64_ratio,
+        }
+    }
+
+    /// Query the architecture of a device
+    pub fn for_device(device: &Device) -> Result<Self> {
+        let props = device.properties()?;
+        Ok(Self::from_arch_name(
+            &props.gcn_arch_name,
+            props.warp_size as u32,
+        ))
+    }
+
+    /// Whether the gfx target has `feature`
+    ///
+    /// Returns `None` for features that depend on the system or driver
+    /// rather than the architecture; use [`Device::supports`] for those.
+    pub fn supports(&self, feature: Feature) -> Option<bool> {
+        match feature {
+            Feature::Fp16Arith => Some(gfx_major(&self.gfx_target).is_some_and(|major| major >= 8)),
+            Feature::MatrixCores => Some(self.has_matrix_cores),
+            Feature::CoopLaunch | Feature::PcieAtomics => None,
+        }
+    }
+
+    /// Whether this is a CDNA (Instinct) data-center architecture
+    pub fn is_cdna(&self) -> bool {
+        matches!(self.gfx_target.as_str(), "gfx908" | "gfx90a")
+            || self.gfx_target.starts_with("gfx94")
+            || self.gfx_target.starts_with("gfx95")
+    }
+
+    /// Whether this is an RDNA (Radeon) architecture
+    pub fn is_rdna(&self) -> bool {
+        self.gfx_target.starts_with("gfx10")
+            || self.gfx_target.starts_with("gfx11")
+            || self.gfx_target.starts_with("gfx12")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arch_name_with_features() {
+        let arch = DeviceArch::from_arch_name("gfx90a:sramecc+:xnack-", 64);
+        assert_eq!(arch.gfx_target, "gfx90a");
+        assert_eq!(arch.wavefront_size, 64);
+        assert!(arch.has_matrix_cores);
+        assert!(arch.is_cdna());
+        assert_eq!(arch.fp64_ratio, 1.0);
+    }
+
+    #[test]
+    fn test_rdna_arch() {
+        let arch = DeviceArch::from_arch_name("gfx1030", 32);
+        assert!(arch.is_rdna());
+        assert!(!arch.has_matrix_cores);
+        assert_eq!(arch.fp16_ratio, 2.0);
+    }
+
+    #[test]
+    fn test_arch_features() {
+        let vega = DeviceArch::from_arch_name("gfx906", 64);
+        assert_eq!(vega.supports(Feature::Fp16Arith), Some(true));
+        assert_eq!(vega.supports(Feature::MatrixCores), Some(false));
+        assert_eq!(vega.supports(Feature::CoopLaunch), None);
+
+        let rdna3 = DeviceArch::from_arch_name("gfx1100", 32);
+        assert_eq!(rdna3.supports(Feature::MatrixCores), Some(true));
+
+        let hawaii = DeviceArch::from_arch_name("gfx701", 64);
+        assert_eq!(hawaii.supports(Feature::Fp16Arith), Some(false));
+    }
+
+    #[test]
+    fn test_gfx_major() {
+        assert_eq!(gfx_major("gfx803"), Some(8));
+        assert_eq!(gfx_major("gfx90a"), Some(9));
+        assert_eq!(gfx_major("gfx1030"), Some(10));
+        assert_eq!(gfx_major("gfx"), None);
+    }
+
+    #[test]
+    fn test_unknown_arch() {
+        let arch = DeviceArch::from_arch_name("gfx9999", 64);
+        assert!(!arch.has_matrix_cores);
+        assert_eq!(arch.bf16_ratio, 1.0);
+    }
+}
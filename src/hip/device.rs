@@ -1,5 +1,6 @@
 // src/hip/device.rs
 
+use crate::hip::arch::{DeviceArch, Feature};
 use crate::hip::error::{Error, Result};
 use crate::hip::{Stream, ffi};
 use std::ffi::CStr;
@@ -15,6 +16,7 @@ pub fn get_device_count() -> Result<i32> {
 #[derive(Debug, Clone)]
 pub struct DeviceProperties {
     pub name: String,
+    pub gcn_arch_name: String,
     pub total_global_mem: usize,
     pub shared_mem_per_block: usize,
     pub regs_per_block: i32,
@@ -42,7 +44,7 @@ pub fn get_device_properties(device_id: i32) -> Result<DeviceProperties> {
     let error = unsafe { ffi::hipGetDevicePropertiesR0600(&mut props, device_id) };
 
     if error != ffi::hipError_t_hipSuccess {
-        return Err(Error::new(error));
+        return Err(Error::from_api(error, "hipGetDevicePropertiesR0600"));
     }
 
     let name = unsafe {
@@ -50,8 +52,14 @@ pub fn get_device_properties(device_id: i32) -> Result<DeviceProperties> {
         CStr::from_ptr(name_ptr).to_string_lossy().into_owned()
     };
 
+    let gcn_arch_name = unsafe {
+        let name_ptr = props.gcnArchName.as_ptr();
+        CStr::from_ptr(name_ptr).to_string_lossy().into_owned()
+    };
+
     Ok(DeviceProperties {
         name,
+        gcn_arch_name,
         total_global_mem: props.totalGlobalMem,
         shared_mem_per_block: props.sharedMemPerBlock,
         regs_per_block: props.regsPerBlock,
@@ -74,6 +82,27 @@ pub fn get_device_properties(device_id: i32) -> Result<DeviceProperties> {
     })
 }
 
+/// Per-device resource limits that can be queried and configured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    /// Stack size in bytes of each GPU thread
+    StackSize,
+    /// Size in bytes of the FIFO used by device-side `printf`
+    PrintfFifoSize,
+    /// Size in bytes of the heap used by device-side `malloc`/`free`
+    MallocHeapSize,
+}
+
+impl From<Limit> for ffi::hipLimit_t {
+    fn from(limit: Limit) -> Self {
+        match limit {
+            Limit::StackSize => ffi::hipLimit_t_hipLimitStackSize,
+            Limit::PrintfFifoSize => ffi::hipLimit_t_hipLimitPrintfFifoSize,
+            Limit::MallocHeapSize => ffi::hipLimit_t_hipLimitMallocHeapSize,
+        }
+    }
+}
+
 /// A wrapper for HIP device operations
 #[derive(Debug, Clone)]
 pub struct Device {
@@ -95,7 +124,7 @@ impl Device {
         let mut device_id = 0;
         let error = unsafe { ffi::hipGetDevice(&mut device_id) };
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipGetDevice"));
         }
         Ok(Self { id: device_id })
     }
@@ -145,11 +174,115 @@ impl Device {
         Error::from_hip_error(error)
     }
 
+    /// Get the current value of a resource limit on this device
+    pub fn get_limit(&self, limit: Limit) -> Result<usize> {
+        // Save current device
+        let current_device = Self::current()?;
+
+        // Set this device as current
+        self.set_current()?;
+
+        let mut value = 0;
+        let error = unsafe { ffi::hipDeviceGetLimit(&mut value, limit.into()) };
+
+        // Restore previous device
+        current_device.set_current()?;
+
+        Error::from_hip_error_with_value(error, value)
+    }
+
+    /// Set a resource limit on this device
+    ///
+    /// Limits must be configured before launching kernels that depend on them,
+    /// e.g. `Limit::MallocHeapSize` for kernels calling device-side `malloc`.
+    pub fn set_limit(&self, limit: Limit, value: usize) -> Result<()> {
+        // Save current device
+        let current_device = Self::current()?;
+
+        // Set this device as current
+        self.set_current()?;
+
+        let error = unsafe { ffi::hipDeviceSetLimit(limit.into(), value) };
+
+        // Restore previous device
+        current_device.set_current()?;
+
+        Error::from_hip_error(error)
+    }
+
+    /// Whether this device can directly access memory on `peer`
+    pub fn can_access_peer(&self, peer: &Device) -> Result<bool> {
+        let mut can_access = 0;
+        let error = unsafe { ffi::hipDeviceCanAccessPeer(&mut can_access, self.id, peer.id) };
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipDeviceCanAccessPeer"));
+        }
+        Ok(can_access != 0)
+    }
+
+    /// Let kernels and copies on this device access memory on `peer`
+    ///
+    /// Enabling access that is already enabled is not an error.
+    pub fn enable_peer_access(&self, peer: &Device) -> Result<()> {
+        // Save current device
+        let current_device = Self::current()?;
+
+        // Set this device as current
+        self.set_current()?;
+
+        let error = unsafe { ffi::hipDeviceEnablePeerAccess(peer.id, 0) };
+
+        // Restore previous device
+        current_device.set_current()?;
+
+        if error != ffi::hipError_t_hipSuccess
+            && error != ffi::hipError_t_hipErrorPeerAccessAlreadyEnabled
+        {
+            return Err(Error::from_api(error, "hipDeviceEnablePeerAccess"));
+        }
+        Ok(())
+    }
+
     /// Get the properties of this device
     pub fn properties(&self) -> Result<DeviceProperties> {
         get_device_properties(self.id)
     }
 
+    /// Get the architecture features of this device
+    pub fn arch(&self) -> Result<DeviceArch> {
+        DeviceArch::for_device(self)
+    }
+
+    /// Whether this device supports `feature`
+    ///
+    /// Architecture features come from a table keyed by gfx target; the
+    /// others are queried from the runtime, since they also depend on the
+    /// driver and the platform (PCIe atomics need root port support).
+    pub fn supports(&self, feature: Feature) -> Result<bool> {
+        if let Some(supported) = self.arch()?.supports(feature) {
+            return Ok(supported);
+        }
+
+        let attribute = match feature {
+            Feature::CoopLaunch => ffi::hipDeviceAttribute_t_hipDeviceAttributeCooperativeLaunch,
+            Feature::PcieAtomics => {
+                ffi::hipDeviceAttribute_t_hipDeviceAttributeHostNativeAtomicSupported
+            }
+            Feature::Fp16Arith | Feature::MatrixCores => unreachable!(),
+        };
+        Ok(self.attribute(attribute)? != 0)
+    }
+
+    /// Get an integer device attribute
+    fn attribute(&self, attribute: ffi::hipDeviceAttribute_t) -> Result<i32> {
+        let mut value = 0;
+        let error = unsafe { ffi::hipDeviceGetAttribute(&mut value, attribute, self.id) };
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipDeviceGetAttribute"));
+        }
+        Ok(value)
+    }
+
     pub fn get_stream(&self) -> Result<Stream> {
         Stream::new()
     }
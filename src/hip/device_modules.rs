@@ -0,0 +1,80 @@
+// src/hip/device_modules.rs - Embedded kernel sources loaded once per device
+
+use crate::error::Result;
+use crate::hip::{Device, Function, Module, ModuleCache};
+use std::sync::{Arc, Mutex};
+
+/// The modules built from one embedded kernel source, one per device
+///
+/// HIP modules belong to the device that loaded them, so each device gets
+/// its own copy, compiled for its architecture on first use through the
+/// global [`ModuleCache`]. Lookups are thread-safe, and a failed build is
+/// reported to the caller and retried on the next call.
+pub(crate) struct DeviceModules {
+    source: &'static str,
+    // (device id, module); there are only ever a handful of devices
+    modules: Mutex<Vec<(i32, Arc<Module>)>>,
+}
+
+impl DeviceModules {
+    pub(crate) const fn new(source: &'static str) -> Self {
+        Self {
+            source,
+            modules: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Look up a kernel in the current device's module
+    pub(crate) fn function(&self, name: &str) -> Result<Function> {
+        Ok(self.module()?.get_function(name)?)
+    }
+
+    fn module(&self) -> Result<Arc<Module>> {
+        let device = Device::current()?.id();
+        if let Some(module) = self.find(device) {
+            return Ok(module);
+        }
+
+        // Built without holding the lock; the module cache makes concurrent
+        // builds for one device wait for each other
+        let module = ModuleCache::global().get_or_compile(self.source, &[])?;
+        let mut modules = self.modules.lock().unwrap();
+        if !modules.iter().any(|(id, _)| *id == device) {
+            modules.push((device, module.clone()));
+        }
+        Ok(module)
+    }
+
+    fn find(&self, device: i32) -> Option<Arc<Module>> {
+        self.modules
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(id, _)| *id == device)
+            .map(|(_, module)| module.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Result;
+    use crate::rocarray::ROCArray;
+    use std::thread;
+
+    #[test]
+    fn test_kernels_from_many_threads() -> Result<()> {
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                thread::spawn(move || -> Result<Vec<f32>> {
+                    let a = ROCArray::from_vec(vec![i as f32; 8])?;
+                    a.add(&a)?.to_vec()
+                })
+            })
+            .collect();
+
+        for (i, handle) in handles.into_iter().enumerate() {
+            assert_eq!(handle.join().unwrap()?, vec![2.0 * i as f32; 8]);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+// src/hip/device_vec.rs
+//
+// Growable device buffer with host-driven appends
+
+use crate::hip::error::{Error, Result};
+use crate::hip::ffi;
+use crate::hip::kernel::AsKernelArg;
+use crate::hip::memory::{DeviceMemory, KernelArg};
+use std::ffi::c_void;
+
+/// Smallest capacity allocated when a `DeviceVec` first grows
+const MIN_CAPACITY: usize = 8;
+
+/// A growable device buffer
+///
+/// Elements are appended from the host and live on the device. Like `Vec`,
+/// the capacity grows geometrically, so a sequence of pushes only reallocates
+/// (and copies on the device) a logarithmic number of times.
+pub struct DeviceVec<T> {
+    memory: DeviceMemory<T>,
+    len: usize,
+}
+
+impl<T: Copy> DeviceVec<T> {
+    /// Create an empty vector without allocating
+    pub fn new() -> Result<Self> {
+        Self::with_capacity(0)
+    }
+
+    /// Create an empty vector with room for `capacity` elements
+    pub fn with_capacity(capacity: usize) -> Result<Self> {
+        Ok(Self {
+            memory: DeviceMemory::new(capacity)?,
+            len: 0,
+        })
+    }
+
+    /// Create a vector holding a copy of host data
+    pub fn from_slice(data: &[T]) -> Result<Self> {
+        let mut vec = Self::with_capacity(data.len())?;
+        vec.extend_from_slice(data)?;
+        Ok(vec)
+    }
+
+    /// Get the number of elements
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the vector is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the number of elements the vector can hold without reallocating
+    pub fn capacity(&self) -> usize {
+        self.memory.count()
+    }
+
+    /// Make room for at least `additional` more elements
+    pub fn reserve(&mut self, additional: usize) -> Result<()> {
+        let required = self
+            .len
+            .checked_add(additional)
+            .ok_or(Error::new(ffi::hipError_t_hipErrorInvalidValue))?;
+
+        if required <= self.capacity() {
+            return Ok(());
+        }
+
+        let capacity = required
+            .max(self.capacity().saturating_mul(2))
+            .max(MIN_CAPACITY);
+        self.memory.reserve(capacity - self.capacity())
+    }
+
+    /// Append an element
+    pub fn push(&mut self, value: T) -> Result<()> {
+        self.extend_from_slice(std::slice::from_ref(&value))
+    }
+
+    /// Append a slice of host data
+    pub fn extend_from_slice(&mut self, data: &[T]) -> Result<()> {
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        self.reserve(data.len())?;
+
+        let error = unsafe {
+            ffi::hipMemcpy(
+                self.element_ptr(self.len),
+                data.as_ptr() as *const c_void,
+                std::mem::size_of_val(data),
+                ffi::hipMemcpyKind_hipMemcpyHostToDevice,
+            )
+        };
+
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipMemcpy"));
+        }
+
+        self.len += data.len();
+        Ok(())
+    }
+
+    /// Remove the last element and copy it back to the host
+    pub fn pop(&mut self) -> Result<Option<T>> {
+        if self.len == 0 {
+            return Ok(None);
+        }
+
+        let mut value = std::mem::MaybeUninit::<T>::uninit();
+        let error = unsafe {
+            ffi::hipMemcpy(
+                value.as_mut_ptr() as *mut c_void,
+                self.element_ptr(self.len - 1),
+                std::mem::size_of::<T>(),
+                ffi::hipMemcpyKind_hipMemcpyDeviceToHost,
+            )
+        };
+
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipMemcpy"));
+        }
+
+        self.len -= 1;
+        Ok(Some(unsafe { value.assume_init() }))
+    }
+
+    /// Shorten the vector to `len` elements, keeping its capacity
+    pub fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+
+    /// Remove all elements, keeping the capacity
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Release unused capacity
+    pub fn shrink_to_fit(&mut self) -> Result<()> {
+        self.memory.resize(self.len)
+    }
+
+    /// Copy the elements to a host vector
+    pub fn to_vec(&self) -> Result<Vec<T>>
+    where
+        T: Default,
+    {
+        let mut host = vec![T::default(); self.len];
+        self.memory.copy_to_host(&mut host)?;
+        Ok(host)
+    }
+
+    /// Get the underlying allocation, which may be larger than `len()`
+    pub fn as_device_memory(&self) -> &DeviceMemory<T> {
+        &self.memory
+    }
+
+    /// Convert into a `DeviceMemory` holding exactly `len()` elements
+    pub fn into_device_memory(mut self) -> Result<DeviceMemory<T>> {
+        self.shrink_to_fit()?;
+        Ok(self.memory)
+    }
+
+    /// Get a device pointer to the element at `index`
+    fn element_ptr(&self, index: usize) -> *mut c_void {
+        unsafe { (self.memory.as_ptr() as *mut T).add(index) as *mut c_void }
+    }
+}
+
+impl<T> AsKernelArg for DeviceVec<T> {
+    fn as_kernel_arg(&self) -> KernelArg {
+        self.memory.as_kernel_arg()
+    }
+}
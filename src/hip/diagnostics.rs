@@ -0,0 +1,283 @@
+// src/hip/diagnostics.rs
+//
+// Detection of common ROCm setup problems, with remediation hints
+
+use crate::hip::bindings::{HIP_VERSION_MAJOR, HIP_VERSION_MINOR};
+use crate::hip::device::{Device, get_device_count};
+use crate::hip::error::{Error, Result};
+use crate::hip::ffi;
+use std::env;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::ErrorKind as IoErrorKind;
+use std::path::{Path, PathBuf};
+
+/// A problem with the ROCm installation or the process environment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// `/dev/kfd` doesn't exist, so the amdgpu kernel driver isn't loaded
+    KfdMissing,
+    /// A device node exists but the process can't open it
+    DevicePermission { path: PathBuf },
+    /// The runtime initialized but found no GPUs
+    NoDevices,
+    /// The installed ROCm libraries ship no kernels for a device's gfx target
+    UnsupportedArch { device: i32, gfx_target: String },
+    /// The runtime is a different major/minor release than the headers the
+    /// bindings were generated from
+    VersionMismatch {
+        runtime: (u32, u32),
+        bindings: (u32, u32),
+    },
+}
+
+/// A detected problem and how to fix it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub problem: Problem,
+    pub hint: String,
+}
+
+impl Diagnostic {
+    fn new(problem: Problem) -> Self {
+        let hint = match &problem {
+            Problem::KfdMissing => "the amdgpu kernel driver is not loaded; install the \
+                amdgpu-dkms package or load the amdgpu module, and inside containers pass \
+                --device=/dev/kfd --device=/dev/dri"
+                .to_string(),
+            Problem::DevicePermission { path } => format!(
+                "cannot open {}; add the user to the render and video groups \
+                 (`sudo usermod -aG render,video $USER`) and log in again",
+                path.display()
+            ),
+            Problem::NoDevices => "no GPU is visible; check that HIP_VISIBLE_DEVICES and \
+                ROCR_VISIBLE_DEVICES don't hide every device and that `rocminfo` lists one"
+                .to_string(),
+            Problem::UnsupportedArch { device, gfx_target } => format!(
+                "device {} is {}, which the installed ROCm libraries have no kernels for; \
+                 upgrade ROCm, or set HSA_OVERRIDE_GFX_VERSION to the closest supported \
+                 target (e.g. 10.3.0 for RDNA2, 11.0.0 for RDNA3)",
+                device, gfx_target
+            ),
+            Problem::VersionMismatch { runtime, bindings } => format!(
+                "the HIP runtime is {}.{} but the bindings were generated from {}.{} headers; \
+                 rebuild with ROCM_PATH pointing at the installed ROCm",
+                runtime.0, runtime.1, bindings.0, bindings.1
+            ),
+        };
+
+        Self { problem, hint }
+    }
+
+    fn error_code(&self) -> ffi::hipError_t {
+        match self.problem {
+            Problem::KfdMissing | Problem::DevicePermission { .. } | Problem::NoDevices => {
+                ffi::hipError_t_hipErrorNoDevice
+            }
+            Problem::UnsupportedArch { .. } => ffi::hipError_t_hipErrorNoBinaryForGpu,
+            Problem::VersionMismatch { .. } => ffi::hipError_t_hipErrorInsufficientDriver,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.hint)
+    }
+}
+
+/// Look for common environment problems
+///
+/// Device node permissions are checked without touching the runtime; the
+/// remaining checks run only if HIP initializes.
+pub fn diagnose() -> Vec<Diagnostic> {
+    let mut problems = device_node_problems();
+    if !problems.is_empty() {
+        return problems;
+    }
+
+    if unsafe { ffi::hipInit(0) } != ffi::hipError_t_hipSuccess {
+        return problems;
+    }
+
+    if let Ok(version) = crate::hip::runtime_version() {
+        let runtime = decode_hip_version(version);
+        let bindings = (HIP_VERSION_MAJOR, HIP_VERSION_MINOR);
+        if runtime != bindings {
+            problems.push(Diagnostic::new(Problem::VersionMismatch {
+                runtime,
+                bindings,
+            }));
+        }
+    }
+
+    match get_device_count() {
+        Ok(0) | Err(_) => problems.push(Diagnostic::new(Problem::NoDevices)),
+        Ok(count) => {
+            if let Some(names) = rocblas_library_files() {
+                for id in 0..count {
+                    let Ok(arch) = Device::new(id).and_then(|device| device.arch()) else {
+                        continue;
+                    };
+                    if !library_supports(&names, &arch.gfx_target) {
+                        problems.push(Diagnostic::new(Problem::UnsupportedArch {
+                            device: id,
+                            gfx_target: arch.gfx_target,
+                        }));
+                    }
+                }
+            }
+        }
+    }
+
+    problems
+}
+
+/// Run [`diagnose`] and turn the first problem found into an error
+///
+/// The error's context carries the remediation hint.
+pub fn check_environment() -> Result<()> {
+    match diagnose().into_iter().next() {
+        Some(diagnostic) => Err(Error::with_context(
+            diagnostic.error_code(),
+            diagnostic.hint,
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Attach remediation hints to a failed `hipInit`
+#[track_caller]
+pub(crate) fn init_error(code: ffi::hipError_t) -> Error {
+    let hints: Vec<String> = device_node_problems()
+        .into_iter()
+        .map(|diagnostic| diagnostic.hint)
+        .collect();
+
+    if hints.is_empty() {
+        Error::from_api(code, "hipInit")
+    } else {
+        Error::with_context(code, hints.join("; "))
+    }
+}
+
+/// Get a remediation hint for an error code typical of setup problems
+pub fn hint(code: ffi::hipError_t) -> Option<&'static str> {
+    match code {
+        ffi::hipError_t_hipErrorNoDevice | ffi::hipError_t_hipErrorNotInitialized => Some(
+            "no usable GPU; run hip::diagnostics::diagnose() to check driver, permission \
+             and visibility settings",
+        ),
+        ffi::hipError_t_hipErrorInsufficientDriver => Some(
+            "the kernel driver is older than the HIP runtime; update amdgpu-dkms or use a \
+             matching ROCm release",
+        ),
+        ffi::hipError_t_hipErrorNoBinaryForGpu
+        | ffi::hipError_t_hipErrorInvalidImage
+        | ffi::hipError_t_hipErrorSharedObjectInitFailed => Some(
+            "the code object has no kernels for this GPU; rebuild with \
+             --offload-arch=<gfx target> for the device reported by hip::DeviceArch",
+        ),
+        _ => None,
+    }
+}
+
+#[cfg(unix)]
+fn device_node_problems() -> Vec<Diagnostic> {
+    let mut nodes = vec![PathBuf::from("/dev/kfd")];
+    if let Ok(entries) = fs::read_dir("/dev/dri") {
+        let mut render_nodes: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with("renderD"))
+            })
+            .collect();
+        render_nodes.sort();
+        nodes.extend(render_nodes);
+    }
+
+    let mut problems = Vec::new();
+    for path in nodes {
+        match OpenOptions::new().read(true).write(true).open(&path) {
+            Ok(_) => {}
+            Err(e) if e.kind() == IoErrorKind::NotFound && path == Path::new("/dev/kfd") => {
+                problems.push(Diagnostic::new(Problem::KfdMissing));
+                break;
+            }
+            Err(e) if e.kind() == IoErrorKind::PermissionDenied => {
+                problems.push(Diagnostic::new(Problem::DevicePermission { path }));
+                // One permission hint covers every node
+                break;
+            }
+            Err(_) => {}
+        }
+    }
+    problems
+}
+
+#[cfg(not(unix))]
+fn device_node_problems() -> Vec<Diagnostic> {
+    Vec::new()
+}
+
+/// HIP versions are encoded as `major * 10_000_000 + minor * 100_000 + patch`
+fn decode_hip_version(version: i32) -> (u32, u32) {
+    let version = version.max(0) as u32;
+    (version / 10_000_000, (version / 100_000) % 100)
+}
+
+/// List the rocBLAS kernel library, whose file names carry the gfx targets
+/// the installation was built for
+fn rocblas_library_files() -> Option<Vec<String>> {
+    let rocm_path = env::var_os("ROCM_PATH").unwrap_or_else(|| "/opt/rocm".into());
+    let dir = Path::new(&rocm_path).join("lib/rocblas/library");
+
+    let names: Vec<String> = fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .collect();
+    (!names.is_empty()).then_some(names)
+}
+
+fn library_supports(names: &[String], gfx_target: &str) -> bool {
+    names.iter().any(|name| {
+        name.split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|part| part == gfx_target)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_hip_version() {
+        assert_eq!(decode_hip_version(70152802), (7, 1));
+        assert_eq!(decode_hip_version(60342134), (6, 3));
+    }
+
+    #[test]
+    fn test_library_supports_exact_target() {
+        let names = vec![
+            "TensileLibrary_lazy_gfx90a.dat".to_string(),
+            "Kernels.so-000-gfx1030.hsaco".to_string(),
+        ];
+        assert!(library_supports(&names, "gfx90a"));
+        assert!(library_supports(&names, "gfx1030"));
+        assert!(!library_supports(&names, "gfx90"));
+        assert!(!library_supports(&names, "gfx1031"));
+    }
+
+    #[test]
+    fn test_hints_mention_fix() {
+        let diagnostic = Diagnostic::new(Problem::DevicePermission {
+            path: PathBuf::from("/dev/kfd"),
+        });
+        assert!(diagnostic.hint.contains("/dev/kfd"));
+        assert!(diagnostic.hint.contains("render"));
+        assert!(hint(ffi::hipError_t_hipErrorNoBinaryForGpu).is_some());
+        assert!(hint(ffi::hipError_t_hipSuccess).is_none());
+    }
+}
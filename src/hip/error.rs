@@ -1,13 +1,23 @@
 // src/hip/error.rs
 
+use crate::error::ErrorKind;
 use crate::hip::ffi;
+use std::borrow::Cow;
 use std::error::Error as StdError;
 use std::fmt;
+use std::panic::Location;
 
 /// Error type for HIP operations
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Besides the raw status code, an error records the HIP API that returned
+/// it (when known), any context message and where in this crate it was
+/// raised.
+#[derive(Debug, Clone)]
 pub struct Error {
     code: ffi::hipError_t,
+    api: Option<&'static str>,
+    context: Option<Cow<'static, str>>,
+    location: &'static Location<'static>,
 }
 
 /// Result type for HIP operations
@@ -15,11 +25,94 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 impl Error {
     /// Create a new error from a HIP error code
+    #[track_caller]
     pub fn new(code: ffi::hipError_t) -> Self {
-        Self { code }
+        Self {
+            code,
+            api: None,
+            context: None,
+            location: Location::caller(),
+        }
+    }
+
+    /// Create a new error from the status returned by a HIP API call
+    #[track_caller]
+    pub fn from_api(code: ffi::hipError_t, api: &'static str) -> Self {
+        Self {
+            api: Some(api),
+            ..Self::new(code)
+        }
+    }
+
+    /// Create a new error from a HIP error code with a description of
+    /// what went wrong
+    #[track_caller]
+    pub fn with_context(code: ffi::hipError_t, context: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            context: Some(context.into()),
+            ..Self::new(code)
+        }
+    }
+
+    /// Get the HIP API that returned this error, if known
+    pub fn api(&self) -> Option<&'static str> {
+        self.api
+    }
+
+    /// Get the source location where this error was raised
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// Get the class of failure this error belongs to
+    pub fn kind(&self) -> ErrorKind {
+        match self.code {
+            ffi::hipError_t_hipErrorOutOfMemory => ErrorKind::OutOfMemory,
+            ffi::hipError_t_hipErrorInvalidValue
+            | ffi::hipError_t_hipErrorInvalidConfiguration
+            | ffi::hipError_t_hipErrorInvalidPitchValue
+            | ffi::hipError_t_hipErrorInvalidSymbol
+            | ffi::hipError_t_hipErrorInvalidDevicePointer => ErrorKind::InvalidValue,
+            ffi::hipError_t_hipErrorInvalidDevice | ffi::hipError_t_hipErrorNoDevice => {
+                ErrorKind::InvalidDevice
+            }
+            ffi::hipError_t_hipErrorInvalidHandle | ffi::hipError_t_hipErrorInvalidContext => {
+                ErrorKind::InvalidHandle
+            }
+            ffi::hipError_t_hipErrorNotInitialized | ffi::hipError_t_hipErrorDeinitialized => {
+                ErrorKind::NotInitialized
+            }
+            ffi::hipError_t_hipErrorNotReady => ErrorKind::NotReady,
+            ffi::hipError_t_hipErrorNotSupported
+            | ffi::hipError_t_hipErrorPeerAccessUnsupported => ErrorKind::NotSupported,
+            ffi::hipError_t_hipErrorNotFound | ffi::hipError_t_hipErrorFileNotFound => {
+                ErrorKind::NotFound
+            }
+            ffi::hipError_t_hipErrorLaunchFailure
+            | ffi::hipError_t_hipErrorLaunchOutOfResources
+            | ffi::hipError_t_hipErrorInvalidDeviceFunction => ErrorKind::LaunchFailure,
+            ffi::hipError_t_hipErrorInvalidImage
+            | ffi::hipError_t_hipErrorInvalidKernelFile
+            | ffi::hipError_t_hipErrorNoBinaryForGpu
+            | ffi::hipError_t_hipErrorSharedObjectInitFailed => ErrorKind::KernelCompilation,
+            ffi::hipError_t_hipErrorIllegalAddress => ErrorKind::IllegalAddress,
+            ffi::hipError_t_hipErrorLaunchTimeOut => ErrorKind::Timeout,
+            _ => ErrorKind::Other,
+        }
+    }
+
+    /// Get a hint for fixing the setup problem this error usually indicates
+    pub fn hint(&self) -> Option<&'static str> {
+        crate::hip::diagnostics::hint(self.code)
+    }
+
+    /// Get the additional context attached to this error, if any
+    pub fn context(&self) -> Option<&str> {
+        self.context.as_deref()
     }
 
     /// Convert a HIP error code to a Result
+    #[track_caller]
     pub fn from_hip_error<T>(error: ffi::hipError_t) -> Result<T>
     where
         T: Default,
@@ -32,6 +125,7 @@ impl Error {
     }
 
     /// Convert a HIP error code to a Result with a specific value
+    #[track_caller]
     pub fn from_hip_error_with_value<T>(error: ffi::hipError_t, value: T) -> Result<T> {
         if error == ffi::hipError_t_hipSuccess {
             Ok(value)
@@ -89,10 +183,26 @@ impl fmt::Display for Error {
             self.code,
             self.name(),
             self.description()
-        )
+        )?;
+        if let Some(api) = self.api {
+            write!(f, " in {}", api)?;
+        }
+        if let Some(context) = &self.context {
+            write!(f, " ({})", context)?;
+        }
+        write!(f, " at {}", self.location)
     }
 }
 
+// Where an error was raised doesn't make it a different error
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code && self.api == other.api && self.context == other.context
+    }
+}
+
+impl Eq for Error {}
+
 impl StdError for Error {}
 
 // Define error conversion functions for common HIP error codes
@@ -17,7 +17,7 @@ impl Event {
         let error = unsafe { ffi::hipEventCreate(&mut event) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipEventCreate"));
         }
 
         Ok(Self { event })
@@ -29,18 +29,32 @@ impl Event {
         let error = unsafe { ffi::hipEventCreateWithFlags(&mut event, flags) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipEventCreateWithFlags"));
         }
 
         Ok(Self { event })
     }
 
+    /// Create an event whose `synchronize` blocks the thread instead of
+    /// spinning, trading wake-up latency for CPU time
+    pub fn blocking_sync() -> Result<Self> {
+        Self::with_flags(event_flags::BLOCKING_SYNC)
+    }
+
+    /// Create an event that records no timestamp
+    ///
+    /// Such events are cheaper to record and wait on, which suits events
+    /// used only to order streams. They can't be passed to `elapsed_time`.
+    pub fn without_timing() -> Result<Self> {
+        Self::with_flags(event_flags::DISABLE_TIMING)
+    }
+
     /// Record an event in a stream
     pub fn record(&self, stream: &Stream) -> Result<()> {
         let error = unsafe { ffi::hipEventRecord(self.event, stream.as_raw()) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipEventRecord"));
         }
 
         Ok(())
@@ -51,23 +65,26 @@ impl Event {
         let error = unsafe { ffi::hipEventSynchronize(self.event) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipEventSynchronize"));
         }
 
         Ok(())
     }
 
-    /// Query if the event has completed
-    pub fn query(&self) -> Result<()> {
+    /// Check whether the work captured by the event has completed, without
+    /// blocking
+    ///
+    /// An event that was never recorded counts as complete.
+    pub fn query(&self) -> Result<bool> {
         let error = unsafe { ffi::hipEventQuery(self.event) };
 
         if error == ffi::hipError_t_hipSuccess {
-            Ok(())
+            Ok(true)
         } else if error == ffi::hipError_t_hipErrorNotReady {
             // Not ready isn't a true error in this context
-            Err(Error::new(error))
+            Ok(false)
         } else {
-            Err(Error::new(error))
+            Err(Error::from_api(error, "hipEventQuery"))
         }
     }
 
@@ -77,12 +94,20 @@ impl Event {
         let error = unsafe { ffi::hipEventElapsedTime(&mut time, self.event, end.event) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipEventElapsedTime"));
         }
 
         Ok(time)
     }
 
+    /// Calculate elapsed time from `start` to this event in milliseconds
+    ///
+    /// Both events must have completed; call `synchronize` on this one
+    /// first if unsure.
+    pub fn elapsed_since(&self, start: &Event) -> Result<f32> {
+        start.elapsed_time(self)
+    }
+
     /// Get the raw event handle
     pub fn as_raw(&self) -> ffi::hipEvent_t {
         self.event
@@ -0,0 +1,347 @@
+// src/hip/external.rs
+//
+// Import of memory and semaphores exported by other APIs (Vulkan, VAAPI, ...)
+
+use crate::hip::error::{Error, Result};
+use crate::hip::kernel::AsKernelArg;
+use crate::hip::memory::{DeviceMemory, DeviceMut, DeviceRef, KernelArg};
+use crate::hip::{Stream, ffi};
+use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::mem::{self, size_of};
+use std::ptr;
+
+#[cfg(unix)]
+use std::os::fd::{AsRawFd, IntoRawFd, OwnedFd};
+
+/// Memory allocated by another API and imported into HIP
+///
+/// Buffers mapped from the import alias the exporter's memory, so data
+/// produced there (a Vulkan image, a VAAPI decoded frame exported as a
+/// dma-buf, ...) can be read by kernels without a host round-trip.
+/// Synchronize access with an [`ExternalSemaphore`].
+pub struct ExternalMemory {
+    handle: ffi::hipExternalMemory_t,
+    size: usize,
+}
+
+impl ExternalMemory {
+    /// Import memory exported as a file descriptor
+    ///
+    /// This covers Vulkan opaque fds and dma-buf fds. On success HIP takes
+    /// ownership of the descriptor; on failure it is closed. Set `dedicated`
+    /// if the memory was exported from a dedicated allocation.
+    #[cfg(unix)]
+    pub fn from_fd(fd: OwnedFd, size: usize, dedicated: bool) -> Result<Self> {
+        let mut desc = Self::handle_desc(
+            ffi::hipExternalMemoryHandleType_enum_hipExternalMemoryHandleTypeOpaqueFd,
+            size,
+            dedicated,
+        );
+        desc.handle.fd = fd.as_raw_fd();
+
+        let memory = unsafe { Self::import(&desc, size) }?;
+        // The descriptor now belongs to HIP
+        let _ = fd.into_raw_fd();
+        Ok(memory)
+    }
+
+    /// Import memory exported as a Windows NT handle
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid handle to memory of at least `size` bytes.
+    pub unsafe fn from_win32_handle(
+        handle: *mut c_void,
+        size: usize,
+        dedicated: bool,
+    ) -> Result<Self> {
+        let mut desc = Self::handle_desc(
+            ffi::hipExternalMemoryHandleType_enum_hipExternalMemoryHandleTypeOpaqueWin32,
+            size,
+            dedicated,
+        );
+        desc.handle.win32.handle = handle;
+        desc.handle.win32.name = ptr::null();
+
+        unsafe { Self::import(&desc, size) }
+    }
+
+    fn handle_desc(
+        type_: ffi::hipExternalMemoryHandleType,
+        size: usize,
+        dedicated: bool,
+    ) -> ffi::hipExternalMemoryHandleDesc {
+        let mut desc: ffi::hipExternalMemoryHandleDesc = unsafe { mem::zeroed() };
+        desc.type_ = type_;
+        desc.size = size as u64;
+        if dedicated {
+            desc.flags = ffi::hipExternalMemoryDedicated;
+        }
+        desc
+    }
+
+    unsafe fn import(desc: &ffi::hipExternalMemoryHandleDesc, size: usize) -> Result<Self> {
+        let mut handle = ptr::null_mut();
+        let error = unsafe { ffi::hipImportExternalMemory(&mut handle, desc) };
+
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipImportExternalMemory"));
+        }
+
+        Ok(Self { handle, size })
+    }
+
+    /// Map `count` elements starting `offset` bytes into the memory as a
+    /// device buffer
+    pub fn mapped_buffer<T>(&self, offset: usize, count: usize) -> Result<ExternalBuffer<'_, T>> {
+        let size = count * size_of::<T>();
+        if offset.checked_add(size).is_none_or(|end| end > self.size) {
+            return Err(Error::with_context(
+                ffi::hipError_t_hipErrorInvalidValue,
+                format!(
+                    "mapping {} bytes at offset {} exceeds the {} byte import",
+                    size, offset, self.size
+                ),
+            ));
+        }
+
+        let mut desc: ffi::hipExternalMemoryBufferDesc = unsafe { mem::zeroed() };
+        desc.offset = offset as u64;
+        desc.size = size as u64;
+
+        let mut ptr = ptr::null_mut();
+        let error = unsafe { ffi::hipExternalMemoryGetMappedBuffer(&mut ptr, self.handle, &desc) };
+
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipExternalMemoryGetMappedBuffer"));
+        }
+
+        Ok(ExternalBuffer {
+            // Mapped buffers are released with hipFree
+            memory: unsafe { DeviceMemory::from_raw_parts(ptr, size) },
+            _import: PhantomData,
+        })
+    }
+
+    /// Get the size in bytes of the imported memory
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Get the raw external memory handle
+    pub fn as_raw(&self) -> ffi::hipExternalMemory_t {
+        self.handle
+    }
+}
+
+impl Drop for ExternalMemory {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe {
+                let _ = ffi::hipDestroyExternalMemory(self.handle);
+                // We cannot handle errors in drop, so just ignore the result
+            };
+            self.handle = ptr::null_mut();
+        }
+    }
+}
+
+/// A device buffer mapped from [`ExternalMemory`]
+///
+/// The mapping is released on drop and cannot outlive the import.
+pub struct ExternalBuffer<'a, T> {
+    memory: DeviceMemory<T>,
+    _import: PhantomData<&'a ExternalMemory>,
+}
+
+impl<T> ExternalBuffer<'_, T> {
+    /// Get the device pointer
+    pub fn as_ptr(&self) -> *mut c_void {
+        self.memory.as_ptr()
+    }
+
+    /// Get the size in bytes
+    pub fn size(&self) -> usize {
+        self.memory.size()
+    }
+
+    /// Get the number of elements
+    pub fn count(&self) -> usize {
+        self.memory.count()
+    }
+
+    /// Get the mapping as device memory
+    pub fn as_device_memory(&self) -> &DeviceMemory<T> {
+        &self.memory
+    }
+
+    /// Borrow this buffer as a read-only kernel input
+    pub fn as_device_ref(&self) -> DeviceRef<'_, T> {
+        self.memory.as_device_ref()
+    }
+
+    /// Borrow this buffer as a writable kernel output
+    pub fn as_device_mut(&mut self) -> DeviceMut<'_, T> {
+        self.memory.as_device_mut()
+    }
+
+    /// Copy data from host to the mapped memory
+    pub fn copy_from_host(&mut self, data: &[T]) -> Result<()> {
+        self.memory.copy_from_host(data)
+    }
+
+    /// Copy data from the mapped memory to host
+    pub fn copy_to_host(&self, data: &mut [T]) -> Result<()> {
+        self.memory.copy_to_host(data)
+    }
+}
+
+impl<T> AsKernelArg for ExternalBuffer<'_, T> {
+    fn as_kernel_arg(&self) -> KernelArg {
+        self.memory.as_kernel_arg()
+    }
+}
+
+/// Kind of an imported semaphore
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SemaphoreKind {
+    /// A binary semaphore; signal and wait values are ignored
+    Binary,
+    /// A timeline semaphore signaled and waited on by value
+    Timeline,
+}
+
+/// A semaphore exported by another API and imported into HIP
+///
+/// Waiting on a stream orders the stream's following work after the
+/// exporter's signal; signaling lets the exporter wait for work queued
+/// before it.
+pub struct ExternalSemaphore {
+    handle: ffi::hipExternalSemaphore_t,
+    kind: SemaphoreKind,
+}
+
+impl ExternalSemaphore {
+    /// Import a semaphore exported as a file descriptor
+    ///
+    /// On success HIP takes ownership of the descriptor; on failure it is
+    /// closed.
+    #[cfg(unix)]
+    pub fn from_fd(fd: OwnedFd, kind: SemaphoreKind) -> Result<Self> {
+        let type_ = match kind {
+            SemaphoreKind::Binary => {
+                ffi::hipExternalSemaphoreHandleType_enum_hipExternalSemaphoreHandleTypeOpaqueFd
+            }
+            SemaphoreKind::Timeline => {
+                ffi::hipExternalSemaphoreHandleType_enum_hipExternalSemaphoreHandleTypeTimelineSemaphoreFd
+            }
+        };
+
+        let mut desc: ffi::hipExternalSemaphoreHandleDesc = unsafe { mem::zeroed() };
+        desc.type_ = type_;
+        desc.handle.fd = fd.as_raw_fd();
+
+        let semaphore = unsafe { Self::import(&desc, kind) }?;
+        // The descriptor now belongs to HIP
+        let _ = fd.into_raw_fd();
+        Ok(semaphore)
+    }
+
+    /// Import a semaphore exported as a Windows NT handle
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid handle to a semaphore of the given kind.
+    pub unsafe fn from_win32_handle(handle: *mut c_void, kind: SemaphoreKind) -> Result<Self> {
+        let type_ = match kind {
+            SemaphoreKind::Binary => {
+                ffi::hipExternalSemaphoreHandleType_enum_hipExternalSemaphoreHandleTypeOpaqueWin32
+            }
+            SemaphoreKind::Timeline => {
+                ffi::hipExternalSemaphoreHandleType_enum_hipExternalSemaphoreHandleTypeTimelineSemaphoreWin32
+            }
+        };
+
+        let mut desc: ffi::hipExternalSemaphoreHandleDesc = unsafe { mem::zeroed() };
+        desc.type_ = type_;
+        desc.handle.win32.handle = handle;
+        desc.handle.win32.name = ptr::null();
+
+        unsafe { Self::import(&desc, kind) }
+    }
+
+    unsafe fn import(
+        desc: &ffi::hipExternalSemaphoreHandleDesc,
+        kind: SemaphoreKind,
+    ) -> Result<Self> {
+        let mut handle = ptr::null_mut();
+        let error = unsafe { ffi::hipImportExternalSemaphore(&mut handle, desc) };
+
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipImportExternalSemaphore"));
+        }
+
+        Ok(Self { handle, kind })
+    }
+
+    /// Signal the semaphore once work queued so far on `stream` completes
+    ///
+    /// `value` is the timeline value to signal and is ignored for binary
+    /// semaphores.
+    pub fn signal(&self, value: u64, stream: &Stream) -> Result<()> {
+        let mut params: ffi::hipExternalSemaphoreSignalParams = unsafe { mem::zeroed() };
+        params.params.fence.value = value;
+
+        let error = unsafe {
+            ffi::hipSignalExternalSemaphoresAsync(&self.handle, &params, 1, stream.as_raw())
+        };
+
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipSignalExternalSemaphoresAsync"));
+        }
+
+        Ok(())
+    }
+
+    /// Make work queued afterwards on `stream` wait for the semaphore
+    ///
+    /// `value` is the timeline value to wait for and is ignored for binary
+    /// semaphores.
+    pub fn wait(&self, value: u64, stream: &Stream) -> Result<()> {
+        let mut params: ffi::hipExternalSemaphoreWaitParams = unsafe { mem::zeroed() };
+        params.params.fence.value = value;
+
+        let error = unsafe {
+            ffi::hipWaitExternalSemaphoresAsync(&self.handle, &params, 1, stream.as_raw())
+        };
+
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipWaitExternalSemaphoresAsync"));
+        }
+
+        Ok(())
+    }
+
+    /// Get the kind of the semaphore
+    pub fn kind(&self) -> SemaphoreKind {
+        self.kind
+    }
+
+    /// Get the raw external semaphore handle
+    pub fn as_raw(&self) -> ffi::hipExternalSemaphore_t {
+        self.handle
+    }
+}
+
+impl Drop for ExternalSemaphore {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe {
+                let _ = ffi::hipDestroyExternalSemaphore(self.handle);
+                // We cannot handle errors in drop, so just ignore the result
+            };
+            self.handle = ptr::null_mut();
+        }
+    }
+}
@@ -11,19 +11,49 @@ use crate::hip::bindings;
 
 // Error type and constants
 pub use bindings::hipError_t;
+pub use bindings::hipError_t_hipErrorDeinitialized;
+pub use bindings::hipError_t_hipErrorFileNotFound;
+pub use bindings::hipError_t_hipErrorIllegalAddress;
+pub use bindings::hipError_t_hipErrorInsufficientDriver;
+pub use bindings::hipError_t_hipErrorInvalidConfiguration;
 pub use bindings::hipError_t_hipErrorInvalidContext;
 pub use bindings::hipError_t_hipErrorInvalidDevice;
+pub use bindings::hipError_t_hipErrorInvalidDeviceFunction;
+pub use bindings::hipError_t_hipErrorInvalidDevicePointer;
+pub use bindings::hipError_t_hipErrorInvalidHandle;
+pub use bindings::hipError_t_hipErrorInvalidImage;
+pub use bindings::hipError_t_hipErrorInvalidKernelFile;
+pub use bindings::hipError_t_hipErrorInvalidPitchValue;
+pub use bindings::hipError_t_hipErrorInvalidSymbol;
 pub use bindings::hipError_t_hipErrorInvalidValue;
+pub use bindings::hipError_t_hipErrorLaunchFailure;
+pub use bindings::hipError_t_hipErrorLaunchOutOfResources;
+pub use bindings::hipError_t_hipErrorLaunchTimeOut;
 pub use bindings::hipError_t_hipErrorMemoryAllocation;
+pub use bindings::hipError_t_hipErrorNoBinaryForGpu;
+pub use bindings::hipError_t_hipErrorNoDevice;
+pub use bindings::hipError_t_hipErrorNotFound;
 pub use bindings::hipError_t_hipErrorNotInitialized;
 pub use bindings::hipError_t_hipErrorNotReady;
+pub use bindings::hipError_t_hipErrorNotSupported;
 pub use bindings::hipError_t_hipErrorOutOfMemory;
+pub use bindings::hipError_t_hipErrorPeerAccessAlreadyEnabled;
+pub use bindings::hipError_t_hipErrorPeerAccessUnsupported;
+pub use bindings::hipError_t_hipErrorSharedObjectInitFailed;
 pub use bindings::hipError_t_hipSuccess;
 
 // Device handle and operations
 pub use bindings::hipDevice_t;
+pub use bindings::hipDeviceAttribute_t;
+pub use bindings::hipDeviceAttribute_t_hipDeviceAttributeCooperativeLaunch;
+pub use bindings::hipDeviceAttribute_t_hipDeviceAttributeHostNativeAtomicSupported;
+pub use bindings::hipDeviceCanAccessPeer;
+pub use bindings::hipDeviceEnablePeerAccess;
+pub use bindings::hipDeviceGetAttribute;
+pub use bindings::hipDeviceGetLimit;
 pub use bindings::hipDeviceProp_tR0600;
 pub use bindings::hipDeviceReset;
+pub use bindings::hipDeviceSetLimit;
 pub use bindings::hipDeviceSynchronize;
 pub use bindings::hipDriverGetVersion;
 pub use bindings::hipGetDevice;
@@ -36,16 +66,35 @@ pub use bindings::hipInit;
 pub use bindings::hipRuntimeGetVersion;
 pub use bindings::hipSetDevice;
 
+// Device limits
+pub use bindings::hipLimit_t;
+pub use bindings::hipLimit_t_hipLimitMallocHeapSize;
+pub use bindings::hipLimit_t_hipLimitPrintfFifoSize;
+pub use bindings::hipLimit_t_hipLimitStackSize;
+
 // Memory management
 pub use bindings::hipFree;
 pub use bindings::hipHostFree;
 pub use bindings::hipHostGetDevicePointer;
 pub use bindings::hipHostMalloc;
 pub use bindings::hipMalloc;
+pub use bindings::hipMemGetAddressRange;
 pub use bindings::hipMemGetInfo;
 pub use bindings::hipMemcpy;
 pub use bindings::hipMemcpyAsync;
+pub use bindings::hipMemcpyPeer;
 pub use bindings::hipMemset;
+pub use bindings::hipMemsetAsync;
+pub use bindings::hipPointerAttribute_t;
+pub use bindings::hipPointerGetAttributes;
+
+// Memory types
+pub use bindings::hipMemoryType_hipMemoryTypeArray;
+pub use bindings::hipMemoryType_hipMemoryTypeDevice;
+pub use bindings::hipMemoryType_hipMemoryTypeHost;
+pub use bindings::hipMemoryType_hipMemoryTypeManaged;
+pub use bindings::hipMemoryType_hipMemoryTypeUnified;
+pub use bindings::hipMemoryType_hipMemoryTypeUnregistered;
 
 // Memory copy kinds
 pub use bindings::hipMemcpyKind_hipMemcpyDefault;
@@ -88,8 +137,26 @@ pub use bindings::hipEventQuery;
 pub use bindings::hipEventRecord;
 pub use bindings::hipEventSynchronize;
 
+// Graph capture
+pub use bindings::hipGraph_t;
+pub use bindings::hipGraphDestroy;
+pub use bindings::hipGraphExec_t;
+pub use bindings::hipGraphExecDestroy;
+pub use bindings::hipGraphInstantiate;
+pub use bindings::hipGraphLaunch;
+pub use bindings::hipStreamBeginCapture;
+pub use bindings::hipStreamCaptureMode;
+pub use bindings::hipStreamCaptureMode_hipStreamCaptureModeGlobal;
+pub use bindings::hipStreamCaptureMode_hipStreamCaptureModeRelaxed;
+pub use bindings::hipStreamCaptureMode_hipStreamCaptureModeThreadLocal;
+pub use bindings::hipStreamEndCapture;
+
 // Kernel launching
 pub use bindings::dim3;
+pub use bindings::hipFuncGetAttribute;
+pub use bindings::hipFunction_attribute;
+pub use bindings::hipFunction_attribute_HIP_FUNC_ATTRIBUTE_MAX_DYNAMIC_SHARED_SIZE_BYTES;
+pub use bindings::hipFunction_attribute_HIP_FUNC_ATTRIBUTE_MAX_THREADS_PER_BLOCK;
 pub use bindings::hipFunction_t;
 pub use bindings::hipLaunchKernel;
 pub use bindings::hipModuleGetFunction;
@@ -111,5 +178,45 @@ pub use bindings::hipModuleLoadData;
 pub use bindings::hipModuleLoadDataEx;
 pub use bindings::hipModuleUnload;
 
+// External memory and semaphores
+pub use bindings::hipDestroyExternalMemory;
+pub use bindings::hipDestroyExternalSemaphore;
+pub use bindings::hipExternalMemory_t;
+pub use bindings::hipExternalMemoryBufferDesc;
+pub use bindings::hipExternalMemoryDedicated;
+pub use bindings::hipExternalMemoryGetMappedBuffer;
+pub use bindings::hipExternalMemoryHandleDesc;
+pub use bindings::hipExternalMemoryHandleType;
+pub use bindings::hipExternalMemoryHandleType_enum_hipExternalMemoryHandleTypeOpaqueFd;
+pub use bindings::hipExternalMemoryHandleType_enum_hipExternalMemoryHandleTypeOpaqueWin32;
+pub use bindings::hipExternalSemaphore_t;
+pub use bindings::hipExternalSemaphoreHandleDesc;
+pub use bindings::hipExternalSemaphoreHandleType_enum_hipExternalSemaphoreHandleTypeOpaqueFd;
+pub use bindings::hipExternalSemaphoreHandleType_enum_hipExternalSemaphoreHandleTypeOpaqueWin32;
+pub use bindings::hipExternalSemaphoreHandleType_enum_hipExternalSemaphoreHandleTypeTimelineSemaphoreFd;
+pub use bindings::hipExternalSemaphoreHandleType_enum_hipExternalSemaphoreHandleTypeTimelineSemaphoreWin32;
+pub use bindings::hipExternalSemaphoreSignalParams;
+pub use bindings::hipExternalSemaphoreWaitParams;
+pub use bindings::hipImportExternalMemory;
+pub use bindings::hipImportExternalSemaphore;
+pub use bindings::hipSignalExternalSemaphoresAsync;
+pub use bindings::hipWaitExternalSemaphoresAsync;
+
+// Graphics interop
+pub use bindings::hipGraphicsMapResources;
+pub use bindings::hipGraphicsResource_t;
+pub use bindings::hipGraphicsResourceGetMappedPointer;
+pub use bindings::hipGraphicsUnmapResources;
+pub use bindings::hipGraphicsUnregisterResource;
+
+// OpenGL interop lives in hip_gl_interop.h, which the bindings don't cover
+unsafe extern "C" {
+    pub fn hipGraphicsGLRegisterBuffer(
+        resource: *mut hipGraphicsResource_t,
+        buffer: u32,
+        flags: u32,
+    ) -> hipError_t;
+}
+
 // Other useful constants and types as needed for your implementation
 // Add more imports as required by your wrapper implementation
@@ -0,0 +1,197 @@
+// src/hip/gl_interop.rs
+//
+// Sharing OpenGL buffer objects with HIP kernels
+
+use crate::hip::error::{Error, Result};
+use crate::hip::kernel::AsKernelArg;
+use crate::hip::memory::{DeviceMemory, DeviceMut, DeviceRef, KernelArg};
+use crate::hip::{Stream, ffi};
+use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::mem::{ManuallyDrop, size_of};
+use std::ptr;
+
+/// An OpenGL buffer object registered with HIP
+///
+/// Kernels can write straight into a vertex buffer by mapping it, and the
+/// buffer can be drawn once it is unmapped, without copying through the
+/// host. The OpenGL context owning the buffer must be current on the
+/// calling thread when registering.
+pub struct GlBuffer {
+    resource: ffi::hipGraphicsResource_t,
+}
+
+impl GlBuffer {
+    /// Register the OpenGL buffer object named `buffer`
+    ///
+    /// `flags` is a combination of [`graphics_register_flags`] hinting how
+    /// kernels will access the buffer.
+    pub fn register(buffer: u32, flags: u32) -> Result<Self> {
+        let mut resource = ptr::null_mut();
+        let error = unsafe { ffi::hipGraphicsGLRegisterBuffer(&mut resource, buffer, flags) };
+
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipGraphicsGLRegisterBuffer"));
+        }
+
+        Ok(Self { resource })
+    }
+
+    /// Map the buffer for access by work queued on `stream`
+    ///
+    /// OpenGL must not use the buffer until it is unmapped.
+    pub fn map<T>(&mut self, stream: &Stream) -> Result<MappedGlBuffer<'_, T>> {
+        let error = unsafe { ffi::hipGraphicsMapResources(1, &mut self.resource, stream.as_raw()) };
+
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipGraphicsMapResources"));
+        }
+
+        let mut ptr = ptr::null_mut();
+        let mut size = 0;
+        let error =
+            unsafe { ffi::hipGraphicsResourceGetMappedPointer(&mut ptr, &mut size, self.resource) };
+
+        if error != ffi::hipError_t_hipSuccess {
+            unsafe {
+                let _ = ffi::hipGraphicsUnmapResources(1, &mut self.resource, stream.as_raw());
+            }
+            return Err(Error::from_api(
+                error,
+                "hipGraphicsResourceGetMappedPointer",
+            ));
+        }
+
+        // Round down to whole elements
+        let size = size / size_of::<T>() * size_of::<T>();
+
+        Ok(MappedGlBuffer {
+            // The mapping is owned by OpenGL, so it must never be freed
+            memory: ManuallyDrop::new(unsafe { DeviceMemory::from_raw_parts(ptr, size) }),
+            resource: self.resource,
+            stream: stream.clone(),
+            _buffer: PhantomData,
+        })
+    }
+
+    /// Get the raw graphics resource handle
+    pub fn as_raw(&self) -> ffi::hipGraphicsResource_t {
+        self.resource
+    }
+}
+
+impl Drop for GlBuffer {
+    fn drop(&mut self) {
+        if !self.resource.is_null() {
+            unsafe {
+                let _ = ffi::hipGraphicsUnregisterResource(self.resource);
+                // We cannot handle errors in drop, so just ignore the result
+            };
+            self.resource = ptr::null_mut();
+        }
+    }
+}
+
+/// A mapped OpenGL buffer, usable as device memory
+///
+/// The buffer is unmapped on drop, after work queued on the mapping stream.
+/// Call [`MappedGlBuffer::unmap`] to observe unmap errors.
+pub struct MappedGlBuffer<'a, T> {
+    memory: ManuallyDrop<DeviceMemory<T>>,
+    resource: ffi::hipGraphicsResource_t,
+    stream: Stream,
+    _buffer: PhantomData<&'a mut GlBuffer>,
+}
+
+impl<T> MappedGlBuffer<'_, T> {
+    /// Get the device pointer
+    pub fn as_ptr(&self) -> *mut c_void {
+        self.memory.as_ptr()
+    }
+
+    /// Get the size in bytes
+    pub fn size(&self) -> usize {
+        self.memory.size()
+    }
+
+    /// Get the number of elements
+    pub fn count(&self) -> usize {
+        self.memory.count()
+    }
+
+    /// Get the mapping as device memory
+    pub fn as_device_memory(&self) -> &DeviceMemory<T> {
+        &self.memory
+    }
+
+    /// Borrow this buffer as a read-only kernel input
+    pub fn as_device_ref(&self) -> DeviceRef<'_, T> {
+        self.memory.as_device_ref()
+    }
+
+    /// Borrow this buffer as a writable kernel output
+    pub fn as_device_mut(&mut self) -> DeviceMut<'_, T> {
+        self.memory.as_device_mut()
+    }
+
+    /// Copy data from host to the buffer
+    pub fn copy_from_host(&mut self, data: &[T]) -> Result<()> {
+        self.memory.copy_from_host(data)
+    }
+
+    /// Copy data from the buffer to host
+    pub fn copy_to_host(&self, data: &mut [T]) -> Result<()> {
+        self.memory.copy_to_host(data)
+    }
+
+    /// Unmap the buffer, handing it back to OpenGL
+    pub fn unmap(mut self) -> Result<()> {
+        let result = self.unmap_inner();
+        self.resource = ptr::null_mut();
+        result
+    }
+
+    fn unmap_inner(&mut self) -> Result<()> {
+        let error =
+            unsafe { ffi::hipGraphicsUnmapResources(1, &mut self.resource, self.stream.as_raw()) };
+
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipGraphicsUnmapResources"));
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> AsKernelArg for MappedGlBuffer<'_, T> {
+    fn as_kernel_arg(&self) -> KernelArg {
+        self.memory.as_kernel_arg()
+    }
+}
+
+impl<T> Drop for MappedGlBuffer<'_, T> {
+    fn drop(&mut self) {
+        if !self.resource.is_null() {
+            // We cannot handle errors in drop, so just ignore the result
+            let _ = self.unmap_inner();
+        }
+    }
+}
+
+/// Flags for registering graphics resources
+pub mod graphics_register_flags {
+    /// No hint about how the resource is accessed
+    pub const NONE: u32 = 0;
+
+    /// Kernels only read the resource
+    pub const READ_ONLY: u32 = 1;
+
+    /// Kernels overwrite the whole resource without reading it
+    pub const WRITE_DISCARD: u32 = 2;
+
+    /// The resource is bound as a surface for load/store access
+    pub const SURFACE_LOAD_STORE: u32 = 4;
+
+    /// The resource is used for texture gather operations
+    pub const TEXTURE_GATHER: u32 = 8;
+}
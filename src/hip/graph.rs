@@ -0,0 +1,135 @@
+// src/hip/graph.rs
+//
+// Capture of stream work into replayable graphs
+
+use crate::hip::error::{Error, Result};
+use crate::hip::{Stream, ffi};
+use std::ptr;
+
+/// How a capture interacts with unsafe API calls from other threads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CaptureMode {
+    /// Potentially unsafe calls from any thread fail during the capture
+    #[default]
+    Global,
+    /// Only the capturing thread is restricted
+    ThreadLocal,
+    /// No calls are restricted
+    Relaxed,
+}
+
+impl From<CaptureMode> for ffi::hipStreamCaptureMode {
+    fn from(mode: CaptureMode) -> Self {
+        match mode {
+            CaptureMode::Global => ffi::hipStreamCaptureMode_hipStreamCaptureModeGlobal,
+            CaptureMode::ThreadLocal => ffi::hipStreamCaptureMode_hipStreamCaptureModeThreadLocal,
+            CaptureMode::Relaxed => ffi::hipStreamCaptureMode_hipStreamCaptureModeRelaxed,
+        }
+    }
+}
+
+/// A graph of work captured from a stream
+pub struct Graph {
+    graph: ffi::hipGraph_t,
+}
+
+impl Graph {
+    /// Record the work `enqueue` queues on `stream` into a graph
+    ///
+    /// Nothing is executed while capturing. The capture is ended even if
+    /// `enqueue` fails, in which case its error is returned.
+    pub fn capture<E>(
+        stream: &Stream,
+        mode: CaptureMode,
+        enqueue: impl FnOnce(&Stream) -> std::result::Result<(), E>,
+    ) -> std::result::Result<Self, E>
+    where
+        E: From<Error>,
+    {
+        let error = unsafe { ffi::hipStreamBeginCapture(stream.as_raw(), mode.into()) };
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipStreamBeginCapture").into());
+        }
+
+        let result = enqueue(stream);
+
+        let mut graph = ptr::null_mut();
+        let error = unsafe { ffi::hipStreamEndCapture(stream.as_raw(), &mut graph) };
+        let captured = Self { graph };
+
+        result?;
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipStreamEndCapture").into());
+        }
+        Ok(captured)
+    }
+
+    /// Prepare the graph for launching
+    pub fn instantiate(&self) -> Result<GraphExec> {
+        let mut exec = ptr::null_mut();
+        let error = unsafe {
+            ffi::hipGraphInstantiate(&mut exec, self.graph, ptr::null_mut(), ptr::null_mut(), 0)
+        };
+
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipGraphInstantiate"));
+        }
+
+        Ok(GraphExec { exec })
+    }
+
+    /// Get the raw graph handle
+    pub fn as_raw(&self) -> ffi::hipGraph_t {
+        self.graph
+    }
+}
+
+impl Drop for Graph {
+    fn drop(&mut self) {
+        if !self.graph.is_null() {
+            unsafe {
+                let _ = ffi::hipGraphDestroy(self.graph);
+                // We cannot handle errors in drop, so just ignore the result
+            };
+            self.graph = ptr::null_mut();
+        }
+    }
+}
+
+/// An instantiated graph, ready to be launched repeatedly
+///
+/// Launching replays all captured work with a single call, removing the
+/// per-launch overhead of the individual kernels and copies.
+pub struct GraphExec {
+    exec: ffi::hipGraphExec_t,
+}
+
+impl GraphExec {
+    /// Queue the graph's work on `stream`
+    pub fn launch(&self, stream: &Stream) -> Result<()> {
+        let error = unsafe { ffi::hipGraphLaunch(self.exec, stream.as_raw()) };
+
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipGraphLaunch"));
+        }
+
+        Ok(())
+    }
+
+    /// Get the raw executable graph handle
+    pub fn as_raw(&self) -> ffi::hipGraphExec_t {
+        self.exec
+    }
+}
+
+impl Drop for GraphExec {
+    fn drop(&mut self) {
+        if !self.exec.is_null() {
+            unsafe {
+                let _ = ffi::hipGraphExecDestroy(self.exec);
+                // We cannot handle errors in drop, so just ignore the result
+            };
+            self.exec = ptr::null_mut();
+        }
+    }
+}
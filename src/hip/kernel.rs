@@ -6,13 +6,43 @@ use crate::hip::Stream;
 use crate::hip::error::{Error, Result};
 use crate::hip::ffi;
 use crate::hip::memory::KernelArg;
+use crate::hip::stream::default_stream_raw;
 use crate::hip::utils::Dim3;
 use std::ffi::{CString, c_void};
 use std::ptr;
 
+/// Launch bounds of a kernel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LaunchBounds {
+    /// Maximum number of threads per block the kernel can be launched with
+    pub max_threads_per_block: u32,
+    /// Minimum number of resident blocks per multiprocessor the kernel was
+    /// compiled for, if known
+    ///
+    /// The runtime doesn't report this, so it is only set by bounds recorded
+    /// with [`Function::with_launch_bounds`].
+    pub min_blocks_per_multiprocessor: Option<u32>,
+}
+
 /// A wrapper around a HIP function (kernel)
 pub struct Function {
     function: ffi::hipFunction_t,
+    bounds: Option<LaunchBounds>,
+    max_dynamic_shared_mem: Option<u32>,
+}
+
+fn query_attribute(
+    function: ffi::hipFunction_t,
+    attribute: ffi::hipFunction_attribute,
+) -> Option<u32> {
+    let mut value = 0;
+    let error = unsafe { ffi::hipFuncGetAttribute(&mut value, attribute, function) };
+
+    if error == ffi::hipError_t_hipSuccess && value > 0 {
+        Some(value as u32)
+    } else {
+        None
+    }
 }
 
 impl Function {
@@ -24,13 +54,61 @@ impl Function {
         let error = unsafe { ffi::hipModuleGetFunction(&mut function, module, func_name.as_ptr()) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipModuleGetFunction"));
+        }
+
+        Ok(unsafe { Self::from_raw(function) })
+    }
+
+    /// Record launch bounds for this function, e.g. from kernel macro output
+    ///
+    /// This replaces the bounds queried from the runtime.
+    pub fn with_launch_bounds(mut self, bounds: LaunchBounds) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Get the launch bounds of this function, if known
+    pub fn launch_bounds(&self) -> Option<LaunchBounds> {
+        self.bounds
+    }
+
+    /// Check a launch configuration against the recorded launch bounds
+    fn validate_launch(&self, block_dim: Dim3, shared_mem_bytes: u32) -> Result<()> {
+        let threads = block_dim.x as u64 * block_dim.y as u64 * block_dim.z as u64;
+
+        if let Some(bounds) = self.bounds
+            && threads > bounds.max_threads_per_block as u64
+        {
+            return Err(Error::with_context(
+                ffi::hipError_t_hipErrorInvalidConfiguration,
+                format!(
+                    "block of {}x{}x{} = {} threads exceeds the kernel's launch bound of {} threads per block",
+                    block_dim.x, block_dim.y, block_dim.z, threads, bounds.max_threads_per_block
+                ),
+            ));
         }
 
-        Ok(Self { function })
+        if let Some(max_shared) = self.max_dynamic_shared_mem
+            && shared_mem_bytes > max_shared
+        {
+            return Err(Error::with_context(
+                ffi::hipError_t_hipErrorInvalidConfiguration,
+                format!(
+                    "{} bytes of dynamic shared memory exceeds the kernel's limit of {} bytes",
+                    shared_mem_bytes, max_shared
+                ),
+            ));
+        }
+
+        Ok(())
     }
 
     /// Launch the kernel with the given parameters
+    ///
+    /// Returns `hipErrorInvalidConfiguration` with a description of the
+    /// violated limit if the block size or shared memory exceeds the
+    /// kernel's launch bounds.
     pub fn launch(
         &self,
         grid_dim: Dim3,
@@ -39,9 +117,11 @@ impl Function {
         stream: Option<&Stream>,
         kernel_params: &mut [*mut c_void],
     ) -> Result<()> {
+        self.validate_launch(block_dim, shared_mem_bytes)?;
+
         let stream_ptr = match stream {
             Some(s) => s.as_raw(),
-            None => ptr::null_mut(),
+            None => default_stream_raw(),
         };
 
         let error = unsafe {
@@ -61,12 +141,42 @@ impl Function {
         };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipModuleLaunchKernel"));
         }
 
         Ok(())
     }
 
+    /// Enqueue an empty launch, exercising the launch path without running
+    /// any work
+    ///
+    /// Runtimes that reject empty grids report an invalid configuration or
+    /// value; that is expected and not treated as an error.
+    pub(crate) fn dry_launch(&self, stream: &Stream) -> Result<()> {
+        let error = unsafe {
+            ffi::hipModuleLaunchKernel(
+                self.function,
+                0,
+                0,
+                0,
+                1,
+                1,
+                1,
+                0,
+                stream.as_raw(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+            )
+        };
+
+        match error {
+            ffi::hipError_t_hipSuccess
+            | ffi::hipError_t_hipErrorInvalidConfiguration
+            | ffi::hipError_t_hipErrorInvalidValue => Ok(()),
+            _ => Err(Error::from_api(error, "hipModuleLaunchKernel")),
+        }
+    }
+
     /// Get the raw function handle
     pub fn as_raw(&self) -> ffi::hipFunction_t {
         self.function
@@ -74,7 +184,23 @@ impl Function {
 
     // Creates Function from raw function ponter
     pub unsafe fn from_raw(function: ffi::hipFunction_t) -> Self {
-        Self { function }
+        let max_threads = query_attribute(
+            function,
+            ffi::hipFunction_attribute_HIP_FUNC_ATTRIBUTE_MAX_THREADS_PER_BLOCK,
+        );
+        let max_dynamic_shared_mem = query_attribute(
+            function,
+            ffi::hipFunction_attribute_HIP_FUNC_ATTRIBUTE_MAX_DYNAMIC_SHARED_SIZE_BYTES,
+        );
+
+        Self {
+            function,
+            bounds: max_threads.map(|max_threads_per_block| LaunchBounds {
+                max_threads_per_block,
+                min_blocks_per_multiprocessor: None,
+            }),
+            max_dynamic_shared_mem,
+        }
     }
 }
 
@@ -0,0 +1,420 @@
+// src/hip/kernel_cache.rs
+//
+// On-disk cache of code objects built by the runtime kernel compiler
+
+use crate::error::{Error, Result, kernel_compilation_error};
+use crate::hip::device::Device;
+use std::env;
+use std::fs::{self, File};
+use std::io;
+use std::panic::Location;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::SystemTime;
+
+/// Environment variable overriding the default cache directory
+pub const KERNEL_CACHE_DIR_ENV: &str = "ROCM_RS_KERNEL_CACHE_DIR";
+
+/// Default maximum size in bytes of the cache
+pub const DEFAULT_KERNEL_CACHE_SIZE: u64 = 512 * 1024 * 1024;
+
+const ENTRY_EXTENSION: &str = "hsaco";
+
+/// Configuration of the kernel cache
+///
+/// [`compile_and_load`](crate::hip::compile_and_load) looks up code objects
+/// here before invoking `hipcc`, so kernels are only built once per machine
+/// rather than once per process. Entries are keyed by the source, the
+/// options, the `hipcc` version and the target architecture, so upgrading
+/// ROCm or swapping GPUs never loads a stale code object.
+///
+/// Only `hipcc` builds go through the cache; code objects loaded with
+/// [`Module::load`](crate::hip::Module::load) or
+/// [`Module::load_data`](crate::hip::Module::load_data) are used as given.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KernelCacheConfig {
+    /// Whether compiled code objects are cached (enabled by default)
+    pub enabled: bool,
+    /// Directory holding the cached code objects
+    pub directory: PathBuf,
+    /// Least recently used entries are evicted beyond this many bytes
+    pub max_size: u64,
+}
+
+impl Default for KernelCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            directory: default_directory(),
+            max_size: DEFAULT_KERNEL_CACHE_SIZE,
+        }
+    }
+}
+
+/// Pick the cache directory from the environment
+///
+/// Uses `ROCM_RS_KERNEL_CACHE_DIR` if set, then the XDG cache directory,
+/// falling back to the system temporary directory.
+fn default_directory() -> PathBuf {
+    if let Some(dir) = env::var_os(KERNEL_CACHE_DIR_ENV) {
+        return PathBuf::from(dir);
+    }
+
+    let cache_home = env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")));
+
+    match cache_home {
+        Some(dir) => dir.join("rocm-rs").join("kernels"),
+        None => env::temp_dir().join("rocm-rs-kernels"),
+    }
+}
+
+/// Counters and current contents of the kernel cache
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KernelCacheStats {
+    /// Lookups served from the cache by this process
+    pub hits: u64,
+    /// Lookups that had to compile
+    pub misses: u64,
+    /// Entries removed to stay under the size limit
+    pub evictions: u64,
+    /// Number of code objects in the cache directory
+    pub entries: usize,
+    /// Total size in bytes of the cached code objects
+    pub size: u64,
+}
+
+static CONFIG: OnceLock<Mutex<KernelCacheConfig>> = OnceLock::new();
+static HITS: AtomicU64 = AtomicU64::new(0);
+static MISSES: AtomicU64 = AtomicU64::new(0);
+static EVICTIONS: AtomicU64 = AtomicU64::new(0);
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+fn config_lock() -> &'static Mutex<KernelCacheConfig> {
+    CONFIG.get_or_init(|| Mutex::new(KernelCacheConfig::default()))
+}
+
+/// Set the kernel cache configuration
+///
+/// If the new limit is smaller, entries are evicted on the next insertion.
+pub fn set_kernel_cache_config(config: KernelCacheConfig) {
+    *config_lock().lock().unwrap() = config;
+}
+
+/// Get the current kernel cache configuration
+pub fn kernel_cache_config() -> KernelCacheConfig {
+    config_lock().lock().unwrap().clone()
+}
+
+/// Get the cache counters and the size of the cache directory
+pub fn kernel_cache_stats() -> KernelCacheStats {
+    let config = kernel_cache_config();
+    let entries = list_entries(&config.directory);
+
+    KernelCacheStats {
+        hits: HITS.load(Ordering::Relaxed),
+        misses: MISSES.load(Ordering::Relaxed),
+        evictions: EVICTIONS.load(Ordering::Relaxed),
+        entries: entries.len(),
+        size: entries.iter().map(|entry| entry.size).sum(),
+    }
+}
+
+/// Remove every code object from the cache directory
+pub fn clear_kernel_cache() -> Result<()> {
+    let config = kernel_cache_config();
+    for entry in list_entries(&config.directory) {
+        fs::remove_file(&entry.path).map_err(|e| io_error("removing", &entry.path, e))?;
+    }
+    Ok(())
+}
+
+/// Compile `source` into the cache without loading it
+///
+/// This needs `hipcc` but no GPU, so build scripts and deployment tooling
+/// can populate the cache ahead of time. Pass `--offload-arch=gfxXXX` in
+/// `options` to target a GPU other than the local one; the same options
+/// must then be given to `compile_and_load`. The cache directory is used
+/// even if caching is disabled. Returns the path of the code object.
+pub fn precompile(source: &str, options: &[String]) -> Result<PathBuf> {
+    let config = kernel_cache_config();
+    lookup_or_compile(&config, source, options)
+}
+
+/// Find the cached code object for `source`, compiling it on a miss
+pub(crate) fn lookup_or_compile(
+    config: &KernelCacheConfig,
+    source: &str,
+    options: &[String],
+) -> Result<PathBuf> {
+    let path = config.directory.join(format!(
+        "{:016x}.{}",
+        entry_key(source, options),
+        ENTRY_EXTENSION
+    ));
+
+    if path.is_file() {
+        HITS.fetch_add(1, Ordering::Relaxed);
+        // The modification time orders entries for eviction
+        if let Ok(file) = File::options().write(true).open(&path) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+        return Ok(path);
+    }
+
+    MISSES.fetch_add(1, Ordering::Relaxed);
+    fs::create_dir_all(&config.directory)
+        .map_err(|e| io_error("creating", &config.directory, e))?;
+    compile(source, options, &path)?;
+    evict(&config.directory, config.max_size, &path);
+    Ok(path)
+}
+
+/// Compile `source` to a unique file in the temporary directory
+pub(crate) fn compile_temporary(source: &str, options: &[String]) -> Result<PathBuf> {
+    let path = env::temp_dir().join(format!("{}.{}", temp_stem(), ENTRY_EXTENSION));
+    compile(source, options, &path)?;
+    Ok(path)
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// Continue a 64-bit FNV-1a hash over `bytes`
+///
+/// The hash must be stable across processes and Rust versions, which rules
+/// out the standard library hasher.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Hash a code object or other binary blob
+pub(crate) fn hash_bytes(bytes: &[u8]) -> u64 {
+    fnv1a(FNV_OFFSET, bytes)
+}
+
+/// Hash the source and compiler options
+pub(crate) fn cache_key(source: &str, options: &[String]) -> u64 {
+    options
+        .iter()
+        .fold(hash_bytes(source.as_bytes()), |hash, option| {
+            // Separate options so ["-a", "b"] and ["-ab"] differ
+            fnv1a(fnv1a(hash, &[0]), option.as_bytes())
+        })
+}
+
+/// Hash everything that determines the code object `hipcc` builds
+///
+/// Unless the options pick a target, `hipcc` builds for the local GPU, so
+/// the current device's architecture is part of the key.
+fn entry_key(source: &str, options: &[String]) -> u64 {
+    let hash = fnv1a(
+        fnv1a(cache_key(source, options), &[0]),
+        compiler_version().as_bytes(),
+    );
+    if options.iter().any(|opt| opt.starts_with("--offload-arch")) {
+        hash
+    } else {
+        fnv1a(fnv1a(hash, &[0]), local_arch().as_bytes())
+    }
+}
+
+/// The `hipcc --version` banner, naming the HIP and clang releases
+///
+/// Empty if `hipcc` can't be run, in which case compiling fails anyway.
+fn compiler_version() -> &'static str {
+    static VERSION: OnceLock<String> = OnceLock::new();
+    VERSION.get_or_init(|| {
+        Command::new("hipcc")
+            .arg("--version")
+            .output()
+            .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+            .unwrap_or_default()
+    })
+}
+
+/// Full gfx architecture name of the current device, e.g.
+/// `gfx90a:sramecc+:xnack-`, or empty without a usable device
+fn local_arch() -> String {
+    Device::current()
+        .and_then(|device| device.properties())
+        .map(|properties| properties.gcn_arch_name)
+        .unwrap_or_default()
+}
+
+fn temp_stem() -> String {
+    format!(
+        "rocm-rs-{}-{}",
+        std::process::id(),
+        TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Run `hipcc` on `source`, writing the code object to `output`
+///
+/// The object is built under a unique name and renamed into place, so
+/// concurrent compilations never see a partially written file.
+fn compile(source: &str, options: &[String], output: &Path) -> Result<()> {
+    let dir = output.parent().unwrap_or(Path::new("."));
+    let stem = temp_stem();
+    let src_path = dir.join(format!("{}.cpp", stem));
+    let bin_path = dir.join(format!("{}.tmp", stem));
+
+    fs::write(&src_path, source).map_err(|e| io_error("writing", &src_path, e))?;
+
+    let result = Command::new("hipcc")
+        .arg("--genco")
+        .args(options)
+        .arg("-o")
+        .arg(&bin_path)
+        .arg(&src_path)
+        .output();
+    let _ = fs::remove_file(&src_path);
+
+    let output_status = result.map_err(|e| io_error("running", Path::new("hipcc"), e))?;
+    if !output_status.status.success() {
+        let _ = fs::remove_file(&bin_path);
+        return Err(kernel_compilation_error(format!(
+            "hipcc exited with {}: {}",
+            output_status.status,
+            String::from_utf8_lossy(&output_status.stderr).trim()
+        )));
+    }
+
+    fs::rename(&bin_path, output).map_err(|e| {
+        let _ = fs::remove_file(&bin_path);
+        io_error("writing", output, e)
+    })
+}
+
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    path: PathBuf,
+    size: u64,
+    modified: SystemTime,
+}
+
+fn list_entries(dir: &Path) -> Vec<CacheEntry> {
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    read_dir
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != ENTRY_EXTENSION {
+                return None;
+            }
+            let metadata = fs::metadata(&path).ok()?;
+            Some(CacheEntry {
+                size: metadata.len(),
+                modified: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+                path,
+            })
+        })
+        .collect()
+}
+
+/// Remove least recently used entries until the cache fits in `max_size`
+///
+/// `keep` is never removed, even if it alone exceeds the limit.
+fn evict(dir: &Path, max_size: u64, keep: &Path) {
+    for path in select_evictions(list_entries(dir), max_size, keep) {
+        if fs::remove_file(&path).is_ok() {
+            EVICTIONS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+fn select_evictions(mut entries: Vec<CacheEntry>, max_size: u64, keep: &Path) -> Vec<PathBuf> {
+    let mut total: u64 = entries.iter().map(|entry| entry.size).sum();
+    entries.sort_by_key(|entry| entry.modified);
+
+    let mut evicted = Vec::new();
+    for entry in entries {
+        if total <= max_size {
+            break;
+        }
+        if entry.path != keep {
+            total -= entry.size;
+            evicted.push(entry.path);
+        }
+    }
+    evicted
+}
+
+/// Wrap an I/O error with what was being done to which path
+#[track_caller]
+pub(crate) fn io_error(action: &str, path: &Path, error: io::Error) -> Error {
+    Error::Context {
+        message: format!("{} {}", action, path.display()),
+        source: Box::new(Error::Io(error)),
+        location: Location::caller(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn entry(name: &str, size: u64, age: u64) -> CacheEntry {
+        CacheEntry {
+            path: PathBuf::from(name),
+            size,
+            modified: SystemTime::UNIX_EPOCH + Duration::from_secs(1000 - age),
+        }
+    }
+
+    #[test]
+    fn test_cache_key_covers_options() {
+        let source = "__global__ void k() {}";
+        assert_eq!(cache_key(source, &[]), cache_key(source, &[]));
+        assert_ne!(
+            cache_key(source, &[]),
+            cache_key(source, &["-O3".to_string()])
+        );
+        assert_ne!(
+            cache_key(source, &["-a".to_string(), "b".to_string()]),
+            cache_key(source, &["-ab".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let entries = vec![
+            entry("new", 40, 1),
+            entry("old", 40, 30),
+            entry("mid", 40, 10),
+        ];
+        let evicted = select_evictions(entries, 80, Path::new("new"));
+        assert_eq!(evicted, vec![PathBuf::from("old")]);
+    }
+
+    #[test]
+    fn test_keeps_new_entry() {
+        let entries = vec![entry("new", 100, 50), entry("old", 10, 1)];
+        let evicted = select_evictions(entries, 50, Path::new("new"));
+        assert_eq!(evicted, vec![PathBuf::from("old")]);
+    }
+
+    #[test]
+    fn test_io_error_keeps_path_and_source() {
+        let error = io_error(
+            "writing",
+            Path::new("/cache/k.cpp"),
+            io::Error::from(io::ErrorKind::PermissionDenied),
+        );
+        assert_eq!(error.kind(), crate::error::ErrorKind::Io);
+        assert!(
+            error
+                .to_string()
+                .starts_with("writing /cache/k.cpp: I/O error")
+        );
+        assert!(std::error::Error::source(&error).is_some());
+    }
+}
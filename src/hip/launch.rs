@@ -0,0 +1,300 @@
+// src/hip/launch.rs
+//
+// Typed kernel launch builder with borrow-checked buffer access
+
+use crate::hip::error::{Error, Result};
+use crate::hip::kernel::{AsKernelArg, Function};
+use crate::hip::memory::{DeviceMut, DeviceRef, KernelArg};
+use crate::hip::stream::default_stream_raw;
+use crate::hip::utils::Dim3;
+use crate::hip::{Stream, ffi};
+use std::cell::RefCell;
+use std::marker::PhantomData;
+
+/// Builder for a kernel launch whose buffer arguments are borrowed
+///
+/// Inputs are passed as [`DeviceRef`] and outputs as [`DeviceMut`], so the
+/// borrow checker rejects a launch that passes the same buffer both as an
+/// output and as another argument. [`launch`](Self::launch) holds the
+/// borrows until the kernel has completed; [`LaunchScope::launch`] holds
+/// them until the end of the scope.
+pub struct LaunchBuilder<'a> {
+    function: &'a Function,
+    grid_dim: Dim3,
+    block_dim: Dim3,
+    shared_mem_bytes: u32,
+    stream: Option<&'a Stream>,
+    args: Vec<KernelArg>,
+    _borrows: PhantomData<&'a mut ()>,
+}
+
+impl<'a> LaunchBuilder<'a> {
+    /// Create a builder launching a single thread with no arguments
+    pub fn new(function: &'a Function) -> Self {
+        Self {
+            function,
+            grid_dim: Dim3::new_1d(1),
+            block_dim: Dim3::new_1d(1),
+            shared_mem_bytes: 0,
+            stream: None,
+            args: Vec::new(),
+            _borrows: PhantomData,
+        }
+    }
+
+    /// Set the grid dimensions
+    pub fn grid(mut self, grid_dim: Dim3) -> Self {
+        self.grid_dim = grid_dim;
+        self
+    }
+
+    /// Set the block dimensions
+    pub fn block(mut self, block_dim: Dim3) -> Self {
+        self.block_dim = block_dim;
+        self
+    }
+
+    /// Set the dynamic shared memory size in bytes
+    pub fn shared_mem(mut self, bytes: u32) -> Self {
+        self.shared_mem_bytes = bytes;
+        self
+    }
+
+    /// Launch on the given stream instead of the default stream
+    pub fn stream(mut self, stream: &'a Stream) -> Self {
+        self.stream = Some(stream);
+        self
+    }
+
+    /// Append a scalar argument
+    pub fn arg<A: AsKernelArg>(mut self, value: &'a A) -> Self {
+        self.args.push(value.as_kernel_arg());
+        self
+    }
+
+    /// Append a buffer the kernel only reads from
+    pub fn input<T>(mut self, buffer: DeviceRef<'a, T>) -> Self {
+        self.args.push(buffer.as_kernel_arg());
+        self
+    }
+
+    /// Append a buffer the kernel writes to
+    pub fn output<T>(mut self, buffer: DeviceMut<'a, T>) -> Self {
+        self.args.push(buffer.as_kernel_arg());
+        self
+    }
+
+    /// Launch the kernel and wait for it to complete
+    ///
+    /// Use [`launch_scope`] to queue launches without waiting for each.
+    pub fn launch(self) -> Result<()> {
+        let stream = self.queue()?;
+        wait(stream)
+    }
+
+    /// Queue the kernel, returning the stream it was queued on
+    fn queue(mut self) -> Result<Option<&'a Stream>> {
+        self.function.launch(
+            self.grid_dim,
+            self.block_dim,
+            self.shared_mem_bytes,
+            self.stream,
+            &mut self.args,
+        )?;
+        Ok(self.stream)
+    }
+}
+
+/// A scope for kernel launches that run asynchronously
+///
+/// Created by [`launch_scope`]. Launches queued through it may still be
+/// running when [`launch`](Self::launch) returns; their argument borrows
+/// last until the scope ends, which waits for every stream used.
+pub struct LaunchScope<'scope, 'env: 'scope> {
+    streams: RefCell<Vec<Option<&'scope Stream>>>,
+    // Invariant in 'scope, so borrows can't be shortened to less than the
+    // whole scope
+    scope: PhantomData<&'scope mut &'scope ()>,
+    env: PhantomData<&'env mut &'env ()>,
+}
+
+impl<'scope> LaunchScope<'scope, '_> {
+    /// Queue a launch without waiting for it to complete
+    pub fn launch(&'scope self, builder: LaunchBuilder<'scope>) -> Result<()> {
+        let stream = builder.queue()?;
+
+        let mut streams = self.streams.borrow_mut();
+        let raw = stream.map(Stream::as_raw);
+        if !streams.iter().any(|s| s.map(Stream::as_raw) == raw) {
+            streams.push(stream);
+        }
+        Ok(())
+    }
+
+    /// Wait for every stream a launch was queued on
+    fn wait_all(&self) -> Result<()> {
+        let streams = std::mem::take(&mut *self.streams.borrow_mut());
+        let mut result = Ok(());
+        for stream in streams {
+            let waited = wait(stream);
+            if result.is_ok() {
+                result = waited;
+            }
+        }
+        result
+    }
+}
+
+// Waits if the scope's closure panics, so no borrow ends while a kernel
+// may still be using it
+struct ScopeGuard<'a, 'scope, 'env>(&'a LaunchScope<'scope, 'env>);
+
+impl Drop for ScopeGuard<'_, '_, '_> {
+    fn drop(&mut self) {
+        // We cannot handle errors in drop, so just ignore the result
+        let _ = self.0.wait_all();
+    }
+}
+
+/// Run `f` with a scope for asynchronous launches, then wait for them
+///
+/// Buffers borrowed by launches queued through the scope stay borrowed
+/// until every stream used has been synchronized, even if `f` fails or
+/// panics. This is what makes the borrows sound: unlike a returned guard,
+/// the wait can't be skipped by leaking anything.
+///
+/// ```no_run
+/// use rocm_rs::hip::{DeviceMemory, Function, Stream, launch_scope};
+///
+/// # fn run(fill: &Function) -> rocm_rs::hip::Result<()> {
+/// let (stream_a, stream_b) = (Stream::new()?, Stream::new()?);
+/// let mut a = DeviceMemory::<f32>::new(1)?;
+/// let mut b = DeviceMemory::<f32>::new(1)?;
+///
+/// // Both kernels run concurrently; the scope waits for them
+/// launch_scope(|scope| {
+///     scope.launch(fill.builder().stream(&stream_a).output(a.as_device_mut()))?;
+///     scope.launch(fill.builder().stream(&stream_b).output(b.as_device_mut()))
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn launch_scope<'env, R>(
+    f: impl for<'scope> FnOnce(&'scope LaunchScope<'scope, 'env>) -> Result<R>,
+) -> Result<R> {
+    let scope = LaunchScope {
+        streams: RefCell::new(Vec::new()),
+        scope: PhantomData,
+        env: PhantomData,
+    };
+    let guard = ScopeGuard(&scope);
+
+    let result = f(&scope);
+    let waited = scope.wait_all();
+    drop(guard);
+
+    let value = result?;
+    waited?;
+    Ok(value)
+}
+
+fn wait(stream: Option<&Stream>) -> Result<()> {
+    match stream {
+        Some(stream) => stream.synchronize(),
+        None => {
+            let error = unsafe { ffi::hipStreamSynchronize(default_stream_raw()) };
+            Error::from_hip_error(error)
+        }
+    }
+}
+
+impl Function {
+    /// Start building a launch of this kernel with borrow-checked arguments
+    pub fn builder(&self) -> LaunchBuilder<'_> {
+        LaunchBuilder::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Result;
+    use crate::hip::{DeviceMemory, Dim3, Stream, compile_and_load, launch_scope};
+
+    const SCALE_SOURCE: &str = r#"
+#include <hip/hip_runtime.h>
+
+extern "C" __global__ void scale(const float* x, float* y, float a, unsigned n) {
+    unsigned i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i < n) {
+        y[i] = a * x[i];
+    }
+}
+"#;
+
+    #[test]
+    fn test_builder_launch() -> Result<()> {
+        let module = compile_and_load(SCALE_SOURCE, &[])?;
+        let function = module.get_function("scale")?;
+        let stream = Stream::new()?;
+
+        let input: Vec<f32> = (0..300).map(|i| i as f32).collect();
+        let mut x = DeviceMemory::new(input.len())?;
+        x.copy_from_host(&input)?;
+        let mut y = DeviceMemory::<f32>::new(input.len())?;
+
+        let a = 2.0f32;
+        let n = input.len() as u32;
+        function
+            .builder()
+            .grid(Dim3::new_1d(n.div_ceil(128)))
+            .block(Dim3::new_1d(128))
+            .stream(&stream)
+            .input(x.as_device_ref())
+            .output(y.as_device_mut())
+            .arg(&a)
+            .arg(&n)
+            .launch()?;
+
+        let mut output = vec![0.0f32; input.len()];
+        y.copy_to_host(&mut output)?;
+        assert!(output.iter().zip(&input).all(|(y, x)| *y == 2.0 * x));
+        Ok(())
+    }
+
+    #[test]
+    fn test_scoped_launches() -> Result<()> {
+        let module = compile_and_load(SCALE_SOURCE, &[])?;
+        let function = module.get_function("scale")?;
+        let (stream_a, stream_b) = (Stream::new()?, Stream::new()?);
+
+        let input: Vec<f32> = (0..64).map(|i| i as f32).collect();
+        let mut x = DeviceMemory::new(input.len())?;
+        x.copy_from_host(&input)?;
+        let mut y = DeviceMemory::<f32>::new(input.len())?;
+        let mut z = DeviceMemory::<f32>::new(input.len())?;
+
+        let (two, three) = (2.0f32, 3.0f32);
+        let n = input.len() as u32;
+        launch_scope(|scope| {
+            let scaled = |stream, output, a| {
+                function
+                    .builder()
+                    .block(Dim3::new_1d(n))
+                    .stream(stream)
+                    .input(x.as_device_ref())
+                    .output(output)
+                    .arg(a)
+                    .arg(&n)
+            };
+            scope.launch(scaled(&stream_a, y.as_device_mut(), &two))?;
+            scope.launch(scaled(&stream_b, z.as_device_mut(), &three))
+        })?;
+
+        let mut output = vec![0.0f32; input.len()];
+        y.copy_to_host(&mut output)?;
+        assert!(output.iter().zip(&input).all(|(y, x)| *y == 2.0 * x));
+        z.copy_to_host(&mut output)?;
+        assert!(output.iter().zip(&input).all(|(z, x)| *z == 3.0 * x));
+        Ok(())
+    }
+}
@@ -1,9 +1,10 @@
 // src/hip/memory.rs
 use crate::hip::error::{Error, Result};
 use crate::hip::kernel::AsKernelArg;
-use crate::hip::{Stream, ffi};
+use crate::hip::{Stream, ffi, labels, staging};
 use std::ffi::c_void;
 use std::marker::PhantomData;
+use std::ops::Range;
 use std::{mem, ptr};
 
 pub type KernelArg = *mut c_void;
@@ -21,11 +22,128 @@ pub fn memory_info() -> Result<MemoryInfo> {
     let mut total = 0;
     let error = unsafe { ffi::hipMemGetInfo(&mut free, &mut total) };
     if error != ffi::hipError_t_hipSuccess {
-        return Err(Error::new(error));
+        return Err(Error::from_api(error, "hipMemGetInfo"));
     }
     Ok(MemoryInfo { free, total })
 }
 
+/// Kind of memory a pointer refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryKind {
+    /// Memory unknown to HIP, such as ordinary pageable host memory
+    Unregistered,
+    /// Pinned or registered host memory
+    Host,
+    /// Device memory
+    Device,
+    /// Managed memory migrated between host and device on demand
+    Managed,
+}
+
+/// What HIP knows about a pointer, as reported by [`pointer_attributes`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PointerAttributes {
+    /// Kind of memory the pointer refers to
+    pub kind: MemoryKind,
+    /// Device owning the allocation, if any
+    pub device: Option<i32>,
+    /// Address usable by kernels, or null if there is none
+    pub device_ptr: *mut c_void,
+    /// Address usable by the host, or null if there is none
+    pub host_ptr: *mut c_void,
+    /// Address range of the whole allocation containing the pointer, if known
+    pub allocation: Option<Range<usize>>,
+}
+
+impl PointerAttributes {
+    /// Whether `size` bytes starting at `ptr` lie within the allocation
+    pub fn contains(&self, ptr: *const c_void, size: usize) -> bool {
+        let start = ptr as usize;
+        match (&self.allocation, start.checked_add(size)) {
+            (Some(range), Some(end)) => range.start <= start && end <= range.end,
+            _ => false,
+        }
+    }
+}
+
+/// Query what kind of memory `ptr` points to and which allocation holds it
+///
+/// This helps validate pointers handed over by other libraries before
+/// wrapping them. Pointers HIP knows nothing about are reported as
+/// [`MemoryKind::Unregistered`] rather than as an error.
+pub fn pointer_attributes<T>(ptr: *const T) -> Result<PointerAttributes> {
+    let mut attributes: ffi::hipPointerAttribute_t = unsafe { mem::zeroed() };
+    let error = unsafe { ffi::hipPointerGetAttributes(&mut attributes, ptr as *const c_void) };
+
+    // Older runtimes fail on pointers they don't track instead of reporting
+    // them as unregistered
+    if error == ffi::hipError_t_hipErrorInvalidValue && !ptr.is_null() {
+        return Ok(PointerAttributes {
+            kind: MemoryKind::Unregistered,
+            device: None,
+            device_ptr: ptr::null_mut(),
+            host_ptr: ptr as *mut c_void,
+            allocation: None,
+        });
+    }
+    if error != ffi::hipError_t_hipSuccess {
+        return Err(Error::from_api(error, "hipPointerGetAttributes"));
+    }
+
+    let kind = if attributes.isManaged != 0 {
+        MemoryKind::Managed
+    } else {
+        match attributes.type_ {
+            ffi::hipMemoryType_hipMemoryTypeHost => MemoryKind::Host,
+            ffi::hipMemoryType_hipMemoryTypeDevice | ffi::hipMemoryType_hipMemoryTypeArray => {
+                MemoryKind::Device
+            }
+            ffi::hipMemoryType_hipMemoryTypeManaged | ffi::hipMemoryType_hipMemoryTypeUnified => {
+                MemoryKind::Managed
+            }
+            _ => MemoryKind::Unregistered,
+        }
+    };
+
+    if kind == MemoryKind::Unregistered {
+        return Ok(PointerAttributes {
+            kind,
+            device: None,
+            device_ptr: ptr::null_mut(),
+            host_ptr: ptr as *mut c_void,
+            allocation: None,
+        });
+    }
+
+    // The range is looked up through the device view of the allocation
+    let lookup = if attributes.devicePointer.is_null() {
+        ptr as *mut c_void
+    } else {
+        attributes.devicePointer
+    };
+    let mut base = ptr::null_mut();
+    let mut size = 0;
+    let error = unsafe { ffi::hipMemGetAddressRange(&mut base, &mut size, lookup) };
+    let allocation = (error == ffi::hipError_t_hipSuccess && !base.is_null()).then(|| {
+        // Report the range in the same address space as the queried pointer
+        let offset = if kind == MemoryKind::Host && !attributes.hostPointer.is_null() {
+            (attributes.hostPointer as usize).wrapping_sub(attributes.devicePointer as usize)
+        } else {
+            0
+        };
+        let start = (base as usize).wrapping_add(offset);
+        start..start + size
+    });
+
+    Ok(PointerAttributes {
+        kind,
+        device: (attributes.device >= 0).then_some(attributes.device),
+        device_ptr: attributes.devicePointer,
+        host_ptr: attributes.hostPointer,
+        allocation,
+    })
+}
+
 /// Safe wrapper for hip device memory
 pub struct DeviceMemory<T> {
     ptr: *mut c_void,
@@ -33,14 +151,40 @@ pub struct DeviceMemory<T> {
     phantom: PhantomData<T>,
 }
 
-#[derive(Clone)]
+/// Host buffer that is the destination of an in-flight device-to-host copy
+///
+/// Holds a handle to the stream the copy was issued on, so the stream cannot
+/// be destroyed while the copy is queued. Dropping a `PendingCopy` without
+/// synchronizing waits for the stream before the buffer is released.
 pub struct PendingCopy<T> {
     inner: Vec<T>,
+    stream: Option<Stream>,
 }
 
 impl<T> PendingCopy<T> {
-    pub fn synchronize(self) -> Vec<T> {
-        self.inner
+    /// Track `inner` as the destination of a copy just queued on `stream`
+    pub(crate) fn new(inner: Vec<T>, stream: &Stream) -> Self {
+        Self {
+            inner,
+            stream: Some(stream.clone()),
+        }
+    }
+
+    /// Wait for the copy to finish and return the host buffer
+    pub fn synchronize(mut self) -> Vec<T> {
+        if let Some(stream) = self.stream.take() {
+            let _ = stream.synchronize();
+        }
+        mem::take(&mut self.inner)
+    }
+}
+
+impl<T> Drop for PendingCopy<T> {
+    fn drop(&mut self) {
+        // The device may still be writing into `inner`
+        if let Some(stream) = self.stream.take() {
+            let _ = stream.synchronize();
+        }
     }
 }
 
@@ -53,8 +197,10 @@ pub trait SynchronizeCopies {
 impl<T> SynchronizeCopies for PendingCopy<T> {
     type Output = Vec<T>;
 
-    unsafe fn finalize(self) -> Self::Output {
-        self.synchronize()
+    unsafe fn finalize(mut self) -> Self::Output {
+        // The caller already synchronized the stream
+        self.stream = None;
+        mem::take(&mut self.inner)
     }
 }
 
@@ -66,7 +212,7 @@ where
 
     unsafe fn finalize(self) -> Self::Output {
         let (pending, rest) = self;
-        let vec = pending.synchronize();
+        let vec = unsafe { pending.finalize() };
         let rest_out = unsafe { rest.finalize() };
         (vec, rest_out)
     }
@@ -96,7 +242,7 @@ impl<T> DeviceMemory<T> {
         let error = unsafe { ffi::hipMalloc(&mut ptr, size) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipMalloc"));
         }
 
         Ok(Self {
@@ -106,6 +252,24 @@ impl<T> DeviceMemory<T> {
         })
     }
 
+    /// Allocate device memory for a number of elements and tag it with a label
+    ///
+    /// Labeled allocations are listed by `hip::labels::memory_usage_report`.
+    /// With the `roctx` feature the label is also emitted as a roctx marker
+    /// for the profiler trace.
+    pub fn with_label(count: usize, label: impl Into<String>) -> Result<Self> {
+        let memory = Self::new(count)?;
+        if !memory.ptr.is_null() {
+            labels::register_memory(memory.ptr, memory.size, label.into());
+        }
+        Ok(memory)
+    }
+
+    /// Get the label assigned to this allocation, if any
+    pub fn label(&self) -> Option<String> {
+        labels::memory_label(self.ptr)
+    }
+
     /// Get the device pointer
     pub fn as_ptr(&self) -> *mut c_void {
         self.ptr
@@ -121,6 +285,87 @@ impl<T> DeviceMemory<T> {
         self.size / size_of::<T>()
     }
 
+    /// Borrow this buffer as a read-only kernel input
+    pub fn as_device_ref(&self) -> DeviceRef<'_, T> {
+        DeviceRef { memory: self }
+    }
+
+    /// Borrow this buffer as a writable kernel output
+    pub fn as_device_mut(&mut self) -> DeviceMut<'_, T> {
+        DeviceMut { memory: self }
+    }
+
+    /// Grow the allocation by `additional` elements, preserving its contents
+    ///
+    /// The existing data is copied on the device; the new elements are
+    /// uninitialized.
+    pub fn reserve(&mut self, additional: usize) -> Result<()> {
+        let count = self
+            .count()
+            .checked_add(additional)
+            .ok_or(Error::new(ffi::hipError_t_hipErrorInvalidValue))?;
+        self.reallocate(count)
+    }
+
+    /// Resize the allocation to `len` elements
+    ///
+    /// The first `min(len, count())` elements are preserved and any new
+    /// elements are zeroed.
+    pub fn resize(&mut self, len: usize) -> Result<()> {
+        let old_size = self.size;
+        self.reallocate(len)?;
+
+        if self.size > old_size {
+            let error = unsafe {
+                ffi::hipMemset(
+                    (self.ptr as *mut u8).add(old_size) as *mut c_void,
+                    0,
+                    self.size - old_size,
+                )
+            };
+
+            if error != ffi::hipError_t_hipSuccess {
+                return Err(Error::from_api(error, "hipMemset"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Move the contents into a new allocation of `count` elements
+    fn reallocate(&mut self, count: usize) -> Result<()> {
+        if count == self.count() {
+            return Ok(());
+        }
+
+        let mut memory = DeviceMemory::<T>::new(count)?;
+        let copy_size = std::cmp::min(self.size, memory.size);
+        if copy_size > 0 {
+            let error = unsafe {
+                ffi::hipMemcpy(
+                    memory.ptr,
+                    self.ptr,
+                    copy_size,
+                    ffi::hipMemcpyKind_hipMemcpyDeviceToDevice,
+                )
+            };
+
+            if error != ffi::hipError_t_hipSuccess {
+                return Err(Error::from_api(error, "hipMemcpy"));
+            }
+        }
+
+        if !memory.ptr.is_null()
+            && let Some(label) = self.label()
+        {
+            labels::register_memory(memory.ptr, memory.size, label);
+        }
+
+        // The old allocation is freed (and unregistered) when `memory` drops
+        mem::swap(self, &mut memory);
+        Ok(())
+    }
+
     /// Copy data from host to device
     pub fn copy_from_host(&mut self, data: &[T]) -> Result<()> {
         if self.ptr.is_null() || data.is_empty() {
@@ -128,6 +373,12 @@ impl<T> DeviceMemory<T> {
         }
 
         let copy_size = std::cmp::min(self.size, data.len() * std::mem::size_of::<T>());
+        if staging::should_stage(copy_size) {
+            return unsafe {
+                staging::copy_to_device(self.ptr, data.as_ptr() as *const u8, copy_size)
+            };
+        }
+
         let error = unsafe {
             ffi::hipMemcpy(
                 self.ptr,
@@ -138,7 +389,7 @@ impl<T> DeviceMemory<T> {
         };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipMemcpy"));
         }
 
         Ok(())
@@ -151,6 +402,12 @@ impl<T> DeviceMemory<T> {
         }
 
         let copy_size = std::cmp::min(self.size, data.len() * std::mem::size_of::<T>());
+        if staging::should_stage(copy_size) {
+            return unsafe {
+                staging::copy_to_host(data.as_mut_ptr() as *mut u8, self.ptr, copy_size)
+            };
+        }
+
         let error = unsafe {
             ffi::hipMemcpy(
                 data.as_mut_ptr() as *mut c_void,
@@ -161,7 +418,7 @@ impl<T> DeviceMemory<T> {
         };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipMemcpy"));
         }
 
         Ok(())
@@ -184,7 +441,33 @@ impl<T> DeviceMemory<T> {
         };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipMemcpy"));
+        }
+
+        Ok(())
+    }
+
+    /// Asynchronously copy data from another device memory on `stream`
+    ///
+    /// Copies as many bytes as fit in the smaller of the two buffers.
+    pub fn copy_from_device_async(&mut self, src: &DeviceMemory<T>, stream: &Stream) -> Result<()> {
+        if self.ptr.is_null() || src.ptr.is_null() {
+            return Ok(());
+        }
+
+        let copy_size = std::cmp::min(self.size, src.size);
+        let error = unsafe {
+            ffi::hipMemcpyAsync(
+                self.ptr,
+                src.ptr,
+                copy_size,
+                ffi::hipMemcpyKind_hipMemcpyDeviceToDevice,
+                stream.as_raw(),
+            )
+        };
+
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipMemcpyAsync"));
         }
 
         Ok(())
@@ -199,7 +482,22 @@ impl<T> DeviceMemory<T> {
         let error = unsafe { ffi::hipMemset(self.ptr, value, self.size) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipMemset"));
+        }
+
+        Ok(())
+    }
+
+    /// Set memory to a value on `stream`
+    pub fn memset_async(&mut self, value: i32, stream: &Stream) -> Result<()> {
+        if self.ptr.is_null() {
+            return Ok(());
+        }
+
+        let error = unsafe { ffi::hipMemsetAsync(self.ptr, value, self.size, stream.as_raw()) };
+
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipMemsetAsync"));
         }
 
         Ok(())
@@ -239,8 +537,10 @@ impl<T> DeviceMemory<T> {
 
         // Check hipMemcpyAsync result
         if error != ffi::hipError_t_hipSuccess {
-            Err(Error::new(error)) // Assumes Error::new handles hipError_t
+            Err(Error::from_api(error, "hipMemcpyAsync"))
         } else {
+            // The copy may still be reading from `source`
+            stream.retain(source);
             Ok(())
         }
     }
@@ -270,7 +570,10 @@ impl<T> DeviceMemory<T> {
     ) -> Result<PendingCopy<T>> {
         // Check for empty destination or potentially uninitialized buffer early
         if dest.is_empty() {
-            return Ok(PendingCopy { inner: dest });
+            return Ok(PendingCopy {
+                inner: dest,
+                stream: None,
+            });
         }
         // Check if self.ptr is null if your struct allows for uninitialized state
         // if self.ptr.is_null() { return Err(/* Appropriate error */); }
@@ -284,7 +587,10 @@ impl<T> DeviceMemory<T> {
 
         // Only proceed with copy if there are bytes to copy (handles ZSTs correctly)
         if required_bytes == 0 {
-            return Ok(PendingCopy { inner: dest });
+            return Ok(PendingCopy {
+                inner: dest,
+                stream: None,
+            });
         }
 
         let error = unsafe {
@@ -299,16 +605,35 @@ impl<T> DeviceMemory<T> {
 
         // Check hipMemcpyAsync result
         if error != ffi::hipError_t_hipSuccess {
-            Err(Error::new(error)) // Assumes Error::new handles hipError_t
+            Err(Error::from_api(error, "hipMemcpyAsync"))
         } else {
-            Ok(PendingCopy { inner: dest })
+            Ok(PendingCopy {
+                inner: dest,
+                stream: Some(stream.clone()),
+            })
+        }
+    }
+
+    /// Take ownership of a device allocation made outside this type
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a device allocation of `size` bytes that is released
+    /// with `hipFree` and not owned by anything else.
+    pub(crate) unsafe fn from_raw_parts(ptr: *mut c_void, size: usize) -> Self {
+        Self {
+            ptr,
+            size,
+            phantom: PhantomData,
         }
     }
 
     pub unsafe fn cast<D>(self) -> DeviceMemory<D> {
+        // Ownership of the allocation (and its label) moves to the new buffer
+        let this = mem::ManuallyDrop::new(self);
         DeviceMemory::<D> {
-            ptr: self.ptr,
-            size: self.size,
+            ptr: this.ptr,
+            size: this.size,
             phantom: PhantomData::<D>,
         }
     }
@@ -320,9 +645,67 @@ impl<T> AsKernelArg for DeviceMemory<T> {
     }
 }
 
+/// Shared access to device memory for the duration of a kernel launch
+///
+/// Any number of `DeviceRef`s to the same buffer may coexist, but not
+/// alongside a [`DeviceMut`] to it.
+pub struct DeviceRef<'a, T> {
+    memory: &'a DeviceMemory<T>,
+}
+
+impl<T> DeviceRef<'_, T> {
+    /// Get the device pointer
+    pub fn as_ptr(&self) -> *const c_void {
+        self.memory.ptr
+    }
+
+    /// Get the number of elements
+    pub fn count(&self) -> usize {
+        self.memory.count()
+    }
+}
+
+impl<T> Clone for DeviceRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for DeviceRef<'_, T> {}
+
+impl<T> AsKernelArg for DeviceRef<'_, T> {
+    fn as_kernel_arg(&self) -> KernelArg {
+        self.memory.as_kernel_arg()
+    }
+}
+
+/// Exclusive access to device memory for the duration of a kernel launch
+pub struct DeviceMut<'a, T> {
+    memory: &'a mut DeviceMemory<T>,
+}
+
+impl<T> DeviceMut<'_, T> {
+    /// Get the device pointer
+    pub fn as_ptr(&self) -> *mut c_void {
+        self.memory.ptr
+    }
+
+    /// Get the number of elements
+    pub fn count(&self) -> usize {
+        self.memory.count()
+    }
+}
+
+impl<T> AsKernelArg for DeviceMut<'_, T> {
+    fn as_kernel_arg(&self) -> KernelArg {
+        self.memory.as_kernel_arg()
+    }
+}
+
 impl<T> Drop for DeviceMemory<T> {
     fn drop(&mut self) {
         if !self.ptr.is_null() {
+            labels::unregister_memory(self.ptr);
             unsafe {
                 let _ = ffi::hipFree(self.ptr);
                 // We cannot handle errors in drop, so just ignore the result
@@ -357,7 +740,7 @@ impl<T> PinnedMemory<T> {
         let error = unsafe { ffi::hipHostMalloc(&mut ptr, size, 0) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipHostMalloc"));
         }
 
         Ok(Self {
@@ -384,7 +767,7 @@ impl<T> PinnedMemory<T> {
         let error = unsafe { ffi::hipHostMalloc(&mut ptr, size, flags) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipHostMalloc"));
         }
 
         Ok(Self {
@@ -443,7 +826,7 @@ impl<T> PinnedMemory<T> {
         let error = unsafe { ffi::hipHostGetDevicePointer(&mut device_ptr, self.ptr, 0) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipHostGetDevicePointer"));
         }
 
         Ok(device_ptr)
@@ -0,0 +1,249 @@
+use crate::hip::kernel::AsKernelArg;
+use crate::hip::memory::KernelArg;
+use rocm_kernel_macros::{
+    amdgpu_device, amdgpu_global, amdgpu_kernel_finalize, amdgpu_kernel_init,
+};
+
+amdgpu_kernel_init!(path: __build_in_kernels_fill);
+
+use crate::{
+    hip::{
+        DeviceMemory, Dim3, ModuleCache, Stream, calculate_grid_1d, error::Error, error::Result,
+    },
+    kernel_args,
+};
+
+// Must match the block size assumed by fill_index
+const FILL_BLOCK_SIZE: u32 = 256;
+
+#[amdgpu_device(__build_in_kernels_fill)]
+fn fill_index() -> usize {
+    workgroup_id_x() as usize * 256 + workitem_id_x() as usize
+}
+
+#[amdgpu_device(__build_in_kernels_fill)]
+fn fill_inner<T: Clone + Copy>(arr: *mut T, value: T, size: usize) {
+    let index = fill_index();
+    if index < size {
+        unsafe { *arr.add(index) = value }
+    }
+}
+
+#[amdgpu_device(__build_in_kernels_fill)]
+fn strided_copy_inner<T: Clone + Copy>(
+    src: *const T,
+    dst: *mut T,
+    count: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) {
+    let index = fill_index();
+    if index < count {
+        unsafe { *dst.add(index * dst_stride) = *src.add(index * src_stride) }
+    }
+}
+
+macro_rules! fill_fns {
+    ($t:ty) => {
+        paste::paste! {
+            #[amdgpu_global(__build_in_kernels_fill)]
+            fn [<fill_$t>](arr: *mut $t, value: $t, size: usize) {
+                fill_inner::<$t>(arr, value, size)
+            }
+
+            #[amdgpu_global(__build_in_kernels_fill)]
+            fn [<iota_$t>](arr: *mut $t, start: $t, step: $t, size: usize) {
+                let index = fill_index();
+                if index < size {
+                    unsafe { *arr.add(index) = start + step * (index as $t) }
+                }
+            }
+
+            #[amdgpu_global(__build_in_kernels_fill)]
+            fn [<strided_copy_$t>](
+                src: *const $t,
+                dst: *mut $t,
+                count: usize,
+                src_stride: usize,
+                dst_stride: usize,
+            ) {
+                strided_copy_inner::<$t>(src, dst, count, src_stride, dst_stride)
+            }
+        }
+    };
+}
+
+/// Element types supported by the built-in fill kernels
+pub trait GPUFillAllowed: AsKernelArg + Copy {}
+
+macro_rules! impl_gpu_fill_allowed {
+    ($($t:ty),+) => {
+        $(
+            impl GPUFillAllowed for $t {}
+            fill_fns!($t);
+        )*
+    };
+}
+
+impl_gpu_fill_allowed!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+
+pub(crate) const FILL_KERNEL: &[u8] =
+    include_bytes!(amdgpu_kernel_finalize!(__build_in_kernels_fill));
+
+fn launch_1d<T>(name: &str, count: usize, stream: &Stream, args: &mut [KernelArg]) -> Result<()> {
+    if count == 0 {
+        return Ok(());
+    }
+
+    let module = ModuleCache::global().get_or_load_data(FILL_KERNEL)?;
+    let function = module.get_function(&format!("{}_{}", name, std::any::type_name::<T>()))?;
+
+    function.launch(
+        calculate_grid_1d(count as u32, FILL_BLOCK_SIZE),
+        Dim3::new_1d(FILL_BLOCK_SIZE),
+        0,
+        Some(stream),
+        args,
+    )
+}
+
+/// Check that `count` elements `stride` apart fit in `len` elements
+fn check_strided(len: usize, count: usize, stride: usize, what: &str) -> Result<()> {
+    if count == 0 {
+        return Ok(());
+    }
+
+    let last = (count - 1).checked_mul(stride);
+    if last.is_none_or(|last| last >= len) {
+        return Err(Error::with_context(
+            crate::hip::ffi::hipError_t_hipErrorInvalidValue,
+            format!(
+                "{} elements with stride {} overrun the {} element {}",
+                count, stride, len, what
+            ),
+        ));
+    }
+    Ok(())
+}
+
+impl<T: GPUFillAllowed> DeviceMemory<T> {
+    /// Allocate `count` elements set to `value` without a host upload
+    pub fn filled(count: usize, value: T) -> Result<Self> {
+        let mut memory = Self::new(count)?;
+        memory.fill(value)?;
+        Ok(memory)
+    }
+
+    /// Allocate `count` elements holding `start`, `start + step`, ...
+    pub fn iota(count: usize, start: T, step: T) -> Result<Self> {
+        let memory = Self::new(count)?;
+        let stream = Stream::new()?;
+        launch_1d::<T>(
+            "iota",
+            count,
+            &stream,
+            kernel_args!(memory, start, step, count),
+        )?;
+        stream.synchronize()?;
+        Ok(memory)
+    }
+
+    /// Set every element to `value`
+    pub fn fill(&mut self, value: T) -> Result<()> {
+        let stream = Stream::new()?;
+        self.fill_async(value, &stream)?;
+        stream.synchronize()
+    }
+
+    /// Set every element to `value` on `stream`
+    pub fn fill_async(&mut self, value: T, stream: &Stream) -> Result<()> {
+        let count = self.count();
+        launch_1d::<T>("fill", count, stream, kernel_args!(self, value, count))
+    }
+
+    /// Copy `count` elements `src_stride` apart in `src` to elements
+    /// `dst_stride` apart in this buffer
+    ///
+    /// Gathers a column of a row-major matrix with `src_stride` equal to
+    /// the row length and `dst_stride` of 1, for example.
+    pub fn strided_copy(
+        &mut self,
+        src: &DeviceMemory<T>,
+        count: usize,
+        src_stride: usize,
+        dst_stride: usize,
+    ) -> Result<()> {
+        let stream = Stream::new()?;
+        self.strided_copy_async(src, count, src_stride, dst_stride, &stream)?;
+        stream.synchronize()
+    }
+
+    /// Strided copy on `stream`, see [`DeviceMemory::strided_copy`]
+    pub fn strided_copy_async(
+        &mut self,
+        src: &DeviceMemory<T>,
+        count: usize,
+        src_stride: usize,
+        dst_stride: usize,
+        stream: &Stream,
+    ) -> Result<()> {
+        check_strided(src.count(), count, src_stride, "source")?;
+        check_strided(self.count(), count, dst_stride, "destination")?;
+
+        launch_1d::<T>(
+            "strided_copy",
+            count,
+            stream,
+            kernel_args!(src, self, count, src_stride, dst_stride),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{error::Result, hip::DeviceMemory};
+
+    #[test]
+    fn filled() -> Result<()> {
+        let mem = DeviceMemory::filled(1000, 3.5f32)?;
+
+        let mut host = vec![0.0f32; 1000];
+        mem.copy_to_host(&mut host)?;
+
+        assert!(host.iter().all(|&x| x == 3.5));
+        Ok(())
+    }
+
+    #[test]
+    fn iota() -> Result<()> {
+        let mem = DeviceMemory::iota(300, 10i32, 2)?;
+
+        let mut host = vec![0i32; 300];
+        mem.copy_to_host(&mut host)?;
+
+        assert!(
+            host.iter()
+                .enumerate()
+                .all(|(i, &x)| x == 10 + 2 * i as i32)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn strided_copy_column() -> Result<()> {
+        // 3x4 row-major matrix, copy out the first column
+        let matrix: Vec<u32> = (0..12).collect();
+        let mut src = DeviceMemory::new(matrix.len())?;
+        src.copy_from_host(&matrix)?;
+
+        let mut column = DeviceMemory::<u32>::filled(3, 0)?;
+        column.strided_copy(&src, 3, 4, 1)?;
+
+        let mut host = vec![0u32; 3];
+        column.copy_to_host(&mut host)?;
+        assert_eq!(host, vec![0, 4, 8]);
+
+        assert!(column.strided_copy(&src, 4, 4, 1).is_err());
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+pub mod fill;
 pub mod sorting;
 
 use crate::hip::memory_ext::sorting::GPUSortAllowed;
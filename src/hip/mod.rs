@@ -1,12 +1,28 @@
 // src/hip/mod.rs
 
 // Private modules
+pub mod arch;
 pub mod device;
+pub(crate) mod device_modules;
+pub mod device_vec;
+pub mod diagnostics;
 pub mod error;
 pub mod event;
+pub mod external;
+pub mod gl_interop;
+pub mod graph;
 pub mod kernel;
+pub mod kernel_cache;
+pub mod labels;
+pub mod launch;
 pub mod memory;
 pub mod module;
+pub mod module_cache;
+pub mod multi;
+#[cfg(feature = "allocator-api2")]
+pub mod pinned_alloc;
+pub mod pool;
+pub mod staging;
 pub mod stream;
 pub mod utils;
 
@@ -21,13 +37,33 @@ pub mod ffi;
 pub mod memory_ext;
 
 // Re-export the main components for the public API
-pub use device::{Device, DeviceProperties, get_device_count, get_device_properties};
+pub use arch::{DeviceArch, Feature};
+pub use device::{Device, DeviceProperties, Limit, get_device_count, get_device_properties};
+pub use device_vec::DeviceVec;
+pub use diagnostics::{Diagnostic, check_environment, diagnose};
 pub use error::{Error, Result};
 pub use event::{Event, Timer, event_flags};
-pub use kernel::{Function, stream_to_rocrand};
-pub use memory::{DeviceMemory, MemoryInfo, PinnedMemory, memory_info};
-pub use module::{Module, compile_and_load, load_module, load_module_data};
-pub use stream::{Stream, stream_flags};
+pub use external::{ExternalBuffer, ExternalMemory, ExternalSemaphore, SemaphoreKind};
+pub use gl_interop::{GlBuffer, MappedGlBuffer, graphics_register_flags};
+pub use graph::{CaptureMode, Graph, GraphExec};
+pub use kernel::{Function, LaunchBounds, stream_to_rocrand};
+pub use kernel_cache::{
+    KernelCacheConfig, KernelCacheStats, clear_kernel_cache, kernel_cache_config,
+    kernel_cache_stats, precompile, set_kernel_cache_config,
+};
+pub use labels::{LabeledAllocation, labeled_allocations, memory_usage_report};
+pub use launch::{LaunchBuilder, LaunchScope, launch_scope};
+pub use memory::{
+    DeviceMemory, DeviceMut, DeviceRef, MemoryInfo, MemoryKind, PinnedMemory, PointerAttributes,
+    memory_info, pointer_attributes,
+};
+pub use module::{Module, WarmupOptions, compile_and_load, load_module, load_module_data};
+pub use module_cache::{ModuleCache, ModuleKey};
+#[cfg(feature = "allocator-api2")]
+pub use pinned_alloc::{PendingPinnedCopy, PinnedAllocator, PinnedVec};
+pub use pool::{CompactionReport, DevicePool, PoolHandle, PoolStats};
+pub use staging::{StagingConfig, set_staging_config, staging_config};
+pub use stream::{Stream, per_thread_default_stream, set_per_thread_default_stream, stream_flags};
 pub use utils::{
      Dim3, Version, calculate_grid_1d, calculate_grid_2d, calculate_grid_3d, is_hip_available, print_devices_info,
 };
@@ -38,9 +74,15 @@ pub fn device_count() -> Result<i32> {
 }
 
 /// Initialize the HIP runtime
+///
+/// If initialization fails because of a setup problem such as missing
+/// device permissions, the error's context says how to fix it.
 pub fn init() -> Result<()> {
     let error = unsafe { ffi::hipInit(0) };
-    Error::from_hip_error(error)
+    if error != ffi::hipError_t_hipSuccess {
+        return Err(diagnostics::init_error(error));
+    }
+    Ok(())
 }
 
 /// Get the HIP driver version
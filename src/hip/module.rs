@@ -2,19 +2,33 @@
 //
 // Module loading and management for HIP
 
+use crate::hip::Stream;
 use crate::hip::error::{Error, Result};
 use crate::hip::ffi;
 use crate::hip::kernel::Function;
+use crate::hip::kernel_cache;
 use std::ffi::{CString, c_void};
 use std::fs;
 use std::path::Path;
 use std::ptr;
 
+/// Options for [`Module::warmup_with_options`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WarmupOptions {
+    /// Also enqueue an empty launch of each function
+    pub dry_launch: bool,
+}
+
 /// A wrapper around a HIP module
 pub struct Module {
     module: ffi::hipModule_t,
 }
 
+// Module handles are not tied to the thread that loaded them, and the
+// runtime serializes access to the loaded code object
+unsafe impl Send for Module {}
+unsafe impl Sync for Module {}
+
 impl Module {
     /// Load a module from a file
     pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
@@ -25,7 +39,7 @@ impl Module {
         let error = unsafe { ffi::hipModuleLoad(&mut module, path_cstr.as_ptr()) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipModuleLoad"));
         }
 
         Ok(Self { module })
@@ -38,7 +52,7 @@ impl Module {
             unsafe { ffi::hipModuleLoadData(&mut module, data.as_ref().as_ptr() as *const c_void) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipModuleLoadData"));
         }
 
         Ok(Self { module })
@@ -63,7 +77,7 @@ impl Module {
         };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipModuleLoadDataEx"));
         }
 
         Ok(Self { module })
@@ -74,6 +88,46 @@ impl Module {
         unsafe { Function::new(self.module, name) }
     }
 
+    /// Resolve functions ahead of their first launch
+    ///
+    /// Looking up a function and querying its attributes makes the runtime
+    /// load and finalize its code for the current device, so this moves
+    /// that one-time cost out of the first launch. Returns the functions in
+    /// the order named.
+    pub fn warmup(&self, names: &[&str], stream: &Stream) -> Result<Vec<Function>> {
+        self.warmup_with_options(names, stream, WarmupOptions::default())
+    }
+
+    /// Resolve functions ahead of their first launch, optionally with an
+    /// empty launch of each on `stream`
+    ///
+    /// Dry launches run no work; they only exercise the launch path. The
+    /// stream is synchronized before returning.
+    pub fn warmup_with_options(
+        &self,
+        names: &[&str],
+        stream: &Stream,
+        options: WarmupOptions,
+    ) -> Result<Vec<Function>> {
+        let functions = names
+            .iter()
+            .map(|name| {
+                self.get_function(name).map_err(|e| {
+                    Error::with_context(e.code(), format!("warming up kernel `{}`", name))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if options.dry_launch {
+            for function in &functions {
+                function.dry_launch(stream)?;
+            }
+            stream.synchronize()?;
+        }
+
+        Ok(functions)
+    }
+
     /// Get a global variable from the module
     pub fn get_global<T>(&self, name: &str) -> Result<*mut T> {
         let name_cstr = CString::new(name).unwrap();
@@ -86,7 +140,7 @@ impl Module {
         };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipModuleGetGlobal"));
         }
 
         if size < std::mem::size_of::<T>() {
@@ -125,41 +179,26 @@ pub fn load_module_data(data: &str) -> Result<Module> {
 }
 
 /// Helper function to compile and load HIP code
-pub fn compile_and_load(source: &str, options: &[String]) -> Result<Module> {
-    // This is a placeholder for a function that would:
-    // 1. Save the source to a temporary file
-    // 2. Run hipcc to compile it
-    // 3. Load the resulting binary
-    //
-    // A real implementation would depend on your build system
-    // and how you want to handle compilation.
-    //
-    // For now, let's just show how it might work:
-    use std::env::temp_dir;
-    use std::process::Command;
-
-    let temp_src_path = temp_dir().join("temp_kernel.cpp");
-    let temp_bin_path = temp_dir().join("temp_kernel.hsaco");
-
-    fs::write(&temp_src_path, source)
-        .map_err(|_| Error::new(ffi::hipError_t_hipErrorInvalidValue))?;
-
-    let mut cmd = Command::new("hipcc");
-    cmd.arg("--genco");
-
-    for opt in options {
-        cmd.arg(opt);
+///
+/// Code objects are cached on disk, see [`KernelCacheConfig`](crate::hip::KernelCacheConfig).
+/// Failures to run `hipcc` or access the cache report the path and the
+/// underlying I/O error; compiler errors carry `hipcc`'s exit status and
+/// diagnostics.
+pub fn compile_and_load(source: &str, options: &[String]) -> crate::error::Result<Module> {
+    let config = kernel_cache::kernel_cache_config();
+    if !config.enabled {
+        let path = kernel_cache::compile_temporary(source, options)?;
+        let module = load_code_object(&path);
+        let _ = fs::remove_file(&path);
+        return module;
     }
 
-    cmd.arg("-o").arg(&temp_bin_path).arg(&temp_src_path);
-
-    let status = cmd
-        .status()
-        .map_err(|_| Error::new(ffi::hipError_t_hipErrorInvalidValue))?;
-
-    if !status.success() {
-        return Err(Error::new(ffi::hipError_t_hipErrorInvalidValue));
-    }
+    let path = kernel_cache::lookup_or_compile(&config, source, options)?;
+    load_code_object(&path)
+}
 
-    Module::load(temp_bin_path)
+// Read the object up front so a concurrent eviction can't remove it mid-load
+fn load_code_object(path: &Path) -> crate::error::Result<Module> {
+    let data = fs::read(path).map_err(|e| kernel_cache::io_error("reading", path, e))?;
+    Ok(Module::load_data(data)?)
 }
@@ -0,0 +1,156 @@
+// src/hip/module_cache.rs
+//
+// Process-wide cache of loaded modules, keyed by source and architecture
+
+use crate::error;
+use crate::hip::device::Device;
+use crate::hip::error::Result;
+use crate::hip::kernel_cache;
+use crate::hip::module::{Module, compile_and_load};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Identifies a module by what it was built from and what it was built for
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ModuleKey {
+    /// Hash of the source and compiler options, or of the code object
+    pub source_hash: u64,
+    /// Full gfx architecture name of the target, e.g. `gfx90a:sramecc+:xnack-`
+    pub arch: String,
+}
+
+// A slot is filled once; threads asking for the same module wait on its lock
+type Slot = Arc<Mutex<Option<Arc<Module>>>>;
+
+/// A cache of loaded modules shared across threads
+///
+/// Loading a code object finalizes it for the device, which is expensive
+/// enough that repeating it per call shows up in profiles. The cache loads
+/// each module once per device and hands out shared references; concurrent
+/// requests for a module that is still loading wait for the first one
+/// instead of loading it again. Compiled code objects are also written to
+/// the on-disk [kernel cache](crate::hip::KernelCacheConfig), so later
+/// processes skip compilation.
+#[derive(Default)]
+pub struct ModuleCache {
+    slots: Mutex<HashMap<(i32, ModuleKey), Slot>>,
+    archs: Mutex<HashMap<i32, String>>,
+}
+
+impl ModuleCache {
+    /// Create an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the process-wide cache
+    pub fn global() -> &'static ModuleCache {
+        static GLOBAL: OnceLock<ModuleCache> = OnceLock::new();
+        GLOBAL.get_or_init(ModuleCache::new)
+    }
+
+    /// Get the module built from `source` for the current device, compiling
+    /// and loading it on first use
+    ///
+    /// Unless `options` already pick a target, the source is compiled for
+    /// the current device's architecture.
+    pub fn get_or_compile(&self, source: &str, options: &[String]) -> error::Result<Arc<Module>> {
+        let device = Device::current()?;
+        let arch = self.arch(&device)?;
+
+        let mut options = options.to_vec();
+        if !options.iter().any(|opt| opt.starts_with("--offload-arch")) {
+            options.push(format!("--offload-arch={}", arch));
+        }
+
+        let key = ModuleKey {
+            source_hash: kernel_cache::cache_key(source, &options),
+            arch,
+        };
+        self.get_or_insert_with(device.id(), key, || compile_and_load(source, &options))
+    }
+
+    /// Get the module loaded from the code object `data` on the current
+    /// device, loading it on first use
+    pub fn get_or_load_data(&self, data: &[u8]) -> Result<Arc<Module>> {
+        let device = Device::current()?;
+        let key = ModuleKey {
+            source_hash: kernel_cache::hash_bytes(data),
+            arch: self.arch(&device)?,
+        };
+        self.get_or_insert_with(device.id(), key, || Module::load_data(data))
+    }
+
+    /// Get an already loaded module for the current device
+    pub fn get(&self, key: &ModuleKey) -> Result<Option<Arc<Module>>> {
+        let device = Device::current()?;
+        let slot = self
+            .slots
+            .lock()
+            .unwrap()
+            .get(&(device.id(), key.clone()))
+            .cloned();
+
+        Ok(slot.and_then(|slot| slot.lock().unwrap().clone()))
+    }
+
+    /// Get the number of loaded modules across all devices
+    pub fn len(&self) -> usize {
+        let slots: Vec<Slot> = self.slots.lock().unwrap().values().cloned().collect();
+        slots
+            .iter()
+            .filter(|slot| slot.lock().unwrap().is_some())
+            .count()
+    }
+
+    /// Whether no modules are loaded
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop the cache's references to all modules
+    ///
+    /// Modules still referenced elsewhere stay loaded until those references
+    /// are dropped.
+    pub fn clear(&self) {
+        self.slots.lock().unwrap().clear();
+    }
+
+    fn arch(&self, device: &Device) -> Result<String> {
+        let mut archs = self.archs.lock().unwrap();
+        if let Some(arch) = archs.get(&device.id()) {
+            return Ok(arch.clone());
+        }
+
+        let arch = device.properties()?.gcn_arch_name;
+        archs.insert(device.id(), arch.clone());
+        Ok(arch)
+    }
+
+    fn get_or_insert_with<E>(
+        &self,
+        device_id: i32,
+        key: ModuleKey,
+        load: impl FnOnce() -> std::result::Result<Module, E>,
+    ) -> std::result::Result<Arc<Module>, E> {
+        // Only hold the map lock long enough to find the slot, so loads of
+        // different modules proceed in parallel
+        let slot = self
+            .slots
+            .lock()
+            .unwrap()
+            .entry((device_id, key))
+            .or_default()
+            .clone();
+
+        let mut module = slot.lock().unwrap();
+        if let Some(module) = module.as_ref() {
+            return Ok(module.clone());
+        }
+
+        // On failure the slot stays empty and the next caller retries
+        let loaded = Arc::new(load()?);
+        *module = Some(loaded.clone());
+        Ok(loaded)
+    }
+}
@@ -0,0 +1,121 @@
+// src/hip/multi.rs
+//
+// Helpers for spreading data across several GPUs
+
+use crate::hip::device::Device;
+use crate::hip::error::{Error, Result};
+use crate::hip::ffi;
+use crate::hip::memory::{DeviceMemory, pointer_attributes};
+use std::ffi::c_void;
+
+/// Replicate `source` to each of `devices`
+///
+/// Returns one copy per device, in the order given, each allocated on its
+/// device. Devices that can access the source device directly receive a
+/// peer copy; the others are filled from a single host-staged download.
+/// The current device is restored before returning.
+pub fn broadcast<T>(source: &DeviceMemory<T>, devices: &[Device]) -> Result<Vec<DeviceMemory<T>>> {
+    let current = Device::current()?;
+    let result = broadcast_from(source, devices, &current);
+    current.set_current()?;
+    result
+}
+
+fn broadcast_from<T>(
+    source: &DeviceMemory<T>,
+    devices: &[Device],
+    current: &Device,
+) -> Result<Vec<DeviceMemory<T>>> {
+    let size = source.size();
+    let source_device = if size == 0 {
+        current.clone()
+    } else {
+        match pointer_attributes(source.as_ptr())?.device {
+            Some(id) => Device::new(id)?,
+            None => current.clone(),
+        }
+    };
+
+    // Filled on first use by a device without peer access
+    let mut staged: Option<Vec<u8>> = None;
+    let mut copies = Vec::with_capacity(devices.len());
+
+    for device in devices {
+        device.set_current()?;
+        let copy = DeviceMemory::<T>::new(source.count())?;
+        if size == 0 {
+            copies.push(copy);
+            continue;
+        }
+
+        let (error, api) = if device.id() == source_device.id() {
+            let error = unsafe {
+                ffi::hipMemcpy(
+                    copy.as_ptr(),
+                    source.as_ptr(),
+                    size,
+                    ffi::hipMemcpyKind_hipMemcpyDeviceToDevice,
+                )
+            };
+            (error, "hipMemcpy")
+        } else if device.can_access_peer(&source_device)? {
+            device.enable_peer_access(&source_device)?;
+            let error = unsafe {
+                ffi::hipMemcpyPeer(
+                    copy.as_ptr(),
+                    device.id(),
+                    source.as_ptr(),
+                    source_device.id(),
+                    size,
+                )
+            };
+            (error, "hipMemcpyPeer")
+        } else {
+            let host = match &mut staged {
+                Some(host) => host,
+                None => staged.insert(download(source, &source_device, device)?),
+            };
+            let error = unsafe {
+                ffi::hipMemcpy(
+                    copy.as_ptr(),
+                    host.as_ptr() as *const c_void,
+                    size,
+                    ffi::hipMemcpyKind_hipMemcpyHostToDevice,
+                )
+            };
+            (error, "hipMemcpy")
+        };
+
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, api));
+        }
+        copies.push(copy);
+    }
+
+    Ok(copies)
+}
+
+/// Copy `source` to the host, leaving `resume` as the current device
+fn download<T>(
+    source: &DeviceMemory<T>,
+    source_device: &Device,
+    resume: &Device,
+) -> Result<Vec<u8>> {
+    source_device.set_current()?;
+
+    let mut host = vec![0u8; source.size()];
+    let error = unsafe {
+        ffi::hipMemcpy(
+            host.as_mut_ptr() as *mut c_void,
+            source.as_ptr(),
+            source.size(),
+            ffi::hipMemcpyKind_hipMemcpyDeviceToHost,
+        )
+    };
+
+    resume.set_current()?;
+    if error != ffi::hipError_t_hipSuccess {
+        return Err(Error::from_api(error, "hipMemcpy"));
+    }
+    Ok(host)
+}
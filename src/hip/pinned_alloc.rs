@@ -0,0 +1,222 @@
+// src/hip/pinned_alloc.rs
+//
+// Page-locked host allocations for standard collections
+
+use crate::hip::error::{Error, Result};
+use crate::hip::ffi;
+use crate::hip::memory::{DeviceMemory, SynchronizeCopies};
+use crate::hip::stream::Stream;
+use allocator_api2::alloc::{AllocError, Allocator, Layout};
+use std::ffi::c_void;
+use std::mem;
+use std::ptr::{self, NonNull};
+
+/// A `Vec` whose buffer is pinned host memory
+///
+/// With the `nightly` feature this is the standard library `Vec`.
+pub type PinnedVec<T> = allocator_api2::vec::Vec<T, PinnedAllocator>;
+
+// hipHostMalloc returns page-aligned memory
+const PINNED_ALIGNMENT: usize = 4096;
+
+/// Allocator handing out page-locked host memory from `hipHostMalloc`
+///
+/// Collections built on it transfer to and from the device at full DMA
+/// speed and can be copied asynchronously without a staging buffer.
+///
+/// ```ignore
+/// let mut host = PinnedVec::with_capacity_in(1 << 20, PinnedAllocator::new());
+/// host.extend((0..1 << 20).map(|i| i as f32));
+/// device.copy_from_pinned_async(host, &stream)?;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PinnedAllocator {
+    flags: u32,
+}
+
+impl PinnedAllocator {
+    /// Create an allocator with default `hipHostMalloc` flags
+    pub fn new() -> Self {
+        Self::with_flags(ffi::hipHostMallocDefault)
+    }
+
+    /// Create an allocator passing `flags` (`ffi::hipHostMalloc*`) to
+    /// every allocation
+    pub fn with_flags(flags: u32) -> Self {
+        Self { flags }
+    }
+
+    /// Get the allocation flags
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+}
+
+unsafe impl Allocator for PinnedAllocator {
+    fn allocate(&self, layout: Layout) -> std::result::Result<NonNull<[u8]>, AllocError> {
+        if layout.size() == 0 {
+            let dangling = ptr::without_provenance_mut::<u8>(layout.align());
+            return Ok(NonNull::slice_from_raw_parts(
+                NonNull::new(dangling).ok_or(AllocError)?,
+                0,
+            ));
+        }
+        if layout.align() > PINNED_ALIGNMENT {
+            return Err(AllocError);
+        }
+
+        let mut ptr = ptr::null_mut();
+        let error = unsafe { ffi::hipHostMalloc(&mut ptr, layout.size(), self.flags) };
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(AllocError);
+        }
+
+        let ptr = NonNull::new(ptr as *mut u8).ok_or(AllocError)?;
+        Ok(NonNull::slice_from_raw_parts(ptr, layout.size()))
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        if layout.size() != 0 {
+            unsafe {
+                let _ = ffi::hipHostFree(ptr.as_ptr() as *mut c_void);
+                // We cannot handle errors in drop, so just ignore the result
+            }
+        }
+    }
+}
+
+/// Pinned host buffer that is the destination of an in-flight
+/// device-to-host copy
+///
+/// The pinned counterpart of [`PendingCopy`](crate::hip::memory::PendingCopy).
+pub struct PendingPinnedCopy<T> {
+    inner: PinnedVec<T>,
+    stream: Option<Stream>,
+}
+
+impl<T> PendingPinnedCopy<T> {
+    /// Wait for the copy to finish and return the host buffer
+    pub fn synchronize(mut self) -> PinnedVec<T> {
+        if let Some(stream) = self.stream.take() {
+            let _ = stream.synchronize();
+        }
+        mem::replace(&mut self.inner, PinnedVec::new_in(PinnedAllocator::new()))
+    }
+}
+
+impl<T> Drop for PendingPinnedCopy<T> {
+    fn drop(&mut self) {
+        // The device may still be writing into `inner`
+        if let Some(stream) = self.stream.take() {
+            let _ = stream.synchronize();
+        }
+    }
+}
+
+impl<T> SynchronizeCopies for PendingPinnedCopy<T> {
+    type Output = PinnedVec<T>;
+
+    unsafe fn finalize(mut self) -> Self::Output {
+        // The caller already synchronized the stream
+        self.stream = None;
+        mem::replace(&mut self.inner, PinnedVec::new_in(PinnedAllocator::new()))
+    }
+}
+
+impl<T> DeviceMemory<T> {
+    /// Asynchronously copy a pinned host vector to the start of this buffer
+    ///
+    /// The vector is kept alive until the copy has completed.
+    pub fn copy_from_pinned_async(&self, source: PinnedVec<T>, stream: &Stream) -> Result<()> {
+        let bytes = mem::size_of_val(source.as_slice());
+        if bytes == 0 {
+            return Ok(());
+        }
+        if bytes > self.size() {
+            return Err(Error::new(ffi::hipError_t_hipErrorInvalidValue));
+        }
+
+        let error = unsafe {
+            ffi::hipMemcpyAsync(
+                self.as_ptr(),
+                source.as_ptr() as *const c_void,
+                bytes,
+                ffi::hipMemcpyKind_hipMemcpyHostToDevice,
+                stream.as_raw(),
+            )
+        };
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipMemcpyAsync"));
+        }
+
+        // The copy may still be reading from `source`
+        stream.retain_pinned(source);
+        Ok(())
+    }
+
+    /// Asynchronously fill a pinned host vector from the start of this
+    /// buffer
+    pub fn copy_to_pinned_async(
+        &self,
+        mut dest: PinnedVec<T>,
+        stream: &Stream,
+    ) -> Result<PendingPinnedCopy<T>> {
+        let bytes = mem::size_of_val(dest.as_slice());
+        if bytes == 0 {
+            return Ok(PendingPinnedCopy {
+                inner: dest,
+                stream: None,
+            });
+        }
+        if bytes > self.size() {
+            return Err(Error::new(ffi::hipError_t_hipErrorInvalidValue));
+        }
+
+        let error = unsafe {
+            ffi::hipMemcpyAsync(
+                dest.as_mut_ptr() as *mut c_void,
+                self.as_ptr(),
+                bytes,
+                ffi::hipMemcpyKind_hipMemcpyDeviceToHost,
+                stream.as_raw(),
+            )
+        };
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipMemcpyAsync"));
+        }
+
+        Ok(PendingPinnedCopy {
+            inner: dest,
+            stream: Some(stream.clone()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pinned_vec_round_trip() {
+        let stream = Stream::new().unwrap();
+        let device = DeviceMemory::<u32>::new(1000).unwrap();
+
+        let mut host = PinnedVec::with_capacity_in(1000, PinnedAllocator::new());
+        host.extend(0..1000u32);
+        device.copy_from_pinned_async(host, &stream).unwrap();
+
+        let mut back = PinnedVec::with_capacity_in(1000, PinnedAllocator::new());
+        back.resize(1000, 0);
+        let pending = device.copy_to_pinned_async(back, &stream).unwrap();
+        let back = stream.synchronize_memory(pending).unwrap();
+
+        assert!(back.iter().copied().eq(0..1000u32));
+    }
+
+    #[test]
+    fn test_zero_sized_allocation() {
+        let mut empty: PinnedVec<u64> = PinnedVec::new_in(PinnedAllocator::new());
+        assert!(empty.is_empty());
+        empty.shrink_to_fit();
+    }
+}
@@ -0,0 +1,395 @@
+// src/hip/pool.rs
+//
+// Sub-allocating device memory pool with handle indirection and compaction
+
+use crate::hip::error::{Error, Result};
+use crate::hip::ffi;
+use crate::hip::memory::DeviceMemory;
+use std::collections::BTreeMap;
+use std::ffi::c_void;
+use std::mem::size_of_val;
+
+/// Alignment in bytes of every pool allocation
+pub const POOL_ALIGNMENT: usize = 256;
+
+/// Handle to an allocation in a [`DevicePool`]
+///
+/// Handles stay valid across [`DevicePool::compact`]; device addresses
+/// resolved from them do not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PoolHandle {
+    index: u32,
+    generation: u32,
+}
+
+/// Occupancy of a [`DevicePool`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PoolStats {
+    /// Total size in bytes of the pool
+    pub capacity: usize,
+    /// Bytes held by live allocations, including alignment padding
+    pub used: usize,
+    /// Number of live allocations
+    pub allocations: usize,
+    /// Size in bytes of the largest contiguous free range
+    pub largest_free: usize,
+    /// Share of free memory outside the largest free range, from 0 to 1
+    pub fragmentation: f64,
+}
+
+/// Result of a [`DevicePool::compact`] pass
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompactionReport {
+    /// Number of allocations that were moved
+    pub moved: usize,
+    /// Bytes copied device-to-device
+    pub bytes_moved: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Slot {
+    generation: u32,
+    // Offset and size of the allocation, or None if the slot is free
+    range: Option<(usize, usize)>,
+}
+
+/// A device memory pool handing out sub-allocations through handles
+///
+/// Long-running services that allocate and free buffers of varying sizes
+/// fragment the pool until large requests fail although enough memory is
+/// free in total. Because callers hold [`PoolHandle`]s rather than raw
+/// addresses, [`DevicePool::compact`] can slide live allocations together
+/// and patch the indirection table, recovering one contiguous free range.
+pub struct DevicePool {
+    memory: DeviceMemory<u8>,
+    slots: Vec<Slot>,
+    free_slots: Vec<u32>,
+    // Live allocations by offset, mapping to their slot index
+    live: BTreeMap<usize, u32>,
+    used: usize,
+}
+
+impl DevicePool {
+    /// Create a pool of `capacity` bytes of device memory
+    pub fn new(capacity: usize) -> Result<Self> {
+        Ok(Self {
+            memory: DeviceMemory::new(capacity)?,
+            slots: Vec::new(),
+            free_slots: Vec::new(),
+            live: BTreeMap::new(),
+            used: 0,
+        })
+    }
+
+    /// Allocate `size` bytes from the pool
+    ///
+    /// Fails with `hipErrorOutOfMemory` if no free range is large enough,
+    /// even when compacting would make room.
+    pub fn allocate(&mut self, size: usize) -> Result<PoolHandle> {
+        let size = size.max(1).next_multiple_of(POOL_ALIGNMENT);
+        let offset = find_free_range(self.live_ranges(), self.memory.size(), size).ok_or_else(
+            || {
+                Error::with_context(
+                    ffi::hipError_t_hipErrorOutOfMemory,
+                    format!(
+                        "no free range of {} bytes in pool ({} of {} bytes used, compact() may help)",
+                        size,
+                        self.used,
+                        self.memory.size()
+                    ),
+                )
+            },
+        )?;
+
+        let index = match self.free_slots.pop() {
+            Some(index) => index,
+            None => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    range: None,
+                });
+                (self.slots.len() - 1) as u32
+            }
+        };
+
+        let slot = &mut self.slots[index as usize];
+        slot.range = Some((offset, size));
+        self.live.insert(offset, index);
+        self.used += size;
+
+        Ok(PoolHandle {
+            index,
+            generation: slot.generation,
+        })
+    }
+
+    /// Free an allocation
+    pub fn free(&mut self, handle: PoolHandle) -> Result<()> {
+        let (offset, size) = self.range(handle)?;
+        let slot = &mut self.slots[handle.index as usize];
+        slot.range = None;
+        // Stale copies of the handle no longer resolve
+        slot.generation = slot.generation.wrapping_add(1);
+
+        self.live.remove(&offset);
+        self.free_slots.push(handle.index);
+        self.used -= size;
+        Ok(())
+    }
+
+    /// Resolve a handle to its current device address
+    ///
+    /// The address is invalidated by [`DevicePool::compact`], so resolve
+    /// handles again before each launch rather than keeping addresses.
+    pub fn device_ptr(&self, handle: PoolHandle) -> Result<*mut c_void> {
+        let (offset, _) = self.range(handle)?;
+        Ok(unsafe { (self.memory.as_ptr() as *mut u8).add(offset) } as *mut c_void)
+    }
+
+    /// Get the size in bytes of an allocation, including alignment padding
+    pub fn allocation_size(&self, handle: PoolHandle) -> Result<usize> {
+        self.range(handle).map(|(_, size)| size)
+    }
+
+    /// Copy data from host into an allocation
+    pub fn copy_from_host<T>(&self, handle: PoolHandle, data: &[T]) -> Result<()> {
+        let bytes = size_of_val(data);
+        self.check_fits(handle, bytes)?;
+
+        let error = unsafe {
+            ffi::hipMemcpy(
+                self.device_ptr(handle)?,
+                data.as_ptr() as *const c_void,
+                bytes,
+                ffi::hipMemcpyKind_hipMemcpyHostToDevice,
+            )
+        };
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipMemcpy"));
+        }
+        Ok(())
+    }
+
+    /// Copy data from an allocation to host
+    pub fn copy_to_host<T>(&self, handle: PoolHandle, data: &mut [T]) -> Result<()> {
+        let bytes = size_of_val(data);
+        self.check_fits(handle, bytes)?;
+
+        let error = unsafe {
+            ffi::hipMemcpy(
+                data.as_mut_ptr() as *mut c_void,
+                self.device_ptr(handle)?,
+                bytes,
+                ffi::hipMemcpyKind_hipMemcpyDeviceToHost,
+            )
+        };
+        if error != ffi::hipError_t_hipSuccess {
+            return Err(Error::from_api(error, "hipMemcpy"));
+        }
+        Ok(())
+    }
+
+    /// Get the pool's current occupancy
+    pub fn stats(&self) -> PoolStats {
+        let capacity = self.memory.size();
+        let largest_free = free_ranges(self.live_ranges(), capacity)
+            .map(|(_, size)| size)
+            .max()
+            .unwrap_or(0);
+        let free = capacity - self.used;
+
+        PoolStats {
+            capacity,
+            used: self.used,
+            allocations: self.live.len(),
+            largest_free,
+            fragmentation: if free == 0 {
+                0.0
+            } else {
+                1.0 - largest_free as f64 / free as f64
+            },
+        }
+    }
+
+    /// Move all live allocations to the start of the pool
+    ///
+    /// Call this at a safepoint: every kernel and copy touching pool memory
+    /// must be finished or at least queued, and no device address resolved
+    /// before the call may be used afterwards. The device is synchronized
+    /// before and after moving data. Handles remain valid.
+    pub fn compact(&mut self) -> Result<CompactionReport> {
+        let moves = plan_compaction(self.live_ranges());
+        if moves.is_empty() {
+            return Ok(CompactionReport::default());
+        }
+
+        crate::hip::device_synchronize()?;
+
+        let mut report = CompactionReport::default();
+        for &(from, to, size) in &moves {
+            self.move_range(from, to, size)?;
+
+            let index = self
+                .live
+                .remove(&from)
+                .expect("planned move of a live range");
+            self.live.insert(to, index);
+            self.slots[index as usize].range = Some((to, size));
+
+            report.moved += 1;
+            report.bytes_moved += size;
+        }
+
+        crate::hip::device_synchronize()?;
+        Ok(report)
+    }
+
+    /// Get the total size in bytes of the pool
+    pub fn capacity(&self) -> usize {
+        self.memory.size()
+    }
+
+    fn range(&self, handle: PoolHandle) -> Result<(usize, usize)> {
+        self.slots
+            .get(handle.index as usize)
+            .filter(|slot| slot.generation == handle.generation)
+            .and_then(|slot| slot.range)
+            .ok_or_else(|| {
+                Error::with_context(
+                    ffi::hipError_t_hipErrorInvalidHandle,
+                    "pool handle was freed or belongs to another pool",
+                )
+            })
+    }
+
+    fn check_fits(&self, handle: PoolHandle, bytes: usize) -> Result<()> {
+        let size = self.allocation_size(handle)?;
+        if bytes > size {
+            return Err(Error::with_context(
+                ffi::hipError_t_hipErrorInvalidValue,
+                format!("{} bytes don't fit a {} byte allocation", bytes, size),
+            ));
+        }
+        Ok(())
+    }
+
+    fn live_ranges(&self) -> Vec<(usize, usize)> {
+        self.live
+            .values()
+            .filter_map(|&index| self.slots[index as usize].range)
+            .collect()
+    }
+
+    /// Copy `size` bytes from `from` down to `to` within the pool
+    fn move_range(&self, from: usize, to: usize, size: usize) -> Result<()> {
+        let base = self.memory.as_ptr() as *mut u8;
+        let copy = |src: usize, dst: usize, len: usize| {
+            let error = unsafe {
+                ffi::hipMemcpy(
+                    base.add(dst) as *mut c_void,
+                    base.add(src) as *const c_void,
+                    len,
+                    ffi::hipMemcpyKind_hipMemcpyDeviceToDevice,
+                )
+            };
+            if error != ffi::hipError_t_hipSuccess {
+                return Err(Error::from_api(error, "hipMemcpy"));
+            }
+            Ok(())
+        };
+
+        // Chunks no longer than the distance moved never overlap their
+        // destination, since data only moves towards the start
+        let step = from - to;
+        let mut done = 0;
+        while done < size {
+            let len = step.min(size - done);
+            copy(from + done, to + done, len)?;
+            done += len;
+        }
+        Ok(())
+    }
+}
+
+/// Iterate over the gaps between sorted live ranges
+fn free_ranges(live: Vec<(usize, usize)>, capacity: usize) -> impl Iterator<Item = (usize, usize)> {
+    let mut cursor = 0;
+    live.into_iter()
+        .chain(std::iter::once((capacity, 0)))
+        .filter_map(move |(offset, size)| {
+            let gap = (cursor, offset - cursor);
+            cursor = offset + size;
+            (gap.1 > 0).then_some(gap)
+        })
+}
+
+/// Find the first free range that fits `size` bytes
+fn find_free_range(live: Vec<(usize, usize)>, capacity: usize, size: usize) -> Option<usize> {
+    free_ranges(live, capacity)
+        .find(|&(_, free)| free >= size)
+        .map(|(offset, _)| offset)
+}
+
+/// Plan moves packing sorted live ranges at the start of the pool
+///
+/// Returns `(from, to, size)` in an order where no move overwrites a range
+/// that has yet to be moved.
+fn plan_compaction(live: Vec<(usize, usize)>) -> Vec<(usize, usize, usize)> {
+    let mut cursor = 0;
+    let mut moves = Vec::new();
+    for (offset, size) in live {
+        if offset != cursor {
+            moves.push((offset, cursor, size));
+        }
+        cursor += size;
+    }
+    moves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_free_range_first_fit() {
+        let live = vec![(0, 256), (512, 256), (1024, 512)];
+        assert_eq!(find_free_range(live.clone(), 2048, 256), Some(256));
+        assert_eq!(find_free_range(live.clone(), 2048, 512), Some(1536));
+        assert_eq!(find_free_range(live, 2048, 768), None);
+    }
+
+    #[test]
+    fn test_plan_compaction_packs_ranges() {
+        let live = vec![(256, 256), (1024, 512), (1536, 256)];
+        assert_eq!(
+            plan_compaction(live),
+            vec![(256, 0, 256), (1024, 256, 512), (1536, 768, 256)]
+        );
+        assert!(plan_compaction(vec![(0, 256), (256, 512)]).is_empty());
+    }
+
+    #[test]
+    fn test_pool_compaction() {
+        let mut pool = DevicePool::new(4 * POOL_ALIGNMENT).unwrap();
+        let handles: Vec<_> = (0..4)
+            .map(|_| pool.allocate(POOL_ALIGNMENT).unwrap())
+            .collect();
+        pool.copy_from_host(handles[3], &[7u32; 64]).unwrap();
+        pool.free(handles[0]).unwrap();
+        pool.free(handles[2]).unwrap();
+
+        // Half the pool is free, but not in one piece
+        assert!(pool.allocate(2 * POOL_ALIGNMENT).is_err());
+        assert_eq!(pool.stats().fragmentation, 0.5);
+
+        let report = pool.compact().unwrap();
+        assert_eq!(report.moved, 2);
+        assert_eq!(pool.stats().fragmentation, 0.0);
+        assert!(pool.allocate(2 * POOL_ALIGNMENT).is_ok());
+
+        let mut data = [0u32; 64];
+        pool.copy_to_host(handles[3], &mut data).unwrap();
+        assert_eq!(data, [7u32; 64]);
+        assert!(pool.free(handles[0]).is_err());
+    }
+}
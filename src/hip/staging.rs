@@ -0,0 +1,259 @@
+// src/hip/staging.rs
+//
+// Pool of pinned host buffers for staging transfers of pageable memory
+
+use crate::hip::device::Device;
+use crate::hip::error::{Error, Result};
+use crate::hip::event::Event;
+use crate::hip::memory::PinnedMemory;
+use crate::hip::{Stream, ffi};
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::ptr;
+use std::sync::{Mutex, OnceLock};
+
+/// Default size in bytes of each staging buffer
+pub const DEFAULT_STAGING_BUFFER_SIZE: usize = 4 * 1024 * 1024;
+
+/// Configuration of the staging pool
+///
+/// When enabled, `DeviceMemory::copy_from_host` and `copy_to_host` copy
+/// large transfers through a pair of pinned buffers, overlapping the host
+/// side copy of one chunk with the DMA transfer of the other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StagingConfig {
+    /// Whether copies are routed through the pool (disabled by default)
+    pub enabled: bool,
+    /// Size in bytes of each staging buffer
+    pub buffer_size: usize,
+    /// Maximum number of idle buffers kept per device
+    pub max_buffers: usize,
+    /// Transfers smaller than this many bytes are copied directly
+    pub min_transfer_size: usize,
+}
+
+impl StagingConfig {
+    const DEFAULT: Self = Self {
+        enabled: false,
+        buffer_size: DEFAULT_STAGING_BUFFER_SIZE,
+        max_buffers: 4,
+        min_transfer_size: 256 * 1024,
+    };
+}
+
+impl Default for StagingConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A pooled pinned buffer
+struct StagingBuffer(PinnedMemory<u8>);
+
+// Pinned host memory is not tied to the thread that allocated it
+unsafe impl Send for StagingBuffer {}
+
+static CONFIG: Mutex<StagingConfig> = Mutex::new(StagingConfig::DEFAULT);
+static POOLS: OnceLock<Mutex<HashMap<i32, Vec<StagingBuffer>>>> = OnceLock::new();
+
+/// Set the staging pool configuration
+///
+/// Idle buffers allocated under the previous configuration are released.
+pub fn set_staging_config(config: StagingConfig) {
+    *CONFIG.lock().unwrap() = config;
+    clear_staging_pool();
+}
+
+/// Get the current staging pool configuration
+pub fn staging_config() -> StagingConfig {
+    *CONFIG.lock().unwrap()
+}
+
+/// Release all idle staging buffers
+pub fn clear_staging_pool() {
+    if let Some(pools) = POOLS.get() {
+        pools.lock().unwrap().clear();
+    }
+}
+
+/// Get the number of idle staging buffers held for a device
+pub fn idle_staging_buffers(device_id: i32) -> usize {
+    POOLS
+        .get()
+        .and_then(|pools| pools.lock().unwrap().get(&device_id).map(Vec::len))
+        .unwrap_or(0)
+}
+
+/// Whether a transfer of `size` bytes should go through the pool
+pub(crate) fn should_stage(size: usize) -> bool {
+    let config = staging_config();
+    config.enabled && config.buffer_size > 0 && size >= config.min_transfer_size
+}
+
+fn checkout(device_id: i32, buffer_size: usize) -> Result<StagingBuffer> {
+    let pools = POOLS.get_or_init(|| Mutex::new(HashMap::new()));
+    let pooled = pools
+        .lock()
+        .unwrap()
+        .get_mut(&device_id)
+        .and_then(Vec::pop)
+        .filter(|buffer| buffer.0.size() == buffer_size);
+    if let Some(buffer) = pooled {
+        return Ok(buffer);
+    }
+
+    Ok(StagingBuffer(PinnedMemory::new(buffer_size)?))
+}
+
+fn checkin(device_id: i32, buffer: StagingBuffer) {
+    let config = staging_config();
+    if buffer.0.size() != config.buffer_size {
+        return;
+    }
+
+    let pools = POOLS.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut pools = pools.lock().unwrap();
+    let pool = pools.entry(device_id).or_default();
+    if pool.len() < config.max_buffers {
+        pool.push(buffer);
+    }
+}
+
+/// Run a double-buffered transfer, returning the buffers to the pool
+fn with_staging_buffers<F>(f: F) -> Result<()>
+where
+    F: FnOnce(&mut [StagingBuffer; 2], &Stream) -> Result<()>,
+{
+    let buffer_size = staging_config().buffer_size;
+    let device_id = Device::current()?.id();
+    let mut buffers = [
+        checkout(device_id, buffer_size)?,
+        checkout(device_id, buffer_size)?,
+    ];
+    let stream = Stream::default_stream();
+
+    let result = f(&mut buffers, &stream).and_then(|_| stream.synchronize());
+    if result.is_err() {
+        // Queued copies may still be using the buffers
+        let _ = stream.synchronize();
+    }
+
+    let [first, second] = buffers;
+    checkin(device_id, first);
+    checkin(device_id, second);
+    result
+}
+
+/// Copy `size` bytes from pageable host memory to the device
+///
+/// # Safety
+///
+/// `src` must be valid for `size` bytes of reads and `dst` must be a device
+/// allocation of at least `size` bytes.
+pub(crate) unsafe fn copy_to_device(dst: *mut c_void, src: *const u8, size: usize) -> Result<()> {
+    with_staging_buffers(|buffers, stream| {
+        let events = [Event::new()?, Event::new()?];
+        let chunk_size = buffers[0].0.size();
+
+        let mut offset = 0;
+        let mut chunk_index = 0;
+        while offset < size {
+            let len = chunk_size.min(size - offset);
+            let slot = chunk_index % 2;
+
+            // Wait until the previous transfer out of this buffer is done
+            if chunk_index >= 2 {
+                events[slot].synchronize()?;
+            }
+
+            let error = unsafe {
+                let staging = buffers[slot].0.as_mut_ptr();
+                ptr::copy_nonoverlapping(src.add(offset), staging, len);
+                ffi::hipMemcpyAsync(
+                    (dst as *mut u8).add(offset) as *mut c_void,
+                    staging as *const c_void,
+                    len,
+                    ffi::hipMemcpyKind_hipMemcpyHostToDevice,
+                    stream.as_raw(),
+                )
+            };
+
+            if error != ffi::hipError_t_hipSuccess {
+                return Err(Error::from_api(error, "hipMemcpyAsync"));
+            }
+
+            events[slot].record(stream)?;
+            offset += len;
+            chunk_index += 1;
+        }
+
+        Ok(())
+    })
+}
+
+/// Copy `size` bytes from the device to pageable host memory
+///
+/// # Safety
+///
+/// `dst` must be valid for `size` bytes of writes and `src` must be a
+/// device allocation of at least `size` bytes.
+pub(crate) unsafe fn copy_to_host(dst: *mut u8, src: *const c_void, size: usize) -> Result<()> {
+    with_staging_buffers(|buffers, stream| {
+        let events = [Event::new()?, Event::new()?];
+        let chunk_size = buffers[0].0.size();
+        let mut pending: [Option<(usize, usize)>; 2] = [None, None];
+
+        let mut offset = 0;
+        let mut chunk_index = 0;
+        while offset < size {
+            let len = chunk_size.min(size - offset);
+            let slot = chunk_index % 2;
+
+            // Drain the chunk previously transferred into this buffer
+            if let Some((pending_offset, pending_len)) = pending[slot].take() {
+                events[slot].synchronize()?;
+                unsafe {
+                    ptr::copy_nonoverlapping(
+                        buffers[slot].0.as_ptr(),
+                        dst.add(pending_offset),
+                        pending_len,
+                    );
+                }
+            }
+
+            let error = unsafe {
+                ffi::hipMemcpyAsync(
+                    buffers[slot].0.as_mut_ptr() as *mut c_void,
+                    (src as *const u8).add(offset) as *const c_void,
+                    len,
+                    ffi::hipMemcpyKind_hipMemcpyDeviceToHost,
+                    stream.as_raw(),
+                )
+            };
+
+            if error != ffi::hipError_t_hipSuccess {
+                return Err(Error::from_api(error, "hipMemcpyAsync"));
+            }
+
+            events[slot].record(stream)?;
+            pending[slot] = Some((offset, len));
+            offset += len;
+            chunk_index += 1;
+        }
+
+        for slot in 0..2 {
+            if let Some((pending_offset, pending_len)) = pending[slot].take() {
+                events[slot].synchronize()?;
+                unsafe {
+                    ptr::copy_nonoverlapping(
+                        buffers[slot].0.as_ptr(),
+                        dst.add(pending_offset),
+                        pending_len,
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    })
+}
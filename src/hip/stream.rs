@@ -4,14 +4,148 @@ use crate::hip;
 use crate::hip::error::{Error, Result};
 use crate::hip::event::Event;
 use crate::hip::ffi;
-use std::{panic, ptr};
+use crate::hip::labels;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::{fmt, panic, ptr};
 
 use super::memory::SynchronizeCopies;
 
+/// HIP's `hipStreamPerThread` handle (a sentinel, not a real stream)
+const STREAM_PER_THREAD: ffi::hipStream_t = 2 as ffi::hipStream_t;
+
+static PER_THREAD_DEFAULT_STREAM: AtomicBool = AtomicBool::new(cfg!(feature = "per_thread_stream"));
+
+/// Make the default stream per-thread instead of the legacy NULL stream
+///
+/// When enabled, kernel launches and synchronization that don't name a
+/// stream use `hipStreamPerThread`, so threads submitting work concurrently
+/// don't serialize on the legacy default stream. Enabled from the start with
+/// the `per_thread_stream` feature.
+pub fn set_per_thread_default_stream(enabled: bool) {
+    PER_THREAD_DEFAULT_STREAM.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether the default stream has per-thread semantics
+pub fn per_thread_default_stream() -> bool {
+    PER_THREAD_DEFAULT_STREAM.load(Ordering::Relaxed)
+}
+
+/// Raw handle of the stream used when no stream is given
+pub(crate) fn default_stream_raw() -> ffi::hipStream_t {
+    if per_thread_default_stream() {
+        STREAM_PER_THREAD
+    } else {
+        ptr::null_mut()
+    }
+}
+
 /// Safe wrapper for HIP streams
-#[derive(Clone, Debug)]
+///
+/// Cloning a `Stream` is cheap and yields another handle to the same
+/// underlying HIP stream. The stream is destroyed when the last handle is
+/// dropped. Queued work can never outlive its stream:
+///
+/// - Dropping the last handle synchronizes the stream before destroying it
+///   (see [`Stream::set_synchronize_on_drop`]).
+/// - Pending async copies hold a handle to the stream they were issued on,
+///   and host buffers passed to async uploads are retained by the stream
+///   until their upload is observed to have completed: by
+///   [`Stream::synchronize`], a successful [`Stream::query`], or the next
+///   upload on the stream finding it done.
+/// - `DeviceMemory` can be dropped while kernels using it are queued:
+///   `hipFree` waits for outstanding device work before releasing memory.
+#[derive(Clone)]
 pub struct Stream {
+    pub(crate) inner: Rc<StreamInner>,
+}
+
+pub(crate) struct StreamInner {
     pub(crate) stream: hip::ffi::hipStream_t,
+    owned: bool,
+    synchronize_on_drop: Cell<bool>,
+    retained: RefCell<Vec<RetainedBatch>>,
+}
+
+/// Buffers retained by one upload, with an event recorded after it
+struct RetainedBatch {
+    done: Event,
+    _buffer: RetainedBuffer,
+}
+
+/// A type-erased host buffer kept alive until queued work completes
+struct RetainedBuffer {
+    ptr: *mut u8,
+    len: usize,
+    capacity: usize,
+    drop_fn: unsafe fn(*mut u8, usize, usize),
+}
+
+unsafe fn drop_retained_vec<T>(ptr: *mut u8, len: usize, capacity: usize) {
+    drop(unsafe { Vec::from_raw_parts(ptr as *mut T, len, capacity) });
+}
+
+#[cfg(feature = "allocator-api2")]
+unsafe fn drop_retained_pinned_vec<T>(ptr: *mut u8, len: usize, capacity: usize) {
+    use crate::hip::pinned_alloc::{PinnedAllocator, PinnedVec};
+
+    // Freeing pinned memory doesn't depend on the allocation flags
+    drop(unsafe {
+        PinnedVec::from_raw_parts_in(ptr as *mut T, len, capacity, PinnedAllocator::new())
+    });
+}
+
+impl Drop for RetainedBuffer {
+    fn drop(&mut self) {
+        unsafe { (self.drop_fn)(self.ptr, self.len, self.capacity) }
+    }
+}
+
+impl StreamInner {
+    fn owned(stream: ffi::hipStream_t) -> Self {
+        Self {
+            stream,
+            owned: true,
+            synchronize_on_drop: Cell::new(true),
+            retained: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl Drop for StreamInner {
+    fn drop(&mut self) {
+        if !self.owned {
+            // Borrowed handles (e.g. from a library handle) are not ours to
+            // destroy, and must not hold retained buffers either.
+            return;
+        }
+
+        if !self.stream.is_null() {
+            let retained = self.retained.get_mut();
+            if self.synchronize_on_drop.get() || !retained.is_empty() {
+                unsafe {
+                    let _ = ffi::hipStreamSynchronize(self.stream);
+                }
+            }
+            retained.clear();
+            labels::unregister_stream(self.stream);
+            unsafe {
+                let _ = ffi::hipStreamDestroy(self.stream);
+                // We cannot handle errors in drop, so just ignore the result
+            };
+            self.stream = ptr::null_mut();
+        }
+    }
+}
+
+impl fmt::Debug for Stream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Stream")
+            .field("stream", &self.inner.stream)
+            .field("owned", &self.inner.owned)
+            .finish()
+    }
 }
 
 impl Stream {
@@ -21,10 +155,10 @@ impl Stream {
         let error = unsafe { ffi::hipStreamCreate(&mut stream) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipStreamCreate"));
         }
 
-        Ok(Self { stream })
+        Ok(Self::from_owned(stream))
     }
 
     /// Create a new stream with specific flags
@@ -33,10 +167,10 @@ impl Stream {
         let error = unsafe { ffi::hipStreamCreateWithFlags(&mut stream, flags) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipStreamCreateWithFlags"));
         }
 
-        Ok(Self { stream })
+        Ok(Self::from_owned(stream))
     }
 
     /// Create a new stream with priority
@@ -45,23 +179,133 @@ impl Stream {
         let error = unsafe { ffi::hipStreamCreateWithPriority(&mut stream, flags, priority) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipStreamCreateWithPriority"));
         }
 
-        Ok(Self { stream })
+        Ok(Self::from_owned(stream))
+    }
+
+    fn from_owned(stream: ffi::hipStream_t) -> Self {
+        Self {
+            inner: Rc::new(StreamInner::owned(stream)),
+        }
+    }
+
+    /// Create a new stream with a human-readable name
+    ///
+    /// The name is reported by `hip::labels::stream_name`, for profiling
+    /// and diagnostics output to attribute work to the stream. With the
+    /// `roctx` feature it is also emitted as a roctx marker for the
+    /// profiler trace.
+    pub fn with_name(name: impl Into<String>) -> Result<Self> {
+        let stream = Self::new()?;
+        labels::register_stream(stream.as_raw(), name.into());
+        Ok(stream)
+    }
+
+    /// Get the name assigned to this stream, if any
+    pub fn name(&self) -> Option<String> {
+        labels::stream_name(self.as_raw())
     }
 
     /// Wait for a stream to complete
     pub fn synchronize(&self) -> Result<()> {
-        let error = unsafe { ffi::hipStreamSynchronize(self.stream) };
+        let error = unsafe { ffi::hipStreamSynchronize(self.as_raw()) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipStreamSynchronize"));
         }
 
+        // All queued work is done, so retained host buffers can be released
+        self.inner.retained.borrow_mut().clear();
+
         Ok(())
     }
 
+    /// Control whether dropping the last handle to this stream waits for
+    /// queued work to finish (enabled by default)
+    ///
+    /// Disabling this only skips the wait when the stream holds no retained
+    /// host buffers; HIP still completes queued work after destruction.
+    pub fn set_synchronize_on_drop(&self, enabled: bool) {
+        self.inner.synchronize_on_drop.set(enabled);
+    }
+
+    /// Whether dropping the last handle to this stream synchronizes it
+    pub fn synchronize_on_drop(&self) -> bool {
+        self.inner.synchronize_on_drop.get()
+    }
+
+    /// Keep a host buffer alive until the work queued so far has completed
+    pub(crate) fn retain<T>(&self, buffer: Vec<T>) {
+        if buffer.capacity() == 0 || std::mem::size_of::<T>() == 0 {
+            return;
+        }
+
+        let mut buffer = std::mem::ManuallyDrop::new(buffer);
+        self.retain_raw(RetainedBuffer {
+            ptr: buffer.as_mut_ptr() as *mut u8,
+            len: buffer.len(),
+            capacity: buffer.capacity(),
+            drop_fn: drop_retained_vec::<T>,
+        });
+    }
+
+    /// Keep a pinned host buffer alive until the work queued so far has completed
+    #[cfg(feature = "allocator-api2")]
+    pub(crate) fn retain_pinned<T>(&self, buffer: crate::hip::pinned_alloc::PinnedVec<T>) {
+        if buffer.capacity() == 0 || std::mem::size_of::<T>() == 0 {
+            return;
+        }
+
+        let mut buffer = std::mem::ManuallyDrop::new(buffer);
+        self.retain_raw(RetainedBuffer {
+            ptr: buffer.as_mut_ptr() as *mut u8,
+            len: buffer.len(),
+            capacity: buffer.capacity(),
+            drop_fn: drop_retained_pinned_vec::<T>,
+        });
+    }
+
+    fn retain_raw(&self, retained: RetainedBuffer) {
+        self.release_completed();
+
+        // We cannot observe the lifetime of a borrowed stream, so wait for
+        // the queued work instead of retaining the buffer. Do the same if
+        // the completion event can't be recorded.
+        if self.inner.owned {
+            let done = Event::without_timing().and_then(|event| {
+                event.record(self)?;
+                Ok(event)
+            });
+            if let Ok(done) = done {
+                self.inner.retained.borrow_mut().push(RetainedBatch {
+                    done,
+                    _buffer: retained,
+                });
+                return;
+            }
+        }
+
+        unsafe {
+            let _ = ffi::hipStreamSynchronize(self.as_raw());
+        }
+        drop(retained);
+    }
+
+    /// Free retained buffers whose uploads have completed
+    ///
+    /// Work on a stream completes in order, so this stops at the first
+    /// batch that is still pending.
+    fn release_completed(&self) {
+        let mut retained = self.inner.retained.borrow_mut();
+        let completed = retained
+            .iter()
+            .take_while(|batch| batch.done.query().unwrap_or(false))
+            .count();
+        retained.drain(..completed);
+    }
+
     pub fn synchronize_memory<T: SynchronizeCopies>(&self, copies: T) -> Result<T::Output> {
         Self::synchronize(&self)?;
         Ok(unsafe { copies.finalize() })
@@ -69,24 +313,26 @@ impl Stream {
 
     /// Query if all operations in the stream have completed
     pub fn query(&self) -> Result<()> {
-        let error = unsafe { ffi::hipStreamQuery(self.stream) };
+        let error = unsafe { ffi::hipStreamQuery(self.as_raw()) };
 
         if error == ffi::hipError_t_hipSuccess {
+            // All queued work is done, so retained host buffers can be released
+            self.inner.retained.borrow_mut().clear();
             Ok(())
         } else if error == ffi::hipError_t_hipErrorNotReady {
             // Not ready isn't a true error in this context
-            Err(Error::new(error))
+            Err(Error::from_api(error, "hipStreamQuery"))
         } else {
-            Err(Error::new(error))
+            Err(Error::from_api(error, "hipStreamQuery"))
         }
     }
 
     /// Wait on an event
     pub fn wait_event(&self, event: &Event, flags: u32) -> Result<()> {
-        let error = unsafe { ffi::hipStreamWaitEvent(self.stream, event.as_raw(), flags) };
+        let error = unsafe { ffi::hipStreamWaitEvent(self.as_raw(), event.as_raw(), flags) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipStreamWaitEvent"));
         }
 
         Ok(())
@@ -117,11 +363,11 @@ impl Stream {
         }
 
         let error =
-            unsafe { ffi::hipStreamAddCallback(self.stream, Some(helper_callback), ptr, 0) };
+            unsafe { ffi::hipStreamAddCallback(self.as_raw(), Some(helper_callback), ptr, 0) };
 
         if error != ffi::hipError_t_hipSuccess {
             unsafe { drop(Box::from_raw(ptr)) }
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipStreamAddCallback"));
         }
 
         Ok(())
@@ -129,7 +375,7 @@ impl Stream {
 
     /// Get the raw stream handle
     pub fn as_raw(&self) -> ffi::hipStream_t {
-        self.stream
+        self.inner.stream
     }
 
     /// Get the stream priority range
@@ -142,7 +388,7 @@ impl Stream {
         };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipDeviceGetStreamPriorityRange"));
         }
 
         Ok((least_priority, greatest_priority))
@@ -152,10 +398,10 @@ impl Stream {
     pub fn get_priority(&self) -> Result<i32> {
         let mut priority = 0;
 
-        let error = unsafe { ffi::hipStreamGetPriority(self.stream, &mut priority) };
+        let error = unsafe { ffi::hipStreamGetPriority(self.as_raw(), &mut priority) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipStreamGetPriority"));
         }
 
         Ok(priority)
@@ -165,10 +411,10 @@ impl Stream {
     pub fn get_flags(&self) -> Result<u32> {
         let mut flags = 0;
 
-        let error = unsafe { ffi::hipStreamGetFlags(self.stream, &mut flags) };
+        let error = unsafe { ffi::hipStreamGetFlags(self.as_raw(), &mut flags) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipStreamGetFlags"));
         }
 
         Ok(flags)
@@ -178,27 +424,41 @@ impl Stream {
     pub fn get_device(&self) -> Result<i32> {
         let mut device = 0;
 
-        let error = unsafe { ffi::hipStreamGetDevice(self.stream, &mut device) };
+        let error = unsafe { ffi::hipStreamGetDevice(self.as_raw(), &mut device) };
 
         if error != ffi::hipError_t_hipSuccess {
-            return Err(Error::new(error));
+            return Err(Error::from_api(error, "hipStreamGetDevice"));
         }
 
         Ok(device)
     }
-    pub fn from_raw(stream: ffi::hipStream_t) -> Self {
-        Self { stream }
+
+    /// Get a handle to the default stream
+    ///
+    /// This is the per-thread stream if [`set_per_thread_default_stream`]
+    /// is enabled, and the legacy NULL stream otherwise. It can be passed to
+    /// the async copy APIs to queue work behind default-stream launches.
+    pub fn default_stream() -> Self {
+        Self::from_raw(default_stream_raw())
     }
-}
 
-impl Drop for Stream {
-    fn drop(&mut self) {
-        if !self.stream.is_null() {
-            unsafe {
-                let _ = ffi::hipStreamDestroy(self.stream);
-                // We cannot handle errors in drop, so just ignore the result
-            };
-            self.stream = ptr::null_mut();
+    /// Get a handle to the calling thread's per-thread default stream
+    pub fn per_thread() -> Self {
+        Self::from_raw(STREAM_PER_THREAD)
+    }
+
+    /// Wrap a raw stream handle without taking ownership of it
+    ///
+    /// The returned stream is never destroyed by this crate; the caller (or
+    /// the library that created it) remains responsible for its lifetime.
+    pub fn from_raw(stream: ffi::hipStream_t) -> Self {
+        Self {
+            inner: Rc::new(StreamInner {
+                stream,
+                owned: false,
+                synchronize_on_drop: Cell::new(false),
+                retained: RefCell::new(Vec::new()),
+            }),
         }
     }
 }
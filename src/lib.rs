@@ -1,8 +1,11 @@
+#![cfg_attr(feature = "nightly", feature(allocator_api))]
 extern crate core;
 pub mod error;
+pub mod gpu;
 pub mod hip;
 #[cfg(feature = "miopen")]
 pub mod miopen;
+pub mod nn;
 pub mod rocblas;
 pub mod rocfft;
 pub mod rocrand;
@@ -14,6 +17,7 @@ pub mod rocmsmi;
 // mod rocprofiler;
 pub mod rocarray;
 pub mod rocsparse;
+pub mod session;
 
 #[cfg(feature = "macros")]
 pub use rocm_kernel_macros;
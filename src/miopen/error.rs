@@ -1,5 +1,6 @@
 // src/miopen/error.rs
 
+use crate::error::ErrorKind;
 use crate::miopen::ffi;
 use std::error::Error as StdError;
 use std::fmt;
@@ -50,6 +51,20 @@ impl Error {
         self.code
     }
 
+    /// Get the class of failure this error belongs to
+    pub fn kind(&self) -> ErrorKind {
+        match self.code {
+            ffi::miopenStatus_t_miopenStatusNotInitialized => ErrorKind::NotInitialized,
+            ffi::miopenStatus_t_miopenStatusInvalidValue
+            | ffi::miopenStatus_t_miopenStatusBadParm => ErrorKind::InvalidValue,
+            ffi::miopenStatus_t_miopenStatusAllocFailed => ErrorKind::OutOfMemory,
+            ffi::miopenStatus_t_miopenStatusInternalError => ErrorKind::Internal,
+            ffi::miopenStatus_t_miopenStatusNotImplemented
+            | ffi::miopenStatus_t_miopenStatusUnsupportedOp => ErrorKind::NotSupported,
+            _ => ErrorKind::Other,
+        }
+    }
+
     /// Returns the error description as a string
     pub fn description(&self) -> &'static str {
         unsafe {
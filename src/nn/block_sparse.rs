@@ -0,0 +1,479 @@
+// src/nn/block_sparse.rs - Block-sparse matrices described by a block bitmap
+use crate::error::Result;
+use crate::hip::kernel::AsKernelArg;
+use crate::hip::{DeviceMemory, Dim3, Stream, calculate_grid_1d, calculate_grid_2d};
+use crate::kernel_args;
+use crate::nn::get_kernel_function;
+use crate::rocarray::ROCArray;
+
+/// Element types supported by the block-sparse kernels
+pub trait BlockSparseOps: Copy + Default + 'static {
+    const TYPE_NAME: &'static str;
+}
+
+impl BlockSparseOps for f32 {
+    const TYPE_NAME: &'static str = "float";
+}
+
+impl BlockSparseOps for f64 {
+    const TYPE_NAME: &'static str = "double";
+}
+
+/// Which fixed-size blocks of a matrix are present
+///
+/// The matrix is tiled into `block_size` x `block_size` blocks and one bit
+/// per block (row-major) records whether it is stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockSparseLayout {
+    rows: usize,
+    cols: usize,
+    block_size: usize,
+    bitmap: Vec<u64>,
+}
+
+impl BlockSparseLayout {
+    /// Create a layout with no blocks present
+    pub fn new(rows: usize, cols: usize, block_size: usize) -> Result<Self> {
+        if block_size == 0 || !rows.is_multiple_of(block_size) || !cols.is_multiple_of(block_size) {
+            return Err(crate::error::custom_error(format!(
+                "Matrix of {}x{} cannot be tiled into blocks of {}",
+                rows, cols, block_size
+            )));
+        }
+
+        let n_blocks = (rows / block_size) * (cols / block_size);
+        Ok(Self {
+            rows,
+            cols,
+            block_size,
+            bitmap: vec![0; n_blocks.div_ceil(64)],
+        })
+    }
+
+    /// Create a layout with every block present
+    pub fn dense(rows: usize, cols: usize, block_size: usize) -> Result<Self> {
+        let mut layout = Self::new(rows, cols, block_size)?;
+        for block_row in 0..layout.block_rows() {
+            for block_col in 0..layout.block_cols() {
+                layout.set(block_row, block_col, true);
+            }
+        }
+        Ok(layout)
+    }
+
+    /// Create a square layout with the blocks on and below the diagonal
+    /// present, as used by causal attention
+    pub fn causal(n: usize, block_size: usize) -> Result<Self> {
+        let mut layout = Self::new(n, n, block_size)?;
+        for block_row in 0..layout.block_rows() {
+            for block_col in 0..=block_row {
+                layout.set(block_row, block_col, true);
+            }
+        }
+        Ok(layout)
+    }
+
+    /// Create a layout from a row-major element mask
+    ///
+    /// A block is present if any element of the mask inside it is set.
+    pub fn from_dense_mask(
+        mask: &[bool],
+        rows: usize,
+        cols: usize,
+        block_size: usize,
+    ) -> Result<Self> {
+        if mask.len() != rows * cols {
+            return Err(crate::error::custom_error(format!(
+                "Mask length {} doesn't match matrix size {}x{}",
+                mask.len(),
+                rows,
+                cols
+            )));
+        }
+
+        let mut layout = Self::new(rows, cols, block_size)?;
+        for (index, _) in mask.iter().enumerate().filter(|(_, set)| **set) {
+            let (row, col) = (index / cols, index % cols);
+            layout.set(row / block_size, col / block_size, true);
+        }
+        Ok(layout)
+    }
+
+    /// Get the number of rows of the matrix
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Get the number of columns of the matrix
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Get the block edge length
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Get the number of block rows
+    pub fn block_rows(&self) -> usize {
+        self.rows / self.block_size
+    }
+
+    /// Get the number of block columns
+    pub fn block_cols(&self) -> usize {
+        self.cols / self.block_size
+    }
+
+    fn bit(&self, block_row: usize, block_col: usize) -> usize {
+        assert!(
+            block_row < self.block_rows() && block_col < self.block_cols(),
+            "block ({}, {}) out of range for {}x{} blocks",
+            block_row,
+            block_col,
+            self.block_rows(),
+            self.block_cols()
+        );
+        block_row * self.block_cols() + block_col
+    }
+
+    /// Mark a block as present or absent
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block coordinates are out of range.
+    pub fn set(&mut self, block_row: usize, block_col: usize, present: bool) {
+        let bit = self.bit(block_row, block_col);
+        if present {
+            self.bitmap[bit / 64] |= 1 << (bit % 64);
+        } else {
+            self.bitmap[bit / 64] &= !(1 << (bit % 64));
+        }
+    }
+
+    /// Check whether a block is present
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block coordinates are out of range.
+    pub fn is_set(&self, block_row: usize, block_col: usize) -> bool {
+        let bit = self.bit(block_row, block_col);
+        self.bitmap[bit / 64] & (1 << (bit % 64)) != 0
+    }
+
+    /// Get the number of present blocks
+    pub fn nnz_blocks(&self) -> usize {
+        self.bitmap
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Get the fraction of blocks that are present
+    pub fn density(&self) -> f64 {
+        let total = self.block_rows() * self.block_cols();
+        if total == 0 {
+            0.0
+        } else {
+            self.nnz_blocks() as f64 / total as f64
+        }
+    }
+
+    /// Get the raw bitmap, one bit per block in row-major order
+    pub fn bitmap(&self) -> &[u64] {
+        &self.bitmap
+    }
+
+    /// Block coordinates of the present blocks, plus per-block-row offsets
+    fn block_index(&self) -> (Vec<u32>, Vec<u32>, Vec<u32>) {
+        let mut block_rows = Vec::with_capacity(self.nnz_blocks());
+        let mut block_cols = Vec::with_capacity(self.nnz_blocks());
+        let mut row_ptr = Vec::with_capacity(self.block_rows() + 1);
+
+        row_ptr.push(0);
+        for block_row in 0..self.block_rows() {
+            for block_col in 0..self.block_cols() {
+                if self.is_set(block_row, block_col) {
+                    block_rows.push(block_row as u32);
+                    block_cols.push(block_col as u32);
+                }
+            }
+            row_ptr.push(block_cols.len() as u32);
+        }
+
+        (block_rows, block_cols, row_ptr)
+    }
+}
+
+fn upload(data: &[u32]) -> Result<DeviceMemory<u32>> {
+    let mut memory = DeviceMemory::new(data.len())?;
+    memory.copy_from_host(data)?;
+    Ok(memory)
+}
+
+fn matrix_dims<T>(array: &ROCArray<T>, name: &str) -> Result<(usize, usize)>
+where
+    T: BlockSparseOps,
+{
+    match array.dims() {
+        [rows, cols] => Ok((*rows, *cols)),
+        dims => Err(crate::error::custom_error(format!(
+            "{} must be a 2D matrix, got shape {:?}",
+            name, dims
+        ))),
+    }
+}
+
+/// A matrix storing only the blocks present in its layout
+pub struct BlockSparseMatrix<T> {
+    layout: BlockSparseLayout,
+    values: DeviceMemory<T>,
+    block_rows: DeviceMemory<u32>,
+    block_cols: DeviceMemory<u32>,
+    row_ptr: DeviceMemory<u32>,
+}
+
+impl<T> BlockSparseMatrix<T>
+where
+    T: BlockSparseOps,
+{
+    /// Create a matrix with all present blocks zeroed
+    pub fn zeros(layout: BlockSparseLayout) -> Result<Self> {
+        let (block_rows, block_cols, row_ptr) = layout.block_index();
+        let block_elems = layout.block_size * layout.block_size;
+
+        let mut values = DeviceMemory::new(layout.nnz_blocks() * block_elems)?;
+        values.memset(0)?;
+
+        Ok(Self {
+            block_rows: upload(&block_rows)?,
+            block_cols: upload(&block_cols)?,
+            row_ptr: upload(&row_ptr)?,
+            values,
+            layout,
+        })
+    }
+
+    /// Gather the present blocks of a dense matrix
+    pub fn from_dense(dense: &ROCArray<T>, layout: BlockSparseLayout) -> Result<Self> {
+        let (rows, cols) = matrix_dims(dense, "Dense matrix")?;
+        if (rows, cols) != (layout.rows, layout.cols) {
+            return Err(crate::error::custom_error(format!(
+                "Dense matrix of {}x{} doesn't match layout of {}x{}",
+                rows, cols, layout.rows, layout.cols
+            )));
+        }
+
+        let matrix = Self::zeros(layout)?;
+        let total = matrix.values.count() as u32;
+        if total == 0 {
+            return Ok(matrix);
+        }
+
+        let stream = Stream::new()?;
+        let cols_u32 = cols as u32;
+        let block_size = matrix.layout.block_size as u32;
+        let nnz_blocks = matrix.layout.nnz_blocks() as u32;
+
+        let function = get_kernel_function(&format!("block_sparse_from_dense_{}", T::TYPE_NAME))?;
+        function.launch(
+            calculate_grid_1d(total, 256),
+            Dim3::new_1d(256),
+            0,
+            Some(&stream),
+            kernel_args!(
+                dense.device_memory(),
+                cols_u32,
+                matrix.block_rows,
+                matrix.block_cols,
+                block_size,
+                nnz_blocks,
+                matrix.values
+            ),
+        )?;
+        stream.synchronize()?;
+
+        Ok(matrix)
+    }
+
+    /// Compute only the layout's blocks of the product `a * b`
+    ///
+    /// This is the sampled dense-dense product used for sparse attention
+    /// scores: `a` is M x K, `b` is K x N and the layout is M x N.
+    pub fn masked_matmul(
+        a: &ROCArray<T>,
+        b: &ROCArray<T>,
+        layout: BlockSparseLayout,
+    ) -> Result<Self> {
+        let (m, k) = matrix_dims(a, "Left operand")?;
+        let (k_b, n) = matrix_dims(b, "Right operand")?;
+        if k != k_b || (m, n) != (layout.rows, layout.cols) {
+            return Err(crate::error::custom_error(format!(
+                "Cannot compute {}x{} layout from {}x{} * {}x{}",
+                layout.rows, layout.cols, m, k, k_b, n
+            )));
+        }
+
+        let matrix = Self::zeros(layout)?;
+        let total = matrix.values.count() as u32;
+        if total == 0 {
+            return Ok(matrix);
+        }
+
+        let stream = Stream::new()?;
+        let k_u32 = k as u32;
+        let n_u32 = n as u32;
+        let block_size = matrix.layout.block_size as u32;
+        let nnz_blocks = matrix.layout.nnz_blocks() as u32;
+
+        let function = get_kernel_function(&format!("block_sparse_sddmm_{}", T::TYPE_NAME))?;
+        function.launch(
+            calculate_grid_1d(total, 256),
+            Dim3::new_1d(256),
+            0,
+            Some(&stream),
+            kernel_args!(
+                a.device_memory(),
+                b.device_memory(),
+                k_u32,
+                n_u32,
+                matrix.block_rows,
+                matrix.block_cols,
+                block_size,
+                nnz_blocks,
+                matrix.values
+            ),
+        )?;
+        stream.synchronize()?;
+
+        Ok(matrix)
+    }
+
+    /// Multiply by a dense K x N matrix, producing a dense M x N matrix
+    pub fn matmul(&self, b: &ROCArray<T>) -> Result<ROCArray<T>> {
+        let (k, n) = matrix_dims(b, "Right operand")?;
+        if k != self.layout.cols {
+            return Err(crate::error::custom_error(format!(
+                "Cannot multiply {}x{} block-sparse matrix by {}x{}",
+                self.layout.rows, self.layout.cols, k, n
+            )));
+        }
+
+        let m = self.layout.rows;
+        let result = ROCArray::new_2d(m, n)?;
+        if m == 0 || n == 0 {
+            return Ok(result);
+        }
+
+        let stream = Stream::new()?;
+        let m_u32 = m as u32;
+        let n_u32 = n as u32;
+        let block_size = self.layout.block_size as u32;
+
+        let function = get_kernel_function(&format!("block_sparse_matmul_{}", T::TYPE_NAME))?;
+        function.launch(
+            calculate_grid_2d(n_u32, m_u32, 16, 16),
+            Dim3::new_2d(16, 16),
+            0,
+            Some(&stream),
+            kernel_args!(
+                self.values,
+                self.row_ptr,
+                self.block_cols,
+                block_size,
+                b.device_memory(),
+                result.device_memory(),
+                m_u32,
+                n_u32
+            ),
+        )?;
+        stream.synchronize()?;
+
+        Ok(result)
+    }
+
+    /// Expand to a dense matrix with absent blocks set to zero
+    pub fn to_dense(&self) -> Result<ROCArray<T>> {
+        let dense = ROCArray::zeros(crate::rocarray::Shape::new_2d(
+            self.layout.rows,
+            self.layout.cols,
+        ))?;
+        let total = self.values.count() as u32;
+        if total == 0 {
+            return Ok(dense);
+        }
+
+        let stream = Stream::new()?;
+        let block_size = self.layout.block_size as u32;
+        let nnz_blocks = self.layout.nnz_blocks() as u32;
+        let cols_u32 = self.layout.cols as u32;
+
+        let function = get_kernel_function(&format!("block_sparse_to_dense_{}", T::TYPE_NAME))?;
+        function.launch(
+            calculate_grid_1d(total, 256),
+            Dim3::new_1d(256),
+            0,
+            Some(&stream),
+            kernel_args!(
+                self.values,
+                self.block_rows,
+                self.block_cols,
+                block_size,
+                nnz_blocks,
+                cols_u32,
+                dense.device_memory()
+            ),
+        )?;
+        stream.synchronize()?;
+
+        Ok(dense)
+    }
+
+    /// Get the layout of this matrix
+    pub fn layout(&self) -> &BlockSparseLayout {
+        &self.layout
+    }
+
+    /// Get the stored block values, row-major within each block
+    pub fn values(&self) -> &DeviceMemory<T> {
+        &self.values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_from_dense_mask() -> Result<()> {
+        let mut mask = vec![false; 4 * 4];
+        mask[1] = true; // block (0, 0)
+        mask[2 * 4 + 3] = true; // block (1, 1)
+
+        let layout = BlockSparseLayout::from_dense_mask(&mask, 4, 4, 2)?;
+        assert!(layout.is_set(0, 0));
+        assert!(!layout.is_set(0, 1));
+        assert!(!layout.is_set(1, 0));
+        assert!(layout.is_set(1, 1));
+        assert_eq!(layout.nnz_blocks(), 2);
+        assert_eq!(layout.density(), 0.5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_causal_layout() -> Result<()> {
+        let layout = BlockSparseLayout::causal(8, 2)?;
+        assert_eq!(layout.nnz_blocks(), 10);
+        assert!(layout.is_set(3, 0));
+        assert!(!layout.is_set(0, 3));
+
+        let (_, block_cols, row_ptr) = layout.block_index();
+        assert_eq!(row_ptr, vec![0, 1, 3, 6, 10]);
+        assert_eq!(&block_cols[1..3], &[0, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_layout_rejects_uneven_blocks() {
+        assert!(BlockSparseLayout::new(5, 4, 2).is_err());
+    }
+}
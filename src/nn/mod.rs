@@ -0,0 +1,21 @@
+// src/nn/mod.rs - Neural network building blocks on top of ROCArray
+
+use crate::error::Result;
+use crate::hip::Function;
+use crate::hip::device_modules::DeviceModules;
+
+pub mod block_sparse;
+pub mod paged;
+#[cfg(feature = "half")]
+pub mod quant;
+
+pub use block_sparse::{BlockSparseLayout, BlockSparseMatrix, BlockSparseOps};
+pub use paged::{PagedBuffer, PagedOps, SequenceId};
+#[cfg(feature = "half")]
+pub use quant::{QuantBits, QuantizedMatrix, quant_gemv, quant_gemv_async};
+
+static NN_KERNELS: DeviceModules = DeviceModules::new(include_str!("kernels.hip"));
+
+pub(crate) fn get_kernel_function(name: &str) -> Result<Function> {
+    NN_KERNELS.function(name)
+}
@@ -0,0 +1,385 @@
+// src/nn/paged.rs - Paged storage for variable-length sequence batches
+use crate::error::Result;
+use crate::hip::kernel::AsKernelArg;
+use crate::hip::{DeviceMemory, Dim3, Stream, calculate_grid_1d};
+use crate::kernel_args;
+use crate::nn::get_kernel_function;
+use crate::rocarray::{ROCArray, Shape};
+use std::collections::HashMap;
+
+/// Page table entry for pages a sequence doesn't have
+pub const NO_PAGE: u32 = u32::MAX;
+
+/// Element types supported by the paged buffer kernels
+pub trait PagedOps: Copy + Default + 'static {
+    const TYPE_NAME: &'static str;
+}
+
+impl PagedOps for f32 {
+    const TYPE_NAME: &'static str = "float";
+}
+
+impl PagedOps for f64 {
+    const TYPE_NAME: &'static str = "double";
+}
+
+impl PagedOps for i32 {
+    const TYPE_NAME: &'static str = "int";
+}
+
+impl PagedOps for u32 {
+    const TYPE_NAME: &'static str = "uint";
+}
+
+/// Handle to a sequence stored in a [`PagedBuffer`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SequenceId(usize);
+
+#[derive(Debug, Default)]
+struct Sequence {
+    pages: Vec<u32>,
+    len: usize,
+}
+
+/// Device storage for many growing sequences, allocated in fixed-size pages
+///
+/// Each sequence is a list of rows of `row_len` elements (one token's keys
+/// or values, say). Rows are stored in pages of `page_size` rows taken from
+/// one preallocated pool, so sequences can grow and be released without
+/// device allocations, and a page table maps each sequence's logical rows
+/// to physical pages as in paged attention.
+pub struct PagedBuffer<T> {
+    storage: DeviceMemory<T>,
+    page_size: usize,
+    row_len: usize,
+    num_pages: usize,
+    free_pages: Vec<u32>,
+    sequences: HashMap<SequenceId, Sequence>,
+    next_id: usize,
+}
+
+impl<T> PagedBuffer<T>
+where
+    T: PagedOps,
+{
+    /// Allocate a pool of `num_pages` pages of `page_size` rows of `row_len`
+    /// elements
+    pub fn new(num_pages: usize, page_size: usize, row_len: usize) -> Result<Self> {
+        if page_size == 0 || row_len == 0 {
+            return Err(crate::error::custom_error(format!(
+                "Page size ({}) and row length ({}) must be non-zero",
+                page_size, row_len
+            )));
+        }
+        if num_pages >= NO_PAGE as usize {
+            return Err(crate::error::custom_error(format!(
+                "Too many pages: {}",
+                num_pages
+            )));
+        }
+
+        Ok(Self {
+            storage: DeviceMemory::new(num_pages * page_size * row_len)?,
+            page_size,
+            row_len,
+            num_pages,
+            // Hand out low page numbers first
+            free_pages: (0..num_pages as u32).rev().collect(),
+            sequences: HashMap::new(),
+            next_id: 0,
+        })
+    }
+
+    /// Start a new empty sequence
+    pub fn add_sequence(&mut self) -> SequenceId {
+        let id = SequenceId(self.next_id);
+        self.next_id += 1;
+        self.sequences.insert(id, Sequence::default());
+        id
+    }
+
+    /// Release a sequence, returning its pages to the pool
+    pub fn release(&mut self, seq: SequenceId) -> Result<()> {
+        let sequence = self
+            .sequences
+            .remove(&seq)
+            .ok_or_else(|| unknown_sequence(seq))?;
+        self.free_pages.extend(sequence.pages.into_iter().rev());
+        Ok(())
+    }
+
+    /// Make sure a sequence has pages for `additional` more rows
+    ///
+    /// Fails without allocating anything if the pool runs out of pages.
+    pub fn reserve(&mut self, seq: SequenceId, additional: usize) -> Result<()> {
+        let page_size = self.page_size;
+        let sequence = self
+            .sequences
+            .get_mut(&seq)
+            .ok_or_else(|| unknown_sequence(seq))?;
+
+        let needed = (sequence.len + additional).div_ceil(page_size);
+        let missing = needed.saturating_sub(sequence.pages.len());
+        if missing > self.free_pages.len() {
+            return Err(crate::error::custom_error(format!(
+                "Paged buffer out of pages: need {}, {} free",
+                missing,
+                self.free_pages.len()
+            )));
+        }
+
+        let start = self.free_pages.len() - missing;
+        sequence.pages.extend(self.free_pages.drain(start..).rev());
+        Ok(())
+    }
+
+    /// Append rows to a sequence
+    ///
+    /// `rows` holds a whole number of rows, e.g. an `n x row_len` matrix.
+    pub fn append(&mut self, seq: SequenceId, rows: &ROCArray<T>) -> Result<()> {
+        if !rows.len().is_multiple_of(self.row_len) {
+            return Err(crate::error::custom_error(format!(
+                "Cannot append {} elements as rows of {}",
+                rows.len(),
+                self.row_len
+            )));
+        }
+
+        let n_rows = rows.len() / self.row_len;
+        if n_rows == 0 {
+            return Ok(());
+        }
+
+        self.reserve(seq, n_rows)?;
+        let sequence = &self.sequences[&seq];
+        let pages = upload(&sequence.pages)?;
+
+        let stream = Stream::new()?;
+        let n_rows_u32 = n_rows as u32;
+        let start_row = sequence.len as u32;
+        let page_size = self.page_size as u32;
+        let row_len = self.row_len as u32;
+
+        let function = get_kernel_function(&format!("paged_write_{}", T::TYPE_NAME))?;
+        function.launch(
+            calculate_grid_1d((n_rows * self.row_len) as u32, 256),
+            Dim3::new_1d(256),
+            0,
+            Some(&stream),
+            kernel_args!(
+                rows.device_memory(),
+                n_rows_u32,
+                start_row,
+                pages,
+                page_size,
+                row_len,
+                self.storage
+            ),
+        )?;
+        stream.synchronize()?;
+
+        if let Some(sequence) = self.sequences.get_mut(&seq) {
+            sequence.len += n_rows;
+        }
+        Ok(())
+    }
+
+    /// Copy a sequence's rows into a contiguous `len x row_len` array
+    pub fn read(&self, seq: SequenceId) -> Result<ROCArray<T>> {
+        let sequence = self.sequence(seq)?;
+        let result = ROCArray::new_2d(sequence.len, self.row_len)?;
+        if sequence.len == 0 {
+            return Ok(result);
+        }
+
+        let pages = upload(&sequence.pages)?;
+        let stream = Stream::new()?;
+        let page_size = self.page_size as u32;
+        let row_len = self.row_len as u32;
+        let n_rows = sequence.len as u32;
+
+        let function = get_kernel_function(&format!("paged_read_{}", T::TYPE_NAME))?;
+        function.launch(
+            calculate_grid_1d(n_rows * row_len, 256),
+            Dim3::new_1d(256),
+            0,
+            Some(&stream),
+            kernel_args!(
+                self.storage,
+                pages,
+                page_size,
+                row_len,
+                n_rows,
+                result.device_memory()
+            ),
+        )?;
+        stream.synchronize()?;
+
+        Ok(result)
+    }
+
+    /// Build the page table of a batch of sequences
+    ///
+    /// The result is `seqs.len() x max_pages`, with [`NO_PAGE`] past the end
+    /// of shorter sequences.
+    pub fn page_table(&self, seqs: &[SequenceId]) -> Result<ROCArray<u32>> {
+        let (table, max_pages) = self.page_table_host(seqs)?;
+        ROCArray::from_vec_with_shape(table, Shape::new_2d(seqs.len(), max_pages))
+    }
+
+    /// Gather a batch of sequences into a zero-padded
+    /// `seqs.len() x max_len x row_len` array
+    pub fn gather_batch(&self, seqs: &[SequenceId]) -> Result<ROCArray<T>> {
+        let lengths = seqs
+            .iter()
+            .map(|&seq| self.sequence(seq).map(|s| s.len as u32))
+            .collect::<Result<Vec<_>>>()?;
+        let max_len = lengths.iter().copied().max().unwrap_or(0) as usize;
+
+        let result = ROCArray::new_3d(seqs.len(), max_len, self.row_len)?;
+        if result.is_empty() {
+            return Ok(result);
+        }
+
+        let page_table = self.page_table(seqs)?;
+        let lengths = upload(&lengths)?;
+
+        let stream = Stream::new()?;
+        let max_pages = page_table.dims()[1] as u32;
+        let page_size = self.page_size as u32;
+        let row_len = self.row_len as u32;
+        let max_len_u32 = max_len as u32;
+        let n_seqs = seqs.len() as u32;
+
+        let function = get_kernel_function(&format!("paged_gather_batch_{}", T::TYPE_NAME))?;
+        function.launch(
+            calculate_grid_1d(result.len() as u32, 256),
+            Dim3::new_1d(256),
+            0,
+            Some(&stream),
+            kernel_args!(
+                self.storage,
+                page_table.device_memory(),
+                max_pages,
+                lengths,
+                page_size,
+                row_len,
+                max_len_u32,
+                n_seqs,
+                result.device_memory()
+            ),
+        )?;
+        stream.synchronize()?;
+
+        Ok(result)
+    }
+
+    /// Get the number of rows in a sequence
+    pub fn seq_len(&self, seq: SequenceId) -> Result<usize> {
+        Ok(self.sequence(seq)?.len)
+    }
+
+    /// Get the physical pages of a sequence, in logical order
+    pub fn pages(&self, seq: SequenceId) -> Result<&[u32]> {
+        Ok(&self.sequence(seq)?.pages)
+    }
+
+    /// Get the number of unused pages
+    pub fn free_pages(&self) -> usize {
+        self.free_pages.len()
+    }
+
+    /// Get the total number of pages
+    pub fn num_pages(&self) -> usize {
+        self.num_pages
+    }
+
+    /// Get the number of rows per page
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Get the number of elements per row
+    pub fn row_len(&self) -> usize {
+        self.row_len
+    }
+
+    /// Get the page pool, `num_pages x page_size x row_len` elements
+    pub fn storage(&self) -> &DeviceMemory<T> {
+        &self.storage
+    }
+
+    fn sequence(&self, seq: SequenceId) -> Result<&Sequence> {
+        self.sequences
+            .get(&seq)
+            .ok_or_else(|| unknown_sequence(seq))
+    }
+
+    fn page_table_host(&self, seqs: &[SequenceId]) -> Result<(Vec<u32>, usize)> {
+        let sequences = seqs
+            .iter()
+            .map(|&seq| self.sequence(seq))
+            .collect::<Result<Vec<_>>>()?;
+        let max_pages = sequences.iter().map(|s| s.pages.len()).max().unwrap_or(0);
+
+        let mut table = vec![NO_PAGE; seqs.len() * max_pages];
+        for (row, sequence) in sequences.iter().enumerate() {
+            table[row * max_pages..row * max_pages + sequence.pages.len()]
+                .copy_from_slice(&sequence.pages);
+        }
+        Ok((table, max_pages))
+    }
+}
+
+fn unknown_sequence(seq: SequenceId) -> crate::error::Error {
+    crate::error::custom_error(format!("Unknown sequence {:?}", seq))
+}
+
+fn upload(data: &[u32]) -> Result<DeviceMemory<u32>> {
+    let mut memory = DeviceMemory::new(data.len())?;
+    memory.copy_from_host(data)?;
+    Ok(memory)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paged_append_and_gather() -> Result<()> {
+        let mut buffer = PagedBuffer::<f32>::new(8, 2, 3)?;
+        let a = buffer.add_sequence();
+        let b = buffer.add_sequence();
+
+        buffer.append(a, &ROCArray::from_vec((0..9).map(|i| i as f32).collect())?)?;
+        buffer.append(b, &ROCArray::from_vec(vec![-1.0; 3])?)?;
+        buffer.append(a, &ROCArray::from_vec(vec![9.0, 10.0, 11.0])?)?;
+
+        assert_eq!(buffer.seq_len(a)?, 4);
+        assert_eq!(buffer.pages(a)?.len(), 2);
+        assert_eq!(buffer.free_pages(), 5);
+
+        let expected: Vec<f32> = (0..12).map(|i| i as f32).collect();
+        assert_eq!(buffer.read(a)?.to_vec()?, expected);
+
+        let batch = buffer.gather_batch(&[b, a])?;
+        assert_eq!(batch.dims(), &[2, 4, 3]);
+        let batch = batch.to_vec()?;
+        assert_eq!(&batch[..3], &[-1.0; 3]);
+        assert_eq!(&batch[3..12], &[0.0; 9]);
+        assert_eq!(&batch[12..], &expected[..]);
+
+        buffer.release(a)?;
+        assert_eq!(buffer.free_pages(), 7);
+        Ok(())
+    }
+
+    #[test]
+    fn test_paged_out_of_pages() -> Result<()> {
+        let mut buffer = PagedBuffer::<f32>::new(1, 2, 1)?;
+        let seq = buffer.add_sequence();
+        assert!(buffer.reserve(seq, 3).is_err());
+        assert_eq!(buffer.free_pages(), 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,285 @@
+// src/nn/quant.rs - Matrix-vector products with int4/int8 quantized weights
+use half::f16;
+
+use crate::error::Result;
+use crate::hip::kernel::AsKernelArg;
+use crate::hip::{DeviceMemory, Dim3, Stream};
+use crate::kernel_args;
+use crate::nn::get_kernel_function;
+
+// Must match QUANT_GEMV_BLOCK_SIZE in kernels.hip
+const QUANT_GEMV_BLOCK_SIZE: u32 = 256;
+
+/// Width of the quantized weights
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantBits {
+    /// Signed 4-bit values, two per byte with the low nibble first
+    Int4,
+    /// Signed 8-bit values
+    Int8,
+}
+
+impl QuantBits {
+    fn max_value(self) -> f32 {
+        match self {
+            QuantBits::Int4 => 7.0,
+            QuantBits::Int8 => 127.0,
+        }
+    }
+
+    /// Bytes used by a row of `cols` values
+    pub fn row_bytes(self, cols: usize) -> usize {
+        match self {
+            QuantBits::Int4 => cols.div_ceil(2),
+            QuantBits::Int8 => cols,
+        }
+    }
+}
+
+/// Quantize row-major weights symmetrically, one scale per group
+///
+/// Returns the packed weights and the scales.
+fn quantize_host(
+    weights: &[f32],
+    rows: usize,
+    cols: usize,
+    bits: QuantBits,
+    group_size: usize,
+) -> (Vec<u8>, Vec<f16>) {
+    let groups = cols.div_ceil(group_size);
+    let row_bytes = bits.row_bytes(cols);
+    let max_value = bits.max_value();
+
+    let mut packed = vec![0u8; rows * row_bytes];
+    let mut scales = vec![f16::ZERO; rows * groups];
+
+    for row in 0..rows {
+        let row_weights = &weights[row * cols..(row + 1) * cols];
+        for (group, chunk) in row_weights.chunks(group_size).enumerate() {
+            let max_abs = chunk.iter().fold(0.0f32, |acc, w| acc.max(w.abs()));
+            let half_scale = f16::from_f32(max_abs / max_value);
+            scales[row * groups + group] = half_scale;
+
+            // Quantize against the scale the kernel will actually see
+            let scale = half_scale.to_f32();
+            for (i, weight) in chunk.iter().enumerate() {
+                let q = if scale > 0.0 {
+                    (weight / scale).round().clamp(-max_value, max_value) as i8
+                } else {
+                    0
+                };
+
+                let col = group * group_size + i;
+                match bits {
+                    QuantBits::Int8 => packed[row * row_bytes + col] = q as u8,
+                    QuantBits::Int4 => {
+                        let nibble = (q as u8) & 0xf;
+                        let byte = &mut packed[row * row_bytes + col / 2];
+                        if col.is_multiple_of(2) {
+                            *byte |= nibble;
+                        } else {
+                            *byte |= nibble << 4;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    (packed, scales)
+}
+
+/// A weight matrix quantized to int4 or int8 with group-wise f16 scales
+pub struct QuantizedMatrix {
+    rows: usize,
+    cols: usize,
+    bits: QuantBits,
+    group_size: usize,
+    weights: DeviceMemory<u8>,
+    scales: DeviceMemory<f16>,
+}
+
+impl QuantizedMatrix {
+    /// Quantize a row-major `rows` x `cols` matrix
+    ///
+    /// Each group of `group_size` consecutive columns of a row gets its own
+    /// scale, chosen so the group's largest magnitude maps to the largest
+    /// quantized value.
+    pub fn quantize(
+        weights: &[f32],
+        rows: usize,
+        cols: usize,
+        bits: QuantBits,
+        group_size: usize,
+    ) -> Result<Self> {
+        if weights.len() != rows * cols {
+            return Err(crate::error::custom_error(format!(
+                "Weights length {} doesn't match matrix size {}x{}",
+                weights.len(),
+                rows,
+                cols
+            )));
+        }
+        if group_size == 0 {
+            return Err(crate::error::custom_error(
+                "Group size must be non-zero".to_string(),
+            ));
+        }
+
+        let (packed, scales) = quantize_host(weights, rows, cols, bits, group_size);
+        Self::from_packed(rows, cols, bits, group_size, &packed, &scales)
+    }
+
+    /// Upload already quantized weights
+    ///
+    /// `weights` holds `bits.row_bytes(cols)` bytes per row and `scales`
+    /// holds `cols.div_ceil(group_size)` scales per row.
+    pub fn from_packed(
+        rows: usize,
+        cols: usize,
+        bits: QuantBits,
+        group_size: usize,
+        weights: &[u8],
+        scales: &[f16],
+    ) -> Result<Self> {
+        if group_size == 0 {
+            return Err(crate::error::custom_error(
+                "Group size must be non-zero".to_string(),
+            ));
+        }
+        if weights.len() != rows * bits.row_bytes(cols) {
+            return Err(crate::error::custom_error(format!(
+                "Expected {} bytes of packed weights, got {}",
+                rows * bits.row_bytes(cols),
+                weights.len()
+            )));
+        }
+        if scales.len() != rows * cols.div_ceil(group_size) {
+            return Err(crate::error::custom_error(format!(
+                "Expected {} scales, got {}",
+                rows * cols.div_ceil(group_size),
+                scales.len()
+            )));
+        }
+
+        let mut device_weights = DeviceMemory::new(weights.len())?;
+        device_weights.copy_from_host(weights)?;
+        let mut device_scales = DeviceMemory::new(scales.len())?;
+        device_scales.copy_from_host(scales)?;
+
+        Ok(Self {
+            rows,
+            cols,
+            bits,
+            group_size,
+            weights: device_weights,
+            scales: device_scales,
+        })
+    }
+
+    /// Get the number of rows
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Get the number of columns
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Get the width of the quantized weights
+    pub fn bits(&self) -> QuantBits {
+        self.bits
+    }
+
+    /// Get the number of columns sharing a scale
+    pub fn group_size(&self) -> usize {
+        self.group_size
+    }
+}
+
+/// Compute `W x` for quantized weights and f16 activations
+///
+/// `x` holds `cols` values and the result holds `rows`. Weights are
+/// dequantized in registers and products accumulate in f32.
+pub fn quant_gemv(matrix: &QuantizedMatrix, x: &DeviceMemory<f16>) -> Result<DeviceMemory<f16>> {
+    let stream = Stream::new()?;
+    let y = quant_gemv_async(matrix, x, &stream)?;
+    stream.synchronize()?;
+    Ok(y)
+}
+
+/// Queue `W x` on `stream` without waiting for it
+///
+/// Like [`quant_gemv`], but returns as soon as the kernel is queued. The
+/// result is only written once `stream` reaches the kernel, so synchronize
+/// `stream` or order later work after it before reading the result.
+pub fn quant_gemv_async(
+    matrix: &QuantizedMatrix,
+    x: &DeviceMemory<f16>,
+    stream: &Stream,
+) -> Result<DeviceMemory<f16>> {
+    if x.count() != matrix.cols {
+        return Err(crate::error::custom_error(format!(
+            "Activation length {} doesn't match {} weight columns",
+            x.count(),
+            matrix.cols
+        )));
+    }
+
+    let y = DeviceMemory::<f16>::new(matrix.rows)?;
+    if matrix.rows == 0 {
+        return Ok(y);
+    }
+
+    let name = match matrix.bits {
+        QuantBits::Int4 => "quant_gemv_int4",
+        QuantBits::Int8 => "quant_gemv_int8",
+    };
+    let rows_u32 = matrix.rows as u32;
+    let cols_u32 = matrix.cols as u32;
+    let group_u32 = matrix.group_size as u32;
+
+    let function = get_kernel_function(name)?;
+    function.launch(
+        Dim3::new_1d(rows_u32),
+        Dim3::new_1d(QUANT_GEMV_BLOCK_SIZE),
+        0,
+        Some(stream),
+        kernel_args!(
+            matrix.weights,
+            matrix.scales,
+            x,
+            y,
+            rows_u32,
+            cols_u32,
+            group_u32
+        ),
+    )?;
+
+    Ok(y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_int4_packing() {
+        let weights = [1.0f32, -1.0, 7.0, -7.0];
+        let (packed, scales) = quantize_host(&weights, 1, 4, QuantBits::Int4, 4);
+        assert_eq!(scales[0].to_f32(), 1.0);
+        assert_eq!(packed, vec![0xf1, 0x97]);
+    }
+
+    #[test]
+    fn test_int8_group_scales() {
+        let weights = [127.0f32, -63.5, 0.0, 0.0];
+        let (packed, scales) = quantize_host(&weights, 1, 4, QuantBits::Int8, 2);
+        assert_eq!(scales[0].to_f32(), 1.0);
+        assert_eq!(scales[1], f16::ZERO);
+        assert_eq!(packed[0] as i8, 127);
+        assert_eq!(packed[1] as i8, -64);
+        assert_eq!(&packed[2..], &[0, 0]);
+    }
+}
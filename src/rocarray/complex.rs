@@ -0,0 +1,142 @@
+// src/rocarray/complex.rs - Complex-valued ROCArrays and interop with rocBLAS types
+
+use crate::error::{Result, invalid_argument};
+use crate::hip::DeviceMemory;
+use crate::rocarray::kernels::{self, ComplexOps};
+use crate::rocarray::{ROCArray, Shape};
+use crate::rocblas::ffi::{rocblas_double_complex, rocblas_float_complex};
+use num_complex::{Complex32, Complex64};
+use std::marker::PhantomData;
+use std::mem::size_of;
+
+impl<T> ROCArray<T>
+where
+    T: ComplexOps,
+{
+    /// Element-wise complex conjugate
+    pub fn conj(&self) -> Result<ROCArray<T>> {
+        let result = ROCArray::new(self.shape.clone())?;
+        kernels::complex_conj(&self.data, &result.data, self.len())?;
+        Ok(result)
+    }
+}
+
+impl<T> ROCArray<T> {
+    // Callers guarantee that T and U have the same size and layout
+    fn reinterpret<U>(self) -> ROCArray<U> {
+        debug_assert_eq!(size_of::<T>(), size_of::<U>());
+        ROCArray {
+            data: unsafe { self.data.cast() },
+            shape: self.shape,
+            capacity: self.capacity,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+macro_rules! impl_rocblas_complex {
+    ($complex:ty, $rocblas:ty) => {
+        impl From<$complex> for $rocblas {
+            fn from(value: $complex) -> Self {
+                Self {
+                    x: value.re,
+                    y: value.im,
+                }
+            }
+        }
+
+        impl From<$rocblas> for $complex {
+            fn from(value: $rocblas) -> Self {
+                Self::new(value.x, value.y)
+            }
+        }
+
+        impl ROCArray<$complex> {
+            /// Hand the elements to rocBLAS without copying
+            ///
+            /// The returned buffer holds the array's `len()` elements
+            /// followed by any spare capacity.
+            pub fn into_rocblas(self) -> DeviceMemory<$rocblas> {
+                self.reinterpret::<$rocblas>().data
+            }
+
+            /// Wrap a rocBLAS buffer as an array of the given shape without
+            /// copying; the buffer must hold at least `shape.size()` elements
+            pub fn from_rocblas(memory: DeviceMemory<$rocblas>, shape: Shape) -> Result<Self> {
+                let capacity = memory.count();
+                if capacity < shape.size() {
+                    return Err(invalid_argument(format!(
+                        "Buffer of {} elements is too small for shape {:?}",
+                        capacity,
+                        shape.dims()
+                    )));
+                }
+                let array = ROCArray {
+                    data: memory,
+                    shape,
+                    capacity,
+                    _phantom: PhantomData::<$rocblas>,
+                };
+                Ok(array.reinterpret())
+            }
+        }
+    };
+}
+
+impl_rocblas_complex!(Complex32, rocblas_float_complex);
+impl_rocblas_complex!(Complex64, rocblas_double_complex);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c(re: f32, im: f32) -> Complex32 {
+        Complex32::new(re, im)
+    }
+
+    #[test]
+    fn test_complex_arithmetic() -> Result<()> {
+        let a = ROCArray::from_vec(vec![c(1.0, 2.0), c(3.0, -1.0)])?;
+        let b = ROCArray::from_vec(vec![c(0.5, 0.0), c(1.0, 1.0)])?;
+
+        assert_eq!(a.add(&b)?.to_vec()?, vec![c(1.5, 2.0), c(4.0, 0.0)]);
+        assert_eq!(a.sub(&b)?.to_vec()?, vec![c(0.5, 2.0), c(2.0, -2.0)]);
+        assert_eq!(a.mul(&b)?.to_vec()?, vec![c(0.5, 1.0), c(4.0, 2.0)]);
+        assert_eq!(a.div(&b)?.to_vec()?, vec![c(2.0, 4.0), c(1.0, -2.0)]);
+        assert_eq!(
+            a.mul_scalar(c(0.0, 1.0))?.to_vec()?,
+            vec![c(-2.0, 1.0), c(1.0, 3.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_complex_abs_and_conj() -> Result<()> {
+        let a = ROCArray::from_vec(vec![Complex64::new(3.0, 4.0), Complex64::new(0.0, -2.0)])?;
+        assert_eq!(a.abs()?.to_vec()?, vec![5.0, 2.0]);
+        assert_eq!(
+            a.conj()?.to_vec()?,
+            vec![Complex64::new(3.0, -4.0), Complex64::new(0.0, 2.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rocblas_round_trip() -> Result<()> {
+        let scalar: rocblas_float_complex = c(1.0, -1.0).into();
+        assert_eq!((scalar.x, scalar.y), (1.0, -1.0));
+        assert_eq!(Complex32::from(scalar), c(1.0, -1.0));
+
+        let a = ROCArray::from_vec_with_shape(
+            vec![c(1.0, 0.0), c(0.0, 1.0), c(2.0, 2.0), c(-1.0, 0.5)],
+            Shape::new_2d(2, 2),
+        )?;
+        let memory = a.into_rocblas();
+        assert_eq!(memory.count(), 4);
+
+        let b = ROCArray::<Complex32>::from_rocblas(memory, Shape::new_2d(2, 2))?;
+        assert_eq!(b.to_vec()?[2], c(2.0, 2.0));
+        assert!(ROCArray::<Complex32>::from_rocblas(b.into_rocblas(), Shape::new_1d(5)).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,188 @@
+// src/rocarray/context.rs - Device and stream that ROCArray operations are queued on
+
+use crate::error::Result;
+use crate::hip::{Device, Event, Stream};
+use std::cell::RefCell;
+use std::ops::Deref;
+
+thread_local! {
+    static ACTIVE: RefCell<Vec<Stream>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A device and stream for running `ROCArray` operations asynchronously
+///
+/// Outside a context every operation launches on a fresh stream and waits
+/// for it before returning. Inside [`Context::run`], operations on the
+/// calling thread are queued on the context's stream instead and return as
+/// soon as they are launched, so consecutive kernels pipeline without host
+/// round trips. Operations that return host values (such as `sum` or
+/// `to_vec`) still wait for the work they depend on.
+///
+/// ```ignore
+/// let ctx = Context::new(Device::new(0)?)?;
+/// let (c, done) = ctx.run(|| a.add(&b)?.mul_scalar(2.0))?;
+/// done.synchronize()?;
+/// ```
+#[derive(Clone)]
+pub struct Context {
+    device: Device,
+    stream: Stream,
+}
+
+impl Context {
+    /// Create a context with a new stream on `device`
+    pub fn new(device: Device) -> Result<Self> {
+        let current = Device::current()?;
+        device.set_current()?;
+        let stream = Stream::new();
+        current.set_current()?;
+        Ok(Self {
+            device,
+            stream: stream?,
+        })
+    }
+
+    /// Create a context with a new stream on the current device
+    pub fn current() -> Result<Self> {
+        Ok(Self {
+            device: Device::current()?,
+            stream: Stream::new()?,
+        })
+    }
+
+    /// Use an existing stream, which must belong to `device`
+    pub fn with_stream(device: Device, stream: Stream) -> Self {
+        Self { device, stream }
+    }
+
+    /// The device operations run on
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+
+    /// The stream operations are queued on
+    pub fn stream(&self) -> &Stream {
+        &self.stream
+    }
+
+    /// Run `f` with this context active on the calling thread
+    ///
+    /// The context's device is made current for the duration of `f`, and
+    /// every `ROCArray` operation inside it is queued on the context's
+    /// stream. Returns `f`'s result with an event that completes once the
+    /// queued work has finished. Contexts nest; the innermost one wins.
+    pub fn run<R>(&self, f: impl FnOnce() -> Result<R>) -> Result<(R, Event)> {
+        let current = Device::current()?;
+        self.device.set_current()?;
+        let result = {
+            let _guard = ActiveGuard::push(self.stream.clone());
+            f()
+        };
+        let event = result.and_then(|value| Ok((value, self.record()?)));
+        current.set_current()?;
+        event
+    }
+
+    /// Record an event after the work queued so far
+    pub fn record(&self) -> Result<Event> {
+        let event = Event::new()?;
+        event.record(&self.stream)?;
+        Ok(event)
+    }
+
+    /// Wait for all work queued on the context
+    pub fn synchronize(&self) -> Result<()> {
+        Ok(self.stream.synchronize()?)
+    }
+}
+
+// Pops the context again even if the closure panics
+struct ActiveGuard;
+
+impl ActiveGuard {
+    fn push(stream: Stream) -> Self {
+        ACTIVE.with(|active| active.borrow_mut().push(stream));
+        ActiveGuard
+    }
+}
+
+impl Drop for ActiveGuard {
+    fn drop(&mut self) {
+        ACTIVE.with(|active| active.borrow_mut().pop());
+    }
+}
+
+/// Stream for a single operation
+///
+/// This is the active context's stream if there is one, or else a fresh
+/// stream that is synchronized when dropped.
+pub(crate) struct OpStream {
+    stream: Stream,
+    owned: bool,
+}
+
+impl OpStream {
+    /// Wait for the operation unless it runs in a context
+    pub(crate) fn finish(self) -> Result<()> {
+        if self.owned {
+            self.stream.synchronize()?;
+        }
+        Ok(())
+    }
+}
+
+impl Deref for OpStream {
+    type Target = Stream;
+
+    fn deref(&self) -> &Stream {
+        &self.stream
+    }
+}
+
+pub(crate) fn op_stream() -> Result<OpStream> {
+    match ACTIVE.with(|active| active.borrow().last().cloned()) {
+        Some(stream) => Ok(OpStream {
+            stream,
+            owned: false,
+        }),
+        None => Ok(OpStream {
+            stream: Stream::new()?,
+            owned: true,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rocarray::ROCArray;
+
+    #[test]
+    fn test_context_pipelines_operations() -> Result<()> {
+        let a = ROCArray::from_vec(vec![1.0f32, 2.0, 3.0, 4.0])?;
+        let b = ROCArray::from_vec(vec![4.0f32, 3.0, 2.0, 1.0])?;
+        let ctx = Context::current()?;
+
+        let (c, done) = ctx.run(|| a.add(&b)?.mul_scalar(2.0)?.sub(&a))?;
+        done.synchronize()?;
+        assert_eq!(c.to_vec()?, vec![9.0, 8.0, 7.0, 6.0]);
+
+        // Host results wait for the context's work
+        let (sum, _) = ctx.run(|| a.mul(&b)?.sum())?;
+        assert_eq!(sum, 20.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_context_is_scoped() -> Result<()> {
+        let ctx = Context::current()?;
+        let (inside, _) = ctx.run(|| Ok(op_stream()?.owned))?;
+        assert!(!inside);
+        assert!(op_stream()?.owned);
+
+        let failed = ctx.run(|| -> Result<()> { Err(crate::error::invalid_argument("stop")) });
+        assert!(failed.is_err());
+        assert!(op_stream()?.owned);
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+// src/rocarray/conv.rs - Convolutions on ROCArray through MIOpen
+
+use crate::error::{Result, invalid_argument};
+use crate::hip::DeviceMemory;
+use crate::miopen::{self, ConvolutionDescriptor, DataType, TensorDescriptor, ffi};
+use crate::rocarray::{ROCArray, Shape};
+use std::ffi::c_void;
+
+fn to_i32(value: usize) -> Result<i32> {
+    i32::try_from(value)
+        .map_err(|_| invalid_argument(format!("Dimension {} is too large for MIOpen", value)))
+}
+
+fn descriptor_4d(dims: &[usize]) -> Result<TensorDescriptor> {
+    Ok(TensorDescriptor::new_4d(
+        DataType::MiopenFloat,
+        to_i32(dims[0])?,
+        to_i32(dims[1])?,
+        to_i32(dims[2])?,
+        to_i32(dims[3])?,
+    )?)
+}
+
+impl ROCArray<f32> {
+    /// 2D cross-correlation of an (N, C, H, W) input with (K, C, R, S)
+    /// weights, giving (N, K, H', W')
+    ///
+    /// `stride` and `padding` are (height, width) pairs. The descriptors,
+    /// algorithm search and workspace are handled here and released when the
+    /// call returns.
+    pub fn conv2d(
+        &self,
+        weights: &ROCArray<f32>,
+        stride: (usize, usize),
+        padding: (usize, usize),
+    ) -> Result<ROCArray<f32>> {
+        if self.ndim() != 4 || weights.ndim() != 4 {
+            return Err(invalid_argument(
+                "conv2d requires an (N, C, H, W) input and (K, C, R, S) weights",
+            ));
+        }
+        if self.dims()[1] != weights.dims()[1] {
+            return Err(invalid_argument(format!(
+                "conv2d input has {} channels but weights expect {}",
+                self.dims()[1],
+                weights.dims()[1]
+            )));
+        }
+        if stride.0 == 0 || stride.1 == 0 {
+            return Err(invalid_argument("conv2d stride must be positive"));
+        }
+
+        let handle = miopen::Handle::new()?;
+        let x_desc = descriptor_4d(self.dims())?;
+        let w_desc = descriptor_4d(weights.dims())?;
+
+        let mut conv_desc = ConvolutionDescriptor::new()?;
+        conv_desc.init_2d(
+            ffi::miopenConvolutionMode_t_miopenConvolution,
+            to_i32(padding.0)?,
+            to_i32(padding.1)?,
+            to_i32(stride.0)?,
+            to_i32(stride.1)?,
+            1,
+            1,
+        )?;
+
+        let (n, k, h, w) = conv_desc.get_forward_output_dim(&x_desc, &w_desc)?;
+        if h <= 0 || w <= 0 {
+            return Err(invalid_argument(
+                "conv2d weights are larger than the padded input",
+            ));
+        }
+        let output_dims = [n as usize, k as usize, h as usize, w as usize];
+        let output = ROCArray::new(Shape::new(output_dims.to_vec()))?;
+        let y_desc = descriptor_4d(&output_dims)?;
+
+        let workspace_size = miopen::convolution::get_convolution_forward_workspace_size(
+            &handle, &w_desc, &x_desc, &conv_desc, &y_desc,
+        )?;
+        let workspace = DeviceMemory::<u8>::new(workspace_size)?;
+
+        let x = self.device_memory().as_ptr() as *const c_void;
+        let w = weights.device_memory().as_ptr() as *const c_void;
+        let y = output.device_memory().as_ptr();
+
+        unsafe {
+            let (_, perf) = miopen::find_convolution_forward_algorithm(
+                &handle,
+                &x_desc,
+                x,
+                &w_desc,
+                w,
+                &conv_desc,
+                &y_desc,
+                y,
+                1,
+                workspace.as_ptr(),
+                workspace_size,
+                false,
+            )?;
+            let algo = perf
+                .first()
+                .map(|perf| perf.__bindgen_anon_1.fwd_algo)
+                .ok_or_else(|| {
+                    crate::error::custom_error("MIOpen found no forward convolution algorithm")
+                })?;
+
+            miopen::convolution_forward(
+                &handle,
+                &1.0f32.to_ne_bytes(),
+                &x_desc,
+                x,
+                &w_desc,
+                w,
+                &conv_desc,
+                algo,
+                &0.0f32.to_ne_bytes(),
+                &y_desc,
+                y,
+                workspace.as_ptr(),
+                workspace_size,
+            )?;
+        }
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conv2d() -> Result<()> {
+        let input = ROCArray::from_vec_with_shape(vec![1.0f32; 9], Shape::new(vec![1, 1, 3, 3]))?;
+        let weights = ROCArray::from_vec_with_shape(vec![1.0f32; 4], Shape::new(vec![1, 1, 2, 2]))?;
+
+        let valid = input.conv2d(&weights, (1, 1), (0, 0))?;
+        assert_eq!(valid.dims(), &[1, 1, 2, 2]);
+        assert_eq!(valid.to_vec()?, vec![4.0; 4]);
+
+        let padded = input.conv2d(&weights, (2, 2), (1, 1))?;
+        assert_eq!(padded.dims(), &[1, 1, 2, 2]);
+        assert_eq!(padded.to_vec()?, vec![1.0, 2.0, 2.0, 4.0]);
+
+        let wrong_channels = ROCArray::<f32>::zeros(Shape::new(vec![1, 2, 2, 2]))?;
+        assert!(input.conv2d(&wrong_channels, (1, 1), (0, 0)).is_err());
+        Ok(())
+    }
+}
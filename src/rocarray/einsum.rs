@@ -0,0 +1,337 @@
+// src/rocarray/einsum.rs - Einstein summation planned onto transposes, reductions and GEMMs
+
+use crate::error::{Result, invalid_argument};
+use crate::rocarray::kernels::NumericOps;
+use crate::rocarray::{ROCArray, Shape};
+use crate::rocblas::level3::GemmStridedBatchedType;
+use std::collections::HashMap;
+
+/// Evaluate an Einstein summation such as `"ij,jk->ik"` over `operands`
+///
+/// Operands are contracted left to right. Each contraction is lowered to a
+/// permutation that groups the batch, free and summed labels, a reshape to
+/// (B, M, K) and (K, N) blocks, and one `gemm_strided_batched` call. Labels
+/// that appear in a single operand and not in the output are summed first.
+///
+/// Without `->` the output holds the labels that appear exactly once, in
+/// alphabetical order. A label may appear only once per operand, and `...`
+/// is not supported. A result with no labels has shape `[1]`.
+///
+/// ```no_run
+/// use rocm_rs::rocarray::{ROCArray, einsum};
+///
+/// let a = ROCArray::from_vec(vec![1.0f32, 2.0, 3.0, 4.0])?;
+/// let b = ROCArray::from_vec(vec![5.0f32, 6.0, 7.0, 8.0])?;
+/// let dot = einsum("i,i->", &[&a, &b])?;
+/// assert_eq!(dot.to_vec()?, vec![70.0]);
+/// # Ok::<(), rocm_rs::error::Error>(())
+/// ```
+pub fn einsum<T>(spec: &str, operands: &[&ROCArray<T>]) -> Result<ROCArray<T>>
+where
+    T: NumericOps + From<u8> + GemmStridedBatchedType,
+{
+    let (inputs, output) = parse_spec(spec, operands.len())?;
+    let sizes = label_sizes(&inputs, operands)?;
+
+    let mut terms = operands.iter().zip(&inputs).map(|(&array, labels)| Term {
+        array: Operand::Borrowed(array),
+        labels: labels.clone(),
+    });
+
+    let mut acc = terms.next().expect("parse_spec checks the operand count");
+    for (step, rhs) in terms.enumerate() {
+        // Labels still needed by the output or by an operand not yet folded in
+        let later: Vec<char> = inputs[step + 2..]
+            .iter()
+            .flatten()
+            .chain(&output)
+            .copied()
+            .collect();
+
+        let lhs_keep = |label| later.contains(&label) || rhs.labels.contains(&label);
+        let lhs = acc.sum_out(lhs_keep)?;
+        let rhs_keep = |label| later.contains(&label) || lhs.labels.contains(&label);
+        let rhs = rhs.sum_out(rhs_keep)?;
+
+        acc = contract(lhs, rhs, &later, &sizes)?;
+    }
+
+    let acc = acc.sum_out(|label| output.contains(&label))?;
+    match acc.permuted(&output)?.array {
+        Operand::Owned(array) => Ok(array),
+        Operand::Borrowed(array) => array.view().to_array(),
+    }
+}
+
+/// An input operand, or an intermediate result owned by the plan
+enum Operand<'a, T> {
+    Borrowed(&'a ROCArray<T>),
+    Owned(ROCArray<T>),
+}
+
+impl<T> Operand<'_, T> {
+    fn get(&self) -> &ROCArray<T> {
+        match self {
+            Operand::Borrowed(array) => array,
+            Operand::Owned(array) => array,
+        }
+    }
+}
+
+/// An operand with one label per axis; no labels means a single element
+struct Term<'a, T> {
+    array: Operand<'a, T>,
+    labels: Vec<char>,
+}
+
+impl<'a, T> Term<'a, T>
+where
+    T: NumericOps,
+{
+    /// Sum away every axis whose label fails `keep`
+    fn sum_out(mut self, keep: impl Fn(char) -> bool) -> Result<Self> {
+        for axis in (0..self.labels.len()).rev() {
+            if !keep(self.labels[axis]) {
+                self.array = Operand::Owned(self.array.get().sum_axis(axis)?);
+                self.labels.remove(axis);
+            }
+        }
+        Ok(self)
+    }
+
+    /// Reorder the axes to follow `order`, which must hold the same labels
+    fn permuted(self, order: &[char]) -> Result<Self> {
+        if self.labels == order {
+            return Ok(self);
+        }
+        let axes: Vec<usize> = order
+            .iter()
+            .map(|label| self.labels.iter().position(|l| l == label).unwrap())
+            .collect();
+        let array = self.array.get().permute(&axes)?.to_array()?;
+        Ok(Term {
+            array: Operand::Owned(array),
+            labels: order.to_vec(),
+        })
+    }
+}
+
+/// Contract two terms whose labels outside `keep` appear in both
+fn contract<'a, T>(
+    lhs: Term<'_, T>,
+    rhs: Term<'_, T>,
+    keep: &[char],
+    sizes: &HashMap<char, usize>,
+) -> Result<Term<'a, T>>
+where
+    T: NumericOps + From<u8> + GemmStridedBatchedType,
+{
+    let (shared, lhs_free): (Vec<char>, Vec<char>) =
+        lhs.labels.iter().partition(|l| rhs.labels.contains(l));
+    let (batch, summed): (Vec<char>, Vec<char>) = shared.iter().partition(|l| keep.contains(l));
+    let rhs_free: Vec<char> = rhs
+        .labels
+        .iter()
+        .filter(|l| !lhs.labels.contains(l))
+        .copied()
+        .collect();
+
+    let product = |labels: &[char]| labels.iter().map(|l| sizes[l]).product::<usize>();
+    let dims = [
+        product(&batch),
+        product(&lhs_free),
+        product(&summed),
+        product(&rhs_free),
+    ];
+
+    let lhs = lhs.permuted(&[&batch[..], &lhs_free, &summed].concat())?;
+    let rhs = rhs.permuted(&[&batch[..], &summed, &rhs_free].concat())?;
+
+    let labels = [&batch[..], &lhs_free, &rhs_free].concat();
+    let shape = if labels.is_empty() {
+        Shape::new(vec![1])
+    } else {
+        Shape::new(labels.iter().map(|l| sizes[l]).collect())
+    };
+
+    let result = ROCArray::new(shape)?;
+    ROCArray::batched_gemm(
+        lhs.array.get().device_memory(),
+        rhs.array.get().device_memory(),
+        result.device_memory(),
+        dims,
+    )?;
+    Ok(Term {
+        array: Operand::Owned(result),
+        labels,
+    })
+}
+
+/// Split a spec into per-operand labels and output labels
+fn parse_spec(spec: &str, operand_count: usize) -> Result<(Vec<Vec<char>>, Vec<char>)> {
+    let spec: String = spec.chars().filter(|c| !c.is_whitespace()).collect();
+    if spec.contains('.') {
+        return Err(invalid_argument("einsum does not support '...'"));
+    }
+
+    let (lhs, rhs) = match spec.split_once("->") {
+        Some((lhs, rhs)) => (lhs, Some(rhs)),
+        None => (spec.as_str(), None),
+    };
+
+    let parse_labels = |term: &str| -> Result<Vec<char>> {
+        let labels: Vec<char> = term.chars().collect();
+        for (i, &label) in labels.iter().enumerate() {
+            if !label.is_ascii_alphabetic() {
+                return Err(invalid_argument(format!(
+                    "Invalid einsum label '{}' in \"{}\"",
+                    label, spec
+                )));
+            }
+            if labels[..i].contains(&label) {
+                return Err(invalid_argument(format!(
+                    "einsum label '{}' repeats within a term of \"{}\"",
+                    label, spec
+                )));
+            }
+        }
+        Ok(labels)
+    };
+
+    let inputs = lhs
+        .split(',')
+        .map(parse_labels)
+        .collect::<Result<Vec<_>>>()?;
+    if inputs.len() != operand_count {
+        return Err(invalid_argument(format!(
+            "einsum spec \"{}\" names {} operands, {} given",
+            spec,
+            inputs.len(),
+            operand_count
+        )));
+    }
+
+    let output = match rhs {
+        Some(rhs) => {
+            let output = parse_labels(rhs)?;
+            if let Some(label) = output
+                .iter()
+                .find(|l| !inputs.iter().flatten().any(|i| i == *l))
+            {
+                return Err(invalid_argument(format!(
+                    "einsum output label '{}' does not appear in any operand",
+                    label
+                )));
+            }
+            output
+        }
+        None => {
+            let all: Vec<char> = inputs.iter().flatten().copied().collect();
+            let mut output: Vec<char> = all
+                .iter()
+                .filter(|l| all.iter().filter(|a| a == l).count() == 1)
+                .copied()
+                .collect();
+            output.sort_unstable();
+            output
+        }
+    };
+
+    Ok((inputs, output))
+}
+
+/// Check every operand against its labels and collect the size of each label
+fn label_sizes<T>(inputs: &[Vec<char>], operands: &[&ROCArray<T>]) -> Result<HashMap<char, usize>>
+where
+    T: Copy + Default + 'static,
+{
+    let mut sizes = HashMap::new();
+    for (index, (labels, operand)) in inputs.iter().zip(operands).enumerate() {
+        let dims = operand.dims();
+        let matches = if labels.is_empty() {
+            operand.len() == 1
+        } else {
+            dims.len() == labels.len()
+        };
+        if !matches {
+            return Err(invalid_argument(format!(
+                "einsum operand {} has shape {:?} but {} labels",
+                index,
+                dims,
+                labels.len()
+            )));
+        }
+
+        for (&label, &dim) in labels.iter().zip(dims) {
+            let size = *sizes.entry(label).or_insert(dim);
+            if size != dim {
+                return Err(invalid_argument(format!(
+                    "einsum label '{}' has sizes {} and {}",
+                    label, size, dim
+                )));
+            }
+        }
+    }
+    Ok(sizes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_spec() {
+        let (inputs, output) = parse_spec("ij, jk -> ik", 2).unwrap();
+        assert_eq!(inputs, vec![vec!['i', 'j'], vec!['j', 'k']]);
+        assert_eq!(output, vec!['i', 'k']);
+
+        // Implicit output keeps labels that appear once, sorted
+        let (_, output) = parse_spec("kj,ji", 2).unwrap();
+        assert_eq!(output, vec!['i', 'k']);
+
+        assert!(parse_spec("ii->i", 1).is_err());
+        assert!(parse_spec("ij->k", 1).is_err());
+        assert!(parse_spec("ij,jk->ik", 1).is_err());
+        assert!(parse_spec("...i->i", 1).is_err());
+    }
+
+    #[test]
+    fn test_einsum() -> Result<()> {
+        let a = ROCArray::from_vec_with_shape(
+            vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0],
+            Shape::new_2d(2, 3),
+        )?;
+        let b = ROCArray::from_vec_with_shape(
+            vec![1.0f32, 0.0, 0.0, 1.0, 1.0, 1.0],
+            Shape::new_2d(3, 2),
+        )?;
+
+        let matmul = einsum("ij,jk->ik", &[&a, &b])?;
+        assert_eq!(matmul.dims(), &[2, 2]);
+        assert_eq!(matmul.to_vec()?, vec![4.0, 5.0, 10.0, 11.0]);
+
+        let transposed_product = einsum("ij,jk->ki", &[&a, &b])?;
+        assert_eq!(transposed_product.to_vec()?, vec![4.0, 10.0, 5.0, 11.0]);
+
+        assert_eq!(
+            einsum("ij->ji", &[&a])?.to_vec()?,
+            vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]
+        );
+        assert_eq!(einsum("ij->j", &[&a])?.to_vec()?, vec![5.0, 7.0, 9.0]);
+        assert_eq!(einsum("ij->", &[&a])?.to_vec()?, vec![21.0]);
+
+        let x = ROCArray::from_vec(vec![1.0f32, 2.0])?;
+        let y = ROCArray::from_vec(vec![3.0f32, 4.0, 5.0])?;
+        assert_eq!(
+            einsum("i,j", &[&x, &y])?.to_vec()?,
+            vec![3.0, 4.0, 5.0, 6.0, 8.0, 10.0]
+        );
+
+        // Three operands: x^T A y
+        assert_eq!(einsum("i,ij,j->", &[&x, &a, &y])?.to_vec()?, vec![150.0]);
+        assert_eq!(einsum("i,i->i", &[&x, &x])?.to_vec()?, vec![1.0, 4.0]);
+
+        assert!(einsum("ij,ij->i", &[&a, &b]).is_err());
+        Ok(())
+    }
+}
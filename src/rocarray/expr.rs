@@ -0,0 +1,486 @@
+// src/rocarray/expr.rs - Lazy elementwise expressions fused into one kernel
+
+//! Lazy elementwise expressions
+//!
+//! Chaining eager operations such as `a.add(&b)?.mul(&c)?` launches one
+//! kernel per operation and allocates an array for every intermediate
+//! result. An [`Expr`] instead records the operations as a tree; evaluating
+//! it generates a single kernel for the whole tree, compiles it, and reads
+//! each input and writes the output exactly once.
+//!
+//! The kernel is built by running `hipcc` for the current device's
+//! architecture, as [`compile_and_load`](crate::hip::compile_and_load)
+//! does, so evaluating an expression needs `hipcc` on the `PATH` the first
+//! time a form is seen. Compiled kernels are kept in the process-wide
+//! [`ModuleCache`](crate::hip::ModuleCache), keyed by the generated source,
+//! so an expression of the same form and element type compiles once per
+//! device, and in the on-disk [kernel cache](crate::hip::KernelCacheConfig)
+//! so later processes skip `hipcc`. Scalars are kernel arguments rather
+//! than literals, so changing their values does not recompile.
+//!
+//! ```no_run
+//! use rocm_rs::rocarray::ROCArray;
+//!
+//! let a = ROCArray::from_vec(vec![1.0f32, 2.0, 3.0])?;
+//! let b = ROCArray::from_vec(vec![4.0f32, 5.0, 6.0])?;
+//! let c = ROCArray::from_vec(vec![0.5f32, 0.5, 0.5])?;
+//!
+//! // One launch, no intermediate arrays
+//! let fused = ((a.lazy() + &b) * &c).relu().eval()?;
+//! assert_eq!(fused.to_vec()?, vec![2.5, 3.5, 4.5]);
+//! # Ok::<(), rocm_rs::error::Error>(())
+//! ```
+//!
+//! All inputs must have the same shape; broadcasting is not supported. The
+//! transcendental operations (`exp`, `log`, `sqrt`, `tanh`, `sigmoid`)
+//! need a floating-point element type.
+
+use crate::error::{Result, invalid_argument};
+use crate::hip::{Dim3, ModuleCache, calculate_grid_1d};
+use crate::rocarray::context::op_stream;
+use crate::rocarray::kernels::NumericOps;
+use crate::rocarray::{ROCArray, Shape};
+use std::ffi::c_void;
+use std::fmt::Write;
+
+const FUSED_KERNEL_NAME: &str = "fused_elementwise";
+
+// Helpers shared by every generated kernel
+const FUSED_PRELUDE: &str = r#"#include <hip/hip_runtime.h>
+#include <hip/hip_fp16.h>
+#include <hip/hip_bf16.h>
+
+template <typename T> __device__ inline T fused_min(T a, T b) { return a < b ? a : b; }
+template <typename T> __device__ inline T fused_max(T a, T b) { return a < b ? b : a; }
+template <typename T> __device__ inline T fused_abs(T a) { return a < T(0) ? T(-a) : a; }
+template <typename T> __device__ inline T fused_relu(T a) { return a < T(0) ? T(0) : a; }
+template <typename T> __device__ inline T fused_sigmoid(T a) { return T(1) / (T(1) + exp(-a)); }
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UnaryOp {
+    Neg,
+    Abs,
+    Exp,
+    Log,
+    Sqrt,
+    Tanh,
+    Relu,
+    Sigmoid,
+}
+
+impl UnaryOp {
+    fn emit(self, operand: &str) -> String {
+        match self {
+            UnaryOp::Neg => format!("(-{})", operand),
+            UnaryOp::Abs => format!("fused_abs({})", operand),
+            UnaryOp::Exp => format!("exp({})", operand),
+            UnaryOp::Log => format!("log({})", operand),
+            UnaryOp::Sqrt => format!("sqrt({})", operand),
+            UnaryOp::Tanh => format!("tanh({})", operand),
+            UnaryOp::Relu => format!("fused_relu({})", operand),
+            UnaryOp::Sigmoid => format!("fused_sigmoid({})", operand),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Min,
+    Max,
+}
+
+impl BinaryOp {
+    fn emit(self, lhs: &str, rhs: &str) -> String {
+        match self {
+            BinaryOp::Add => format!("({} + {})", lhs, rhs),
+            BinaryOp::Sub => format!("({} - {})", lhs, rhs),
+            BinaryOp::Mul => format!("({} * {})", lhs, rhs),
+            BinaryOp::Div => format!("({} / {})", lhs, rhs),
+            BinaryOp::Min => format!("fused_min({}, {})", lhs, rhs),
+            BinaryOp::Max => format!("fused_max({}, {})", lhs, rhs),
+        }
+    }
+}
+
+/// A node of the expression tree; leaves index the inputs and scalars
+#[derive(Debug, Clone)]
+enum Node {
+    Input(usize),
+    Scalar(usize),
+    Unary(UnaryOp, Box<Node>),
+    Binary(BinaryOp, Box<Node>, Box<Node>),
+}
+
+impl Node {
+    /// Shift leaf indices after appending this tree's leaves to another's,
+    /// mapping inputs through `inputs`
+    fn remap(self, inputs: &[usize], scalar_offset: usize) -> Node {
+        match self {
+            Node::Input(i) => Node::Input(inputs[i]),
+            Node::Scalar(i) => Node::Scalar(i + scalar_offset),
+            Node::Unary(op, operand) => {
+                Node::Unary(op, Box::new(operand.remap(inputs, scalar_offset)))
+            }
+            Node::Binary(op, lhs, rhs) => Node::Binary(
+                op,
+                Box::new(lhs.remap(inputs, scalar_offset)),
+                Box::new(rhs.remap(inputs, scalar_offset)),
+            ),
+        }
+    }
+
+    fn emit(&self) -> String {
+        match self {
+            Node::Input(i) => format!("x{}", i),
+            Node::Scalar(i) => format!("s{}", i),
+            Node::Unary(op, operand) => op.emit(&operand.emit()),
+            Node::Binary(op, lhs, rhs) => op.emit(&lhs.emit(), &rhs.emit()),
+        }
+    }
+}
+
+/// A lazily evaluated elementwise expression over arrays of the same shape
+///
+/// Build one with [`ROCArray::lazy`] or [`Expr::from`], combine with the
+/// arithmetic operators or the named methods, and run it with
+/// [`eval`](Self::eval). See the [module documentation](self).
+#[derive(Debug, Clone)]
+pub struct Expr<'a, T> {
+    inputs: Vec<&'a ROCArray<T>>,
+    scalars: Vec<T>,
+    node: Node,
+}
+
+impl<'a, T> From<&'a ROCArray<T>> for Expr<'a, T> {
+    fn from(array: &'a ROCArray<T>) -> Self {
+        Expr {
+            inputs: vec![array],
+            scalars: Vec::new(),
+            node: Node::Input(0),
+        }
+    }
+}
+
+impl<T> ROCArray<T>
+where
+    T: NumericOps,
+{
+    /// Start a lazy elementwise expression over this array
+    pub fn lazy(&self) -> Expr<'_, T> {
+        Expr::from(self)
+    }
+}
+
+impl<'a, T> Expr<'a, T>
+where
+    T: NumericOps,
+{
+    /// A constant, passed to the kernel as an argument
+    pub fn scalar(value: T) -> Self {
+        Expr {
+            inputs: Vec::new(),
+            scalars: vec![value],
+            node: Node::Scalar(0),
+        }
+    }
+
+    fn unary(mut self, op: UnaryOp) -> Self {
+        self.node = Node::Unary(op, Box::new(self.node));
+        self
+    }
+
+    fn binary(mut self, op: BinaryOp, other: Expr<'a, T>) -> Self {
+        // Inputs shared by both sides are loaded once
+        let mapping: Vec<usize> = other
+            .inputs
+            .iter()
+            .map(|&input| {
+                match self
+                    .inputs
+                    .iter()
+                    .position(|&known| std::ptr::eq(known, input))
+                {
+                    Some(index) => index,
+                    None => {
+                        self.inputs.push(input);
+                        self.inputs.len() - 1
+                    }
+                }
+            })
+            .collect();
+
+        let rhs = other.node.remap(&mapping, self.scalars.len());
+        self.scalars.extend(other.scalars);
+        self.node = Node::Binary(op, Box::new(self.node), Box::new(rhs));
+        self
+    }
+
+    /// Elementwise minimum
+    pub fn min(self, other: impl Into<Expr<'a, T>>) -> Self {
+        self.binary(BinaryOp::Min, other.into())
+    }
+
+    /// Elementwise maximum
+    pub fn max(self, other: impl Into<Expr<'a, T>>) -> Self {
+        self.binary(BinaryOp::Max, other.into())
+    }
+
+    /// Add a scalar to every element
+    pub fn add_scalar(self, value: T) -> Self {
+        self.binary(BinaryOp::Add, Expr::scalar(value))
+    }
+
+    /// Multiply every element by a scalar
+    pub fn mul_scalar(self, value: T) -> Self {
+        self.binary(BinaryOp::Mul, Expr::scalar(value))
+    }
+
+    /// Absolute value
+    pub fn abs(self) -> Self {
+        self.unary(UnaryOp::Abs)
+    }
+
+    /// Natural exponential
+    pub fn exp(self) -> Self {
+        self.unary(UnaryOp::Exp)
+    }
+
+    /// Natural logarithm
+    pub fn log(self) -> Self {
+        self.unary(UnaryOp::Log)
+    }
+
+    /// Square root
+    pub fn sqrt(self) -> Self {
+        self.unary(UnaryOp::Sqrt)
+    }
+
+    /// Hyperbolic tangent
+    pub fn tanh(self) -> Self {
+        self.unary(UnaryOp::Tanh)
+    }
+
+    /// `max(x, 0)`
+    pub fn relu(self) -> Self {
+        self.unary(UnaryOp::Relu)
+    }
+
+    /// `1 / (1 + exp(-x))`
+    pub fn sigmoid(self) -> Self {
+        self.unary(UnaryOp::Sigmoid)
+    }
+
+    /// The HIP source of the fused kernel this expression compiles to
+    pub fn kernel_source(&self) -> String {
+        let ty = c_type(T::TYPE_NAME);
+        let mut source = String::from(FUSED_PRELUDE);
+
+        let _ = write!(
+            source,
+            "\nextern \"C\" __global__ void {}({}* out",
+            FUSED_KERNEL_NAME, ty
+        );
+        for i in 0..self.inputs.len() {
+            let _ = write!(source, ", const {}* in{}", ty, i);
+        }
+        for i in 0..self.scalars.len() {
+            let _ = write!(source, ", {} s{}", ty, i);
+        }
+        source.push_str(", unsigned int n) {\n");
+        source.push_str("    unsigned int idx = blockDim.x * blockIdx.x + threadIdx.x;\n");
+        source.push_str("    if (idx >= n) return;\n");
+        for i in 0..self.inputs.len() {
+            let _ = writeln!(source, "    const {} x{} = in{}[idx];", ty, i, i);
+        }
+        let _ = writeln!(source, "    out[idx] = ({})({});", ty, self.node.emit());
+        source.push_str("}\n");
+        source
+    }
+
+    /// Shape shared by every input
+    fn shape(&self) -> Result<Shape> {
+        let Some(first) = self.inputs.first() else {
+            return Err(invalid_argument(
+                "An expression needs at least one array input",
+            ));
+        };
+        if let Some(other) = self
+            .inputs
+            .iter()
+            .find(|input| input.shape() != first.shape())
+        {
+            return Err(invalid_argument(format!(
+                "Fused expressions need inputs of one shape, got {:?} and {:?}",
+                first.dims(),
+                other.dims()
+            )));
+        }
+        Ok(first.shape().clone())
+    }
+
+    /// Evaluate into a new array with a single fused kernel
+    pub fn eval(&self) -> Result<ROCArray<T>> {
+        let mut out = ROCArray::new(self.shape()?)?;
+        self.eval_into(&mut out)?;
+        Ok(out)
+    }
+
+    /// Evaluate into `out`, which must have the inputs' shape
+    ///
+    /// `out` may not be one of the inputs; evaluate into a fresh array and
+    /// copy back to update an input.
+    pub fn eval_into(&self, out: &mut ROCArray<T>) -> Result<()> {
+        let shape = self.shape()?;
+        out.check_out_shape(&shape)?;
+        let len = shape.size();
+        if len == 0 {
+            return Ok(());
+        }
+
+        let module = ModuleCache::global().get_or_compile(&self.kernel_source(), &[])?;
+        let function = module.get_function(FUSED_KERNEL_NAME)?;
+
+        let len_u32 = u32::try_from(len)
+            .map_err(|_| invalid_argument("Fused expressions support up to 2^32 elements"))?;
+        let mut args: Vec<*mut c_void> =
+            Vec::with_capacity(self.inputs.len() + self.scalars.len() + 2);
+        args.push(out.as_ptr());
+        args.extend(self.inputs.iter().map(|input| input.as_ptr()));
+        args.extend(
+            self.scalars
+                .iter()
+                .map(|scalar| scalar as *const T as *mut c_void),
+        );
+        args.push(&len_u32 as *const u32 as *mut c_void);
+
+        let block_size = 256;
+        let stream = op_stream()?;
+        function.launch(
+            calculate_grid_1d(len_u32, block_size),
+            Dim3::new_1d(block_size),
+            0,
+            Some(&stream),
+            &mut args,
+        )?;
+        stream.finish()?;
+        Ok(())
+    }
+}
+
+/// C spelling of a kernel type name
+fn c_type(type_name: &str) -> &str {
+    match type_name {
+        "uint" => "unsigned int",
+        "long" => "long long",
+        "ulong" => "unsigned long long",
+        "ushort" => "unsigned short",
+        "char" => "signed char",
+        "uchar" => "unsigned char",
+        "half" => "__half",
+        "bfloat16" => "__hip_bfloat16",
+        other => other,
+    }
+}
+
+// Right-hand sides are expressions or arrays
+macro_rules! impl_expr_op {
+    ($trait:ident, $method:ident, $op:ident) => {
+        impl<'a, T, R> std::ops::$trait<R> for Expr<'a, T>
+        where
+            T: NumericOps,
+            R: Into<Expr<'a, T>>,
+        {
+            type Output = Expr<'a, T>;
+
+            fn $method(self, rhs: R) -> Expr<'a, T> {
+                self.binary(BinaryOp::$op, rhs.into())
+            }
+        }
+    };
+}
+
+impl_expr_op!(Add, add, Add);
+impl_expr_op!(Sub, sub, Sub);
+impl_expr_op!(Mul, mul, Mul);
+impl_expr_op!(Div, div, Div);
+
+impl<T: NumericOps> std::ops::Neg for Expr<'_, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.unary(UnaryOp::Neg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kernel_source() -> Result<()> {
+        let a = ROCArray::<f32>::new_1d(4)?;
+        let b = ROCArray::<f32>::new_1d(4)?;
+
+        // `a` appears twice but is loaded once
+        let expr = (a.lazy() + &b).mul_scalar(2.0) - &a;
+        assert_eq!(expr.inputs.len(), 2);
+        assert_eq!(expr.scalars, vec![2.0]);
+
+        let source = expr.kernel_source();
+        assert!(source.contains(
+            "void fused_elementwise(float* out, const float* in0, const float* in1, float s0, unsigned int n)"
+        ));
+        assert!(source.contains("out[idx] = (float)((((x0 + x1) * s0) - x0));"));
+
+        let ints = ROCArray::<u32>::new_1d(4)?;
+        assert!(
+            ints.lazy()
+                .max(&ints)
+                .kernel_source()
+                .contains("const unsigned int x0")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_fused_eval() -> Result<()> {
+        let a = ROCArray::from_vec(vec![1.0f32, -2.0, 3.0, -4.0])?;
+        let b = ROCArray::from_vec(vec![0.5f32, 0.5, 0.5, 0.5])?;
+        let c = ROCArray::from_vec(vec![2.0f32, 2.0, 2.0, 2.0])?;
+
+        let fused = ((a.lazy() + &b) * &c).eval()?;
+        let eager = a.add(&b)?.mul(&c)?;
+        assert_eq!(fused.to_vec()?, eager.to_vec()?);
+
+        assert_eq!(
+            a.lazy().abs().relu().eval()?.to_vec()?,
+            vec![1.0, 2.0, 3.0, 4.0]
+        );
+        assert_eq!(
+            (-a.lazy()).relu().eval()?.to_vec()?,
+            vec![0.0, 2.0, 0.0, 4.0]
+        );
+        assert_eq!(
+            a.lazy().min(&b).add_scalar(1.0).eval()?.to_vec()?,
+            vec![1.5, -1.0, 1.5, -3.0]
+        );
+
+        let sigmoid = ROCArray::from_vec(vec![0.0f32])?.lazy().sigmoid().eval()?;
+        assert_eq!(sigmoid.to_vec()?, vec![0.5]);
+
+        // Same form with another scalar reuses the compiled kernel
+        let mut out = ROCArray::new_1d(4)?;
+        a.lazy().mul_scalar(3.0).eval_into(&mut out)?;
+        assert_eq!(out.to_vec()?, vec![3.0, -6.0, 9.0, -12.0]);
+
+        let short = ROCArray::from_vec(vec![1.0f32])?;
+        assert!((a.lazy() + &short).eval().is_err());
+        assert!(Expr::scalar(1.0f32).eval().is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+// src/rocarray/fft.rs - FFTs on ROCArray through rocFFT
+
+use crate::error::Result;
+use crate::rocarray::ROCArray;
+use crate::rocfft::fft::{self, Complex32, Fft};
+
+impl ROCArray<Complex32> {
+    /// Forward FFT along the last axis, batched over the others
+    ///
+    /// Plans are cached per thread and keyed by shape, so repeated
+    /// transforms of the same shape only pay for planning once.
+    pub fn fft(&self) -> Result<ROCArray<Complex32>> {
+        Fft::forward().execute_batched(self)
+    }
+
+    /// Inverse FFT along the last axis, scaled by 1/N so that
+    /// `x.fft()?.ifft()?` gives back `x`
+    pub fn ifft(&self) -> Result<ROCArray<Complex32>> {
+        Fft::inverse().normalize(true).execute_batched(self)
+    }
+
+    /// In-place version of [`fft`](Self::fft)
+    pub fn fft_in_place(&mut self) -> Result<()> {
+        Fft::forward().execute_in_place(self)
+    }
+
+    /// In-place version of [`ifft`](Self::ifft)
+    pub fn ifft_in_place(&mut self) -> Result<()> {
+        Fft::inverse().normalize(true).execute_in_place(self)
+    }
+}
+
+impl ROCArray<f32> {
+    /// FFT of real signals along the last axis, keeping the `n / 2 + 1`
+    /// non-redundant coefficients
+    pub fn rfft(&self) -> Result<ROCArray<Complex32>> {
+        fft::real_forward(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rocarray::Shape;
+
+    fn assert_close(actual: &[Complex32], expected: &[Complex32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!(
+                (a.re - e.re).abs() < 1e-4 && (a.im - e.im).abs() < 1e-4,
+                "{a:?} != {e:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_fft_round_trip() -> Result<()> {
+        let signal: Vec<Complex32> = (0..8).map(|i| Complex32::new(i as f32, 0.0)).collect();
+        let rows = [signal.clone(), signal.clone()].concat();
+        let array = ROCArray::from_vec_with_shape(rows.clone(), Shape::new_2d(2, 8))?;
+
+        let spectrum = array.fft()?;
+        let values = spectrum.to_vec()?;
+        // The DC term is the sum of the signal
+        assert_close(&values[..1], &[Complex32::new(28.0, 0.0)]);
+        assert_close(&values[8..9], &[Complex32::new(28.0, 0.0)]);
+        assert_close(&spectrum.ifft()?.to_vec()?, &rows);
+
+        let mut in_place = ROCArray::from_vec_with_shape(rows.clone(), Shape::new_2d(2, 8))?;
+        in_place.fft_in_place()?;
+        assert_close(&in_place.to_vec()?, &values);
+        in_place.ifft_in_place()?;
+        assert_close(&in_place.to_vec()?, &rows);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rfft() -> Result<()> {
+        let signal = ROCArray::from_vec(vec![1.0f32, 0.0, -1.0, 0.0])?;
+        let spectrum = signal.rfft()?;
+        assert_eq!(spectrum.dims(), &[3]);
+        assert_close(
+            &spectrum.to_vec()?,
+            &[
+                Complex32::new(0.0, 0.0),
+                Complex32::new(2.0, 0.0),
+                Complex32::new(0.0, 0.0),
+            ],
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+// src/rocarray/half_precision.rs - Conversions between f32 and half-precision arrays
+
+use crate::error::Result;
+use crate::rocarray::ROCArray;
+use half::{bf16, f16};
+
+impl ROCArray<f32> {
+    /// Round every element to IEEE half precision on the device
+    pub fn to_f16(&self) -> Result<ROCArray<f16>> {
+        self.astype()
+    }
+
+    /// Round every element to bfloat16 on the device
+    pub fn to_bf16(&self) -> Result<ROCArray<bf16>> {
+        self.astype()
+    }
+}
+
+impl ROCArray<f16> {
+    /// Widen every element to f32 on the device
+    pub fn to_f32(&self) -> Result<ROCArray<f32>> {
+        self.astype()
+    }
+}
+
+impl ROCArray<bf16> {
+    /// Widen every element to f32 on the device
+    pub fn to_f32(&self) -> Result<ROCArray<f32>> {
+        self.astype()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f16_round_trip() -> Result<()> {
+        let values = vec![1.0f32, -2.5, 0.333_333_34, 65504.0];
+        let half = ROCArray::from_vec(values.clone())?.to_f16()?;
+        assert_eq!(
+            half.to_vec()?,
+            values.iter().map(|&x| f16::from_f32(x)).collect::<Vec<_>>()
+        );
+
+        let back = half.to_f32()?.to_vec()?;
+        assert_eq!(back[0], 1.0);
+        assert_eq!(back[1], -2.5);
+        assert!((back[2] - 0.333_333_34).abs() < 1e-3);
+        assert_eq!(back[3], 65504.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_half_arithmetic() -> Result<()> {
+        let a = ROCArray::from_vec(vec![f16::from_f32(1.5), f16::from_f32(2.0)])?;
+        let b = ROCArray::from_vec(vec![f16::from_f32(0.5), f16::from_f32(4.0)])?;
+        assert_eq!(a.add(&b)?.to_f32()?.to_vec()?, vec![2.0, 6.0]);
+        assert_eq!(a.mul(&b)?.to_f32()?.to_vec()?, vec![0.75, 8.0]);
+        assert_eq!(
+            a.mul_scalar(f16::from_f32(2.0))?.to_f32()?.to_vec()?,
+            vec![3.0, 4.0]
+        );
+
+        let x = ROCArray::from_vec(vec![1.0f32, 256.0, -0.5])?.to_bf16()?;
+        let y = x.add(&x)?;
+        assert_eq!(y.to_f32()?.to_vec()?, vec![2.0, 512.0, -1.0]);
+        assert_eq!(y.astype::<f64>()?.to_vec()?, vec![2.0, 512.0, -1.0]);
+        Ok(())
+    }
+}
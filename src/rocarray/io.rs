@@ -0,0 +1,209 @@
+// src/rocarray/io.rs - Parsing of text data into ROCArrays on device
+use crate::error::Result;
+use crate::hip::device_modules::DeviceModules;
+use crate::hip::kernel::AsKernelArg;
+use crate::hip::{DeviceMemory, Dim3, Function, Stream, calculate_grid_1d};
+use crate::kernel_args;
+use crate::rocarray::ROCArray;
+
+static IO_KERNELS: DeviceModules = DeviceModules::new(include_str!("io_kernels.hip"));
+
+// Must match CSV_BLOCK_SIZE and CSV_CHUNK in io_kernels.hip
+const CSV_BLOCK_SIZE: u32 = 256;
+const CSV_CHUNK: usize = 16;
+
+/// Element types that CSV fields can be parsed into
+pub trait CsvNumeric: Copy + Default + 'static {
+    const TYPE_NAME: &'static str;
+}
+
+impl CsvNumeric for f32 {
+    const TYPE_NAME: &'static str = "float";
+}
+
+impl CsvNumeric for f64 {
+    const TYPE_NAME: &'static str = "double";
+}
+
+/// Options for parsing CSV data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// Field separator
+    pub delimiter: u8,
+    /// Skip the first row
+    pub has_header: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            has_header: false,
+        }
+    }
+}
+
+fn get_io_kernel_function(name: &str) -> Result<Function> {
+    IO_KERNELS.function(name)
+}
+
+/// Parse a comma-separated numeric buffer on device into one array per column
+///
+/// See [`parse_csv_numeric_with_options`].
+pub fn parse_csv_numeric<T>(bytes: &DeviceMemory<u8>, n_cols: usize) -> Result<Vec<ROCArray<T>>>
+where
+    T: CsvNumeric,
+{
+    parse_csv_numeric_with_options(bytes, n_cols, &CsvOptions::default())
+}
+
+/// Parse a delimited numeric buffer on device into one array per column
+///
+/// Rows are separated by `\n` (a trailing `\r` is ignored) and a final
+/// newline is optional. Missing, empty or malformed fields become NaN and
+/// fields beyond `n_cols` are ignored. Quoted fields are not supported.
+/// Values are parsed in double precision and may differ from the host
+/// parser in the last bit.
+pub fn parse_csv_numeric_with_options<T>(
+    bytes: &DeviceMemory<u8>,
+    n_cols: usize,
+    options: &CsvOptions,
+) -> Result<Vec<ROCArray<T>>>
+where
+    T: CsvNumeric,
+{
+    let len = bytes.count();
+    if len == 0 {
+        return (0..n_cols).map(|_| ROCArray::new_1d(0)).collect();
+    }
+    if len >= u32::MAX as usize {
+        return Err(crate::error::custom_error(format!(
+            "CSV buffer of {} bytes exceeds the 4 GiB limit",
+            len
+        )));
+    }
+
+    let stream = Stream::new()?;
+    let len_u32 = len as u32;
+
+    // Count newlines per block
+    let bytes_per_block = CSV_BLOCK_SIZE as usize * CSV_CHUNK;
+    let n_blocks = len.div_ceil(bytes_per_block);
+    let block_counts = DeviceMemory::<u32>::new(n_blocks + 1)?;
+
+    let grid_dim = Dim3::new_1d(n_blocks as u32);
+    let block_dim = Dim3::new_1d(CSV_BLOCK_SIZE);
+
+    let count_rows = get_io_kernel_function("csv_count_rows")?;
+    count_rows.launch(
+        grid_dim,
+        block_dim,
+        0,
+        Some(&stream),
+        kernel_args!(bytes, len_u32, block_counts),
+    )?;
+    stream.synchronize()?;
+
+    let mut host_counts = vec![0u32; n_blocks + 1];
+    block_counts.copy_to_host(&mut host_counts)?;
+    let ends_with_newline = host_counts[n_blocks] != 0;
+
+    // Exclusive scan of the block counts gives each block's first row
+    let mut block_offsets = Vec::with_capacity(n_blocks);
+    let mut total_newlines = 0u32;
+    for &count in &host_counts[..n_blocks] {
+        block_offsets.push(total_newlines);
+        total_newlines += count;
+    }
+
+    let mut device_offsets = DeviceMemory::<u32>::new(n_blocks)?;
+    device_offsets.copy_from_host(&block_offsets)?;
+    let row_starts = DeviceMemory::<u32>::new(total_newlines as usize + 2)?;
+
+    let row_offsets = get_io_kernel_function("csv_row_offsets")?;
+    row_offsets.launch(
+        grid_dim,
+        block_dim,
+        0,
+        Some(&stream),
+        kernel_args!(bytes, len_u32, device_offsets, total_newlines, row_starts),
+    )?;
+
+    let n_lines = total_newlines as usize + 1 - ends_with_newline as usize;
+    let first_row = options.has_header as usize;
+    let n_rows = n_lines.saturating_sub(first_row);
+
+    let columns = (0..n_cols)
+        .map(|_| ROCArray::<T>::new_1d(n_rows))
+        .collect::<Result<Vec<_>>>()?;
+
+    if n_rows == 0 || n_cols == 0 {
+        stream.synchronize()?;
+        return Ok(columns);
+    }
+
+    let column_ptrs: Vec<u64> = columns.iter().map(|c| c.as_ptr() as u64).collect();
+    let mut device_columns = DeviceMemory::<u64>::new(n_cols)?;
+    device_columns.copy_from_host(&column_ptrs)?;
+
+    let first_row_u32 = first_row as u32;
+    let n_rows_u32 = n_rows as u32;
+    let n_cols_u32 = n_cols as u32;
+
+    let parse = get_io_kernel_function(&format!("csv_parse_{}", T::TYPE_NAME))?;
+    parse.launch(
+        calculate_grid_1d(n_rows_u32, CSV_BLOCK_SIZE),
+        block_dim,
+        0,
+        Some(&stream),
+        kernel_args!(
+            bytes,
+            row_starts,
+            first_row_u32,
+            n_rows_u32,
+            n_cols_u32,
+            options.delimiter,
+            device_columns
+        ),
+    )?;
+    stream.synchronize()?;
+
+    Ok(columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn upload(text: &str) -> Result<DeviceMemory<u8>> {
+        let mut bytes = DeviceMemory::<u8>::new(text.len())?;
+        bytes.copy_from_host(text.as_bytes())?;
+        Ok(bytes)
+    }
+
+    #[test]
+    fn test_parse_csv_numeric() -> Result<()> {
+        let bytes = upload("1,2.5\n-3e2, 4\n5,6\n")?;
+        let columns = parse_csv_numeric::<f64>(&bytes, 2)?;
+
+        assert_eq!(columns[0].to_vec()?, vec![1.0, -300.0, 5.0]);
+        assert_eq!(columns[1].to_vec()?, vec![2.5, 4.0, 6.0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_csv_header_and_missing_fields() -> Result<()> {
+        let bytes = upload("a;b\r\n1;\r\n2;x\r\n3;7")?;
+        let options = CsvOptions {
+            delimiter: b';',
+            has_header: true,
+        };
+        let columns = parse_csv_numeric_with_options::<f32>(&bytes, 2, &options)?;
+
+        assert_eq!(columns[0].to_vec()?, vec![1.0, 2.0, 3.0]);
+        let second = columns[1].to_vec()?;
+        assert!(second[0].is_nan() && second[1].is_nan());
+        assert_eq!(second[2], 7.0);
+        Ok(())
+    }
+}
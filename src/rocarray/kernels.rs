@@ -1,12 +1,16 @@
 // src/rocarray/kernels.rs - Complete implementation of GPU kernels for ROCArray operations
 use crate::error::Result;
-use crate::hip::{DeviceMemory, Dim3, Function, Module, Stream, calculate_grid_1d};
+use crate::hip::device_modules::DeviceModules;
+use crate::hip::kernel::AsKernelArg;
+use crate::hip::{DeviceMemory, Dim3, Function, Stream, calculate_grid_1d};
+use crate::kernel_args;
 use crate::rocarray::Shape;
+use crate::rocarray::context::op_stream;
+use crate::rocarray::scalar::DeviceScalar;
+use crate::rocarray::view::{MAX_VIEW_DIMS, StridedLayout};
 use std::ffi::c_void;
-use std::sync::Once;
 
-static INIT: Once = Once::new();
-static mut KERNELS_MODULE: Option<Module> = None;
+static KERNELS: DeviceModules = DeviceModules::new(include_str!("kernels.hip"));
 
 // Trait for types that support numeric operations
 pub trait NumericOps: Copy + Default + 'static {
@@ -53,6 +57,81 @@ impl NumericOps for u8 {
     const TYPE_NAME: &'static str = "uchar";
 }
 
+// Half-precision arrays support elementwise arithmetic, scalar add and
+// multiply, strided copies, flip, roll and conversion to and from f32/f64
+#[cfg(feature = "half")]
+impl NumericOps for half::f16 {
+    const TYPE_NAME: &'static str = "half";
+}
+
+#[cfg(feature = "half")]
+impl NumericOps for half::bf16 {
+    const TYPE_NAME: &'static str = "bfloat16";
+}
+
+// Complex arrays support elementwise and scalar arithmetic (without
+// broadcasting), strided copies, flip, roll, abs and conj
+#[cfg(feature = "num-complex")]
+impl NumericOps for num_complex::Complex32 {
+    const TYPE_NAME: &'static str = "cfloat";
+}
+
+#[cfg(feature = "num-complex")]
+impl NumericOps for num_complex::Complex64 {
+    const TYPE_NAME: &'static str = "cdouble";
+}
+
+/// Complex element types and their real component type
+#[cfg(feature = "num-complex")]
+pub trait ComplexOps: NumericOps {
+    type Real: NumericOps;
+}
+
+#[cfg(feature = "num-complex")]
+impl ComplexOps for num_complex::Complex32 {
+    type Real = f32;
+}
+
+#[cfg(feature = "num-complex")]
+impl ComplexOps for num_complex::Complex64 {
+    type Real = f64;
+}
+
+/// Element types with an absolute value; complex numbers map to their
+/// real magnitude
+pub trait AbsOps: NumericOps {
+    type Magnitude: NumericOps;
+}
+
+macro_rules! impl_abs_ops {
+    ($($t:ty),*) => {
+        $(
+            impl AbsOps for $t {
+                type Magnitude = $t;
+            }
+        )*
+    };
+}
+
+impl_abs_ops!(f32, f64, i32, u32, i64, u64, i16, u16, i8, u8);
+
+#[cfg(feature = "num-complex")]
+impl AbsOps for num_complex::Complex32 {
+    type Magnitude = f32;
+}
+
+#[cfg(feature = "num-complex")]
+impl AbsOps for num_complex::Complex64 {
+    type Magnitude = f64;
+}
+
+/// Floating-point element types, which support the transcendental functions
+pub trait FloatOps: NumericOps {}
+
+impl FloatOps for f32 {}
+
+impl FloatOps for f64 {}
+
 // Trait for transposable operations
 pub trait TransposableOps: Copy + Default + 'static {
     const TYPE_NAME: &'static str;
@@ -142,35 +221,8 @@ macro_rules! impl_kernel_traits {
 
 impl_kernel_traits!(f32, f64, i32, u32, i64, u64, i16, u16, i8, u8);
 
-// Kernel initialization
-fn init_kernels() -> Result<()> {
-    INIT.call_once(|| {
-        let kernel_source = include_str!("kernels.hip");
-
-        match crate::hip::compile_and_load(kernel_source, &[]) {
-            Ok(module) => unsafe {
-                KERNELS_MODULE = Some(module);
-            },
-            Err(e) => {
-                eprintln!("Failed to load kernels: {:?}", e);
-            }
-        }
-    });
-    Ok(())
-}
-
 fn get_kernel_function(name: &str) -> Result<Function> {
-    init_kernels()?;
-
-    unsafe {
-        if let Some(ref module) = KERNELS_MODULE {
-            Ok(module.get_function(name)?)
-        } else {
-            Err(crate::error::Error::InvalidOperation(
-                "Kernels not initialized".to_string(),
-            ))
-        }
-    }
+    KERNELS.function(name)
 }
 
 // =============================================================================
@@ -186,7 +238,7 @@ pub fn elementwise_add<T>(
 where
     T: NumericOps,
 {
-    elementwise_add_async(a, b, result, len, &Stream::new()?)
+    elementwise_add_async(a, b, result, len, &*op_stream()?)
 }
 
 pub fn elementwise_add_async<T>(
@@ -227,7 +279,7 @@ pub fn elementwise_sub<T>(
 where
     T: NumericOps,
 {
-    elementwise_sub_async(a, b, result, len, &Stream::new()?)
+    elementwise_sub_async(a, b, result, len, &*op_stream()?)
 }
 
 pub fn elementwise_sub_async<T>(
@@ -268,7 +320,7 @@ pub fn elementwise_mul<T>(
 where
     T: NumericOps,
 {
-    elementwise_mul_async(a, b, result, len, &Stream::new()?)
+    elementwise_mul_async(a, b, result, len, &*op_stream()?)
 }
 
 pub fn elementwise_mul_async<T>(
@@ -309,7 +361,7 @@ pub fn elementwise_div<T>(
 where
     T: NumericOps,
 {
-    elementwise_div_async(a, b, result, len, &Stream::new()?)
+    elementwise_div_async(a, b, result, len, &*op_stream()?)
 }
 
 pub fn elementwise_div_async<T>(
@@ -356,15 +408,7 @@ pub fn elementwise_add_broadcast<T>(
 where
     T: NumericOps,
 {
-    elementwise_add_broadcast_async(
-        a,
-        b,
-        result,
-        a_shape,
-        b_shape,
-        result_shape,
-        &Stream::new()?,
-    )
+    elementwise_add_broadcast_async(a, b, result, a_shape, b_shape, result_shape, &*op_stream()?)
 }
 
 pub fn elementwise_add_broadcast_async<T>(
@@ -430,15 +474,7 @@ pub fn elementwise_sub_broadcast<T>(
 where
     T: NumericOps,
 {
-    elementwise_sub_broadcast_async(
-        a,
-        b,
-        result,
-        a_shape,
-        b_shape,
-        result_shape,
-        &Stream::new()?,
-    )
+    elementwise_sub_broadcast_async(a, b, result, a_shape, b_shape, result_shape, &*op_stream()?)
 }
 
 pub fn elementwise_sub_broadcast_async<T>(
@@ -504,15 +540,7 @@ pub fn elementwise_mul_broadcast<T>(
 where
     T: NumericOps,
 {
-    elementwise_mul_broadcast_async(
-        a,
-        b,
-        result,
-        a_shape,
-        b_shape,
-        result_shape,
-        &Stream::new()?,
-    )
+    elementwise_mul_broadcast_async(a, b, result, a_shape, b_shape, result_shape, &*op_stream()?)
 }
 
 pub fn elementwise_mul_broadcast_async<T>(
@@ -578,15 +606,7 @@ pub fn elementwise_div_broadcast<T>(
 where
     T: NumericOps,
 {
-    elementwise_div_broadcast_async(
-        a,
-        b,
-        result,
-        a_shape,
-        b_shape,
-        result_shape,
-        &Stream::new()?,
-    )
+    elementwise_div_broadcast_async(a, b, result, a_shape, b_shape, result_shape, &*op_stream()?)
 }
 
 pub fn elementwise_div_broadcast_async<T>(
@@ -654,7 +674,7 @@ pub fn scalar_add<T>(
 where
     T: NumericOps,
 {
-    scalar_add_async(input, scalar, result, len, &Stream::new()?)
+    scalar_add_async(input, scalar, result, len, &*op_stream()?)
 }
 
 pub fn scalar_add_async<T>(
@@ -695,7 +715,7 @@ pub fn scalar_mul<T>(
 where
     T: NumericOps,
 {
-    scalar_mul_async(input, scalar, result, len, &Stream::new()?)
+    scalar_mul_async(input, scalar, result, len, &*op_stream()?)
 }
 
 pub fn scalar_mul_async<T>(
@@ -735,87 +755,59 @@ pub fn reduce_sum<T>(input: &DeviceMemory<T>, len: usize) -> Result<T>
 where
     T: NumericOps,
 {
-    reduce_sum_async(input, len, &Stream::new()?)
+    reduce_sum_async(input, len, &*op_stream()?)
 }
 
 pub fn reduce_sum_async<T>(input: &DeviceMemory<T>, len: usize, stream: &Stream) -> Result<T>
 where
     T: NumericOps,
 {
-    let kernel_name = format!("reduce_sum_{}", T::TYPE_NAME);
-    let function = get_kernel_function(&kernel_name)?;
-
-    let block_size = 256;
-    let grid_dim = calculate_grid_1d(len as u32, block_size);
-
-    let mut temp_result = DeviceMemory::<T>::new(1)?;
-    // Initialize result to zero
-    temp_result.memset(0)?;
-
-    let len_u32 = len as u32;
-    let mut kernel_args = [
-        input.as_ptr(),
-        &len_u32 as *const u32 as *mut c_void,
-        temp_result.as_ptr() as *mut c_void,
-    ];
-
-    function.launch(
-        grid_dim,
-        Dim3::new_1d(block_size),
-        0,
-        Some(stream),
-        &mut kernel_args,
-    )?;
-    stream.synchronize()?;
+    reduce_sum_device_async(input, len, stream)?.read(stream)
+}
 
-    let mut result = vec![T::default(); 1];
-    temp_result.copy_to_host(&mut result)?;
-    Ok(result[0])
+/// Sum `len` elements into a device scalar without synchronizing
+pub fn reduce_sum_device_async<T>(
+    input: &DeviceMemory<T>,
+    len: usize,
+    stream: &Stream,
+) -> Result<DeviceScalar<T>>
+where
+    T: NumericOps,
+{
+    let mut result = DeviceMemory::<T>::new(1)?;
+    if len == 0 {
+        result.memset_async(0, stream)?;
+    } else {
+        launch_reduce("reduce_sum", input, len, &result, stream)?;
+    }
+    Ok(DeviceScalar::from_memory(result))
 }
 
 pub fn reduce_min<T>(input: &DeviceMemory<T>, len: usize) -> Result<T>
 where
     T: NumericOps + PartialOrd,
 {
-    reduce_min_async(input, len, &Stream::new()?)
+    reduce_min_async(input, len, &*op_stream()?)
 }
 
 pub fn reduce_min_async<T>(input: &DeviceMemory<T>, len: usize, stream: &Stream) -> Result<T>
 where
     T: NumericOps + PartialOrd,
 {
-    let kernel_name = format!("reduce_min_{}", T::TYPE_NAME);
-    let function = get_kernel_function(&kernel_name)?;
-
-    let block_size = 256;
-    let grid_dim = calculate_grid_1d(len as u32, block_size);
-
-    let mut temp_result = DeviceMemory::<T>::new(1)?;
-    // Initialize with first element
-    if len > 0 {
-        let first_device = DeviceMemory::<T>::new(1)?;
-        temp_result.copy_from_device(&first_device)?;
-    }
-
-    let len_u32 = len as u32;
-    let mut kernel_args = [
-        input.as_ptr(),
-        &len_u32 as *const u32 as *mut c_void,
-        temp_result.as_ptr() as *mut c_void,
-    ];
-
-    function.launch(
-        grid_dim,
-        Dim3::new_1d(block_size),
-        0,
-        Some(stream),
-        &mut kernel_args,
-    )?;
-    stream.synchronize()?;
+    reduce_min_device_async(input, len, stream)?.read(stream)
+}
 
-    let mut result = vec![T::default(); 1];
-    temp_result.copy_to_host(&mut result)?;
-    Ok(result[0])
+/// Find the minimum of `len` elements into a device scalar without
+/// synchronizing
+pub fn reduce_min_device_async<T>(
+    input: &DeviceMemory<T>,
+    len: usize,
+    stream: &Stream,
+) -> Result<DeviceScalar<T>>
+where
+    T: NumericOps + PartialOrd,
+{
+    reduce_extremum_device_async("reduce_min", input, len, stream)
 }
 
 // Reduction along specific axis
@@ -828,7 +820,7 @@ pub fn reduce_sum_axis<T>(
 where
     T: NumericOps,
 {
-    reduce_sum_axis_async(input, output, input_shape, axis, &Stream::new()?)
+    reduce_sum_axis_async(input, output, input_shape, axis, &*op_stream()?)
 }
 
 pub fn reduce_sum_axis_async<T>(
@@ -871,609 +863,2817 @@ where
     Ok(())
 }
 
-// =============================================================================
-// Matrix operations
-// =============================================================================
+/// A reduction or scan along one axis of a row-major array, seen as
+/// `lanes` independent runs of `axis_size` elements spaced `inner` apart
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct AxisLanes {
+    pub lanes: usize,
+    pub axis_size: usize,
+    pub inner: usize,
+}
 
-pub fn matrix_multiply<T>(
-    a: &DeviceMemory<T>,
-    b: &DeviceMemory<T>,
-    c: &DeviceMemory<T>,
-    m: usize,
-    k: usize,
-    n: usize,
-) -> Result<()>
+impl AxisLanes {
+    pub(crate) fn new(shape: &Shape, axis: usize) -> Result<Self> {
+        let dims = shape.dims();
+        if axis >= dims.len() {
+            return Err(crate::error::custom_error("Axis out of bounds"));
+        }
+
+        let inner: usize = dims[axis + 1..].iter().product();
+        let outer: usize = dims[..axis].iter().product();
+        Ok(Self {
+            lanes: outer * inner,
+            axis_size: dims[axis],
+            inner,
+        })
+    }
+}
+
+// Index of the maximum or minimum
+
+// Blocks launched for the first pass of two-pass reductions
+const TWO_PASS_MAX_BLOCKS: usize = 1024;
+
+pub fn argmax<T>(input: &DeviceMemory<T>, len: usize) -> Result<usize>
 where
     T: NumericOps,
 {
-    matrix_multiply_async(a, b, c, m, k, n, &Stream::new()?)
+    arg_reduce("argmax", input, len, &*op_stream()?)
 }
 
-pub fn matrix_multiply_async<T>(
-    a: &DeviceMemory<T>,
-    b: &DeviceMemory<T>,
-    c: &DeviceMemory<T>,
-    m: usize,
-    k: usize,
-    n: usize,
+pub fn argmin<T>(input: &DeviceMemory<T>, len: usize) -> Result<usize>
+where
+    T: NumericOps,
+{
+    arg_reduce("argmin", input, len, &*op_stream()?)
+}
+
+/// Index of the first extreme element, found per block and then across
+/// the block results in a second single-block pass
+fn arg_reduce<T>(
+    kernel: &str,
+    input: &DeviceMemory<T>,
+    len: usize,
     stream: &Stream,
-) -> Result<()>
+) -> Result<usize>
 where
     T: NumericOps,
 {
-    let kernel_name = format!("matrix_multiply_{}", T::TYPE_NAME);
-    let function = get_kernel_function(&kernel_name)?;
+    if len == 0 {
+        return Err(crate::error::invalid_argument(
+            "Cannot reduce an empty array",
+        ));
+    }
 
-    // Use 2D grid for matrix multiplication
-    let block_x = 16;
-    let block_y = 16;
-    let grid_x = (n as u32 + block_x - 1) / block_x;
-    let grid_y = (m as u32 + block_y - 1) / block_y;
+    let function = get_kernel_function(&format!("{}_blocks_{}", kernel, T::TYPE_NAME))?;
 
-    let grid_dim = Dim3::new_2d(grid_x, grid_y);
-    let block_dim = Dim3::new_2d(block_x, block_y);
+    let block_size = 256;
+    let blocks = len.div_ceil(block_size as usize).min(TWO_PASS_MAX_BLOCKS);
+    let block_values = DeviceMemory::<T>::new(blocks)?;
+    let block_indices = DeviceMemory::<u32>::new(blocks)?;
+    let result_value = DeviceMemory::<T>::new(1)?;
+    let result_index = DeviceMemory::<u32>::new(1)?;
+    // Null: the first pass uses each element's own position
+    let no_indices = DeviceMemory::<u32>::new(0)?;
 
-    let m_u32 = m as u32;
-    let k_u32 = k as u32;
-    let n_u32 = n as u32;
+    let len_u32 = len as u32;
+    function.launch(
+        Dim3::new_1d(blocks as u32),
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(input, no_indices, len_u32, block_values, block_indices),
+    )?;
 
-    let mut kernel_args = [
-        a.as_ptr(),
-        b.as_ptr(),
-        c.as_ptr() as *mut c_void,
-        &m_u32 as *const u32 as *mut c_void,
-        &k_u32 as *const u32 as *mut c_void,
-        &n_u32 as *const u32 as *mut c_void,
-    ];
+    let blocks_u32 = blocks as u32;
+    function.launch(
+        Dim3::new_1d(1),
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(
+            block_values,
+            block_indices,
+            blocks_u32,
+            result_value,
+            result_index
+        ),
+    )?;
 
-    function.launch(grid_dim, block_dim, 0, Some(stream), &mut kernel_args)?;
-    Ok(())
+    let pending = result_index.copy_to_host_async(vec![0u32], stream)?;
+    Ok(stream.synchronize_memory(pending)?[0] as usize)
 }
 
-pub fn transpose<T>(
+pub fn argmax_axis<T>(
     input: &DeviceMemory<T>,
-    output: &DeviceMemory<T>,
+    output: &DeviceMemory<u32>,
     input_shape: &Shape,
-    output_shape: &Shape,
+    axis: usize,
 ) -> Result<()>
 where
-    T: TransposableOps,
+    T: NumericOps,
 {
-    transpose_async(input, output, input_shape, output_shape, &Stream::new()?)
+    arg_reduce_axis_async("argmax", input, output, input_shape, axis, &*op_stream()?)
 }
 
-pub fn transpose_async<T>(
+pub fn argmax_axis_async<T>(
     input: &DeviceMemory<T>,
-    output: &DeviceMemory<T>,
+    output: &DeviceMemory<u32>,
     input_shape: &Shape,
-    output_shape: &Shape,
+    axis: usize,
     stream: &Stream,
 ) -> Result<()>
 where
-    T: TransposableOps,
+    T: NumericOps,
 {
-    let kernel_name = format!("transpose_{}", T::TYPE_NAME);
-    let function = get_kernel_function(&kernel_name)?;
-
-    let block_size = 256;
-    let total_elements = input_shape.size();
-    let grid_dim = calculate_grid_1d(total_elements as u32, block_size);
-    let block_dim = Dim3::new_1d(block_size);
-
-    // Prepare shape data
-    let input_dims: Vec<u32> = input_shape.dims().iter().map(|&x| x as u32).collect();
-    let output_dims: Vec<u32> = output_shape.dims().iter().map(|&x| x as u32).collect();
-    let input_strides: Vec<u32> = input_shape.strides().iter().map(|&x| x as u32).collect();
-    let output_strides: Vec<u32> = output_shape.strides().iter().map(|&x| x as u32).collect();
-
-    let ndim = input_shape.ndim() as u32;
-    let total_elements_u32 = total_elements as u32;
-
-    let mut kernel_args = [
-        input.as_ptr(),
-        output.as_ptr() as *mut c_void,
-        input_dims.as_ptr() as *mut c_void,
-        input_strides.as_ptr() as *mut c_void,
-        output_dims.as_ptr() as *mut c_void,
-        output_strides.as_ptr() as *mut c_void,
-        &ndim as *const u32 as *mut c_void,
-        &total_elements_u32 as *const u32 as *mut c_void,
-    ];
-
-    function.launch(grid_dim, block_dim, 0, Some(stream), &mut kernel_args)?;
-    Ok(())
+    arg_reduce_axis_async("argmax", input, output, input_shape, axis, stream)
 }
 
-// =============================================================================
-// Indexing and slicing operations
-// =============================================================================
-
-pub fn get_element<T>(input: &DeviceMemory<T>, index: usize) -> Result<T>
+pub fn argmin_axis<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<u32>,
+    input_shape: &Shape,
+    axis: usize,
+) -> Result<()>
 where
-    T: Copy + Default + 'static,
+    T: NumericOps,
 {
-    // For single element access, copy to host
-    let mut result = vec![T::default(); 1];
-    let temp_device = DeviceMemory::<T>::new(1)?;
-
-    // Use copy kernel to get single element
-    let kernel_name = "copy_element";
-    let function = get_kernel_function(&kernel_name)?;
+    arg_reduce_axis_async("argmin", input, output, input_shape, axis, &*op_stream()?)
+}
+
+pub fn argmin_axis_async<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<u32>,
+    input_shape: &Shape,
+    axis: usize,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    arg_reduce_axis_async("argmin", input, output, input_shape, axis, stream)
+}
+
+fn arg_reduce_axis_async<T>(
+    kernel: &str,
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<u32>,
+    input_shape: &Shape,
+    axis: usize,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    let lanes = AxisLanes::new(input_shape, axis)?;
+    if lanes.axis_size == 0 {
+        return Err(crate::error::invalid_argument(
+            "Cannot reduce along an empty axis",
+        ));
+    }
+    if lanes.lanes == 0 {
+        return Ok(());
+    }
+
+    let function = get_kernel_function(&format!("{}_axis_{}", kernel, T::TYPE_NAME))?;
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(lanes.lanes as u32, block_size);
+    let lanes_u32 = lanes.lanes as u32;
+    let axis_size_u32 = lanes.axis_size as u32;
+    let inner_u32 = lanes.inner as u32;
+
+    function.launch(
+        grid_dim,
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(input, output, lanes_u32, axis_size_u32, inner_u32),
+    )?;
+    Ok(())
+}
+
+// Product
+
+/// Product of the first `len` elements; integer products wrap on overflow
+pub fn reduce_prod<T>(input: &DeviceMemory<T>, len: usize) -> Result<T>
+where
+    T: NumericOps,
+{
+    Ok(reduce_prod_async(input, len, &*op_stream()?)?.0)
+}
+
+/// Product of the first `len` elements, or `None` if an integer multiply
+/// overflowed
+pub fn reduce_prod_checked<T>(input: &DeviceMemory<T>, len: usize) -> Result<Option<T>>
+where
+    T: NumericOps,
+{
+    let (product, overflowed) = reduce_prod_async(input, len, &*op_stream()?)?;
+    Ok((!overflowed).then_some(product))
+}
+
+/// Product and overflow flag, reduced per block and then across the
+/// block results with the same kernel
+fn reduce_prod_async<T>(input: &DeviceMemory<T>, len: usize, stream: &Stream) -> Result<(T, bool)>
+where
+    T: NumericOps,
+{
+    let function = get_kernel_function(&format!("reduce_prod_blocks_{}", T::TYPE_NAME))?;
+
+    // At least one block so an empty product still yields 1
+    let block_size = 256;
+    let blocks = len
+        .div_ceil(block_size as usize)
+        .clamp(1, TWO_PASS_MAX_BLOCKS);
+    let block_results = DeviceMemory::<T>::new(blocks)?;
+    let result = DeviceMemory::<T>::new(1)?;
+    let mut overflowed = DeviceMemory::<u32>::new(1)?;
+    overflowed.memset_async(0, stream)?;
+
+    let len_u32 = len as u32;
+    function.launch(
+        Dim3::new_1d(blocks as u32),
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(input, len_u32, block_results, overflowed),
+    )?;
+
+    let blocks_u32 = blocks as u32;
+    function.launch(
+        Dim3::new_1d(1),
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(block_results, blocks_u32, result, overflowed),
+    )?;
+
+    let product = result.copy_to_host_async(vec![T::default()], stream)?;
+    let flag = overflowed.copy_to_host_async(vec![0u32], stream)?;
+    let (product, flag) = stream.synchronize_memory((product, flag))?;
+    Ok((product[0], flag[0] != 0))
+}
+
+/// Product along an axis; integer products wrap on overflow
+pub fn reduce_prod_axis<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    input_shape: &Shape,
+    axis: usize,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    reduce_prod_axis_async(input, output, input_shape, axis, &*op_stream()?)?;
+    Ok(())
+}
+
+/// Product along an axis, returning whether any integer product
+/// overflowed
+pub fn reduce_prod_axis_checked<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    input_shape: &Shape,
+    axis: usize,
+) -> Result<bool>
+where
+    T: NumericOps,
+{
+    reduce_prod_axis_async(input, output, input_shape, axis, &*op_stream()?)
+}
+
+fn reduce_prod_axis_async<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    input_shape: &Shape,
+    axis: usize,
+    stream: &Stream,
+) -> Result<bool>
+where
+    T: NumericOps,
+{
+    let lanes = AxisLanes::new(input_shape, axis)?;
+    if lanes.lanes == 0 {
+        return Ok(false);
+    }
+
+    let function = get_kernel_function(&format!("reduce_prod_axis_{}", T::TYPE_NAME))?;
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(lanes.lanes as u32, block_size);
+    let lanes_u32 = lanes.lanes as u32;
+    let axis_size_u32 = lanes.axis_size as u32;
+    let inner_u32 = lanes.inner as u32;
+    let mut overflowed = DeviceMemory::<u32>::new(1)?;
+    overflowed.memset_async(0, stream)?;
+
+    function.launch(
+        grid_dim,
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(
+            input,
+            output,
+            lanes_u32,
+            axis_size_u32,
+            inner_u32,
+            overflowed
+        ),
+    )?;
+
+    let flag = overflowed.copy_to_host_async(vec![0u32], stream)?;
+    Ok(stream.synchronize_memory(flag)?[0] != 0)
+}
+
+// Variance
+
+/// Running count, mean and sum of squared deviations; must match
+/// `Welford` in kernels.hip
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+struct Welford {
+    count: f64,
+    mean: f64,
+    m2: f64,
+}
+
+/// Population variance of the first `len` elements, computed on the device
+/// in a single pass
+pub fn variance<T>(input: &DeviceMemory<T>, len: usize) -> Result<f64>
+where
+    T: NumericOps,
+{
+    variance_async(input, len, &*op_stream()?)
+}
+
+pub fn variance_async<T>(input: &DeviceMemory<T>, len: usize, stream: &Stream) -> Result<f64>
+where
+    T: NumericOps,
+{
+    if len == 0 {
+        return Err(crate::error::invalid_argument(
+            "Cannot compute the variance of an empty array",
+        ));
+    }
+
+    let blocks_function = get_kernel_function(&format!("welford_blocks_{}", T::TYPE_NAME))?;
+    let merge_function = get_kernel_function("welford_merge_blocks")?;
+
+    let block_size = 256;
+    let blocks = len.div_ceil(block_size as usize).min(TWO_PASS_MAX_BLOCKS);
+    let partials = DeviceMemory::<Welford>::new(blocks)?;
+    let result = DeviceMemory::<Welford>::new(1)?;
+
+    let len_u32 = len as u32;
+    blocks_function.launch(
+        Dim3::new_1d(blocks as u32),
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(input, len_u32, partials),
+    )?;
+
+    let blocks_u32 = blocks as u32;
+    merge_function.launch(
+        Dim3::new_1d(1),
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(partials, blocks_u32, result),
+    )?;
+
+    let pending = result.copy_to_host_async(vec![Welford::default()], stream)?;
+    let welford = stream.synchronize_memory(pending)?[0];
+    Ok(welford.m2 / welford.count)
+}
+
+/// Population variance along an axis
+pub fn variance_axis<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<f64>,
+    input_shape: &Shape,
+    axis: usize,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    welford_axis_async(input, output, input_shape, axis, false, &*op_stream()?)
+}
+
+/// Population standard deviation along an axis
+pub fn std_axis<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<f64>,
+    input_shape: &Shape,
+    axis: usize,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    welford_axis_async(input, output, input_shape, axis, true, &*op_stream()?)
+}
+
+fn welford_axis_async<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<f64>,
+    input_shape: &Shape,
+    axis: usize,
+    take_sqrt: bool,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    let lanes = AxisLanes::new(input_shape, axis)?;
+    if lanes.axis_size == 0 {
+        return Err(crate::error::invalid_argument(
+            "Cannot compute the variance along an empty axis",
+        ));
+    }
+    if lanes.lanes == 0 {
+        return Ok(());
+    }
+
+    let function = get_kernel_function(&format!("welford_axis_{}", T::TYPE_NAME))?;
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(lanes.lanes as u32, block_size);
+    let lanes_u32 = lanes.lanes as u32;
+    let axis_size_u32 = lanes.axis_size as u32;
+    let inner_u32 = lanes.inner as u32;
+    let take_sqrt_u32 = take_sqrt as u32;
+
+    function.launch(
+        grid_dim,
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(
+            input,
+            output,
+            lanes_u32,
+            axis_size_u32,
+            inner_u32,
+            take_sqrt_u32
+        ),
+    )?;
+    Ok(())
+}
+
+// Prefix scans along an axis
+
+pub fn cumsum<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    input_shape: &Shape,
+    axis: usize,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    scan_async("cumsum", input, output, input_shape, axis, &*op_stream()?)
+}
+
+pub fn cumsum_async<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    input_shape: &Shape,
+    axis: usize,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    scan_async("cumsum", input, output, input_shape, axis, stream)
+}
+
+pub fn cumprod<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    input_shape: &Shape,
+    axis: usize,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    scan_async("cumprod", input, output, input_shape, axis, &*op_stream()?)
+}
+
+pub fn cumprod_async<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    input_shape: &Shape,
+    axis: usize,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    scan_async("cumprod", input, output, input_shape, axis, stream)
+}
+
+/// Launch an inclusive scan kernel with one block per lane
+fn scan_async<T>(
+    kernel: &str,
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    input_shape: &Shape,
+    axis: usize,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    let lanes = AxisLanes::new(input_shape, axis)?;
+    if lanes.lanes == 0 || lanes.axis_size == 0 {
+        return Ok(());
+    }
+
+    let function = get_kernel_function(&format!("{}_{}", kernel, T::TYPE_NAME))?;
+
+    // The kernel scans 2 * 256 elements per step in shared memory
+    let block_size = 256;
+    let axis_size_u32 = lanes.axis_size as u32;
+    let inner_u32 = lanes.inner as u32;
+
+    function.launch(
+        Dim3::new_1d(lanes.lanes as u32),
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(input, output, axis_size_u32, inner_u32),
+    )?;
+    Ok(())
+}
+
+// =============================================================================
+// Matrix operations
+// =============================================================================
+
+pub fn matrix_multiply<T>(
+    a: &DeviceMemory<T>,
+    b: &DeviceMemory<T>,
+    c: &DeviceMemory<T>,
+    m: usize,
+    k: usize,
+    n: usize,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    matrix_multiply_async(a, b, c, m, k, n, &*op_stream()?)
+}
+
+pub fn matrix_multiply_async<T>(
+    a: &DeviceMemory<T>,
+    b: &DeviceMemory<T>,
+    c: &DeviceMemory<T>,
+    m: usize,
+    k: usize,
+    n: usize,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    let kernel_name = format!("matrix_multiply_{}", T::TYPE_NAME);
+    let function = get_kernel_function(&kernel_name)?;
+
+    // Use 2D grid for matrix multiplication
+    let block_x = 16;
+    let block_y = 16;
+    let grid_x = (n as u32 + block_x - 1) / block_x;
+    let grid_y = (m as u32 + block_y - 1) / block_y;
+
+    let grid_dim = Dim3::new_2d(grid_x, grid_y);
+    let block_dim = Dim3::new_2d(block_x, block_y);
+
+    let m_u32 = m as u32;
+    let k_u32 = k as u32;
+    let n_u32 = n as u32;
+
+    let mut kernel_args = [
+        a.as_ptr(),
+        b.as_ptr(),
+        c.as_ptr() as *mut c_void,
+        &m_u32 as *const u32 as *mut c_void,
+        &k_u32 as *const u32 as *mut c_void,
+        &n_u32 as *const u32 as *mut c_void,
+    ];
+
+    function.launch(grid_dim, block_dim, 0, Some(stream), &mut kernel_args)?;
+    Ok(())
+}
+
+pub fn transpose<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    input_shape: &Shape,
+    output_shape: &Shape,
+) -> Result<()>
+where
+    T: TransposableOps,
+{
+    transpose_async(input, output, input_shape, output_shape, &*op_stream()?)
+}
+
+pub fn transpose_async<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    input_shape: &Shape,
+    output_shape: &Shape,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: TransposableOps,
+{
+    let kernel_name = format!("transpose_{}", T::TYPE_NAME);
+    let function = get_kernel_function(&kernel_name)?;
+
+    let block_size = 256;
+    let total_elements = input_shape.size();
+    let grid_dim = calculate_grid_1d(total_elements as u32, block_size);
+    let block_dim = Dim3::new_1d(block_size);
+
+    // Prepare shape data
+    let input_dims: Vec<u32> = input_shape.dims().iter().map(|&x| x as u32).collect();
+    let output_dims: Vec<u32> = output_shape.dims().iter().map(|&x| x as u32).collect();
+    let input_strides: Vec<u32> = input_shape.strides().iter().map(|&x| x as u32).collect();
+    let output_strides: Vec<u32> = output_shape.strides().iter().map(|&x| x as u32).collect();
+
+    let ndim = input_shape.ndim() as u32;
+    let total_elements_u32 = total_elements as u32;
+
+    let mut kernel_args = [
+        input.as_ptr(),
+        output.as_ptr() as *mut c_void,
+        input_dims.as_ptr() as *mut c_void,
+        input_strides.as_ptr() as *mut c_void,
+        output_dims.as_ptr() as *mut c_void,
+        output_strides.as_ptr() as *mut c_void,
+        &ndim as *const u32 as *mut c_void,
+        &total_elements_u32 as *const u32 as *mut c_void,
+    ];
+
+    function.launch(grid_dim, block_dim, 0, Some(stream), &mut kernel_args)?;
+    Ok(())
+}
+
+// =============================================================================
+// Indexing and slicing operations
+// =============================================================================
+
+pub fn get_element<T>(input: &DeviceMemory<T>, index: usize) -> Result<T>
+where
+    T: Copy + Default + 'static,
+{
+    // For single element access, copy to host
+    let mut result = vec![T::default(); 1];
+    let temp_device = DeviceMemory::<T>::new(1)?;
+
+    // Use copy kernel to get single element
+    let kernel_name = "copy_element";
+    let function = get_kernel_function(&kernel_name)?;
+
+    let index_u32 = index as u32;
+    let mut kernel_args = [
+        input.as_ptr(),
+        temp_device.as_ptr() as *mut c_void,
+        &index_u32 as *const u32 as *mut c_void,
+    ];
+
+    function.launch(Dim3::new_1d(1), Dim3::new_1d(1), 0, None, &mut kernel_args)?;
+    temp_device.copy_to_host(&mut result)?;
+    Ok(result[0])
+}
+
+pub fn set_element<T>(output: &mut DeviceMemory<T>, index: usize, value: T) -> Result<()>
+where
+    T: Copy + Default + 'static,
+{
+    let kernel_name = "set_element";
+    let function = get_kernel_function(&kernel_name)?;
 
     let index_u32 = index as u32;
+    let mut kernel_args = [
+        output.as_ptr() as *mut c_void,
+        &index_u32 as *const u32 as *mut c_void,
+        &value as *const T as *mut c_void,
+    ];
+
+    function.launch(Dim3::new_1d(1), Dim3::new_1d(1), 0, None, &mut kernel_args)?;
+    Ok(())
+}
+
+pub fn slice_first_dim<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    input_shape: &Shape,
+    start: usize,
+    end: usize,
+) -> Result<()>
+where
+    T: Copy + Default + 'static,
+{
+    slice_first_dim_async(input, output, input_shape, start, end, &*op_stream()?)
+}
+
+pub fn slice_first_dim_async<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    input_shape: &Shape,
+    start: usize,
+    end: usize,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: Copy + Default + 'static,
+{
+    let kernel_name = "slice_first_dim";
+    let function = get_kernel_function(&kernel_name)?;
+
+    let slice_len = end - start;
+    let elements_per_slice = input_shape.size() / input_shape.dims()[0];
+    let total_output_elements = slice_len * elements_per_slice;
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(total_output_elements as u32, block_size);
+    let block_dim = Dim3::new_1d(block_size);
+
+    let start_u32 = start as u32;
+    let slice_len_u32 = slice_len as u32;
+    let elements_per_slice_u32 = elements_per_slice as u32;
+    let total_output_elements_u32 = total_output_elements as u32;
+
+    let mut kernel_args = [
+        input.as_ptr(),
+        output.as_ptr() as *mut c_void,
+        &start_u32 as *const u32 as *mut c_void,
+        &slice_len_u32 as *const u32 as *mut c_void,
+        &elements_per_slice_u32 as *const u32 as *mut c_void,
+        &total_output_elements_u32 as *const u32 as *mut c_void,
+    ];
+
+    function.launch(grid_dim, block_dim, 0, Some(stream), &mut kernel_args)?;
+    Ok(())
+}
+
+pub fn extract_column<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    input_shape: &Shape,
+    col_index: usize,
+) -> Result<()>
+where
+    T: Copy + Default + 'static,
+{
+    extract_column_async(input, output, input_shape, col_index, &*op_stream()?)
+}
+
+pub fn extract_column_async<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    input_shape: &Shape,
+    col_index: usize,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: Copy + Default + 'static,
+{
+    let kernel_name = "extract_column";
+    let function = get_kernel_function(&kernel_name)?;
+
+    if input_shape.ndim() != 2 {
+        return Err(crate::error::Error::InvalidOperation(
+            "Extract column requires 2D array".to_string(),
+        ));
+    }
+
+    let rows = input_shape.dims()[0];
+    let cols = input_shape.dims()[1];
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(rows as u32, block_size);
+    let block_dim = Dim3::new_1d(block_size);
+
+    let rows_u32 = rows as u32;
+    let cols_u32 = cols as u32;
+    let col_index_u32 = col_index as u32;
+
+    let mut kernel_args = [
+        input.as_ptr(),
+        output.as_ptr() as *mut c_void,
+        &rows_u32 as *const u32 as *mut c_void,
+        &cols_u32 as *const u32 as *mut c_void,
+        &col_index_u32 as *const u32 as *mut c_void,
+    ];
+
+    function.launch(grid_dim, block_dim, 0, Some(stream), &mut kernel_args)?;
+    Ok(())
+}
+
+// =============================================================================
+// Map, filter, reduce operations
+// =============================================================================
+
+pub fn map<T, U, F>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<U>,
+    len: usize,
+    _func: F,
+) -> Result<()>
+where
+    T: Mappable<U>,
+    U: Copy + Default + 'static,
+    F: Fn(T) -> U,
+{
+    // In a real implementation, you'd need to compile the function into a kernel
+    // For now, this is a placeholder that would require kernel generation
+    let function = get_kernel_function(T::map_kernel_name())?;
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(len as u32, block_size);
+    let block_dim = Dim3::new_1d(block_size);
+
+    let len_u32 = len as u32;
+    let mut kernel_args = [
+        input.as_ptr(),
+        output.as_ptr() as *mut c_void,
+        &len_u32 as *const u32 as *mut c_void,
+    ];
+
+    function.launch(grid_dim, block_dim, 0, None, &mut kernel_args)?;
+    Ok(())
+}
+
+pub fn filter<T, F>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    len: usize,
+    _predicate: F,
+) -> Result<usize>
+where
+    T: Filterable,
+    F: Fn(T) -> bool,
+{
+    // In a real implementation, you'd need stream compaction algorithms
+    // This is a simplified placeholder
+    let function = get_kernel_function(T::filter_kernel_name())?;
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(len as u32, block_size);
+    let block_dim = Dim3::new_1d(block_size);
+
+    let mut count_buffer = DeviceMemory::<u32>::new(1)?;
+    let len_u32 = len as u32;
     let mut kernel_args = [
         input.as_ptr(),
-        temp_device.as_ptr() as *mut c_void,
-        &index_u32 as *const u32 as *mut c_void,
+        output.as_ptr() as *mut c_void,
+        &len_u32 as *const u32 as *mut c_void,
+        count_buffer.as_ptr() as *mut c_void,
+    ];
+
+    function.launch(grid_dim, block_dim, 0, None, &mut kernel_args)?;
+
+    let mut count = vec![0u32; 1];
+    count_buffer.copy_to_host(&mut count)?;
+    Ok(count[0] as usize)
+}
+
+pub fn reduce<T, F>(input: &DeviceMemory<T>, len: usize, initial: T, _func: F) -> Result<T>
+where
+    T: Reducible,
+    F: Fn(T, T) -> T,
+{
+    // Similar to sum, but with custom operation
+    let function = get_kernel_function(T::reduce_kernel_name())?;
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(len as u32, block_size);
+
+    let mut temp_result = DeviceMemory::<T>::new(1)?;
+    let len_u32 = len as u32;
+    let mut kernel_args = [
+        input.as_ptr(),
+        &len_u32 as *const u32 as *mut c_void,
+        &initial as *const T as *mut c_void,
+        temp_result.as_ptr() as *mut c_void,
+    ];
+
+    function.launch(
+        grid_dim,
+        Dim3::new_1d(block_size),
+        0,
+        None,
+        &mut kernel_args,
+    )?;
+
+    let mut result = vec![T::default(); 1];
+    temp_result.copy_to_host(&mut result)?;
+    Ok(result[0])
+}
+
+pub fn find_index<T, F>(input: &DeviceMemory<T>, len: usize, _predicate: F) -> Result<Option<usize>>
+where
+    T: Searchable,
+    F: Fn(T) -> bool,
+{
+    let function = get_kernel_function(T::search_kernel_name())?;
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(len as u32, block_size);
+
+    let mut index_buffer = DeviceMemory::<i32>::new(1)?;
+    // Initialize to -1 (not found)
+    let not_found = -1i32;
+    index_buffer.copy_from_host(&[not_found])?;
+
+    let len_u32 = len as u32;
+    let mut kernel_args = [
+        input.as_ptr(),
+        &len_u32 as *const u32 as *mut c_void,
+        index_buffer.as_ptr() as *mut c_void,
+    ];
+
+    function.launch(
+        grid_dim,
+        Dim3::new_1d(block_size),
+        0,
+        None,
+        &mut kernel_args,
+    )?;
+
+    let mut index = vec![-1i32; 1];
+    index_buffer.copy_to_host(&mut index)?;
+
+    if index[0] >= 0 {
+        Ok(Some(index[0] as usize))
+    } else {
+        Ok(None)
+    }
+}
+
+// =============================================================================
+// Range operations
+// =============================================================================
+
+pub fn calculate_range_len<T>(start: T, end: T, step: T) -> Result<usize>
+where
+    T: RangeOps + PartialOrd + std::ops::Sub<Output = T> + std::ops::Div<Output = T> + Into<f64>,
+{
+    if step.into() == 0.0 {
+        return Err(crate::error::Error::InvalidOperation(
+            "Step cannot be zero".to_string(),
+        ));
+    }
+
+    let diff = end - start;
+    let len = diff / step;
+    Ok(len.into().ceil() as usize)
+}
+
+pub fn fill_range<T>(output: &DeviceMemory<T>, start: T, step: T, len: usize) -> Result<()>
+where
+    T: RangeOps,
+{
+    fill_range_async(output, start, step, len, &*op_stream()?)
+}
+
+pub fn fill_range_async<T>(
+    output: &DeviceMemory<T>,
+    start: T,
+    step: T,
+    len: usize,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: RangeOps,
+{
+    let function = get_kernel_function(T::range_kernel_name())?;
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(len as u32, block_size);
+    let block_dim = Dim3::new_1d(block_size);
+
+    let len_u32 = len as u32;
+    let mut kernel_args = [
+        &start as *const T as *mut c_void,
+        &step as *const T as *mut c_void,
+        &len_u32 as *const u32 as *mut c_void,
+        output.as_ptr() as *mut c_void,
+    ];
+
+    function.launch(grid_dim, block_dim, 0, Some(stream), &mut kernel_args)?;
+    Ok(())
+}
+
+pub fn fill_linspace(output: &DeviceMemory<f64>, start: f64, step: f64, len: usize) -> Result<()> {
+    fill_linspace_async(output, start, step, len, &*op_stream()?)
+}
+
+pub fn fill_linspace_async(
+    output: &DeviceMemory<f64>,
+    start: f64,
+    step: f64,
+    len: usize,
+    stream: &Stream,
+) -> Result<()> {
+    let function = get_kernel_function("linspace_double")?;
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(len as u32, block_size);
+    let block_dim = Dim3::new_1d(block_size);
+
+    let len_u32 = len as u32;
+    let mut kernel_args = [
+        &start as *const f64 as *mut c_void,
+        &step as *const f64 as *mut c_void,
+        &len_u32 as *const u32 as *mut c_void,
+        output.as_ptr() as *mut c_void,
+    ];
+
+    function.launch(grid_dim, block_dim, 0, Some(stream), &mut kernel_args)?;
+    Ok(())
+}
+
+// =============================================================================
+// Utility functions
+// =============================================================================
+
+pub fn copy_memory<T>(src: &DeviceMemory<T>, dst: &DeviceMemory<T>, len: usize) -> Result<()>
+where
+    T: Copy + Default + 'static,
+{
+    copy_memory_async(src, dst, len, &*op_stream()?)
+}
+
+pub fn copy_memory_async<T>(
+    src: &DeviceMemory<T>,
+    dst: &DeviceMemory<T>,
+    len: usize,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: Copy + Default + 'static,
+{
+    let function = get_kernel_function("copy_memory")?;
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(len as u32, block_size);
+    let block_dim = Dim3::new_1d(block_size);
+
+    let len_u32 = len as u32;
+    let mut kernel_args = [
+        src.as_ptr(),
+        dst.as_ptr() as *mut c_void,
+        &len_u32 as *const u32 as *mut c_void,
+    ];
+
+    function.launch(grid_dim, block_dim, 0, Some(stream), &mut kernel_args)?;
+    Ok(())
+}
+
+pub fn fill_value<T>(output: &DeviceMemory<T>, value: T, len: usize) -> Result<()>
+where
+    T: Copy + Default + 'static,
+{
+    fill_value_async(output, value, len, &*op_stream()?)
+}
+
+pub fn fill_value_async<T>(
+    output: &DeviceMemory<T>,
+    value: T,
+    len: usize,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: Copy + Default + 'static,
+{
+    let function = get_kernel_function("fill_value")?;
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(len as u32, block_size);
+    let block_dim = Dim3::new_1d(block_size);
+
+    let len_u32 = len as u32;
+    let mut kernel_args = [
+        output.as_ptr() as *mut c_void,
+        &value as *const T as *mut c_void,
+        &len_u32 as *const u32 as *mut c_void,
     ];
 
-    function.launch(Dim3::new_1d(1), Dim3::new_1d(1), 0, None, &mut kernel_args)?;
-    temp_device.copy_to_host(&mut result)?;
-    Ok(result[0])
+    function.launch(grid_dim, block_dim, 0, Some(stream), &mut kernel_args)?;
+    Ok(())
+}
+
+pub fn reduce_max<T>(input: &DeviceMemory<T>, len: usize) -> Result<T>
+where
+    T: NumericOps + PartialOrd,
+{
+    reduce_max_async(input, len, &*op_stream()?)
+}
+
+pub fn reduce_max_async<T>(input: &DeviceMemory<T>, len: usize, stream: &Stream) -> Result<T>
+where
+    T: NumericOps + PartialOrd,
+{
+    reduce_max_device_async(input, len, stream)?.read(stream)
+}
+
+/// Find the maximum of `len` elements into a device scalar without
+/// synchronizing
+pub fn reduce_max_device_async<T>(
+    input: &DeviceMemory<T>,
+    len: usize,
+    stream: &Stream,
+) -> Result<DeviceScalar<T>>
+where
+    T: NumericOps + PartialOrd,
+{
+    reduce_extremum_device_async("reduce_max", input, len, stream)
+}
+
+fn reduce_extremum_device_async<T>(
+    kernel: &str,
+    input: &DeviceMemory<T>,
+    len: usize,
+    stream: &Stream,
+) -> Result<DeviceScalar<T>>
+where
+    T: NumericOps,
+{
+    if len == 0 {
+        return Err(crate::error::invalid_argument(
+            "Cannot reduce an empty array",
+        ));
+    }
+
+    let result = DeviceMemory::<T>::new(1)?;
+    launch_reduce(kernel, input, len, &result, stream)?;
+    Ok(DeviceScalar::from_memory(result))
+}
+
+/// Reduce `len > 0` elements into `result` with two launches of a
+/// block-tree kernel: one over the input, one over the per-block results
+///
+/// The block count depends only on `len`, so partials are always combined
+/// in the same order and floating-point results are reproducible.
+fn launch_reduce<T>(
+    kernel: &str,
+    input: &DeviceMemory<T>,
+    len: usize,
+    result: &DeviceMemory<T>,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    let function = get_kernel_function(&format!("{}_{}", kernel, T::TYPE_NAME))?;
+
+    let block_size = 256;
+    let blocks = len.div_ceil(block_size as usize).min(TWO_PASS_MAX_BLOCKS);
+    let block_results = DeviceMemory::<T>::new(blocks)?;
+
+    let len_u32 = len as u32;
+    function.launch(
+        Dim3::new_1d(blocks as u32),
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(input, len_u32, block_results),
+    )?;
+
+    let blocks_u32 = blocks as u32;
+    function.launch(
+        Dim3::new_1d(1),
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(block_results, blocks_u32, result),
+    )?;
+    Ok(())
+}
+
+// =============================================================================
+// Strided views
+// =============================================================================
+
+/// Copy the elements of a view into a contiguous buffer
+pub(crate) fn strided_gather_async<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    layout: &StridedLayout,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    launch_strided_copy("strided_gather", input, output, layout, stream)
+}
+
+/// Copy a contiguous buffer into the elements of a view
+pub(crate) fn strided_scatter_async<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    layout: &StridedLayout,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    launch_strided_copy("strided_scatter", input, output, layout, stream)
+}
+
+fn launch_strided_copy<T>(
+    kernel: &str,
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    layout: &StridedLayout,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    let len = layout.len();
+    if len == 0 {
+        return Ok(());
+    }
+
+    let function = get_kernel_function(&format!("{}_{}", kernel, T::TYPE_NAME))?;
+    let view_layout = layout.kernel_layout()?;
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(len as u32, block_size);
+    let len_u32 = len as u32;
+
+    function.launch(
+        grid_dim,
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(input, output, view_layout, len_u32),
+    )?;
+    Ok(())
+}
+
+/// Multiply two 2D views into a contiguous `c`, reading the operands
+/// through their strides
+pub(crate) fn matrix_multiply_strided_async<T>(
+    a: &DeviceMemory<T>,
+    a_layout: &StridedLayout,
+    b: &DeviceMemory<T>,
+    b_layout: &StridedLayout,
+    c: &DeviceMemory<T>,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    let [m, k] = [a_layout.dims()[0], a_layout.dims()[1]];
+    let n = b_layout.dims()[1];
+    if m == 0 || n == 0 {
+        return Ok(());
+    }
+
+    let function = get_kernel_function(&format!("matrix_multiply_strided_{}", T::TYPE_NAME))?;
+
+    let block_x = 16;
+    let block_y = 16;
+    let grid_dim = Dim3::new_2d((n as u32).div_ceil(block_x), (m as u32).div_ceil(block_y));
+    let block_dim = Dim3::new_2d(block_x, block_y);
+
+    let m_u32 = m as u32;
+    let k_u32 = k as u32;
+    let n_u32 = n as u32;
+    let a_view = a_layout.kernel_layout()?;
+    let b_view = b_layout.kernel_layout()?;
+
+    function.launch(
+        grid_dim,
+        block_dim,
+        0,
+        Some(stream),
+        kernel_args!(a, b, c, m_u32, k_u32, n_u32, a_view, b_view),
+    )?;
+    Ok(())
+}
+
+// =============================================================================
+// Selection
+// =============================================================================
+
+/// Elementwise `mask ? a : b` into `output`, broadcasting every operand to
+/// `output_shape`
+#[allow(clippy::too_many_arguments)]
+pub fn where_select<T>(
+    mask: &DeviceMemory<u8>,
+    a: &DeviceMemory<T>,
+    b: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    mask_shape: &Shape,
+    a_shape: &Shape,
+    b_shape: &Shape,
+    output_shape: &Shape,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    where_select_async(
+        mask,
+        a,
+        b,
+        output,
+        mask_shape,
+        a_shape,
+        b_shape,
+        output_shape,
+        &*op_stream()?,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn where_select_async<T>(
+    mask: &DeviceMemory<u8>,
+    a: &DeviceMemory<T>,
+    b: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    mask_shape: &Shape,
+    a_shape: &Shape,
+    b_shape: &Shape,
+    output_shape: &Shape,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    let broadcast = |shape: &Shape| {
+        StridedLayout::broadcast(shape, output_shape)
+            .ok_or_else(|| {
+                crate::error::custom_error("Shapes are not compatible for broadcasting")
+            })?
+            .kernel_layout()
+    };
+    let mask_layout = broadcast(mask_shape)?;
+    let a_layout = broadcast(a_shape)?;
+    let b_layout = broadcast(b_shape)?;
+
+    let len = output_shape.size();
+    if len == 0 {
+        return Ok(());
+    }
+
+    let function = get_kernel_function(&format!("where_{}", T::TYPE_NAME))?;
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(len as u32, block_size);
+    let len_u32 = len as u32;
+
+    function.launch(
+        grid_dim,
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(mask, a, b, output, mask_layout, a_layout, b_layout, len_u32),
+    )?;
+    Ok(())
+}
+
+// =============================================================================
+// Compaction
+// =============================================================================
+
+/// Flags of the kept elements and their inclusive scan, which gives each
+/// kept element its 1-based output position
+pub struct CompactionPlan {
+    flags: DeviceMemory<u32>,
+    positions: DeviceMemory<u32>,
+    count: usize,
+}
+
+impl CompactionPlan {
+    /// Number of elements that will be kept
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+/// Plan a compaction keeping the elements where `mask` is nonzero
+pub fn plan_compaction<M>(mask: &DeviceMemory<M>, len: usize) -> Result<CompactionPlan>
+where
+    M: NumericOps,
+{
+    plan_compaction_async(mask, len, &*op_stream()?)
+}
+
+pub fn plan_compaction_async<M>(
+    mask: &DeviceMemory<M>,
+    len: usize,
+    stream: &Stream,
+) -> Result<CompactionPlan>
+where
+    M: NumericOps,
+{
+    let flags = DeviceMemory::<u32>::new(len)?;
+    let positions = DeviceMemory::<u32>::new(len)?;
+    if len == 0 {
+        return Ok(CompactionPlan {
+            flags,
+            positions,
+            count: 0,
+        });
+    }
+
+    let function = get_kernel_function(&format!("nonzero_flags_{}", M::TYPE_NAME))?;
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(len as u32, block_size);
+    let len_u32 = len as u32;
+
+    function.launch(
+        grid_dim,
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(mask, flags, len_u32),
+    )?;
+
+    scan_async("cumsum", &flags, &positions, &Shape::new_1d(len), 0, stream)?;
+    let count = reduce_sum_device_async(&flags, len, stream)?.read(stream)?;
+
+    Ok(CompactionPlan {
+        flags,
+        positions,
+        count: count as usize,
+    })
+}
+
+/// Scatter the elements kept by `plan` into `output`, which must hold
+/// `plan.count()` elements
+pub fn compact<T>(
+    input: &DeviceMemory<T>,
+    plan: &CompactionPlan,
+    output: &DeviceMemory<T>,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    compact_async(input, plan, output, &*op_stream()?)
+}
+
+pub fn compact_async<T>(
+    input: &DeviceMemory<T>,
+    plan: &CompactionPlan,
+    output: &DeviceMemory<T>,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    if output.count() < plan.count {
+        return Err(crate::error::invalid_argument(
+            "Compaction output is smaller than the number of kept elements",
+        ));
+    }
+
+    let len = plan.flags.count();
+    if plan.count == 0 {
+        return Ok(());
+    }
+
+    let function = get_kernel_function(&format!("compact_{}", T::TYPE_NAME))?;
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(len as u32, block_size);
+    let len_u32 = len as u32;
+
+    function.launch(
+        grid_dim,
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(input, plan.flags, plan.positions, output, len_u32),
+    )?;
+    Ok(())
+}
+
+/// Count the nonzero elements among the first `len`
+pub fn count_nonzero<T>(input: &DeviceMemory<T>, len: usize) -> Result<usize>
+where
+    T: NumericOps,
+{
+    Ok(plan_compaction(input, len)?.count())
+}
+
+// =============================================================================
+// Gather and scatter
+// =============================================================================
+
+/// Copy `input` rows (runs of `row_len` elements, `rows` in total) picked
+/// by `indices` into consecutive rows of `output`
+///
+/// With `check_bounds` an out-of-range index is an error; without it the
+/// kernel reads out of bounds, so callers must have validated the indices.
+pub fn gather_rows<T>(
+    input: &DeviceMemory<T>,
+    indices: &DeviceMemory<u32>,
+    output: &DeviceMemory<T>,
+    row_len: usize,
+    rows: usize,
+    check_bounds: bool,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    let stream = op_stream()?;
+    launch_rows_by_index(
+        "gather_rows",
+        input,
+        indices,
+        output,
+        row_len,
+        rows,
+        check_bounds,
+        &stream,
+    )
+}
+
+/// Copy consecutive rows of `values` into the `output` rows picked by
+/// `indices`; with repeated indices one of the rows wins
+pub fn scatter_rows<T>(
+    values: &DeviceMemory<T>,
+    indices: &DeviceMemory<u32>,
+    output: &DeviceMemory<T>,
+    row_len: usize,
+    rows: usize,
+    check_bounds: bool,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    let stream = op_stream()?;
+    launch_rows_by_index(
+        "scatter_rows",
+        values,
+        indices,
+        output,
+        row_len,
+        rows,
+        check_bounds,
+        &stream,
+    )
 }
 
-pub fn set_element<T>(output: &mut DeviceMemory<T>, index: usize, value: T) -> Result<()>
+#[allow(clippy::too_many_arguments)]
+fn launch_rows_by_index<T>(
+    kernel: &str,
+    input: &DeviceMemory<T>,
+    indices: &DeviceMemory<u32>,
+    output: &DeviceMemory<T>,
+    row_len: usize,
+    rows: usize,
+    check_bounds: bool,
+    stream: &Stream,
+) -> Result<()>
 where
-    T: Copy + Default + 'static,
+    T: NumericOps,
 {
-    let kernel_name = "set_element";
-    let function = get_kernel_function(&kernel_name)?;
+    let len = indices.count() * row_len;
+    if len == 0 {
+        return Ok(());
+    }
+
+    let function = get_kernel_function(&format!("{}_{}", kernel, T::TYPE_NAME))?;
+
+    // Left empty (a null pointer) to skip the check
+    let mut out_of_bounds = DeviceMemory::<u32>::new(check_bounds as usize)?;
+    out_of_bounds.memset_async(0, stream)?;
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(len as u32, block_size);
+    let len_u32 = len as u32;
+    let row_len_u32 = row_len as u32;
+    let rows_u32 = rows as u32;
+
+    function.launch(
+        grid_dim,
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(
+            input,
+            indices,
+            output,
+            len_u32,
+            row_len_u32,
+            rows_u32,
+            out_of_bounds
+        ),
+    )?;
+
+    if check_bounds {
+        let flag = out_of_bounds.copy_to_host_async(vec![0u32], stream)?;
+        if stream.synchronize_memory(flag)?[0] != 0 {
+            return Err(crate::error::invalid_argument(format!(
+                "Index out of bounds for {} rows",
+                rows
+            )));
+        }
+    }
+    Ok(())
+}
+
+// =============================================================================
+// Unique
+// =============================================================================
+
+/// Runs of equal values in sorted data
+pub struct Runs {
+    plan: CompactionPlan,
+}
+
+impl Runs {
+    /// Number of distinct values
+    pub fn count(&self) -> usize {
+        self.plan.count()
+    }
+}
+
+/// Find the runs of equal values in the first `len` elements of `sorted`
+pub fn find_runs<T>(sorted: &DeviceMemory<T>, len: usize) -> Result<Runs>
+where
+    T: NumericOps,
+{
+    let stream = op_stream()?;
+    let heads = DeviceMemory::<u32>::new(len)?;
+    if len > 0 {
+        let function = get_kernel_function(&format!("run_heads_{}", T::TYPE_NAME))?;
+
+        let block_size = 256;
+        let grid_dim = calculate_grid_1d(len as u32, block_size);
+        let len_u32 = len as u32;
+
+        function.launch(
+            grid_dim,
+            Dim3::new_1d(block_size),
+            0,
+            Some(&stream),
+            kernel_args!(sorted, heads, len_u32),
+        )?;
+    }
+
+    Ok(Runs {
+        plan: plan_compaction_async(&heads, len, &stream)?,
+    })
+}
+
+/// Copy the first value of every run into `output`
+pub fn run_values<T>(sorted: &DeviceMemory<T>, runs: &Runs, output: &DeviceMemory<T>) -> Result<()>
+where
+    T: NumericOps,
+{
+    compact(sorted, &runs.plan, output)
+}
+
+/// Write the length of every run into `lengths`
+pub fn run_lengths(runs: &Runs, lengths: &DeviceMemory<u32>) -> Result<()> {
+    let count = runs.count();
+    if count == 0 {
+        return Ok(());
+    }
+
+    let stream = op_stream()?;
+    let starts = DeviceMemory::<u32>::new(count)?;
+    let len = runs.plan.flags.count();
+
+    let block_size = 256;
+    let len_u32 = len as u32;
+    let count_u32 = count as u32;
+
+    get_kernel_function("run_starts")?.launch(
+        calculate_grid_1d(len_u32, block_size),
+        Dim3::new_1d(block_size),
+        0,
+        Some(&stream),
+        kernel_args!(runs.plan.flags, runs.plan.positions, starts, len_u32),
+    )?;
+    get_kernel_function("run_lengths")?.launch(
+        calculate_grid_1d(count_u32, block_size),
+        Dim3::new_1d(block_size),
+        0,
+        Some(&stream),
+        kernel_args!(starts, lengths, count_u32, len_u32),
+    )?;
+    stream.finish()?;
+    Ok(())
+}
+
+/// Write the run number of every element into `inverse`, where
+/// `sort_indices` is the permutation that sorted the data
+pub fn run_inverse(
+    runs: &Runs,
+    sort_indices: &DeviceMemory<u32>,
+    inverse: &DeviceMemory<u32>,
+) -> Result<()> {
+    let len = runs.plan.flags.count();
+    if len == 0 {
+        return Ok(());
+    }
+
+    let stream = op_stream()?;
+    let block_size = 256;
+    let len_u32 = len as u32;
+
+    get_kernel_function("run_inverse")?.launch(
+        calculate_grid_1d(len_u32, block_size),
+        Dim3::new_1d(block_size),
+        0,
+        Some(&stream),
+        kernel_args!(sort_indices, runs.plan.positions, inverse, len_u32),
+    )?;
+    stream.finish()?;
+    Ok(())
+}
+
+// =============================================================================
+// Histogram
+// =============================================================================
+
+// Most bins counted in shared memory (16 KiB of u32 counters)
+const HISTOGRAM_SHARED_BINS: usize = 4096;
+
+/// Count the first `len` elements into `counts.count()` equal-width bins
+/// spanning `lo..=hi`; elements outside the range are ignored
+pub fn histogram<T>(
+    input: &DeviceMemory<T>,
+    len: usize,
+    counts: &mut DeviceMemory<u32>,
+    lo: f64,
+    hi: f64,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    histogram_async(input, len, counts, lo, hi, &*op_stream()?)
+}
+
+pub fn histogram_async<T>(
+    input: &DeviceMemory<T>,
+    len: usize,
+    counts: &mut DeviceMemory<u32>,
+    lo: f64,
+    hi: f64,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    let bins = counts.count();
+    if bins == 0 {
+        return Err(crate::error::invalid_argument(
+            "Histogram needs at least one bin",
+        ));
+    }
+    if !(lo.is_finite() && hi.is_finite() && lo < hi) {
+        return Err(crate::error::invalid_argument(format!(
+            "Invalid histogram range {}..={}",
+            lo, hi
+        )));
+    }
+
+    counts.memset_async(0, stream)?;
+    if len == 0 {
+        return Ok(());
+    }
+
+    let function = get_kernel_function(&format!("histogram_{}", T::TYPE_NAME))?;
+
+    let use_shared = bins <= HISTOGRAM_SHARED_BINS;
+    let shared_bytes = if use_shared {
+        (bins * std::mem::size_of::<u32>()) as u32
+    } else {
+        0
+    };
+
+    let block_size = 256;
+    let blocks = len.div_ceil(block_size as usize).min(TWO_PASS_MAX_BLOCKS);
+    let len_u32 = len as u32;
+    let bins_u32 = bins as u32;
+    let use_shared_u32 = use_shared as u32;
+
+    function.launch(
+        Dim3::new_1d(blocks as u32),
+        Dim3::new_1d(block_size),
+        shared_bytes,
+        Some(stream),
+        kernel_args!(input, len_u32, counts, bins_u32, lo, hi, use_shared_u32),
+    )?;
+    Ok(())
+}
+
+// =============================================================================
+// Type conversion
+// =============================================================================
+
+/// Convert the first `len` elements of `input` to `U`
+///
+/// Floats convert to integers with Rust `as` semantics: out-of-range values
+/// clamp and NaN becomes zero. Integer narrowing wraps, or clamps to the
+/// target range when `saturate` is set.
+pub fn cast<T, U>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<U>,
+    len: usize,
+    saturate: bool,
+) -> Result<()>
+where
+    T: NumericOps,
+    U: NumericOps,
+{
+    cast_async(input, output, len, saturate, &*op_stream()?)
+}
+
+pub fn cast_async<T, U>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<U>,
+    len: usize,
+    saturate: bool,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+    U: NumericOps,
+{
+    if len == 0 {
+        return Ok(());
+    }
+
+    let function = get_kernel_function(&format!("cast_{}_to_{}", T::TYPE_NAME, U::TYPE_NAME))?;
+
+    let block_size = 256;
+    let grid_dim = calculate_grid_1d(len as u32, block_size);
+    let len_u32 = len as u32;
+    let saturate_u32 = saturate as u32;
+
+    function.launch(
+        grid_dim,
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(input, output, len_u32, saturate_u32),
+    )?;
+    Ok(())
+}
+
+// =============================================================================
+// Multi-axis reductions
+// =============================================================================
+
+/// Sum over every axis in `axes` in a single pass; `output` holds the kept
+/// axes in their original order
+pub fn reduce_sum_axes<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    shape: &Shape,
+    axes: &[usize],
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    reduce_sum_axes_async(input, output, shape, axes, &*op_stream()?)
+}
+
+pub fn reduce_sum_axes_async<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    shape: &Shape,
+    axes: &[usize],
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    reduce_axes_async("reduce_sum_axes", input, output, shape, axes, stream)
+}
+
+/// Mean over every axis in `axes` in a single pass, accumulated in `f64`
+pub fn reduce_mean_axes<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<f64>,
+    shape: &Shape,
+    axes: &[usize],
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    reduce_mean_axes_async(input, output, shape, axes, &*op_stream()?)
+}
+
+pub fn reduce_mean_axes_async<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<f64>,
+    shape: &Shape,
+    axes: &[usize],
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    reduce_axes_async("reduce_mean_axes", input, output, shape, axes, stream)
+}
+
+fn reduce_axes_async<T, U>(
+    name: &str,
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<U>,
+    shape: &Shape,
+    axes: &[usize],
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    let ndim = shape.ndim();
+    let mut reduced = vec![false; ndim];
+    for &axis in axes {
+        if axis >= ndim || std::mem::replace(&mut reduced[axis], true) {
+            return Err(crate::error::custom_error(format!(
+                "Invalid reduction axes {:?} for {} dimensions",
+                axes, ndim
+            )));
+        }
+    }
+
+    // Kept axes first, then the reduced ones
+    let order: Vec<usize> = (0..ndim)
+        .filter(|&axis| !reduced[axis])
+        .chain((0..ndim).filter(|&axis| reduced[axis]))
+        .collect();
+    let layout = StridedLayout::contiguous(shape)
+        .permuted(&order)?
+        .kernel_layout()?;
+
+    let reduce_len: usize = axes.iter().map(|&axis| shape.dims()[axis]).product();
+    let outputs = shape.size().checked_div(reduce_len).unwrap_or(0);
+    if outputs == 0 {
+        return Ok(());
+    }
+
+    let function = get_kernel_function(&format!("{}_{}", name, T::TYPE_NAME))?;
+    let reduce_len_u32 = reduce_len as u32;
+
+    function.launch(
+        Dim3::new_1d(outputs as u32),
+        Dim3::new_1d(256),
+        0,
+        Some(stream),
+        kernel_args!(input, output, layout, reduce_len_u32),
+    )?;
+    Ok(())
+}
+
+// =============================================================================
+// Padding
+// =============================================================================
+
+/// How [`pad`] fills the new border
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadMode<T> {
+    /// Fill with a constant value
+    Constant(T),
+    /// Mirror about the edge elements without repeating them
+    Reflect,
+    /// Repeat the edge elements
+    Edge,
+}
+
+/// Kernel-side padding description; must match `PadLayout` in kernels.hip
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+struct PadLayout {
+    in_dims: [u32; MAX_VIEW_DIMS],
+    out_dims: [u32; MAX_VIEW_DIMS],
+    before: [u32; MAX_VIEW_DIMS],
+    ndim: u32,
+}
+
+impl AsKernelArg for PadLayout {
+    fn as_kernel_arg(&self) -> crate::hip::memory::KernelArg {
+        self as *const PadLayout as *mut c_void
+    }
+}
+
+/// Pad the contiguous `input` of `shape` by `(before, after)` elements per
+/// axis into `output`, whose shape is the padded shape
+pub fn pad<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    shape: &Shape,
+    pad_width: &[(usize, usize)],
+    mode: PadMode<T>,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    pad_async(input, output, shape, pad_width, mode, &*op_stream()?)
+}
+
+pub fn pad_async<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    shape: &Shape,
+    pad_width: &[(usize, usize)],
+    mode: PadMode<T>,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    let ndim = shape.ndim();
+    if pad_width.len() != ndim || ndim > MAX_VIEW_DIMS {
+        return Err(crate::error::invalid_argument(format!(
+            "Padding needs one (before, after) pair per axis for {} dimensions, got {}",
+            ndim,
+            pad_width.len()
+        )));
+    }
+
+    let to_u32 = |value: usize| {
+        u32::try_from(value).map_err(|_| crate::error::custom_error("Padded array is too large"))
+    };
+
+    let mut layout = PadLayout {
+        ndim: ndim as u32,
+        ..Default::default()
+    };
+    let mut len = 1usize;
+    for (i, (&dim, &(before, after))) in shape.dims().iter().zip(pad_width).enumerate() {
+        if dim == 0 && before + after > 0 && !matches!(mode, PadMode::Constant(_)) {
+            return Err(crate::error::invalid_argument(
+                "Only constant padding can extend an empty axis",
+            ));
+        }
+        let out_dim = dim + before + after;
+        layout.in_dims[i] = to_u32(dim)?;
+        layout.out_dims[i] = to_u32(out_dim)?;
+        layout.before[i] = to_u32(before)?;
+        len *= out_dim;
+    }
+    let len_u32 = to_u32(len)?;
+    if len == 0 {
+        return Ok(());
+    }
+
+    let (mode, value) = match mode {
+        PadMode::Constant(value) => (0u32, value),
+        PadMode::Reflect => (1, T::default()),
+        PadMode::Edge => (2, T::default()),
+    };
+
+    let function = get_kernel_function(&format!("pad_{}", T::TYPE_NAME))?;
+    let block_size = 256;
 
-    let index_u32 = index as u32;
     let mut kernel_args = [
-        output.as_ptr() as *mut c_void,
-        &index_u32 as *const u32 as *mut c_void,
+        input.as_ptr(),
+        output.as_ptr(),
+        layout.as_kernel_arg(),
+        &len_u32 as *const u32 as *mut c_void,
+        &mode as *const u32 as *mut c_void,
         &value as *const T as *mut c_void,
     ];
 
-    function.launch(Dim3::new_1d(1), Dim3::new_1d(1), 0, None, &mut kernel_args)?;
+    function.launch(
+        calculate_grid_1d(len_u32, block_size),
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        &mut kernel_args,
+    )?;
     Ok(())
 }
 
-pub fn slice_first_dim<T>(
+// =============================================================================
+// Flip and roll along an axis
+// =============================================================================
+
+/// Reverse the order of elements along `axis`
+pub fn flip_axis<T>(
     input: &DeviceMemory<T>,
     output: &DeviceMemory<T>,
     input_shape: &Shape,
-    start: usize,
-    end: usize,
+    axis: usize,
 ) -> Result<()>
 where
-    T: Copy + Default + 'static,
+    T: NumericOps,
 {
-    slice_first_dim_async(input, output, input_shape, start, end, &Stream::new()?)
+    flip_axis_async(input, output, input_shape, axis, &*op_stream()?)
 }
 
-pub fn slice_first_dim_async<T>(
+pub fn flip_axis_async<T>(
     input: &DeviceMemory<T>,
     output: &DeviceMemory<T>,
     input_shape: &Shape,
-    start: usize,
-    end: usize,
+    axis: usize,
     stream: &Stream,
 ) -> Result<()>
 where
-    T: Copy + Default + 'static,
+    T: NumericOps,
 {
-    let kernel_name = "slice_first_dim";
-    let function = get_kernel_function(&kernel_name)?;
-
-    let slice_len = end - start;
-    let elements_per_slice = input_shape.size() / input_shape.dims()[0];
-    let total_output_elements = slice_len * elements_per_slice;
+    let lanes = AxisLanes::new(input_shape, axis)?;
+    let len = input_shape.size();
+    if len == 0 {
+        return Ok(());
+    }
 
+    let function = get_kernel_function(&format!("flip_axis_{}", T::TYPE_NAME))?;
     let block_size = 256;
-    let grid_dim = calculate_grid_1d(total_output_elements as u32, block_size);
-    let block_dim = Dim3::new_1d(block_size);
-
-    let start_u32 = start as u32;
-    let slice_len_u32 = slice_len as u32;
-    let elements_per_slice_u32 = elements_per_slice as u32;
-    let total_output_elements_u32 = total_output_elements as u32;
-
-    let mut kernel_args = [
-        input.as_ptr(),
-        output.as_ptr() as *mut c_void,
-        &start_u32 as *const u32 as *mut c_void,
-        &slice_len_u32 as *const u32 as *mut c_void,
-        &elements_per_slice_u32 as *const u32 as *mut c_void,
-        &total_output_elements_u32 as *const u32 as *mut c_void,
-    ];
+    let len_u32 = len as u32;
+    let axis_size_u32 = lanes.axis_size as u32;
+    let inner_u32 = lanes.inner as u32;
 
-    function.launch(grid_dim, block_dim, 0, Some(stream), &mut kernel_args)?;
+    function.launch(
+        calculate_grid_1d(len_u32, block_size),
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(input, output, len_u32, axis_size_u32, inner_u32),
+    )?;
     Ok(())
 }
 
-pub fn extract_column<T>(
+/// Shift elements along `axis` by `shift` positions, wrapping those pushed
+/// past the end around to the start; negative shifts move towards the start
+pub fn roll_axis<T>(
     input: &DeviceMemory<T>,
     output: &DeviceMemory<T>,
     input_shape: &Shape,
-    col_index: usize,
+    shift: isize,
+    axis: usize,
 ) -> Result<()>
 where
-    T: Copy + Default + 'static,
+    T: NumericOps,
 {
-    extract_column_async(input, output, input_shape, col_index, &Stream::new()?)
+    roll_axis_async(input, output, input_shape, shift, axis, &*op_stream()?)
 }
 
-pub fn extract_column_async<T>(
+pub fn roll_axis_async<T>(
     input: &DeviceMemory<T>,
     output: &DeviceMemory<T>,
     input_shape: &Shape,
-    col_index: usize,
+    shift: isize,
+    axis: usize,
     stream: &Stream,
 ) -> Result<()>
 where
-    T: Copy + Default + 'static,
+    T: NumericOps,
 {
-    let kernel_name = "extract_column";
-    let function = get_kernel_function(&kernel_name)?;
-
-    if input_shape.ndim() != 2 {
-        return Err(crate::error::Error::InvalidOperation(
-            "Extract column requires 2D array".to_string(),
-        ));
+    let lanes = AxisLanes::new(input_shape, axis)?;
+    let len = input_shape.size();
+    if len == 0 {
+        return Ok(());
     }
 
-    let rows = input_shape.dims()[0];
-    let cols = input_shape.dims()[1];
-
+    let function = get_kernel_function(&format!("roll_axis_{}", T::TYPE_NAME))?;
     let block_size = 256;
-    let grid_dim = calculate_grid_1d(rows as u32, block_size);
-    let block_dim = Dim3::new_1d(block_size);
-
-    let rows_u32 = rows as u32;
-    let cols_u32 = cols as u32;
-    let col_index_u32 = col_index as u32;
-
-    let mut kernel_args = [
-        input.as_ptr(),
-        output.as_ptr() as *mut c_void,
-        &rows_u32 as *const u32 as *mut c_void,
-        &cols_u32 as *const u32 as *mut c_void,
-        &col_index_u32 as *const u32 as *mut c_void,
-    ];
+    let len_u32 = len as u32;
+    let axis_size_u32 = lanes.axis_size as u32;
+    let inner_u32 = lanes.inner as u32;
+    let shift_u32 = shift.rem_euclid(lanes.axis_size as isize) as u32;
 
-    function.launch(grid_dim, block_dim, 0, Some(stream), &mut kernel_args)?;
+    function.launch(
+        calculate_grid_1d(len_u32, block_size),
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(input, output, len_u32, axis_size_u32, inner_u32, shift_u32),
+    )?;
     Ok(())
 }
 
 // =============================================================================
-// Map, filter, reduce operations
+// Triangular and diagonal utilities
 // =============================================================================
 
-pub fn map<T, U, F>(
+/// Which side of the diagonal [`triangle`] keeps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Triangle {
+    Lower,
+    Upper,
+}
+
+/// Zero the elements of every trailing `rows` x `cols` matrix of `input`
+/// that lie above (lower) or below (upper) diagonal `k`
+pub fn triangle<T>(
     input: &DeviceMemory<T>,
-    output: &DeviceMemory<U>,
+    output: &DeviceMemory<T>,
     len: usize,
-    _func: F,
+    rows: usize,
+    cols: usize,
+    k: isize,
+    side: Triangle,
 ) -> Result<()>
 where
-    T: Mappable<U>,
-    U: Copy + Default + 'static,
-    F: Fn(T) -> U,
+    T: NumericOps,
 {
-    // In a real implementation, you'd need to compile the function into a kernel
-    // For now, this is a placeholder that would require kernel generation
-    let function = get_kernel_function(T::map_kernel_name())?;
+    triangle_async(input, output, len, rows, cols, k, side, &*op_stream()?)
+}
 
-    let block_size = 256;
-    let grid_dim = calculate_grid_1d(len as u32, block_size);
-    let block_dim = Dim3::new_1d(block_size);
+#[allow(clippy::too_many_arguments)]
+pub fn triangle_async<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    len: usize,
+    rows: usize,
+    cols: usize,
+    k: isize,
+    side: Triangle,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    if len == 0 {
+        return Ok(());
+    }
 
+    let function = get_kernel_function(&format!("triangle_{}", T::TYPE_NAME))?;
+    let block_size = 256;
     let len_u32 = len as u32;
-    let mut kernel_args = [
-        input.as_ptr(),
-        output.as_ptr() as *mut c_void,
-        &len_u32 as *const u32 as *mut c_void,
-    ];
+    let rows_u32 = rows as u32;
+    let cols_u32 = cols as u32;
+    // Diagonals past either edge select all or nothing, as any larger one does
+    let k_i32 = k.clamp(-(rows as isize), cols as isize) as i32;
+    let upper = (side == Triangle::Upper) as u32;
 
-    function.launch(grid_dim, block_dim, 0, None, &mut kernel_args)?;
+    function.launch(
+        calculate_grid_1d(len_u32, block_size),
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(input, output, len_u32, rows_u32, cols_u32, k_i32, upper),
+    )?;
     Ok(())
 }
 
-pub fn filter<T, F>(
+/// Where diagonal `k` of a `rows` x `cols` row-major matrix starts, and how
+/// many elements it has
+pub(crate) fn diagonal_span(rows: usize, cols: usize, k: isize) -> (usize, usize) {
+    let (row, col) = if k >= 0 {
+        (0, k.unsigned_abs())
+    } else {
+        (k.unsigned_abs(), 0)
+    };
+    let len = rows.saturating_sub(row).min(cols.saturating_sub(col));
+    if len == 0 {
+        return (0, 0);
+    }
+    (row * cols + col, len)
+}
+
+/// Copy `len` elements spaced `stride` apart from `offset` in `input` into
+/// the contiguous `output`
+pub fn diag_extract<T>(
     input: &DeviceMemory<T>,
     output: &DeviceMemory<T>,
     len: usize,
-    _predicate: F,
-) -> Result<usize>
+    stride: usize,
+    offset: usize,
+) -> Result<()>
 where
-    T: Filterable,
-    F: Fn(T) -> bool,
+    T: NumericOps,
 {
-    // In a real implementation, you'd need stream compaction algorithms
-    // This is a simplified placeholder
-    let function = get_kernel_function(T::filter_kernel_name())?;
-
-    let block_size = 256;
-    let grid_dim = calculate_grid_1d(len as u32, block_size);
-    let block_dim = Dim3::new_1d(block_size);
+    diag_extract_async(input, output, len, stride, offset, &*op_stream()?)
+}
 
-    let mut count_buffer = DeviceMemory::<u32>::new(1)?;
-    let len_u32 = len as u32;
-    let mut kernel_args = [
-        input.as_ptr(),
-        output.as_ptr() as *mut c_void,
-        &len_u32 as *const u32 as *mut c_void,
-        count_buffer.as_ptr() as *mut c_void,
-    ];
+pub fn diag_extract_async<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    len: usize,
+    stride: usize,
+    offset: usize,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    strided_copy_async("diag_extract", input, output, len, stride, offset, stream)
+}
 
-    function.launch(grid_dim, block_dim, 0, None, &mut kernel_args)?;
+/// Copy the contiguous `input` into `len` elements spaced `stride` apart
+/// from `offset` in `output`, leaving the rest of `output` untouched
+pub fn diag_embed<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    len: usize,
+    stride: usize,
+    offset: usize,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    diag_embed_async(input, output, len, stride, offset, &*op_stream()?)
+}
 
-    let mut count = vec![0u32; 1];
-    count_buffer.copy_to_host(&mut count)?;
-    Ok(count[0] as usize)
+pub fn diag_embed_async<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    len: usize,
+    stride: usize,
+    offset: usize,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    strided_copy_async("diag_embed", input, output, len, stride, offset, stream)
 }
 
-pub fn reduce<T, F>(input: &DeviceMemory<T>, len: usize, initial: T, _func: F) -> Result<T>
+fn strided_copy_async<T>(
+    kernel: &str,
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    len: usize,
+    stride: usize,
+    offset: usize,
+    stream: &Stream,
+) -> Result<()>
 where
-    T: Reducible,
-    F: Fn(T, T) -> T,
+    T: NumericOps,
 {
-    // Similar to sum, but with custom operation
-    let function = get_kernel_function(T::reduce_kernel_name())?;
+    if len == 0 {
+        return Ok(());
+    }
 
+    let function = get_kernel_function(&format!("{}_{}", kernel, T::TYPE_NAME))?;
     let block_size = 256;
-    let grid_dim = calculate_grid_1d(len as u32, block_size);
-
-    let mut temp_result = DeviceMemory::<T>::new(1)?;
     let len_u32 = len as u32;
-    let mut kernel_args = [
-        input.as_ptr(),
-        &len_u32 as *const u32 as *mut c_void,
-        &initial as *const T as *mut c_void,
-        temp_result.as_ptr() as *mut c_void,
-    ];
+    let stride_u32 = stride as u32;
+    let offset_u32 = offset as u32;
 
     function.launch(
-        grid_dim,
+        calculate_grid_1d(len_u32, block_size),
         Dim3::new_1d(block_size),
         0,
-        None,
-        &mut kernel_args,
+        Some(stream),
+        kernel_args!(input, output, len_u32, stride_u32, offset_u32),
     )?;
-
-    let mut result = vec![T::default(); 1];
-    temp_result.copy_to_host(&mut result)?;
-    Ok(result[0])
+    Ok(())
 }
 
-pub fn find_index<T, F>(input: &DeviceMemory<T>, len: usize, _predicate: F) -> Result<Option<usize>>
+/// Set `len` elements spaced `stride` apart from `offset` to `value`
+pub fn fill_diagonal<T>(
+    output: &DeviceMemory<T>,
+    len: usize,
+    stride: usize,
+    offset: usize,
+    value: T,
+) -> Result<()>
 where
-    T: Searchable,
-    F: Fn(T) -> bool,
+    T: NumericOps,
 {
-    let function = get_kernel_function(T::search_kernel_name())?;
-
-    let block_size = 256;
-    let grid_dim = calculate_grid_1d(len as u32, block_size);
+    fill_diagonal_async(output, len, stride, offset, value, &*op_stream()?)
+}
 
-    let mut index_buffer = DeviceMemory::<i32>::new(1)?;
-    // Initialize to -1 (not found)
-    let not_found = -1i32;
-    index_buffer.copy_from_host(&[not_found])?;
+pub fn fill_diagonal_async<T>(
+    output: &DeviceMemory<T>,
+    len: usize,
+    stride: usize,
+    offset: usize,
+    value: T,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    if len == 0 {
+        return Ok(());
+    }
 
+    let function = get_kernel_function(&format!("fill_diagonal_{}", T::TYPE_NAME))?;
+    let block_size = 256;
     let len_u32 = len as u32;
+    let stride_u32 = stride as u32;
+    let offset_u32 = offset as u32;
+
     let mut kernel_args = [
-        input.as_ptr(),
+        output.as_ptr(),
         &len_u32 as *const u32 as *mut c_void,
-        index_buffer.as_ptr() as *mut c_void,
+        &stride_u32 as *const u32 as *mut c_void,
+        &offset_u32 as *const u32 as *mut c_void,
+        &value as *const T as *mut c_void,
     ];
 
     function.launch(
-        grid_dim,
+        calculate_grid_1d(len_u32, block_size),
         Dim3::new_1d(block_size),
         0,
-        None,
+        Some(stream),
         &mut kernel_args,
     )?;
-
-    let mut index = vec![-1i32; 1];
-    index_buffer.copy_to_host(&mut index)?;
-
-    if index[0] >= 0 {
-        Ok(Some(index[0] as usize))
-    } else {
-        Ok(None)
-    }
+    Ok(())
 }
 
 // =============================================================================
-// Range operations
+// Random integer and Bernoulli transforms
 // =============================================================================
 
-pub fn calculate_range_len<T>(start: T, end: T, step: T) -> Result<usize>
-where
-    T: RangeOps + PartialOrd + std::ops::Sub<Output = T> + std::ops::Div<Output = T> + Into<f64>,
-{
-    if step.into() == 0.0 {
-        return Err(crate::error::Error::InvalidOperation(
-            "Step cannot be zero".to_string(),
-        ));
-    }
-
-    let diff = end - start;
-    let len = diff / step;
-    Ok(len.into().ceil() as usize)
-}
-
-pub fn fill_range<T>(output: &DeviceMemory<T>, start: T, step: T, len: usize) -> Result<()>
+/// Map uniform random words onto `[low, low + range)`, reading `words`
+/// (1 or 2) entries of `bits` per output element
+pub fn random_int_from_bits<T>(
+    bits: &DeviceMemory<u32>,
+    output: &DeviceMemory<T>,
+    len: usize,
+    low: T,
+    range: u64,
+    words: usize,
+) -> Result<()>
 where
-    T: RangeOps,
+    T: NumericOps,
 {
-    fill_range_async(output, start, step, len, &Stream::new()?)
+    random_int_from_bits_async(bits, output, len, low, range, words, &*op_stream()?)
 }
 
-pub fn fill_range_async<T>(
+pub fn random_int_from_bits_async<T>(
+    bits: &DeviceMemory<u32>,
     output: &DeviceMemory<T>,
-    start: T,
-    step: T,
     len: usize,
+    low: T,
+    range: u64,
+    words: usize,
     stream: &Stream,
 ) -> Result<()>
 where
-    T: RangeOps,
+    T: NumericOps,
 {
-    let function = get_kernel_function(T::range_kernel_name())?;
+    if len == 0 {
+        return Ok(());
+    }
 
+    let function = get_kernel_function(&format!("random_int_{}", T::TYPE_NAME))?;
     let block_size = 256;
-    let grid_dim = calculate_grid_1d(len as u32, block_size);
-    let block_dim = Dim3::new_1d(block_size);
-
     let len_u32 = len as u32;
+    let words_u32 = words as u32;
+
     let mut kernel_args = [
-        &start as *const T as *mut c_void,
-        &step as *const T as *mut c_void,
+        bits.as_ptr(),
+        output.as_ptr(),
         &len_u32 as *const u32 as *mut c_void,
-        output.as_ptr() as *mut c_void,
+        &low as *const T as *mut c_void,
+        &range as *const u64 as *mut c_void,
+        &words_u32 as *const u32 as *mut c_void,
     ];
 
-    function.launch(grid_dim, block_dim, 0, Some(stream), &mut kernel_args)?;
+    function.launch(
+        calculate_grid_1d(len_u32, block_size),
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        &mut kernel_args,
+    )?;
     Ok(())
 }
 
-pub fn fill_linspace(output: &DeviceMemory<f64>, start: f64, step: f64, len: usize) -> Result<()> {
-    fill_linspace_async(output, start, step, len, &Stream::new()?)
+/// Write 1 where the uniform value in `(0, 1]` is at most `p`, else 0
+pub fn bernoulli_from_uniform<T>(
+    uniform: &DeviceMemory<f32>,
+    output: &DeviceMemory<T>,
+    len: usize,
+    p: f32,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    bernoulli_from_uniform_async(uniform, output, len, p, &*op_stream()?)
 }
 
-pub fn fill_linspace_async(
-    output: &DeviceMemory<f64>,
-    start: f64,
-    step: f64,
+pub fn bernoulli_from_uniform_async<T>(
+    uniform: &DeviceMemory<f32>,
+    output: &DeviceMemory<T>,
     len: usize,
+    p: f32,
     stream: &Stream,
-) -> Result<()> {
-    let function = get_kernel_function("linspace_double")?;
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    if len == 0 {
+        return Ok(());
+    }
 
+    let function = get_kernel_function(&format!("bernoulli_{}", T::TYPE_NAME))?;
     let block_size = 256;
-    let grid_dim = calculate_grid_1d(len as u32, block_size);
-    let block_dim = Dim3::new_1d(block_size);
-
     let len_u32 = len as u32;
-    let mut kernel_args = [
-        &start as *const f64 as *mut c_void,
-        &step as *const f64 as *mut c_void,
-        &len_u32 as *const u32 as *mut c_void,
-        output.as_ptr() as *mut c_void,
-    ];
 
-    function.launch(grid_dim, block_dim, 0, Some(stream), &mut kernel_args)?;
+    function.launch(
+        calculate_grid_1d(len_u32, block_size),
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(uniform, output, len_u32, p),
+    )?;
     Ok(())
 }
 
 // =============================================================================
-// Utility functions
+// Multinomial sampling
 // =============================================================================
 
-pub fn copy_memory<T>(src: &DeviceMemory<T>, dst: &DeviceMemory<T>, len: usize) -> Result<()>
+/// Draw `num_samples` category indices per row of a `rows` x `categories`
+/// array of running weight sums, one per uniform value in `(0, 1]`
+pub fn multinomial_from_cdf<T>(
+    cdf: &DeviceMemory<T>,
+    uniform: &DeviceMemory<f64>,
+    output: &DeviceMemory<u32>,
+    rows: usize,
+    categories: usize,
+    num_samples: usize,
+) -> Result<()>
 where
-    T: Copy + Default + 'static,
+    T: NumericOps,
 {
-    copy_memory_async(src, dst, len, &Stream::new()?)
+    multinomial_from_cdf_async(
+        cdf,
+        uniform,
+        output,
+        rows,
+        categories,
+        num_samples,
+        &*op_stream()?,
+    )
 }
 
-pub fn copy_memory_async<T>(
-    src: &DeviceMemory<T>,
-    dst: &DeviceMemory<T>,
-    len: usize,
+pub fn multinomial_from_cdf_async<T>(
+    cdf: &DeviceMemory<T>,
+    uniform: &DeviceMemory<f64>,
+    output: &DeviceMemory<u32>,
+    rows: usize,
+    categories: usize,
+    num_samples: usize,
     stream: &Stream,
 ) -> Result<()>
 where
-    T: Copy + Default + 'static,
+    T: NumericOps,
 {
-    let function = get_kernel_function("copy_memory")?;
+    let len = rows * num_samples;
+    if len == 0 {
+        return Ok(());
+    }
+    if categories == 0 {
+        return Err(crate::error::invalid_argument(
+            "Cannot sample from an empty set of categories",
+        ));
+    }
 
+    let function = get_kernel_function(&format!("multinomial_{}", T::TYPE_NAME))?;
     let block_size = 256;
-    let grid_dim = calculate_grid_1d(len as u32, block_size);
-    let block_dim = Dim3::new_1d(block_size);
-
-    let len_u32 = len as u32;
-    let mut kernel_args = [
-        src.as_ptr(),
-        dst.as_ptr() as *mut c_void,
-        &len_u32 as *const u32 as *mut c_void,
-    ];
+    let rows_u32 = rows as u32;
+    let categories_u32 = categories as u32;
+    let num_samples_u32 = num_samples as u32;
 
-    function.launch(grid_dim, block_dim, 0, Some(stream), &mut kernel_args)?;
+    function.launch(
+        calculate_grid_1d(len as u32, block_size),
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(
+            cdf,
+            uniform,
+            output,
+            rows_u32,
+            categories_u32,
+            num_samples_u32
+        ),
+    )?;
     Ok(())
 }
 
-pub fn fill_value<T>(output: &DeviceMemory<T>, value: T, len: usize) -> Result<()>
+// =============================================================================
+// Element-wise math functions
+// =============================================================================
+
+/// Transcendental functions applied element-wise to float arrays
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathFunction {
+    Exp,
+    Log,
+    Sqrt,
+    Sin,
+    Cos,
+    Tanh,
+}
+
+impl MathFunction {
+    fn kernel_name(self) -> &'static str {
+        match self {
+            MathFunction::Exp => "exp",
+            MathFunction::Log => "log",
+            MathFunction::Sqrt => "sqrt",
+            MathFunction::Sin => "sin",
+            MathFunction::Cos => "cos",
+            MathFunction::Tanh => "tanh",
+        }
+    }
+}
+
+/// Apply `function` to every element
+pub fn unary_math<T>(
+    function: MathFunction,
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    len: usize,
+) -> Result<()>
 where
-    T: Copy + Default + 'static,
+    T: FloatOps,
 {
-    fill_value_async(output, value, len, &Stream::new()?)
+    unary_math_async(function, input, output, len, &*op_stream()?)
 }
 
-pub fn fill_value_async<T>(
+pub fn unary_math_async<T>(
+    function: MathFunction,
+    input: &DeviceMemory<T>,
     output: &DeviceMemory<T>,
-    value: T,
     len: usize,
     stream: &Stream,
 ) -> Result<()>
 where
-    T: Copy + Default + 'static,
+    T: FloatOps,
 {
-    let function = get_kernel_function("fill_value")?;
+    launch_unary(function.kernel_name(), input, output, len, stream)
+}
 
-    let block_size = 256;
-    let grid_dim = calculate_grid_1d(len as u32, block_size);
-    let block_dim = Dim3::new_1d(block_size);
+/// Absolute value of every element; complex elements give their magnitude
+pub fn abs<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T::Magnitude>,
+    len: usize,
+) -> Result<()>
+where
+    T: AbsOps,
+{
+    abs_async(input, output, len, &*op_stream()?)
+}
+
+pub fn abs_async<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T::Magnitude>,
+    len: usize,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: AbsOps,
+{
+    launch_unary("abs", input, output, len, stream)
+}
+
+/// Raise every element to the power `exponent`
+pub fn pow_scalar<T>(
+    input: &DeviceMemory<T>,
+    exponent: T,
+    output: &DeviceMemory<T>,
+    len: usize,
+) -> Result<()>
+where
+    T: FloatOps,
+{
+    pow_scalar_async(input, exponent, output, len, &*op_stream()?)
+}
+
+pub fn pow_scalar_async<T>(
+    input: &DeviceMemory<T>,
+    exponent: T,
+    output: &DeviceMemory<T>,
+    len: usize,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: FloatOps,
+{
+    if len == 0 {
+        return Ok(());
+    }
 
+    let function = get_kernel_function(&format!("pow_scalar_{}", T::TYPE_NAME))?;
+    let block_size = 256;
     let len_u32 = len as u32;
     let mut kernel_args = [
-        output.as_ptr() as *mut c_void,
-        &value as *const T as *mut c_void,
+        input.as_ptr(),
+        &exponent as *const T as *mut c_void,
+        output.as_ptr(),
         &len_u32 as *const u32 as *mut c_void,
     ];
 
-    function.launch(grid_dim, block_dim, 0, Some(stream), &mut kernel_args)?;
+    function.launch(
+        calculate_grid_1d(len_u32, block_size),
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        &mut kernel_args,
+    )?;
     Ok(())
 }
 
-pub fn reduce_max<T>(input: &DeviceMemory<T>, len: usize) -> Result<T>
+/// Limit every element to `[min, max]`
+pub fn clamp<T>(
+    input: &DeviceMemory<T>,
+    min: T,
+    max: T,
+    output: &DeviceMemory<T>,
+    len: usize,
+) -> Result<()>
 where
-    T: NumericOps + PartialOrd,
+    T: NumericOps,
 {
-    reduce_max_async(input, len, &Stream::new()?)
+    clamp_async(input, min, max, output, len, &*op_stream()?)
 }
 
-pub fn reduce_max_async<T>(input: &DeviceMemory<T>, len: usize, stream: &Stream) -> Result<T>
+pub fn clamp_async<T>(
+    input: &DeviceMemory<T>,
+    min: T,
+    max: T,
+    output: &DeviceMemory<T>,
+    len: usize,
+    stream: &Stream,
+) -> Result<()>
 where
-    T: NumericOps + PartialOrd,
+    T: NumericOps,
 {
-    let kernel_name = format!("reduce_max_{}", T::TYPE_NAME);
-    let function = get_kernel_function(&kernel_name)?;
-
-    let block_size = 256;
-    let grid_dim = calculate_grid_1d(len as u32, block_size);
-
-    let mut temp_result = DeviceMemory::<T>::new(1)?;
-    // Initialize with first element
-    if len > 0 {
-        let mut first_element = vec![T::default(); 1];
-        let first_device = DeviceMemory::<T>::new(1)?;
-        // Copy first element to initialize result
-        temp_result.copy_from_device(&first_device)?;
+    if len == 0 {
+        return Ok(());
     }
 
+    let function = get_kernel_function(&format!("clamp_{}", T::TYPE_NAME))?;
+    let block_size = 256;
     let len_u32 = len as u32;
     let mut kernel_args = [
         input.as_ptr(),
+        &min as *const T as *mut c_void,
+        &max as *const T as *mut c_void,
+        output.as_ptr(),
         &len_u32 as *const u32 as *mut c_void,
-        temp_result.as_ptr() as *mut c_void,
     ];
 
     function.launch(
-        grid_dim,
+        calculate_grid_1d(len_u32, block_size),
         Dim3::new_1d(block_size),
         0,
         Some(stream),
         &mut kernel_args,
     )?;
-    stream.synchronize()?;
+    Ok(())
+}
 
-    let mut result = vec![T::default(); 1];
-    temp_result.copy_to_host(&mut result)?;
-    Ok(result[0])
+/// Launch a `name_<type>(input, output, n)` kernel
+fn launch_unary<T, U>(
+    name: &str,
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<U>,
+    len: usize,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    if len == 0 {
+        return Ok(());
+    }
+
+    let function = get_kernel_function(&format!("{}_{}", name, T::TYPE_NAME))?;
+    let block_size = 256;
+    let len_u32 = len as u32;
+
+    function.launch(
+        calculate_grid_1d(len_u32, block_size),
+        Dim3::new_1d(block_size),
+        0,
+        Some(stream),
+        kernel_args!(input, output, len_u32),
+    )?;
+    Ok(())
+}
+
+// =============================================================================
+// Complex conjugate
+// =============================================================================
+
+/// Complex conjugate of every element
+#[cfg(feature = "num-complex")]
+pub fn complex_conj<T>(input: &DeviceMemory<T>, output: &DeviceMemory<T>, len: usize) -> Result<()>
+where
+    T: ComplexOps,
+{
+    complex_conj_async(input, output, len, &*op_stream()?)
+}
+
+#[cfg(feature = "num-complex")]
+pub fn complex_conj_async<T>(
+    input: &DeviceMemory<T>,
+    output: &DeviceMemory<T>,
+    len: usize,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: ComplexOps,
+{
+    launch_unary("complex_conj", input, output, len, stream)
 }
@@ -0,0 +1,147 @@
+// src/rocarray/math.rs - Element-wise math functions
+
+use crate::error::{Result, invalid_argument};
+use crate::rocarray::ROCArray;
+use crate::rocarray::kernels::{self, AbsOps, FloatOps, MathFunction, NumericOps};
+
+impl<T> ROCArray<T>
+where
+    T: NumericOps,
+{
+    /// Element-wise absolute value; complex arrays give their real magnitude
+    pub fn abs(&self) -> Result<ROCArray<T::Magnitude>>
+    where
+        T: AbsOps,
+    {
+        let result = ROCArray::new(self.shape.clone())?;
+        kernels::abs(&self.data, &result.data, self.len())?;
+        Ok(result)
+    }
+
+    /// Element-wise natural exponential
+    pub fn exp(&self) -> Result<ROCArray<T>>
+    where
+        T: FloatOps,
+    {
+        self.apply(MathFunction::Exp)
+    }
+
+    /// Element-wise natural logarithm
+    pub fn log(&self) -> Result<ROCArray<T>>
+    where
+        T: FloatOps,
+    {
+        self.apply(MathFunction::Log)
+    }
+
+    /// Element-wise square root
+    pub fn sqrt(&self) -> Result<ROCArray<T>>
+    where
+        T: FloatOps,
+    {
+        self.apply(MathFunction::Sqrt)
+    }
+
+    /// Element-wise sine
+    pub fn sin(&self) -> Result<ROCArray<T>>
+    where
+        T: FloatOps,
+    {
+        self.apply(MathFunction::Sin)
+    }
+
+    /// Element-wise cosine
+    pub fn cos(&self) -> Result<ROCArray<T>>
+    where
+        T: FloatOps,
+    {
+        self.apply(MathFunction::Cos)
+    }
+
+    /// Element-wise hyperbolic tangent
+    pub fn tanh(&self) -> Result<ROCArray<T>>
+    where
+        T: FloatOps,
+    {
+        self.apply(MathFunction::Tanh)
+    }
+
+    /// Raise every element to the power `exponent`
+    pub fn pow_scalar(&self, exponent: T) -> Result<ROCArray<T>>
+    where
+        T: FloatOps,
+    {
+        let result = ROCArray::new(self.shape.clone())?;
+        kernels::pow_scalar(&self.data, exponent, &result.data, self.len())?;
+        Ok(result)
+    }
+
+    /// Limit every element to the range `[min, max]`
+    pub fn clamp(&self, min: T, max: T) -> Result<ROCArray<T>>
+    where
+        T: PartialOrd,
+    {
+        if min > max {
+            return Err(invalid_argument("clamp requires min <= max"));
+        }
+        let result = ROCArray::new(self.shape.clone())?;
+        kernels::clamp(&self.data, min, max, &result.data, self.len())?;
+        Ok(result)
+    }
+
+    fn apply(&self, function: MathFunction) -> Result<ROCArray<T>>
+    where
+        T: FloatOps,
+    {
+        let result = ROCArray::new(self.shape.clone())?;
+        kernels::unary_math(function, &self.data, &result.data, self.len())?;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: Vec<f32>, expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-5, "{} != {}", a, e);
+        }
+    }
+
+    #[test]
+    fn test_transcendental_functions() -> Result<()> {
+        let values = vec![0.25f32, 1.0, 2.0, 4.0];
+        let a = ROCArray::from_vec(values.clone())?;
+
+        let expected = |f: fn(f32) -> f32| values.iter().map(|&x| f(x)).collect::<Vec<_>>();
+        assert_close(a.exp()?.to_vec()?, &expected(f32::exp));
+        assert_close(a.log()?.to_vec()?, &expected(f32::ln));
+        assert_close(a.sqrt()?.to_vec()?, &expected(f32::sqrt));
+        assert_close(a.sin()?.to_vec()?, &expected(f32::sin));
+        assert_close(a.cos()?.to_vec()?, &expected(f32::cos));
+        assert_close(a.tanh()?.to_vec()?, &expected(f32::tanh));
+        assert_close(a.pow_scalar(1.5)?.to_vec()?, &expected(|x| x.powf(1.5)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_abs_and_clamp() -> Result<()> {
+        let a = ROCArray::from_vec_with_shape(
+            vec![-3i32, -1, 0, 2, 5, -7],
+            crate::rocarray::Shape::new_2d(2, 3),
+        )?;
+        let abs = a.abs()?;
+        assert_eq!(abs.dims(), &[2, 3]);
+        assert_eq!(abs.to_vec()?, vec![3, 1, 0, 2, 5, 7]);
+        assert_eq!(a.clamp(-2, 3)?.to_vec()?, vec![-2, -1, 0, 2, 3, -2]);
+
+        let b = ROCArray::from_vec(vec![-1.5f64, 0.5, 2.5])?;
+        assert_eq!(b.abs()?.to_vec()?, vec![1.5, 0.5, 2.5]);
+        assert_eq!(b.clamp(0.0, 1.0)?.to_vec()?, vec![0.0, 0.5, 1.0]);
+
+        assert!(a.clamp(3, -2).is_err());
+        Ok(())
+    }
+}
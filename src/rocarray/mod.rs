@@ -7,9 +7,39 @@ use crate::hip::{DeviceMemory, Stream};
 use std::fmt;
 use std::marker::PhantomData;
 
+pub use context::Context;
+use context::op_stream;
+pub use einsum::einsum;
+pub use kernels::PadMode;
+pub use scalar::DeviceScalar;
+pub use sharded::ShardedROCArray;
+pub use snapshot::ROCArrayData;
+use view::StridedLayout;
+pub use view::{ROCArrayView, ROCArrayViewMut};
+
+#[cfg(feature = "num-complex")]
+mod complex;
+mod context;
+#[cfg(feature = "miopen")]
+mod conv;
+pub mod einsum;
+pub mod expr;
+#[cfg(feature = "rocfft")]
+mod fft;
+#[cfg(feature = "half")]
+mod half_precision;
+pub mod io;
 pub mod kernels;
+mod math;
+pub mod npy;
+mod ops;
 pub mod random;
+pub mod scalar;
+mod sharded;
+mod snapshot;
 pub mod sorting;
+pub mod timeseries;
+pub mod view;
 
 /// Shape information for multidimensional arrays
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -66,15 +96,13 @@ impl Shape {
 
         for i in 0..max_ndim {
             let dim1 = self
-                .dims
-                .get(self.ndim().saturating_sub(i + 1))
-                .copied()
-                .unwrap_or(1);
+                .ndim()
+                .checked_sub(i + 1)
+                .map_or(1, |axis| self.dims[axis]);
             let dim2 = other
-                .dims
-                .get(other.ndim().saturating_sub(i + 1))
-                .copied()
-                .unwrap_or(1);
+                .ndim()
+                .checked_sub(i + 1)
+                .map_or(1, |axis| other.dims[axis]);
 
             if dim1 != dim2 && dim1 != 1 && dim2 != 1 {
                 return false;
@@ -94,15 +122,13 @@ impl Shape {
 
         for i in 0..max_ndim {
             let dim1 = self
-                .dims
-                .get(self.ndim().saturating_sub(i + 1))
-                .copied()
-                .unwrap_or(1);
+                .ndim()
+                .checked_sub(i + 1)
+                .map_or(1, |axis| self.dims[axis]);
             let dim2 = other
-                .dims
-                .get(other.ndim().saturating_sub(i + 1))
-                .copied()
-                .unwrap_or(1);
+                .ndim()
+                .checked_sub(i + 1)
+                .map_or(1, |axis| other.dims[axis]);
             result_dims.push(dim1.max(dim2));
         }
 
@@ -326,33 +352,22 @@ where
     }
 
     /// Create a reshaped view of the array
-    pub fn reshaped(&self, new_dims: Vec<usize>) -> Result<ROCArray<T>> {
-        let new_size: usize = new_dims.iter().product();
-        if new_size != self.len() {
-            return Err(crate::error::custom_error(
-                "New shape must have the same total size".to_string(),
-            ));
-        }
-
-        // In practice, you'd want to create a view that shares the same memory
-        // For now, we'll create a copy
-        let mut result = Self::new(Shape::new(new_dims))?;
-        result.data.copy_from_device(&self.data)?;
-        Ok(result)
+    pub fn reshaped(&self, new_dims: Vec<usize>) -> Result<ROCArrayView<'_, T>> {
+        self.view().reshaped(new_dims)
     }
 
-    /// Transpose the array (reverse all dimensions)
-    pub fn transpose(&self) -> Result<ROCArray<T>>
-    where
-        T: kernels::TransposableOps,
-    {
-        let mut new_dims = self.shape.dims().to_vec();
-        new_dims.reverse();
-        let new_shape = Shape::new(new_dims);
+    /// Transpose the array (reverse all dimensions) as a strided view
+    ///
+    /// Nothing is copied; call `to_array` on the view when a contiguous
+    /// copy is needed.
+    pub fn transpose(&self) -> ROCArrayView<'_, T> {
+        self.view().transpose()
+    }
 
-        let mut result = ROCArray::new(new_shape.clone())?;
-        kernels::transpose(&self.data, &result.data, &self.shape, &new_shape)?;
-        Ok(result)
+    /// Reorder the axes as a strided view, so that axis `i` of the result
+    /// is axis `axes[i]` of this array
+    pub fn permute(&self, axes: &[usize]) -> Result<ROCArrayView<'_, T>> {
+        self.view().permute(axes)
     }
 
     /// Squeeze dimensions of size 1
@@ -374,6 +389,63 @@ where
         self.shape = Shape::new(new_dims);
     }
 
+    /// Output buffers passed to the `*_into` methods must match exactly
+    fn check_out_shape(&self, expected: &Shape) -> Result<()> {
+        if self.shape != *expected {
+            return Err(crate::error::custom_error(format!(
+                "Output has shape {:?}, expected {:?}",
+                self.shape.dims(),
+                expected.dims()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Shape left after reducing every axis in `axes`, which must be
+    /// distinct
+    fn reduced_shape_axes(&self, axes: &[usize], keepdims: bool) -> Result<Shape> {
+        let mut dims = self.shape.dims().to_vec();
+        for (i, &axis) in axes.iter().enumerate() {
+            if axis >= dims.len() || axes[..i].contains(&axis) {
+                return Err(crate::error::custom_error(format!(
+                    "Invalid reduction axes {:?} for {} dimensions",
+                    axes,
+                    dims.len()
+                )));
+            }
+        }
+
+        if keepdims {
+            for &axis in axes {
+                dims[axis] = 1;
+            }
+        } else {
+            dims = (0..dims.len())
+                .filter(|axis| !axes.contains(axis))
+                .map(|axis| dims[axis])
+                .collect();
+        }
+        if dims.is_empty() {
+            dims.push(1);
+        }
+        Ok(Shape::new(dims))
+    }
+
+    /// Shape left after reducing `axis` away
+    fn reduced_shape(&self, axis: usize) -> Result<Shape> {
+        if axis >= self.ndim() {
+            return Err(crate::error::custom_error("Axis out of bounds".to_string()));
+        }
+
+        let mut new_dims = self.shape.dims().to_vec();
+        new_dims.remove(axis);
+        if new_dims.is_empty() {
+            Ok(Shape::new(vec![1]))
+        } else {
+            Ok(Shape::new(new_dims))
+        }
+    }
+
     // Indexing and slicing methods
 
     /// Get element at specified indices
@@ -394,55 +466,58 @@ where
         kernels::set_element(&mut self.data, flat_index, value)
     }
 
+    /// View the whole array without copying
+    pub fn view(&self) -> ROCArrayView<'_, T> {
+        ROCArrayView::new(&self.data, StridedLayout::contiguous(&self.shape))
+    }
+
+    /// Mutably view the whole array without copying
+    pub fn view_mut(&mut self) -> ROCArrayViewMut<'_, T> {
+        ROCArrayViewMut::new(&mut self.data, StridedLayout::contiguous(&self.shape))
+    }
+
     /// Get a slice along the first dimension
-    pub fn slice(&self, start: usize, end: usize) -> Result<ROCArray<T>> {
-        if self.ndim() == 0 {
-            return Err(crate::error::custom_error(
-                "Cannot slice 0-dimensional array".to_string(),
-            ));
-        }
+    pub fn slice(&self, start: usize, end: usize) -> Result<ROCArrayView<'_, T>> {
+        self.view().slice(start, end)
+    }
 
-        let first_dim = self.shape.dims()[0];
-        if start >= first_dim || end > first_dim || start >= end {
-            return Err(crate::error::custom_error(
-                "Invalid slice indices".to_string(),
-            ));
-        }
+    /// Get row (for 2D arrays)
+    pub fn row(&self, index: usize) -> Result<ROCArrayView<'_, T>> {
+        self.view().row(index)
+    }
 
-        let mut new_dims = self.shape.dims().to_vec();
-        new_dims[0] = end - start;
-        let new_shape = Shape::new(new_dims);
+    /// Get column (for 2D arrays)
+    pub fn col(&self, index: usize) -> Result<ROCArrayView<'_, T>> {
+        self.view().col(index)
+    }
 
-        let mut result = ROCArray::new(new_shape)?;
-        kernels::slice_first_dim(&self.data, &result.data, &self.shape, start, end)?;
-        Ok(result)
+    /// Split `axis` into `sections` equal views
+    pub fn split(&self, axis: usize, sections: usize) -> Result<Vec<ROCArrayView<'_, T>>> {
+        self.view().split(axis, sections)
     }
 
-    /// Get row (for 2D arrays)
-    pub fn row(&self, index: usize) -> Result<ROCArray<T>> {
-        if self.ndim() != 2 {
-            return Err(crate::error::custom_error(
-                "Row access requires 2D array".to_string(),
-            ));
-        }
+    /// Split the first dimension into at most `n` views of equal length,
+    /// the last of which may be shorter, e.g. for minibatches
+    pub fn chunk(&self, n: usize) -> Result<Vec<ROCArrayView<'_, T>>> {
+        self.view().chunk(n)
+    }
 
-        let row_data = self.slice(index, index + 1)?;
-        let mut result = row_data;
-        result.reshape(vec![self.shape.dims()[1]])?;
-        Ok(result)
+    /// Get a mutable slice along the first dimension
+    pub fn slice_mut(&mut self, start: usize, end: usize) -> Result<ROCArrayViewMut<'_, T>> {
+        let layout = StridedLayout::contiguous(&self.shape).slice(start, end)?;
+        Ok(ROCArrayViewMut::new(&mut self.data, layout))
     }
 
-    /// Get column (for 2D arrays)
-    pub fn col(&self, index: usize) -> Result<ROCArray<T>> {
-        if self.ndim() != 2 {
-            return Err(crate::error::custom_error(
-                "Column access requires 2D array".to_string(),
-            ));
-        }
+    /// Get a mutable row (for 2D arrays)
+    pub fn row_mut(&mut self, index: usize) -> Result<ROCArrayViewMut<'_, T>> {
+        let layout = StridedLayout::contiguous(&self.shape).row(index)?;
+        Ok(ROCArrayViewMut::new(&mut self.data, layout))
+    }
 
-        let mut result = ROCArray::new_1d(self.shape.dims()[0])?;
-        kernels::extract_column(&self.data, &result.data, &self.shape, index)?;
-        Ok(result)
+    /// Get a mutable column (for 2D arrays)
+    pub fn col_mut(&mut self, index: usize) -> Result<ROCArrayViewMut<'_, T>> {
+        let layout = StridedLayout::contiguous(&self.shape).col(index)?;
+        Ok(ROCArrayViewMut::new(&mut self.data, layout))
     }
 
     // Data access methods
@@ -470,6 +545,28 @@ where
         &self.data
     }
 
+    /// Wrap device memory holding at least `shape.size()` row-major elements
+    pub(crate) fn from_device_memory(data: DeviceMemory<T>, shape: Shape) -> Result<Self> {
+        if data.count() < shape.size() {
+            return Err(crate::error::invalid_argument(
+                "device memory is smaller than the array shape",
+            ));
+        }
+
+        let capacity = data.count();
+        Ok(Self {
+            data,
+            shape,
+            capacity,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Take the underlying device memory
+    pub(crate) fn into_device_memory(self) -> DeviceMemory<T> {
+        self.data
+    }
+
     /// Copy from another ROCArray
     pub fn copy_from(&mut self, other: &ROCArray<T>) -> Result<()> {
         if other.len() > self.capacity {
@@ -498,178 +595,839 @@ where
 {
     /// Element-wise addition with broadcasting
     pub fn add(&self, other: &ROCArray<T>) -> Result<ROCArray<T>> {
-        let result_shape = self.shape.broadcast_with(&other.shape).ok_or_else(|| {
-            crate::error::custom_error("Shapes are not compatible for broadcasting".to_string())
-        })?;
-
-        let mut result = ROCArray::new(result_shape)?;
+        let mut result = ROCArray::new(self.broadcast_shape(other)?)?;
+        self.add_into(other, &mut result)?;
+        Ok(result)
+    }
 
+    /// Element-wise addition into `out`, which must have the broadcast shape
+    pub fn add_into(&self, other: &ROCArray<T>, out: &mut ROCArray<T>) -> Result<()> {
+        out.check_out_shape(&self.broadcast_shape(other)?)?;
         if self.shape == other.shape {
-            // Simple element-wise addition
-            kernels::elementwise_add(&self.data, &other.data, &result.data, self.len())?;
+            kernels::elementwise_add(&self.data, &other.data, &out.data, self.len())
         } else {
-            // Addition with broadcasting
             kernels::elementwise_add_broadcast(
                 &self.data,
                 &other.data,
-                &result.data,
+                &out.data,
                 &self.shape,
                 &other.shape,
-                &result.shape,
-            )?;
+                &out.shape,
+            )
         }
-
-        Ok(result)
     }
 
     /// Element-wise subtraction with broadcasting
     pub fn sub(&self, other: &ROCArray<T>) -> Result<ROCArray<T>> {
-        let result_shape = self.shape.broadcast_with(&other.shape).ok_or_else(|| {
-            crate::error::custom_error("Shapes are not compatible for broadcasting".to_string())
-        })?;
-
-        let mut result = ROCArray::new(result_shape)?;
+        let mut result = ROCArray::new(self.broadcast_shape(other)?)?;
+        self.sub_into(other, &mut result)?;
+        Ok(result)
+    }
 
+    /// Element-wise subtraction into `out`, which must have the broadcast shape
+    pub fn sub_into(&self, other: &ROCArray<T>, out: &mut ROCArray<T>) -> Result<()> {
+        out.check_out_shape(&self.broadcast_shape(other)?)?;
         if self.shape == other.shape {
-            kernels::elementwise_sub(&self.data, &other.data, &result.data, self.len())?;
+            kernels::elementwise_sub(&self.data, &other.data, &out.data, self.len())
         } else {
             kernels::elementwise_sub_broadcast(
                 &self.data,
                 &other.data,
-                &result.data,
+                &out.data,
                 &self.shape,
                 &other.shape,
-                &result.shape,
-            )?;
+                &out.shape,
+            )
         }
-
-        Ok(result)
     }
 
     /// Element-wise multiplication with broadcasting
     pub fn mul(&self, other: &ROCArray<T>) -> Result<ROCArray<T>> {
-        let result_shape = self.shape.broadcast_with(&other.shape).ok_or_else(|| {
-            crate::error::custom_error("Shapes are not compatible for broadcasting".to_string())
-        })?;
-
-        let mut result = ROCArray::new(result_shape)?;
+        let mut result = ROCArray::new(self.broadcast_shape(other)?)?;
+        self.mul_into(other, &mut result)?;
+        Ok(result)
+    }
 
+    /// Element-wise multiplication into `out`, which must have the broadcast shape
+    pub fn mul_into(&self, other: &ROCArray<T>, out: &mut ROCArray<T>) -> Result<()> {
+        out.check_out_shape(&self.broadcast_shape(other)?)?;
         if self.shape == other.shape {
-            kernels::elementwise_mul(&self.data, &other.data, &result.data, self.len())?;
+            kernels::elementwise_mul(&self.data, &other.data, &out.data, self.len())
         } else {
             kernels::elementwise_mul_broadcast(
                 &self.data,
                 &other.data,
-                &result.data,
+                &out.data,
                 &self.shape,
                 &other.shape,
-                &result.shape,
-            )?;
+                &out.shape,
+            )
         }
-
-        Ok(result)
     }
 
     /// Element-wise division with broadcasting
     pub fn div(&self, other: &ROCArray<T>) -> Result<ROCArray<T>> {
-        let result_shape = self.shape.broadcast_with(&other.shape).ok_or_else(|| {
-            crate::error::custom_error("Shapes are not compatible for broadcasting".to_string())
-        })?;
+        let mut result = ROCArray::new(self.broadcast_shape(other)?)?;
+        self.div_into(other, &mut result)?;
+        Ok(result)
+    }
 
-        let mut result = ROCArray::new(result_shape)?;
+    /// Element-wise division into `out`, which must have the broadcast shape
+    pub fn div_into(&self, other: &ROCArray<T>, out: &mut ROCArray<T>) -> Result<()> {
+        out.check_out_shape(&self.broadcast_shape(other)?)?;
+        if self.shape == other.shape {
+            kernels::elementwise_div(&self.data, &other.data, &out.data, self.len())
+        } else {
+            kernels::elementwise_div_broadcast(
+                &self.data,
+                &other.data,
+                &out.data,
+                &self.shape,
+                &other.shape,
+                &out.shape,
+            )
+        }
+    }
+
+    /// In-place element-wise addition, broadcasting `other` to the shape of
+    /// `self` without allocating
+    pub fn add_inplace(&mut self, other: &ROCArray<T>) -> Result<()> {
+        self.check_inplace_operand(other)?;
+        if self.shape == other.shape {
+            kernels::elementwise_add(&self.data, &other.data, &self.data, self.len())
+        } else {
+            kernels::elementwise_add_broadcast(
+                &self.data,
+                &other.data,
+                &self.data,
+                &self.shape,
+                &other.shape,
+                &self.shape,
+            )
+        }
+    }
+
+    /// In-place element-wise subtraction, broadcasting `other` to the shape of
+    /// `self` without allocating
+    pub fn sub_inplace(&mut self, other: &ROCArray<T>) -> Result<()> {
+        self.check_inplace_operand(other)?;
+        if self.shape == other.shape {
+            kernels::elementwise_sub(&self.data, &other.data, &self.data, self.len())
+        } else {
+            kernels::elementwise_sub_broadcast(
+                &self.data,
+                &other.data,
+                &self.data,
+                &self.shape,
+                &other.shape,
+                &self.shape,
+            )
+        }
+    }
 
+    /// In-place element-wise multiplication, broadcasting `other` to the shape of
+    /// `self` without allocating
+    pub fn mul_inplace(&mut self, other: &ROCArray<T>) -> Result<()> {
+        self.check_inplace_operand(other)?;
         if self.shape == other.shape {
-            kernels::elementwise_div(&self.data, &other.data, &result.data, self.len())?;
+            kernels::elementwise_mul(&self.data, &other.data, &self.data, self.len())
+        } else {
+            kernels::elementwise_mul_broadcast(
+                &self.data,
+                &other.data,
+                &self.data,
+                &self.shape,
+                &other.shape,
+                &self.shape,
+            )
+        }
+    }
+
+    /// In-place element-wise division, broadcasting `other` to the shape of
+    /// `self` without allocating
+    pub fn div_inplace(&mut self, other: &ROCArray<T>) -> Result<()> {
+        self.check_inplace_operand(other)?;
+        if self.shape == other.shape {
+            kernels::elementwise_div(&self.data, &other.data, &self.data, self.len())
         } else {
             kernels::elementwise_div_broadcast(
                 &self.data,
                 &other.data,
-                &result.data,
+                &self.data,
                 &self.shape,
                 &other.shape,
-                &result.shape,
-            )?;
+                &self.shape,
+            )
+        }
+    }
+
+    fn broadcast_shape(&self, other: &ROCArray<T>) -> Result<Shape> {
+        self.shape.broadcast_with(&other.shape).ok_or_else(|| {
+            crate::error::custom_error("Shapes are not compatible for broadcasting".to_string())
+        })
+    }
+
+    /// In-place operands must broadcast to the shape of `self`
+    fn check_inplace_operand(&self, other: &ROCArray<T>) -> Result<()> {
+        if self.shape.broadcast_with(&other.shape).as_ref() != Some(&self.shape) {
+            return Err(crate::error::custom_error(format!(
+                "Shape {:?} does not broadcast to {:?} for an in-place operation",
+                other.shape.dims(),
+                self.shape.dims()
+            )));
         }
+        Ok(())
+    }
+
+    /// Elementwise select: `self` where `mask` is nonzero, `other` elsewhere
+    ///
+    /// All three arrays are broadcast together, like NumPy's
+    /// `where(mask, self, other)`.
+    pub fn r#where(&self, mask: &ROCArray<u8>, other: &ROCArray<T>) -> Result<ROCArray<T>> {
+        let result_shape = mask
+            .shape
+            .broadcast_with(&self.shape)
+            .and_then(|shape| shape.broadcast_with(&other.shape))
+            .ok_or_else(|| {
+                crate::error::custom_error("Shapes are not compatible for broadcasting")
+            })?;
+
+        let result = ROCArray::new(result_shape)?;
+        kernels::where_select(
+            &mask.data,
+            &self.data,
+            &other.data,
+            &result.data,
+            &mask.shape,
+            &self.shape,
+            &other.shape,
+            &result.shape,
+        )?;
+        Ok(result)
+    }
+
+    /// Copy the elements where `mask` is nonzero into a new 1D array, in
+    /// row-major order
+    ///
+    /// The compaction runs on the device; only the number of selected
+    /// elements is read back to size the result.
+    pub fn masked_select(&self, mask: &ROCArray<u8>) -> Result<ROCArray<T>> {
+        if mask.shape != self.shape {
+            return Err(crate::error::custom_error(format!(
+                "Mask shape {:?} doesn't match array shape {:?}",
+                mask.dims(),
+                self.dims()
+            )));
+        }
+
+        let plan = kernels::plan_compaction(&mask.data, self.len())?;
+        let result = ROCArray::new_1d(plan.count())?;
+        kernels::compact(&self.data, &plan, &result.data)?;
+        Ok(result)
+    }
+
+    /// Count the nonzero elements
+    pub fn count_nonzero(&self) -> Result<usize> {
+        kernels::count_nonzero(&self.data, self.len())
+    }
+
+    /// Read the elements at several flat (row-major) indices with a single
+    /// gather kernel and one copy back to the host
+    ///
+    /// Prefer this to calling [`get`](Self::get) in a loop, which pays a
+    /// kernel launch and a device round trip per element.
+    pub fn get_many(&self, indices: &[usize]) -> Result<Vec<T>> {
+        if indices.is_empty() {
+            return Ok(Vec::new());
+        }
+        let indices = self.flat_indices(indices.iter().copied())?;
+        let output = DeviceMemory::<T>::new(indices.count())?;
+        kernels::gather_rows(&self.data, &indices, &output, 1, self.len(), false)?;
+
+        let mut values = vec![T::default(); indices.count()];
+        output.copy_to_host(&mut values)?;
+        Ok(values)
+    }
+
+    /// Write several `(flat index, value)` pairs with a single scatter
+    /// kernel
+    ///
+    /// If an index appears more than once, which of its values is kept is
+    /// unspecified.
+    pub fn set_many(&mut self, updates: &[(usize, T)]) -> Result<()> {
+        if updates.is_empty() {
+            return Ok(());
+        }
+        let indices = self.flat_indices(updates.iter().map(|&(index, _)| index))?;
+        let mut values = DeviceMemory::<T>::new(updates.len())?;
+        values.copy_from_host(&updates.iter().map(|&(_, value)| value).collect::<Vec<_>>())?;
+        kernels::scatter_rows(&values, &indices, &self.data, 1, self.len(), false)
+    }
+
+    // Check flat indices on the host and upload them for a gather or scatter
+    fn flat_indices(&self, indices: impl Iterator<Item = usize>) -> Result<DeviceMemory<u32>> {
+        let len = self.len();
+        let indices = indices
+            .map(|index| {
+                if index < len {
+                    Ok(index as u32)
+                } else {
+                    Err(crate::error::invalid_argument(format!(
+                        "Index {} is out of bounds for an array of {} elements",
+                        index, len
+                    )))
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut memory = DeviceMemory::new(indices.len())?;
+        memory.copy_from_host(&indices)?;
+        Ok(memory)
+    }
+
+    /// Pick rows along the first dimension by index
+    ///
+    /// The result has shape `indices.dims() ++ self.dims()[1..]`, so a
+    /// `[vocab, dim]` table gathered with `[batch, seq]` token ids gives
+    /// `[batch, seq, dim]` embeddings.
+    pub fn gather(&self, indices: &ROCArray<u32>) -> Result<ROCArray<T>> {
+        let (result_shape, row_len) = self.gathered_shape(indices);
+        let result = ROCArray::new(result_shape)?;
+        kernels::gather_rows(
+            &self.data,
+            &indices.data,
+            &result.data,
+            row_len,
+            self.dims()[0],
+            true,
+        )?;
+        Ok(result)
+    }
+
+    /// Like [`gather`](Self::gather) without checking the indices on the
+    /// device
+    ///
+    /// # Safety
+    ///
+    /// Every index must be less than `self.dims()[0]`.
+    pub unsafe fn gather_unchecked(&self, indices: &ROCArray<u32>) -> Result<ROCArray<T>> {
+        let (result_shape, row_len) = self.gathered_shape(indices);
+        let result = ROCArray::new(result_shape)?;
+        kernels::gather_rows(
+            &self.data,
+            &indices.data,
+            &result.data,
+            row_len,
+            self.dims()[0],
+            false,
+        )?;
+        Ok(result)
+    }
+
+    /// Overwrite rows along the first dimension by index
+    ///
+    /// `values` must have shape `indices.dims() ++ self.dims()[1..]`. When
+    /// an index repeats, which of its rows lands is unspecified.
+    pub fn scatter(&mut self, indices: &ROCArray<u32>, values: &ROCArray<T>) -> Result<()> {
+        let row_len = self.check_scatter_shape(indices, values)?;
+        kernels::scatter_rows(
+            &values.data,
+            &indices.data,
+            &self.data,
+            row_len,
+            self.dims()[0],
+            true,
+        )
+    }
+
+    /// Like [`scatter`](Self::scatter) without checking the indices on the
+    /// device
+    ///
+    /// # Safety
+    ///
+    /// Every index must be less than `self.dims()[0]`.
+    pub unsafe fn scatter_unchecked(
+        &mut self,
+        indices: &ROCArray<u32>,
+        values: &ROCArray<T>,
+    ) -> Result<()> {
+        let row_len = self.check_scatter_shape(indices, values)?;
+        kernels::scatter_rows(
+            &values.data,
+            &indices.data,
+            &self.data,
+            row_len,
+            self.dims()[0],
+            false,
+        )
+    }
+
+    /// Result shape and row length of a gather
+    fn gathered_shape(&self, indices: &ROCArray<u32>) -> (Shape, usize) {
+        let row_dims = &self.dims()[1..];
+        let mut dims = indices.dims().to_vec();
+        dims.extend_from_slice(row_dims);
+        (Shape::new(dims), row_dims.iter().product())
+    }
+
+    /// Row length of a scatter, after checking the shape of `values`
+    fn check_scatter_shape(&self, indices: &ROCArray<u32>, values: &ROCArray<T>) -> Result<usize> {
+        let (expected, row_len) = self.gathered_shape(indices);
+        if values.shape != expected {
+            return Err(crate::error::custom_error(format!(
+                "Values shape {:?} doesn't match expected shape {:?}",
+                values.dims(),
+                expected.dims()
+            )));
+        }
+        Ok(row_len)
+    }
+
+    /// Join arrays along an existing axis with device-side copies
+    ///
+    /// All arrays must have the same dimensions except along `axis`.
+    pub fn concat(arrays: &[&ROCArray<T>], axis: usize) -> Result<ROCArray<T>> {
+        let first = arrays
+            .first()
+            .ok_or_else(|| crate::error::invalid_argument("Nothing to concatenate"))?;
+        if axis >= first.ndim() {
+            return Err(crate::error::custom_error("Axis out of bounds".to_string()));
+        }
+
+        let mut dims = first.dims().to_vec();
+        dims[axis] = 0;
+        for array in arrays {
+            let compatible = array.ndim() == dims.len()
+                && array
+                    .dims()
+                    .iter()
+                    .zip(&dims)
+                    .enumerate()
+                    .all(|(i, (a, b))| i == axis || a == b);
+            if !compatible {
+                return Err(crate::error::custom_error(format!(
+                    "Cannot concatenate shape {:?} with {:?} along axis {}",
+                    array.dims(),
+                    first.dims(),
+                    axis
+                )));
+            }
+            dims[axis] += array.dims()[axis];
+        }
+
+        let result = ROCArray::new(Shape::new(dims))?;
+        let stream = op_stream()?;
+        let mut start = 0;
+        for array in arrays {
+            let len = array.dims()[axis];
+            let layout = StridedLayout::contiguous(&result.shape).narrow(axis, start, len)?;
+            kernels::strided_scatter_async(&array.data, &result.data, &layout, &stream)?;
+            start += len;
+        }
+        stream.finish()?;
+        Ok(result)
+    }
+
+    /// Join arrays of identical shape along a new axis
+    pub fn stack(arrays: &[&ROCArray<T>], axis: usize) -> Result<ROCArray<T>> {
+        let first = arrays
+            .first()
+            .ok_or_else(|| crate::error::invalid_argument("Nothing to stack"))?;
+        if axis > first.ndim() {
+            return Err(crate::error::custom_error("Axis out of bounds".to_string()));
+        }
+        if arrays.iter().any(|array| array.shape != first.shape) {
+            return Err(crate::error::custom_error(
+                "Stacked arrays must have the same shape".to_string(),
+            ));
+        }
+
+        let mut dims = first.dims().to_vec();
+        dims.insert(axis, arrays.len());
+
+        // Each array fills index i of the new axis
+        let result = ROCArray::new(Shape::new(dims))?;
+        let stream = op_stream()?;
+        for (i, array) in arrays.iter().enumerate() {
+            let layout = StridedLayout::contiguous(&result.shape)
+                .narrow(axis, i, 1)?
+                .squeeze_axis(axis);
+            kernels::strided_scatter_async(&array.data, &result.data, &layout, &stream)?;
+        }
+        stream.finish()?;
+        Ok(result)
+    }
+
+    /// Reverse the order of elements along `axis`
+    pub fn flip(&self, axis: usize) -> Result<ROCArray<T>> {
+        let result = ROCArray::new(self.shape.clone())?;
+        kernels::flip_axis(&self.data, &result.data, &self.shape, axis)?;
+        Ok(result)
+    }
+
+    /// Shift elements along `axis` by `shift` positions with wraparound, as
+    /// NumPy's `roll`; negative shifts move elements towards the start
+    pub fn roll(&self, shift: isize, axis: usize) -> Result<ROCArray<T>> {
+        let result = ROCArray::new(self.shape.clone())?;
+        kernels::roll_axis(&self.data, &result.data, &self.shape, shift, axis)?;
+        Ok(result)
+    }
+
+    /// The `n` x `n` identity matrix
+    pub fn eye(n: usize) -> Result<ROCArray<T>>
+    where
+        T: From<u8>,
+    {
+        let result = ROCArray::zeros(Shape::new_2d(n, n))?;
+        kernels::fill_diagonal(&result.data, n, n + 1, 0, T::from(1u8))?;
+        Ok(result)
+    }
+
+    /// Lower triangle of each trailing matrix: elements above diagonal `k`
+    /// are zeroed, where `k > 0` lies above the main diagonal
+    pub fn tril(&self, k: isize) -> Result<ROCArray<T>> {
+        self.triangle(k, kernels::Triangle::Lower)
+    }
+
+    /// Upper triangle of each trailing matrix: elements below diagonal `k`
+    /// are zeroed, where `k > 0` lies above the main diagonal
+    pub fn triu(&self, k: isize) -> Result<ROCArray<T>> {
+        self.triangle(k, kernels::Triangle::Upper)
+    }
+
+    fn triangle(&self, k: isize, side: kernels::Triangle) -> Result<ROCArray<T>> {
+        let dims = self.dims();
+        if dims.len() < 2 {
+            return Err(crate::error::invalid_argument(
+                "Triangular parts need at least 2 dimensions",
+            ));
+        }
+        let (rows, cols) = (dims[dims.len() - 2], dims[dims.len() - 1]);
+
+        let result = ROCArray::new(self.shape.clone())?;
+        kernels::triangle(&self.data, &result.data, self.len(), rows, cols, k, side)?;
+        Ok(result)
+    }
+
+    /// Diagonal `k` as NumPy's `diag`: a 2-D array gives its diagonal as a
+    /// 1-D array, and a 1-D array gives a square matrix with it on
+    /// diagonal `k` and zeros elsewhere
+    pub fn diag(&self, k: isize) -> Result<ROCArray<T>> {
+        match *self.dims() {
+            [len] => {
+                let n = len + k.unsigned_abs();
+                let result = ROCArray::zeros(Shape::new_2d(n, n))?;
+                let (offset, _) = kernels::diagonal_span(n, n, k);
+                kernels::diag_embed(&self.data, &result.data, len, n + 1, offset)?;
+                Ok(result)
+            }
+            [rows, cols] => {
+                let (offset, len) = kernels::diagonal_span(rows, cols, k);
+                let result = ROCArray::new(Shape::new_1d(len))?;
+                kernels::diag_extract(&self.data, &result.data, len, cols + 1, offset)?;
+                Ok(result)
+            }
+            _ => Err(crate::error::invalid_argument(format!(
+                "diag needs a 1-D or 2-D array, got {} dimensions",
+                self.ndim()
+            ))),
+        }
+    }
+
+    /// Pad every axis by `(before, after)` elements, filled according to
+    /// `mode`
+    pub fn pad(&self, pad_width: &[(usize, usize)], mode: PadMode<T>) -> Result<ROCArray<T>> {
+        if pad_width.len() != self.ndim() {
+            return Err(crate::error::invalid_argument(format!(
+                "Padding needs one (before, after) pair per axis for {} dimensions, got {}",
+                self.ndim(),
+                pad_width.len()
+            )));
+        }
+        let dims = self
+            .shape
+            .dims()
+            .iter()
+            .zip(pad_width)
+            .map(|(&dim, &(before, after))| dim + before + after)
+            .collect();
+
+        let result = ROCArray::new(Shape::new(dims))?;
+        kernels::pad(&self.data, &result.data, &self.shape, pad_width, mode)?;
+        Ok(result)
+    }
+
+    /// Scalar addition
+    pub fn add_scalar(&self, scalar: T) -> Result<ROCArray<T>> {
+        let mut result = ROCArray::new(self.shape.clone())?;
+        self.add_scalar_into(scalar, &mut result)?;
+        Ok(result)
+    }
+
+    /// Scalar addition into `out`, which must have the shape of `self`
+    pub fn add_scalar_into(&self, scalar: T, out: &mut ROCArray<T>) -> Result<()> {
+        out.check_out_shape(&self.shape)?;
+        kernels::scalar_add(&self.data, scalar, &out.data, self.len())
+    }
+
+    /// Scalar multiplication
+    pub fn mul_scalar(&self, scalar: T) -> Result<ROCArray<T>> {
+        let mut result = ROCArray::new(self.shape.clone())?;
+        self.mul_scalar_into(scalar, &mut result)?;
+        Ok(result)
+    }
+
+    /// Scalar multiplication into `out`, which must have the shape of `self`
+    pub fn mul_scalar_into(&self, scalar: T, out: &mut ROCArray<T>) -> Result<()> {
+        out.check_out_shape(&self.shape)?;
+        kernels::scalar_mul(&self.data, scalar, &out.data, self.len())
+    }
+
+    /// Matrix multiplication (only for 2D arrays)
+    pub fn matmul(&self, other: &ROCArray<T>) -> Result<ROCArray<T>> {
+        if self.ndim() != 2 || other.ndim() != 2 {
+            return Err(crate::error::custom_error(
+                "Matrix multiplication requires 2D arrays".to_string(),
+            ));
+        }
+
+        let [m, k] = [self.shape.dims()[0], self.shape.dims()[1]];
+        let [k2, n] = [other.shape.dims()[0], other.shape.dims()[1]];
+
+        if k != k2 {
+            return Err(crate::error::custom_error(
+                "Inner dimensions must match for matrix multiplication".to_string(),
+            ));
+        }
+
+        let result_shape = Shape::new_2d(m, n);
+        let mut result = ROCArray::new(result_shape)?;
+
+        kernels::matrix_multiply(&self.data, &other.data, &result.data, m, k, n)?;
+        Ok(result)
+    }
+
+    /// Sum all elements
+    pub fn sum(&self) -> Result<T> {
+        kernels::reduce_sum(&self.data, self.len())
+    }
+
+    /// Sum along specified axis
+    pub fn sum_axis(&self, axis: usize) -> Result<ROCArray<T>> {
+        let mut result = ROCArray::new(self.reduced_shape(axis)?)?;
+        self.sum_axis_into(axis, &mut result)?;
+        Ok(result)
+    }
+
+    /// Sum along an axis into `out`, which must have the reduced shape
+    pub fn sum_axis_into(&self, axis: usize, out: &mut ROCArray<T>) -> Result<()> {
+        out.check_out_shape(&self.reduced_shape(axis)?)?;
+        kernels::reduce_sum_axis(&self.data, &out.data, &self.shape, axis)
+    }
+
+    /// Find maximum element
+    pub fn max(&self) -> Result<T>
+    where
+        T: PartialOrd,
+    {
+        kernels::reduce_max(&self.data, self.len())
+    }
+
+    /// Find minimum element
+    pub fn min(&self) -> Result<T>
+    where
+        T: PartialOrd,
+    {
+        kernels::reduce_min(&self.data, self.len())
+    }
+
+    /// Find the flat index of the first maximum element
+    pub fn argmax(&self) -> Result<usize> {
+        kernels::argmax(&self.data, self.len())
+    }
+
+    /// Find the flat index of the first minimum element
+    pub fn argmin(&self) -> Result<usize> {
+        kernels::argmin(&self.data, self.len())
+    }
+
+    /// Find the index of the first maximum along an axis
+    pub fn argmax_axis(&self, axis: usize) -> Result<ROCArray<u32>> {
+        let mut result = ROCArray::new(self.reduced_shape(axis)?)?;
+        self.argmax_axis_into(axis, &mut result)?;
+        Ok(result)
+    }
+
+    /// Index of the first maximum along an axis into `out`, which must have the reduced shape
+    pub fn argmax_axis_into(&self, axis: usize, out: &mut ROCArray<u32>) -> Result<()> {
+        out.check_out_shape(&self.reduced_shape(axis)?)?;
+        kernels::argmax_axis(&self.data, &out.data, &self.shape, axis)
+    }
+
+    /// Find the index of the first minimum along an axis
+    pub fn argmin_axis(&self, axis: usize) -> Result<ROCArray<u32>> {
+        let mut result = ROCArray::new(self.reduced_shape(axis)?)?;
+        self.argmin_axis_into(axis, &mut result)?;
+        Ok(result)
+    }
+
+    /// Index of the first minimum along an axis into `out`, which must have the reduced shape
+    pub fn argmin_axis_into(&self, axis: usize, out: &mut ROCArray<u32>) -> Result<()> {
+        out.check_out_shape(&self.reduced_shape(axis)?)?;
+        kernels::argmin_axis(&self.data, &out.data, &self.shape, axis)
+    }
+
+    /// Multiply all elements; integer products wrap on overflow
+    pub fn prod(&self) -> Result<T> {
+        kernels::reduce_prod(&self.data, self.len())
+    }
 
+    /// Multiply all elements, returning `None` if an integer product
+    /// overflowed
+    pub fn checked_prod(&self) -> Result<Option<T>> {
+        kernels::reduce_prod_checked(&self.data, self.len())
+    }
+
+    /// Product along an axis; integer products wrap on overflow
+    pub fn prod_axis(&self, axis: usize) -> Result<ROCArray<T>> {
+        let mut result = ROCArray::new(self.reduced_shape(axis)?)?;
+        self.prod_axis_into(axis, &mut result)?;
         Ok(result)
     }
 
-    /// Scalar addition
-    pub fn add_scalar(&self, scalar: T) -> Result<ROCArray<T>> {
-        let mut result = ROCArray::new(self.shape.clone())?;
-        kernels::scalar_add(&self.data, scalar, &result.data, self.len())?;
+    /// Product along an axis into `out`, which must have the reduced shape
+    pub fn prod_axis_into(&self, axis: usize, out: &mut ROCArray<T>) -> Result<()> {
+        out.check_out_shape(&self.reduced_shape(axis)?)?;
+        kernels::reduce_prod_axis(&self.data, &out.data, &self.shape, axis)
+    }
+
+    /// Product along an axis, returning `None` if any integer product
+    /// overflowed
+    pub fn checked_prod_axis(&self, axis: usize) -> Result<Option<ROCArray<T>>> {
+        let result = ROCArray::new(self.reduced_shape(axis)?)?;
+        let overflowed =
+            kernels::reduce_prod_axis_checked(&self.data, &result.data, &self.shape, axis)?;
+        Ok((!overflowed).then_some(result))
+    }
+
+    /// Population variance of all elements
+    pub fn var(&self) -> Result<f64> {
+        kernels::variance(&self.data, self.len())
+    }
+
+    /// Population standard deviation of all elements
+    pub fn std(&self) -> Result<f64> {
+        Ok(self.var()?.sqrt())
+    }
+
+    /// Population variance along an axis
+    pub fn var_axis(&self, axis: usize) -> Result<ROCArray<f64>> {
+        let mut result = ROCArray::new(self.reduced_shape(axis)?)?;
+        self.var_axis_into(axis, &mut result)?;
         Ok(result)
     }
 
-    /// Scalar multiplication
-    pub fn mul_scalar(&self, scalar: T) -> Result<ROCArray<T>> {
-        let mut result = ROCArray::new(self.shape.clone())?;
-        kernels::scalar_mul(&self.data, scalar, &result.data, self.len())?;
+    /// Population variance along an axis into `out`, which must have the reduced shape
+    pub fn var_axis_into(&self, axis: usize, out: &mut ROCArray<f64>) -> Result<()> {
+        out.check_out_shape(&self.reduced_shape(axis)?)?;
+        kernels::variance_axis(&self.data, &out.data, &self.shape, axis)
+    }
+
+    /// Population standard deviation along an axis
+    pub fn std_axis(&self, axis: usize) -> Result<ROCArray<f64>> {
+        let mut result = ROCArray::new(self.reduced_shape(axis)?)?;
+        self.std_axis_into(axis, &mut result)?;
         Ok(result)
     }
 
-    /// Matrix multiplication (only for 2D arrays)
-    pub fn matmul(&self, other: &ROCArray<T>) -> Result<ROCArray<T>> {
-        if self.ndim() != 2 || other.ndim() != 2 {
-            return Err(crate::error::custom_error(
-                "Matrix multiplication requires 2D arrays".to_string(),
-            ));
-        }
+    /// Population standard deviation along an axis into `out`, which must have the reduced shape
+    pub fn std_axis_into(&self, axis: usize, out: &mut ROCArray<f64>) -> Result<()> {
+        out.check_out_shape(&self.reduced_shape(axis)?)?;
+        kernels::std_axis(&self.data, &out.data, &self.shape, axis)
+    }
 
-        let [m, k] = [self.shape.dims()[0], self.shape.dims()[1]];
-        let [k2, n] = [other.shape.dims()[0], other.shape.dims()[1]];
+    /// Convert every element to `U` on the device
+    ///
+    /// Follows Rust `as` semantics: floats clamp to the integer range (NaN
+    /// becomes zero) and integer narrowing wraps.
+    pub fn astype<U: kernels::NumericOps>(&self) -> Result<ROCArray<U>> {
+        self.cast_impl(false)
+    }
 
-        if k != k2 {
-            return Err(crate::error::custom_error(
-                "Inner dimensions must match for matrix multiplication".to_string(),
-            ));
-        }
+    /// Like [`astype`](Self::astype), but integer narrowing clamps to the
+    /// target range instead of wrapping
+    pub fn astype_saturating<U: kernels::NumericOps>(&self) -> Result<ROCArray<U>> {
+        self.cast_impl(true)
+    }
 
-        let result_shape = Shape::new_2d(m, n);
-        let mut result = ROCArray::new(result_shape)?;
+    /// Convert every element into `out`, which must have the shape of
+    /// `self`, with the same semantics as [`astype`](Self::astype)
+    pub fn astype_into<U: kernels::NumericOps>(&self, out: &mut ROCArray<U>) -> Result<()> {
+        out.check_out_shape(&self.shape)?;
+        kernels::cast(&self.data, &out.data, self.len(), false)
+    }
 
-        kernels::matrix_multiply(&self.data, &other.data, &result.data, m, k, n)?;
+    fn cast_impl<U: kernels::NumericOps>(&self, saturate: bool) -> Result<ROCArray<U>> {
+        let result = ROCArray::new(self.shape.clone())?;
+        kernels::cast(&self.data, &result.data, self.len(), saturate)?;
         Ok(result)
     }
 
-    /// Sum all elements
-    pub fn sum(&self) -> Result<T> {
-        kernels::reduce_sum(&self.data, self.len())
+    /// Count elements into `bins` equal-width bins spanning `range`
+    /// (inclusive of both ends); elements outside the range are ignored
+    pub fn histogram(&self, bins: usize, range: (f64, f64)) -> Result<ROCArray<u32>> {
+        let mut counts = ROCArray::new_1d(bins)?;
+        kernels::histogram(&self.data, self.len(), &mut counts.data, range.0, range.1)?;
+        Ok(counts)
     }
 
-    /// Sum along specified axis
-    pub fn sum_axis(&self, axis: usize) -> Result<ROCArray<T>> {
-        if axis >= self.ndim() {
-            return Err(crate::error::custom_error("Axis out of bounds".to_string()));
-        }
+    /// Cumulative sum along an axis
+    pub fn cumsum(&self, axis: usize) -> Result<ROCArray<T>> {
+        let mut result = ROCArray::new(self.shape.clone())?;
+        self.cumsum_into(axis, &mut result)?;
+        Ok(result)
+    }
 
-        let mut new_dims = self.shape.dims().to_vec();
-        new_dims.remove(axis);
-        let result_shape = if new_dims.is_empty() {
-            Shape::new(vec![1])
-        } else {
-            Shape::new(new_dims)
-        };
+    /// Cumulative sum along an axis into `out`, which must have the shape
+    /// of `self`
+    pub fn cumsum_into(&self, axis: usize, out: &mut ROCArray<T>) -> Result<()> {
+        out.check_out_shape(&self.shape)?;
+        kernels::cumsum(&self.data, &out.data, &self.shape, axis)
+    }
 
-        let mut result = ROCArray::new(result_shape)?;
-        kernels::reduce_sum_axis(&self.data, &result.data, &self.shape, axis)?;
+    /// Cumulative product along an axis
+    pub fn cumprod(&self, axis: usize) -> Result<ROCArray<T>> {
+        let mut result = ROCArray::new(self.shape.clone())?;
+        self.cumprod_into(axis, &mut result)?;
         Ok(result)
     }
 
-    /// Find maximum element
-    pub fn max(&self) -> Result<T>
+    /// Cumulative product along an axis into `out`, which must have the shape
+    /// of `self`
+    pub fn cumprod_into(&self, axis: usize, out: &mut ROCArray<T>) -> Result<()> {
+        out.check_out_shape(&self.shape)?;
+        kernels::cumprod(&self.data, &out.data, &self.shape, axis)
+    }
+
+    /// Sum all elements into a device scalar without synchronizing
+    pub fn sum_device(&self, stream: &Stream) -> Result<DeviceScalar<T>> {
+        kernels::reduce_sum_device_async(&self.data, self.len(), stream)
+    }
+
+    /// Find the maximum element into a device scalar without synchronizing
+    pub fn max_device(&self, stream: &Stream) -> Result<DeviceScalar<T>>
     where
         T: PartialOrd,
     {
-        kernels::reduce_max(&self.data, self.len())
+        kernels::reduce_max_device_async(&self.data, self.len(), stream)
     }
 
-    /// Find minimum element
-    pub fn min(&self) -> Result<T>
+    /// Find the minimum element into a device scalar without synchronizing
+    pub fn min_device(&self, stream: &Stream) -> Result<DeviceScalar<T>>
     where
         T: PartialOrd,
     {
-        kernels::reduce_min(&self.data, self.len())
+        kernels::reduce_min_device_async(&self.data, self.len(), stream)
     }
 
     /// Calculate mean
@@ -686,14 +1444,26 @@ where
     where
         T: Into<f64>,
     {
-        let sum_result = self.sum_axis(axis)?;
-        let axis_size = self.shape.dims()[axis] as f64;
+        self.mean_axes(&[axis], false)
+    }
 
-        // Convert sum result to f64 and divide by axis size
-        let sum_vec = sum_result.to_vec()?;
-        let mean_vec: Vec<f64> = sum_vec.into_iter().map(|x| x.into() / axis_size).collect();
+    /// Sum over several axes in one kernel pass
+    ///
+    /// With `keepdims` the reduced axes stay in the result with length 1, so
+    /// it broadcasts against `self`.
+    pub fn sum_axes(&self, axes: &[usize], keepdims: bool) -> Result<ROCArray<T>> {
+        let result = ROCArray::new(self.reduced_shape_axes(axes, keepdims)?)?;
+        kernels::reduce_sum_axes(&self.data, &result.data, &self.shape, axes)?;
+        Ok(result)
+    }
 
-        ROCArray::from_vec_with_shape(mean_vec, sum_result.shape)
+    /// Mean over several axes in one kernel pass, accumulated in `f64`
+    ///
+    /// `keepdims` works as in [`sum_axes`](Self::sum_axes).
+    pub fn mean_axes(&self, axes: &[usize], keepdims: bool) -> Result<ROCArray<f64>> {
+        let result = ROCArray::new(self.reduced_shape_axes(axes, keepdims)?)?;
+        kernels::reduce_mean_axes(&self.data, &result.data, &self.shape, axes)?;
+        Ok(result)
     }
 }
 
@@ -763,6 +1533,61 @@ where
         sorting::sort_descending(&mut self.data)
     }
 
+    /// Sort each lane along `axis` in ascending order, in place
+    pub fn sort_axis(&mut self, axis: usize) -> Result<()>
+    where
+        T: kernels::NumericOps,
+    {
+        let (order, seg_len) = self.axis_last_order(axis)?;
+        let segments = self.len().checked_div(seg_len).unwrap_or(0);
+        let stream = op_stream()?;
+
+        if axis + 1 == self.ndim() {
+            sorting::sort_segments_async(&self.data, None, segments, seg_len, &stream)?;
+        } else {
+            // Sort a copy with the axis innermost, then write it back
+            let keys = self.permute(&order)?.to_array()?;
+            sorting::sort_segments_async(&keys.data, None, segments, seg_len, &stream)?;
+            let layout = StridedLayout::contiguous(&self.shape).permuted(&order)?;
+            kernels::strided_scatter_async(&keys.data, &self.data, &layout, &stream)?;
+        }
+        stream.finish()?;
+        Ok(())
+    }
+
+    /// Indices that would sort each lane along `axis`, stable for ties
+    pub fn argsort_axis(&self, axis: usize) -> Result<ROCArray<u32>>
+    where
+        T: kernels::NumericOps,
+    {
+        let (order, seg_len) = self.axis_last_order(axis)?;
+        let segments = self.len().checked_div(seg_len).unwrap_or(0);
+        let stream = op_stream()?;
+
+        let keys = self.permute(&order)?.to_array()?;
+        let indices = ROCArray::<u32>::new(keys.shape.clone())?;
+        sorting::init_segment_indices_async(&indices.data, self.len(), seg_len, &stream)?;
+        sorting::sort_segments_async(&keys.data, Some(&indices.data), segments, seg_len, &stream)?;
+
+        let result = ROCArray::<u32>::new(self.shape.clone())?;
+        let layout = StridedLayout::contiguous(&self.shape).permuted(&order)?;
+        kernels::strided_scatter_async(&indices.data, &result.data, &layout, &stream)?;
+        stream.finish()?;
+        Ok(result)
+    }
+
+    /// Axis order that moves `axis` innermost, and the length of that axis
+    fn axis_last_order(&self, axis: usize) -> Result<(Vec<usize>, usize)> {
+        if axis >= self.ndim() {
+            return Err(crate::error::custom_error("Axis out of bounds".to_string()));
+        }
+        let order = (0..self.ndim())
+            .filter(|&a| a != axis)
+            .chain(std::iter::once(axis))
+            .collect();
+        Ok((order, self.shape.dims()[axis]))
+    }
+
     /// Get indices that would sort the array (argsort)
     pub fn argsort(&self) -> Result<ROCArray<u32>> {
         let indices = ROCArray::<u32>::new_1d(self.len())?;
@@ -770,6 +1595,73 @@ where
         Ok(indices)
     }
 
+    /// Sorted distinct elements, as a 1D array
+    pub fn unique(&self) -> Result<ROCArray<T>>
+    where
+        T: kernels::NumericOps,
+    {
+        Ok(self.unique_impl(false, false)?.0)
+    }
+
+    /// Sorted distinct elements and how often each occurs
+    pub fn unique_counts(&self) -> Result<(ROCArray<T>, ROCArray<u32>)>
+    where
+        T: kernels::NumericOps,
+    {
+        let (values, counts, _) = self.unique_impl(true, false)?;
+        Ok((values, counts.expect("counts were requested")))
+    }
+
+    /// Sorted distinct elements and, for every element of `self`, the index
+    /// of its value among them, so `values.gather(&inverse)` rebuilds the
+    /// flattened array
+    pub fn unique_inverse(&self) -> Result<(ROCArray<T>, ROCArray<u32>)>
+    where
+        T: kernels::NumericOps,
+    {
+        let (values, _, inverse) = self.unique_impl(false, true)?;
+        Ok((values, inverse.expect("inverse was requested")))
+    }
+
+    /// Sort, mark the first element of each run of equal values, and
+    /// compact the marked elements
+    #[allow(clippy::type_complexity)]
+    fn unique_impl(
+        &self,
+        with_counts: bool,
+        with_inverse: bool,
+    ) -> Result<(ROCArray<T>, Option<ROCArray<u32>>, Option<ROCArray<u32>>)>
+    where
+        T: kernels::NumericOps,
+    {
+        let len = self.len();
+        let sort_indices = self.argsort()?;
+        let sorted = ROCArray::<T>::new_1d(len)?;
+        kernels::gather_rows(&self.data, &sort_indices.data, &sorted.data, 1, len, false)?;
+
+        let runs = kernels::find_runs(&sorted.data, len)?;
+        let values = ROCArray::new_1d(runs.count())?;
+        kernels::run_values(&sorted.data, &runs, &values.data)?;
+
+        let counts = if with_counts {
+            let counts = ROCArray::new_1d(runs.count())?;
+            kernels::run_lengths(&runs, &counts.data)?;
+            Some(counts)
+        } else {
+            None
+        };
+
+        let inverse = if with_inverse {
+            let inverse = ROCArray::new_1d(len)?;
+            kernels::run_inverse(&runs, &sort_indices.data, &inverse.data)?;
+            Some(inverse)
+        } else {
+            None
+        };
+
+        Ok((values, counts, inverse))
+    }
+
     /// Partial sort (sort only the first k elements)
     pub fn partial_sort(&mut self, k: usize) -> Result<()> {
         let len = self.len();
@@ -777,6 +1669,101 @@ where
     }
 }
 
+// Batched linear algebra
+impl<T> ROCArray<T>
+where
+    T: Copy + Default + 'static + From<u8> + crate::rocblas::level3::GemmStridedBatchedType,
+{
+    /// Batched matrix multiplication of (B, M, K) by (B, K, N) arrays,
+    /// giving (B, M, N)
+    pub fn bmm(&self, other: &ROCArray<T>) -> Result<ROCArray<T>> {
+        if self.ndim() != 3 || other.ndim() != 3 {
+            return Err(crate::error::custom_error(
+                "Batched matrix multiplication requires 3D arrays".to_string(),
+            ));
+        }
+
+        let [batch, m, k] = [
+            self.shape.dims()[0],
+            self.shape.dims()[1],
+            self.shape.dims()[2],
+        ];
+        let [batch2, k2, n] = [
+            other.shape.dims()[0],
+            other.shape.dims()[1],
+            other.shape.dims()[2],
+        ];
+
+        if batch != batch2 {
+            return Err(crate::error::custom_error(format!(
+                "Batch sizes must match for batched matrix multiplication: {} vs {}",
+                batch, batch2
+            )));
+        }
+        if k != k2 {
+            return Err(crate::error::custom_error(
+                "Inner dimensions must match for matrix multiplication".to_string(),
+            ));
+        }
+
+        let result = ROCArray::new(Shape::new_3d(batch, m, n))?;
+        Self::batched_gemm(&self.data, &other.data, &result.data, [batch, m, k, n])?;
+        Ok(result)
+    }
+
+    /// Row-major `c[i] = a[i] * b[i]` over contiguous (M, K) and (K, N)
+    /// matrices, `dims` being `[batch, m, k, n]`
+    pub(crate) fn batched_gemm(
+        a: &DeviceMemory<T>,
+        b: &DeviceMemory<T>,
+        c: &DeviceMemory<T>,
+        dims: [usize; 4],
+    ) -> Result<()> {
+        let [batch, m, k, n] = dims;
+        if batch * m * n == 0 {
+            return Ok(());
+        }
+
+        let to_i32 = |value: usize| {
+            i32::try_from(value).map_err(|_| {
+                crate::error::invalid_argument(format!(
+                    "Dimension {} is too large for rocBLAS",
+                    value
+                ))
+            })
+        };
+
+        // rocBLAS is column-major, so compute C^T = B^T * A^T, which has the
+        // same memory layout as the row-major C = A * B
+        let handle = crate::rocblas::Handle::new()?;
+        let alpha = T::from(1);
+        let beta = T::default();
+        unsafe {
+            crate::rocblas::gemm_strided_batched(
+                &handle,
+                crate::rocblas::types::Operation::None,
+                crate::rocblas::types::Operation::None,
+                to_i32(n)?,
+                to_i32(m)?,
+                to_i32(k)?,
+                &alpha,
+                b.as_ptr() as *const T,
+                to_i32(n.max(1))?,
+                (k * n) as i64,
+                a.as_ptr() as *const T,
+                to_i32(k.max(1))?,
+                (m * k) as i64,
+                &beta,
+                c.as_ptr() as *mut T,
+                to_i32(n.max(1))?,
+                (m * n) as i64,
+                to_i32(batch)?,
+            )?;
+        }
+        Ok(())
+    }
+}
+
 // Async operations
 impl<T> ROCArray<T>
 where
@@ -902,4 +1889,511 @@ mod tests {
         let result = shape1.broadcast_with(&shape2).unwrap();
         assert_eq!(result.dims(), &[3, 2, 4]);
     }
+
+    #[test]
+    fn test_device_scalar_reductions() -> Result<()> {
+        let arr = ROCArray::from_vec(vec![3.0f32, -1.0, 4.0, 1.5])?;
+        let stream = Stream::new()?;
+
+        let sum = arr.sum_device(&stream)?;
+        let max = arr.max_device(&stream)?;
+        let min = arr.min_device(&stream)?;
+
+        assert_eq!(sum.read(&stream)?, 7.5);
+        assert_eq!(max.read(&stream)?, 4.0);
+        assert_eq!(min.read(&stream)?, -1.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reductions_are_deterministic() -> Result<()> {
+        // Enough elements for every block of the first pass to loop
+        let values: Vec<f32> = (0..1_000_003u64)
+            .map(|i| ((i * 7919) % 1000) as f32 * 0.001)
+            .collect();
+        let arr = ROCArray::from_vec(values.clone())?;
+
+        let first = arr.sum()?;
+        for _ in 0..5 {
+            assert_eq!(arr.sum()?.to_bits(), first.to_bits());
+        }
+        let expected: f64 = values.iter().map(|&x| x as f64).sum();
+        assert!((first as f64 - expected).abs() / expected < 1e-5);
+
+        assert_eq!(arr.max()?, values.iter().copied().fold(f32::MIN, f32::max));
+        assert_eq!(arr.min()?, 0.0);
+
+        let small = ROCArray::from_vec(vec![3i16, -7, 12, 5])?;
+        assert_eq!(small.sum()?, 13);
+        assert_eq!(small.max()?, 12);
+        assert_eq!(small.min()?, -7);
+        assert_eq!(ROCArray::<u8>::new(Shape::new_1d(0))?.sum()?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_transposed_matmul() -> Result<()> {
+        // a is 3x2, so a^T a is 2x2
+        let a = ROCArray::from_vec_with_shape(
+            vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0],
+            Shape::new_2d(3, 2),
+        )?;
+        let at = a.transpose();
+        assert_eq!(at.dims(), &[2, 3]);
+        assert_eq!(at.to_vec()?, vec![1.0, 3.0, 5.0, 2.0, 4.0, 6.0]);
+
+        let product = at.matmul(&a.view())?;
+        assert_eq!(product.to_vec()?, vec![35.0, 44.0, 44.0, 56.0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_views_share_memory() -> Result<()> {
+        let mut arr = ROCArray::from_vec_with_shape(
+            (0..12).map(|x| x as f32).collect(),
+            Shape::new_2d(4, 3),
+        )?;
+
+        assert_eq!(
+            arr.slice(1, 3)?.to_vec()?,
+            vec![3.0, 4.0, 5.0, 6.0, 7.0, 8.0]
+        );
+        assert_eq!(arr.row(2)?.to_vec()?, vec![6.0, 7.0, 8.0]);
+        assert_eq!(arr.col(1)?.to_vec()?, vec![1.0, 4.0, 7.0, 10.0]);
+        assert_eq!(
+            arr.reshaped(vec![3, 4])?.row(1)?.to_vec()?,
+            vec![4.0, 5.0, 6.0, 7.0]
+        );
+
+        let column = ROCArray::from_vec(vec![-1.0f32; 4])?;
+        arr.col_mut(2)?.copy_from(&column)?;
+        assert_eq!(arr.row(3)?.to_vec()?, vec![9.0, 10.0, -1.0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_argmax_argmin() -> Result<()> {
+        let arr = ROCArray::from_vec_with_shape(
+            vec![3.0f32, 9.0, 1.0, 9.0, -2.0, 4.0],
+            Shape::new_2d(2, 3),
+        )?;
+
+        // Ties resolve to the first occurrence
+        assert_eq!(arr.argmax()?, 1);
+        assert_eq!(arr.argmin()?, 4);
+        assert_eq!(arr.argmax_axis(0)?.to_vec()?, vec![1, 0, 1]);
+        assert_eq!(arr.argmin_axis(1)?.to_vec()?, vec![2, 1]);
+
+        let large = ROCArray::from_vec((0..100_000).map(|i: i32| i % 977).collect())?;
+        assert_eq!(large.argmax()?, 976);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cumsum_cumprod() -> Result<()> {
+        let arr = ROCArray::from_vec_with_shape(vec![1i32, 2, 3, 4, 5, 6], Shape::new_2d(2, 3))?;
+        assert_eq!(arr.cumsum(1)?.to_vec()?, vec![1, 3, 6, 4, 9, 15]);
+        assert_eq!(arr.cumsum(0)?.to_vec()?, vec![1, 2, 3, 5, 7, 9]);
+        assert_eq!(arr.cumprod(1)?.to_vec()?, vec![1, 2, 6, 4, 20, 120]);
+
+        // Longer than one 512-element chunk
+        let ones = ROCArray::from_vec(vec![1u32; 1500])?;
+        let scanned = ones.cumsum(0)?.to_vec()?;
+        assert!(scanned.iter().copied().eq(1..=1500u32));
+        Ok(())
+    }
+
+    #[test]
+    fn test_var_std() -> Result<()> {
+        let arr = ROCArray::from_vec_with_shape(
+            vec![2.0f32, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0],
+            Shape::new_2d(2, 4),
+        )?;
+        assert!((arr.var()? - 4.0).abs() < 1e-9);
+        assert!((arr.std()? - 2.0).abs() < 1e-9);
+
+        assert_eq!(arr.var_axis(1)?.to_vec()?, vec![0.75, 2.75]);
+        assert_eq!(arr.std_axis(0)?.to_vec()?, vec![1.5, 0.5, 1.5, 2.5]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_prod() -> Result<()> {
+        let arr = ROCArray::from_vec_with_shape(vec![1i32, 2, 3, 4, 5, 6], Shape::new_2d(2, 3))?;
+        assert_eq!(arr.prod()?, 720);
+        assert_eq!(arr.prod_axis(0)?.to_vec()?, vec![4, 10, 18]);
+        assert_eq!(arr.checked_prod_axis(1)?.unwrap().to_vec()?, vec![6, 120]);
+
+        let big = ROCArray::from_vec(vec![1000i32; 4])?;
+        assert_eq!(big.checked_prod()?, None);
+        assert!(big.checked_prod_axis(0)?.is_none());
+        assert_eq!(ROCArray::<f32>::new_1d(0)?.prod()?, 1.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_broadcast_lower_rank() {
+        let shape = Shape::new(vec![2, 3]);
+        let row = Shape::new(vec![3]);
+        assert_eq!(shape.broadcast_with(&row).unwrap().dims(), &[2, 3]);
+        assert_eq!(row.broadcast_with(&shape).unwrap().dims(), &[2, 3]);
+        assert!(!Shape::new(vec![2]).can_broadcast_with(&shape));
+    }
+
+    #[test]
+    fn test_where() -> Result<()> {
+        let a = ROCArray::from_vec_with_shape(
+            vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0],
+            Shape::new_2d(2, 3),
+        )?;
+        let zeros = ROCArray::from_vec(vec![0.0f32])?;
+        let mask = ROCArray::from_vec(vec![1u8, 0, 1])?;
+
+        let selected = a.r#where(&mask, &zeros)?;
+        assert_eq!(selected.dims(), &[2, 3]);
+        assert_eq!(selected.to_vec()?, vec![1.0, 0.0, 3.0, 4.0, 0.0, 6.0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_masked_select() -> Result<()> {
+        let values: Vec<i32> = (0..2000).collect();
+        let arr = ROCArray::from_vec_with_shape(values, Shape::new_2d(40, 50))?;
+        let mask = ROCArray::from_vec_with_shape(
+            (0..2000).map(|i| (i % 3 == 0) as u8).collect(),
+            Shape::new_2d(40, 50),
+        )?;
+
+        assert_eq!(mask.count_nonzero()?, 667);
+        let selected = arr.masked_select(&mask)?;
+        assert_eq!(selected.dims(), &[667]);
+        assert!(selected.to_vec()?.into_iter().eq((0..2000).step_by(3)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gather_scatter() -> Result<()> {
+        let table =
+            ROCArray::from_vec_with_shape((0..8).map(|x| x as f32).collect(), Shape::new_2d(4, 2))?;
+        let ids = ROCArray::from_vec_with_shape(vec![3u32, 0, 3], Shape::new_2d(1, 3))?;
+
+        let embedded = table.gather(&ids)?;
+        assert_eq!(embedded.dims(), &[1, 3, 2]);
+        assert_eq!(embedded.to_vec()?, vec![6.0, 7.0, 0.0, 1.0, 6.0, 7.0]);
+
+        let mut target = ROCArray::<f32>::zeros(Shape::new_2d(4, 2))?;
+        let rows = ROCArray::from_vec(vec![2u32, 0])?;
+        let values =
+            ROCArray::from_vec_with_shape(vec![1.0f32, 2.0, 3.0, 4.0], Shape::new_2d(2, 2))?;
+        target.scatter(&rows, &values)?;
+        assert_eq!(
+            target.to_vec()?,
+            vec![3.0, 4.0, 0.0, 0.0, 1.0, 2.0, 0.0, 0.0]
+        );
+
+        let bad = ROCArray::from_vec(vec![4u32])?;
+        assert!(table.gather(&bad).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_many_set_many() -> Result<()> {
+        let mut arr =
+            ROCArray::from_vec_with_shape((0..6).map(|x| x as f64).collect(), Shape::new_2d(2, 3))?;
+        assert_eq!(arr.get_many(&[5, 0, 4, 0])?, vec![5.0, 0.0, 4.0, 0.0]);
+        assert!(arr.get_many(&[])?.is_empty());
+        assert!(arr.get_many(&[1, 6]).is_err());
+
+        arr.set_many(&[(1, -1.0), (3, 30.0)])?;
+        assert_eq!(arr.to_vec()?, vec![0.0, -1.0, 2.0, 30.0, 4.0, 5.0]);
+        assert!(arr.set_many(&[(6, 0.0)]).is_err());
+        assert_eq!(arr.get(&[1, 0])?, 30.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_stack() -> Result<()> {
+        let a = ROCArray::from_vec_with_shape(vec![1i32, 2, 3, 4], Shape::new_2d(2, 2))?;
+        let b = ROCArray::from_vec_with_shape(vec![5i32, 6], Shape::new_2d(2, 1))?;
+
+        let joined = ROCArray::concat(&[&a, &b], 1)?;
+        assert_eq!(joined.dims(), &[2, 3]);
+        assert_eq!(joined.to_vec()?, vec![1, 2, 5, 3, 4, 6]);
+        assert!(ROCArray::concat(&[&a, &b], 0).is_err());
+
+        let stacked = ROCArray::stack(&[&a, &a], 2)?;
+        assert_eq!(stacked.dims(), &[2, 2, 2]);
+        assert_eq!(stacked.to_vec()?, vec![1, 1, 2, 2, 3, 3, 4, 4]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_chunk() -> Result<()> {
+        let arr =
+            ROCArray::from_vec_with_shape((0..10).collect::<Vec<i32>>(), Shape::new_2d(5, 2))?;
+
+        let halves = arr.split(1, 2)?;
+        assert_eq!(halves[0].to_vec()?, vec![0, 2, 4, 6, 8]);
+        assert_eq!(halves[1].to_vec()?, vec![1, 3, 5, 7, 9]);
+        assert!(arr.split(0, 2).is_err());
+
+        let batches = arr.chunk(2)?;
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].dims(), &[3, 2]);
+        assert_eq!(batches[1].to_vec()?, vec![6, 7, 8, 9]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unique() -> Result<()> {
+        let arr = ROCArray::from_vec(vec![3i32, 1, 3, 2, 1, 3])?;
+        assert_eq!(arr.unique()?.to_vec()?, vec![1, 2, 3]);
+
+        let (values, counts) = arr.unique_counts()?;
+        assert_eq!(values.to_vec()?, vec![1, 2, 3]);
+        assert_eq!(counts.to_vec()?, vec![2, 1, 3]);
+
+        let (values, inverse) = arr.unique_inverse()?;
+        assert_eq!(inverse.to_vec()?, vec![2, 0, 2, 1, 0, 2]);
+        assert_eq!(values.gather(&inverse)?.to_vec()?, arr.to_vec()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_histogram() -> Result<()> {
+        let arr = ROCArray::from_vec(vec![0.0f32, 0.5, 1.0, 2.4, 2.5, 4.0, 5.0, -1.0])?;
+        let counts = arr.histogram(4, (0.0, 4.0))?;
+        assert_eq!(counts.to_vec()?, vec![2, 1, 2, 1]);
+
+        // Too many bins for shared memory
+        let ramp = ROCArray::from_vec((0..10_000).collect::<Vec<u32>>())?;
+        let counts = ramp.histogram(10_000, (0.0, 10_000.0))?.to_vec()?;
+        assert!(counts.iter().all(|&c| c == 1));
+        assert!(arr.histogram(0, (0.0, 1.0)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_astype() -> Result<()> {
+        let arr = ROCArray::from_vec(vec![-1.5f32, 0.7, 300.0, f32::NAN])?;
+        assert_eq!(arr.astype::<u8>()?.to_vec()?, vec![0, 0, 255, 0]);
+        assert_eq!(arr.astype::<i32>()?.to_vec()?, vec![-1, 0, 300, 0]);
+        assert_eq!(
+            arr.astype::<f64>()?.to_vec()?[..3],
+            [-1.5, 0.7f32 as f64, 300.0]
+        );
+
+        let ints = ROCArray::from_vec(vec![-1i32, 200, 70_000])?;
+        assert_eq!(
+            ints.astype::<i16>()?.to_vec()?,
+            vec![-1, 200, 70_000i32 as i16]
+        );
+        assert_eq!(
+            ints.astype_saturating::<i16>()?.to_vec()?,
+            vec![-1, 200, i16::MAX]
+        );
+        assert_eq!(ints.astype_saturating::<u8>()?.to_vec()?, vec![0, 200, 255]);
+        assert_eq!(ints.astype::<f32>()?.shape().dims(), &[3]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bmm() -> Result<()> {
+        // Batch 1 selects the first two rows of its right-hand side
+        let a = ROCArray::from_vec_with_shape(
+            vec![
+                1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0,
+            ],
+            Shape::new_3d(2, 2, 3),
+        )?;
+        let b = ROCArray::from_vec_with_shape(
+            vec![
+                1.0f32, 0.0, 0.0, 1.0, 1.0, 1.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0,
+            ],
+            Shape::new_3d(2, 3, 2),
+        )?;
+
+        let c = a.bmm(&b)?;
+        assert_eq!(c.shape().dims(), &[2, 2, 2]);
+        assert_eq!(c.to_vec()?, vec![4.0, 5.0, 10.0, 11.0, 7.0, 8.0, 9.0, 10.0]);
+
+        let wrong_batch = ROCArray::<f32>::zeros(Shape::new_3d(3, 3, 2))?;
+        assert!(a.bmm(&wrong_batch).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_into_variants_reuse_output() -> Result<()> {
+        let a = ROCArray::from_vec_with_shape(vec![1.0f32, 2.0, 3.0, 4.0], Shape::new_2d(2, 2))?;
+        let b = ROCArray::from_vec(vec![10.0f32, 20.0])?;
+
+        let mut out = ROCArray::<f32>::new(Shape::new_2d(2, 2))?;
+        let ptr = out.as_ptr();
+        a.add_into(&b, &mut out)?;
+        assert_eq!(out.to_vec()?, vec![11.0, 22.0, 13.0, 24.0]);
+        a.mul_into(&a, &mut out)?;
+        assert_eq!(out.to_vec()?, vec![1.0, 4.0, 9.0, 16.0]);
+        a.mul_scalar_into(3.0, &mut out)?;
+        assert_eq!(out.to_vec()?, vec![3.0, 6.0, 9.0, 12.0]);
+        a.cumsum_into(1, &mut out)?;
+        assert_eq!(out.to_vec()?, vec![1.0, 3.0, 3.0, 7.0]);
+        assert_eq!(out.as_ptr(), ptr);
+
+        let mut sums = ROCArray::<f32>::new_1d(2)?;
+        a.sum_axis_into(0, &mut sums)?;
+        assert_eq!(sums.to_vec()?, vec![4.0, 6.0]);
+        let mut indices = ROCArray::<u32>::new_1d(2)?;
+        a.argmax_axis_into(1, &mut indices)?;
+        assert_eq!(indices.to_vec()?, vec![1, 1]);
+
+        // Wrong output shapes are rejected rather than reallocated
+        assert!(a.sum_axis_into(0, &mut out).is_err());
+        assert!(a.add_into(&b, &mut sums).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_multi_axis_reductions() -> Result<()> {
+        let arr =
+            ROCArray::from_vec_with_shape((0..24).collect::<Vec<i32>>(), Shape::new_3d(2, 3, 4))?;
+
+        let sums = arr.sum_axes(&[0, 2], false)?;
+        assert_eq!(sums.dims(), &[3]);
+        assert_eq!(sums.to_vec()?, vec![60, 92, 124]);
+
+        let kept = arr.sum_axes(&[2, 0], true)?;
+        assert_eq!(kept.dims(), &[1, 3, 1]);
+        assert_eq!(kept.to_vec()?, vec![60, 92, 124]);
+
+        let means = arr.mean_axes(&[1, 2], false)?;
+        assert_eq!(means.to_vec()?, vec![5.5, 17.5]);
+        assert_eq!(arr.mean_axes(&[0, 1, 2], false)?.to_vec()?, vec![11.5]);
+        assert_eq!(arr.mean_axis(1)?.dims(), &[2, 4]);
+
+        assert!(arr.sum_axes(&[1, 1], false).is_err());
+        assert!(arr.sum_axes(&[3], false).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_axis() -> Result<()> {
+        let mut rows = ROCArray::from_vec_with_shape(vec![3, 1, 2, 9, 7, 8], Shape::new_2d(2, 3))?;
+        rows.sort_axis(1)?;
+        assert_eq!(rows.to_vec()?, vec![1, 2, 3, 7, 8, 9]);
+
+        let mut cols =
+            ROCArray::from_vec_with_shape(vec![3.0f32, 1.0, 2.0, 4.0], Shape::new_2d(2, 2))?;
+        cols.sort_axis(0)?;
+        assert_eq!(cols.to_vec()?, vec![2.0, 1.0, 3.0, 4.0]);
+
+        // Segments longer than a block and not a power of two
+        let data: Vec<u32> = (0..2 * 1000u32).map(|i| (i * 7919) % 1009).collect();
+        let mut long = ROCArray::from_vec_with_shape(data.clone(), Shape::new_2d(2, 1000))?;
+        long.sort_axis(1)?;
+        let mut expected = data;
+        expected[..1000].sort_unstable();
+        expected[1000..].sort_unstable();
+        assert_eq!(long.to_vec()?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_argsort_axis() -> Result<()> {
+        let arr = ROCArray::from_vec_with_shape(
+            vec![3.0f64, 1.0, 2.0, 5.0, 5.0, 4.0],
+            Shape::new_2d(2, 3),
+        )?;
+        assert_eq!(arr.argsort_axis(1)?.to_vec()?, vec![1, 2, 0, 2, 0, 1]);
+        assert_eq!(arr.argsort_axis(0)?.to_vec()?, vec![0, 0, 0, 1, 1, 1]);
+        assert!(arr.argsort_axis(2).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_pad() -> Result<()> {
+        let arr = ROCArray::from_vec(vec![1, 2, 3])?;
+        let constant = arr.pad(&[(2, 1)], PadMode::Constant(-1))?;
+        assert_eq!(constant.to_vec()?, vec![-1, -1, 1, 2, 3, -1]);
+        assert_eq!(
+            arr.pad(&[(2, 3)], PadMode::Reflect)?.to_vec()?,
+            vec![3, 2, 1, 2, 3, 2, 1, 2]
+        );
+        assert_eq!(
+            arr.pad(&[(2, 1)], PadMode::Edge)?.to_vec()?,
+            vec![1, 1, 1, 2, 3, 3]
+        );
+
+        let grid = ROCArray::from_vec_with_shape(vec![1.0f32, 2.0, 3.0, 4.0], Shape::new_2d(2, 2))?;
+        let padded = grid.pad(&[(1, 0), (0, 1)], PadMode::Constant(0.0))?;
+        assert_eq!(padded.dims(), &[3, 3]);
+        assert_eq!(
+            padded.to_vec()?,
+            vec![0.0, 0.0, 0.0, 1.0, 2.0, 0.0, 3.0, 4.0, 0.0]
+        );
+
+        assert!(grid.pad(&[(1, 1)], PadMode::Edge).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_flip_and_roll() -> Result<()> {
+        let arr = ROCArray::from_vec_with_shape(vec![1, 2, 3, 4, 5, 6], Shape::new_2d(2, 3))?;
+        assert_eq!(arr.flip(0)?.to_vec()?, vec![4, 5, 6, 1, 2, 3]);
+        assert_eq!(arr.flip(1)?.to_vec()?, vec![3, 2, 1, 6, 5, 4]);
+
+        assert_eq!(arr.roll(1, 1)?.to_vec()?, vec![3, 1, 2, 6, 4, 5]);
+        assert_eq!(arr.roll(-1, 1)?.to_vec()?, vec![2, 3, 1, 5, 6, 4]);
+        assert_eq!(arr.roll(3, 0)?.to_vec()?, vec![4, 5, 6, 1, 2, 3]);
+        assert_eq!(arr.roll(0, 1)?.to_vec()?, arr.to_vec()?);
+
+        assert!(arr.flip(2).is_err());
+        assert!(arr.roll(1, 2).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_triangular_and_diagonal() -> Result<()> {
+        let eye = ROCArray::<f32>::eye(3)?;
+        assert_eq!(
+            eye.to_vec()?,
+            vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]
+        );
+
+        let arr = ROCArray::from_vec_with_shape(
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12],
+            Shape::new_2d(3, 4),
+        )?;
+        assert_eq!(
+            arr.tril(0)?.to_vec()?,
+            vec![1, 0, 0, 0, 5, 6, 0, 0, 9, 10, 11, 0]
+        );
+        assert_eq!(
+            arr.triu(1)?.to_vec()?,
+            vec![0, 2, 3, 4, 0, 0, 7, 8, 0, 0, 0, 12]
+        );
+        assert_eq!(
+            arr.tril(-1)?.to_vec()?,
+            vec![0, 0, 0, 0, 5, 0, 0, 0, 9, 10, 0, 0]
+        );
+
+        // Batched over leading axes
+        let batch =
+            ROCArray::from_vec_with_shape(vec![1, 2, 3, 4, 5, 6, 7, 8], Shape::new_3d(2, 2, 2))?;
+        assert_eq!(batch.triu(0)?.to_vec()?, vec![1, 2, 0, 4, 5, 6, 0, 8]);
+
+        assert_eq!(arr.diag(0)?.to_vec()?, vec![1, 6, 11]);
+        assert_eq!(arr.diag(2)?.to_vec()?, vec![3, 8]);
+        assert_eq!(arr.diag(-2)?.to_vec()?, vec![9]);
+        assert!(arr.diag(5)?.is_empty());
+
+        let v = ROCArray::from_vec(vec![1, 2])?;
+        let m = v.diag(1)?;
+        assert_eq!(m.dims(), &[3, 3]);
+        assert_eq!(m.to_vec()?, vec![0, 1, 0, 0, 0, 2, 0, 0, 0]);
+        assert_eq!(v.diag(-1)?.to_vec()?, vec![0, 0, 0, 1, 0, 0, 0, 2, 0]);
+
+        assert!(v.tril(0).is_err());
+        assert!(batch.diag(0).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,790 @@
+// src/rocarray/npy.rs - NumPy .npy and .npz files for ROCArrays
+
+use crate::error::{Result, invalid_argument, not_implemented, parse_error};
+use crate::hip::staging::DEFAULT_STAGING_BUFFER_SIZE;
+use crate::hip::{DeviceMemory, Event, PinnedMemory, Stream, ffi};
+use crate::rocarray::kernels::NumericOps;
+use crate::rocarray::{ROCArray, Shape};
+use std::ffi::c_void;
+use std::fs::File;
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Element types with a NumPy dtype
+pub trait NpyElement: NumericOps {
+    /// NumPy type string, little-endian
+    const DESCR: &'static str;
+}
+
+macro_rules! impl_npy_element {
+    ($($ty:ty => $descr:literal),* $(,)?) => {
+        $(
+            impl NpyElement for $ty {
+                const DESCR: &'static str = $descr;
+            }
+        )*
+    };
+}
+
+impl_npy_element! {
+    f32 => "<f4",
+    f64 => "<f8",
+    i32 => "<i4",
+    u32 => "<u4",
+    i64 => "<i8",
+    u64 => "<u8",
+    i16 => "<i2",
+    u16 => "<u2",
+    i8 => "|i1",
+    u8 => "|u1",
+}
+
+#[cfg(feature = "half")]
+impl_npy_element! {
+    half::f16 => "<f2",
+}
+
+#[cfg(feature = "num-complex")]
+impl_npy_element! {
+    num_complex::Complex32 => "<c8",
+    num_complex::Complex64 => "<c16",
+}
+
+impl<T> ROCArray<T>
+where
+    T: NpyElement,
+{
+    /// Save the array to a NumPy `.npy` file
+    pub fn save_npy(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+        self.write_npy(&mut file)?;
+        file.flush()?;
+        Ok(())
+    }
+
+    /// Load an array from a NumPy `.npy` file
+    ///
+    /// The file's dtype must match `T`. Fortran-order files are transposed
+    /// into row-major order on the device.
+    pub fn load_npy(path: impl AsRef<Path>) -> Result<Self> {
+        Self::read_npy(&mut File::open(path)?)
+    }
+
+    /// Write the array in `.npy` format
+    ///
+    /// Data is copied from the device in chunks through two pinned buffers,
+    /// so the next chunk transfers while the previous one is written.
+    pub fn write_npy<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&npy_header(T::DESCR, self.dims()))?;
+        write_device_bytes(&self.data, self.len() * size_of::<T>(), writer)
+    }
+
+    /// Read an array in `.npy` format
+    ///
+    /// Data is read in chunks into two pinned buffers, so one chunk
+    /// transfers to the device while the next is read.
+    pub fn read_npy<R: Read>(reader: &mut R) -> Result<Self> {
+        let header = read_npy_header(reader)?;
+        if !descr_matches(&header.descr, T::DESCR) {
+            return Err(invalid_argument(format!(
+                "npy data has dtype '{}', expected '{}'",
+                header.descr,
+                T::DESCR
+            )));
+        }
+
+        let mut dims = header.dims;
+        if header.fortran_order {
+            dims.reverse();
+        }
+        let array = ROCArray::new(Shape::new(dims))?;
+        read_device_bytes(&array.data, array.len() * size_of::<T>(), reader)?;
+
+        if header.fortran_order && array.ndim() > 1 {
+            let axes: Vec<usize> = (0..array.ndim()).rev().collect();
+            return array.permute(&axes)?.to_array();
+        }
+        Ok(array)
+    }
+}
+
+/// Writer for NumPy `.npz` archives
+///
+/// Each array becomes an uncompressed `<name>.npy` entry, as written by
+/// `numpy.savez`. [`finish`](Self::finish) must be called to write the
+/// archive's directory. Entries and the archive are limited to 4 GiB.
+///
+/// ```no_run
+/// use rocm_rs::rocarray::ROCArray;
+/// use rocm_rs::rocarray::npy::NpzWriter;
+///
+/// let weights = ROCArray::from_vec(vec![0.5f32, 1.5])?;
+/// let steps = ROCArray::from_vec(vec![100u32])?;
+///
+/// let mut npz = NpzWriter::create("checkpoint.npz")?;
+/// npz.add("weights", &weights)?;
+/// npz.add("steps", &steps)?;
+/// npz.finish()?;
+/// # Ok::<(), rocm_rs::error::Error>(())
+/// ```
+pub struct NpzWriter {
+    file: File,
+    entries: Vec<ZipEntry>,
+}
+
+impl NpzWriter {
+    /// Create an archive at `path`, replacing any existing file
+    pub fn create(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self {
+            file: File::create(path)?,
+            entries: Vec::new(),
+        })
+    }
+
+    /// Add `array` as the entry `name`
+    pub fn add<T: NpyElement>(&mut self, name: &str, array: &ROCArray<T>) -> Result<()> {
+        let name = format!("{}.npy", name);
+        if self.entries.iter().any(|entry| entry.name == name) {
+            return Err(invalid_argument(format!(
+                "npz archive already has an entry '{}'",
+                name
+            )));
+        }
+
+        let offset = self.file.stream_position()?;
+        // CRC and sizes are patched in once the data is written
+        let mut entry = ZipEntry {
+            name,
+            crc: 0,
+            size: 0,
+            offset: zip32(offset)?,
+            method: 0,
+        };
+        self.file.write_all(&entry.local_header())?;
+
+        let mut writer = CrcWriter {
+            inner: BufWriter::new(&mut self.file),
+            crc: !0,
+            len: 0,
+        };
+        array.write_npy(&mut writer)?;
+        writer.inner.flush()?;
+        entry.crc = !writer.crc;
+        entry.size = zip32(writer.len)?;
+        drop(writer);
+
+        let end = self.file.stream_position()?;
+        self.file.seek(SeekFrom::Start(offset))?;
+        self.file.write_all(&entry.local_header())?;
+        self.file.seek(SeekFrom::Start(end))?;
+
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    /// Write the archive's directory and close the file
+    pub fn finish(mut self) -> Result<()> {
+        let directory_offset = self.file.stream_position()?;
+        let mut directory = Vec::new();
+        for entry in &self.entries {
+            directory.extend_from_slice(&entry.central_header());
+        }
+        self.file.write_all(&directory)?;
+
+        let count = u16::try_from(self.entries.len())
+            .map_err(|_| invalid_argument("npz archives hold at most 65535 arrays"))?;
+        let mut end = Vec::with_capacity(22);
+        end.extend_from_slice(&ZIP_END_SIGNATURE.to_le_bytes());
+        end.extend_from_slice(&[0; 4]); // disk numbers
+        end.extend_from_slice(&count.to_le_bytes());
+        end.extend_from_slice(&count.to_le_bytes());
+        end.extend_from_slice(&zip32(directory.len() as u64)?.to_le_bytes());
+        end.extend_from_slice(&zip32(directory_offset)?.to_le_bytes());
+        end.extend_from_slice(&[0; 2]); // comment length
+        self.file.write_all(&end)?;
+        self.file.flush()?;
+        Ok(())
+    }
+}
+
+/// Reader for NumPy `.npz` archives
+///
+/// Entries must be stored uncompressed, as `numpy.savez` writes them;
+/// archives from `numpy.savez_compressed` are not supported.
+pub struct NpzReader {
+    file: File,
+    entries: Vec<ZipEntry>,
+}
+
+impl NpzReader {
+    /// Open the archive at `path` and read its directory
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let mut file = File::open(path)?;
+
+        // The end record is the last 22 bytes unless the archive has a comment
+        let len = file.seek(SeekFrom::End(0))?;
+        let tail_len = len.min(22 + u16::MAX as u64);
+        file.seek(SeekFrom::Start(len - tail_len))?;
+        let mut tail = vec![0; tail_len as usize];
+        file.read_exact(&mut tail)?;
+
+        let signature = ZIP_END_SIGNATURE.to_le_bytes();
+        let end = (0..tail.len().saturating_sub(21))
+            .rev()
+            .find(|&i| tail[i..i + 4] == signature)
+            .ok_or_else(|| parse_error("Not a zip archive: end of central directory not found"))?;
+        let end = &tail[end..];
+        let count = le_u16(end, 10);
+        let directory_offset = le_u32(end, 16);
+        if count == u16::MAX || directory_offset == u32::MAX {
+            return Err(not_implemented("zip64 npz archives are not supported"));
+        }
+
+        file.seek(SeekFrom::Start(directory_offset as u64))?;
+        let mut entries = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let mut header = [0; 46];
+            file.read_exact(&mut header)?;
+            if le_u32(&header, 0) != ZIP_CENTRAL_SIGNATURE {
+                return Err(parse_error("Corrupt zip central directory"));
+            }
+            let mut name = vec![0; le_u16(&header, 28) as usize];
+            file.read_exact(&mut name)?;
+            let skip = le_u16(&header, 30) as i64 + le_u16(&header, 32) as i64;
+            file.seek(SeekFrom::Current(skip))?;
+
+            entries.push(ZipEntry {
+                name: String::from_utf8_lossy(&name).into_owned(),
+                crc: le_u32(&header, 16),
+                size: le_u32(&header, 24),
+                offset: le_u32(&header, 42),
+                method: le_u16(&header, 10),
+            });
+        }
+
+        Ok(Self { file, entries })
+    }
+
+    /// Names of the arrays in the archive
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries
+            .iter()
+            .map(|entry| entry.name.strip_suffix(".npy").unwrap_or(&entry.name))
+    }
+
+    /// Load the array `name`, whose dtype must match `T`
+    pub fn load<T: NpyElement>(&mut self, name: &str) -> Result<ROCArray<T>> {
+        let entry = self
+            .entries
+            .iter()
+            .find(|entry| entry.name.strip_suffix(".npy") == Some(name) || entry.name == name)
+            .ok_or_else(|| invalid_argument(format!("npz archive has no array '{}'", name)))?;
+        if entry.method != 0 {
+            return Err(not_implemented(
+                "Compressed npz entries (numpy.savez_compressed) are not supported",
+            ));
+        }
+        if entry.size == u32::MAX || entry.offset == u32::MAX {
+            return Err(not_implemented("zip64 npz entries are not supported"));
+        }
+
+        self.file.seek(SeekFrom::Start(entry.offset as u64))?;
+        let mut header = [0; 30];
+        self.file.read_exact(&mut header)?;
+        if le_u32(&header, 0) != ZIP_LOCAL_SIGNATURE {
+            return Err(parse_error("Corrupt zip local header"));
+        }
+        let skip = le_u16(&header, 26) as i64 + le_u16(&header, 28) as i64;
+        self.file.seek(SeekFrom::Current(skip))?;
+
+        let mut reader = (&mut self.file).take(entry.size as u64);
+        ROCArray::read_npy(&mut reader)
+    }
+}
+
+// =============================================================================
+// npy headers
+// =============================================================================
+
+struct NpyHeader {
+    descr: String,
+    fortran_order: bool,
+    dims: Vec<usize>,
+}
+
+/// Version 1.0 header, padded with spaces so the data is 64-byte aligned
+fn npy_header(descr: &str, dims: &[usize]) -> Vec<u8> {
+    let shape = match dims {
+        [dim] => format!("({},)", dim),
+        _ => format!(
+            "({})",
+            dims.iter()
+                .map(|dim| dim.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let mut dict = format!(
+        "{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}",
+        descr, shape
+    );
+
+    // Magic, version and length take 10 bytes; the dict ends with '\n'
+    let unpadded = 10 + dict.len() + 1;
+    dict.extend(std::iter::repeat_n(
+        ' ',
+        unpadded.next_multiple_of(64) - unpadded,
+    ));
+    dict.push('\n');
+
+    let mut header = Vec::with_capacity(10 + dict.len());
+    header.extend_from_slice(NPY_MAGIC);
+    header.extend_from_slice(&[1, 0]);
+    header.extend_from_slice(&(dict.len() as u16).to_le_bytes());
+    header.extend_from_slice(dict.as_bytes());
+    header
+}
+
+fn read_npy_header<R: Read>(reader: &mut R) -> Result<NpyHeader> {
+    let mut preamble = [0; 8];
+    reader.read_exact(&mut preamble)?;
+    if &preamble[..6] != NPY_MAGIC {
+        return Err(parse_error("Not an npy file: bad magic string"));
+    }
+
+    let dict_len = match preamble[6] {
+        1 => {
+            let mut len = [0; 2];
+            reader.read_exact(&mut len)?;
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0; 4];
+            reader.read_exact(&mut len)?;
+            u32::from_le_bytes(len) as usize
+        }
+        version => {
+            return Err(not_implemented(format!(
+                "npy format version {} is not supported",
+                version
+            )));
+        }
+    };
+    let mut dict = vec![0; dict_len];
+    reader.read_exact(&mut dict)?;
+    let dict = String::from_utf8(dict).map_err(|_| parse_error("npy header is not UTF-8"))?;
+
+    parse_npy_dict(&dict)
+}
+
+fn parse_npy_dict(dict: &str) -> Result<NpyHeader> {
+    let descr = header_value(dict, "descr")?;
+    let descr = descr
+        .strip_prefix('\'')
+        .and_then(|d| d.strip_suffix('\''))
+        .ok_or_else(|| parse_error(format!("Unsupported npy dtype {}", descr)))?;
+
+    let fortran_order = match header_value(dict, "fortran_order")? {
+        "True" => true,
+        "False" => false,
+        other => {
+            return Err(parse_error(format!("Invalid npy fortran_order {}", other)));
+        }
+    };
+
+    let shape = header_value(dict, "shape")?;
+    let dims = shape
+        .strip_prefix('(')
+        .and_then(|s| s.strip_suffix(')'))
+        .ok_or_else(|| parse_error(format!("Invalid npy shape {}", shape)))?
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| {
+            dim.parse()
+                .map_err(|_| parse_error(format!("Invalid npy shape {}", shape)))
+        })
+        .collect::<Result<Vec<usize>>>()?;
+
+    Ok(NpyHeader {
+        descr: descr.to_string(),
+        fortran_order,
+        dims,
+    })
+}
+
+/// The text of the value for `key` in a header dict
+fn header_value<'a>(dict: &'a str, key: &str) -> Result<&'a str> {
+    let pattern = format!("'{}':", key);
+    let start = dict
+        .find(&pattern)
+        .ok_or_else(|| parse_error(format!("npy header has no '{}'", key)))?;
+    let rest = dict[start + pattern.len()..].trim_start();
+
+    let mut depth = 0;
+    for (i, c) in rest.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' | '}' if depth == 0 => return Ok(rest[..i].trim()),
+            _ => {}
+        }
+    }
+    Err(parse_error(format!(
+        "npy header value for '{}' is unterminated",
+        key
+    )))
+}
+
+/// Whether a file's dtype holds the same values as `expected`
+///
+/// Single-byte types carry no byte order, and `=` means native, which is
+/// little-endian on every ROCm platform.
+fn descr_matches(found: &str, expected: &str) -> bool {
+    let (Some(order), Some(kind)) = (found.chars().next(), found.get(1..)) else {
+        return false;
+    };
+    let single_byte = expected.starts_with('|');
+    kind == &expected[1..] && (matches!(order, '<' | '=' | '|') || single_byte)
+}
+
+// =============================================================================
+// Pinned, double-buffered transfers
+// =============================================================================
+
+fn staging_pair(bytes: usize) -> Result<[PinnedMemory<u8>; 2]> {
+    let chunk = DEFAULT_STAGING_BUFFER_SIZE.min(bytes);
+    Ok([PinnedMemory::new(chunk)?, PinnedMemory::new(chunk)?])
+}
+
+fn memcpy_async(
+    dst: *mut c_void,
+    src: *const c_void,
+    len: usize,
+    kind: crate::hip::bindings::hipMemcpyKind,
+    stream: &Stream,
+) -> Result<()> {
+    let error = unsafe { ffi::hipMemcpyAsync(dst, src, len, kind, stream.as_raw()) };
+    if error != ffi::hipError_t_hipSuccess {
+        return Err(crate::hip::Error::from_api(error, "hipMemcpyAsync").into());
+    }
+    Ok(())
+}
+
+/// Copy the first `bytes` bytes of `data` to `writer`, writing each chunk
+/// while the next one transfers
+fn write_device_bytes<T, W: Write>(
+    data: &DeviceMemory<T>,
+    bytes: usize,
+    writer: &mut W,
+) -> Result<()> {
+    if bytes == 0 {
+        return Ok(());
+    }
+
+    let mut buffers = staging_pair(bytes)?;
+    let events = [Event::new()?, Event::new()?];
+    let stream = Stream::new()?;
+    let chunk = buffers[0].count();
+    let src = data.as_ptr() as *const u8;
+
+    let queue = |index: usize, buffer: &mut PinnedMemory<u8>| -> Result<()> {
+        let offset = index * chunk;
+        let len = chunk.min(bytes - offset);
+        memcpy_async(
+            buffer.as_mut_ptr() as *mut c_void,
+            unsafe { src.add(offset) } as *const c_void,
+            len,
+            ffi::hipMemcpyKind_hipMemcpyDeviceToHost,
+            &stream,
+        )?;
+        events[index % 2].record(&stream)?;
+        Ok(())
+    };
+
+    let chunks = bytes.div_ceil(chunk);
+    let result = (|| {
+        queue(0, &mut buffers[0])?;
+        for index in 0..chunks {
+            if index + 1 < chunks {
+                queue(index + 1, &mut buffers[(index + 1) % 2])?;
+            }
+            events[index % 2].synchronize()?;
+            let len = chunk.min(bytes - index * chunk);
+            writer.write_all(&buffers[index % 2].as_slice()[..len])?;
+        }
+        Ok(())
+    })();
+
+    // Queued copies may still target the buffers
+    stream.synchronize()?;
+    result
+}
+
+/// Fill the first `bytes` bytes of `data` from `reader`, reading each chunk
+/// while the previous one transfers
+fn read_device_bytes<T, R: Read>(
+    data: &DeviceMemory<T>,
+    bytes: usize,
+    reader: &mut R,
+) -> Result<()> {
+    if bytes == 0 {
+        return Ok(());
+    }
+
+    let mut buffers = staging_pair(bytes)?;
+    let events = [Event::new()?, Event::new()?];
+    let stream = Stream::new()?;
+    let chunk = buffers[0].count();
+    let dst = data.as_ptr() as *mut u8;
+
+    let result = (|| {
+        for (index, offset) in (0..bytes).step_by(chunk).enumerate() {
+            let len = chunk.min(bytes - offset);
+            let slot = index % 2;
+
+            // Wait until the previous transfer out of this buffer is done
+            if index >= 2 {
+                events[slot].synchronize()?;
+            }
+            reader.read_exact(&mut buffers[slot].as_slice_mut()[..len])?;
+
+            memcpy_async(
+                unsafe { dst.add(offset) } as *mut c_void,
+                buffers[slot].as_ptr() as *const c_void,
+                len,
+                ffi::hipMemcpyKind_hipMemcpyHostToDevice,
+                &stream,
+            )?;
+            events[slot].record(&stream)?;
+        }
+        Ok(())
+    })();
+
+    stream.synchronize()?;
+    result
+}
+
+// =============================================================================
+// Zip container
+// =============================================================================
+
+const ZIP_LOCAL_SIGNATURE: u32 = 0x0403_4b50;
+const ZIP_CENTRAL_SIGNATURE: u32 = 0x0201_4b50;
+const ZIP_END_SIGNATURE: u32 = 0x0605_4b50;
+const ZIP_VERSION: u16 = 20;
+// 1980-01-01, the earliest date a zip entry can carry
+const ZIP_DATE: u16 = 0x21;
+
+struct ZipEntry {
+    name: String,
+    crc: u32,
+    size: u32,
+    offset: u32,
+    method: u16,
+}
+
+impl ZipEntry {
+    fn local_header(&self) -> Vec<u8> {
+        let mut header = Vec::with_capacity(30 + self.name.len());
+        header.extend_from_slice(&ZIP_LOCAL_SIGNATURE.to_le_bytes());
+        header.extend_from_slice(&ZIP_VERSION.to_le_bytes());
+        self.push_common(&mut header);
+        header.extend_from_slice(&[0; 2]); // extra field length
+        header.extend_from_slice(self.name.as_bytes());
+        header
+    }
+
+    fn central_header(&self) -> Vec<u8> {
+        let mut header = Vec::with_capacity(46 + self.name.len());
+        header.extend_from_slice(&ZIP_CENTRAL_SIGNATURE.to_le_bytes());
+        header.extend_from_slice(&ZIP_VERSION.to_le_bytes()); // made by
+        header.extend_from_slice(&ZIP_VERSION.to_le_bytes()); // needed
+        self.push_common(&mut header);
+        // Extra and comment lengths, disk number, internal and external
+        // attributes
+        header.extend_from_slice(&[0; 12]);
+        header.extend_from_slice(&self.offset.to_le_bytes());
+        header.extend_from_slice(self.name.as_bytes());
+        header
+    }
+
+    /// Flags, method, time, date, CRC, sizes and name length
+    fn push_common(&self, header: &mut Vec<u8>) {
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(&self.method.to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(&ZIP_DATE.to_le_bytes());
+        header.extend_from_slice(&self.crc.to_le_bytes());
+        header.extend_from_slice(&self.size.to_le_bytes());
+        header.extend_from_slice(&self.size.to_le_bytes());
+        header.extend_from_slice(&(self.name.len() as u16).to_le_bytes());
+    }
+}
+
+fn zip32(value: u64) -> Result<u32> {
+    u32::try_from(value)
+        .ok()
+        .filter(|&v| v != u32::MAX)
+        .ok_or_else(|| not_implemented("npz archives larger than 4 GiB are not supported"))
+}
+
+fn le_u16(bytes: &[u8], at: usize) -> u16 {
+    u16::from_le_bytes([bytes[at], bytes[at + 1]])
+}
+
+fn le_u32(bytes: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap())
+}
+
+/// CRC-32 (IEEE) lookup table
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                0xEDB8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Passes writes through while tracking their CRC-32 and length
+struct CrcWriter<W> {
+    inner: W,
+    crc: u32,
+    len: u64,
+}
+
+impl<W: Write> Write for CrcWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        for &byte in &buf[..written] {
+            self.crc = CRC_TABLE[((self.crc ^ byte as u32) & 0xFF) as usize] ^ (self.crc >> 8);
+        }
+        self.len += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("rocm_rs_{}_{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn test_header_round_trip() -> Result<()> {
+        let header = npy_header("<f4", &[2, 3]);
+        assert_eq!(header.len() % 64, 0);
+        let parsed = read_npy_header(&mut &header[..])?;
+        assert_eq!(parsed.descr, "<f4");
+        assert!(!parsed.fortran_order);
+        assert_eq!(parsed.dims, vec![2, 3]);
+
+        // As written by numpy for a 1-D array and a scalar
+        let parsed = parse_npy_dict("{'descr': '|u1', 'fortran_order': True, 'shape': (5,), }")?;
+        assert_eq!(parsed.descr, "|u1");
+        assert!(parsed.fortran_order);
+        assert_eq!(parsed.dims, vec![5]);
+        let parsed = parse_npy_dict("{'descr': '<i8', 'fortran_order': False, 'shape': (), }")?;
+        assert!(parsed.dims.is_empty());
+
+        assert!(descr_matches("<f4", "<f4"));
+        assert!(descr_matches("=u4", "<u4"));
+        assert!(!descr_matches(">f4", "<f4"));
+        assert!(descr_matches("<u1", "|u1"));
+        assert!(!descr_matches("<f8", "<f4"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_crc32() {
+        let mut writer = CrcWriter {
+            inner: Vec::new(),
+            crc: !0,
+            len: 0,
+        };
+        writer.write_all(b"123456789").unwrap();
+        assert_eq!(!writer.crc, 0xCBF4_3926);
+        assert_eq!(writer.len, 9);
+    }
+
+    #[test]
+    fn test_npy_round_trip() -> Result<()> {
+        let path = temp_path("round_trip.npy");
+        let array = ROCArray::from_vec_with_shape(
+            (0..12).map(|x| x as f32).collect(),
+            Shape::new_2d(3, 4),
+        )?;
+        array.save_npy(&path)?;
+
+        let loaded = ROCArray::<f32>::load_npy(&path)?;
+        assert_eq!(loaded.dims(), &[3, 4]);
+        assert_eq!(loaded.to_vec()?, array.to_vec()?);
+
+        assert!(ROCArray::<f64>::load_npy(&path).is_err());
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_fortran_order_is_transposed() -> Result<()> {
+        // A 2x3 array [[1, 2, 3], [4, 5, 6]] stored column by column
+        let dict = "{'descr': '<i4', 'fortran_order': True, 'shape': (2, 3), }\n";
+        let mut bytes = NPY_MAGIC.to_vec();
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(dict.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(dict.as_bytes());
+        for value in [1i32, 4, 2, 5, 3, 6] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        let array = ROCArray::<i32>::read_npy(&mut &bytes[..])?;
+        assert_eq!(array.dims(), &[2, 3]);
+        assert_eq!(array.to_vec()?, vec![1, 2, 3, 4, 5, 6]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_npz_round_trip() -> Result<()> {
+        let path = temp_path("round_trip.npz");
+        let weights =
+            ROCArray::from_vec_with_shape(vec![0.5f64, 1.5, 2.5, 3.5], Shape::new_2d(2, 2))?;
+        let labels = ROCArray::from_vec(vec![7u8, 8, 9])?;
+
+        let mut npz = NpzWriter::create(&path)?;
+        npz.add("weights", &weights)?;
+        npz.add("labels", &labels)?;
+        assert!(npz.add("labels", &labels).is_err());
+        npz.finish()?;
+
+        let mut npz = NpzReader::open(&path)?;
+        assert_eq!(npz.names().collect::<Vec<_>>(), vec!["weights", "labels"]);
+        assert_eq!(npz.load::<u8>("labels")?.to_vec()?, vec![7, 8, 9]);
+        let loaded = npz.load::<f64>("weights")?;
+        assert_eq!(loaded.dims(), &[2, 2]);
+        assert_eq!(loaded.to_vec()?, weights.to_vec()?);
+        assert!(npz.load::<f32>("missing").is_err());
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+// src/rocarray/ops.rs - Arithmetic operators for ROCArray
+//
+// Binary operators borrow their left operand and return
+// `Result<ROCArray<T>>`, since an allocation or launch can fail, so
+// expressions read `(&a + &b)?`. Borrowing keeps `a.add(&b)` resolving to
+// the inherent method. The compound assignments run in place without
+// allocating and panic on failure; `add_inplace` and friends return the
+// error instead.
+
+use crate::error::Result;
+use crate::rocarray::ROCArray;
+use crate::rocarray::kernels::NumericOps;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+macro_rules! impl_binary_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $inplace:ident, $symbol:literal) => {
+        impl<T: NumericOps> $trait<&ROCArray<T>> for &ROCArray<T> {
+            type Output = Result<ROCArray<T>>;
+
+            fn $method(self, rhs: &ROCArray<T>) -> Self::Output {
+                ROCArray::$method(self, rhs)
+            }
+        }
+
+        impl<T: NumericOps> $trait<ROCArray<T>> for &ROCArray<T> {
+            type Output = Result<ROCArray<T>>;
+
+            fn $method(self, rhs: ROCArray<T>) -> Self::Output {
+                ROCArray::$method(self, &rhs)
+            }
+        }
+
+        /// # Panics
+        ///
+        /// If `rhs` does not broadcast to the shape of `self`, or the kernel
+        /// launch fails
+        impl<T: NumericOps> $assign_trait<&ROCArray<T>> for ROCArray<T> {
+            fn $assign_method(&mut self, rhs: &ROCArray<T>) {
+                if let Err(e) = self.$inplace(rhs) {
+                    panic!("ROCArray {} failed: {}", $symbol, e);
+                }
+            }
+        }
+
+        impl<T: NumericOps> $assign_trait<ROCArray<T>> for ROCArray<T> {
+            fn $assign_method(&mut self, rhs: ROCArray<T>) {
+                self.$assign_method(&rhs);
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, AddAssign, add_assign, add_inplace, "+=");
+impl_binary_op!(Sub, sub, SubAssign, sub_assign, sub_inplace, "-=");
+impl_binary_op!(Mul, mul, MulAssign, mul_assign, mul_inplace, "*=");
+impl_binary_op!(Div, div, DivAssign, div_assign, div_inplace, "/=");
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Result;
+    use crate::rocarray::{ROCArray, Shape};
+
+    #[test]
+    fn test_binary_operators() -> Result<()> {
+        let a = ROCArray::from_vec(vec![6.0f32, 8.0, 10.0])?;
+        let b = ROCArray::from_vec(vec![2.0f32, 4.0, 5.0])?;
+
+        assert_eq!((&a + &b)?.to_vec()?, vec![8.0, 12.0, 15.0]);
+        assert_eq!((&a - &b)?.to_vec()?, vec![4.0, 4.0, 5.0]);
+        assert_eq!((&a * &b)?.to_vec()?, vec![12.0, 32.0, 50.0]);
+        assert_eq!((&a / b)?.to_vec()?, vec![3.0, 2.0, 2.0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_assign_operators() -> Result<()> {
+        let mut a = ROCArray::from_vec_with_shape(vec![1i32, 2, 3, 4, 5, 6], Shape::new_2d(2, 3))?;
+        let row = ROCArray::from_vec(vec![10i32, 20, 30])?;
+        let ptr = a.as_ptr();
+
+        a += &row;
+        a *= ROCArray::from_vec(vec![2i32, 2, 2])?;
+        a -= &row;
+        assert_eq!(a.to_vec()?, vec![12, 24, 36, 18, 30, 42]);
+        a /= &row;
+        assert_eq!(a.to_vec()?, vec![1, 1, 1, 1, 1, 1]);
+        // Still the original allocation
+        assert_eq!(a.as_ptr(), ptr);
+
+        // The result must keep the shape of the left operand
+        assert!(row.clone_array()?.add_inplace(&a).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assign_shape_mismatch_panics() {
+        let mut a = ROCArray::from_vec(vec![1.0f32, 2.0]).unwrap();
+        a += &ROCArray::from_vec(vec![1.0f32, 2.0, 3.0]).unwrap();
+    }
+}
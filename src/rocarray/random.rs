@@ -2,6 +2,8 @@
 
 use crate::error::Result;
 use crate::hip::DeviceMemory;
+use crate::rocarray::kernels::{self, NumericOps};
+use crate::rocarray::{ROCArray, Shape};
 use crate::rocrand::{
     Generator, LogNormal, Normal, Poisson, PseudoRng, QuasiRng, Uniform, rng_type,
 };
@@ -268,6 +270,167 @@ where
     T::fill_poisson_device(&mut generator, output, lambda)
 }
 
+/// Fill the first `len` elements of a DeviceMemory buffer with integers
+/// drawn uniformly from `[low, high)`
+///
+/// rocRAND supplies 32 random bits per element, or 64 when the range does
+/// not fit in 32 bits, which a kernel scales onto the range.
+pub fn fill_int_range<T>(
+    output: &mut DeviceMemory<T>,
+    len: usize,
+    low: T,
+    high: T,
+    seed: Option<u64>,
+) -> Result<()>
+where
+    T: NumericOps + Into<i128>,
+{
+    let range = high.into() - low.into();
+    if range <= 0 {
+        return Err(crate::error::Error::Custom(
+            "Invalid range: low must be less than high".to_string(),
+        ));
+    }
+    if len == 0 {
+        return Ok(());
+    }
+    let range = range as u64;
+    let words = if range > u32::MAX as u64 + 1 { 2 } else { 1 };
+
+    let mut generator = PseudoRng::new(rng_type::XORWOW)?;
+
+    if let Some(seed_value) = seed {
+        generator.set_seed(seed_value)?;
+    }
+
+    generator.initialize()?;
+
+    let mut bits = DeviceMemory::<u32>::new(len * words)?;
+    generator.generate_u32(&mut bits)?;
+    kernels::random_int_from_bits(&bits, output, len, low, range, words)
+}
+
+/// Fill the first `len` elements of a DeviceMemory buffer with 1 with
+/// probability `p` and 0 otherwise
+pub fn fill_bernoulli<T>(
+    output: &mut DeviceMemory<T>,
+    len: usize,
+    p: f32,
+    seed: Option<u64>,
+) -> Result<()>
+where
+    T: NumericOps,
+{
+    if !(0.0..=1.0).contains(&p) {
+        return Err(crate::error::Error::Custom(format!(
+            "Bernoulli probability must be in [0, 1], got {}",
+            p
+        )));
+    }
+    if len == 0 {
+        return Ok(());
+    }
+
+    let mut generator = PseudoRng::new(rng_type::XORWOW)?;
+
+    if let Some(seed_value) = seed {
+        generator.set_seed(seed_value)?;
+    }
+
+    generator.initialize()?;
+
+    let mut uniform = DeviceMemory::<f32>::new(len)?;
+    generator.generate_uniform(&mut uniform)?;
+    kernels::bernoulli_from_uniform(&uniform, output, len, p)
+}
+
+/// Create a ROCArray of integers drawn uniformly from `[low, high)`
+pub fn random_int<T>(shape: Shape, low: T, high: T, seed: Option<u64>) -> Result<ROCArray<T>>
+where
+    T: NumericOps + Into<i128>,
+{
+    let mut array = ROCArray::new(shape)?;
+    let len = array.len();
+    fill_int_range(&mut array.data, len, low, high, seed)?;
+    Ok(array)
+}
+
+/// Create a ROCArray of Bernoulli trials: 1 with probability `p`, else 0
+pub fn random_bernoulli<T>(shape: Shape, p: f32, seed: Option<u64>) -> Result<ROCArray<T>>
+where
+    T: NumericOps,
+{
+    let mut array = ROCArray::new(shape)?;
+    let len = array.len();
+    fill_bernoulli(&mut array.data, len, p, seed)?;
+    Ok(array)
+}
+
+/// Draw `num_samples` category indices, with replacement, from each
+/// distribution in `probs`
+///
+/// `probs` is a 1-D array of category weights, or a 2-D array with one
+/// distribution per row. Weights must be non-negative with a positive sum
+/// but need not be normalized. The result has shape `[num_samples]` or
+/// `[rows, num_samples]`.
+///
+/// Sampling runs on the device by inverse CDF: the weights are scanned
+/// into running sums and each rocRAND uniform is located in them by binary
+/// search. Weights may be `f32`, `f64`, `i32` or `u32`.
+pub fn multinomial<T>(
+    probs: &ROCArray<T>,
+    num_samples: usize,
+    seed: Option<u64>,
+) -> Result<ROCArray<u32>>
+where
+    T: NumericOps,
+{
+    let (rows, categories, shape) = match *probs.dims() {
+        [categories] => (1, categories, Shape::new_1d(num_samples)),
+        [rows, categories] => (rows, categories, Shape::new_2d(rows, num_samples)),
+        _ => {
+            return Err(crate::error::invalid_argument(format!(
+                "multinomial needs a 1-D or 2-D array of weights, got {} dimensions",
+                probs.ndim()
+            )));
+        }
+    };
+    if categories == 0 {
+        return Err(crate::error::invalid_argument(
+            "Cannot sample from an empty set of categories",
+        ));
+    }
+
+    let output = ROCArray::new(shape)?;
+    let len = rows * num_samples;
+    if len == 0 {
+        return Ok(output);
+    }
+
+    let cdf = probs.cumsum(probs.ndim() - 1)?;
+
+    let mut generator = PseudoRng::new(rng_type::PHILOX4_32_10)?;
+
+    if let Some(seed_value) = seed {
+        generator.set_seed(seed_value)?;
+    }
+
+    generator.initialize()?;
+
+    let mut uniform = DeviceMemory::<f64>::new(len)?;
+    generator.generate_uniform_double(&mut uniform)?;
+
+    kernels::multinomial_from_cdf(
+        &cdf.data,
+        &uniform,
+        &output.data,
+        rows,
+        categories,
+        num_samples,
+    )?;
+    Ok(output)
+}
+
 /// Generate uniformly distributed random values and return them as a Vec
 pub fn generate_uniform<T>(count: usize, seed: Option<u64>) -> Result<Vec<T>>
 where
@@ -446,12 +609,8 @@ impl RandomUtils {
             ));
         }
 
-        // Generate uniform values and scale to range
-        fill_uniform(output, output.count(), seed)?;
-
-        // TODO: Add kernel to scale values to range [min_val, max_val)
-        // For now, this is a placeholder
-        Ok(())
+        let len = output.count();
+        fill_int_range(output, len, min_val, max_val, seed)
     }
 
     /// Generate random permutation of indices
@@ -532,6 +691,65 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_random_int() -> Result<()> {
+        let values = random_int::<i32>(Shape::new_1d(1000), -5, 5, Some(42))?.to_vec()?;
+        assert!(values.iter().all(|&x| (-5..5).contains(&x)));
+        // Every value in a small range shows up in 1000 draws
+        assert!((-5..5).all(|v| values.contains(&v)));
+
+        // Ranges wider than 32 bits take two random words per element
+        let wide = random_int::<u64>(Shape::new_1d(100), 0, u64::MAX, Some(7))?.to_vec()?;
+        assert!(wide.iter().any(|&x| x > u32::MAX as u64));
+
+        let same = random_int::<i32>(Shape::new_1d(1000), -5, 5, Some(42))?.to_vec()?;
+        assert_eq!(values, same);
+
+        assert!(random_int::<u8>(Shape::new_1d(4), 3, 3, None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_random_bernoulli() -> Result<()> {
+        let values = random_bernoulli::<u8>(Shape::new_1d(10000), 0.25, Some(42))?.to_vec()?;
+        assert!(values.iter().all(|&x| x <= 1));
+        let ones = values.iter().filter(|&&x| x == 1).count();
+        assert!((2000..3000).contains(&ones));
+
+        let all = random_bernoulli::<f32>(Shape::new_1d(100), 1.0, Some(1))?.to_vec()?;
+        assert!(all.iter().all(|&x| x == 1.0));
+        let none = random_bernoulli::<f32>(Shape::new_1d(100), 0.0, Some(1))?.to_vec()?;
+        assert!(none.iter().all(|&x| x == 0.0));
+
+        assert!(random_bernoulli::<u8>(Shape::new_1d(4), 1.5, None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_multinomial() -> Result<()> {
+        let probs = ROCArray::from_vec(vec![0.1f32, 0.0, 0.6, 0.3])?;
+        let samples = multinomial(&probs, 10000, Some(42))?;
+        assert_eq!(samples.dims(), &[10000]);
+
+        let mut counts = [0usize; 4];
+        for s in samples.to_vec()? {
+            counts[s as usize] += 1;
+        }
+        assert_eq!(counts[1], 0);
+        assert!((500..1500).contains(&counts[0]));
+        assert!((5500..6500).contains(&counts[2]));
+        assert!((2500..3500).contains(&counts[3]));
+
+        // Unnormalized weights, one distribution per row
+        let rows = ROCArray::from_vec_with_shape(vec![0u32, 5, 0, 2, 0, 0], Shape::new_2d(2, 3))?;
+        let samples = multinomial(&rows, 8, Some(1))?;
+        assert_eq!(samples.dims(), &[2, 8]);
+        assert_eq!(samples.to_vec()?, [vec![1; 8], vec![0; 8]].concat());
+
+        assert!(multinomial(&ROCArray::<f32>::new(Shape::new_2d(2, 0))?, 4, None).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_random_permutation() -> Result<()> {
         let perm = RandomUtils::random_permutation(10, Some(42))?;
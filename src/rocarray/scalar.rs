@@ -0,0 +1,101 @@
+// src/rocarray/scalar.rs - Single values that stay on the device
+
+use crate::error::Result;
+use crate::hip::kernel::AsKernelArg;
+use crate::hip::memory::{KernelArg, PendingCopy};
+use crate::hip::{DeviceMemory, Stream};
+use std::fmt;
+
+/// A single value in device memory
+///
+/// Returned by the `*_device` reductions so their result can feed further
+/// kernels, or rocBLAS calls in `PointerMode::Device`, without a round trip
+/// through the host. Only reading the value back synchronizes.
+pub struct DeviceScalar<T> {
+    memory: DeviceMemory<T>,
+}
+
+impl<T> fmt::Debug for DeviceScalar<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeviceScalar")
+            .field("ptr", &self.memory.as_ptr())
+            .field("type", &std::any::type_name::<T>())
+            .finish()
+    }
+}
+
+impl<T> DeviceScalar<T>
+where
+    T: Copy + Default + 'static,
+{
+    /// Allocate a scalar set to zero bytes
+    pub fn zeroed() -> Result<Self> {
+        let mut memory = DeviceMemory::new(1)?;
+        memory.memset(0)?;
+        Ok(Self { memory })
+    }
+
+    /// Allocate a scalar holding `value`
+    pub fn from_value(value: T) -> Result<Self> {
+        let mut memory = DeviceMemory::new(1)?;
+        memory.copy_from_host(&[value])?;
+        Ok(Self { memory })
+    }
+
+    /// Wrap a one-element allocation
+    pub(crate) fn from_memory(memory: DeviceMemory<T>) -> Self {
+        debug_assert_eq!(memory.count(), 1);
+        Self { memory }
+    }
+
+    /// Copy the value to the host after the work queued on `stream`
+    pub fn read(&self, stream: &Stream) -> Result<T> {
+        let pending = self.read_async(stream)?;
+        Ok(stream.synchronize_memory(pending)?[0])
+    }
+
+    /// Start copying the value to the host on `stream`
+    pub fn read_async(&self, stream: &Stream) -> Result<PendingCopy<T>> {
+        Ok(self.memory.copy_to_host_async(vec![T::default()], stream)?)
+    }
+
+    /// Overwrite the value on `stream`
+    pub fn write_async(&self, value: T, stream: &Stream) -> Result<()> {
+        Ok(self.memory.copy_from_host_async(vec![value], stream)?)
+    }
+
+    /// Get the raw device pointer
+    pub fn as_ptr(&self) -> *mut std::ffi::c_void {
+        self.memory.as_ptr()
+    }
+
+    /// Get the underlying one-element allocation
+    pub fn device_memory(&self) -> &DeviceMemory<T> {
+        &self.memory
+    }
+
+    /// Get the device pointer to the value, for rocBLAS scalar arguments
+    /// such as `alpha`
+    ///
+    /// Pass it to entry points that take scalars as raw pointers, such as
+    /// [`gemm_ex`](crate::rocblas::level3::gemm_ex) or the `rocblas::ffi`
+    /// functions, with the handle in `PointerMode::Device`. The pointer
+    /// must never be dereferenced on the host.
+    ///
+    /// ```ignore
+    /// let norm = grads.sum_device(&stream)?;
+    /// handle.set_pointer_mode(PointerMode::Device.into())?;
+    /// let status = unsafe {
+    ///     ffi::rocblas_saxpy(handle.as_raw(), n, norm.as_device_ptr(), x, 1, y, 1)
+    /// };
+    /// ```
+    pub fn as_device_ptr(&self) -> *const T {
+        self.memory.as_ptr() as *const T
+    }
+}
+
+impl<T> AsKernelArg for DeviceScalar<T> {
+    fn as_kernel_arg(&self) -> KernelArg {
+        self.memory.as_kernel_arg()
+    }
+}
@@ -0,0 +1,350 @@
+// src/rocarray/sharded.rs - Arrays split along the leading dimension across GPUs
+
+use crate::error::{Result, invalid_argument};
+use crate::hip::{Device, DeviceMemory, Stream};
+use crate::rocarray::kernels::{self, NumericOps};
+use crate::rocarray::{DeviceScalar, ROCArray, Shape};
+use std::ops::Range;
+
+/// One device's slice of a [`ShardedROCArray`]
+struct Shard<T> {
+    device: Device,
+    stream: Stream,
+    rows: Range<usize>,
+    array: ROCArray<T>,
+}
+
+/// An array whose leading dimension is split across several devices
+///
+/// Each device holds a contiguous block of rows, as evenly sized as
+/// possible, with its own stream. Element-wise operations run on every
+/// shard concurrently and produce a sharded result with the same layout;
+/// reductions combine the per-shard results on the host. Use
+/// [`gather`](Self::gather) to bring the whole array back to one device.
+///
+/// Every method restores the current device before returning.
+pub struct ShardedROCArray<T> {
+    shards: Vec<Shard<T>>,
+    shape: Shape,
+}
+
+impl<T> ShardedROCArray<T>
+where
+    T: NumericOps,
+{
+    /// Split host data of the given shape across `devices`
+    ///
+    /// Devices beyond the number of rows are left without a shard.
+    pub fn scatter(data: &[T], shape: Shape, devices: &[Device]) -> Result<Self> {
+        if data.len() != shape.size() {
+            return Err(invalid_argument(format!(
+                "Data length {} does not match shape {:?}",
+                data.len(),
+                shape.dims()
+            )));
+        }
+        if devices.is_empty() || shape.ndim() == 0 {
+            return Err(invalid_argument(
+                "Sharding needs at least one device and one dimension",
+            ));
+        }
+
+        let rows = shape.dims()[0];
+        let row_len = shape.dims()[1..].iter().product::<usize>();
+        let count = devices.len().min(rows).max(1);
+        let mut shards = Vec::with_capacity(count);
+
+        with_current_restored(|| {
+            let mut start = 0;
+            for (i, device) in devices.iter().take(count).enumerate() {
+                // The first rows % count shards take one extra row
+                let end = start + rows / count + usize::from(i < rows % count);
+                let mut dims = shape.dims().to_vec();
+                dims[0] = end - start;
+
+                device.set_current()?;
+                let array = ROCArray::from_vec_with_shape(
+                    data[start * row_len..end * row_len].to_vec(),
+                    Shape::new(dims),
+                )?;
+                shards.push(Shard {
+                    device: device.clone(),
+                    stream: Stream::new()?,
+                    rows: start..end,
+                    array,
+                });
+                start = end;
+            }
+            Ok(())
+        })?;
+
+        Ok(Self { shards, shape })
+    }
+
+    /// Split an existing array across `devices`, staging it through the host
+    pub fn from_array(array: &ROCArray<T>, devices: &[Device]) -> Result<Self> {
+        Self::scatter(&array.to_vec()?, array.shape().clone(), devices)
+    }
+
+    /// Shape of the whole array
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    /// Number of devices holding a shard
+    pub fn num_shards(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// Each shard's device, leading-dimension row range and data
+    pub fn shards(&self) -> impl Iterator<Item = (&Device, Range<usize>, &ROCArray<T>)> {
+        self.shards
+            .iter()
+            .map(|shard| (&shard.device, shard.rows.clone(), &shard.array))
+    }
+
+    /// Copy the whole array to the host
+    pub fn to_vec(&self) -> Result<Vec<T>> {
+        let mut result = Vec::with_capacity(self.shape.size());
+        with_current_restored(|| {
+            for shard in &self.shards {
+                shard.device.set_current()?;
+                result.extend(shard.array.to_vec()?);
+            }
+            Ok(())
+        })?;
+        Ok(result)
+    }
+
+    /// Collect every shard into one array on the current device
+    pub fn gather(&self) -> Result<ROCArray<T>> {
+        let data = self.to_vec()?;
+        ROCArray::from_vec_with_shape(data, self.shape.clone())
+    }
+
+    /// Element-wise addition of two arrays sharded the same way
+    pub fn add(&self, other: &Self) -> Result<Self> {
+        self.zip_with(other, kernels::elementwise_add_async)
+    }
+
+    /// Element-wise subtraction of two arrays sharded the same way
+    pub fn sub(&self, other: &Self) -> Result<Self> {
+        self.zip_with(other, kernels::elementwise_sub_async)
+    }
+
+    /// Element-wise multiplication of two arrays sharded the same way
+    pub fn mul(&self, other: &Self) -> Result<Self> {
+        self.zip_with(other, kernels::elementwise_mul_async)
+    }
+
+    /// Element-wise division of two arrays sharded the same way
+    pub fn div(&self, other: &Self) -> Result<Self> {
+        self.zip_with(other, kernels::elementwise_div_async)
+    }
+
+    /// Add a scalar to every element
+    pub fn add_scalar(&self, scalar: T) -> Result<Self> {
+        self.map_shards(|shard, out| {
+            kernels::scalar_add_async(
+                &shard.array.data,
+                scalar,
+                &out.data,
+                shard.array.len(),
+                &shard.stream,
+            )
+        })
+    }
+
+    /// Multiply every element by a scalar
+    pub fn mul_scalar(&self, scalar: T) -> Result<Self> {
+        self.map_shards(|shard, out| {
+            kernels::scalar_mul_async(
+                &shard.array.data,
+                scalar,
+                &out.data,
+                shard.array.len(),
+                &shard.stream,
+            )
+        })
+    }
+
+    /// Sum of all elements
+    ///
+    /// Shards are reduced concurrently and their sums added on the host in
+    /// shard order.
+    pub fn sum(&self) -> Result<T>
+    where
+        T: std::ops::Add<Output = T>,
+    {
+        let partials = self.reduce_shards(kernels::reduce_sum_device_async)?;
+        Ok(partials.into_iter().fold(T::default(), |acc, x| acc + x))
+    }
+
+    /// Largest element
+    pub fn max(&self) -> Result<T>
+    where
+        T: PartialOrd,
+    {
+        let partials = self.reduce_shards(kernels::reduce_max_device_async)?;
+        Ok(pick(partials, |candidate, best| candidate > best))
+    }
+
+    /// Smallest element
+    pub fn min(&self) -> Result<T>
+    where
+        T: PartialOrd,
+    {
+        let partials = self.reduce_shards(kernels::reduce_min_device_async)?;
+        Ok(pick(partials, |candidate, best| candidate < best))
+    }
+
+    // Launch `op` on every shard's stream, then wait for all of them
+    fn map_shards<F>(&self, mut op: F) -> Result<Self>
+    where
+        F: FnMut(&Shard<T>, &ROCArray<T>) -> Result<()>,
+    {
+        let mut shards = Vec::with_capacity(self.shards.len());
+        with_current_restored(|| {
+            for shard in &self.shards {
+                shard.device.set_current()?;
+                let out = ROCArray::new(shard.array.shape().clone())?;
+                op(shard, &out)?;
+                shards.push(Shard {
+                    device: shard.device.clone(),
+                    stream: Stream::new()?,
+                    rows: shard.rows.clone(),
+                    array: out,
+                });
+            }
+            for shard in &self.shards {
+                shard.device.set_current()?;
+                shard.stream.synchronize()?;
+            }
+            Ok(())
+        })?;
+
+        Ok(Self {
+            shards,
+            shape: self.shape.clone(),
+        })
+    }
+
+    fn zip_with<F>(&self, other: &Self, op: F) -> Result<Self>
+    where
+        F: Fn(&DeviceMemory<T>, &DeviceMemory<T>, &DeviceMemory<T>, usize, &Stream) -> Result<()>,
+    {
+        let same_layout = self.shape == other.shape
+            && self.shards.len() == other.shards.len()
+            && self
+                .shards
+                .iter()
+                .zip(&other.shards)
+                .all(|(a, b)| a.device.id() == b.device.id() && a.rows == b.rows);
+        if !same_layout {
+            return Err(invalid_argument(
+                "Sharded arrays must have the same shape and shard layout",
+            ));
+        }
+
+        let mut others = other.shards.iter();
+        self.map_shards(|shard, out| {
+            let rhs = others.next().expect("shard counts checked above");
+            op(
+                &shard.array.data,
+                &rhs.array.data,
+                &out.data,
+                shard.array.len(),
+                &shard.stream,
+            )
+        })
+    }
+
+    fn reduce_shards<F>(&self, op: F) -> Result<Vec<T>>
+    where
+        F: Fn(&DeviceMemory<T>, usize, &Stream) -> Result<DeviceScalar<T>>,
+    {
+        with_current_restored(|| {
+            // Launch everywhere first so the devices work concurrently
+            let mut pending = Vec::with_capacity(self.shards.len());
+            for shard in &self.shards {
+                shard.device.set_current()?;
+                pending.push(op(&shard.array.data, shard.array.len(), &shard.stream)?);
+            }
+            self.shards
+                .iter()
+                .zip(pending)
+                .map(|(shard, scalar)| {
+                    shard.device.set_current()?;
+                    scalar.read(&shard.stream)
+                })
+                .collect()
+        })
+    }
+}
+
+// First value that `better` prefers over everything before it
+fn pick<T: Copy>(values: Vec<T>, better: impl Fn(&T, &T) -> bool) -> T {
+    let mut values = values.into_iter();
+    let first = values
+        .next()
+        .expect("sharded arrays have at least one shard");
+    values.fold(first, |best, x| if better(&x, &best) { x } else { best })
+}
+
+fn with_current_restored<R>(f: impl FnOnce() -> Result<R>) -> Result<R> {
+    let current = Device::current()?;
+    let result = f();
+    current.set_current()?;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn devices() -> Result<Vec<Device>> {
+        let count = crate::hip::get_device_count()?;
+        (0..count.max(1)).map(|id| Ok(Device::new(id)?)).collect()
+    }
+
+    #[test]
+    fn test_scatter_and_gather() -> Result<()> {
+        let devices = devices()?;
+        let data: Vec<f32> = (0..15).map(|x| x as f32).collect();
+        let sharded = ShardedROCArray::scatter(&data, Shape::new_2d(5, 3), &devices)?;
+
+        assert_eq!(sharded.num_shards(), devices.len().min(5));
+        let rows: usize = sharded.shards().map(|(_, rows, _)| rows.len()).sum();
+        assert_eq!(rows, 5);
+
+        let gathered = sharded.gather()?;
+        assert_eq!(gathered.dims(), &[5, 3]);
+        assert_eq!(gathered.to_vec()?, data);
+
+        assert!(ShardedROCArray::scatter(&data, Shape::new_2d(4, 3), &devices).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sharded_ops_and_reductions() -> Result<()> {
+        let devices = devices()?;
+        let a = ShardedROCArray::from_array(
+            &ROCArray::from_vec(vec![1.0f32, -2.0, 3.0, 4.0, 5.0, 6.0, 7.0])?,
+            &devices,
+        )?;
+        let b = ShardedROCArray::scatter(&[1.0f32; 7], Shape::new_1d(7), &devices)?;
+
+        assert_eq!(
+            a.add(&b)?.to_vec()?,
+            vec![2.0, -1.0, 4.0, 5.0, 6.0, 7.0, 8.0]
+        );
+        assert_eq!(
+            a.mul_scalar(2.0)?.sub(&b)?.to_vec()?,
+            vec![1.0, -5.0, 5.0, 7.0, 9.0, 11.0, 13.0]
+        );
+        assert_eq!(a.sum()?, 24.0);
+        assert_eq!(a.max()?, 7.0);
+        assert_eq!(a.min()?, -2.0);
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+// src/rocarray/snapshot.rs - Host-side snapshots of ROCArrays for serialization
+
+use crate::error::Result;
+use crate::rocarray::{ROCArray, Shape};
+
+/// Host copy of a [`ROCArray`]'s shape and elements
+///
+/// With the `serde` feature this implements `Serialize` and `Deserialize`,
+/// so device arrays can be checkpointed with any serde format by way of
+/// [`ROCArray::to_serializable`] and [`ROCArray::from_serializable`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ROCArrayData<T> {
+    /// Dimensions of the array
+    pub shape: Shape,
+    /// Elements in row-major order
+    pub data: Vec<T>,
+}
+
+impl<T> ROCArray<T>
+where
+    T: Copy + Default + 'static,
+{
+    /// Copy the array to the host as a snapshot
+    pub fn to_serializable(&self) -> Result<ROCArrayData<T>> {
+        Ok(ROCArrayData {
+            shape: self.shape.clone(),
+            data: self.to_vec()?,
+        })
+    }
+
+    /// Create an array on the device from a snapshot
+    ///
+    /// Fails if the element count does not match the shape, which can
+    /// happen with a hand-edited or truncated checkpoint.
+    pub fn from_serializable(snapshot: ROCArrayData<T>) -> Result<Self> {
+        Self::from_vec_with_shape(snapshot.data, snapshot.shape)
+    }
+}
+
+// Only the dimensions are stored; strides are recomputed on load
+#[cfg(feature = "serde")]
+impl serde::Serialize for Shape {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        self.dims().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Shape {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        Ok(Shape::new(Vec::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_round_trip() -> Result<()> {
+        let array = ROCArray::from_vec_with_shape(
+            vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0],
+            Shape::new_2d(2, 3),
+        )?;
+        let snapshot = array.to_serializable()?;
+        assert_eq!(snapshot.shape, Shape::new_2d(2, 3));
+        assert_eq!(snapshot.data, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let restored = ROCArray::from_serializable(snapshot)?;
+        assert_eq!(restored.dims(), &[2, 3]);
+        assert_eq!(restored.to_vec()?, array.to_vec()?);
+
+        let truncated = ROCArrayData {
+            shape: Shape::new_2d(2, 3),
+            data: vec![1.0f32; 5],
+        };
+        assert!(ROCArray::from_serializable(truncated).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let array = ROCArray::from_vec_with_shape(vec![1u32, 2, 3, 4], Shape::new_2d(2, 2))?;
+        let json = serde_json::to_string(&array.to_serializable()?).unwrap();
+        assert_eq!(json, r#"{"shape":[2,2],"data":[1,2,3,4]}"#);
+
+        let snapshot: ROCArrayData<u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(snapshot.shape.strides(), &[2, 1]);
+        assert_eq!(
+            ROCArray::from_serializable(snapshot)?.to_vec()?,
+            vec![1, 2, 3, 4]
+        );
+        Ok(())
+    }
+}
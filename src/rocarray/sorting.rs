@@ -1,14 +1,13 @@
 // src/rocarray/sorting.rs - Complete implementation
 use crate::error::Result;
+use crate::hip::device_modules::DeviceModules;
 use crate::hip::kernel::AsKernelArg;
 use crate::hip::memory_ext::sorting::GPUSortAllowed;
-use crate::hip::{
-    DeviceMemory, Dim3, Function, Module, Stream, calculate_grid_1d, memory_ext::MemoryExt,
-};
-use std::sync::Once;
+use crate::hip::{DeviceMemory, Dim3, Function, Stream, calculate_grid_1d, memory_ext::MemoryExt};
+use crate::kernel_args;
+use crate::rocarray::context::op_stream;
 
-static INIT_SORT: Once = Once::new();
-static mut SORT_MODULE: Option<Module> = None;
+static SORT_KERNELS: DeviceModules = DeviceModules::new(include_str!("sorting_kernels.hip"));
 
 pub trait Sortable: Copy + Default + PartialOrd + 'static {
     const TYPE_NAME: &'static str;
@@ -30,32 +29,8 @@ impl Sortable for u32 {
     const TYPE_NAME: &'static str = "uint";
 }
 
-fn init_sort_kernels() -> Result<()> {
-    INIT_SORT.call_once(|| {
-        let kernel_source = include_str!("sorting_kernels.hip");
-        match crate::hip::compile_and_load(kernel_source, &[]) {
-            Ok(module) => unsafe {
-                SORT_MODULE = Some(module);
-            },
-            Err(e) => {
-                eprintln!("Failed to load sorting kernels: {:?}", e);
-            }
-        }
-    });
-    Ok(())
-}
-
 fn get_sort_kernel_function(name: &str) -> Result<Function> {
-    init_sort_kernels()?;
-    unsafe {
-        if let Some(ref module) = SORT_MODULE {
-            Ok(module.get_function(name)?)
-        } else {
-            Err(crate::error::Error::InvalidOperation(
-                "Sort kernels not initialized".to_string(),
-            ))
-        }
-    }
+    SORT_KERNELS.function(name)
 }
 
 // Ascending sort
@@ -87,9 +62,9 @@ pub fn argsort<T>(data: &DeviceMemory<T>, indices: &DeviceMemory<u32>, len: usiz
 where
     T: Sortable,
 {
-    let stream = Stream::new()?;
+    let stream = op_stream()?;
     argsort_async(data, indices, len, &stream)?;
-    stream.synchronize()?;
+    stream.finish()?;
     Ok(())
 }
 
@@ -102,7 +77,7 @@ pub fn argsort_async<T>(
 where
     T: Sortable,
 {
-    if len <= 1 {
+    if len == 0 {
         return Ok(());
     }
 
@@ -119,6 +94,9 @@ where
     ];
 
     init_kernel.launch(grid_dim, block_dim, 0, Some(stream), &mut init_args.clone())?;
+    if len == 1 {
+        return Ok(());
+    }
 
     // Then sort indices based on data values
     let kernel_name = format!("argsort_{}", T::TYPE_NAME);
@@ -148,7 +126,7 @@ where
         return sort_ascending(data);
     }
 
-    let stream = Stream::new()?;
+    let stream = op_stream()?;
     let kernel_name = format!("partial_sort_{}", T::TYPE_NAME);
     let function = get_sort_kernel_function(&kernel_name)?;
 
@@ -170,7 +148,68 @@ where
         Some(&stream),
         &mut kernel_args.clone(),
     )?;
-    stream.synchronize()?;
+    stream.finish()?;
+    Ok(())
+}
+
+/// Sort `segments` contiguous runs of `seg_len` elements each in ascending
+/// order, moving `indices` along with the keys when given
+///
+/// Ties keep their index order, so sorting with indices from
+/// [`init_segment_indices_async`] gives a stable segmented argsort.
+pub fn sort_segments_async<T>(
+    keys: &DeviceMemory<T>,
+    indices: Option<&DeviceMemory<u32>>,
+    segments: usize,
+    seg_len: usize,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: Sortable,
+{
+    if segments == 0 || seg_len < 2 {
+        return Ok(());
+    }
+
+    let function = get_sort_kernel_function(&format!("segmented_sort_{}", T::TYPE_NAME))?;
+
+    let no_indices = DeviceMemory::<u32>::new(0)?;
+    let indices = indices.unwrap_or(&no_indices);
+    let seg_len_u32 = seg_len as u32;
+    let padded_len = seg_len.next_power_of_two() as u32;
+
+    function.launch(
+        Dim3::new_1d(segments as u32),
+        Dim3::new_1d(256),
+        0,
+        Some(stream),
+        kernel_args!(keys, indices, seg_len_u32, padded_len),
+    )?;
+    Ok(())
+}
+
+/// Fill `indices` with each element's position within its run of `seg_len`
+pub fn init_segment_indices_async(
+    indices: &DeviceMemory<u32>,
+    len: usize,
+    seg_len: usize,
+    stream: &Stream,
+) -> Result<()> {
+    if len == 0 {
+        return Ok(());
+    }
+
+    let function = get_sort_kernel_function("init_segment_indices")?;
+    let len_u32 = len as u32;
+    let seg_len_u32 = seg_len as u32;
+
+    function.launch(
+        calculate_grid_1d(len_u32, 256),
+        Dim3::new_1d(256),
+        0,
+        Some(stream),
+        kernel_args!(indices, len_u32, seg_len_u32),
+    )?;
     Ok(())
 }
 
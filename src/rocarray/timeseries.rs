@@ -0,0 +1,348 @@
+// src/rocarray/timeseries.rs - Time-series downsampling and gap filling on device
+use crate::error::Result;
+use crate::hip::device_modules::DeviceModules;
+use crate::hip::kernel::AsKernelArg;
+use crate::hip::{DeviceMemory, Dim3, Function, Stream, calculate_grid_1d};
+use crate::kernel_args;
+use crate::rocarray::ROCArray;
+use crate::rocarray::context::op_stream;
+
+static TIMESERIES_KERNELS: DeviceModules =
+    DeviceModules::new(include_str!("timeseries_kernels.hip"));
+
+// Must match LTTB_BLOCK_SIZE in timeseries_kernels.hip
+const LTTB_BLOCK_SIZE: u32 = 256;
+const FILL_CHUNK: usize = 64;
+
+/// Element types supported by the time-series kernels
+pub trait TimeSeriesOps: Copy + Default + 'static {
+    const TYPE_NAME: &'static str;
+}
+
+impl TimeSeriesOps for f32 {
+    const TYPE_NAME: &'static str = "float";
+}
+
+impl TimeSeriesOps for f64 {
+    const TYPE_NAME: &'static str = "double";
+}
+
+fn get_timeseries_kernel_function(name: &str) -> Result<Function> {
+    TIMESERIES_KERNELS.function(name)
+}
+
+fn check_series_len<T>(x: &ROCArray<T>, y: &ROCArray<T>) -> Result<usize>
+where
+    T: TimeSeriesOps,
+{
+    if x.len() != y.len() {
+        return Err(crate::error::custom_error(format!(
+            "x has {} points but y has {}",
+            x.len(),
+            y.len()
+        )));
+    }
+    if x.len() > i32::MAX as usize {
+        return Err(crate::error::custom_error(format!(
+            "Series of {} points is too long",
+            x.len()
+        )));
+    }
+    Ok(x.len())
+}
+
+// =============================================================================
+// Downsampling
+// =============================================================================
+
+/// Downsample a series to `target_points` points with Largest-Triangle-Three-Buckets
+///
+/// The first and last points are always kept. `x` must be sorted ascending.
+/// Series with at most `target_points` points are returned unchanged.
+pub fn lttb<T>(
+    x: &ROCArray<T>,
+    y: &ROCArray<T>,
+    target_points: usize,
+) -> Result<(ROCArray<T>, ROCArray<T>)>
+where
+    T: TimeSeriesOps,
+{
+    lttb_async(x, y, target_points, &*op_stream()?)
+}
+
+pub fn lttb_async<T>(
+    x: &ROCArray<T>,
+    y: &ROCArray<T>,
+    target_points: usize,
+    stream: &Stream,
+) -> Result<(ROCArray<T>, ROCArray<T>)>
+where
+    T: TimeSeriesOps,
+{
+    let n = check_series_len(x, y)?;
+
+    if target_points >= n {
+        return Ok((x.clone_array()?, y.clone_array()?));
+    }
+    if target_points < 3 {
+        return Err(crate::error::custom_error(format!(
+            "LTTB needs at least 3 target points, got {}",
+            target_points
+        )));
+    }
+
+    let n_u32 = n as u32;
+    let target_u32 = target_points as u32;
+    let indices = DeviceMemory::<u32>::new(target_points)?;
+
+    let function = get_timeseries_kernel_function(&format!("lttb_{}", T::TYPE_NAME))?;
+    function.launch(
+        Dim3::new_1d(1),
+        Dim3::new_1d(LTTB_BLOCK_SIZE),
+        0,
+        Some(stream),
+        kernel_args!(
+            x.device_memory(),
+            y.device_memory(),
+            n_u32,
+            target_u32,
+            indices
+        ),
+    )?;
+
+    let out_x = ROCArray::new_1d(target_points)?;
+    let out_y = ROCArray::new_1d(target_points)?;
+
+    let gather = get_timeseries_kernel_function(&format!("ts_gather_{}", T::TYPE_NAME))?;
+    let grid_dim = calculate_grid_1d(target_u32, 256);
+    let block_dim = Dim3::new_1d(256);
+    gather.launch(
+        grid_dim,
+        block_dim,
+        0,
+        Some(stream),
+        kernel_args!(
+            x.device_memory(),
+            indices,
+            out_x.device_memory(),
+            target_u32
+        ),
+    )?;
+    gather.launch(
+        grid_dim,
+        block_dim,
+        0,
+        Some(stream),
+        kernel_args!(
+            y.device_memory(),
+            indices,
+            out_y.device_memory(),
+            target_u32
+        ),
+    )?;
+
+    // `indices` is freed on return, so the gathers must be done
+    stream.synchronize()?;
+    Ok((out_x, out_y))
+}
+
+// =============================================================================
+// Gap filling
+// =============================================================================
+
+/// Nearest valid index before and after each chunk, or -1
+struct ChunkNeighbors {
+    prev_valid: DeviceMemory<i32>,
+    next_valid: DeviceMemory<i32>,
+    n_chunks: usize,
+}
+
+fn chunk_neighbors<T>(values: &ROCArray<T>, stream: &Stream) -> Result<ChunkNeighbors>
+where
+    T: TimeSeriesOps,
+{
+    let n = values.len();
+    let n_chunks = n.div_ceil(FILL_CHUNK);
+    let first_valid = DeviceMemory::<i32>::new(n_chunks)?;
+    let last_valid = DeviceMemory::<i32>::new(n_chunks)?;
+
+    let n_u32 = n as u32;
+    let chunk_u32 = FILL_CHUNK as u32;
+    let function = get_timeseries_kernel_function(&format!("ts_chunk_bounds_{}", T::TYPE_NAME))?;
+    function.launch(
+        calculate_grid_1d(n_chunks as u32, 256),
+        Dim3::new_1d(256),
+        0,
+        Some(stream),
+        kernel_args!(
+            values.device_memory(),
+            n_u32,
+            chunk_u32,
+            first_valid,
+            last_valid
+        ),
+    )?;
+    stream.synchronize()?;
+
+    let mut first = vec![0i32; n_chunks];
+    let mut last = vec![0i32; n_chunks];
+    first_valid.copy_to_host(&mut first)?;
+    last_valid.copy_to_host(&mut last)?;
+
+    let mut prev = vec![-1i32; n_chunks];
+    for c in 1..n_chunks {
+        prev[c] = if last[c - 1] >= 0 {
+            last[c - 1]
+        } else {
+            prev[c - 1]
+        };
+    }
+
+    let mut next = vec![-1i32; n_chunks];
+    for c in (0..n_chunks.saturating_sub(1)).rev() {
+        next[c] = if first[c + 1] >= 0 {
+            first[c + 1]
+        } else {
+            next[c + 1]
+        };
+    }
+
+    let mut prev_valid = DeviceMemory::<i32>::new(n_chunks)?;
+    let mut next_valid = DeviceMemory::<i32>::new(n_chunks)?;
+    prev_valid.copy_from_host(&prev)?;
+    next_valid.copy_from_host(&next)?;
+
+    Ok(ChunkNeighbors {
+        prev_valid,
+        next_valid,
+        n_chunks,
+    })
+}
+
+/// Fill NaN gaps with the last preceding valid value
+///
+/// Leading gaps are left as NaN.
+pub fn forward_fill<T>(values: &mut ROCArray<T>) -> Result<()>
+where
+    T: TimeSeriesOps,
+{
+    forward_fill_async(values, &*op_stream()?)
+}
+
+pub fn forward_fill_async<T>(values: &mut ROCArray<T>, stream: &Stream) -> Result<()>
+where
+    T: TimeSeriesOps,
+{
+    let n = check_series_len(values, values)?;
+    if n == 0 {
+        return Ok(());
+    }
+
+    let neighbors = chunk_neighbors(values, stream)?;
+
+    let n_u32 = n as u32;
+    let chunk_u32 = FILL_CHUNK as u32;
+    let function = get_timeseries_kernel_function(&format!("ts_forward_fill_{}", T::TYPE_NAME))?;
+    function.launch(
+        calculate_grid_1d(neighbors.n_chunks as u32, 256),
+        Dim3::new_1d(256),
+        0,
+        Some(stream),
+        kernel_args!(
+            values.device_memory(),
+            n_u32,
+            chunk_u32,
+            neighbors.prev_valid
+        ),
+    )?;
+
+    stream.synchronize()?;
+    Ok(())
+}
+
+/// Fill NaN gaps in `y` by linear interpolation over `x`
+///
+/// Each gap is interpolated between the nearest valid points on either
+/// side. Leading and trailing gaps are left as NaN.
+pub fn interpolate_linear<T>(x: &ROCArray<T>, y: &mut ROCArray<T>) -> Result<()>
+where
+    T: TimeSeriesOps,
+{
+    interpolate_linear_async(x, y, &*op_stream()?)
+}
+
+pub fn interpolate_linear_async<T>(
+    x: &ROCArray<T>,
+    y: &mut ROCArray<T>,
+    stream: &Stream,
+) -> Result<()>
+where
+    T: TimeSeriesOps,
+{
+    let n = check_series_len(x, y)?;
+    if n == 0 {
+        return Ok(());
+    }
+
+    let neighbors = chunk_neighbors(y, stream)?;
+
+    let n_u32 = n as u32;
+    let chunk_u32 = FILL_CHUNK as u32;
+    let function = get_timeseries_kernel_function(&format!("ts_linear_fill_{}", T::TYPE_NAME))?;
+    function.launch(
+        calculate_grid_1d(neighbors.n_chunks as u32, 256),
+        Dim3::new_1d(256),
+        0,
+        Some(stream),
+        kernel_args!(
+            x.device_memory(),
+            y.device_memory(),
+            n_u32,
+            chunk_u32,
+            neighbors.prev_valid,
+            neighbors.next_valid
+        ),
+    )?;
+
+    stream.synchronize()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lttb_keeps_endpoints_and_peaks() -> Result<()> {
+        let x: Vec<f64> = (0..100).map(|i| i as f64).collect();
+        let y: Vec<f64> = (0..100).map(|i| if i == 50 { 10.0 } else { 0.0 }).collect();
+        let (dx, dy) = lttb(&ROCArray::from_vec(x)?, &ROCArray::from_vec(y)?, 10)?;
+
+        let dx = dx.to_vec()?;
+        let dy = dy.to_vec()?;
+        assert_eq!(dx.len(), 10);
+        assert_eq!(dx[0], 0.0);
+        assert_eq!(dx[9], 99.0);
+        assert!(dy.contains(&10.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gap_filling() -> Result<()> {
+        let x = ROCArray::from_vec(vec![0.0f32, 1.0, 2.0, 3.0, 4.0])?;
+        let gaps = vec![f32::NAN, 1.0, f32::NAN, 3.0, f32::NAN];
+
+        let mut filled = ROCArray::from_vec(gaps.clone())?;
+        forward_fill(&mut filled)?;
+        let filled = filled.to_vec()?;
+        assert!(filled[0].is_nan());
+        assert_eq!(&filled[1..], &[1.0, 1.0, 3.0, 3.0]);
+
+        let mut interpolated = ROCArray::from_vec(gaps)?;
+        interpolate_linear(&x, &mut interpolated)?;
+        let interpolated = interpolated.to_vec()?;
+        assert!(interpolated[0].is_nan() && interpolated[4].is_nan());
+        assert_eq!(&interpolated[1..4], &[1.0, 2.0, 3.0]);
+        Ok(())
+    }
+}
@@ -0,0 +1,682 @@
+// src/rocarray/view.rs - Non-owning windows into ROCArray storage
+
+use crate::error::{Result, custom_error};
+use crate::hip::DeviceMemory;
+use crate::hip::kernel::AsKernelArg;
+use crate::hip::memory::KernelArg;
+use crate::rocarray::context::op_stream;
+use crate::rocarray::kernels::{self, NumericOps};
+use crate::rocarray::{ROCArray, Shape};
+use std::ffi::c_void;
+use std::fmt;
+
+/// Most dimensions a view can have when it is passed to a kernel
+pub(crate) const MAX_VIEW_DIMS: usize = 8;
+
+/// Position of a view inside its allocation, in elements
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct StridedLayout {
+    offset: usize,
+    dims: Vec<usize>,
+    strides: Vec<usize>,
+}
+
+impl StridedLayout {
+    /// Layout covering a whole row-major array
+    pub(crate) fn contiguous(shape: &Shape) -> Self {
+        Self {
+            offset: 0,
+            dims: shape.dims().to_vec(),
+            strides: shape.strides().to_vec(),
+        }
+    }
+
+    pub(crate) fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub(crate) fn dims(&self) -> &[usize] {
+        &self.dims
+    }
+
+    pub(crate) fn strides(&self) -> &[usize] {
+        &self.strides
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.dims.iter().product()
+    }
+
+    /// Whether the elements are packed in row-major order
+    pub(crate) fn is_contiguous(&self) -> bool {
+        let mut expected = 1;
+        for (&dim, &stride) in self.dims.iter().zip(&self.strides).rev() {
+            // The stride of a length-1 axis is never used
+            if dim != 1 && stride != expected {
+                return false;
+            }
+            expected *= dim;
+        }
+        true
+    }
+
+    /// Rows `start..end` along the first dimension
+    pub(crate) fn slice(&self, start: usize, end: usize) -> Result<Self> {
+        if self.dims.is_empty() {
+            return Err(custom_error("Cannot slice 0-dimensional array"));
+        }
+
+        let first_dim = self.dims[0];
+        if start >= first_dim || end > first_dim || start >= end {
+            return Err(custom_error("Invalid slice indices"));
+        }
+
+        self.narrow(0, start, end - start)
+    }
+
+    /// Indices `start..start + len` along `axis`
+    pub(crate) fn narrow(&self, axis: usize, start: usize, len: usize) -> Result<Self> {
+        if axis >= self.dims.len() {
+            return Err(custom_error("Axis out of bounds"));
+        }
+        if start + len > self.dims[axis] {
+            return Err(custom_error("Invalid slice indices"));
+        }
+
+        let mut dims = self.dims.clone();
+        dims[axis] = len;
+        Ok(Self {
+            offset: self.offset + start * self.strides[axis],
+            dims,
+            strides: self.strides.clone(),
+        })
+    }
+
+    /// Drop a length-1 axis
+    pub(crate) fn squeeze_axis(mut self, axis: usize) -> Self {
+        debug_assert_eq!(self.dims[axis], 1);
+        self.dims.remove(axis);
+        self.strides.remove(axis);
+        self
+    }
+
+    /// Row `index` of a 2D layout
+    pub(crate) fn row(&self, index: usize) -> Result<Self> {
+        if self.dims.len() != 2 {
+            return Err(custom_error("Row access requires 2D array"));
+        }
+        if index >= self.dims[0] {
+            return Err(custom_error("Row index out of bounds"));
+        }
+
+        Ok(Self {
+            offset: self.offset + index * self.strides[0],
+            dims: vec![self.dims[1]],
+            strides: vec![self.strides[1]],
+        })
+    }
+
+    /// Column `index` of a 2D layout
+    pub(crate) fn col(&self, index: usize) -> Result<Self> {
+        if self.dims.len() != 2 {
+            return Err(custom_error("Column access requires 2D array"));
+        }
+        if index >= self.dims[1] {
+            return Err(custom_error("Column index out of bounds"));
+        }
+
+        Ok(Self {
+            offset: self.offset + index * self.strides[1],
+            dims: vec![self.dims[0]],
+            strides: vec![self.strides[0]],
+        })
+    }
+
+    /// The same elements with new dimensions
+    pub(crate) fn reshaped(&self, new_dims: Vec<usize>) -> Result<Self> {
+        let new_size: usize = new_dims.iter().product();
+        if new_size != self.len() {
+            return Err(custom_error("New shape must have the same total size"));
+        }
+        if !self.is_contiguous() {
+            return Err(custom_error(
+                "Cannot reshape a non-contiguous view, call to_array first",
+            ));
+        }
+
+        let strides = Shape::compute_strides(&new_dims);
+        Ok(Self {
+            offset: self.offset,
+            dims: new_dims,
+            strides,
+        })
+    }
+
+    /// The same elements with axis `i` taken from axis `axes[i]`
+    pub(crate) fn permuted(&self, axes: &[usize]) -> Result<Self> {
+        let mut seen = vec![false; self.dims.len()];
+        let valid = axes.len() == self.dims.len()
+            && axes
+                .iter()
+                .all(|&axis| axis < seen.len() && !std::mem::replace(&mut seen[axis], true));
+        if !valid {
+            return Err(custom_error(format!(
+                "{:?} is not a permutation of {} axes",
+                axes,
+                self.dims.len()
+            )));
+        }
+
+        Ok(Self {
+            offset: self.offset,
+            dims: axes.iter().map(|&axis| self.dims[axis]).collect(),
+            strides: axes.iter().map(|&axis| self.strides[axis]).collect(),
+        })
+    }
+
+    /// The same elements with the axis order reversed
+    pub(crate) fn transposed(&self) -> Self {
+        Self {
+            offset: self.offset,
+            dims: self.dims.iter().rev().copied().collect(),
+            strides: self.strides.iter().rev().copied().collect(),
+        }
+    }
+
+    /// Layout reading a row-major array of `shape` as if it had been
+    /// broadcast to `target`, with zero strides along broadcast axes
+    pub(crate) fn broadcast(shape: &Shape, target: &Shape) -> Option<Self> {
+        let lead = target.ndim().checked_sub(shape.ndim())?;
+        let mut strides = vec![0; target.ndim()];
+        for (axis, &dim) in shape.dims().iter().enumerate() {
+            let target_dim = target.dims()[lead + axis];
+            if dim == target_dim {
+                strides[lead + axis] = shape.strides()[axis];
+            } else if dim != 1 {
+                return None;
+            }
+        }
+
+        Some(Self {
+            offset: 0,
+            dims: target.dims().to_vec(),
+            strides,
+        })
+    }
+
+    /// Pack the layout for the strided copy kernels
+    pub(crate) fn kernel_layout(&self) -> Result<ViewLayout> {
+        if self.dims.len() > MAX_VIEW_DIMS {
+            return Err(custom_error(format!(
+                "Views support at most {} dimensions",
+                MAX_VIEW_DIMS
+            )));
+        }
+
+        let to_u32 = |value: usize| {
+            u32::try_from(value).map_err(|_| custom_error("View is too large for u32 indexing"))
+        };
+
+        let mut layout = ViewLayout {
+            dims: [1; MAX_VIEW_DIMS],
+            strides: [0; MAX_VIEW_DIMS],
+            ndim: self.dims.len() as u32,
+            offset: to_u32(self.offset)?,
+        };
+        for (i, (&dim, &stride)) in self.dims.iter().zip(&self.strides).enumerate() {
+            layout.dims[i] = to_u32(dim)?;
+            layout.strides[i] = to_u32(stride)?;
+        }
+        Ok(layout)
+    }
+}
+
+/// Kernel-side description of a view; must match `ViewLayout` in kernels.hip
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ViewLayout {
+    dims: [u32; MAX_VIEW_DIMS],
+    strides: [u32; MAX_VIEW_DIMS],
+    ndim: u32,
+    offset: u32,
+}
+
+impl AsKernelArg for ViewLayout {
+    fn as_kernel_arg(&self) -> KernelArg {
+        self as *const ViewLayout as *mut c_void
+    }
+}
+
+/// A read-only view into the memory of a [`ROCArray`]
+///
+/// Produced by [`ROCArray::slice`], [`ROCArray::row`], [`ROCArray::col`],
+/// [`ROCArray::reshaped`], [`ROCArray::transpose`] and
+/// [`ROCArray::permute`]. No device memory is copied until the view is
+/// materialized with [`to_array`](Self::to_array) or
+/// [`to_vec`](Self::to_vec), which only strided views such as transposes
+/// need before a reshape.
+pub struct ROCArrayView<'a, T> {
+    data: &'a DeviceMemory<T>,
+    layout: StridedLayout,
+}
+
+/// A mutable view into the memory of a [`ROCArray`]
+///
+/// Writes through the view land in the parent array.
+pub struct ROCArrayViewMut<'a, T> {
+    data: &'a mut DeviceMemory<T>,
+    layout: StridedLayout,
+}
+
+impl<T> fmt::Debug for ROCArrayView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ROCArrayView")
+            .field("layout", &self.layout)
+            .field("type", &std::any::type_name::<T>())
+            .finish()
+    }
+}
+
+impl<T> fmt::Debug for ROCArrayViewMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ROCArrayViewMut")
+            .field("layout", &self.layout)
+            .field("type", &std::any::type_name::<T>())
+            .finish()
+    }
+}
+
+impl<T> Clone for ROCArrayView<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data,
+            layout: self.layout.clone(),
+        }
+    }
+}
+
+impl<'a, T> ROCArrayView<'a, T>
+where
+    T: Copy + Default + 'static,
+{
+    pub(crate) fn new(data: &'a DeviceMemory<T>, layout: StridedLayout) -> Self {
+        Self { data, layout }
+    }
+
+    /// Get the shape of the view
+    pub fn shape(&self) -> Shape {
+        Shape::new(self.layout.dims.clone())
+    }
+
+    /// Get the size of each dimension
+    pub fn dims(&self) -> &[usize] {
+        self.layout.dims()
+    }
+
+    /// Get the distance in elements between neighbours along each dimension
+    pub fn strides(&self) -> &[usize] {
+        self.layout.strides()
+    }
+
+    /// Get the element offset of the view inside the parent allocation
+    pub fn offset(&self) -> usize {
+        self.layout.offset()
+    }
+
+    /// Get the number of dimensions
+    pub fn ndim(&self) -> usize {
+        self.layout.dims.len()
+    }
+
+    /// Get the total number of elements
+    pub fn len(&self) -> usize {
+        self.layout.len()
+    }
+
+    /// Check if the view is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Check if the elements are packed in row-major order
+    pub fn is_contiguous(&self) -> bool {
+        self.layout.is_contiguous()
+    }
+
+    /// Get a device pointer to the first element
+    pub fn as_ptr(&self) -> *mut c_void {
+        unsafe {
+            self.data
+                .as_ptr()
+                .byte_add(self.layout.offset * std::mem::size_of::<T>())
+        }
+    }
+
+    /// Get the parent allocation
+    pub fn device_memory(&self) -> &'a DeviceMemory<T> {
+        self.data
+    }
+
+    /// Get a slice along the first dimension
+    pub fn slice(&self, start: usize, end: usize) -> Result<ROCArrayView<'a, T>> {
+        Ok(Self::new(self.data, self.layout.slice(start, end)?))
+    }
+
+    /// Get row (for 2D views)
+    pub fn row(&self, index: usize) -> Result<ROCArrayView<'a, T>> {
+        Ok(Self::new(self.data, self.layout.row(index)?))
+    }
+
+    /// Get column (for 2D views)
+    pub fn col(&self, index: usize) -> Result<ROCArrayView<'a, T>> {
+        Ok(Self::new(self.data, self.layout.col(index)?))
+    }
+
+    /// View the same elements with new dimensions (contiguous views only)
+    pub fn reshaped(&self, new_dims: Vec<usize>) -> Result<ROCArrayView<'a, T>> {
+        Ok(Self::new(self.data, self.layout.reshaped(new_dims)?))
+    }
+
+    /// Get indices `start..start + len` along `axis`
+    pub fn narrow(&self, axis: usize, start: usize, len: usize) -> Result<ROCArrayView<'a, T>> {
+        Ok(Self::new(self.data, self.layout.narrow(axis, start, len)?))
+    }
+
+    /// Split `axis` into `sections` equal views
+    pub fn split(&self, axis: usize, sections: usize) -> Result<Vec<ROCArrayView<'a, T>>> {
+        let dim = *self
+            .dims()
+            .get(axis)
+            .ok_or_else(|| custom_error("Axis out of bounds"))?;
+        if sections == 0 || !dim.is_multiple_of(sections) {
+            return Err(custom_error(format!(
+                "Axis of length {} cannot be split into {} equal sections",
+                dim, sections
+            )));
+        }
+
+        let len = dim / sections;
+        (0..sections)
+            .map(|i| self.narrow(axis, i * len, len))
+            .collect()
+    }
+
+    /// Split the first dimension into at most `n` views of equal length,
+    /// the last of which may be shorter
+    pub fn chunk(&self, n: usize) -> Result<Vec<ROCArrayView<'a, T>>> {
+        let dim = *self
+            .dims()
+            .first()
+            .ok_or_else(|| custom_error("Cannot chunk 0-dimensional array"))?;
+        if n == 0 {
+            return Err(custom_error("Cannot split into 0 chunks"));
+        }
+
+        let len = dim.div_ceil(n).max(1);
+        (0..dim)
+            .step_by(len)
+            .map(|start| self.narrow(0, start, len.min(dim - start)))
+            .collect()
+    }
+
+    /// Reverse the axis order by swapping strides, without copying
+    pub fn transpose(&self) -> ROCArrayView<'a, T> {
+        Self::new(self.data, self.layout.transposed())
+    }
+
+    /// Reorder the axes so that axis `i` of the result is axis `axes[i]`
+    /// of this view, without copying
+    pub fn permute(&self, axes: &[usize]) -> Result<ROCArrayView<'a, T>> {
+        Ok(Self::new(self.data, self.layout.permuted(axes)?))
+    }
+
+    /// Multiply two 2D views into a new array
+    ///
+    /// Both operands are read through their strides, so transposed views
+    /// are multiplied without being copied first.
+    pub fn matmul(&self, other: &ROCArrayView<'_, T>) -> Result<ROCArray<T>>
+    where
+        T: NumericOps,
+    {
+        if self.ndim() != 2 || other.ndim() != 2 {
+            return Err(custom_error("Matrix multiplication requires 2D arrays"));
+        }
+
+        let [m, k] = [self.dims()[0], self.dims()[1]];
+        let [k2, n] = [other.dims()[0], other.dims()[1]];
+        if k != k2 {
+            return Err(custom_error(
+                "Inner dimensions must match for matrix multiplication",
+            ));
+        }
+
+        let result = ROCArray::new(Shape::new_2d(m, n))?;
+        let stream = op_stream()?;
+        kernels::matrix_multiply_strided_async(
+            self.data,
+            &self.layout,
+            other.data,
+            &other.layout,
+            result.device_memory(),
+            &stream,
+        )?;
+        stream.finish()?;
+        Ok(result)
+    }
+
+    /// Copy the viewed elements into a new contiguous array
+    pub fn to_array(&self) -> Result<ROCArray<T>>
+    where
+        T: NumericOps,
+    {
+        let result = ROCArray::new(self.shape())?;
+        let stream = op_stream()?;
+        kernels::strided_gather_async(self.data, result.device_memory(), &self.layout, &stream)?;
+        stream.finish()?;
+        Ok(result)
+    }
+
+    /// Copy the viewed elements to the host in row-major order
+    pub fn to_vec(&self) -> Result<Vec<T>>
+    where
+        T: NumericOps,
+    {
+        self.to_array()?.to_vec()
+    }
+}
+
+impl<'a, T> ROCArrayViewMut<'a, T>
+where
+    T: Copy + Default + 'static,
+{
+    pub(crate) fn new(data: &'a mut DeviceMemory<T>, layout: StridedLayout) -> Self {
+        Self { data, layout }
+    }
+
+    /// Get the shape of the view
+    pub fn shape(&self) -> Shape {
+        Shape::new(self.layout.dims.clone())
+    }
+
+    /// Get the size of each dimension
+    pub fn dims(&self) -> &[usize] {
+        self.layout.dims()
+    }
+
+    /// Get the distance in elements between neighbours along each dimension
+    pub fn strides(&self) -> &[usize] {
+        self.layout.strides()
+    }
+
+    /// Get the total number of elements
+    pub fn len(&self) -> usize {
+        self.layout.len()
+    }
+
+    /// Check if the view is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reborrow as a read-only view
+    pub fn as_view(&self) -> ROCArrayView<'_, T> {
+        ROCArrayView::new(self.data, self.layout.clone())
+    }
+
+    /// Get a mutable slice along the first dimension
+    pub fn slice_mut(&mut self, start: usize, end: usize) -> Result<ROCArrayViewMut<'_, T>> {
+        let layout = self.layout.slice(start, end)?;
+        Ok(ROCArrayViewMut::new(self.data, layout))
+    }
+
+    /// Get a mutable row (for 2D views)
+    pub fn row_mut(&mut self, index: usize) -> Result<ROCArrayViewMut<'_, T>> {
+        let layout = self.layout.row(index)?;
+        Ok(ROCArrayViewMut::new(self.data, layout))
+    }
+
+    /// Get a mutable column (for 2D views)
+    pub fn col_mut(&mut self, index: usize) -> Result<ROCArrayViewMut<'_, T>> {
+        let layout = self.layout.col(index)?;
+        Ok(ROCArrayViewMut::new(self.data, layout))
+    }
+
+    /// Overwrite the viewed elements with a contiguous array of the same
+    /// shape
+    pub fn copy_from(&mut self, source: &ROCArray<T>) -> Result<()>
+    where
+        T: NumericOps,
+    {
+        if source.dims() != self.dims() {
+            return Err(custom_error(format!(
+                "Source shape {:?} doesn't match view shape {:?}",
+                source.dims(),
+                self.dims()
+            )));
+        }
+
+        let stream = op_stream()?;
+        kernels::strided_scatter_async(source.device_memory(), self.data, &self.layout, &stream)?;
+        stream.finish()?;
+        Ok(())
+    }
+
+    /// Overwrite the viewed elements with those of another view
+    pub fn assign(&mut self, source: &ROCArrayView<'_, T>) -> Result<()>
+    where
+        T: NumericOps,
+    {
+        self.copy_from(&source.to_array()?)
+    }
+
+    /// Copy the viewed elements into a new contiguous array
+    pub fn to_array(&self) -> Result<ROCArray<T>>
+    where
+        T: NumericOps,
+    {
+        self.as_view().to_array()
+    }
+
+    /// Copy the viewed elements to the host in row-major order
+    pub fn to_vec(&self) -> Result<Vec<T>>
+    where
+        T: NumericOps,
+    {
+        self.as_view().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout(dims: Vec<usize>) -> StridedLayout {
+        StridedLayout::contiguous(&Shape::new(dims))
+    }
+
+    #[test]
+    fn test_slice_and_row_stay_contiguous() {
+        let base = layout(vec![4, 3]);
+        let slice = base.slice(1, 3).unwrap();
+        assert_eq!((slice.offset(), slice.dims()), (3, &[2, 3][..]));
+        assert!(slice.is_contiguous());
+
+        let row = base.row(2).unwrap();
+        assert_eq!(
+            (row.offset(), row.dims(), row.strides()),
+            (6, &[3][..], &[1][..])
+        );
+        assert!(row.is_contiguous());
+    }
+
+    #[test]
+    fn test_narrow_inner_axis() {
+        let narrowed = layout(vec![4, 6]).narrow(1, 2, 3).unwrap();
+        assert_eq!((narrowed.offset(), narrowed.dims()), (2, &[4, 3][..]));
+        assert_eq!(narrowed.strides(), &[6, 1]);
+        assert!(!narrowed.is_contiguous());
+        assert!(layout(vec![4, 6]).narrow(1, 4, 3).is_err());
+    }
+
+    #[test]
+    fn test_col_is_strided() {
+        let col = layout(vec![4, 3]).col(1).unwrap();
+        assert_eq!(
+            (col.offset(), col.dims(), col.strides()),
+            (1, &[4][..], &[3][..])
+        );
+        assert!(!col.is_contiguous());
+        assert!(col.reshaped(vec![2, 2]).is_err());
+    }
+
+    #[test]
+    fn test_reshape_slice() {
+        let reshaped = layout(vec![4, 6]).slice(2, 4).unwrap().reshaped(vec![3, 4]);
+        let reshaped = reshaped.unwrap();
+        assert_eq!(reshaped.offset(), 12);
+        assert_eq!(reshaped.strides(), &[4, 1]);
+    }
+
+    #[test]
+    fn test_transpose_swaps_strides() {
+        let transposed = layout(vec![2, 3, 4]).transposed();
+        assert_eq!(transposed.dims(), &[4, 3, 2]);
+        assert_eq!(transposed.strides(), &[1, 4, 12]);
+        assert!(!transposed.is_contiguous());
+        assert!(transposed.transposed().is_contiguous());
+    }
+
+    #[test]
+    fn test_permute() {
+        let permuted = layout(vec![2, 3, 4]).permuted(&[1, 0, 2]).unwrap();
+        assert_eq!(permuted.dims(), &[3, 2, 4]);
+        assert_eq!(permuted.strides(), &[4, 12, 1]);
+
+        let base = layout(vec![2, 3, 4]);
+        assert!(base.permuted(&[0, 1]).is_err());
+        assert!(base.permuted(&[0, 1, 1]).is_err());
+        assert!(base.permuted(&[0, 1, 3]).is_err());
+    }
+
+    #[test]
+    fn test_broadcast() {
+        let target = Shape::new(vec![2, 3, 4]);
+        let row = StridedLayout::broadcast(&Shape::new(vec![4]), &target).unwrap();
+        assert_eq!(row.strides(), &[0, 0, 1]);
+
+        let column = StridedLayout::broadcast(&Shape::new(vec![3, 1]), &target).unwrap();
+        assert_eq!(column.strides(), &[0, 1, 0]);
+
+        assert!(StridedLayout::broadcast(&Shape::new(vec![2, 4]), &target).is_none());
+    }
+
+    #[test]
+    fn test_invalid_indices() {
+        let base = layout(vec![4, 3]);
+        assert!(base.slice(2, 2).is_err());
+        assert!(base.slice(0, 5).is_err());
+        assert!(base.row(4).is_err());
+        assert!(base.col(3).is_err());
+        assert!(layout(vec![12]).row(0).is_err());
+    }
+}
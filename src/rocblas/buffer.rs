@@ -0,0 +1,123 @@
+// src/rocblas/buffer.rs - Level 1 routines on whole device buffers
+
+//! Level 1 BLAS on whole [`DeviceMemory`] buffers
+//!
+//! Each buffer is treated as one contiguous vector, so `n` is its length and
+//! the strides are 1. Buffers that must be the same length are checked
+//! before anything is launched, and mismatches are reported as
+//! `rocblas_status_invalid_size`. For strided vectors or parts of a buffer
+//! use [`safe`](crate::rocblas::safe) or the pointer-based routines in
+//! [`level1`](crate::rocblas::level1).
+//!
+//! Scalars are passed and returned on the host; a handle in device pointer
+//! mode is switched to host mode for the call.
+//!
+//! ```ignore
+//! buffer::axpy(&handle, &2.0f32, &x, &mut y)?;
+//! let d = buffer::dot(&handle, &x, &y)?;
+//! ```
+
+use crate::hip::DeviceMemory;
+use crate::rocblas::error::{Error, Result};
+use crate::rocblas::ffi;
+use crate::rocblas::handle::Handle;
+use crate::rocblas::level1::{self, AxpyType, CopyType, DotType, ScalType, SwapType};
+use crate::rocblas::safe;
+use crate::rocblas::utils::PointerMode;
+use std::mem::MaybeUninit;
+
+/// Scale a buffer by a scalar
+///
+/// x := alpha * x
+pub fn scal<T>(handle: &Handle, alpha: &T, x: &mut DeviceMemory<T>) -> Result<()>
+where
+    T: ScalType,
+{
+    let n = len(x)?;
+    on_host(handle, || level1::scal(handle, n, alpha, x, 1))
+}
+
+/// Copy one buffer into another of the same length
+///
+/// y := x
+pub fn copy<T>(handle: &Handle, x: &DeviceMemory<T>, y: &mut DeviceMemory<T>) -> Result<()>
+where
+    T: CopyType,
+{
+    let n = same_len(x, y)?;
+    unsafe { level1::copy(handle, n, x.as_ptr().cast::<T>(), 1, y.as_ptr().cast(), 1) }
+}
+
+/// Interchange the contents of two buffers of the same length
+///
+/// x <-> y
+pub fn swap<T>(handle: &Handle, x: &mut DeviceMemory<T>, y: &mut DeviceMemory<T>) -> Result<()>
+where
+    T: SwapType,
+{
+    let n = same_len(x, y)?;
+    unsafe { level1::swap(handle, n, x.as_ptr().cast::<T>(), 1, y.as_ptr().cast(), 1) }
+}
+
+/// Add a scaled buffer to another of the same length
+///
+/// y := alpha * x + y
+pub fn axpy<T>(
+    handle: &Handle,
+    alpha: &T,
+    x: &DeviceMemory<T>,
+    y: &mut DeviceMemory<T>,
+) -> Result<()>
+where
+    T: AxpyType,
+{
+    let n = same_len(x, y)?;
+    on_host(handle, || unsafe {
+        level1::axpy(handle, n, alpha, x.as_ptr().cast(), 1, y.as_ptr().cast(), 1)
+    })
+}
+
+/// Dot product of two buffers of the same length
+///
+/// result := x * y
+///
+/// Waits for the result, like any call in host pointer mode.
+pub fn dot<T>(handle: &Handle, x: &DeviceMemory<T>, y: &DeviceMemory<T>) -> Result<T>
+where
+    T: DotType,
+{
+    let n = same_len(x, y)?;
+    let mut result = MaybeUninit::<T>::uninit();
+    on_host(handle, || unsafe {
+        level1::dot(
+            handle,
+            n,
+            x.as_ptr().cast(),
+            1,
+            y.as_ptr().cast(),
+            1,
+            result.as_mut_ptr(),
+        )
+    })?;
+
+    // rocBLAS writes the result before reporting success
+    Ok(unsafe { result.assume_init() })
+}
+
+fn on_host<R>(handle: &Handle, f: impl FnOnce() -> Result<R>) -> Result<R> {
+    safe::with_pointer_mode(handle, PointerMode::Host, f)
+}
+
+/// Length of a buffer as a rocBLAS element count
+fn len<T>(x: &DeviceMemory<T>) -> Result<i32> {
+    i32::try_from(x.count())
+        .map_err(|_| Error::new(ffi::rocblas_status__rocblas_status_invalid_size))
+}
+
+/// Common length of two buffers that must match
+fn same_len<T>(x: &DeviceMemory<T>, y: &DeviceMemory<T>) -> Result<i32> {
+    if x.count() != y.count() {
+        return Err(Error::new(ffi::rocblas_status__rocblas_status_invalid_size));
+    }
+    len(x)
+}
@@ -0,0 +1,48 @@
+// src/rocblas/complex.rs - Complex number interop
+
+//! Interop between rocBLAS's complex types and `num_complex`
+//!
+//! With the `num-complex` feature, [`Complex32`](num_complex::Complex32) and
+//! [`Complex64`](num_complex::Complex64) share the layout of
+//! [`rocblas_float_complex`](ffi::rocblas_float_complex) and
+//! [`rocblas_double_complex`](ffi::rocblas_double_complex). They convert
+//! into each other with `From`, and the num-complex types implement the
+//! rocBLAS type traits, so buffers such as `DeviceMemory<Complex32>` and
+//! scalars such as `&Complex32` can be passed to the generic wrappers
+//! directly.
+
+use crate::hip::kernel::AsKernelArg;
+use crate::hip::memory::KernelArg;
+use crate::rocblas::ffi;
+use std::ffi::c_void;
+
+impl AsKernelArg for ffi::rocblas_float_complex {
+    fn as_kernel_arg(&self) -> KernelArg {
+        self as *const Self as *mut c_void
+    }
+}
+
+impl AsKernelArg for ffi::rocblas_double_complex {
+    fn as_kernel_arg(&self) -> KernelArg {
+        self as *const Self as *mut c_void
+    }
+}
+
+#[cfg(feature = "num-complex")]
+mod interop {
+    use crate::rocblas::ffi;
+    use crate::rocblas::types::{DataType, ExType};
+    use num_complex::{Complex32, Complex64};
+
+    impl ExType for Complex32 {
+        const DATA_TYPE: DataType = DataType::F32Complex;
+    }
+
+    impl ExType for Complex64 {
+        const DATA_TYPE: DataType = DataType::F64Complex;
+    }
+
+    // The GEMM traits are written out by hand rather than generated
+    crate::impl_rocblas_gemm_forward!(Complex32 => ffi::rocblas_float_complex);
+    crate::impl_rocblas_gemm_forward!(Complex64 => ffi::rocblas_double_complex);
+}
@@ -1,5 +1,6 @@
 // src/rocblas/error.rs
 
+use crate::error::ErrorKind;
 use crate::rocblas::ffi;
 use std::error::Error as StdError;
 use std::fmt;
@@ -50,6 +51,22 @@ impl Error {
         self.code
     }
 
+    /// Get the class of failure this error belongs to
+    pub fn kind(&self) -> ErrorKind {
+        match self.code {
+            ffi::rocblas_status__rocblas_status_invalid_handle => ErrorKind::InvalidHandle,
+            ffi::rocblas_status__rocblas_status_not_implemented
+            | ffi::rocblas_status__rocblas_status_excluded_from_build
+            | ffi::rocblas_status__rocblas_status_arch_mismatch => ErrorKind::NotSupported,
+            ffi::rocblas_status__rocblas_status_invalid_pointer
+            | ffi::rocblas_status__rocblas_status_invalid_size
+            | ffi::rocblas_status__rocblas_status_invalid_value => ErrorKind::InvalidValue,
+            ffi::rocblas_status__rocblas_status_memory_error => ErrorKind::OutOfMemory,
+            ffi::rocblas_status__rocblas_status_internal_error => ErrorKind::Internal,
+            _ => ErrorKind::Other,
+        }
+    }
+
     /// Get the name of the error code
     pub fn name(&self) -> &'static str {
         match self.code {
@@ -1,8 +1,9 @@
+use rocm_rs::rocblas::safe::{get_matrix, set_matrix, MatrixDims};
 use rocm_rs::rocblas::scal;
 use rocm_rs::{hip::*, rocblas};
 use std::error::Error;
 
-// this example shows Matrix-Vector Multiply with rocblas
+// this example scales a matrix with rocblas
 fn main() -> std::result::Result<(), Box<dyn Error>> {
     // Initialize rocBLAS handle
     let handle = rocblas::Handle::new()?;
@@ -17,20 +18,18 @@ fn main() -> std::result::Result<(), Box<dyn Error>> {
         2.0, 5.0, // Column 1
         3.0, 6.0, // Column 2
     ];
+    let dims = MatrixDims::new(m, n);
 
-    // Device memory pointers
-    let mut d_a = DeviceMemory::<f32>::new(m * n)?;
-
-    d_a.copy_from_host(&h_a)?;
-
+    // Upload the matrix to the device
+    let mut d_a = DeviceMemory::<f32>::new((m * n) as usize)?;
+    set_matrix(&h_a, dims, &mut d_a, dims)?;
 
     let alpha: f32 = 2.0;
-    // Perform y = alpha * A
-    scal(&handle, (n*m) as i32, &alpha, &d_a, 1)?;
-    
+    // Perform A = alpha * A
+    scal(&handle, m * n, &alpha, &d_a, 1)?;
 
     // Copy result back to host
-    d_a.copy_to_host(&mut h_a)?;
+    get_matrix(&d_a, dims, &mut h_a, dims)?;
 
     println!("Result: {:?}", h_a);
 
@@ -390,14 +390,274 @@ pub use bindings::rocblas_zgbmv_strided_batched;
 pub use bindings::rocblas_chbmv;
 pub use bindings::rocblas_zhbmv;
 
+pub use bindings::rocblas_cher;
+pub use bindings::rocblas_zher;
+
+pub use bindings::rocblas_cher_batched;
+pub use bindings::rocblas_zher_batched;
+
+pub use bindings::rocblas_cher_strided_batched;
+pub use bindings::rocblas_zher_strided_batched;
+
+pub use bindings::rocblas_cher2;
+pub use bindings::rocblas_zher2;
+
+pub use bindings::rocblas_cher2_batched;
+pub use bindings::rocblas_zher2_batched;
+
+pub use bindings::rocblas_cher2_strided_batched;
+pub use bindings::rocblas_zher2_strided_batched;
+
+pub use bindings::rocblas_chpr;
+pub use bindings::rocblas_zhpr;
+
+pub use bindings::rocblas_chpr_batched;
+pub use bindings::rocblas_zhpr_batched;
+
+pub use bindings::rocblas_chpr_strided_batched;
+pub use bindings::rocblas_zhpr_strided_batched;
+
+pub use bindings::rocblas_chpr2;
+pub use bindings::rocblas_zhpr2;
+
+pub use bindings::rocblas_chpr2_batched;
+pub use bindings::rocblas_zhpr2_batched;
+
+pub use bindings::rocblas_chpr2_strided_batched;
+pub use bindings::rocblas_zhpr2_strided_batched;
+
+pub use bindings::rocblas_ctrmv;
+pub use bindings::rocblas_dtrmv;
+pub use bindings::rocblas_strmv;
+pub use bindings::rocblas_ztrmv;
+
+pub use bindings::rocblas_ctrmv_batched;
+pub use bindings::rocblas_dtrmv_batched;
+pub use bindings::rocblas_strmv_batched;
+pub use bindings::rocblas_ztrmv_batched;
+
+pub use bindings::rocblas_ctrmv_strided_batched;
+pub use bindings::rocblas_dtrmv_strided_batched;
+pub use bindings::rocblas_strmv_strided_batched;
+pub use bindings::rocblas_ztrmv_strided_batched;
+
+pub use bindings::rocblas_ctbmv;
+pub use bindings::rocblas_dtbmv;
+pub use bindings::rocblas_stbmv;
+pub use bindings::rocblas_ztbmv;
+
+pub use bindings::rocblas_ctbmv_batched;
+pub use bindings::rocblas_dtbmv_batched;
+pub use bindings::rocblas_stbmv_batched;
+pub use bindings::rocblas_ztbmv_batched;
+
+pub use bindings::rocblas_ctbmv_strided_batched;
+pub use bindings::rocblas_dtbmv_strided_batched;
+pub use bindings::rocblas_stbmv_strided_batched;
+pub use bindings::rocblas_ztbmv_strided_batched;
+
+pub use bindings::rocblas_ctpmv;
+pub use bindings::rocblas_dtpmv;
+pub use bindings::rocblas_stpmv;
+pub use bindings::rocblas_ztpmv;
+
+pub use bindings::rocblas_ctpmv_batched;
+pub use bindings::rocblas_dtpmv_batched;
+pub use bindings::rocblas_stpmv_batched;
+pub use bindings::rocblas_ztpmv_batched;
+
+pub use bindings::rocblas_ctpmv_strided_batched;
+pub use bindings::rocblas_dtpmv_strided_batched;
+pub use bindings::rocblas_stpmv_strided_batched;
+pub use bindings::rocblas_ztpmv_strided_batched;
+
+pub use bindings::rocblas_ctrsv;
+pub use bindings::rocblas_dtrsv;
+pub use bindings::rocblas_strsv;
+pub use bindings::rocblas_ztrsv;
+
+pub use bindings::rocblas_ctrsv_batched;
+pub use bindings::rocblas_dtrsv_batched;
+pub use bindings::rocblas_strsv_batched;
+pub use bindings::rocblas_ztrsv_batched;
+
+pub use bindings::rocblas_ctrsv_strided_batched;
+pub use bindings::rocblas_dtrsv_strided_batched;
+pub use bindings::rocblas_strsv_strided_batched;
+pub use bindings::rocblas_ztrsv_strided_batched;
+
+pub use bindings::rocblas_ctbsv;
+pub use bindings::rocblas_dtbsv;
+pub use bindings::rocblas_stbsv;
+pub use bindings::rocblas_ztbsv;
+
+pub use bindings::rocblas_ctbsv_batched;
+pub use bindings::rocblas_dtbsv_batched;
+pub use bindings::rocblas_stbsv_batched;
+pub use bindings::rocblas_ztbsv_batched;
+
+pub use bindings::rocblas_ctbsv_strided_batched;
+pub use bindings::rocblas_dtbsv_strided_batched;
+pub use bindings::rocblas_stbsv_strided_batched;
+pub use bindings::rocblas_ztbsv_strided_batched;
+
+pub use bindings::rocblas_ctpsv;
+pub use bindings::rocblas_dtpsv;
+pub use bindings::rocblas_stpsv;
+pub use bindings::rocblas_ztpsv;
+
+pub use bindings::rocblas_ctpsv_batched;
+pub use bindings::rocblas_dtpsv_batched;
+pub use bindings::rocblas_stpsv_batched;
+pub use bindings::rocblas_ztpsv_batched;
+
+pub use bindings::rocblas_ctpsv_strided_batched;
+pub use bindings::rocblas_dtpsv_strided_batched;
+pub use bindings::rocblas_stpsv_strided_batched;
+pub use bindings::rocblas_ztpsv_strided_batched;
+
+pub use bindings::rocblas_dspmv;
+pub use bindings::rocblas_sspmv;
+
+pub use bindings::rocblas_dspmv_batched;
+pub use bindings::rocblas_sspmv_batched;
+
+pub use bindings::rocblas_dspmv_strided_batched;
+pub use bindings::rocblas_sspmv_strided_batched;
+
+pub use bindings::rocblas_dsbmv;
+pub use bindings::rocblas_ssbmv;
+
+pub use bindings::rocblas_dsbmv_batched;
+pub use bindings::rocblas_ssbmv_batched;
+
+pub use bindings::rocblas_dsbmv_strided_batched;
+pub use bindings::rocblas_ssbmv_strided_batched;
+
+pub use bindings::rocblas_chpmv;
+pub use bindings::rocblas_zhpmv;
+
+pub use bindings::rocblas_chpmv_batched;
+pub use bindings::rocblas_zhpmv_batched;
+
+pub use bindings::rocblas_chpmv_strided_batched;
+pub use bindings::rocblas_zhpmv_strided_batched;
+
 // Level 3 BLAS
+pub use bindings::rocblas_cdgmm;
+pub use bindings::rocblas_ddgmm;
+pub use bindings::rocblas_sdgmm;
+pub use bindings::rocblas_zdgmm;
+
+pub use bindings::rocblas_cdgmm_batched;
+pub use bindings::rocblas_ddgmm_batched;
+pub use bindings::rocblas_sdgmm_batched;
+pub use bindings::rocblas_zdgmm_batched;
+
+pub use bindings::rocblas_cdgmm_strided_batched;
+pub use bindings::rocblas_ddgmm_strided_batched;
+pub use bindings::rocblas_sdgmm_strided_batched;
+pub use bindings::rocblas_zdgmm_strided_batched;
+
+pub use bindings::rocblas_cgeam;
+pub use bindings::rocblas_dgeam;
+pub use bindings::rocblas_sgeam;
+pub use bindings::rocblas_zgeam;
+
+pub use bindings::rocblas_cgeam_batched;
+pub use bindings::rocblas_dgeam_batched;
+pub use bindings::rocblas_sgeam_batched;
+pub use bindings::rocblas_zgeam_batched;
+
+pub use bindings::rocblas_cgeam_strided_batched;
+pub use bindings::rocblas_dgeam_strided_batched;
+pub use bindings::rocblas_sgeam_strided_batched;
+pub use bindings::rocblas_zgeam_strided_batched;
+
+pub use bindings::rocblas_csymm;
+pub use bindings::rocblas_dsymm;
+pub use bindings::rocblas_ssymm;
+pub use bindings::rocblas_zsymm;
+
+pub use bindings::rocblas_csymm_batched;
+pub use bindings::rocblas_dsymm_batched;
+pub use bindings::rocblas_ssymm_batched;
+pub use bindings::rocblas_zsymm_batched;
+
+pub use bindings::rocblas_csymm_strided_batched;
+pub use bindings::rocblas_dsymm_strided_batched;
+pub use bindings::rocblas_ssymm_strided_batched;
+pub use bindings::rocblas_zsymm_strided_batched;
+
+pub use bindings::rocblas_csyrk;
+pub use bindings::rocblas_dsyrk;
+pub use bindings::rocblas_ssyrk;
+pub use bindings::rocblas_zsyrk;
+
+pub use bindings::rocblas_csyrk_batched;
+pub use bindings::rocblas_dsyrk_batched;
+pub use bindings::rocblas_ssyrk_batched;
+pub use bindings::rocblas_zsyrk_batched;
+
+pub use bindings::rocblas_csyrk_strided_batched;
+pub use bindings::rocblas_dsyrk_strided_batched;
+pub use bindings::rocblas_ssyrk_strided_batched;
+pub use bindings::rocblas_zsyrk_strided_batched;
+
+pub use bindings::rocblas_csyr2k;
+pub use bindings::rocblas_dsyr2k;
+pub use bindings::rocblas_ssyr2k;
+pub use bindings::rocblas_zsyr2k;
+
+pub use bindings::rocblas_csyr2k_batched;
+pub use bindings::rocblas_dsyr2k_batched;
+pub use bindings::rocblas_ssyr2k_batched;
+pub use bindings::rocblas_zsyr2k_batched;
+
+pub use bindings::rocblas_csyr2k_strided_batched;
+pub use bindings::rocblas_dsyr2k_strided_batched;
+pub use bindings::rocblas_ssyr2k_strided_batched;
+pub use bindings::rocblas_zsyr2k_strided_batched;
+
+pub use bindings::rocblas_ctrsm;
+pub use bindings::rocblas_dtrsm;
+pub use bindings::rocblas_strsm;
+pub use bindings::rocblas_ztrsm;
+
+pub use bindings::rocblas_ctrsm_batched;
+pub use bindings::rocblas_dtrsm_batched;
+pub use bindings::rocblas_strsm_batched;
+pub use bindings::rocblas_ztrsm_batched;
+
+pub use bindings::rocblas_ctrsm_strided_batched;
+pub use bindings::rocblas_dtrsm_strided_batched;
+pub use bindings::rocblas_strsm_strided_batched;
+pub use bindings::rocblas_ztrsm_strided_batched;
+
+pub use bindings::rocblas_ctrmm;
+pub use bindings::rocblas_dtrmm;
+pub use bindings::rocblas_strmm;
+pub use bindings::rocblas_ztrmm;
+
+pub use bindings::rocblas_ctrmm_batched;
+pub use bindings::rocblas_dtrmm_batched;
+pub use bindings::rocblas_strmm_batched;
+pub use bindings::rocblas_ztrmm_batched;
+
+pub use bindings::rocblas_ctrmm_strided_batched;
+pub use bindings::rocblas_dtrmm_strided_batched;
+pub use bindings::rocblas_strmm_strided_batched;
+pub use bindings::rocblas_ztrmm_strided_batched;
+
 pub use bindings::rocblas_cgemm;
 pub use bindings::rocblas_dgemm;
+pub use bindings::rocblas_hgemm;
 pub use bindings::rocblas_sgemm;
 pub use bindings::rocblas_zgemm;
 
 pub use bindings::rocblas_cgemm_batched;
 pub use bindings::rocblas_dgemm_batched;
+pub use bindings::rocblas_hgemm_batched;
 pub use bindings::rocblas_sgemm_batched;
 pub use bindings::rocblas_zgemm_batched;
 
@@ -431,6 +691,7 @@ pub use bindings::rocblas_csyr2;
 pub use bindings::rocblas_csyr2_batched;
 pub use bindings::rocblas_csyr2_strided_batched;
 pub use bindings::rocblas_dgemm_strided_batched;
+pub use bindings::rocblas_hgemm_strided_batched;
 pub use bindings::rocblas_dger;
 pub use bindings::rocblas_dger_batched;
 pub use bindings::rocblas_dger_strided_batched;
@@ -492,7 +753,11 @@ pub use bindings::rocblas_zsyr2_strided_batched;
 pub use bindings::hipStream_t;
 pub use bindings::rocblas_abort;
 pub use bindings::rocblas_device_malloc_set_default_memory_size;
+pub use bindings::rocblas_axpy_ex;
+pub use bindings::rocblas_dot_ex;
 pub use bindings::rocblas_gemm_ex;
+pub use bindings::rocblas_nrm2_ex;
+pub use bindings::rocblas_scal_ex;
 pub use bindings::rocblas_get_device_memory_size;
 pub use bindings::rocblas_get_version_string;
 pub use bindings::rocblas_get_version_string_size;
@@ -506,12 +771,83 @@ pub use bindings::rocblas_start_device_memory_size_query;
 pub use bindings::rocblas_status_to_string;
 pub use bindings::rocblas_stop_device_memory_size_query;
 
+pub use bindings::rocblas_get_matrix;
 pub use bindings::rocblas_get_matrix_async;
 pub use bindings::rocblas_get_matrix_async_64;
+pub use bindings::rocblas_get_vector;
 pub use bindings::rocblas_get_vector_async;
 pub use bindings::rocblas_get_vector_async_64;
+pub use bindings::rocblas_set_matrix;
 pub use bindings::rocblas_set_matrix_async;
 pub use bindings::rocblas_set_matrix_async_64;
 pub use bindings::rocblas_set_start_stop_events;
+pub use bindings::rocblas_set_vector;
 pub use bindings::rocblas_set_vector_async;
 pub use bindings::rocblas_set_vector_async_64;
+
+// ILP64 (64-bit integer) API
+pub use bindings::rocblas_caxpy_64;
+pub use bindings::rocblas_cdgmm_64;
+pub use bindings::rocblas_cgeam_64;
+pub use bindings::rocblas_cgemm_64;
+pub use bindings::rocblas_cgemv_64;
+pub use bindings::rocblas_cgerc_64;
+pub use bindings::rocblas_cgeru_64;
+pub use bindings::rocblas_chemv_64;
+pub use bindings::rocblas_daxpy_64;
+pub use bindings::rocblas_ddgmm_64;
+pub use bindings::rocblas_dgeam_64;
+pub use bindings::rocblas_dgemm_64;
+pub use bindings::rocblas_dgemv_64;
+pub use bindings::rocblas_dger_64;
+pub use bindings::rocblas_get_matrix_64;
+pub use bindings::rocblas_get_vector_64;
+pub use bindings::rocblas_hgemm_64;
+pub use bindings::rocblas_saxpy_64;
+pub use bindings::rocblas_sdgmm_64;
+pub use bindings::rocblas_set_matrix_64;
+pub use bindings::rocblas_set_vector_64;
+pub use bindings::rocblas_sgeam_64;
+pub use bindings::rocblas_sgemm_64;
+pub use bindings::rocblas_sgemv_64;
+pub use bindings::rocblas_sger_64;
+pub use bindings::rocblas_zaxpy_64;
+pub use bindings::rocblas_zdgmm_64;
+pub use bindings::rocblas_zgeam_64;
+pub use bindings::rocblas_zgemm_64;
+pub use bindings::rocblas_zgemv_64;
+pub use bindings::rocblas_zgerc_64;
+pub use bindings::rocblas_zgeru_64;
+pub use bindings::rocblas_zhemv_64;
+
+// Solution queries are a beta API behind ROCBLAS_BETA_FEATURES_API, which
+// the bindings don't cover
+unsafe extern "C" {
+    pub fn rocblas_gemm_ex_get_solutions(
+        handle: rocblas_handle,
+        transA: rocblas_operation,
+        transB: rocblas_operation,
+        m: i32,
+        n: i32,
+        k: i32,
+        alpha: *const ::std::os::raw::c_void,
+        a: *const ::std::os::raw::c_void,
+        a_type: rocblas_datatype,
+        lda: i32,
+        b: *const ::std::os::raw::c_void,
+        b_type: rocblas_datatype,
+        ldb: i32,
+        beta: *const ::std::os::raw::c_void,
+        c: *const ::std::os::raw::c_void,
+        c_type: rocblas_datatype,
+        ldc: i32,
+        d: *mut ::std::os::raw::c_void,
+        d_type: rocblas_datatype,
+        ldd: i32,
+        compute_type: rocblas_datatype,
+        algo: rocblas_gemm_algo,
+        flags: u32,
+        list_array: *mut i32,
+        list_size: *mut i32,
+    ) -> rocblas_status;
+}
@@ -0,0 +1,85 @@
+// src/rocblas/half.rs - Half-precision interop
+
+//! Interop between [`rocblas_half`](ffi::rocblas_half) and `half::f16`
+//!
+//! With the `half` feature, `f16` converts to and from `rocblas_half` with
+//! `From`, host slices and device buffers can be reinterpreted between the
+//! two without copying, and `f16` implements the rocBLAS type traits that
+//! `rocblas_half` does, including GEMM (`rocblas_hgemm`).
+
+use crate::hip::kernel::AsKernelArg;
+use crate::hip::memory::KernelArg;
+use crate::rocblas::ffi;
+use std::ffi::c_void;
+
+impl AsKernelArg for ffi::rocblas_half {
+    fn as_kernel_arg(&self) -> KernelArg {
+        self as *const Self as *mut c_void
+    }
+}
+
+#[cfg(feature = "half")]
+pub use interop::*;
+
+#[cfg(feature = "half")]
+mod interop {
+    use crate::hip::DeviceMemory;
+    use crate::rocblas::ffi;
+    use crate::rocblas::types::{DataType, ExType};
+    use half::f16;
+    use std::slice;
+
+    impl From<f16> for ffi::rocblas_half {
+        fn from(value: f16) -> Self {
+            Self {
+                data: value.to_bits(),
+            }
+        }
+    }
+
+    impl From<ffi::rocblas_half> for f16 {
+        fn from(value: ffi::rocblas_half) -> Self {
+            f16::from_bits(value.data)
+        }
+    }
+
+    impl ExType for f16 {
+        const DATA_TYPE: DataType = DataType::F16Real;
+    }
+
+    // Both types are a single u16 holding the IEEE binary16 bits, so the
+    // reinterpretations below only change the element type
+
+    /// View `f16` values as `rocblas_half`
+    pub fn as_rocblas_half(values: &[f16]) -> &[ffi::rocblas_half] {
+        unsafe { slice::from_raw_parts(values.as_ptr().cast(), values.len()) }
+    }
+
+    /// View `f16` values as mutable `rocblas_half`
+    pub fn as_rocblas_half_mut(values: &mut [f16]) -> &mut [ffi::rocblas_half] {
+        unsafe { slice::from_raw_parts_mut(values.as_mut_ptr().cast(), values.len()) }
+    }
+
+    /// View `rocblas_half` values as `f16`
+    pub fn as_f16(values: &[ffi::rocblas_half]) -> &[f16] {
+        unsafe { slice::from_raw_parts(values.as_ptr().cast(), values.len()) }
+    }
+
+    /// View `rocblas_half` values as mutable `f16`
+    pub fn as_f16_mut(values: &mut [ffi::rocblas_half]) -> &mut [f16] {
+        unsafe { slice::from_raw_parts_mut(values.as_mut_ptr().cast(), values.len()) }
+    }
+
+    /// Reinterpret a device buffer of `f16` as `rocblas_half` without copying
+    pub fn into_rocblas_half(memory: DeviceMemory<f16>) -> DeviceMemory<ffi::rocblas_half> {
+        unsafe { memory.cast() }
+    }
+
+    /// Reinterpret a device buffer of `rocblas_half` as `f16` without copying
+    pub fn into_f16(memory: DeviceMemory<ffi::rocblas_half>) -> DeviceMemory<f16> {
+        unsafe { memory.cast() }
+    }
+
+    // The GEMM traits are written out by hand rather than generated
+    crate::impl_rocblas_gemm_forward!(f16 => ffi::rocblas_half);
+}
@@ -1,9 +1,15 @@
 // src/rocblas/handle.rs
 
-use crate::hip::Stream;
+use crate::hip::{Device, DeviceMemory, Stream};
 use crate::rocblas::error::{Error, Result};
 use crate::rocblas::ffi;
+use crate::rocblas::utils::LogConfig;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::env;
+use std::ops::Deref;
 use std::ptr;
+use std::rc::Rc;
 
 /// Safe wrapper for RocBLAS handle
 pub struct Handle {
@@ -23,6 +29,35 @@ impl Handle {
         Ok(Self { handle })
     }
 
+    /// Create a new RocBLAS handle that logs according to `config`
+    ///
+    /// rocBLAS has no API for its logging settings and only reads them from
+    /// the environment when a handle is created, so they are set for the
+    /// duration of the call and restored afterwards; other handles are
+    /// unaffected. Log files are opened once per process, so the paths of
+    /// the first handle created with logging enabled apply to all later
+    /// ones.
+    ///
+    /// # Safety
+    ///
+    /// This modifies the process environment. No other thread may read or
+    /// write the environment while it runs, including through `getenv` in
+    /// C code such as the HIP and rocBLAS runtimes. Calling it before any
+    /// other threads are started satisfies this.
+    pub unsafe fn with_logging(config: &LogConfig) -> Result<Self> {
+        let vars = config.env_vars();
+        let saved: Vec<_> = vars
+            .iter()
+            .map(|(key, _)| (*key, env::var_os(key)))
+            .collect();
+
+        // SAFETY: the caller guarantees no concurrent environment access
+        unsafe { set_env(&vars) };
+        let handle = Self::new();
+        unsafe { set_env(&saved) };
+        handle
+    }
+
     /// Set the stream for this handle
     pub fn set_stream(&self, stream: &Stream) -> Result<()> {
         // Use a type cast to convert between the two hipStream_t types
@@ -147,12 +182,147 @@ impl Handle {
         Ok(mode)
     }
 
+    /// Run rocBLAS calls on a caller-provided device workspace
+    ///
+    /// Until the returned [`Workspace`] is dropped, calls made through it
+    /// use `workspace` for their temporary storage instead of memory the
+    /// handle allocates itself, so the VRAM used by many handles stays
+    /// bounded and predictable. Calls that need more than `workspace`
+    /// provides fail with `rocblas_status_memory_error`; use
+    /// [`query_workspace_size`](Self::query_workspace_size) to size it.
+    ///
+    /// Dropping the guard hands memory management back to rocBLAS.
+    pub fn with_workspace<'a>(&'a self, workspace: &'a DeviceMemory<u8>) -> Result<Workspace<'a>> {
+        let error = unsafe {
+            ffi::rocblas_set_workspace(self.handle, workspace.as_ptr(), workspace.size())
+        };
+
+        if error != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(error));
+        }
+
+        Ok(Workspace { handle: self })
+    }
+
+    /// Workspace size in bytes the rocBLAS calls in `f` need
+    ///
+    /// The calls made in `f` only record their memory requirements and do
+    /// not run, so their output buffers are left untouched.
+    pub fn query_workspace_size(&self, f: impl FnOnce(&Handle) -> Result<()>) -> Result<usize> {
+        let error = unsafe { ffi::rocblas_start_device_memory_size_query(self.handle) };
+
+        if error != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(error));
+        }
+
+        // Always end the query, even if one of the calls failed
+        let result = f(self);
+        let mut size = 0;
+        let error = unsafe { ffi::rocblas_stop_device_memory_size_query(self.handle, &mut size) };
+        result?;
+
+        if error != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(error));
+        }
+
+        Ok(size)
+    }
+
+    /// Size in bytes of the device memory currently available to the handle
+    pub fn device_memory_size(&self) -> Result<usize> {
+        let mut size = 0;
+        let error = unsafe { ffi::rocblas_get_device_memory_size(self.handle, &mut size) };
+
+        if error != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(error));
+        }
+
+        Ok(size)
+    }
+
     /// Get the raw handle
     pub fn as_raw(&self) -> ffi::rocblas_handle {
         self.handle
     }
 }
 
+/// Run `f` with the calling thread's handle for the current device
+///
+/// The handle is created on first use and kept for later calls on the same
+/// thread and device, so code that only occasionally issues BLAS calls does
+/// not need a [`Handle`] passed in or pay for creating one each time.
+/// Settings changed through it, such as its stream, persist for later
+/// callers on the thread. Calls may nest.
+///
+/// ```ignore
+/// rocblas::with_handle(|handle| Ok(safe::axpy(handle, &2.0, &x, dims, &mut y, dims)?))?;
+/// ```
+pub fn with_handle<R>(
+    f: impl FnOnce(&Handle) -> crate::error::Result<R>,
+) -> crate::error::Result<R> {
+    let device = Device::current()?.id();
+    let handle = HANDLES.with(|handles| -> Result<Rc<Handle>> {
+        let mut handles = handles.borrow_mut();
+        if let Some(handle) = handles.get(&device) {
+            return Ok(handle.clone());
+        }
+        let handle = Rc::new(Handle::new()?);
+        handles.insert(device, handle.clone());
+        Ok(handle)
+    })?;
+
+    // The pool is not borrowed while `f` runs, so `f` can call back in
+    f(&handle)
+}
+
+/// Destroy the handles [`with_handle`] created on the calling thread
+pub fn clear_handle_pool() {
+    HANDLES.with(|handles| handles.borrow_mut().clear());
+}
+
+thread_local! {
+    // Handles must not be used from several threads at once, so each
+    // thread keeps its own, one per device
+    static HANDLES: RefCell<HashMap<i32, Rc<Handle>>> = RefCell::new(HashMap::new());
+}
+
+// Callers must ensure no other thread accesses the environment meanwhile
+unsafe fn set_env(vars: &[(&str, Option<std::ffi::OsString>)]) {
+    for (key, value) in vars {
+        unsafe {
+            match value {
+                Some(value) => env::set_var(key, value),
+                None => env::remove_var(key),
+            }
+        }
+    }
+}
+
+/// A handle borrowed together with a user-provided device workspace
+///
+/// Created by [`Handle::with_workspace`]. Dereferences to the [`Handle`],
+/// so it can be passed anywhere a `&Handle` is expected.
+pub struct Workspace<'a> {
+    handle: &'a Handle,
+}
+
+impl Deref for Workspace<'_> {
+    type Target = Handle;
+
+    fn deref(&self) -> &Handle {
+        self.handle
+    }
+}
+
+impl Drop for Workspace<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            // A null workspace lets rocBLAS allocate its own memory again
+            let _ = ffi::rocblas_set_workspace(self.handle.handle, ptr::null_mut(), 0);
+        }
+    }
+}
+
 impl Drop for Handle {
     fn drop(&mut self) {
         if !self.handle.is_null() {
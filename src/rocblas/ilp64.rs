@@ -0,0 +1,924 @@
+// src/rocblas/ilp64.rs - Buffer-checked wrappers over the 64-bit integer API
+
+//! The [`safe`](crate::rocblas::safe) wrappers with 64-bit dimensions
+//!
+//! rocBLAS's ILP64 entry points (the `*_64` functions) take `i64` sizes,
+//! strides and leading dimensions, for matrices and vectors with more than
+//! `i32::MAX` rows, columns or elements. This module mirrors `safe` with the
+//! same function names, so switching is a matter of importing
+//! `rocblas::ilp64` instead; its [`MatrixDims`] and [`VectorDims`] hold
+//! `i64`s. Arguments are checked the same way.
+//!
+//! Requires the `ilp64` feature and a rocBLAS built with ILP64 support.
+
+use crate::hip::memory::PendingCopy;
+use crate::hip::{DeviceMemory, Stream};
+use crate::rocblas::bindings::_rocblas_handle;
+use crate::rocblas::error::{Error, Result};
+use crate::rocblas::ffi;
+use crate::rocblas::handle::Handle;
+use crate::rocblas::safe::with_pointer_mode;
+use crate::rocblas::types::{Fill, Operation, Side};
+use crate::rocblas::utils::PointerMode;
+use crate::{impl_rocblas_func, impl_rocblas_func_inner, impl_rocblas_traits};
+
+/// Layout of a column-major matrix stored in a buffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatrixDims {
+    /// Number of rows
+    pub rows: i64,
+    /// Number of columns
+    pub cols: i64,
+    /// Leading dimension: distance between the starts of adjacent columns
+    pub ld: i64,
+}
+
+impl MatrixDims {
+    /// A densely packed `rows x cols` matrix (`ld == rows`)
+    pub fn new(rows: i64, cols: i64) -> Self {
+        Self {
+            rows,
+            cols,
+            ld: rows.max(1),
+        }
+    }
+
+    /// A `rows x cols` matrix with an explicit leading dimension
+    pub fn with_ld(rows: i64, cols: i64, ld: i64) -> Self {
+        Self { rows, cols, ld }
+    }
+
+    /// Rows and columns after applying `op`
+    fn op_shape(&self, op: Operation) -> (i64, i64) {
+        match op {
+            Operation::None => (self.rows, self.cols),
+            Operation::Transpose | Operation::ConjugateTranspose => (self.cols, self.rows),
+        }
+    }
+
+    /// Number of elements the layout spans
+    fn span(&self) -> usize {
+        if self.rows == 0 || self.cols == 0 {
+            0
+        } else {
+            self.ld as usize * (self.cols as usize - 1) + self.rows as usize
+        }
+    }
+
+    fn check<T>(&self, buffer: &DeviceMemory<T>) -> Result<()> {
+        self.check_len(buffer.count())
+    }
+
+    fn check_len(&self, len: usize) -> Result<()> {
+        if self.rows < 0 || self.cols < 0 || self.ld < self.rows.max(1) {
+            return Err(invalid_size());
+        }
+        ensure(self.span() <= len)
+    }
+}
+
+/// Layout of a vector stored in a buffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VectorDims {
+    /// Number of elements
+    pub len: i64,
+    /// Stride between consecutive elements; negative strides walk backwards
+    pub inc: i64,
+}
+
+impl VectorDims {
+    /// A contiguous vector (`inc == 1`)
+    pub fn new(len: i64) -> Self {
+        Self { len, inc: 1 }
+    }
+
+    /// A vector with an explicit stride
+    pub fn strided(len: i64, inc: i64) -> Self {
+        Self { len, inc }
+    }
+
+    /// Number of elements the layout spans
+    fn span(&self) -> usize {
+        if self.len == 0 {
+            0
+        } else {
+            (self.len as usize - 1) * self.inc.unsigned_abs() as usize + 1
+        }
+    }
+
+    fn check<T>(&self, buffer: &DeviceMemory<T>) -> Result<()> {
+        self.check_len(buffer.count())
+    }
+
+    fn check_len(&self, len: usize) -> Result<()> {
+        if self.len < 0 || self.inc == 0 {
+            return Err(invalid_size());
+        }
+        ensure(self.span() <= len)
+    }
+}
+
+fn invalid_size() -> Error {
+    Error::new(ffi::rocblas_status__rocblas_status_invalid_size)
+}
+
+fn ensure(condition: bool) -> Result<()> {
+    if condition {
+        Ok(())
+    } else {
+        Err(invalid_size())
+    }
+}
+
+//==============================================================================
+// Level 1
+//==============================================================================
+
+/// Add a scaled vector to another
+///
+/// y := alpha * x + y
+pub fn axpy<T>(
+    handle: &Handle,
+    alpha: &T,
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    y: &mut DeviceMemory<T>,
+    y_dims: VectorDims,
+) -> Result<()>
+where
+    T: Axpy64Type,
+{
+    x_dims.check(x)?;
+    y_dims.check(y)?;
+    ensure(x_dims.len == y_dims.len)?;
+
+    with_pointer_mode(handle, PointerMode::Host, || unsafe {
+        T::rocblas_axpy_64(
+            handle,
+            x_dims.len,
+            alpha,
+            x.as_ptr().cast(),
+            x_dims.inc,
+            y.as_ptr().cast(),
+            y_dims.inc,
+        )
+    })
+}
+
+//==============================================================================
+// Level 2
+//==============================================================================
+
+/// General matrix-vector multiplication
+///
+/// y := alpha * op(A) * x + beta * y
+///
+/// `x` must have as many elements as op(A) has columns and `y` as many as
+/// op(A) has rows.
+#[allow(clippy::too_many_arguments)]
+pub fn gemv<T>(
+    handle: &Handle,
+    trans: Operation,
+    alpha: &T,
+    a: &DeviceMemory<T>,
+    a_dims: MatrixDims,
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    beta: &T,
+    y: &mut DeviceMemory<T>,
+    y_dims: VectorDims,
+) -> Result<()>
+where
+    T: Gemv64Type,
+{
+    a_dims.check(a)?;
+    x_dims.check(x)?;
+    y_dims.check(y)?;
+    let (rows, cols) = a_dims.op_shape(trans);
+    ensure(x_dims.len == cols && y_dims.len == rows)?;
+
+    with_pointer_mode(handle, PointerMode::Host, || unsafe {
+        T::rocblas_gemv_64(
+            handle,
+            trans,
+            a_dims.rows,
+            a_dims.cols,
+            alpha,
+            a.as_ptr().cast(),
+            a_dims.ld,
+            x.as_ptr().cast(),
+            x_dims.inc,
+            beta,
+            y.as_ptr().cast(),
+            y_dims.inc,
+        )
+    })
+}
+
+/// Hermitian matrix-vector multiplication
+///
+/// y := alpha * A * x + beta * y
+///
+/// Only the `uplo` triangle of the square matrix A is read.
+#[allow(clippy::too_many_arguments)]
+pub fn hemv<T>(
+    handle: &Handle,
+    uplo: Fill,
+    alpha: &T,
+    a: &DeviceMemory<T>,
+    a_dims: MatrixDims,
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    beta: &T,
+    y: &mut DeviceMemory<T>,
+    y_dims: VectorDims,
+) -> Result<()>
+where
+    T: Hemv64Type,
+{
+    a_dims.check(a)?;
+    x_dims.check(x)?;
+    y_dims.check(y)?;
+    let n = a_dims.rows;
+    ensure(a_dims.cols == n && x_dims.len == n && y_dims.len == n)?;
+
+    with_pointer_mode(handle, PointerMode::Host, || unsafe {
+        T::rocblas_hemv_64(
+            handle,
+            uplo,
+            n,
+            alpha,
+            a.as_ptr().cast(),
+            a_dims.ld,
+            x.as_ptr().cast(),
+            x_dims.inc,
+            beta,
+            y.as_ptr().cast(),
+            y_dims.inc,
+        )
+    })
+}
+
+/// General rank-1 update
+///
+/// A := alpha * x * y^T + A
+///
+/// `x` must have as many elements as A has rows and `y` as many as A has
+/// columns.
+#[allow(clippy::too_many_arguments)]
+pub fn ger<T>(
+    handle: &Handle,
+    alpha: &T,
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    y: &DeviceMemory<T>,
+    y_dims: VectorDims,
+    a: &mut DeviceMemory<T>,
+    a_dims: MatrixDims,
+) -> Result<()>
+where
+    T: Ger64Type,
+{
+    check_rank1(x, x_dims, y, y_dims, a, a_dims)?;
+    with_pointer_mode(handle, PointerMode::Host, || unsafe {
+        T::rocblas_ger_64(
+            handle,
+            a_dims.rows,
+            a_dims.cols,
+            alpha,
+            x.as_ptr().cast(),
+            x_dims.inc,
+            y.as_ptr().cast(),
+            y_dims.inc,
+            a.as_ptr().cast(),
+            a_dims.ld,
+        )
+    })
+}
+
+/// General rank-1 update of a complex matrix, without conjugation
+///
+/// A := alpha * x * y^T + A
+#[allow(clippy::too_many_arguments)]
+pub fn geru<T>(
+    handle: &Handle,
+    alpha: &T,
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    y: &DeviceMemory<T>,
+    y_dims: VectorDims,
+    a: &mut DeviceMemory<T>,
+    a_dims: MatrixDims,
+) -> Result<()>
+where
+    T: Geru64Type,
+{
+    check_rank1(x, x_dims, y, y_dims, a, a_dims)?;
+    with_pointer_mode(handle, PointerMode::Host, || unsafe {
+        T::rocblas_geru_64(
+            handle,
+            a_dims.rows,
+            a_dims.cols,
+            alpha,
+            x.as_ptr().cast(),
+            x_dims.inc,
+            y.as_ptr().cast(),
+            y_dims.inc,
+            a.as_ptr().cast(),
+            a_dims.ld,
+        )
+    })
+}
+
+/// General rank-1 update of a complex matrix, conjugating y
+///
+/// A := alpha * x * y^H + A
+#[allow(clippy::too_many_arguments)]
+pub fn gerc<T>(
+    handle: &Handle,
+    alpha: &T,
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    y: &DeviceMemory<T>,
+    y_dims: VectorDims,
+    a: &mut DeviceMemory<T>,
+    a_dims: MatrixDims,
+) -> Result<()>
+where
+    T: Gerc64Type,
+{
+    check_rank1(x, x_dims, y, y_dims, a, a_dims)?;
+    with_pointer_mode(handle, PointerMode::Host, || unsafe {
+        T::rocblas_gerc_64(
+            handle,
+            a_dims.rows,
+            a_dims.cols,
+            alpha,
+            x.as_ptr().cast(),
+            x_dims.inc,
+            y.as_ptr().cast(),
+            y_dims.inc,
+            a.as_ptr().cast(),
+            a_dims.ld,
+        )
+    })
+}
+
+fn check_rank1<T>(
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    y: &DeviceMemory<T>,
+    y_dims: VectorDims,
+    a: &DeviceMemory<T>,
+    a_dims: MatrixDims,
+) -> Result<()> {
+    a_dims.check(a)?;
+    x_dims.check(x)?;
+    y_dims.check(y)?;
+    ensure(x_dims.len == a_dims.rows && y_dims.len == a_dims.cols)
+}
+
+//==============================================================================
+// Level 3
+//==============================================================================
+
+/// General matrix-matrix multiplication
+///
+/// C := alpha * op(A) * op(B) + beta * C
+///
+/// op(A) must be `m x k`, op(B) `k x n` and C `m x n`; `m`, `n` and `k` are
+/// taken from the dimension structs.
+#[allow(clippy::too_many_arguments)]
+pub fn gemm<T>(
+    handle: &Handle,
+    transa: Operation,
+    transb: Operation,
+    alpha: &T,
+    a: &DeviceMemory<T>,
+    a_dims: MatrixDims,
+    b: &DeviceMemory<T>,
+    b_dims: MatrixDims,
+    beta: &T,
+    c: &mut DeviceMemory<T>,
+    c_dims: MatrixDims,
+) -> Result<()>
+where
+    T: Gemm64Type,
+{
+    let (m, n, k) = check_gemm(transa, transb, a, a_dims, b, b_dims, c, c_dims)?;
+
+    with_pointer_mode(handle, PointerMode::Host, || unsafe {
+        T::rocblas_gemm_64(
+            handle,
+            transa,
+            transb,
+            m,
+            n,
+            k,
+            alpha,
+            a.as_ptr().cast(),
+            a_dims.ld,
+            b.as_ptr().cast(),
+            b_dims.ld,
+            beta,
+            c.as_ptr().cast(),
+            c_dims.ld,
+        )
+    })
+}
+
+/// Check the operands of a GEMM and return its `(m, n, k)`
+#[allow(clippy::too_many_arguments)]
+fn check_gemm<T>(
+    transa: Operation,
+    transb: Operation,
+    a: &DeviceMemory<T>,
+    a_dims: MatrixDims,
+    b: &DeviceMemory<T>,
+    b_dims: MatrixDims,
+    c: &DeviceMemory<T>,
+    c_dims: MatrixDims,
+) -> Result<(i64, i64, i64)> {
+    a_dims.check(a)?;
+    b_dims.check(b)?;
+    c_dims.check(c)?;
+    let (m, k) = a_dims.op_shape(transa);
+    let (b_rows, n) = b_dims.op_shape(transb);
+    ensure(b_rows == k && c_dims.rows == m && c_dims.cols == n)?;
+    Ok((m, n, k))
+}
+
+/// General matrix-matrix addition and transposition
+///
+/// C := alpha * op(A) + beta * op(B)
+///
+/// op(A), op(B) and C must all have the same shape. With `beta` zero this
+/// is an out-of-place transpose of A; `b` is still checked, so pass A again.
+#[allow(clippy::too_many_arguments)]
+pub fn geam<T>(
+    handle: &Handle,
+    transa: Operation,
+    transb: Operation,
+    alpha: &T,
+    a: &DeviceMemory<T>,
+    a_dims: MatrixDims,
+    beta: &T,
+    b: &DeviceMemory<T>,
+    b_dims: MatrixDims,
+    c: &mut DeviceMemory<T>,
+    c_dims: MatrixDims,
+) -> Result<()>
+where
+    T: Geam64Type,
+{
+    a_dims.check(a)?;
+    b_dims.check(b)?;
+    c_dims.check(c)?;
+    let (m, n) = (c_dims.rows, c_dims.cols);
+    ensure(a_dims.op_shape(transa) == (m, n) && b_dims.op_shape(transb) == (m, n))?;
+
+    with_pointer_mode(handle, PointerMode::Host, || unsafe {
+        T::rocblas_geam_64(
+            handle,
+            transa,
+            transb,
+            m,
+            n,
+            alpha,
+            a.as_ptr().cast(),
+            a_dims.ld,
+            beta,
+            b.as_ptr().cast(),
+            b_dims.ld,
+            c.as_ptr().cast(),
+            c_dims.ld,
+        )
+    })
+}
+
+/// Multiplication by a diagonal matrix
+///
+/// C := diag(x) * A   (side = Left)
+/// C := A * diag(x)   (side = Right)
+///
+/// A and C must have the same shape, and `x` must have one element per row
+/// of A for `Side::Left` or per column for `Side::Right`.
+#[allow(clippy::too_many_arguments)]
+pub fn dgmm<T>(
+    handle: &Handle,
+    side: Side,
+    a: &DeviceMemory<T>,
+    a_dims: MatrixDims,
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    c: &mut DeviceMemory<T>,
+    c_dims: MatrixDims,
+) -> Result<()>
+where
+    T: Dgmm64Type,
+{
+    a_dims.check(a)?;
+    x_dims.check(x)?;
+    c_dims.check(c)?;
+    let diagonal_len = match side {
+        Side::Left => a_dims.rows,
+        Side::Right => a_dims.cols,
+        Side::Both => {
+            return Err(Error::new(
+                ffi::rocblas_status__rocblas_status_invalid_value,
+            ));
+        }
+    };
+    ensure((c_dims.rows, c_dims.cols) == (a_dims.rows, a_dims.cols) && x_dims.len == diagonal_len)?;
+
+    unsafe {
+        T::rocblas_dgmm_64(
+            handle,
+            side,
+            a_dims.rows,
+            a_dims.cols,
+            a.as_ptr().cast(),
+            a_dims.ld,
+            x.as_ptr().cast(),
+            x_dims.inc,
+            c.as_ptr().cast(),
+            c_dims.ld,
+        )
+    }
+}
+
+//==============================================================================
+// Host transfers
+//==============================================================================
+
+/// Copy a strided host vector into a strided device vector
+///
+/// Both layouts must have the same length. Only the addressed elements of
+/// `y` are written, so the gaps of a strided destination are preserved.
+pub fn set_vector<T: Copy>(
+    x: &[T],
+    x_dims: VectorDims,
+    y: &mut DeviceMemory<T>,
+    y_dims: VectorDims,
+) -> Result<()> {
+    x_dims.check_len(x.len())?;
+    y_dims.check(y)?;
+    ensure(x_dims.len == y_dims.len)?;
+
+    let status = unsafe {
+        ffi::rocblas_set_vector_64(
+            x_dims.len,
+            elem_size::<T>(),
+            x.as_ptr().cast(),
+            x_dims.inc,
+            y.as_ptr(),
+            y_dims.inc,
+        )
+    };
+    check(status)
+}
+
+/// Copy a strided device vector into a strided host vector
+pub fn get_vector<T: Copy>(
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    y: &mut [T],
+    y_dims: VectorDims,
+) -> Result<()> {
+    x_dims.check(x)?;
+    y_dims.check_len(y.len())?;
+    ensure(x_dims.len == y_dims.len)?;
+
+    let status = unsafe {
+        ffi::rocblas_get_vector_64(
+            x_dims.len,
+            elem_size::<T>(),
+            x.as_ptr(),
+            x_dims.inc,
+            y.as_mut_ptr().cast(),
+            y_dims.inc,
+        )
+    };
+    check(status)
+}
+
+/// Copy a column-major host matrix into a device matrix
+///
+/// Both layouts must have the same rows and columns but may have different
+/// leading dimensions, e.g. to upload a dense host matrix into a padded
+/// device allocation.
+pub fn set_matrix<T: Copy>(
+    a: &[T],
+    a_dims: MatrixDims,
+    b: &mut DeviceMemory<T>,
+    b_dims: MatrixDims,
+) -> Result<()> {
+    a_dims.check_len(a.len())?;
+    b_dims.check(b)?;
+    ensure((a_dims.rows, a_dims.cols) == (b_dims.rows, b_dims.cols))?;
+
+    let status = unsafe {
+        ffi::rocblas_set_matrix_64(
+            a_dims.rows,
+            a_dims.cols,
+            elem_size::<T>(),
+            a.as_ptr().cast(),
+            a_dims.ld,
+            b.as_ptr(),
+            b_dims.ld,
+        )
+    };
+    check(status)
+}
+
+/// Copy a device matrix into a column-major host matrix
+pub fn get_matrix<T: Copy>(
+    a: &DeviceMemory<T>,
+    a_dims: MatrixDims,
+    b: &mut [T],
+    b_dims: MatrixDims,
+) -> Result<()> {
+    a_dims.check(a)?;
+    b_dims.check_len(b.len())?;
+    ensure((a_dims.rows, a_dims.cols) == (b_dims.rows, b_dims.cols))?;
+
+    let status = unsafe {
+        ffi::rocblas_get_matrix_64(
+            a_dims.rows,
+            a_dims.cols,
+            elem_size::<T>(),
+            a.as_ptr(),
+            a_dims.ld,
+            b.as_mut_ptr().cast(),
+            b_dims.ld,
+        )
+    };
+    check(status)
+}
+
+/// Queue a strided host-to-device vector copy on `stream`
+///
+/// The host data is moved into the stream and kept alive until the copy
+/// has completed.
+pub fn set_vector_async<T: Copy, I: Into<Vec<T>>>(
+    x: I,
+    x_dims: VectorDims,
+    y: &mut DeviceMemory<T>,
+    y_dims: VectorDims,
+    stream: &Stream,
+) -> Result<()> {
+    let x = x.into();
+    x_dims.check_len(x.len())?;
+    y_dims.check(y)?;
+    ensure(x_dims.len == y_dims.len)?;
+
+    let status = unsafe {
+        ffi::rocblas_set_vector_async_64(
+            x_dims.len,
+            elem_size::<T>(),
+            x.as_ptr().cast(),
+            x_dims.inc,
+            y.as_ptr(),
+            y_dims.inc,
+            stream.as_raw() as ffi::hipStream_t,
+        )
+    };
+    check(status)?;
+    stream.retain(x);
+    Ok(())
+}
+
+/// Queue a strided device-to-host vector copy into `dest` on `stream`
+///
+/// The returned [`PendingCopy`] hands `dest` back once the copy finished.
+pub fn get_vector_async<T: Copy>(
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    mut dest: Vec<T>,
+    dest_dims: VectorDims,
+    stream: &Stream,
+) -> Result<PendingCopy<T>> {
+    x_dims.check(x)?;
+    dest_dims.check_len(dest.len())?;
+    ensure(x_dims.len == dest_dims.len)?;
+
+    let status = unsafe {
+        ffi::rocblas_get_vector_async_64(
+            x_dims.len,
+            elem_size::<T>(),
+            x.as_ptr(),
+            x_dims.inc,
+            dest.as_mut_ptr().cast(),
+            dest_dims.inc,
+            stream.as_raw() as ffi::hipStream_t,
+        )
+    };
+    check(status)?;
+    Ok(PendingCopy::new(dest, stream))
+}
+
+/// Queue a host-to-device matrix copy on `stream`
+///
+/// The host data is moved into the stream and kept alive until the copy
+/// has completed.
+pub fn set_matrix_async<T: Copy, I: Into<Vec<T>>>(
+    a: I,
+    a_dims: MatrixDims,
+    b: &mut DeviceMemory<T>,
+    b_dims: MatrixDims,
+    stream: &Stream,
+) -> Result<()> {
+    let a = a.into();
+    a_dims.check_len(a.len())?;
+    b_dims.check(b)?;
+    ensure((a_dims.rows, a_dims.cols) == (b_dims.rows, b_dims.cols))?;
+
+    let status = unsafe {
+        ffi::rocblas_set_matrix_async_64(
+            a_dims.rows,
+            a_dims.cols,
+            elem_size::<T>(),
+            a.as_ptr().cast(),
+            a_dims.ld,
+            b.as_ptr(),
+            b_dims.ld,
+            stream.as_raw() as ffi::hipStream_t,
+        )
+    };
+    check(status)?;
+    stream.retain(a);
+    Ok(())
+}
+
+/// Queue a device-to-host matrix copy into `dest` on `stream`
+///
+/// The returned [`PendingCopy`] hands `dest` back once the copy finished.
+pub fn get_matrix_async<T: Copy>(
+    a: &DeviceMemory<T>,
+    a_dims: MatrixDims,
+    mut dest: Vec<T>,
+    dest_dims: MatrixDims,
+    stream: &Stream,
+) -> Result<PendingCopy<T>> {
+    a_dims.check(a)?;
+    dest_dims.check_len(dest.len())?;
+    ensure((a_dims.rows, a_dims.cols) == (dest_dims.rows, dest_dims.cols))?;
+
+    let status = unsafe {
+        ffi::rocblas_get_matrix_async_64(
+            a_dims.rows,
+            a_dims.cols,
+            elem_size::<T>(),
+            a.as_ptr(),
+            a_dims.ld,
+            dest.as_mut_ptr().cast(),
+            dest_dims.ld,
+            stream.as_raw() as ffi::hipStream_t,
+        )
+    };
+    check(status)?;
+    Ok(PendingCopy::new(dest, stream))
+}
+
+fn elem_size<T>() -> i64 {
+    std::mem::size_of::<T>() as i64
+}
+
+fn check(status: ffi::rocblas_status) -> Result<()> {
+    if status != ffi::rocblas_status__rocblas_status_success {
+        return Err(Error::new(status));
+    }
+    Ok(())
+}
+
+//==============================================================================
+// Type traits for implementation
+//==============================================================================
+
+impl_rocblas_traits!(
+    Axpy64Type,
+    Axpy64TypeFn,
+    {
+        f32 => ffi::rocblas_saxpy_64,
+        f64 => ffi::rocblas_daxpy_64,
+        ffi::rocblas_float_complex => ffi::rocblas_caxpy_64,
+        ffi::rocblas_double_complex => ffi::rocblas_zaxpy_64,
+    },
+    rocblas_axpy_64,
+    (handle: &Handle, n: i64, alpha: &Self, x: *const Self, incx: i64, y: *mut Self, incy: i64),
+    (*mut _rocblas_handle, i64, *const T, *const T, i64, *mut T, i64),
+    (handle.as_raw(), n, alpha, x, incx, y, incy)
+);
+
+impl_rocblas_traits!(
+    Gemv64Type,
+    Gemv64TypeFn,
+    {
+        f32 => ffi::rocblas_sgemv_64,
+        f64 => ffi::rocblas_dgemv_64,
+        ffi::rocblas_float_complex => ffi::rocblas_cgemv_64,
+        ffi::rocblas_double_complex => ffi::rocblas_zgemv_64,
+    },
+    rocblas_gemv_64,
+    (handle: &Handle, trans: Operation, m: i64, n: i64, alpha: &Self, a: *const Self, lda: i64, x: *const Self, incx: i64, beta: &Self, y: *mut Self, incy: i64),
+    (*mut _rocblas_handle, ffi::rocblas_operation, i64, i64, *const T, *const T, i64, *const T, i64, *const T, *mut T, i64),
+    (handle.as_raw(), trans.into(), m, n, alpha, a, lda, x, incx, beta, y, incy)
+);
+
+impl_rocblas_traits!(
+    Hemv64Type,
+    Hemv64TypeFn,
+    {
+        ffi::rocblas_float_complex => ffi::rocblas_chemv_64,
+        ffi::rocblas_double_complex => ffi::rocblas_zhemv_64,
+    },
+    rocblas_hemv_64,
+    (handle: &Handle, uplo: Fill, n: i64, alpha: &Self, a: *const Self, lda: i64, x: *const Self, incx: i64, beta: &Self, y: *mut Self, incy: i64),
+    (*mut _rocblas_handle, ffi::rocblas_fill, i64, *const T, *const T, i64, *const T, i64, *const T, *mut T, i64),
+    (handle.as_raw(), uplo.into(), n, alpha, a, lda, x, incx, beta, y, incy)
+);
+
+impl_rocblas_traits!(
+    Ger64Type,
+    Ger64TypeFn,
+    {
+        f32 => ffi::rocblas_sger_64,
+        f64 => ffi::rocblas_dger_64,
+    },
+    rocblas_ger_64,
+    (handle: &Handle, m: i64, n: i64, alpha: &Self, x: *const Self, incx: i64, y: *const Self, incy: i64, a: *mut Self, lda: i64),
+    (*mut _rocblas_handle, i64, i64, *const T, *const T, i64, *const T, i64, *mut T, i64),
+    (handle.as_raw(), m, n, alpha, x, incx, y, incy, a, lda)
+);
+
+impl_rocblas_traits!(
+    Geru64Type,
+    Geru64TypeFn,
+    {
+        ffi::rocblas_float_complex => ffi::rocblas_cgeru_64,
+        ffi::rocblas_double_complex => ffi::rocblas_zgeru_64,
+    },
+    rocblas_geru_64,
+    (handle: &Handle, m: i64, n: i64, alpha: &Self, x: *const Self, incx: i64, y: *const Self, incy: i64, a: *mut Self, lda: i64),
+    (*mut _rocblas_handle, i64, i64, *const T, *const T, i64, *const T, i64, *mut T, i64),
+    (handle.as_raw(), m, n, alpha, x, incx, y, incy, a, lda)
+);
+
+impl_rocblas_traits!(
+    Gerc64Type,
+    Gerc64TypeFn,
+    {
+        ffi::rocblas_float_complex => ffi::rocblas_cgerc_64,
+        ffi::rocblas_double_complex => ffi::rocblas_zgerc_64,
+    },
+    rocblas_gerc_64,
+    (handle: &Handle, m: i64, n: i64, alpha: &Self, x: *const Self, incx: i64, y: *const Self, incy: i64, a: *mut Self, lda: i64),
+    (*mut _rocblas_handle, i64, i64, *const T, *const T, i64, *const T, i64, *mut T, i64),
+    (handle.as_raw(), m, n, alpha, x, incx, y, incy, a, lda)
+);
+
+impl_rocblas_traits!(
+    Gemm64Type,
+    Gemm64TypeFn,
+    {
+        ffi::rocblas_half => ffi::rocblas_hgemm_64,
+        f32 => ffi::rocblas_sgemm_64,
+        f64 => ffi::rocblas_dgemm_64,
+        ffi::rocblas_float_complex => ffi::rocblas_cgemm_64,
+        ffi::rocblas_double_complex => ffi::rocblas_zgemm_64,
+    },
+    rocblas_gemm_64,
+    (handle: &Handle, transa: Operation, transb: Operation, m: i64, n: i64, k: i64, alpha: &Self, a: *const Self, lda: i64, b: *const Self, ldb: i64, beta: &Self, c: *mut Self, ldc: i64),
+    (*mut _rocblas_handle, ffi::rocblas_operation, ffi::rocblas_operation, i64, i64, i64, *const T, *const T, i64, *const T, i64, *const T, *mut T, i64),
+    (handle.as_raw(), transa.into(), transb.into(), m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+);
+
+impl_rocblas_traits!(
+    Geam64Type,
+    Geam64TypeFn,
+    {
+        f32 => ffi::rocblas_sgeam_64,
+        f64 => ffi::rocblas_dgeam_64,
+        ffi::rocblas_float_complex => ffi::rocblas_cgeam_64,
+        ffi::rocblas_double_complex => ffi::rocblas_zgeam_64,
+    },
+    rocblas_geam_64,
+    (handle: &Handle, transa: Operation, transb: Operation, m: i64, n: i64, alpha: &Self, a: *const Self, lda: i64, beta: &Self, b: *const Self, ldb: i64, c: *mut Self, ldc: i64),
+    (*mut _rocblas_handle, ffi::rocblas_operation, ffi::rocblas_operation, i64, i64, *const T, *const T, i64, *const T, *const T, i64, *mut T, i64),
+    (handle.as_raw(), transa.into(), transb.into(), m, n, alpha, a, lda, beta, b, ldb, c, ldc)
+);
+
+impl_rocblas_traits!(
+    Dgmm64Type,
+    Dgmm64TypeFn,
+    {
+        f32 => ffi::rocblas_sdgmm_64,
+        f64 => ffi::rocblas_ddgmm_64,
+        ffi::rocblas_float_complex => ffi::rocblas_cdgmm_64,
+        ffi::rocblas_double_complex => ffi::rocblas_zdgmm_64,
+    },
+    rocblas_dgmm_64,
+    (handle: &Handle, side: Side, m: i64, n: i64, a: *const Self, lda: i64, x: *const Self, incx: i64, c: *mut Self, ldc: i64),
+    (*mut _rocblas_handle, ffi::rocblas_side, i64, i64, *const T, i64, *const T, i64, *mut T, i64),
+    (handle.as_raw(), side.into(), m, n, a, lda, x, incx, c, ldc)
+);
@@ -6,6 +6,9 @@ use crate::rocblas::bindings::_rocblas_handle;
 use crate::rocblas::error::{Error, Result};
 use crate::rocblas::ffi;
 use crate::rocblas::handle::Handle;
+use crate::rocblas::safe::{self, VectorDims};
+use crate::rocblas::types::{DataType, ExType};
+use crate::rocblas::utils::PointerMode;
 
 //==============================================================================
 // SCAL functions
@@ -169,6 +172,141 @@ where
     }
 }
 
+//==============================================================================
+// SWAP functions
+//==============================================================================
+
+/// Interchange two vectors
+///
+/// x <-> y
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `n` - Number of elements in vectors x and y
+/// * `x` - Device pointer to vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `y` - Device pointer to vector y
+/// * `incy` - Stride between consecutive elements of y
+pub unsafe fn swap<T>(
+    handle: &Handle,
+    n: i32,
+    x: *mut T,
+    incx: i32,
+    y: *mut T,
+    incy: i32,
+) -> Result<()>
+where
+    T: SwapType,
+{
+    unsafe { T::rocblas_swap(handle, n, x, incx, y, incy) }
+}
+
+//==============================================================================
+// AXPY functions
+//==============================================================================
+
+/// Add a scaled vector to another
+///
+/// y := alpha * x + y
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `n` - Number of elements in vectors x and y
+/// * `alpha` - Scalar
+/// * `x` - Device pointer to vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `y` - Device pointer to vector y
+/// * `incy` - Stride between consecutive elements of y
+pub unsafe fn axpy<T>(
+    handle: &Handle,
+    n: i32,
+    alpha: &T,
+    x: *const T,
+    incx: i32,
+    y: *mut T,
+    incy: i32,
+) -> Result<()>
+where
+    T: AxpyType,
+{
+    unsafe { T::rocblas_axpy(handle, n, alpha, x, incx, y, incy) }
+}
+
+/// Add scaled vectors to others in a batch
+///
+/// y_i := alpha * x_i + y_i, for i = 1,...,batch_count
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `n` - Number of elements in each vector x_i and y_i
+/// * `alpha` - Scalar
+/// * `x` - Device array of device pointers to each vector x_i
+/// * `incx` - Stride between consecutive elements of each x_i
+/// * `y` - Device array of device pointers to each vector y_i
+/// * `incy` - Stride between consecutive elements of each y_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn axpy_batched<T>(
+    handle: &Handle,
+    n: i32,
+    alpha: &T,
+    x: *const *const T,
+    incx: i32,
+    y: *const *mut T,
+    incy: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: AxpyBatchedType,
+{
+    unsafe { T::rocblas_axpy_batched(handle, n, alpha, x, incx, y, incy, batch_count) }
+}
+
+/// Add scaled vectors to others in a strided batch
+///
+/// y_i := alpha * x_i + y_i, for i = 1,...,batch_count
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `n` - Number of elements in each vector x_i and y_i
+/// * `alpha` - Scalar
+/// * `x` - Device pointer to first vector x_1
+/// * `incx` - Stride between consecutive elements of each x_i
+/// * `stride_x` - Stride from start of one vector (x_i) to the next (x_i+1)
+/// * `y` - Device pointer to first vector y_1
+/// * `incy` - Stride between consecutive elements of each y_i
+/// * `stride_y` - Stride from start of one vector (y_i) to the next (y_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn axpy_strided_batched<T>(
+    handle: &Handle,
+    n: i32,
+    alpha: &T,
+    x: *const T,
+    incx: i32,
+    stride_x: i64,
+    y: *mut T,
+    incy: i32,
+    stride_y: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: AxpyStridedBatchedType,
+{
+    unsafe {
+        T::rocblas_axpy_strided_batched(
+            handle,
+            n,
+            alpha,
+            x,
+            incx,
+            stride_x,
+            y,
+            incy,
+            stride_y,
+            batch_count,
+        )
+    }
+}
+
 //==============================================================================
 // DOT functions
 //==============================================================================
@@ -254,6 +392,411 @@ where
     unsafe { T::rocblas_dotc(handle, n, x, incx, y, incy, result) }
 }
 
+//==============================================================================
+// AMAX/AMIN functions
+//==============================================================================
+
+/// Find the element of a vector with the largest magnitude
+///
+/// result := 1-based index of the first element maximizing |x_i|, where
+/// |x_i| is |re(x_i)| + |im(x_i)| for complex types
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `n` - Number of elements in vector x
+/// * `x` - Device pointer to vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `result` - Pointer to the result; 0 if `n` or `incx` is not positive
+pub unsafe fn amax<T>(
+    handle: &Handle,
+    n: i32,
+    x: *const T,
+    incx: i32,
+    result: *mut i32,
+) -> Result<()>
+where
+    T: AmaxType,
+{
+    unsafe { T::rocblas_amax(handle, n, x, incx, result) }
+}
+
+/// Find the element of a vector with the smallest magnitude
+///
+/// result := 1-based index of the first element minimizing |x_i|, where
+/// |x_i| is |re(x_i)| + |im(x_i)| for complex types
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `n` - Number of elements in vector x
+/// * `x` - Device pointer to vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `result` - Pointer to the result; 0 if `n` or `incx` is not positive
+pub unsafe fn amin<T>(
+    handle: &Handle,
+    n: i32,
+    x: *const T,
+    incx: i32,
+    result: *mut i32,
+) -> Result<()>
+where
+    T: AminType,
+{
+    unsafe { T::rocblas_amin(handle, n, x, incx, result) }
+}
+
+/// Index of the element with the largest magnitude, checked against `x`
+///
+/// Looks at the `n` elements of `x` that are `incx` apart and returns the
+/// 0-based position of the first largest one among them, so element `i` is
+/// at offset `i * incx` in the buffer. `n` and `incx` must be positive and
+/// the elements must lie within `x`.
+pub fn amax_checked<T>(handle: &Handle, x: &DeviceMemory<T>, n: i32, incx: i32) -> Result<usize>
+where
+    T: AmaxType,
+{
+    checked_index(handle, x, n, incx, |result| unsafe {
+        T::rocblas_amax(handle, n, x.as_ptr().cast(), incx, result)
+    })
+}
+
+/// Index of the element with the smallest magnitude, checked against `x`
+///
+/// The counterpart of [`amax_checked`], with the same requirements.
+pub fn amin_checked<T>(handle: &Handle, x: &DeviceMemory<T>, n: i32, incx: i32) -> Result<usize>
+where
+    T: AminType,
+{
+    checked_index(handle, x, n, incx, |result| unsafe {
+        T::rocblas_amin(handle, n, x.as_ptr().cast(), incx, result)
+    })
+}
+
+/// Validate the vector, run `f` into a host result and make it 0-based
+fn checked_index<T>(
+    handle: &Handle,
+    x: &DeviceMemory<T>,
+    n: i32,
+    incx: i32,
+    f: impl FnOnce(*mut i32) -> Result<()>,
+) -> Result<usize> {
+    check_index_args(x.count(), n, incx)?;
+
+    let mut result = 0;
+    safe::with_pointer_mode(handle, PointerMode::Host, || f(&mut result))?;
+    Ok(result as usize - 1)
+}
+
+/// Check the arguments of an index search over `len` elements
+fn check_index_args(len: usize, n: i32, incx: i32) -> Result<()> {
+    // rocBLAS answers 0 rather than failing for these, which has no 0-based
+    // counterpart
+    if n <= 0 || incx <= 0 {
+        return Err(Error::new(ffi::rocblas_status__rocblas_status_invalid_size));
+    }
+    VectorDims::strided(n, incx).check_len(len)
+}
+
+//==============================================================================
+// ROT functions
+//==============================================================================
+
+/// Apply a Givens rotation to a pair of vectors
+///
+/// x_i := c * x_i + s * y_i
+/// y_i := c * y_i - conj(s) * x_i
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `n` - Number of elements in vectors x and y
+/// * `x` - Device pointer to vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `y` - Device pointer to vector y
+/// * `incy` - Stride between consecutive elements of y
+/// * `c` - Pointer to the cosine of the rotation, which is always real
+/// * `s` - Pointer to the sine of the rotation
+pub unsafe fn rot<T>(
+    handle: &Handle,
+    n: i32,
+    x: *mut T,
+    incx: i32,
+    y: *mut T,
+    incy: i32,
+    c: *const T::Real,
+    s: *const T,
+) -> Result<()>
+where
+    T: RotType,
+{
+    unsafe { T::rocblas_rot(handle, n, x, incx, y, incy, c, s) }
+}
+
+/// Construct a Givens rotation that zeroes the second entry of (a, b)
+///
+/// On return `a` holds r, `b` holds z, and `c` and `s` the rotation
+/// such that [c s; -conj(s) c] * [a; b] = [r; 0].
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `a` - Pointer to the first entry, overwritten with r
+/// * `b` - Pointer to the second entry, overwritten with z
+/// * `c` - Pointer to the cosine of the rotation
+/// * `s` - Pointer to the sine of the rotation
+pub unsafe fn rotg<T>(
+    handle: &Handle,
+    a: *mut T,
+    b: *mut T,
+    c: *mut T::Real,
+    s: *mut T,
+) -> Result<()>
+where
+    T: RotgType,
+{
+    unsafe { T::rocblas_rotg(handle, a, b, c, s) }
+}
+
+/// Apply a modified Givens rotation to a pair of vectors
+///
+/// [x_i; y_i] := H * [x_i; y_i]
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `n` - Number of elements in vectors x and y
+/// * `x` - Device pointer to vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `y` - Device pointer to vector y
+/// * `incy` - Stride between consecutive elements of y
+/// * `param` - Pointer to the 5 element flag and matrix H, as returned by
+///   [`rotmg`]
+pub unsafe fn rotm<T>(
+    handle: &Handle,
+    n: i32,
+    x: *mut T,
+    incx: i32,
+    y: *mut T,
+    incy: i32,
+    param: *const T,
+) -> Result<()>
+where
+    T: RotmType,
+{
+    unsafe { T::rocblas_rotm(handle, n, x, incx, y, incy, param) }
+}
+
+/// Construct a modified Givens rotation
+///
+/// Finds H such that H * [sqrt(d1) * x1; sqrt(d2) * y1] has a zero second
+/// entry, updating `d1`, `d2` and `x1` accordingly.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `d1` - Pointer to the first scaling factor
+/// * `d2` - Pointer to the second scaling factor
+/// * `x1` - Pointer to the first entry of the input vector
+/// * `y1` - Pointer to the second entry of the input vector
+/// * `param` - Pointer to 5 elements receiving the flag and matrix H
+pub unsafe fn rotmg<T>(
+    handle: &Handle,
+    d1: *mut T,
+    d2: *mut T,
+    x1: *mut T,
+    y1: *const T,
+    param: *mut T,
+) -> Result<()>
+where
+    T: RotmgType,
+{
+    unsafe { T::rocblas_rotmg(handle, d1, d2, x1, y1, param) }
+}
+
+//==============================================================================
+// Mixed-precision (_ex) functions
+//==============================================================================
+
+/// Scale a vector by a scalar, with independently chosen precisions
+///
+/// x := alpha * x
+///
+/// The types of `alpha` and `x` are taken from their Rust types; the
+/// arithmetic is carried out in `execution_type`, e.g. f16 vectors scaled
+/// in f32.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `n` - Number of elements in vector x
+/// * `alpha` - Scalar
+/// * `x` - Device memory holding vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `execution_type` - Precision the computation is performed in
+pub fn scal_ex<A, X>(
+    handle: &Handle,
+    n: i32,
+    alpha: &A,
+    x: &DeviceMemory<X>,
+    incx: i32,
+    execution_type: DataType,
+) -> Result<()>
+where
+    A: ExType,
+    X: ExType,
+{
+    let status = unsafe {
+        ffi::rocblas_scal_ex(
+            handle.as_raw(),
+            n,
+            (alpha as *const A).cast(),
+            A::DATA_TYPE.into(),
+            x.as_ptr(),
+            X::DATA_TYPE.into(),
+            incx,
+            execution_type.into(),
+        )
+    };
+    check(status)
+}
+
+/// Add a scaled vector to another, with independently chosen precisions
+///
+/// y := alpha * x + y
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `n` - Number of elements in vectors x and y
+/// * `alpha` - Scalar
+/// * `x` - Device memory holding vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `y` - Device memory holding vector y
+/// * `incy` - Stride between consecutive elements of y
+/// * `execution_type` - Precision the computation is performed in
+#[allow(clippy::too_many_arguments)]
+pub fn axpy_ex<A, X, Y>(
+    handle: &Handle,
+    n: i32,
+    alpha: &A,
+    x: &DeviceMemory<X>,
+    incx: i32,
+    y: &DeviceMemory<Y>,
+    incy: i32,
+    execution_type: DataType,
+) -> Result<()>
+where
+    A: ExType,
+    X: ExType,
+    Y: ExType,
+{
+    let status = unsafe {
+        ffi::rocblas_axpy_ex(
+            handle.as_raw(),
+            n,
+            (alpha as *const A).cast(),
+            A::DATA_TYPE.into(),
+            x.as_ptr(),
+            X::DATA_TYPE.into(),
+            incx,
+            y.as_ptr(),
+            Y::DATA_TYPE.into(),
+            incy,
+            execution_type.into(),
+        )
+    };
+    check(status)
+}
+
+/// Dot product of two vectors, with independently chosen precisions
+///
+/// result := x * y
+///
+/// The handle must be in host pointer mode, as `result` is host memory.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `n` - Number of elements in vectors x and y
+/// * `x` - Device memory holding vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `y` - Device memory holding vector y
+/// * `incy` - Stride between consecutive elements of y
+/// * `result` - Where the result is written
+/// * `execution_type` - Precision the computation is performed in
+#[allow(clippy::too_many_arguments)]
+pub fn dot_ex<X, Y, R>(
+    handle: &Handle,
+    n: i32,
+    x: &DeviceMemory<X>,
+    incx: i32,
+    y: &DeviceMemory<Y>,
+    incy: i32,
+    result: &mut R,
+    execution_type: DataType,
+) -> Result<()>
+where
+    X: ExType,
+    Y: ExType,
+    R: ExType,
+{
+    let status = unsafe {
+        ffi::rocblas_dot_ex(
+            handle.as_raw(),
+            n,
+            x.as_ptr(),
+            X::DATA_TYPE.into(),
+            incx,
+            y.as_ptr(),
+            Y::DATA_TYPE.into(),
+            incy,
+            (result as *mut R).cast(),
+            R::DATA_TYPE.into(),
+            execution_type.into(),
+        )
+    };
+    check(status)
+}
+
+/// Euclidean norm of a vector, with independently chosen precisions
+///
+/// result := sqrt(x * x)
+///
+/// The handle must be in host pointer mode, as `result` is host memory.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `n` - Number of elements in vector x
+/// * `x` - Device memory holding vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `result` - Where the result is written
+/// * `execution_type` - Precision the computation is performed in
+pub fn nrm2_ex<X, R>(
+    handle: &Handle,
+    n: i32,
+    x: &DeviceMemory<X>,
+    incx: i32,
+    result: &mut R,
+    execution_type: DataType,
+) -> Result<()>
+where
+    X: ExType,
+    R: ExType,
+{
+    let status = unsafe {
+        ffi::rocblas_nrm2_ex(
+            handle.as_raw(),
+            n,
+            x.as_ptr(),
+            X::DATA_TYPE.into(),
+            incx,
+            (result as *mut R).cast(),
+            R::DATA_TYPE.into(),
+            execution_type.into(),
+        )
+    };
+    check(status)
+}
+
+fn check(status: ffi::rocblas_status) -> Result<()> {
+    if status != ffi::rocblas_status__rocblas_status_success {
+        return Err(Error::new(status));
+    }
+    Ok(())
+}
+
 //==============================================================================
 // Type traits for implementation
 //==============================================================================
@@ -348,6 +891,66 @@ impl_rocblas_traits!(
     (handle.as_raw(), n, x, incx, stridex, y, incy, stridey, batch_count)
 );
 
+impl_rocblas_traits!(
+    SwapType,
+    SwapTypeFn,
+    {
+        f32 => ffi::rocblas_sswap,
+        f64 => ffi::rocblas_dswap,
+        ffi::rocblas_float_complex => ffi::rocblas_cswap,
+        ffi::rocblas_double_complex => ffi::rocblas_zswap,
+    },
+    rocblas_swap,
+    (handle: &Handle, n: i32, x: *mut Self, incx: i32, y: *mut Self, incy: i32),
+    (*mut _rocblas_handle, i32, *mut T, i32, *mut T, i32),
+    (handle.as_raw(), n, x, incx, y, incy)
+);
+
+impl_rocblas_traits!(
+    AxpyType,
+    AxpyTypeFn,
+    {
+        f32 => ffi::rocblas_saxpy,
+        f64 => ffi::rocblas_daxpy,
+        ffi::rocblas_float_complex => ffi::rocblas_caxpy,
+        ffi::rocblas_double_complex => ffi::rocblas_zaxpy,
+    },
+    rocblas_axpy,
+    (handle: &Handle, n: i32, alpha: &Self, x: *const Self, incx: i32, y: *mut Self, incy: i32),
+    (*mut _rocblas_handle, i32, *const T, *const T, i32, *mut T, i32),
+    (handle.as_raw(), n, alpha, x, incx, y, incy)
+);
+
+impl_rocblas_traits!(
+    AxpyBatchedType,
+    AxpyBatchedTypeFn,
+    {
+        f32 => ffi::rocblas_saxpy_batched,
+        f64 => ffi::rocblas_daxpy_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_caxpy_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_zaxpy_batched,
+    },
+    rocblas_axpy_batched,
+    (handle: &Handle, n: i32, alpha: &Self, x: *const *const Self, incx: i32, y: *const *mut Self, incy: i32, batch_count: i32),
+    (*mut _rocblas_handle, i32, *const T, *const *const T, i32, *const *mut T, i32, i32),
+    (handle.as_raw(), n, alpha, x, incx, y, incy, batch_count)
+);
+
+impl_rocblas_traits!(
+    AxpyStridedBatchedType,
+    AxpyStridedBatchedTypeFn,
+    {
+        f32 => ffi::rocblas_saxpy_strided_batched,
+        f64 => ffi::rocblas_daxpy_strided_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_caxpy_strided_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_zaxpy_strided_batched,
+    },
+    rocblas_axpy_strided_batched,
+    (handle: &Handle, n: i32, alpha: &Self, x: *const Self, incx: i32, stride_x: i64, y: *mut Self, incy: i32, stride_y: i64, batch_count: i32),
+    (*mut _rocblas_handle, i32, *const T, *const T, i32, i64, *mut T, i32, i64, i32),
+    (handle.as_raw(), n, alpha, x, incx, stride_x, y, incy, stride_y, batch_count)
+);
+
 impl_rocblas_traits!(
     DotType,
     DotTypeFn,
@@ -389,21 +992,158 @@ impl_rocblas_traits!(
     (handle.as_raw(), n, x, incx, y, incy, result)
 );
 
+impl_rocblas_traits!(
+    AmaxType,
+    AmaxTypeFn,
+    {
+        f32 => ffi::rocblas_isamax,
+        f64 => ffi::rocblas_idamax,
+        ffi::rocblas_float_complex => ffi::rocblas_icamax,
+        ffi::rocblas_double_complex => ffi::rocblas_izamax,
+    },
+    rocblas_amax,
+    (handle: &Handle, n: i32, x: *const Self, incx: i32, result: *mut i32),
+    (*mut _rocblas_handle, i32, *const T, i32, *mut i32),
+    (handle.as_raw(), n, x, incx, result)
+);
+
+impl_rocblas_traits!(
+    AminType,
+    AminTypeFn,
+    {
+        f32 => ffi::rocblas_isamin,
+        f64 => ffi::rocblas_idamin,
+        ffi::rocblas_float_complex => ffi::rocblas_icamin,
+        ffi::rocblas_double_complex => ffi::rocblas_izamin,
+    },
+    rocblas_amin,
+    (handle: &Handle, n: i32, x: *const Self, incx: i32, result: *mut i32),
+    (*mut _rocblas_handle, i32, *const T, i32, *mut i32),
+    (handle.as_raw(), n, x, incx, result)
+);
+
+/// Types with a Givens rotation
+///
+/// The cosine of a rotation is real, so complex types name their real
+/// counterpart as `Real`.
+pub trait RotType {
+    /// Type of the rotation cosine
+    type Real;
+
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn rocblas_rot(
+        handle: &Handle,
+        n: i32,
+        x: *mut Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+        c: *const Self::Real,
+        s: *const Self,
+    ) -> Result<()>;
+}
+
+/// Types for which a Givens rotation can be constructed
+pub trait RotgType: RotType {
+    unsafe fn rocblas_rotg(
+        handle: &Handle,
+        a: *mut Self,
+        b: *mut Self,
+        c: *mut Self::Real,
+        s: *mut Self,
+    ) -> Result<()>;
+}
+
+// The cosine type differs from `Self` for complex types, which
+// `impl_rocblas_traits!` can't express
+macro_rules! impl_rot_traits {
+    ($($(#[$attr:meta])* $t:ty, $real:ty => $rot:path, $rotg:path;)*) => {
+        $(
+            $(#[$attr])*
+            impl RotType for $t {
+                type Real = $real;
+
+                unsafe fn rocblas_rot(
+                    handle: &Handle,
+                    n: i32,
+                    x: *mut Self,
+                    incx: i32,
+                    y: *mut Self,
+                    incy: i32,
+                    c: *const $real,
+                    s: *const Self,
+                ) -> Result<()> {
+                    impl_rocblas_func_inner!(
+                        $rot,
+                        handle.as_raw(),
+                        n,
+                        x.cast(),
+                        incx,
+                        y.cast(),
+                        incy,
+                        c,
+                        s.cast()
+                    )
+                }
+            }
+
+            $(#[$attr])*
+            impl RotgType for $t {
+                unsafe fn rocblas_rotg(
+                    handle: &Handle,
+                    a: *mut Self,
+                    b: *mut Self,
+                    c: *mut $real,
+                    s: *mut Self,
+                ) -> Result<()> {
+                    impl_rocblas_func_inner!($rotg, handle.as_raw(), a.cast(), b.cast(), c, s.cast())
+                }
+            }
+        )*
+    };
+}
+
+impl_rot_traits! {
+    f32, f32 => ffi::rocblas_srot, ffi::rocblas_srotg;
+    f64, f64 => ffi::rocblas_drot, ffi::rocblas_drotg;
+    ffi::rocblas_float_complex, f32 => ffi::rocblas_crot, ffi::rocblas_crotg;
+    ffi::rocblas_double_complex, f64 => ffi::rocblas_zrot, ffi::rocblas_zrotg;
+    #[cfg(feature = "num-complex")]
+    num_complex::Complex32, f32 => ffi::rocblas_crot, ffi::rocblas_crotg;
+    #[cfg(feature = "num-complex")]
+    num_complex::Complex64, f64 => ffi::rocblas_zrot, ffi::rocblas_zrotg;
+}
+
+impl_rocblas_traits!(
+    RotmType,
+    RotmTypeFn,
+    {
+        f32 => ffi::rocblas_srotm,
+        f64 => ffi::rocblas_drotm,
+    },
+    rocblas_rotm,
+    (handle: &Handle, n: i32, x: *mut Self, incx: i32, y: *mut Self, incy: i32, param: *const Self),
+    (*mut _rocblas_handle, i32, *mut T, i32, *mut T, i32, *const T),
+    (handle.as_raw(), n, x, incx, y, incy, param)
+);
+
+impl_rocblas_traits!(
+    RotmgType,
+    RotmgTypeFn,
+    {
+        f32 => ffi::rocblas_srotmg,
+        f64 => ffi::rocblas_drotmg,
+    },
+    rocblas_rotmg,
+    (handle: &Handle, d1: *mut Self, d2: *mut Self, x1: *mut Self, y1: *const Self, param: *mut Self),
+    (*mut _rocblas_handle, *mut T, *mut T, *mut T, *const T, *mut T),
+    (handle.as_raw(), d1, d2, x1, y1, param)
+);
+
 // Add a placeholder declaration for the remaining functions
 // that we haven't fully implemented yet
 
 // BLAS Level 1
-pub fn axpy<T>(
-    _handle: &Handle,
-    _n: i32,
-    _alpha: &T,
-    _x: *const T,
-    _incx: i32,
-    _y: *mut T,
-    _incy: i32,
-) -> Result<()> {
-    todo!()
-}
 pub fn nrm2<T, R>(
     _handle: &Handle,
     _n: i32,
@@ -422,84 +1162,7 @@ pub fn asum<T, R>(
 ) -> Result<()> {
     todo!()
 }
-pub fn amax<T>(
-    _handle: &Handle,
-    _n: i32,
-    _x: *const T,
-    _incx: i32,
-    _result: *mut i32,
-) -> Result<()> {
-    todo!()
-}
-pub fn amin<T>(
-    _handle: &Handle,
-    _n: i32,
-    _x: *const T,
-    _incx: i32,
-    _result: *mut i32,
-) -> Result<()> {
-    todo!()
-}
-pub fn swap<T>(
-    _handle: &Handle,
-    _n: i32,
-    _x: *mut T,
-    _incx: i32,
-    _y: *mut T,
-    _incy: i32,
-) -> Result<()> {
-    todo!()
-}
-pub fn rot<T>(
-    _handle: &Handle,
-    _n: i32,
-    _x: *mut T,
-    _incx: i32,
-    _y: *mut T,
-    _incy: i32,
-    _c: *const f32,
-    _s: *const f32,
-) -> Result<()> {
-    todo!()
-}
-pub fn rotg<T>(_handle: &Handle, _a: *mut T, _b: *mut T, _c: *mut T, _s: *mut T) -> Result<()> {
-    todo!()
-}
-pub fn rotm<T>(
-    _handle: &Handle,
-    _n: i32,
-    _x: *mut T,
-    _incx: i32,
-    _y: *mut T,
-    _incy: i32,
-    _param: *const T,
-) -> Result<()> {
-    todo!()
-}
-pub fn rotmg<T>(
-    _handle: &Handle,
-    _d1: *mut T,
-    _d2: *mut T,
-    _x1: *mut T,
-    _y1: *const T,
-    _param: *mut T,
-) -> Result<()> {
-    todo!()
-}
-
 // BLAS Level 1 - Batched
-pub fn axpy_batched<T>(
-    _handle: &Handle,
-    _n: i32,
-    _alpha: &T,
-    _x: *const *const T,
-    _incx: i32,
-    _y: *const *mut T,
-    _incy: i32,
-    _batch_count: i32,
-) -> Result<()> {
-    todo!()
-}
 pub fn dot_batched<T, R>(
     _handle: &Handle,
     _n: i32,
@@ -635,20 +1298,6 @@ pub fn rotmg_batched<T>(
 }
 
 // BLAS Level 1 - Strided Batched
-pub fn axpy_strided_batched<T>(
-    _handle: &Handle,
-    _n: i32,
-    _alpha: &T,
-    _x: *const T,
-    _incx: i32,
-    _stridex: i64,
-    _y: *mut T,
-    _incy: i32,
-    _stridey: i64,
-    _batch_count: i32,
-) -> Result<()> {
-    todo!()
-}
 pub fn dot_strided_batched<T, R>(
     _handle: &Handle,
     _n: i32,
@@ -808,3 +1457,21 @@ pub fn rotmg_strided_batched<T>(
 ) -> Result<()> {
     todo!()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_index_args() {
+        assert!(check_index_args(10, 10, 1).is_ok());
+        assert!(check_index_args(10, 4, 3).is_ok());
+        assert!(check_index_args(9, 4, 3).is_err());
+        assert!(check_index_args(10, 11, 1).is_err());
+
+        // No 0-based answer exists for these
+        assert!(check_index_args(10, 0, 1).is_err());
+        assert!(check_index_args(10, 5, 0).is_err());
+        assert!(check_index_args(10, 5, -1).is_err());
+    }
+}
@@ -3,7 +3,7 @@
 use crate::rocblas::bindings::_rocblas_handle;
 use crate::rocblas::error::{Error, Result};
 use crate::rocblas::handle::Handle;
-use crate::rocblas::types::{Fill, Operation};
+use crate::rocblas::types::{Diagonal, Fill, Operation};
 use crate::rocblas::{ffi, rocblas_operation};
 use crate::*;
 
@@ -3578,3 +3578,2650 @@ impl Syr2StridedBatchedType for ffi::rocblas_double_complex {
         Ok(())
     }
 }
+
+//==============================================================================
+// HER, HER2, HPR and HPR2 functions - Hermitian rank updates
+//==============================================================================
+
+/// Hermitian rank-1 update
+///
+/// A := alpha * x * x^H + A
+///
+/// where alpha is a real scalar and A is an n x n Hermitian matrix of which
+/// only the `uplo` triangle is updated.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether the upper or lower triangle of A is updated
+/// * `n` - Order of matrix A
+/// * `alpha` - Real scalar alpha
+/// * `x` - Vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `A` - Matrix A
+/// * `lda` - Leading dimension of matrix A
+pub unsafe fn her<T, R>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    alpha: &R,
+    x: *const T,
+    incx: i32,
+    A: *mut T,
+    lda: i32,
+) -> Result<()>
+where
+    T: HerType<ScalarType = R>,
+{
+    unsafe { T::rocblas_her(handle, uplo, n, alpha, x, incx, A, lda) }
+}
+
+/// Batched Hermitian rank-1 update
+///
+/// Applies [`her`] to each instance of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether the upper or lower triangles of A_i are updated
+/// * `n` - Order of matrices A_i
+/// * `alpha` - Real scalar alpha
+/// * `x` - Array of pointers to vectors x_i
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `A` - Array of pointers to matrices A_i
+/// * `lda` - Leading dimension of matrices A_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn her_batched<T, R>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    alpha: &R,
+    x: *const *const T,
+    incx: i32,
+    A: *const *mut T,
+    lda: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: HerBatchedType<ScalarType = R>,
+{
+    unsafe { T::rocblas_her_batched(handle, uplo, n, alpha, x, incx, A, lda, batch_count) }
+}
+
+/// Strided batched Hermitian rank-1 update
+///
+/// Applies [`her`] to each instance of the batch, with the instances laid
+/// out at fixed strides in single buffers.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether the upper or lower triangles of A_i are updated
+/// * `n` - Order of matrices A_i
+/// * `alpha` - Real scalar alpha
+/// * `x` - Pointer to the first vector x_1
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `stride_x` - Stride from start of one vector (x_i) to the next (x_i+1)
+/// * `A` - Pointer to the first matrix A_1
+/// * `lda` - Leading dimension of matrices A_i
+/// * `stride_A` - Stride from start of one matrix (A_i) to the next (A_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn her_strided_batched<T, R>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    alpha: &R,
+    x: *const T,
+    incx: i32,
+    stride_x: i64,
+    A: *mut T,
+    lda: i32,
+    stride_A: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: HerStridedBatchedType<ScalarType = R>,
+{
+    unsafe {
+        T::rocblas_her_strided_batched(
+            handle,
+            uplo,
+            n,
+            alpha,
+            x,
+            incx,
+            stride_x,
+            A,
+            lda,
+            stride_A,
+            batch_count,
+        )
+    }
+}
+
+/// Hermitian rank-2 update
+///
+/// A := alpha * x * y^H + conj(alpha) * y * x^H + A
+///
+/// where A is an n x n Hermitian matrix of which only the `uplo` triangle
+/// is updated.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether the upper or lower triangle of A is updated
+/// * `n` - Order of matrix A
+/// * `alpha` - Scalar alpha
+/// * `x` - Vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `y` - Vector y
+/// * `incy` - Stride between consecutive elements of y
+/// * `A` - Matrix A
+/// * `lda` - Leading dimension of matrix A
+pub unsafe fn her2<T>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    alpha: &T,
+    x: *const T,
+    incx: i32,
+    y: *const T,
+    incy: i32,
+    A: *mut T,
+    lda: i32,
+) -> Result<()>
+where
+    T: Her2Type,
+{
+    unsafe { T::rocblas_her2(handle, uplo, n, alpha, x, incx, y, incy, A, lda) }
+}
+
+/// Batched Hermitian rank-2 update
+///
+/// Applies [`her2`] to each instance of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether the upper or lower triangles of A_i are updated
+/// * `n` - Order of matrices A_i
+/// * `alpha` - Scalar alpha
+/// * `x` - Array of pointers to vectors x_i
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `y` - Array of pointers to vectors y_i
+/// * `incy` - Stride between consecutive elements of y_i
+/// * `A` - Array of pointers to matrices A_i
+/// * `lda` - Leading dimension of matrices A_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn her2_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    alpha: &T,
+    x: *const *const T,
+    incx: i32,
+    y: *const *const T,
+    incy: i32,
+    A: *const *mut T,
+    lda: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: Her2BatchedType,
+{
+    unsafe {
+        T::rocblas_her2_batched(
+            handle,
+            uplo,
+            n,
+            alpha,
+            x,
+            incx,
+            y,
+            incy,
+            A,
+            lda,
+            batch_count,
+        )
+    }
+}
+
+/// Strided batched Hermitian rank-2 update
+///
+/// Applies [`her2`] to each instance of the batch, with the instances laid
+/// out at fixed strides in single buffers.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether the upper or lower triangles of A_i are updated
+/// * `n` - Order of matrices A_i
+/// * `alpha` - Scalar alpha
+/// * `x` - Pointer to the first vector x_1
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `stride_x` - Stride from start of one vector (x_i) to the next (x_i+1)
+/// * `y` - Pointer to the first vector y_1
+/// * `incy` - Stride between consecutive elements of y_i
+/// * `stride_y` - Stride from start of one vector (y_i) to the next (y_i+1)
+/// * `A` - Pointer to the first matrix A_1
+/// * `lda` - Leading dimension of matrices A_i
+/// * `stride_A` - Stride from start of one matrix (A_i) to the next (A_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn her2_strided_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    alpha: &T,
+    x: *const T,
+    incx: i32,
+    stride_x: i64,
+    y: *const T,
+    incy: i32,
+    stride_y: i64,
+    A: *mut T,
+    lda: i32,
+    stride_A: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: Her2StridedBatchedType,
+{
+    unsafe {
+        T::rocblas_her2_strided_batched(
+            handle,
+            uplo,
+            n,
+            alpha,
+            x,
+            incx,
+            stride_x,
+            y,
+            incy,
+            stride_y,
+            A,
+            lda,
+            stride_A,
+            batch_count,
+        )
+    }
+}
+
+/// Packed Hermitian rank-1 update
+///
+/// A := alpha * x * x^H + A
+///
+/// where alpha is a real scalar and A is an n x n Hermitian matrix whose
+/// `uplo` triangle is stored packed column by column.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether the upper or lower triangle of A is updated
+/// * `n` - Order of matrix A
+/// * `alpha` - Real scalar alpha
+/// * `x` - Vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `AP` - Packed matrix A
+pub unsafe fn hpr<T, R>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    alpha: &R,
+    x: *const T,
+    incx: i32,
+    AP: *mut T,
+) -> Result<()>
+where
+    T: HprType<ScalarType = R>,
+{
+    unsafe { T::rocblas_hpr(handle, uplo, n, alpha, x, incx, AP) }
+}
+
+/// Batched packed Hermitian rank-1 update
+///
+/// Applies [`hpr`] to each instance of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether the upper or lower triangles of A_i are updated
+/// * `n` - Order of matrices A_i
+/// * `alpha` - Real scalar alpha
+/// * `x` - Array of pointers to vectors x_i
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `AP` - Array of pointers to packed matrices A_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn hpr_batched<T, R>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    alpha: &R,
+    x: *const *const T,
+    incx: i32,
+    AP: *const *mut T,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: HprBatchedType<ScalarType = R>,
+{
+    unsafe { T::rocblas_hpr_batched(handle, uplo, n, alpha, x, incx, AP, batch_count) }
+}
+
+/// Strided batched packed Hermitian rank-1 update
+///
+/// Applies [`hpr`] to each instance of the batch, with the instances laid
+/// out at fixed strides in single buffers.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether the upper or lower triangles of A_i are updated
+/// * `n` - Order of matrices A_i
+/// * `alpha` - Real scalar alpha
+/// * `x` - Pointer to the first vector x_1
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `stride_x` - Stride from start of one vector (x_i) to the next (x_i+1)
+/// * `AP` - Pointer to the first packed matrix A_1
+/// * `stride_A` - Stride from start of one matrix (A_i) to the next (A_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn hpr_strided_batched<T, R>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    alpha: &R,
+    x: *const T,
+    incx: i32,
+    stride_x: i64,
+    AP: *mut T,
+    stride_A: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: HprStridedBatchedType<ScalarType = R>,
+{
+    unsafe {
+        T::rocblas_hpr_strided_batched(
+            handle,
+            uplo,
+            n,
+            alpha,
+            x,
+            incx,
+            stride_x,
+            AP,
+            stride_A,
+            batch_count,
+        )
+    }
+}
+
+/// Packed Hermitian rank-2 update
+///
+/// A := alpha * x * y^H + conj(alpha) * y * x^H + A
+///
+/// where A is an n x n Hermitian matrix whose `uplo` triangle is stored
+/// packed column by column.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether the upper or lower triangle of A is updated
+/// * `n` - Order of matrix A
+/// * `alpha` - Scalar alpha
+/// * `x` - Vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `y` - Vector y
+/// * `incy` - Stride between consecutive elements of y
+/// * `AP` - Packed matrix A
+pub unsafe fn hpr2<T>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    alpha: &T,
+    x: *const T,
+    incx: i32,
+    y: *const T,
+    incy: i32,
+    AP: *mut T,
+) -> Result<()>
+where
+    T: Hpr2Type,
+{
+    unsafe { T::rocblas_hpr2(handle, uplo, n, alpha, x, incx, y, incy, AP) }
+}
+
+/// Batched packed Hermitian rank-2 update
+///
+/// Applies [`hpr2`] to each instance of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether the upper or lower triangles of A_i are updated
+/// * `n` - Order of matrices A_i
+/// * `alpha` - Scalar alpha
+/// * `x` - Array of pointers to vectors x_i
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `y` - Array of pointers to vectors y_i
+/// * `incy` - Stride between consecutive elements of y_i
+/// * `AP` - Array of pointers to packed matrices A_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn hpr2_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    alpha: &T,
+    x: *const *const T,
+    incx: i32,
+    y: *const *const T,
+    incy: i32,
+    AP: *const *mut T,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: Hpr2BatchedType,
+{
+    unsafe { T::rocblas_hpr2_batched(handle, uplo, n, alpha, x, incx, y, incy, AP, batch_count) }
+}
+
+/// Strided batched packed Hermitian rank-2 update
+///
+/// Applies [`hpr2`] to each instance of the batch, with the instances laid
+/// out at fixed strides in single buffers.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether the upper or lower triangles of A_i are updated
+/// * `n` - Order of matrices A_i
+/// * `alpha` - Scalar alpha
+/// * `x` - Pointer to the first vector x_1
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `stride_x` - Stride from start of one vector (x_i) to the next (x_i+1)
+/// * `y` - Pointer to the first vector y_1
+/// * `incy` - Stride between consecutive elements of y_i
+/// * `stride_y` - Stride from start of one vector (y_i) to the next (y_i+1)
+/// * `AP` - Pointer to the first packed matrix A_1
+/// * `stride_A` - Stride from start of one matrix (A_i) to the next (A_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn hpr2_strided_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    alpha: &T,
+    x: *const T,
+    incx: i32,
+    stride_x: i64,
+    y: *const T,
+    incy: i32,
+    stride_y: i64,
+    AP: *mut T,
+    stride_A: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: Hpr2StridedBatchedType,
+{
+    unsafe {
+        T::rocblas_hpr2_strided_batched(
+            handle,
+            uplo,
+            n,
+            alpha,
+            x,
+            incx,
+            stride_x,
+            y,
+            incy,
+            stride_y,
+            AP,
+            stride_A,
+            batch_count,
+        )
+    }
+}
+
+// Trait definitions for Hermitian rank updates
+
+pub trait HerType {
+    type ScalarType;
+
+    unsafe fn rocblas_her(
+        handle: &Handle,
+        uplo: Fill,
+        n: i32,
+        alpha: &Self::ScalarType,
+        x: *const Self,
+        incx: i32,
+        A: *mut Self,
+        lda: i32,
+    ) -> Result<()>;
+}
+
+impl HerType for ffi::rocblas_float_complex {
+    type ScalarType = f32;
+
+    unsafe fn rocblas_her(
+        handle: &Handle,
+        uplo: Fill,
+        n: i32,
+        alpha: &Self::ScalarType,
+        x: *const Self,
+        incx: i32,
+        A: *mut Self,
+        lda: i32,
+    ) -> Result<()> {
+        let status =
+            unsafe { ffi::rocblas_cher(handle.as_raw(), uplo.into(), n, alpha, x, incx, A, lda) };
+        if status != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(status));
+        }
+        Ok(())
+    }
+}
+
+impl HerType for ffi::rocblas_double_complex {
+    type ScalarType = f64;
+
+    unsafe fn rocblas_her(
+        handle: &Handle,
+        uplo: Fill,
+        n: i32,
+        alpha: &Self::ScalarType,
+        x: *const Self,
+        incx: i32,
+        A: *mut Self,
+        lda: i32,
+    ) -> Result<()> {
+        let status =
+            unsafe { ffi::rocblas_zher(handle.as_raw(), uplo.into(), n, alpha, x, incx, A, lda) };
+        if status != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(status));
+        }
+        Ok(())
+    }
+}
+
+pub trait HerBatchedType {
+    type ScalarType;
+
+    unsafe fn rocblas_her_batched(
+        handle: &Handle,
+        uplo: Fill,
+        n: i32,
+        alpha: &Self::ScalarType,
+        x: *const *const Self,
+        incx: i32,
+        A: *const *mut Self,
+        lda: i32,
+        batch_count: i32,
+    ) -> Result<()>;
+}
+
+impl HerBatchedType for ffi::rocblas_float_complex {
+    type ScalarType = f32;
+
+    unsafe fn rocblas_her_batched(
+        handle: &Handle,
+        uplo: Fill,
+        n: i32,
+        alpha: &Self::ScalarType,
+        x: *const *const Self,
+        incx: i32,
+        A: *const *mut Self,
+        lda: i32,
+        batch_count: i32,
+    ) -> Result<()> {
+        let status = unsafe {
+            ffi::rocblas_cher_batched(
+                handle.as_raw(),
+                uplo.into(),
+                n,
+                alpha,
+                x,
+                incx,
+                A,
+                lda,
+                batch_count,
+            )
+        };
+        if status != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(status));
+        }
+        Ok(())
+    }
+}
+
+impl HerBatchedType for ffi::rocblas_double_complex {
+    type ScalarType = f64;
+
+    unsafe fn rocblas_her_batched(
+        handle: &Handle,
+        uplo: Fill,
+        n: i32,
+        alpha: &Self::ScalarType,
+        x: *const *const Self,
+        incx: i32,
+        A: *const *mut Self,
+        lda: i32,
+        batch_count: i32,
+    ) -> Result<()> {
+        let status = unsafe {
+            ffi::rocblas_zher_batched(
+                handle.as_raw(),
+                uplo.into(),
+                n,
+                alpha,
+                x,
+                incx,
+                A,
+                lda,
+                batch_count,
+            )
+        };
+        if status != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(status));
+        }
+        Ok(())
+    }
+}
+
+pub trait HerStridedBatchedType {
+    type ScalarType;
+
+    unsafe fn rocblas_her_strided_batched(
+        handle: &Handle,
+        uplo: Fill,
+        n: i32,
+        alpha: &Self::ScalarType,
+        x: *const Self,
+        incx: i32,
+        stride_x: i64,
+        A: *mut Self,
+        lda: i32,
+        stride_A: i64,
+        batch_count: i32,
+    ) -> Result<()>;
+}
+
+impl HerStridedBatchedType for ffi::rocblas_float_complex {
+    type ScalarType = f32;
+
+    unsafe fn rocblas_her_strided_batched(
+        handle: &Handle,
+        uplo: Fill,
+        n: i32,
+        alpha: &Self::ScalarType,
+        x: *const Self,
+        incx: i32,
+        stride_x: i64,
+        A: *mut Self,
+        lda: i32,
+        stride_A: i64,
+        batch_count: i32,
+    ) -> Result<()> {
+        let status = unsafe {
+            ffi::rocblas_cher_strided_batched(
+                handle.as_raw(),
+                uplo.into(),
+                n,
+                alpha,
+                x,
+                incx,
+                stride_x,
+                A,
+                lda,
+                stride_A,
+                batch_count,
+            )
+        };
+        if status != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(status));
+        }
+        Ok(())
+    }
+}
+
+impl HerStridedBatchedType for ffi::rocblas_double_complex {
+    type ScalarType = f64;
+
+    unsafe fn rocblas_her_strided_batched(
+        handle: &Handle,
+        uplo: Fill,
+        n: i32,
+        alpha: &Self::ScalarType,
+        x: *const Self,
+        incx: i32,
+        stride_x: i64,
+        A: *mut Self,
+        lda: i32,
+        stride_A: i64,
+        batch_count: i32,
+    ) -> Result<()> {
+        let status = unsafe {
+            ffi::rocblas_zher_strided_batched(
+                handle.as_raw(),
+                uplo.into(),
+                n,
+                alpha,
+                x,
+                incx,
+                stride_x,
+                A,
+                lda,
+                stride_A,
+                batch_count,
+            )
+        };
+        if status != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(status));
+        }
+        Ok(())
+    }
+}
+
+impl_rocblas_traits!(
+    Her2Type,
+    Her2Fn,
+    {
+        ffi::rocblas_float_complex => ffi::rocblas_cher2,
+        ffi::rocblas_double_complex => ffi::rocblas_zher2,
+    },
+    rocblas_her2,
+    (handle: &Handle, uplo: Fill, n: i32, alpha: &Self, x: *const Self, incx: i32, y: *const Self, incy: i32, A: *mut Self, lda: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, i32, *const T, *const T, i32, *const T, i32, *mut T, i32),
+    (handle.as_raw(), uplo.into(), n, alpha, x, incx, y, incy, A, lda)
+);
+
+impl_rocblas_traits!(
+    Her2BatchedType,
+    Her2BatchedFn,
+    {
+        ffi::rocblas_float_complex => ffi::rocblas_cher2_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_zher2_batched,
+    },
+    rocblas_her2_batched,
+    (handle: &Handle, uplo: Fill, n: i32, alpha: &Self, x: *const *const Self, incx: i32, y: *const *const Self, incy: i32, A: *const *mut Self, lda: i32, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, i32, *const T, *const *const T, i32, *const *const T, i32, *const *mut T, i32, i32),
+    (handle.as_raw(), uplo.into(), n, alpha, x, incx, y, incy, A, lda, batch_count)
+);
+
+impl_rocblas_traits!(
+    Her2StridedBatchedType,
+    Her2StridedBatchedFn,
+    {
+        ffi::rocblas_float_complex => ffi::rocblas_cher2_strided_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_zher2_strided_batched,
+    },
+    rocblas_her2_strided_batched,
+    (handle: &Handle, uplo: Fill, n: i32, alpha: &Self, x: *const Self, incx: i32, stride_x: i64, y: *const Self, incy: i32, stride_y: i64, A: *mut Self, lda: i32, stride_A: i64, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, i32, *const T, *const T, i32, i64, *const T, i32, i64, *mut T, i32, i64, i32),
+    (handle.as_raw(), uplo.into(), n, alpha, x, incx, stride_x, y, incy, stride_y, A, lda, stride_A, batch_count)
+);
+
+pub trait HprType {
+    type ScalarType;
+
+    unsafe fn rocblas_hpr(
+        handle: &Handle,
+        uplo: Fill,
+        n: i32,
+        alpha: &Self::ScalarType,
+        x: *const Self,
+        incx: i32,
+        AP: *mut Self,
+    ) -> Result<()>;
+}
+
+impl HprType for ffi::rocblas_float_complex {
+    type ScalarType = f32;
+
+    unsafe fn rocblas_hpr(
+        handle: &Handle,
+        uplo: Fill,
+        n: i32,
+        alpha: &Self::ScalarType,
+        x: *const Self,
+        incx: i32,
+        AP: *mut Self,
+    ) -> Result<()> {
+        let status =
+            unsafe { ffi::rocblas_chpr(handle.as_raw(), uplo.into(), n, alpha, x, incx, AP) };
+        if status != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(status));
+        }
+        Ok(())
+    }
+}
+
+impl HprType for ffi::rocblas_double_complex {
+    type ScalarType = f64;
+
+    unsafe fn rocblas_hpr(
+        handle: &Handle,
+        uplo: Fill,
+        n: i32,
+        alpha: &Self::ScalarType,
+        x: *const Self,
+        incx: i32,
+        AP: *mut Self,
+    ) -> Result<()> {
+        let status =
+            unsafe { ffi::rocblas_zhpr(handle.as_raw(), uplo.into(), n, alpha, x, incx, AP) };
+        if status != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(status));
+        }
+        Ok(())
+    }
+}
+
+pub trait HprBatchedType {
+    type ScalarType;
+
+    unsafe fn rocblas_hpr_batched(
+        handle: &Handle,
+        uplo: Fill,
+        n: i32,
+        alpha: &Self::ScalarType,
+        x: *const *const Self,
+        incx: i32,
+        AP: *const *mut Self,
+        batch_count: i32,
+    ) -> Result<()>;
+}
+
+impl HprBatchedType for ffi::rocblas_float_complex {
+    type ScalarType = f32;
+
+    unsafe fn rocblas_hpr_batched(
+        handle: &Handle,
+        uplo: Fill,
+        n: i32,
+        alpha: &Self::ScalarType,
+        x: *const *const Self,
+        incx: i32,
+        AP: *const *mut Self,
+        batch_count: i32,
+    ) -> Result<()> {
+        let status = unsafe {
+            ffi::rocblas_chpr_batched(
+                handle.as_raw(),
+                uplo.into(),
+                n,
+                alpha,
+                x,
+                incx,
+                AP,
+                batch_count,
+            )
+        };
+        if status != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(status));
+        }
+        Ok(())
+    }
+}
+
+impl HprBatchedType for ffi::rocblas_double_complex {
+    type ScalarType = f64;
+
+    unsafe fn rocblas_hpr_batched(
+        handle: &Handle,
+        uplo: Fill,
+        n: i32,
+        alpha: &Self::ScalarType,
+        x: *const *const Self,
+        incx: i32,
+        AP: *const *mut Self,
+        batch_count: i32,
+    ) -> Result<()> {
+        let status = unsafe {
+            ffi::rocblas_zhpr_batched(
+                handle.as_raw(),
+                uplo.into(),
+                n,
+                alpha,
+                x,
+                incx,
+                AP,
+                batch_count,
+            )
+        };
+        if status != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(status));
+        }
+        Ok(())
+    }
+}
+
+pub trait HprStridedBatchedType {
+    type ScalarType;
+
+    unsafe fn rocblas_hpr_strided_batched(
+        handle: &Handle,
+        uplo: Fill,
+        n: i32,
+        alpha: &Self::ScalarType,
+        x: *const Self,
+        incx: i32,
+        stride_x: i64,
+        AP: *mut Self,
+        stride_A: i64,
+        batch_count: i32,
+    ) -> Result<()>;
+}
+
+impl HprStridedBatchedType for ffi::rocblas_float_complex {
+    type ScalarType = f32;
+
+    unsafe fn rocblas_hpr_strided_batched(
+        handle: &Handle,
+        uplo: Fill,
+        n: i32,
+        alpha: &Self::ScalarType,
+        x: *const Self,
+        incx: i32,
+        stride_x: i64,
+        AP: *mut Self,
+        stride_A: i64,
+        batch_count: i32,
+    ) -> Result<()> {
+        let status = unsafe {
+            ffi::rocblas_chpr_strided_batched(
+                handle.as_raw(),
+                uplo.into(),
+                n,
+                alpha,
+                x,
+                incx,
+                stride_x,
+                AP,
+                stride_A,
+                batch_count,
+            )
+        };
+        if status != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(status));
+        }
+        Ok(())
+    }
+}
+
+impl HprStridedBatchedType for ffi::rocblas_double_complex {
+    type ScalarType = f64;
+
+    unsafe fn rocblas_hpr_strided_batched(
+        handle: &Handle,
+        uplo: Fill,
+        n: i32,
+        alpha: &Self::ScalarType,
+        x: *const Self,
+        incx: i32,
+        stride_x: i64,
+        AP: *mut Self,
+        stride_A: i64,
+        batch_count: i32,
+    ) -> Result<()> {
+        let status = unsafe {
+            ffi::rocblas_zhpr_strided_batched(
+                handle.as_raw(),
+                uplo.into(),
+                n,
+                alpha,
+                x,
+                incx,
+                stride_x,
+                AP,
+                stride_A,
+                batch_count,
+            )
+        };
+        if status != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(status));
+        }
+        Ok(())
+    }
+}
+
+impl_rocblas_traits!(
+    Hpr2Type,
+    Hpr2Fn,
+    {
+        ffi::rocblas_float_complex => ffi::rocblas_chpr2,
+        ffi::rocblas_double_complex => ffi::rocblas_zhpr2,
+    },
+    rocblas_hpr2,
+    (handle: &Handle, uplo: Fill, n: i32, alpha: &Self, x: *const Self, incx: i32, y: *const Self, incy: i32, AP: *mut Self),
+    (*mut _rocblas_handle, ffi::rocblas_fill, i32, *const T, *const T, i32, *const T, i32, *mut T),
+    (handle.as_raw(), uplo.into(), n, alpha, x, incx, y, incy, AP)
+);
+
+impl_rocblas_traits!(
+    Hpr2BatchedType,
+    Hpr2BatchedFn,
+    {
+        ffi::rocblas_float_complex => ffi::rocblas_chpr2_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_zhpr2_batched,
+    },
+    rocblas_hpr2_batched,
+    (handle: &Handle, uplo: Fill, n: i32, alpha: &Self, x: *const *const Self, incx: i32, y: *const *const Self, incy: i32, AP: *const *mut Self, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, i32, *const T, *const *const T, i32, *const *const T, i32, *const *mut T, i32),
+    (handle.as_raw(), uplo.into(), n, alpha, x, incx, y, incy, AP, batch_count)
+);
+
+impl_rocblas_traits!(
+    Hpr2StridedBatchedType,
+    Hpr2StridedBatchedFn,
+    {
+        ffi::rocblas_float_complex => ffi::rocblas_chpr2_strided_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_zhpr2_strided_batched,
+    },
+    rocblas_hpr2_strided_batched,
+    (handle: &Handle, uplo: Fill, n: i32, alpha: &Self, x: *const Self, incx: i32, stride_x: i64, y: *const Self, incy: i32, stride_y: i64, AP: *mut Self, stride_A: i64, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, i32, *const T, *const T, i32, i64, *const T, i32, i64, *mut T, i64, i32),
+    (handle.as_raw(), uplo.into(), n, alpha, x, incx, stride_x, y, incy, stride_y, AP, stride_A, batch_count)
+);
+
+//==============================================================================
+// TRMV functions - Triangular Matrix-Vector Multiplication
+//==============================================================================
+
+/// Triangular matrix-vector multiplication
+///
+/// x := op(A) * x
+///
+/// where A is an n x n triangular matrix.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether A is upper or lower triangular
+/// * `transa` - Operation op(A) that is non-or (conjugate) transpose
+/// * `diag` - Whether A has a unit diagonal
+/// * `n` - Order of matrix A
+/// * `A` - Buffer storing matrix A
+/// * `lda` - Leading dimension of matrix A
+/// * `x` - Buffer storing vector x
+/// * `incx` - Stride between consecutive elements of x
+pub unsafe fn trmv<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    n: i32,
+    A: *const T,
+    lda: i32,
+    x: *mut T,
+    incx: i32,
+) -> Result<()>
+where
+    T: TrmvType,
+{
+    unsafe { T::rocblas_trmv(handle, uplo, transa, diag, n, A, lda, x, incx) }
+}
+
+/// Batched triangular matrix-vector multiplication
+///
+/// Applies [`trmv`] to each instance of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether A_i are upper or lower triangular
+/// * `transa` - Operation op(A_i) that is non-or (conjugate) transpose
+/// * `diag` - Whether A_i have a unit diagonal
+/// * `n` - Order of matrices A_i
+/// * `A` - Array of pointers to matrices A_i
+/// * `lda` - Leading dimension of matrices A_i
+/// * `x` - Array of pointers to vectors x_i
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn trmv_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    n: i32,
+    A: *const *const T,
+    lda: i32,
+    x: *const *mut T,
+    incx: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: TrmvBatchedType,
+{
+    unsafe { T::rocblas_trmv_batched(handle, uplo, transa, diag, n, A, lda, x, incx, batch_count) }
+}
+
+/// Strided batched triangular matrix-vector multiplication
+///
+/// Applies [`trmv`] to each instance of the batch, with the instances laid
+/// out at fixed strides in single buffers.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether A_i are upper or lower triangular
+/// * `transa` - Operation op(A_i) that is non-or (conjugate) transpose
+/// * `diag` - Whether A_i have a unit diagonal
+/// * `n` - Order of matrices A_i
+/// * `A` - Pointer to the first matrix A_1
+/// * `lda` - Leading dimension of matrices A_i
+/// * `stride_A` - Stride from start of one matrix (A_i) to the next (A_i+1)
+/// * `x` - Pointer to the first vector x_1
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `stride_x` - Stride from start of one vector (x_i) to the next (x_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn trmv_strided_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    n: i32,
+    A: *const T,
+    lda: i32,
+    stride_A: i64,
+    x: *mut T,
+    incx: i32,
+    stride_x: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: TrmvStridedBatchedType,
+{
+    unsafe {
+        T::rocblas_trmv_strided_batched(
+            handle,
+            uplo,
+            transa,
+            diag,
+            n,
+            A,
+            lda,
+            stride_A,
+            x,
+            incx,
+            stride_x,
+            batch_count,
+        )
+    }
+}
+
+impl_rocblas_traits!(
+    TrmvType,
+    TrmvFn,
+    {
+        f32 => ffi::rocblas_strmv,
+        f64 => ffi::rocblas_dtrmv,
+        ffi::rocblas_float_complex => ffi::rocblas_ctrmv,
+        ffi::rocblas_double_complex => ffi::rocblas_ztrmv,
+    },
+    rocblas_trmv,
+    (handle: &Handle, uplo: Fill, transa: Operation, diag: Diagonal, n: i32, A: *const Self, lda: i32, x: *mut Self, incx: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, *const T, i32, *mut T, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), diag.into(), n, A, lda, x, incx)
+);
+
+impl_rocblas_traits!(
+    TrmvBatchedType,
+    TrmvBatchedFn,
+    {
+        f32 => ffi::rocblas_strmv_batched,
+        f64 => ffi::rocblas_dtrmv_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_ctrmv_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_ztrmv_batched,
+    },
+    rocblas_trmv_batched,
+    (handle: &Handle, uplo: Fill, transa: Operation, diag: Diagonal, n: i32, A: *const *const Self, lda: i32, x: *const *mut Self, incx: i32, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, *const *const T, i32, *const *mut T, i32, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), diag.into(), n, A, lda, x, incx, batch_count)
+);
+
+impl_rocblas_traits!(
+    TrmvStridedBatchedType,
+    TrmvStridedBatchedFn,
+    {
+        f32 => ffi::rocblas_strmv_strided_batched,
+        f64 => ffi::rocblas_dtrmv_strided_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_ctrmv_strided_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_ztrmv_strided_batched,
+    },
+    rocblas_trmv_strided_batched,
+    (handle: &Handle, uplo: Fill, transa: Operation, diag: Diagonal, n: i32, A: *const Self, lda: i32, stride_A: i64, x: *mut Self, incx: i32, stride_x: i64, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, *const T, i32, i64, *mut T, i32, i64, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), diag.into(), n, A, lda, stride_A, x, incx, stride_x, batch_count)
+);
+
+//==============================================================================
+// TBMV functions - Triangular Banded Matrix-Vector Multiplication
+//==============================================================================
+
+/// Triangular banded matrix-vector multiplication
+///
+/// x := op(A) * x
+///
+/// where A is an n x n triangular band matrix with k diagonals besides the
+/// main one, stored in banded format.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether A is upper or lower triangular
+/// * `transa` - Operation op(A) that is non-or (conjugate) transpose
+/// * `diag` - Whether A has a unit diagonal
+/// * `n` - Order of matrix A
+/// * `k` - Number of super-diagonals (upper) or sub-diagonals (lower) of A
+/// * `A` - Buffer storing matrix A
+/// * `lda` - Leading dimension of matrix A
+/// * `x` - Buffer storing vector x
+/// * `incx` - Stride between consecutive elements of x
+pub unsafe fn tbmv<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    n: i32,
+    k: i32,
+    A: *const T,
+    lda: i32,
+    x: *mut T,
+    incx: i32,
+) -> Result<()>
+where
+    T: TbmvType,
+{
+    unsafe { T::rocblas_tbmv(handle, uplo, transa, diag, n, k, A, lda, x, incx) }
+}
+
+/// Batched triangular banded matrix-vector multiplication
+///
+/// Applies [`tbmv`] to each instance of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether A_i are upper or lower triangular
+/// * `transa` - Operation op(A_i) that is non-or (conjugate) transpose
+/// * `diag` - Whether A_i have a unit diagonal
+/// * `n` - Order of matrices A_i
+/// * `k` - Number of super-diagonals (upper) or sub-diagonals (lower) of A_i
+/// * `A` - Array of pointers to matrices A_i
+/// * `lda` - Leading dimension of matrices A_i
+/// * `x` - Array of pointers to vectors x_i
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn tbmv_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    n: i32,
+    k: i32,
+    A: *const *const T,
+    lda: i32,
+    x: *const *mut T,
+    incx: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: TbmvBatchedType,
+{
+    unsafe {
+        T::rocblas_tbmv_batched(
+            handle,
+            uplo,
+            transa,
+            diag,
+            n,
+            k,
+            A,
+            lda,
+            x,
+            incx,
+            batch_count,
+        )
+    }
+}
+
+/// Strided batched triangular banded matrix-vector multiplication
+///
+/// Applies [`tbmv`] to each instance of the batch, with the instances laid
+/// out at fixed strides in single buffers.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether A_i are upper or lower triangular
+/// * `transa` - Operation op(A_i) that is non-or (conjugate) transpose
+/// * `diag` - Whether A_i have a unit diagonal
+/// * `n` - Order of matrices A_i
+/// * `k` - Number of super-diagonals (upper) or sub-diagonals (lower) of A_i
+/// * `A` - Pointer to the first matrix A_1
+/// * `lda` - Leading dimension of matrices A_i
+/// * `stride_A` - Stride from start of one matrix (A_i) to the next (A_i+1)
+/// * `x` - Pointer to the first vector x_1
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `stride_x` - Stride from start of one vector (x_i) to the next (x_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn tbmv_strided_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    n: i32,
+    k: i32,
+    A: *const T,
+    lda: i32,
+    stride_A: i64,
+    x: *mut T,
+    incx: i32,
+    stride_x: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: TbmvStridedBatchedType,
+{
+    unsafe {
+        T::rocblas_tbmv_strided_batched(
+            handle,
+            uplo,
+            transa,
+            diag,
+            n,
+            k,
+            A,
+            lda,
+            stride_A,
+            x,
+            incx,
+            stride_x,
+            batch_count,
+        )
+    }
+}
+
+impl_rocblas_traits!(
+    TbmvType,
+    TbmvFn,
+    {
+        f32 => ffi::rocblas_stbmv,
+        f64 => ffi::rocblas_dtbmv,
+        ffi::rocblas_float_complex => ffi::rocblas_ctbmv,
+        ffi::rocblas_double_complex => ffi::rocblas_ztbmv,
+    },
+    rocblas_tbmv,
+    (handle: &Handle, uplo: Fill, transa: Operation, diag: Diagonal, n: i32, k: i32, A: *const Self, lda: i32, x: *mut Self, incx: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, i32, *const T, i32, *mut T, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), diag.into(), n, k, A, lda, x, incx)
+);
+
+impl_rocblas_traits!(
+    TbmvBatchedType,
+    TbmvBatchedFn,
+    {
+        f32 => ffi::rocblas_stbmv_batched,
+        f64 => ffi::rocblas_dtbmv_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_ctbmv_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_ztbmv_batched,
+    },
+    rocblas_tbmv_batched,
+    (handle: &Handle, uplo: Fill, transa: Operation, diag: Diagonal, n: i32, k: i32, A: *const *const Self, lda: i32, x: *const *mut Self, incx: i32, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, i32, *const *const T, i32, *const *mut T, i32, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), diag.into(), n, k, A, lda, x, incx, batch_count)
+);
+
+impl_rocblas_traits!(
+    TbmvStridedBatchedType,
+    TbmvStridedBatchedFn,
+    {
+        f32 => ffi::rocblas_stbmv_strided_batched,
+        f64 => ffi::rocblas_dtbmv_strided_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_ctbmv_strided_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_ztbmv_strided_batched,
+    },
+    rocblas_tbmv_strided_batched,
+    (handle: &Handle, uplo: Fill, transa: Operation, diag: Diagonal, n: i32, k: i32, A: *const Self, lda: i32, stride_A: i64, x: *mut Self, incx: i32, stride_x: i64, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, i32, *const T, i32, i64, *mut T, i32, i64, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), diag.into(), n, k, A, lda, stride_A, x, incx, stride_x, batch_count)
+);
+
+//==============================================================================
+// TPMV functions - Triangular Packed Matrix-Vector Multiplication
+//==============================================================================
+
+/// Triangular packed matrix-vector multiplication
+///
+/// x := op(A) * x
+///
+/// where A is an n x n triangular matrix stored packed column by column.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether A is upper or lower triangular
+/// * `transa` - Operation op(A) that is non-or (conjugate) transpose
+/// * `diag` - Whether A has a unit diagonal
+/// * `n` - Order of matrix A
+/// * `AP` - Packed matrix A
+/// * `x` - Buffer storing vector x
+/// * `incx` - Stride between consecutive elements of x
+pub unsafe fn tpmv<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    n: i32,
+    AP: *const T,
+    x: *mut T,
+    incx: i32,
+) -> Result<()>
+where
+    T: TpmvType,
+{
+    unsafe { T::rocblas_tpmv(handle, uplo, transa, diag, n, AP, x, incx) }
+}
+
+/// Batched triangular packed matrix-vector multiplication
+///
+/// Applies [`tpmv`] to each instance of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether A_i are upper or lower triangular
+/// * `transa` - Operation op(A_i) that is non-or (conjugate) transpose
+/// * `diag` - Whether A_i have a unit diagonal
+/// * `n` - Order of matrices A_i
+/// * `AP` - Array of pointers to packed matrices A_i
+/// * `x` - Array of pointers to vectors x_i
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn tpmv_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    n: i32,
+    AP: *const *const T,
+    x: *const *mut T,
+    incx: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: TpmvBatchedType,
+{
+    unsafe { T::rocblas_tpmv_batched(handle, uplo, transa, diag, n, AP, x, incx, batch_count) }
+}
+
+/// Strided batched triangular packed matrix-vector multiplication
+///
+/// Applies [`tpmv`] to each instance of the batch, with the instances laid
+/// out at fixed strides in single buffers.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether A_i are upper or lower triangular
+/// * `transa` - Operation op(A_i) that is non-or (conjugate) transpose
+/// * `diag` - Whether A_i have a unit diagonal
+/// * `n` - Order of matrices A_i
+/// * `AP` - Pointer to the first packed matrix A_1
+/// * `stride_A` - Stride from start of one packed matrix (A_i) to the next (A_i+1)
+/// * `x` - Pointer to the first vector x_1
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `stride_x` - Stride from start of one vector (x_i) to the next (x_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn tpmv_strided_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    n: i32,
+    AP: *const T,
+    stride_A: i64,
+    x: *mut T,
+    incx: i32,
+    stride_x: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: TpmvStridedBatchedType,
+{
+    unsafe {
+        T::rocblas_tpmv_strided_batched(
+            handle,
+            uplo,
+            transa,
+            diag,
+            n,
+            AP,
+            stride_A,
+            x,
+            incx,
+            stride_x,
+            batch_count,
+        )
+    }
+}
+
+impl_rocblas_traits!(
+    TpmvType,
+    TpmvFn,
+    {
+        f32 => ffi::rocblas_stpmv,
+        f64 => ffi::rocblas_dtpmv,
+        ffi::rocblas_float_complex => ffi::rocblas_ctpmv,
+        ffi::rocblas_double_complex => ffi::rocblas_ztpmv,
+    },
+    rocblas_tpmv,
+    (handle: &Handle, uplo: Fill, transa: Operation, diag: Diagonal, n: i32, AP: *const Self, x: *mut Self, incx: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, *const T, *mut T, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), diag.into(), n, AP, x, incx)
+);
+
+impl_rocblas_traits!(
+    TpmvBatchedType,
+    TpmvBatchedFn,
+    {
+        f32 => ffi::rocblas_stpmv_batched,
+        f64 => ffi::rocblas_dtpmv_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_ctpmv_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_ztpmv_batched,
+    },
+    rocblas_tpmv_batched,
+    (handle: &Handle, uplo: Fill, transa: Operation, diag: Diagonal, n: i32, AP: *const *const Self, x: *const *mut Self, incx: i32, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, *const *const T, *const *mut T, i32, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), diag.into(), n, AP, x, incx, batch_count)
+);
+
+impl_rocblas_traits!(
+    TpmvStridedBatchedType,
+    TpmvStridedBatchedFn,
+    {
+        f32 => ffi::rocblas_stpmv_strided_batched,
+        f64 => ffi::rocblas_dtpmv_strided_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_ctpmv_strided_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_ztpmv_strided_batched,
+    },
+    rocblas_tpmv_strided_batched,
+    (handle: &Handle, uplo: Fill, transa: Operation, diag: Diagonal, n: i32, AP: *const Self, stride_A: i64, x: *mut Self, incx: i32, stride_x: i64, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, *const T, i64, *mut T, i32, i64, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), diag.into(), n, AP, stride_A, x, incx, stride_x, batch_count)
+);
+
+//==============================================================================
+// TRSV functions - Triangular Solve
+//==============================================================================
+
+/// Triangular solve
+///
+/// Solves op(A) * x = b for x, overwriting b
+///
+/// where A is an n x n triangular matrix and b is passed in x.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether A is upper or lower triangular
+/// * `transa` - Operation op(A) that is non-or (conjugate) transpose
+/// * `diag` - Whether A has a unit diagonal
+/// * `n` - Order of matrix A
+/// * `A` - Buffer storing matrix A
+/// * `lda` - Leading dimension of matrix A
+/// * `x` - Buffer storing vector x
+/// * `incx` - Stride between consecutive elements of x
+pub unsafe fn trsv<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    n: i32,
+    A: *const T,
+    lda: i32,
+    x: *mut T,
+    incx: i32,
+) -> Result<()>
+where
+    T: TrsvType,
+{
+    unsafe { T::rocblas_trsv(handle, uplo, transa, diag, n, A, lda, x, incx) }
+}
+
+/// Batched triangular solve
+///
+/// Applies [`trsv`] to each instance of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether A_i are upper or lower triangular
+/// * `transa` - Operation op(A_i) that is non-or (conjugate) transpose
+/// * `diag` - Whether A_i have a unit diagonal
+/// * `n` - Order of matrices A_i
+/// * `A` - Array of pointers to matrices A_i
+/// * `lda` - Leading dimension of matrices A_i
+/// * `x` - Array of pointers to vectors x_i
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn trsv_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    n: i32,
+    A: *const *const T,
+    lda: i32,
+    x: *const *mut T,
+    incx: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: TrsvBatchedType,
+{
+    unsafe { T::rocblas_trsv_batched(handle, uplo, transa, diag, n, A, lda, x, incx, batch_count) }
+}
+
+/// Strided batched triangular solve
+///
+/// Applies [`trsv`] to each instance of the batch, with the instances laid
+/// out at fixed strides in single buffers.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether A_i are upper or lower triangular
+/// * `transa` - Operation op(A_i) that is non-or (conjugate) transpose
+/// * `diag` - Whether A_i have a unit diagonal
+/// * `n` - Order of matrices A_i
+/// * `A` - Pointer to the first matrix A_1
+/// * `lda` - Leading dimension of matrices A_i
+/// * `stride_A` - Stride from start of one matrix (A_i) to the next (A_i+1)
+/// * `x` - Pointer to the first vector x_1
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `stride_x` - Stride from start of one vector (x_i) to the next (x_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn trsv_strided_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    n: i32,
+    A: *const T,
+    lda: i32,
+    stride_A: i64,
+    x: *mut T,
+    incx: i32,
+    stride_x: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: TrsvStridedBatchedType,
+{
+    unsafe {
+        T::rocblas_trsv_strided_batched(
+            handle,
+            uplo,
+            transa,
+            diag,
+            n,
+            A,
+            lda,
+            stride_A,
+            x,
+            incx,
+            stride_x,
+            batch_count,
+        )
+    }
+}
+
+impl_rocblas_traits!(
+    TrsvType,
+    TrsvFn,
+    {
+        f32 => ffi::rocblas_strsv,
+        f64 => ffi::rocblas_dtrsv,
+        ffi::rocblas_float_complex => ffi::rocblas_ctrsv,
+        ffi::rocblas_double_complex => ffi::rocblas_ztrsv,
+    },
+    rocblas_trsv,
+    (handle: &Handle, uplo: Fill, transa: Operation, diag: Diagonal, n: i32, A: *const Self, lda: i32, x: *mut Self, incx: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, *const T, i32, *mut T, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), diag.into(), n, A, lda, x, incx)
+);
+
+impl_rocblas_traits!(
+    TrsvBatchedType,
+    TrsvBatchedFn,
+    {
+        f32 => ffi::rocblas_strsv_batched,
+        f64 => ffi::rocblas_dtrsv_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_ctrsv_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_ztrsv_batched,
+    },
+    rocblas_trsv_batched,
+    (handle: &Handle, uplo: Fill, transa: Operation, diag: Diagonal, n: i32, A: *const *const Self, lda: i32, x: *const *mut Self, incx: i32, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, *const *const T, i32, *const *mut T, i32, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), diag.into(), n, A, lda, x, incx, batch_count)
+);
+
+impl_rocblas_traits!(
+    TrsvStridedBatchedType,
+    TrsvStridedBatchedFn,
+    {
+        f32 => ffi::rocblas_strsv_strided_batched,
+        f64 => ffi::rocblas_dtrsv_strided_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_ctrsv_strided_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_ztrsv_strided_batched,
+    },
+    rocblas_trsv_strided_batched,
+    (handle: &Handle, uplo: Fill, transa: Operation, diag: Diagonal, n: i32, A: *const Self, lda: i32, stride_A: i64, x: *mut Self, incx: i32, stride_x: i64, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, *const T, i32, i64, *mut T, i32, i64, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), diag.into(), n, A, lda, stride_A, x, incx, stride_x, batch_count)
+);
+
+//==============================================================================
+// TBSV functions - Triangular Banded Solve
+//==============================================================================
+
+/// Triangular banded solve
+///
+/// Solves op(A) * x = b for x, overwriting b
+///
+/// where A is an n x n triangular band matrix with k diagonals besides the
+/// main one, stored in banded format, and b is passed in x.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether A is upper or lower triangular
+/// * `transa` - Operation op(A) that is non-or (conjugate) transpose
+/// * `diag` - Whether A has a unit diagonal
+/// * `n` - Order of matrix A
+/// * `k` - Number of super-diagonals (upper) or sub-diagonals (lower) of A
+/// * `A` - Buffer storing matrix A
+/// * `lda` - Leading dimension of matrix A
+/// * `x` - Buffer storing vector x
+/// * `incx` - Stride between consecutive elements of x
+pub unsafe fn tbsv<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    n: i32,
+    k: i32,
+    A: *const T,
+    lda: i32,
+    x: *mut T,
+    incx: i32,
+) -> Result<()>
+where
+    T: TbsvType,
+{
+    unsafe { T::rocblas_tbsv(handle, uplo, transa, diag, n, k, A, lda, x, incx) }
+}
+
+/// Batched triangular banded solve
+///
+/// Applies [`tbsv`] to each instance of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether A_i are upper or lower triangular
+/// * `transa` - Operation op(A_i) that is non-or (conjugate) transpose
+/// * `diag` - Whether A_i have a unit diagonal
+/// * `n` - Order of matrices A_i
+/// * `k` - Number of super-diagonals (upper) or sub-diagonals (lower) of A_i
+/// * `A` - Array of pointers to matrices A_i
+/// * `lda` - Leading dimension of matrices A_i
+/// * `x` - Array of pointers to vectors x_i
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn tbsv_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    n: i32,
+    k: i32,
+    A: *const *const T,
+    lda: i32,
+    x: *const *mut T,
+    incx: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: TbsvBatchedType,
+{
+    unsafe {
+        T::rocblas_tbsv_batched(
+            handle,
+            uplo,
+            transa,
+            diag,
+            n,
+            k,
+            A,
+            lda,
+            x,
+            incx,
+            batch_count,
+        )
+    }
+}
+
+/// Strided batched triangular banded solve
+///
+/// Applies [`tbsv`] to each instance of the batch, with the instances laid
+/// out at fixed strides in single buffers.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether A_i are upper or lower triangular
+/// * `transa` - Operation op(A_i) that is non-or (conjugate) transpose
+/// * `diag` - Whether A_i have a unit diagonal
+/// * `n` - Order of matrices A_i
+/// * `k` - Number of super-diagonals (upper) or sub-diagonals (lower) of A_i
+/// * `A` - Pointer to the first matrix A_1
+/// * `lda` - Leading dimension of matrices A_i
+/// * `stride_A` - Stride from start of one matrix (A_i) to the next (A_i+1)
+/// * `x` - Pointer to the first vector x_1
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `stride_x` - Stride from start of one vector (x_i) to the next (x_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn tbsv_strided_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    n: i32,
+    k: i32,
+    A: *const T,
+    lda: i32,
+    stride_A: i64,
+    x: *mut T,
+    incx: i32,
+    stride_x: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: TbsvStridedBatchedType,
+{
+    unsafe {
+        T::rocblas_tbsv_strided_batched(
+            handle,
+            uplo,
+            transa,
+            diag,
+            n,
+            k,
+            A,
+            lda,
+            stride_A,
+            x,
+            incx,
+            stride_x,
+            batch_count,
+        )
+    }
+}
+
+impl_rocblas_traits!(
+    TbsvType,
+    TbsvFn,
+    {
+        f32 => ffi::rocblas_stbsv,
+        f64 => ffi::rocblas_dtbsv,
+        ffi::rocblas_float_complex => ffi::rocblas_ctbsv,
+        ffi::rocblas_double_complex => ffi::rocblas_ztbsv,
+    },
+    rocblas_tbsv,
+    (handle: &Handle, uplo: Fill, transa: Operation, diag: Diagonal, n: i32, k: i32, A: *const Self, lda: i32, x: *mut Self, incx: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, i32, *const T, i32, *mut T, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), diag.into(), n, k, A, lda, x, incx)
+);
+
+impl_rocblas_traits!(
+    TbsvBatchedType,
+    TbsvBatchedFn,
+    {
+        f32 => ffi::rocblas_stbsv_batched,
+        f64 => ffi::rocblas_dtbsv_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_ctbsv_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_ztbsv_batched,
+    },
+    rocblas_tbsv_batched,
+    (handle: &Handle, uplo: Fill, transa: Operation, diag: Diagonal, n: i32, k: i32, A: *const *const Self, lda: i32, x: *const *mut Self, incx: i32, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, i32, *const *const T, i32, *const *mut T, i32, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), diag.into(), n, k, A, lda, x, incx, batch_count)
+);
+
+impl_rocblas_traits!(
+    TbsvStridedBatchedType,
+    TbsvStridedBatchedFn,
+    {
+        f32 => ffi::rocblas_stbsv_strided_batched,
+        f64 => ffi::rocblas_dtbsv_strided_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_ctbsv_strided_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_ztbsv_strided_batched,
+    },
+    rocblas_tbsv_strided_batched,
+    (handle: &Handle, uplo: Fill, transa: Operation, diag: Diagonal, n: i32, k: i32, A: *const Self, lda: i32, stride_A: i64, x: *mut Self, incx: i32, stride_x: i64, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, i32, *const T, i32, i64, *mut T, i32, i64, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), diag.into(), n, k, A, lda, stride_A, x, incx, stride_x, batch_count)
+);
+
+//==============================================================================
+// TPSV functions - Triangular Packed Solve
+//==============================================================================
+
+/// Triangular packed solve
+///
+/// Solves op(A) * x = b for x, overwriting b
+///
+/// where A is an n x n triangular matrix stored packed column by column,
+/// and b is passed in x.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether A is upper or lower triangular
+/// * `transa` - Operation op(A) that is non-or (conjugate) transpose
+/// * `diag` - Whether A has a unit diagonal
+/// * `n` - Order of matrix A
+/// * `AP` - Packed matrix A
+/// * `x` - Buffer storing vector x
+/// * `incx` - Stride between consecutive elements of x
+pub unsafe fn tpsv<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    n: i32,
+    AP: *const T,
+    x: *mut T,
+    incx: i32,
+) -> Result<()>
+where
+    T: TpsvType,
+{
+    unsafe { T::rocblas_tpsv(handle, uplo, transa, diag, n, AP, x, incx) }
+}
+
+/// Batched triangular packed solve
+///
+/// Applies [`tpsv`] to each instance of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether A_i are upper or lower triangular
+/// * `transa` - Operation op(A_i) that is non-or (conjugate) transpose
+/// * `diag` - Whether A_i have a unit diagonal
+/// * `n` - Order of matrices A_i
+/// * `AP` - Array of pointers to packed matrices A_i
+/// * `x` - Array of pointers to vectors x_i
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn tpsv_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    n: i32,
+    AP: *const *const T,
+    x: *const *mut T,
+    incx: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: TpsvBatchedType,
+{
+    unsafe { T::rocblas_tpsv_batched(handle, uplo, transa, diag, n, AP, x, incx, batch_count) }
+}
+
+/// Strided batched triangular packed solve
+///
+/// Applies [`tpsv`] to each instance of the batch, with the instances laid
+/// out at fixed strides in single buffers.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Whether A_i are upper or lower triangular
+/// * `transa` - Operation op(A_i) that is non-or (conjugate) transpose
+/// * `diag` - Whether A_i have a unit diagonal
+/// * `n` - Order of matrices A_i
+/// * `AP` - Pointer to the first packed matrix A_1
+/// * `stride_A` - Stride from start of one packed matrix (A_i) to the next (A_i+1)
+/// * `x` - Pointer to the first vector x_1
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `stride_x` - Stride from start of one vector (x_i) to the next (x_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn tpsv_strided_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    n: i32,
+    AP: *const T,
+    stride_A: i64,
+    x: *mut T,
+    incx: i32,
+    stride_x: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: TpsvStridedBatchedType,
+{
+    unsafe {
+        T::rocblas_tpsv_strided_batched(
+            handle,
+            uplo,
+            transa,
+            diag,
+            n,
+            AP,
+            stride_A,
+            x,
+            incx,
+            stride_x,
+            batch_count,
+        )
+    }
+}
+
+impl_rocblas_traits!(
+    TpsvType,
+    TpsvFn,
+    {
+        f32 => ffi::rocblas_stpsv,
+        f64 => ffi::rocblas_dtpsv,
+        ffi::rocblas_float_complex => ffi::rocblas_ctpsv,
+        ffi::rocblas_double_complex => ffi::rocblas_ztpsv,
+    },
+    rocblas_tpsv,
+    (handle: &Handle, uplo: Fill, transa: Operation, diag: Diagonal, n: i32, AP: *const Self, x: *mut Self, incx: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, *const T, *mut T, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), diag.into(), n, AP, x, incx)
+);
+
+impl_rocblas_traits!(
+    TpsvBatchedType,
+    TpsvBatchedFn,
+    {
+        f32 => ffi::rocblas_stpsv_batched,
+        f64 => ffi::rocblas_dtpsv_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_ctpsv_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_ztpsv_batched,
+    },
+    rocblas_tpsv_batched,
+    (handle: &Handle, uplo: Fill, transa: Operation, diag: Diagonal, n: i32, AP: *const *const Self, x: *const *mut Self, incx: i32, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, *const *const T, *const *mut T, i32, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), diag.into(), n, AP, x, incx, batch_count)
+);
+
+impl_rocblas_traits!(
+    TpsvStridedBatchedType,
+    TpsvStridedBatchedFn,
+    {
+        f32 => ffi::rocblas_stpsv_strided_batched,
+        f64 => ffi::rocblas_dtpsv_strided_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_ctpsv_strided_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_ztpsv_strided_batched,
+    },
+    rocblas_tpsv_strided_batched,
+    (handle: &Handle, uplo: Fill, transa: Operation, diag: Diagonal, n: i32, AP: *const Self, stride_A: i64, x: *mut Self, incx: i32, stride_x: i64, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, *const T, i64, *mut T, i32, i64, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), diag.into(), n, AP, stride_A, x, incx, stride_x, batch_count)
+);
+
+//==============================================================================
+// SPMV functions - Symmetric Packed Matrix-Vector Multiplication
+//==============================================================================
+
+/// Symmetric packed matrix-vector multiplication
+///
+/// y := alpha * A * x + beta * y
+///
+/// where A is an n x n symmetric matrix whose `uplo` triangle is stored
+/// packed column by column.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Which triangle of A is stored
+/// * `n` - Order of matrix A
+/// * `alpha` - Scalar alpha
+/// * `AP` - Packed matrix A
+/// * `x` - Buffer storing vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `beta` - Scalar beta
+/// * `y` - Buffer storing vector y
+/// * `incy` - Stride between consecutive elements of y
+pub unsafe fn spmv<T>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    alpha: &T,
+    AP: *const T,
+    x: *const T,
+    incx: i32,
+    beta: &T,
+    y: *mut T,
+    incy: i32,
+) -> Result<()>
+where
+    T: SpmvType,
+{
+    unsafe { T::rocblas_spmv(handle, uplo, n, alpha, AP, x, incx, beta, y, incy) }
+}
+
+/// Batched symmetric packed matrix-vector multiplication
+///
+/// Applies [`spmv`] to each instance of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Which triangle of A_i is stored
+/// * `n` - Order of matrices A_i
+/// * `alpha` - Scalar alpha
+/// * `AP` - Array of pointers to packed matrices A_i
+/// * `x` - Array of pointers to vectors x_i
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `beta` - Scalar beta
+/// * `y` - Array of pointers to vectors y_i
+/// * `incy` - Stride between consecutive elements of y_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn spmv_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    alpha: &T,
+    AP: *const *const T,
+    x: *const *const T,
+    incx: i32,
+    beta: &T,
+    y: *const *mut T,
+    incy: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: SpmvBatchedType,
+{
+    unsafe {
+        T::rocblas_spmv_batched(
+            handle,
+            uplo,
+            n,
+            alpha,
+            AP,
+            x,
+            incx,
+            beta,
+            y,
+            incy,
+            batch_count,
+        )
+    }
+}
+
+/// Strided batched symmetric packed matrix-vector multiplication
+///
+/// Applies [`spmv`] to each instance of the batch, with the instances laid
+/// out at fixed strides in single buffers.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Which triangle of A_i is stored
+/// * `n` - Order of matrices A_i
+/// * `alpha` - Scalar alpha
+/// * `AP` - Pointer to the first packed matrix A_1
+/// * `stride_A` - Stride from start of one packed matrix (A_i) to the next (A_i+1)
+/// * `x` - Pointer to the first vector x_1
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `stride_x` - Stride from start of one vector (x_i) to the next (x_i+1)
+/// * `beta` - Scalar beta
+/// * `y` - Pointer to the first vector y_1
+/// * `incy` - Stride between consecutive elements of y_i
+/// * `stride_y` - Stride from start of one vector (y_i) to the next (y_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn spmv_strided_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    alpha: &T,
+    AP: *const T,
+    stride_A: i64,
+    x: *const T,
+    incx: i32,
+    stride_x: i64,
+    beta: &T,
+    y: *mut T,
+    incy: i32,
+    stride_y: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: SpmvStridedBatchedType,
+{
+    unsafe {
+        T::rocblas_spmv_strided_batched(
+            handle,
+            uplo,
+            n,
+            alpha,
+            AP,
+            stride_A,
+            x,
+            incx,
+            stride_x,
+            beta,
+            y,
+            incy,
+            stride_y,
+            batch_count,
+        )
+    }
+}
+
+impl_rocblas_traits!(
+    SpmvType,
+    SpmvFn,
+    {
+        f32 => ffi::rocblas_sspmv,
+        f64 => ffi::rocblas_dspmv,
+    },
+    rocblas_spmv,
+    (handle: &Handle, uplo: Fill, n: i32, alpha: &Self, AP: *const Self, x: *const Self, incx: i32, beta: &Self, y: *mut Self, incy: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, i32, *const T, *const T, *const T, i32, *const T, *mut T, i32),
+    (handle.as_raw(), uplo.into(), n, alpha, AP, x, incx, beta, y, incy)
+);
+
+impl_rocblas_traits!(
+    SpmvBatchedType,
+    SpmvBatchedFn,
+    {
+        f32 => ffi::rocblas_sspmv_batched,
+        f64 => ffi::rocblas_dspmv_batched,
+    },
+    rocblas_spmv_batched,
+    (handle: &Handle, uplo: Fill, n: i32, alpha: &Self, AP: *const *const Self, x: *const *const Self, incx: i32, beta: &Self, y: *const *mut Self, incy: i32, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, i32, *const T, *const *const T, *const *const T, i32, *const T, *const *mut T, i32, i32),
+    (handle.as_raw(), uplo.into(), n, alpha, AP, x, incx, beta, y, incy, batch_count)
+);
+
+impl_rocblas_traits!(
+    SpmvStridedBatchedType,
+    SpmvStridedBatchedFn,
+    {
+        f32 => ffi::rocblas_sspmv_strided_batched,
+        f64 => ffi::rocblas_dspmv_strided_batched,
+    },
+    rocblas_spmv_strided_batched,
+    (handle: &Handle, uplo: Fill, n: i32, alpha: &Self, AP: *const Self, stride_A: i64, x: *const Self, incx: i32, stride_x: i64, beta: &Self, y: *mut Self, incy: i32, stride_y: i64, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, i32, *const T, *const T, i64, *const T, i32, i64, *const T, *mut T, i32, i64, i32),
+    (handle.as_raw(), uplo.into(), n, alpha, AP, stride_A, x, incx, stride_x, beta, y, incy, stride_y, batch_count)
+);
+
+//==============================================================================
+// SBMV functions - Symmetric Banded Matrix-Vector Multiplication
+//==============================================================================
+
+/// Symmetric banded matrix-vector multiplication
+///
+/// y := alpha * A * x + beta * y
+///
+/// where A is an n x n symmetric band matrix with k super-diagonals, of
+/// which the `uplo` triangle is stored in banded format.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Which triangle of A is stored
+/// * `n` - Order of matrix A
+/// * `k` - Number of super-diagonals (upper) or sub-diagonals (lower) of A
+/// * `alpha` - Scalar alpha
+/// * `A` - Buffer storing matrix A
+/// * `lda` - Leading dimension of matrix A
+/// * `x` - Buffer storing vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `beta` - Scalar beta
+/// * `y` - Buffer storing vector y
+/// * `incy` - Stride between consecutive elements of y
+pub unsafe fn sbmv<T>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    k: i32,
+    alpha: &T,
+    A: *const T,
+    lda: i32,
+    x: *const T,
+    incx: i32,
+    beta: &T,
+    y: *mut T,
+    incy: i32,
+) -> Result<()>
+where
+    T: SbmvType,
+{
+    unsafe { T::rocblas_sbmv(handle, uplo, n, k, alpha, A, lda, x, incx, beta, y, incy) }
+}
+
+/// Batched symmetric banded matrix-vector multiplication
+///
+/// Applies [`sbmv`] to each instance of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Which triangle of A_i is stored
+/// * `n` - Order of matrices A_i
+/// * `k` - Number of super-diagonals (upper) or sub-diagonals (lower) of A_i
+/// * `alpha` - Scalar alpha
+/// * `A` - Array of pointers to matrices A_i
+/// * `lda` - Leading dimension of matrices A_i
+/// * `x` - Array of pointers to vectors x_i
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `beta` - Scalar beta
+/// * `y` - Array of pointers to vectors y_i
+/// * `incy` - Stride between consecutive elements of y_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn sbmv_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    k: i32,
+    alpha: &T,
+    A: *const *const T,
+    lda: i32,
+    x: *const *const T,
+    incx: i32,
+    beta: &T,
+    y: *const *mut T,
+    incy: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: SbmvBatchedType,
+{
+    unsafe {
+        T::rocblas_sbmv_batched(
+            handle,
+            uplo,
+            n,
+            k,
+            alpha,
+            A,
+            lda,
+            x,
+            incx,
+            beta,
+            y,
+            incy,
+            batch_count,
+        )
+    }
+}
+
+/// Strided batched symmetric banded matrix-vector multiplication
+///
+/// Applies [`sbmv`] to each instance of the batch, with the instances laid
+/// out at fixed strides in single buffers.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Which triangle of A_i is stored
+/// * `n` - Order of matrices A_i
+/// * `k` - Number of super-diagonals (upper) or sub-diagonals (lower) of A_i
+/// * `alpha` - Scalar alpha
+/// * `A` - Pointer to the first matrix A_1
+/// * `lda` - Leading dimension of matrices A_i
+/// * `stride_A` - Stride from start of one matrix (A_i) to the next (A_i+1)
+/// * `x` - Pointer to the first vector x_1
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `stride_x` - Stride from start of one vector (x_i) to the next (x_i+1)
+/// * `beta` - Scalar beta
+/// * `y` - Pointer to the first vector y_1
+/// * `incy` - Stride between consecutive elements of y_i
+/// * `stride_y` - Stride from start of one vector (y_i) to the next (y_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn sbmv_strided_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    k: i32,
+    alpha: &T,
+    A: *const T,
+    lda: i32,
+    stride_A: i64,
+    x: *const T,
+    incx: i32,
+    stride_x: i64,
+    beta: &T,
+    y: *mut T,
+    incy: i32,
+    stride_y: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: SbmvStridedBatchedType,
+{
+    unsafe {
+        T::rocblas_sbmv_strided_batched(
+            handle,
+            uplo,
+            n,
+            k,
+            alpha,
+            A,
+            lda,
+            stride_A,
+            x,
+            incx,
+            stride_x,
+            beta,
+            y,
+            incy,
+            stride_y,
+            batch_count,
+        )
+    }
+}
+
+impl_rocblas_traits!(
+    SbmvType,
+    SbmvFn,
+    {
+        f32 => ffi::rocblas_ssbmv,
+        f64 => ffi::rocblas_dsbmv,
+    },
+    rocblas_sbmv,
+    (handle: &Handle, uplo: Fill, n: i32, k: i32, alpha: &Self, A: *const Self, lda: i32, x: *const Self, incx: i32, beta: &Self, y: *mut Self, incy: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, i32, i32, *const T, *const T, i32, *const T, i32, *const T, *mut T, i32),
+    (handle.as_raw(), uplo.into(), n, k, alpha, A, lda, x, incx, beta, y, incy)
+);
+
+impl_rocblas_traits!(
+    SbmvBatchedType,
+    SbmvBatchedFn,
+    {
+        f32 => ffi::rocblas_ssbmv_batched,
+        f64 => ffi::rocblas_dsbmv_batched,
+    },
+    rocblas_sbmv_batched,
+    (handle: &Handle, uplo: Fill, n: i32, k: i32, alpha: &Self, A: *const *const Self, lda: i32, x: *const *const Self, incx: i32, beta: &Self, y: *const *mut Self, incy: i32, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, i32, i32, *const T, *const *const T, i32, *const *const T, i32, *const T, *const *mut T, i32, i32),
+    (handle.as_raw(), uplo.into(), n, k, alpha, A, lda, x, incx, beta, y, incy, batch_count)
+);
+
+impl_rocblas_traits!(
+    SbmvStridedBatchedType,
+    SbmvStridedBatchedFn,
+    {
+        f32 => ffi::rocblas_ssbmv_strided_batched,
+        f64 => ffi::rocblas_dsbmv_strided_batched,
+    },
+    rocblas_sbmv_strided_batched,
+    (handle: &Handle, uplo: Fill, n: i32, k: i32, alpha: &Self, A: *const Self, lda: i32, stride_A: i64, x: *const Self, incx: i32, stride_x: i64, beta: &Self, y: *mut Self, incy: i32, stride_y: i64, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, i32, i32, *const T, *const T, i32, i64, *const T, i32, i64, *const T, *mut T, i32, i64, i32),
+    (handle.as_raw(), uplo.into(), n, k, alpha, A, lda, stride_A, x, incx, stride_x, beta, y, incy, stride_y, batch_count)
+);
+
+//==============================================================================
+// HPMV functions - Hermitian Packed Matrix-Vector Multiplication
+//==============================================================================
+
+/// Hermitian packed matrix-vector multiplication
+///
+/// y := alpha * A * x + beta * y
+///
+/// where A is an n x n Hermitian matrix whose `uplo` triangle is stored
+/// packed column by column.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Which triangle of A is stored
+/// * `n` - Order of matrix A
+/// * `alpha` - Scalar alpha
+/// * `AP` - Packed matrix A
+/// * `x` - Buffer storing vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `beta` - Scalar beta
+/// * `y` - Buffer storing vector y
+/// * `incy` - Stride between consecutive elements of y
+pub unsafe fn hpmv<T>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    alpha: &T,
+    AP: *const T,
+    x: *const T,
+    incx: i32,
+    beta: &T,
+    y: *mut T,
+    incy: i32,
+) -> Result<()>
+where
+    T: HpmvType,
+{
+    unsafe { T::rocblas_hpmv(handle, uplo, n, alpha, AP, x, incx, beta, y, incy) }
+}
+
+/// Batched hermitian packed matrix-vector multiplication
+///
+/// Applies [`hpmv`] to each instance of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Which triangle of A_i is stored
+/// * `n` - Order of matrices A_i
+/// * `alpha` - Scalar alpha
+/// * `AP` - Array of pointers to packed matrices A_i
+/// * `x` - Array of pointers to vectors x_i
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `beta` - Scalar beta
+/// * `y` - Array of pointers to vectors y_i
+/// * `incy` - Stride between consecutive elements of y_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn hpmv_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    alpha: &T,
+    AP: *const *const T,
+    x: *const *const T,
+    incx: i32,
+    beta: &T,
+    y: *const *mut T,
+    incy: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: HpmvBatchedType,
+{
+    unsafe {
+        T::rocblas_hpmv_batched(
+            handle,
+            uplo,
+            n,
+            alpha,
+            AP,
+            x,
+            incx,
+            beta,
+            y,
+            incy,
+            batch_count,
+        )
+    }
+}
+
+/// Strided batched hermitian packed matrix-vector multiplication
+///
+/// Applies [`hpmv`] to each instance of the batch, with the instances laid
+/// out at fixed strides in single buffers.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Which triangle of A_i is stored
+/// * `n` - Order of matrices A_i
+/// * `alpha` - Scalar alpha
+/// * `AP` - Pointer to the first packed matrix A_1
+/// * `stride_A` - Stride from start of one packed matrix (A_i) to the next (A_i+1)
+/// * `x` - Pointer to the first vector x_1
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `stride_x` - Stride from start of one vector (x_i) to the next (x_i+1)
+/// * `beta` - Scalar beta
+/// * `y` - Pointer to the first vector y_1
+/// * `incy` - Stride between consecutive elements of y_i
+/// * `stride_y` - Stride from start of one vector (y_i) to the next (y_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn hpmv_strided_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    alpha: &T,
+    AP: *const T,
+    stride_A: i64,
+    x: *const T,
+    incx: i32,
+    stride_x: i64,
+    beta: &T,
+    y: *mut T,
+    incy: i32,
+    stride_y: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: HpmvStridedBatchedType,
+{
+    unsafe {
+        T::rocblas_hpmv_strided_batched(
+            handle,
+            uplo,
+            n,
+            alpha,
+            AP,
+            stride_A,
+            x,
+            incx,
+            stride_x,
+            beta,
+            y,
+            incy,
+            stride_y,
+            batch_count,
+        )
+    }
+}
+
+impl_rocblas_traits!(
+    HpmvType,
+    HpmvFn,
+    {
+        ffi::rocblas_float_complex => ffi::rocblas_chpmv,
+        ffi::rocblas_double_complex => ffi::rocblas_zhpmv,
+    },
+    rocblas_hpmv,
+    (handle: &Handle, uplo: Fill, n: i32, alpha: &Self, AP: *const Self, x: *const Self, incx: i32, beta: &Self, y: *mut Self, incy: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, i32, *const T, *const T, *const T, i32, *const T, *mut T, i32),
+    (handle.as_raw(), uplo.into(), n, alpha, AP, x, incx, beta, y, incy)
+);
+
+impl_rocblas_traits!(
+    HpmvBatchedType,
+    HpmvBatchedFn,
+    {
+        ffi::rocblas_float_complex => ffi::rocblas_chpmv_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_zhpmv_batched,
+    },
+    rocblas_hpmv_batched,
+    (handle: &Handle, uplo: Fill, n: i32, alpha: &Self, AP: *const *const Self, x: *const *const Self, incx: i32, beta: &Self, y: *const *mut Self, incy: i32, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, i32, *const T, *const *const T, *const *const T, i32, *const T, *const *mut T, i32, i32),
+    (handle.as_raw(), uplo.into(), n, alpha, AP, x, incx, beta, y, incy, batch_count)
+);
+
+impl_rocblas_traits!(
+    HpmvStridedBatchedType,
+    HpmvStridedBatchedFn,
+    {
+        ffi::rocblas_float_complex => ffi::rocblas_chpmv_strided_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_zhpmv_strided_batched,
+    },
+    rocblas_hpmv_strided_batched,
+    (handle: &Handle, uplo: Fill, n: i32, alpha: &Self, AP: *const Self, stride_A: i64, x: *const Self, incx: i32, stride_x: i64, beta: &Self, y: *mut Self, incy: i32, stride_y: i64, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, i32, *const T, *const T, i64, *const T, i32, i64, *const T, *mut T, i32, i64, i32),
+    (handle.as_raw(), uplo.into(), n, alpha, AP, stride_A, x, incx, stride_x, beta, y, incy, stride_y, batch_count)
+);
@@ -1,11 +1,14 @@
 // src/rocblas/level3.rs
 
+use crate::rocblas::bindings::_rocblas_handle;
 use crate::rocblas::error::{Error, Result};
 use crate::rocblas::ffi;
 use crate::rocblas::handle::Handle;
 use crate::rocblas::types::{DataType, Operation};
 use crate::rocblas::utils::GemmAlgo;
+use crate::{impl_rocblas_func, impl_rocblas_func_inner, impl_rocblas_traits};
 
+pub use super::types::Diagonal;
 use super::types::{Fill, Side};
 
 //==============================================================================
@@ -281,6 +284,163 @@ pub unsafe fn gemm_ex(
     Ok(())
 }
 
+/// General matrix-matrix multiplication with a specific solution
+///
+/// Same as [`gemm_ex`], but runs the kernel selected by `solution_index`,
+/// one of the indices returned by [`gemm_ex_get_solutions`] for the same
+/// problem. Index 0 lets rocBLAS pick the default solution.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `transa` - Operation op(A) that is non-or (conjugate) transpose
+/// * `transb` - Operation op(B) that is non-or (conjugate) transpose
+/// * `m` - Number of rows of matrix op(A) and C
+/// * `n` - Number of columns of matrix op(B) and C
+/// * `k` - Number of columns of matrix op(A) and rows of op(B)
+/// * `alpha` - Scalar alpha
+/// * `A` - Buffer storing matrix A
+/// * `a_type` - Data type of matrix A
+/// * `lda` - Leading dimension of matrix A
+/// * `B` - Buffer storing matrix B
+/// * `b_type` - Data type of matrix B
+/// * `ldb` - Leading dimension of matrix B
+/// * `beta` - Scalar beta
+/// * `C` - Buffer storing matrix C
+/// * `c_type` - Data type of matrix C
+/// * `ldc` - Leading dimension of matrix C
+/// * `compute_type` - Computation type
+/// * `solution_index` - Solution to run
+pub unsafe fn gemm_ex_with_solution(
+    handle: &Handle,
+    transa: Operation,
+    transb: Operation,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: *const std::ffi::c_void,
+    A: *const std::ffi::c_void,
+    a_type: DataType,
+    lda: i32,
+    B: *const std::ffi::c_void,
+    b_type: DataType,
+    ldb: i32,
+    beta: *const std::ffi::c_void,
+    C: *mut std::ffi::c_void,
+    c_type: DataType,
+    ldc: i32,
+    compute_type: DataType,
+    solution_index: i32,
+) -> Result<()> {
+    let status = unsafe {
+        ffi::rocblas_gemm_ex(
+            handle.as_raw(),
+            transa.into(),
+            transb.into(),
+            m,
+            n,
+            k,
+            alpha,
+            A,
+            a_type.into(),
+            lda,
+            B,
+            b_type.into(),
+            ldb,
+            beta,
+            C,
+            c_type.into(),
+            ldc,
+            C,
+            c_type.into(),
+            ldc,
+            compute_type.into(),
+            ffi::rocblas_gemm_algo__rocblas_gemm_algo_solution_index,
+            solution_index,
+            ffi::rocblas_gemm_flags__rocblas_gemm_flags_none,
+        )
+    };
+
+    if status != ffi::rocblas_status__rocblas_status_success {
+        return Err(Error::new(status));
+    }
+
+    Ok(())
+}
+
+/// List the solutions that can compute a [`gemm_ex`] problem
+///
+/// Returns the solution indices accepted by [`gemm_ex_with_solution`] for
+/// the given arguments. No computation is performed.
+///
+/// # Arguments
+/// Same as [`gemm_ex_with_solution`], without `solution_index`.
+pub unsafe fn gemm_ex_get_solutions(
+    handle: &Handle,
+    transa: Operation,
+    transb: Operation,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: *const std::ffi::c_void,
+    A: *const std::ffi::c_void,
+    a_type: DataType,
+    lda: i32,
+    B: *const std::ffi::c_void,
+    b_type: DataType,
+    ldb: i32,
+    beta: *const std::ffi::c_void,
+    C: *mut std::ffi::c_void,
+    c_type: DataType,
+    ldc: i32,
+    compute_type: DataType,
+) -> Result<Vec<i32>> {
+    let query = |list: *mut i32, size: &mut i32| unsafe {
+        ffi::rocblas_gemm_ex_get_solutions(
+            handle.as_raw(),
+            transa.into(),
+            transb.into(),
+            m,
+            n,
+            k,
+            alpha,
+            A,
+            a_type.into(),
+            lda,
+            B,
+            b_type.into(),
+            ldb,
+            beta,
+            C,
+            c_type.into(),
+            ldc,
+            C,
+            c_type.into(),
+            ldc,
+            compute_type.into(),
+            ffi::rocblas_gemm_algo__rocblas_gemm_algo_solution_index,
+            ffi::rocblas_gemm_flags__rocblas_gemm_flags_none,
+            list,
+            size,
+        )
+    };
+
+    // First call gets the count, second one fills the list
+    let mut size = 0;
+    let status = query(std::ptr::null_mut(), &mut size);
+    if status != ffi::rocblas_status__rocblas_status_success {
+        return Err(Error::new(status));
+    }
+
+    let mut solutions = vec![0; size.max(0) as usize];
+    let status = query(solutions.as_mut_ptr(), &mut size);
+    if status != ffi::rocblas_status__rocblas_status_success {
+        return Err(Error::new(status));
+    }
+
+    solutions.truncate(size.max(0) as usize);
+    Ok(solutions)
+}
+
 //==============================================================================
 // Type traits for implementation
 //==============================================================================
@@ -473,6 +633,48 @@ impl GemmType for ffi::rocblas_double_complex {
     }
 }
 
+impl GemmType for ffi::rocblas_half {
+    unsafe fn rocblas_gemm(
+        handle: &Handle,
+        transa: Operation,
+        transb: Operation,
+        m: i32,
+        n: i32,
+        k: i32,
+        alpha: &Self,
+        A: *const Self,
+        lda: i32,
+        B: *const Self,
+        ldb: i32,
+        beta: &Self,
+        C: *mut Self,
+        ldc: i32,
+    ) -> Result<()> {
+        let status = unsafe {
+            ffi::rocblas_hgemm(
+                handle.as_raw(),
+                transa.into(),
+                transb.into(),
+                m,
+                n,
+                k,
+                alpha,
+                A,
+                lda,
+                B,
+                ldb,
+                beta,
+                C,
+                ldc,
+            )
+        };
+        if status != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(status));
+        }
+        Ok(())
+    }
+}
+
 /// Trait for types that can be used with gemm_batched
 pub trait GemmBatchedType {
     unsafe fn rocblas_gemm_batched(
@@ -670,6 +872,50 @@ impl GemmBatchedType for ffi::rocblas_double_complex {
     }
 }
 
+impl GemmBatchedType for ffi::rocblas_half {
+    unsafe fn rocblas_gemm_batched(
+        handle: &Handle,
+        transa: Operation,
+        transb: Operation,
+        m: i32,
+        n: i32,
+        k: i32,
+        alpha: &Self,
+        A: *const *const Self,
+        lda: i32,
+        B: *const *const Self,
+        ldb: i32,
+        beta: &Self,
+        C: *const *mut Self,
+        ldc: i32,
+        batch_count: i32,
+    ) -> Result<()> {
+        let status = unsafe {
+            ffi::rocblas_hgemm_batched(
+                handle.as_raw(),
+                transa.into(),
+                transb.into(),
+                m,
+                n,
+                k,
+                alpha,
+                A,
+                lda,
+                B,
+                ldb,
+                beta,
+                C,
+                ldc,
+                batch_count,
+            )
+        };
+        if status != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(status));
+        }
+        Ok(())
+    }
+}
+
 /// Trait for types that can be used with gemm_strided_batched
 pub trait GemmStridedBatchedType {
     unsafe fn rocblas_gemm_strided_batched(
@@ -894,56 +1140,47 @@ impl GemmStridedBatchedType for ffi::rocblas_double_complex {
     }
 }
 
-// Trait definitions for HEMM operations
-pub trait HemmType {
-    unsafe fn rocblas_hemm(
-        handle: &Handle,
-        side: Side,
-        uplo: Fill,
-        m: i32,
-        n: i32,
-        alpha: &Self,
-        A: *const Self,
-        lda: i32,
-        B: *const Self,
-        ldb: i32,
-        beta: &Self,
-        C: *mut Self,
-        ldc: i32,
-    ) -> Result<()>;
-}
-
-impl HemmType for ffi::rocblas_float_complex {
-    unsafe fn rocblas_hemm(
+impl GemmStridedBatchedType for ffi::rocblas_half {
+    unsafe fn rocblas_gemm_strided_batched(
         handle: &Handle,
-        side: Side,
-        uplo: Fill,
+        transa: Operation,
+        transb: Operation,
         m: i32,
         n: i32,
+        k: i32,
         alpha: &Self,
         A: *const Self,
         lda: i32,
+        stride_A: i64,
         B: *const Self,
         ldb: i32,
+        stride_B: i64,
         beta: &Self,
         C: *mut Self,
         ldc: i32,
+        stride_C: i64,
+        batch_count: i32,
     ) -> Result<()> {
         let status = unsafe {
-            ffi::rocblas_chemm(
+            ffi::rocblas_hgemm_strided_batched(
                 handle.as_raw(),
-                side.into(),
-                uplo.into(),
+                transa.into(),
+                transb.into(),
                 m,
                 n,
+                k,
                 alpha,
                 A,
                 lda,
+                stride_A,
                 B,
                 ldb,
+                stride_B,
                 beta,
                 C,
                 ldc,
+                stride_C,
+                batch_count,
             )
         };
         if status != ffi::rocblas_status__rocblas_status_success {
@@ -953,7 +1190,8 @@ impl HemmType for ffi::rocblas_float_complex {
     }
 }
 
-impl HemmType for ffi::rocblas_double_complex {
+// Trait definitions for HEMM operations
+pub trait HemmType {
     unsafe fn rocblas_hemm(
         handle: &Handle,
         side: Side,
@@ -968,8 +1206,66 @@ impl HemmType for ffi::rocblas_double_complex {
         beta: &Self,
         C: *mut Self,
         ldc: i32,
-    ) -> Result<()> {
-        let status = unsafe {
+    ) -> Result<()>;
+}
+
+impl HemmType for ffi::rocblas_float_complex {
+    unsafe fn rocblas_hemm(
+        handle: &Handle,
+        side: Side,
+        uplo: Fill,
+        m: i32,
+        n: i32,
+        alpha: &Self,
+        A: *const Self,
+        lda: i32,
+        B: *const Self,
+        ldb: i32,
+        beta: &Self,
+        C: *mut Self,
+        ldc: i32,
+    ) -> Result<()> {
+        let status = unsafe {
+            ffi::rocblas_chemm(
+                handle.as_raw(),
+                side.into(),
+                uplo.into(),
+                m,
+                n,
+                alpha,
+                A,
+                lda,
+                B,
+                ldb,
+                beta,
+                C,
+                ldc,
+            )
+        };
+        if status != ffi::rocblas_status__rocblas_status_success {
+            return Err(Error::new(status));
+        }
+        Ok(())
+    }
+}
+
+impl HemmType for ffi::rocblas_double_complex {
+    unsafe fn rocblas_hemm(
+        handle: &Handle,
+        side: Side,
+        uplo: Fill,
+        m: i32,
+        n: i32,
+        alpha: &Self,
+        A: *const Self,
+        lda: i32,
+        B: *const Self,
+        ldb: i32,
+        beta: &Self,
+        C: *mut Self,
+        ldc: i32,
+    ) -> Result<()> {
+        let status = unsafe {
             ffi::rocblas_zhemm(
                 handle.as_raw(),
                 side.into(),
@@ -2617,32 +2913,1496 @@ impl HerkxStridedBatchedType for ffi::rocblas_double_complex {
     }
 }
 
-// Add to src/rocblas/types.rs if not already present
+//==============================================================================
+// TRSM functions - Triangular Solve with Multiple Right-Hand Sides
+//==============================================================================
 
-/// Enum for diagonal type
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Diagonal {
-    /// Non-unit triangular
-    NonUnit,
-    /// Unit triangular
-    Unit,
+/// Solve a triangular system with multiple right-hand sides
+///
+/// Solves one of the following for X, overwriting B:
+///
+/// op(A) * X = alpha * B   (side = Left)
+/// X * op(A) = alpha * B   (side = Right)
+///
+/// where A is a triangular matrix and B is m x n.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `side` - Whether op(A) is applied from the left or the right
+/// * `uplo` - Whether A is upper or lower triangular
+/// * `transa` - Operation op(A) that is non-or (conjugate) transpose
+/// * `diag` - Whether A has a unit diagonal
+/// * `m` - Number of rows of matrix B
+/// * `n` - Number of columns of matrix B
+/// * `alpha` - Scalar alpha
+/// * `A` - Buffer storing the triangular matrix A
+/// * `lda` - Leading dimension of matrix A
+/// * `B` - Buffer storing matrix B, overwritten with X
+/// * `ldb` - Leading dimension of matrix B
+pub unsafe fn trsm<T>(
+    handle: &Handle,
+    side: Side,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    A: *const T,
+    lda: i32,
+    B: *mut T,
+    ldb: i32,
+) -> Result<()>
+where
+    T: TrsmType,
+{
+    unsafe {
+        T::rocblas_trsm(
+            handle, side, uplo, transa, diag, m, n, alpha, A, lda, B, ldb,
+        )
+    }
 }
 
-impl From<Diagonal> for ffi::rocblas_diagonal {
-    fn from(diag: Diagonal) -> Self {
-        match diag {
-            Diagonal::NonUnit => ffi::rocblas_diagonal__rocblas_diagonal_non_unit,
-            Diagonal::Unit => ffi::rocblas_diagonal__rocblas_diagonal_unit,
-        }
+/// Batched triangular solve with multiple right-hand sides
+///
+/// Solves op(A_i) * X_i = alpha * B_i or X_i * op(A_i) = alpha * B_i for
+/// each instance of the batch, overwriting B_i with X_i.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `side` - Whether op(A_i) is applied from the left or the right
+/// * `uplo` - Whether A_i are upper or lower triangular
+/// * `transa` - Operation op(A_i) that is non-or (conjugate) transpose
+/// * `diag` - Whether A_i have a unit diagonal
+/// * `m` - Number of rows of matrices B_i
+/// * `n` - Number of columns of matrices B_i
+/// * `alpha` - Scalar alpha
+/// * `A` - Array of pointers to matrices A_i
+/// * `lda` - Leading dimension of matrices A_i
+/// * `B` - Array of pointers to matrices B_i
+/// * `ldb` - Leading dimension of matrices B_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn trsm_batched<T>(
+    handle: &Handle,
+    side: Side,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    A: *const *const T,
+    lda: i32,
+    B: *const *mut T,
+    ldb: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: TrsmBatchedType,
+{
+    unsafe {
+        T::rocblas_trsm_batched(
+            handle,
+            side,
+            uplo,
+            transa,
+            diag,
+            m,
+            n,
+            alpha,
+            A,
+            lda,
+            B,
+            ldb,
+            batch_count,
+        )
     }
 }
 
-impl From<ffi::rocblas_diagonal> for Diagonal {
-    fn from(diag: ffi::rocblas_diagonal) -> Self {
-        match diag {
-            ffi::rocblas_diagonal__rocblas_diagonal_non_unit => Diagonal::NonUnit,
-            ffi::rocblas_diagonal__rocblas_diagonal_unit => Diagonal::Unit,
-            _ => Diagonal::NonUnit, // Default to NonUnit for unknown values
-        }
+/// Strided batched triangular solve with multiple right-hand sides
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `side` - Whether op(A_i) is applied from the left or the right
+/// * `uplo` - Whether A_i are upper or lower triangular
+/// * `transa` - Operation op(A_i) that is non-or (conjugate) transpose
+/// * `diag` - Whether A_i have a unit diagonal
+/// * `m` - Number of rows of matrices B_i
+/// * `n` - Number of columns of matrices B_i
+/// * `alpha` - Scalar alpha
+/// * `A` - Pointer to the first matrix A_1
+/// * `lda` - Leading dimension of matrices A_i
+/// * `stride_A` - Stride from start of one matrix (A_i) to the next (A_i+1)
+/// * `B` - Pointer to the first matrix B_1
+/// * `ldb` - Leading dimension of matrices B_i
+/// * `stride_B` - Stride from start of one matrix (B_i) to the next (B_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn trsm_strided_batched<T>(
+    handle: &Handle,
+    side: Side,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    A: *const T,
+    lda: i32,
+    stride_A: i64,
+    B: *mut T,
+    ldb: i32,
+    stride_B: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: TrsmStridedBatchedType,
+{
+    unsafe {
+        T::rocblas_trsm_strided_batched(
+            handle,
+            side,
+            uplo,
+            transa,
+            diag,
+            m,
+            n,
+            alpha,
+            A,
+            lda,
+            stride_A,
+            B,
+            ldb,
+            stride_B,
+            batch_count,
+        )
+    }
+}
+
+//==============================================================================
+// TRMM functions - Triangular Matrix-Matrix Multiplication
+//==============================================================================
+
+/// Triangular matrix-matrix multiplication
+///
+/// Computes one of the following:
+///
+/// C := alpha * op(A) * B   (side = Left)
+/// C := alpha * B * op(A)   (side = Right)
+///
+/// where A is a triangular matrix and B and C are m x n. Passing the same
+/// buffer for B and C (with ldb == ldc) computes the product in place.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `side` - Whether op(A) is applied from the left or the right
+/// * `uplo` - Whether A is upper or lower triangular
+/// * `transa` - Operation op(A) that is non-or (conjugate) transpose
+/// * `diag` - Whether A has a unit diagonal
+/// * `m` - Number of rows of matrices B and C
+/// * `n` - Number of columns of matrices B and C
+/// * `alpha` - Scalar alpha
+/// * `A` - Buffer storing the triangular matrix A
+/// * `lda` - Leading dimension of matrix A
+/// * `B` - Buffer storing matrix B
+/// * `ldb` - Leading dimension of matrix B
+/// * `C` - Buffer storing matrix C
+/// * `ldc` - Leading dimension of matrix C
+pub unsafe fn trmm<T>(
+    handle: &Handle,
+    side: Side,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    A: *const T,
+    lda: i32,
+    B: *const T,
+    ldb: i32,
+    C: *mut T,
+    ldc: i32,
+) -> Result<()>
+where
+    T: TrmmType,
+{
+    unsafe {
+        T::rocblas_trmm(
+            handle, side, uplo, transa, diag, m, n, alpha, A, lda, B, ldb, C, ldc,
+        )
     }
 }
+
+/// Batched triangular matrix-matrix multiplication
+///
+/// C_i := alpha * op(A_i) * B_i or C_i := alpha * B_i * op(A_i) for each
+/// instance of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `side` - Whether op(A_i) is applied from the left or the right
+/// * `uplo` - Whether A_i are upper or lower triangular
+/// * `transa` - Operation op(A_i) that is non-or (conjugate) transpose
+/// * `diag` - Whether A_i have a unit diagonal
+/// * `m` - Number of rows of matrices B_i and C_i
+/// * `n` - Number of columns of matrices B_i and C_i
+/// * `alpha` - Scalar alpha
+/// * `A` - Array of pointers to matrices A_i
+/// * `lda` - Leading dimension of matrices A_i
+/// * `B` - Array of pointers to matrices B_i
+/// * `ldb` - Leading dimension of matrices B_i
+/// * `C` - Array of pointers to matrices C_i
+/// * `ldc` - Leading dimension of matrices C_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn trmm_batched<T>(
+    handle: &Handle,
+    side: Side,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    A: *const *const T,
+    lda: i32,
+    B: *const *const T,
+    ldb: i32,
+    C: *const *mut T,
+    ldc: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: TrmmBatchedType,
+{
+    unsafe {
+        T::rocblas_trmm_batched(
+            handle,
+            side,
+            uplo,
+            transa,
+            diag,
+            m,
+            n,
+            alpha,
+            A,
+            lda,
+            B,
+            ldb,
+            C,
+            ldc,
+            batch_count,
+        )
+    }
+}
+
+/// Strided batched triangular matrix-matrix multiplication
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `side` - Whether op(A_i) is applied from the left or the right
+/// * `uplo` - Whether A_i are upper or lower triangular
+/// * `transa` - Operation op(A_i) that is non-or (conjugate) transpose
+/// * `diag` - Whether A_i have a unit diagonal
+/// * `m` - Number of rows of matrices B_i and C_i
+/// * `n` - Number of columns of matrices B_i and C_i
+/// * `alpha` - Scalar alpha
+/// * `A` - Pointer to the first matrix A_1
+/// * `lda` - Leading dimension of matrices A_i
+/// * `stride_A` - Stride from start of one matrix (A_i) to the next (A_i+1)
+/// * `B` - Pointer to the first matrix B_1
+/// * `ldb` - Leading dimension of matrices B_i
+/// * `stride_B` - Stride from start of one matrix (B_i) to the next (B_i+1)
+/// * `C` - Pointer to the first matrix C_1
+/// * `ldc` - Leading dimension of matrices C_i
+/// * `stride_C` - Stride from start of one matrix (C_i) to the next (C_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn trmm_strided_batched<T>(
+    handle: &Handle,
+    side: Side,
+    uplo: Fill,
+    transa: Operation,
+    diag: Diagonal,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    A: *const T,
+    lda: i32,
+    stride_A: i64,
+    B: *const T,
+    ldb: i32,
+    stride_B: i64,
+    C: *mut T,
+    ldc: i32,
+    stride_C: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: TrmmStridedBatchedType,
+{
+    unsafe {
+        T::rocblas_trmm_strided_batched(
+            handle,
+            side,
+            uplo,
+            transa,
+            diag,
+            m,
+            n,
+            alpha,
+            A,
+            lda,
+            stride_A,
+            B,
+            ldb,
+            stride_B,
+            C,
+            ldc,
+            stride_C,
+            batch_count,
+        )
+    }
+}
+
+impl_rocblas_traits!(
+    TrsmType,
+    TrsmFn,
+    {
+        f32 => ffi::rocblas_strsm,
+        f64 => ffi::rocblas_dtrsm,
+        ffi::rocblas_float_complex => ffi::rocblas_ctrsm,
+        ffi::rocblas_double_complex => ffi::rocblas_ztrsm,
+    },
+    rocblas_trsm,
+    (handle: &Handle, side: Side, uplo: Fill, transa: Operation, diag: Diagonal, m: i32, n: i32, alpha: &Self, A: *const Self, lda: i32, B: *mut Self, ldb: i32),
+    (*mut _rocblas_handle, ffi::rocblas_side, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, i32, *const T, *const T, i32, *mut T, i32),
+    (handle.as_raw(), side.into(), uplo.into(), transa.into(), diag.into(), m, n, alpha, A, lda, B, ldb)
+);
+
+impl_rocblas_traits!(
+    TrsmBatchedType,
+    TrsmBatchedFn,
+    {
+        f32 => ffi::rocblas_strsm_batched,
+        f64 => ffi::rocblas_dtrsm_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_ctrsm_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_ztrsm_batched,
+    },
+    rocblas_trsm_batched,
+    (handle: &Handle, side: Side, uplo: Fill, transa: Operation, diag: Diagonal, m: i32, n: i32, alpha: &Self, A: *const *const Self, lda: i32, B: *const *mut Self, ldb: i32, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_side, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, i32, *const T, *const *const T, i32, *const *mut T, i32, i32),
+    (handle.as_raw(), side.into(), uplo.into(), transa.into(), diag.into(), m, n, alpha, A, lda, B, ldb, batch_count)
+);
+
+impl_rocblas_traits!(
+    TrsmStridedBatchedType,
+    TrsmStridedBatchedFn,
+    {
+        f32 => ffi::rocblas_strsm_strided_batched,
+        f64 => ffi::rocblas_dtrsm_strided_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_ctrsm_strided_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_ztrsm_strided_batched,
+    },
+    rocblas_trsm_strided_batched,
+    (handle: &Handle, side: Side, uplo: Fill, transa: Operation, diag: Diagonal, m: i32, n: i32, alpha: &Self, A: *const Self, lda: i32, stride_A: i64, B: *mut Self, ldb: i32, stride_B: i64, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_side, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, i32, *const T, *const T, i32, i64, *mut T, i32, i64, i32),
+    (handle.as_raw(), side.into(), uplo.into(), transa.into(), diag.into(), m, n, alpha, A, lda, stride_A, B, ldb, stride_B, batch_count)
+);
+
+impl_rocblas_traits!(
+    TrmmType,
+    TrmmFn,
+    {
+        f32 => ffi::rocblas_strmm,
+        f64 => ffi::rocblas_dtrmm,
+        ffi::rocblas_float_complex => ffi::rocblas_ctrmm,
+        ffi::rocblas_double_complex => ffi::rocblas_ztrmm,
+    },
+    rocblas_trmm,
+    (handle: &Handle, side: Side, uplo: Fill, transa: Operation, diag: Diagonal, m: i32, n: i32, alpha: &Self, A: *const Self, lda: i32, B: *const Self, ldb: i32, C: *mut Self, ldc: i32),
+    (*mut _rocblas_handle, ffi::rocblas_side, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, i32, *const T, *const T, i32, *const T, i32, *mut T, i32),
+    (handle.as_raw(), side.into(), uplo.into(), transa.into(), diag.into(), m, n, alpha, A, lda, B, ldb, C, ldc)
+);
+
+impl_rocblas_traits!(
+    TrmmBatchedType,
+    TrmmBatchedFn,
+    {
+        f32 => ffi::rocblas_strmm_batched,
+        f64 => ffi::rocblas_dtrmm_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_ctrmm_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_ztrmm_batched,
+    },
+    rocblas_trmm_batched,
+    (handle: &Handle, side: Side, uplo: Fill, transa: Operation, diag: Diagonal, m: i32, n: i32, alpha: &Self, A: *const *const Self, lda: i32, B: *const *const Self, ldb: i32, C: *const *mut Self, ldc: i32, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_side, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, i32, *const T, *const *const T, i32, *const *const T, i32, *const *mut T, i32, i32),
+    (handle.as_raw(), side.into(), uplo.into(), transa.into(), diag.into(), m, n, alpha, A, lda, B, ldb, C, ldc, batch_count)
+);
+
+impl_rocblas_traits!(
+    TrmmStridedBatchedType,
+    TrmmStridedBatchedFn,
+    {
+        f32 => ffi::rocblas_strmm_strided_batched,
+        f64 => ffi::rocblas_dtrmm_strided_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_ctrmm_strided_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_ztrmm_strided_batched,
+    },
+    rocblas_trmm_strided_batched,
+    (handle: &Handle, side: Side, uplo: Fill, transa: Operation, diag: Diagonal, m: i32, n: i32, alpha: &Self, A: *const Self, lda: i32, stride_A: i64, B: *const Self, ldb: i32, stride_B: i64, C: *mut Self, ldc: i32, stride_C: i64, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_side, ffi::rocblas_fill, ffi::rocblas_operation, ffi::rocblas_diagonal, i32, i32, *const T, *const T, i32, i64, *const T, i32, i64, *mut T, i32, i64, i32),
+    (handle.as_raw(), side.into(), uplo.into(), transa.into(), diag.into(), m, n, alpha, A, lda, stride_A, B, ldb, stride_B, C, ldc, stride_C, batch_count)
+);
+
+//==============================================================================
+// SYMM functions - Symmetric Matrix-Matrix Multiplication
+//==============================================================================
+
+/// Symmetric matrix-matrix multiplication
+///
+/// Computes one of the following:
+///
+/// C := alpha * A * B + beta * C   (side = Left)
+/// C := alpha * B * A + beta * C   (side = Right)
+///
+/// where A is a symmetric matrix stored in its `uplo` triangle, and B and
+/// C are m x n matrices.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `side` - Whether A is applied from the left or the right
+/// * `uplo` - Which triangle of A is referenced
+/// * `m` - Number of rows of matrices B and C
+/// * `n` - Number of columns of matrices B and C
+/// * `alpha` - Scalar alpha
+/// * `A` - Buffer storing symmetric matrix A
+/// * `lda` - Leading dimension of matrix A
+/// * `B` - Buffer storing matrix B
+/// * `ldb` - Leading dimension of matrix B
+/// * `beta` - Scalar beta
+/// * `C` - Buffer storing matrix C
+/// * `ldc` - Leading dimension of matrix C
+pub unsafe fn symm<T>(
+    handle: &Handle,
+    side: Side,
+    uplo: Fill,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    A: *const T,
+    lda: i32,
+    B: *const T,
+    ldb: i32,
+    beta: &T,
+    C: *mut T,
+    ldc: i32,
+) -> Result<()>
+where
+    T: SymmType,
+{
+    unsafe {
+        T::rocblas_symm(
+            handle, side, uplo, m, n, alpha, A, lda, B, ldb, beta, C, ldc,
+        )
+    }
+}
+
+/// Batched symmetric matrix-matrix multiplication
+///
+/// Applies [`symm`] to each instance (A_i, B_i, C_i) of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `side` - Whether A_i is applied from the left or the right
+/// * `uplo` - Which triangle of A_i is referenced
+/// * `m` - Number of rows of matrices B_i and C_i
+/// * `n` - Number of columns of matrices B_i and C_i
+/// * `alpha` - Scalar alpha
+/// * `A` - Array of pointers to matrices A_i
+/// * `lda` - Leading dimension of matrices A_i
+/// * `B` - Array of pointers to matrices B_i
+/// * `ldb` - Leading dimension of matrices B_i
+/// * `beta` - Scalar beta
+/// * `C` - Array of pointers to matrices C_i
+/// * `ldc` - Leading dimension of matrices C_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn symm_batched<T>(
+    handle: &Handle,
+    side: Side,
+    uplo: Fill,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    A: *const *const T,
+    lda: i32,
+    B: *const *const T,
+    ldb: i32,
+    beta: &T,
+    C: *const *mut T,
+    ldc: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: SymmBatchedType,
+{
+    unsafe {
+        T::rocblas_symm_batched(
+            handle,
+            side,
+            uplo,
+            m,
+            n,
+            alpha,
+            A,
+            lda,
+            B,
+            ldb,
+            beta,
+            C,
+            ldc,
+            batch_count,
+        )
+    }
+}
+
+/// Strided batched symmetric matrix-matrix multiplication
+///
+/// Applies [`symm`] to each instance of the batch, with the instances
+/// laid out at fixed strides in single buffers.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `side` - Whether A_i is applied from the left or the right
+/// * `uplo` - Which triangle of A_i is referenced
+/// * `m` - Number of rows of matrices B_i and C_i
+/// * `n` - Number of columns of matrices B_i and C_i
+/// * `alpha` - Scalar alpha
+/// * `A` - Pointer to the first matrix A_1
+/// * `lda` - Leading dimension of matrices A_i
+/// * `stride_A` - Stride from start of one matrix (A_i) to the next (A_i+1)
+/// * `B` - Pointer to the first matrix B_1
+/// * `ldb` - Leading dimension of matrices B_i
+/// * `stride_B` - Stride from start of one matrix (B_i) to the next (B_i+1)
+/// * `beta` - Scalar beta
+/// * `C` - Pointer to the first matrix C_1
+/// * `ldc` - Leading dimension of matrices C_i
+/// * `stride_C` - Stride from start of one matrix (C_i) to the next (C_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn symm_strided_batched<T>(
+    handle: &Handle,
+    side: Side,
+    uplo: Fill,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    A: *const T,
+    lda: i32,
+    stride_A: i64,
+    B: *const T,
+    ldb: i32,
+    stride_B: i64,
+    beta: &T,
+    C: *mut T,
+    ldc: i32,
+    stride_C: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: SymmStridedBatchedType,
+{
+    unsafe {
+        T::rocblas_symm_strided_batched(
+            handle,
+            side,
+            uplo,
+            m,
+            n,
+            alpha,
+            A,
+            lda,
+            stride_A,
+            B,
+            ldb,
+            stride_B,
+            beta,
+            C,
+            ldc,
+            stride_C,
+            batch_count,
+        )
+    }
+}
+
+//==============================================================================
+// SYRK functions - Symmetric Rank-k Update
+//==============================================================================
+
+/// Symmetric rank-k update
+///
+/// Computes one of the following:
+///
+/// C := alpha * A * A^T + beta * C   (trans = None)
+/// C := alpha * A^T * A + beta * C   (trans = Transpose)
+///
+/// where C is an n x n symmetric matrix of which only the `uplo` triangle
+/// is updated, and A is n x k (or k x n when transposed).
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Which triangle of C is updated
+/// * `transa` - Operation op(A) that is non or transpose
+/// * `n` - Number of rows and columns of matrix C
+/// * `k` - Number of columns of op(A)
+/// * `alpha` - Scalar alpha
+/// * `A` - Buffer storing matrix A
+/// * `lda` - Leading dimension of matrix A
+/// * `beta` - Scalar beta
+/// * `C` - Buffer storing symmetric matrix C
+/// * `ldc` - Leading dimension of matrix C
+pub unsafe fn syrk<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    n: i32,
+    k: i32,
+    alpha: &T,
+    A: *const T,
+    lda: i32,
+    beta: &T,
+    C: *mut T,
+    ldc: i32,
+) -> Result<()>
+where
+    T: SyrkType,
+{
+    unsafe { T::rocblas_syrk(handle, uplo, transa, n, k, alpha, A, lda, beta, C, ldc) }
+}
+
+/// Batched symmetric rank-k update
+///
+/// Applies [`syrk`] to each instance (A_i, C_i) of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Which triangle of C_i is updated
+/// * `transa` - Operation op(A_i) that is non or transpose
+/// * `n` - Number of rows and columns of matrices C_i
+/// * `k` - Number of columns of op(A_i)
+/// * `alpha` - Scalar alpha
+/// * `A` - Array of pointers to matrices A_i
+/// * `lda` - Leading dimension of matrices A_i
+/// * `beta` - Scalar beta
+/// * `C` - Array of pointers to matrices C_i
+/// * `ldc` - Leading dimension of matrices C_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn syrk_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    n: i32,
+    k: i32,
+    alpha: &T,
+    A: *const *const T,
+    lda: i32,
+    beta: &T,
+    C: *const *mut T,
+    ldc: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: SyrkBatchedType,
+{
+    unsafe {
+        T::rocblas_syrk_batched(
+            handle,
+            uplo,
+            transa,
+            n,
+            k,
+            alpha,
+            A,
+            lda,
+            beta,
+            C,
+            ldc,
+            batch_count,
+        )
+    }
+}
+
+/// Strided batched symmetric rank-k update
+///
+/// Applies [`syrk`] to each instance of the batch, with the instances
+/// laid out at fixed strides in single buffers.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Which triangle of C_i is updated
+/// * `transa` - Operation op(A_i) that is non or transpose
+/// * `n` - Number of rows and columns of matrices C_i
+/// * `k` - Number of columns of op(A_i)
+/// * `alpha` - Scalar alpha
+/// * `A` - Pointer to the first matrix A_1
+/// * `lda` - Leading dimension of matrices A_i
+/// * `stride_A` - Stride from start of one matrix (A_i) to the next (A_i+1)
+/// * `beta` - Scalar beta
+/// * `C` - Pointer to the first matrix C_1
+/// * `ldc` - Leading dimension of matrices C_i
+/// * `stride_C` - Stride from start of one matrix (C_i) to the next (C_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn syrk_strided_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    transa: Operation,
+    n: i32,
+    k: i32,
+    alpha: &T,
+    A: *const T,
+    lda: i32,
+    stride_A: i64,
+    beta: &T,
+    C: *mut T,
+    ldc: i32,
+    stride_C: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: SyrkStridedBatchedType,
+{
+    unsafe {
+        T::rocblas_syrk_strided_batched(
+            handle,
+            uplo,
+            transa,
+            n,
+            k,
+            alpha,
+            A,
+            lda,
+            stride_A,
+            beta,
+            C,
+            ldc,
+            stride_C,
+            batch_count,
+        )
+    }
+}
+
+//==============================================================================
+// SYR2K functions - Symmetric Rank-2k Update
+//==============================================================================
+
+/// Symmetric rank-2k update
+///
+/// Computes one of the following:
+///
+/// C := alpha * (A * B^T + B * A^T) + beta * C   (trans = None)
+/// C := alpha * (A^T * B + B^T * A) + beta * C   (trans = Transpose)
+///
+/// where C is an n x n symmetric matrix of which only the `uplo` triangle
+/// is updated, and A and B are n x k (or k x n when transposed).
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Which triangle of C is updated
+/// * `trans` - Operation op(A) and op(B) that is non or transpose
+/// * `n` - Number of rows and columns of matrix C
+/// * `k` - Number of columns of op(A) and op(B)
+/// * `alpha` - Scalar alpha
+/// * `A` - Buffer storing matrix A
+/// * `lda` - Leading dimension of matrix A
+/// * `B` - Buffer storing matrix B
+/// * `ldb` - Leading dimension of matrix B
+/// * `beta` - Scalar beta
+/// * `C` - Buffer storing symmetric matrix C
+/// * `ldc` - Leading dimension of matrix C
+pub unsafe fn syr2k<T>(
+    handle: &Handle,
+    uplo: Fill,
+    trans: Operation,
+    n: i32,
+    k: i32,
+    alpha: &T,
+    A: *const T,
+    lda: i32,
+    B: *const T,
+    ldb: i32,
+    beta: &T,
+    C: *mut T,
+    ldc: i32,
+) -> Result<()>
+where
+    T: Syr2kType,
+{
+    unsafe {
+        T::rocblas_syr2k(
+            handle, uplo, trans, n, k, alpha, A, lda, B, ldb, beta, C, ldc,
+        )
+    }
+}
+
+/// Batched symmetric rank-2k update
+///
+/// Applies [`syr2k`] to each instance (A_i, B_i, C_i) of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Which triangle of C_i is updated
+/// * `trans` - Operation op(A_i) and op(B_i) that is non or transpose
+/// * `n` - Number of rows and columns of matrices C_i
+/// * `k` - Number of columns of op(A_i) and op(B_i)
+/// * `alpha` - Scalar alpha
+/// * `A` - Array of pointers to matrices A_i
+/// * `lda` - Leading dimension of matrices A_i
+/// * `B` - Array of pointers to matrices B_i
+/// * `ldb` - Leading dimension of matrices B_i
+/// * `beta` - Scalar beta
+/// * `C` - Array of pointers to matrices C_i
+/// * `ldc` - Leading dimension of matrices C_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn syr2k_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    trans: Operation,
+    n: i32,
+    k: i32,
+    alpha: &T,
+    A: *const *const T,
+    lda: i32,
+    B: *const *const T,
+    ldb: i32,
+    beta: &T,
+    C: *const *mut T,
+    ldc: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: Syr2kBatchedType,
+{
+    unsafe {
+        T::rocblas_syr2k_batched(
+            handle,
+            uplo,
+            trans,
+            n,
+            k,
+            alpha,
+            A,
+            lda,
+            B,
+            ldb,
+            beta,
+            C,
+            ldc,
+            batch_count,
+        )
+    }
+}
+
+/// Strided batched symmetric rank-2k update
+///
+/// Applies [`syr2k`] to each instance of the batch, with the instances
+/// laid out at fixed strides in single buffers.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `uplo` - Which triangle of C_i is updated
+/// * `trans` - Operation op(A_i) and op(B_i) that is non or transpose
+/// * `n` - Number of rows and columns of matrices C_i
+/// * `k` - Number of columns of op(A_i) and op(B_i)
+/// * `alpha` - Scalar alpha
+/// * `A` - Pointer to the first matrix A_1
+/// * `lda` - Leading dimension of matrices A_i
+/// * `stride_A` - Stride from start of one matrix (A_i) to the next (A_i+1)
+/// * `B` - Pointer to the first matrix B_1
+/// * `ldb` - Leading dimension of matrices B_i
+/// * `stride_B` - Stride from start of one matrix (B_i) to the next (B_i+1)
+/// * `beta` - Scalar beta
+/// * `C` - Pointer to the first matrix C_1
+/// * `ldc` - Leading dimension of matrices C_i
+/// * `stride_C` - Stride from start of one matrix (C_i) to the next (C_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn syr2k_strided_batched<T>(
+    handle: &Handle,
+    uplo: Fill,
+    trans: Operation,
+    n: i32,
+    k: i32,
+    alpha: &T,
+    A: *const T,
+    lda: i32,
+    stride_A: i64,
+    B: *const T,
+    ldb: i32,
+    stride_B: i64,
+    beta: &T,
+    C: *mut T,
+    ldc: i32,
+    stride_C: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: Syr2kStridedBatchedType,
+{
+    unsafe {
+        T::rocblas_syr2k_strided_batched(
+            handle,
+            uplo,
+            trans,
+            n,
+            k,
+            alpha,
+            A,
+            lda,
+            stride_A,
+            B,
+            ldb,
+            stride_B,
+            beta,
+            C,
+            ldc,
+            stride_C,
+            batch_count,
+        )
+    }
+}
+
+impl_rocblas_traits!(
+    SymmType,
+    SymmFn,
+    {
+        f32 => ffi::rocblas_ssymm,
+        f64 => ffi::rocblas_dsymm,
+        ffi::rocblas_float_complex => ffi::rocblas_csymm,
+        ffi::rocblas_double_complex => ffi::rocblas_zsymm,
+    },
+    rocblas_symm,
+    (handle: &Handle, side: Side, uplo: Fill, m: i32, n: i32, alpha: &Self, A: *const Self, lda: i32, B: *const Self, ldb: i32, beta: &Self, C: *mut Self, ldc: i32),
+    (*mut _rocblas_handle, ffi::rocblas_side, ffi::rocblas_fill, i32, i32, *const T, *const T, i32, *const T, i32, *const T, *mut T, i32),
+    (handle.as_raw(), side.into(), uplo.into(), m, n, alpha, A, lda, B, ldb, beta, C, ldc)
+);
+
+impl_rocblas_traits!(
+    SymmBatchedType,
+    SymmBatchedFn,
+    {
+        f32 => ffi::rocblas_ssymm_batched,
+        f64 => ffi::rocblas_dsymm_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_csymm_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_zsymm_batched,
+    },
+    rocblas_symm_batched,
+    (handle: &Handle, side: Side, uplo: Fill, m: i32, n: i32, alpha: &Self, A: *const *const Self, lda: i32, B: *const *const Self, ldb: i32, beta: &Self, C: *const *mut Self, ldc: i32, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_side, ffi::rocblas_fill, i32, i32, *const T, *const *const T, i32, *const *const T, i32, *const T, *const *mut T, i32, i32),
+    (handle.as_raw(), side.into(), uplo.into(), m, n, alpha, A, lda, B, ldb, beta, C, ldc, batch_count)
+);
+
+impl_rocblas_traits!(
+    SymmStridedBatchedType,
+    SymmStridedBatchedFn,
+    {
+        f32 => ffi::rocblas_ssymm_strided_batched,
+        f64 => ffi::rocblas_dsymm_strided_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_csymm_strided_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_zsymm_strided_batched,
+    },
+    rocblas_symm_strided_batched,
+    (handle: &Handle, side: Side, uplo: Fill, m: i32, n: i32, alpha: &Self, A: *const Self, lda: i32, stride_A: i64, B: *const Self, ldb: i32, stride_B: i64, beta: &Self, C: *mut Self, ldc: i32, stride_C: i64, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_side, ffi::rocblas_fill, i32, i32, *const T, *const T, i32, i64, *const T, i32, i64, *const T, *mut T, i32, i64, i32),
+    (handle.as_raw(), side.into(), uplo.into(), m, n, alpha, A, lda, stride_A, B, ldb, stride_B, beta, C, ldc, stride_C, batch_count)
+);
+
+impl_rocblas_traits!(
+    SyrkType,
+    SyrkFn,
+    {
+        f32 => ffi::rocblas_ssyrk,
+        f64 => ffi::rocblas_dsyrk,
+        ffi::rocblas_float_complex => ffi::rocblas_csyrk,
+        ffi::rocblas_double_complex => ffi::rocblas_zsyrk,
+    },
+    rocblas_syrk,
+    (handle: &Handle, uplo: Fill, transa: Operation, n: i32, k: i32, alpha: &Self, A: *const Self, lda: i32, beta: &Self, C: *mut Self, ldc: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, i32, i32, *const T, *const T, i32, *const T, *mut T, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), n, k, alpha, A, lda, beta, C, ldc)
+);
+
+impl_rocblas_traits!(
+    SyrkBatchedType,
+    SyrkBatchedFn,
+    {
+        f32 => ffi::rocblas_ssyrk_batched,
+        f64 => ffi::rocblas_dsyrk_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_csyrk_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_zsyrk_batched,
+    },
+    rocblas_syrk_batched,
+    (handle: &Handle, uplo: Fill, transa: Operation, n: i32, k: i32, alpha: &Self, A: *const *const Self, lda: i32, beta: &Self, C: *const *mut Self, ldc: i32, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, i32, i32, *const T, *const *const T, i32, *const T, *const *mut T, i32, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), n, k, alpha, A, lda, beta, C, ldc, batch_count)
+);
+
+impl_rocblas_traits!(
+    SyrkStridedBatchedType,
+    SyrkStridedBatchedFn,
+    {
+        f32 => ffi::rocblas_ssyrk_strided_batched,
+        f64 => ffi::rocblas_dsyrk_strided_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_csyrk_strided_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_zsyrk_strided_batched,
+    },
+    rocblas_syrk_strided_batched,
+    (handle: &Handle, uplo: Fill, transa: Operation, n: i32, k: i32, alpha: &Self, A: *const Self, lda: i32, stride_A: i64, beta: &Self, C: *mut Self, ldc: i32, stride_C: i64, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, i32, i32, *const T, *const T, i32, i64, *const T, *mut T, i32, i64, i32),
+    (handle.as_raw(), uplo.into(), transa.into(), n, k, alpha, A, lda, stride_A, beta, C, ldc, stride_C, batch_count)
+);
+
+impl_rocblas_traits!(
+    Syr2kType,
+    Syr2kFn,
+    {
+        f32 => ffi::rocblas_ssyr2k,
+        f64 => ffi::rocblas_dsyr2k,
+        ffi::rocblas_float_complex => ffi::rocblas_csyr2k,
+        ffi::rocblas_double_complex => ffi::rocblas_zsyr2k,
+    },
+    rocblas_syr2k,
+    (handle: &Handle, uplo: Fill, trans: Operation, n: i32, k: i32, alpha: &Self, A: *const Self, lda: i32, B: *const Self, ldb: i32, beta: &Self, C: *mut Self, ldc: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, i32, i32, *const T, *const T, i32, *const T, i32, *const T, *mut T, i32),
+    (handle.as_raw(), uplo.into(), trans.into(), n, k, alpha, A, lda, B, ldb, beta, C, ldc)
+);
+
+impl_rocblas_traits!(
+    Syr2kBatchedType,
+    Syr2kBatchedFn,
+    {
+        f32 => ffi::rocblas_ssyr2k_batched,
+        f64 => ffi::rocblas_dsyr2k_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_csyr2k_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_zsyr2k_batched,
+    },
+    rocblas_syr2k_batched,
+    (handle: &Handle, uplo: Fill, trans: Operation, n: i32, k: i32, alpha: &Self, A: *const *const Self, lda: i32, B: *const *const Self, ldb: i32, beta: &Self, C: *const *mut Self, ldc: i32, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, i32, i32, *const T, *const *const T, i32, *const *const T, i32, *const T, *const *mut T, i32, i32),
+    (handle.as_raw(), uplo.into(), trans.into(), n, k, alpha, A, lda, B, ldb, beta, C, ldc, batch_count)
+);
+
+impl_rocblas_traits!(
+    Syr2kStridedBatchedType,
+    Syr2kStridedBatchedFn,
+    {
+        f32 => ffi::rocblas_ssyr2k_strided_batched,
+        f64 => ffi::rocblas_dsyr2k_strided_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_csyr2k_strided_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_zsyr2k_strided_batched,
+    },
+    rocblas_syr2k_strided_batched,
+    (handle: &Handle, uplo: Fill, trans: Operation, n: i32, k: i32, alpha: &Self, A: *const Self, lda: i32, stride_A: i64, B: *const Self, ldb: i32, stride_B: i64, beta: &Self, C: *mut Self, ldc: i32, stride_C: i64, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_fill, ffi::rocblas_operation, i32, i32, *const T, *const T, i32, i64, *const T, i32, i64, *const T, *mut T, i32, i64, i32),
+    (handle.as_raw(), uplo.into(), trans.into(), n, k, alpha, A, lda, stride_A, B, ldb, stride_B, beta, C, ldc, stride_C, batch_count)
+);
+
+//==============================================================================
+// GEAM functions - General Matrix-Matrix Addition
+//==============================================================================
+
+/// General matrix-matrix addition and transposition
+///
+/// C := alpha * op(A) + beta * op(B)
+///
+/// where op(A), op(B) and C are m x n matrices. Setting beta to zero gives
+/// an out-of-place (conjugate) transpose of A. C may be the same buffer as
+/// A or B only if the corresponding operation is `Operation::None` and the
+/// leading dimensions match.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `transa` - Operation op(A) that is non-or (conjugate) transpose
+/// * `transb` - Operation op(B) that is non-or (conjugate) transpose
+/// * `m` - Number of rows of matrices op(A), op(B) and C
+/// * `n` - Number of columns of matrices op(A), op(B) and C
+/// * `alpha` - Scalar alpha
+/// * `A` - Buffer storing matrix A
+/// * `lda` - Leading dimension of matrix A
+/// * `beta` - Scalar beta
+/// * `B` - Buffer storing matrix B
+/// * `ldb` - Leading dimension of matrix B
+/// * `C` - Buffer storing matrix C
+/// * `ldc` - Leading dimension of matrix C
+pub unsafe fn geam<T>(
+    handle: &Handle,
+    transa: Operation,
+    transb: Operation,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    A: *const T,
+    lda: i32,
+    beta: &T,
+    B: *const T,
+    ldb: i32,
+    C: *mut T,
+    ldc: i32,
+) -> Result<()>
+where
+    T: GeamType,
+{
+    unsafe {
+        T::rocblas_geam(
+            handle, transa, transb, m, n, alpha, A, lda, beta, B, ldb, C, ldc,
+        )
+    }
+}
+
+/// Batched general matrix-matrix addition and transposition
+///
+/// Applies [`geam`] to each instance of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `transa` - Operation op(A_i) that is non-or (conjugate) transpose
+/// * `transb` - Operation op(B_i) that is non-or (conjugate) transpose
+/// * `m` - Number of rows of matrices op(A_i), op(B_i) and C_i
+/// * `n` - Number of columns of matrices op(A_i), op(B_i) and C_i
+/// * `alpha` - Scalar alpha
+/// * `A` - Array of pointers to matrices A_i
+/// * `lda` - Leading dimension of matrices A_i
+/// * `beta` - Scalar beta
+/// * `B` - Array of pointers to matrices B_i
+/// * `ldb` - Leading dimension of matrices B_i
+/// * `C` - Array of pointers to matrices C_i
+/// * `ldc` - Leading dimension of matrices C_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn geam_batched<T>(
+    handle: &Handle,
+    transa: Operation,
+    transb: Operation,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    A: *const *const T,
+    lda: i32,
+    beta: &T,
+    B: *const *const T,
+    ldb: i32,
+    C: *const *mut T,
+    ldc: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: GeamBatchedType,
+{
+    unsafe {
+        T::rocblas_geam_batched(
+            handle,
+            transa,
+            transb,
+            m,
+            n,
+            alpha,
+            A,
+            lda,
+            beta,
+            B,
+            ldb,
+            C,
+            ldc,
+            batch_count,
+        )
+    }
+}
+
+/// Strided batched general matrix-matrix addition and transposition
+///
+/// Applies [`geam`] to each instance of the batch, with the instances laid
+/// out at fixed strides in single buffers.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `transa` - Operation op(A_i) that is non-or (conjugate) transpose
+/// * `transb` - Operation op(B_i) that is non-or (conjugate) transpose
+/// * `m` - Number of rows of matrices op(A_i), op(B_i) and C_i
+/// * `n` - Number of columns of matrices op(A_i), op(B_i) and C_i
+/// * `alpha` - Scalar alpha
+/// * `A` - Pointer to the first matrix A_1
+/// * `lda` - Leading dimension of matrices A_i
+/// * `stride_A` - Stride from start of one matrix (A_i) to the next (A_i+1)
+/// * `beta` - Scalar beta
+/// * `B` - Pointer to the first matrix B_1
+/// * `ldb` - Leading dimension of matrices B_i
+/// * `stride_B` - Stride from start of one matrix (B_i) to the next (B_i+1)
+/// * `C` - Pointer to the first matrix C_1
+/// * `ldc` - Leading dimension of matrices C_i
+/// * `stride_C` - Stride from start of one matrix (C_i) to the next (C_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn geam_strided_batched<T>(
+    handle: &Handle,
+    transa: Operation,
+    transb: Operation,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    A: *const T,
+    lda: i32,
+    stride_A: i64,
+    beta: &T,
+    B: *const T,
+    ldb: i32,
+    stride_B: i64,
+    C: *mut T,
+    ldc: i32,
+    stride_C: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: GeamStridedBatchedType,
+{
+    unsafe {
+        T::rocblas_geam_strided_batched(
+            handle,
+            transa,
+            transb,
+            m,
+            n,
+            alpha,
+            A,
+            lda,
+            stride_A,
+            beta,
+            B,
+            ldb,
+            stride_B,
+            C,
+            ldc,
+            stride_C,
+            batch_count,
+        )
+    }
+}
+
+impl_rocblas_traits!(
+    GeamType,
+    GeamFn,
+    {
+        f32 => ffi::rocblas_sgeam,
+        f64 => ffi::rocblas_dgeam,
+        ffi::rocblas_float_complex => ffi::rocblas_cgeam,
+        ffi::rocblas_double_complex => ffi::rocblas_zgeam,
+    },
+    rocblas_geam,
+    (handle: &Handle, transa: Operation, transb: Operation, m: i32, n: i32, alpha: &Self, A: *const Self, lda: i32, beta: &Self, B: *const Self, ldb: i32, C: *mut Self, ldc: i32),
+    (*mut _rocblas_handle, ffi::rocblas_operation, ffi::rocblas_operation, i32, i32, *const T, *const T, i32, *const T, *const T, i32, *mut T, i32),
+    (handle.as_raw(), transa.into(), transb.into(), m, n, alpha, A, lda, beta, B, ldb, C, ldc)
+);
+
+impl_rocblas_traits!(
+    GeamBatchedType,
+    GeamBatchedFn,
+    {
+        f32 => ffi::rocblas_sgeam_batched,
+        f64 => ffi::rocblas_dgeam_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_cgeam_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_zgeam_batched,
+    },
+    rocblas_geam_batched,
+    (handle: &Handle, transa: Operation, transb: Operation, m: i32, n: i32, alpha: &Self, A: *const *const Self, lda: i32, beta: &Self, B: *const *const Self, ldb: i32, C: *const *mut Self, ldc: i32, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_operation, ffi::rocblas_operation, i32, i32, *const T, *const *const T, i32, *const T, *const *const T, i32, *const *mut T, i32, i32),
+    (handle.as_raw(), transa.into(), transb.into(), m, n, alpha, A, lda, beta, B, ldb, C, ldc, batch_count)
+);
+
+impl_rocblas_traits!(
+    GeamStridedBatchedType,
+    GeamStridedBatchedFn,
+    {
+        f32 => ffi::rocblas_sgeam_strided_batched,
+        f64 => ffi::rocblas_dgeam_strided_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_cgeam_strided_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_zgeam_strided_batched,
+    },
+    rocblas_geam_strided_batched,
+    (handle: &Handle, transa: Operation, transb: Operation, m: i32, n: i32, alpha: &Self, A: *const Self, lda: i32, stride_A: i64, beta: &Self, B: *const Self, ldb: i32, stride_B: i64, C: *mut Self, ldc: i32, stride_C: i64, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_operation, ffi::rocblas_operation, i32, i32, *const T, *const T, i32, i64, *const T, *const T, i32, i64, *mut T, i32, i64, i32),
+    (handle.as_raw(), transa.into(), transb.into(), m, n, alpha, A, lda, stride_A, beta, B, ldb, stride_B, C, ldc, stride_C, batch_count)
+);
+
+//==============================================================================
+// DGMM functions - Diagonal Matrix Multiplication
+//==============================================================================
+
+/// Multiplication by a diagonal matrix
+///
+/// Computes one of the following:
+///
+/// C := diag(x) * A   (side = Left)
+/// C := A * diag(x)   (side = Right)
+///
+/// where A and C are m x n matrices and the diagonal matrix is stored as
+/// the vector x, scaling the rows (Left) or columns (Right) of A.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `side` - Whether the diagonal matrix multiplies A from the left or the right
+/// * `m` - Number of rows of matrices A and C
+/// * `n` - Number of columns of matrices A and C
+/// * `A` - Buffer storing matrix A
+/// * `lda` - Leading dimension of matrix A
+/// * `x` - Buffer storing the diagonal, m elements for `Side::Left` and n for `Side::Right`
+/// * `incx` - Stride between consecutive elements of x
+/// * `C` - Buffer storing matrix C
+/// * `ldc` - Leading dimension of matrix C
+pub unsafe fn dgmm<T>(
+    handle: &Handle,
+    side: Side,
+    m: i32,
+    n: i32,
+    A: *const T,
+    lda: i32,
+    x: *const T,
+    incx: i32,
+    C: *mut T,
+    ldc: i32,
+) -> Result<()>
+where
+    T: DgmmType,
+{
+    unsafe { T::rocblas_dgmm(handle, side, m, n, A, lda, x, incx, C, ldc) }
+}
+
+/// Batched multiplication by a diagonal matrix
+///
+/// Applies [`dgmm`] to each instance of the batch.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `side` - Whether the diagonal matrices multiply A_i from the left or the right
+/// * `m` - Number of rows of matrices A_i and C_i
+/// * `n` - Number of columns of matrices A_i and C_i
+/// * `A` - Array of pointers to matrices A_i
+/// * `lda` - Leading dimension of matrices A_i
+/// * `x` - Array of pointers to diagonals x_i
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `C` - Array of pointers to matrices C_i
+/// * `ldc` - Leading dimension of matrices C_i
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn dgmm_batched<T>(
+    handle: &Handle,
+    side: Side,
+    m: i32,
+    n: i32,
+    A: *const *const T,
+    lda: i32,
+    x: *const *const T,
+    incx: i32,
+    C: *const *mut T,
+    ldc: i32,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: DgmmBatchedType,
+{
+    unsafe { T::rocblas_dgmm_batched(handle, side, m, n, A, lda, x, incx, C, ldc, batch_count) }
+}
+
+/// Strided batched multiplication by a diagonal matrix
+///
+/// Applies [`dgmm`] to each instance of the batch, with the instances laid
+/// out at fixed strides in single buffers.
+///
+/// # Arguments
+/// * `handle` - RocBLAS handle
+/// * `side` - Whether the diagonal matrices multiply A_i from the left or the right
+/// * `m` - Number of rows of matrices A_i and C_i
+/// * `n` - Number of columns of matrices A_i and C_i
+/// * `A` - Pointer to the first matrix A_1
+/// * `lda` - Leading dimension of matrices A_i
+/// * `stride_A` - Stride from start of one matrix (A_i) to the next (A_i+1)
+/// * `x` - Pointer to the first diagonal x_1
+/// * `incx` - Stride between consecutive elements of x_i
+/// * `stride_x` - Stride from start of one vector (x_i) to the next (x_i+1)
+/// * `C` - Pointer to the first matrix C_1
+/// * `ldc` - Leading dimension of matrices C_i
+/// * `stride_C` - Stride from start of one matrix (C_i) to the next (C_i+1)
+/// * `batch_count` - Number of instances in the batch
+pub unsafe fn dgmm_strided_batched<T>(
+    handle: &Handle,
+    side: Side,
+    m: i32,
+    n: i32,
+    A: *const T,
+    lda: i32,
+    stride_A: i64,
+    x: *const T,
+    incx: i32,
+    stride_x: i64,
+    C: *mut T,
+    ldc: i32,
+    stride_C: i64,
+    batch_count: i32,
+) -> Result<()>
+where
+    T: DgmmStridedBatchedType,
+{
+    unsafe {
+        T::rocblas_dgmm_strided_batched(
+            handle,
+            side,
+            m,
+            n,
+            A,
+            lda,
+            stride_A,
+            x,
+            incx,
+            stride_x,
+            C,
+            ldc,
+            stride_C,
+            batch_count,
+        )
+    }
+}
+
+impl_rocblas_traits!(
+    DgmmType,
+    DgmmFn,
+    {
+        f32 => ffi::rocblas_sdgmm,
+        f64 => ffi::rocblas_ddgmm,
+        ffi::rocblas_float_complex => ffi::rocblas_cdgmm,
+        ffi::rocblas_double_complex => ffi::rocblas_zdgmm,
+    },
+    rocblas_dgmm,
+    (handle: &Handle, side: Side, m: i32, n: i32, A: *const Self, lda: i32, x: *const Self, incx: i32, C: *mut Self, ldc: i32),
+    (*mut _rocblas_handle, ffi::rocblas_side, i32, i32, *const T, i32, *const T, i32, *mut T, i32),
+    (handle.as_raw(), side.into(), m, n, A, lda, x, incx, C, ldc)
+);
+
+impl_rocblas_traits!(
+    DgmmBatchedType,
+    DgmmBatchedFn,
+    {
+        f32 => ffi::rocblas_sdgmm_batched,
+        f64 => ffi::rocblas_ddgmm_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_cdgmm_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_zdgmm_batched,
+    },
+    rocblas_dgmm_batched,
+    (handle: &Handle, side: Side, m: i32, n: i32, A: *const *const Self, lda: i32, x: *const *const Self, incx: i32, C: *const *mut Self, ldc: i32, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_side, i32, i32, *const *const T, i32, *const *const T, i32, *const *mut T, i32, i32),
+    (handle.as_raw(), side.into(), m, n, A, lda, x, incx, C, ldc, batch_count)
+);
+
+impl_rocblas_traits!(
+    DgmmStridedBatchedType,
+    DgmmStridedBatchedFn,
+    {
+        f32 => ffi::rocblas_sdgmm_strided_batched,
+        f64 => ffi::rocblas_ddgmm_strided_batched,
+        ffi::rocblas_float_complex => ffi::rocblas_cdgmm_strided_batched,
+        ffi::rocblas_double_complex => ffi::rocblas_zdgmm_strided_batched,
+    },
+    rocblas_dgmm_strided_batched,
+    (handle: &Handle, side: Side, m: i32, n: i32, A: *const Self, lda: i32, stride_A: i64, x: *const Self, incx: i32, stride_x: i64, C: *mut Self, ldc: i32, stride_C: i64, batch_count: i32),
+    (*mut _rocblas_handle, ffi::rocblas_side, i32, i32, *const T, i32, i64, *const T, i32, i64, *mut T, i32, i64, i32),
+    (handle.as_raw(), side.into(), m, n, A, lda, stride_A, x, incx, stride_x, C, ldc, stride_C, batch_count)
+);
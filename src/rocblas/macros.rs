@@ -1,16 +1,58 @@
 /// impl helper macro for rocblas functions
 #[macro_export]
 macro_rules! impl_rocblas_func {
-    ($trait_name:ident, $fn_type:ident, {$( $t:ty => $func:path ),* $(,)?}) => {
+    ($trait_name:ident, $fn_type:ident, {$( $($t:ident)::+ => $func:path ),* $(,)?}) => {
         $(
-            impl $trait_name for $t {
+            impl $trait_name for $($t)::+ {
                 fn func() -> $fn_type<Self> {
                     $func
                 }
             }
+            $crate::impl_rocblas_interop_func!($trait_name, $fn_type, $($t)::+ => $func);
         )*
     };
 }
+/// Implements the trait for the num-complex or half type matching a rocBLAS
+/// type, reusing its function; other types expand to nothing
+#[macro_export]
+macro_rules! impl_rocblas_interop_func {
+    ($trait_name:ident, $fn_type:ident, ffi::rocblas_float_complex => $func:path) => {
+        #[cfg(feature = "num-complex")]
+        impl $trait_name for num_complex::Complex32 {
+            fn func() -> $fn_type<Self> {
+                // Complex32 is repr(C) with the layout of rocblas_float_complex
+                unsafe {
+                    std::mem::transmute::<$fn_type<ffi::rocblas_float_complex>, $fn_type<Self>>(
+                        $func,
+                    )
+                }
+            }
+        }
+    };
+    ($trait_name:ident, $fn_type:ident, ffi::rocblas_double_complex => $func:path) => {
+        #[cfg(feature = "num-complex")]
+        impl $trait_name for num_complex::Complex64 {
+            fn func() -> $fn_type<Self> {
+                // Complex64 is repr(C) with the layout of rocblas_double_complex
+                unsafe {
+                    std::mem::transmute::<$fn_type<ffi::rocblas_double_complex>, $fn_type<Self>>(
+                        $func,
+                    )
+                }
+            }
+        }
+    };
+    ($trait_name:ident, $fn_type:ident, ffi::rocblas_half => $func:path) => {
+        #[cfg(feature = "half")]
+        impl $trait_name for half::f16 {
+            fn func() -> $fn_type<Self> {
+                // f16 is a transparent u16, like rocblas_half
+                unsafe { std::mem::transmute::<$fn_type<ffi::rocblas_half>, $fn_type<Self>>($func) }
+            }
+        }
+    };
+    ($trait_name:ident, $fn_type:ident, $($t:ident)::+ => $func:path) => {};
+}
 #[macro_export]
 macro_rules! impl_rocblas_func_inner {
     ($func:expr, $($arg:expr),+ $(,)?) => {{
@@ -49,4 +91,134 @@ macro_rules! impl_rocblas_traits {
 
         impl_rocblas_func!($trait_name, $fn_type, $ffi_map);
     };
-}
\ No newline at end of file
+}
+/// Implements the GEMM traits for a type with the layout of a rocBLAS type
+/// by converting the scalars and forwarding to that type
+#[macro_export]
+macro_rules! impl_rocblas_gemm_forward {
+    ($t:ty => $raw:ty) => {
+        impl $crate::rocblas::level3::GemmType for $t {
+            unsafe fn rocblas_gemm(
+                handle: &$crate::rocblas::Handle,
+                transa: $crate::rocblas::types::Operation,
+                transb: $crate::rocblas::types::Operation,
+                m: i32,
+                n: i32,
+                k: i32,
+                alpha: &Self,
+                A: *const Self,
+                lda: i32,
+                B: *const Self,
+                ldb: i32,
+                beta: &Self,
+                C: *mut Self,
+                ldc: i32,
+            ) -> $crate::rocblas::Result<()> {
+                unsafe {
+                    <$raw as $crate::rocblas::level3::GemmType>::rocblas_gemm(
+                        handle,
+                        transa,
+                        transb,
+                        m,
+                        n,
+                        k,
+                        &(*alpha).into(),
+                        A.cast(),
+                        lda,
+                        B.cast(),
+                        ldb,
+                        &(*beta).into(),
+                        C.cast(),
+                        ldc,
+                    )
+                }
+            }
+        }
+
+        impl $crate::rocblas::level3::GemmBatchedType for $t {
+            unsafe fn rocblas_gemm_batched(
+                handle: &$crate::rocblas::Handle,
+                transa: $crate::rocblas::types::Operation,
+                transb: $crate::rocblas::types::Operation,
+                m: i32,
+                n: i32,
+                k: i32,
+                alpha: &Self,
+                A: *const *const Self,
+                lda: i32,
+                B: *const *const Self,
+                ldb: i32,
+                beta: &Self,
+                C: *const *mut Self,
+                ldc: i32,
+                batch_count: i32,
+            ) -> $crate::rocblas::Result<()> {
+                unsafe {
+                    <$raw as $crate::rocblas::level3::GemmBatchedType>::rocblas_gemm_batched(
+                        handle,
+                        transa,
+                        transb,
+                        m,
+                        n,
+                        k,
+                        &(*alpha).into(),
+                        A.cast(),
+                        lda,
+                        B.cast(),
+                        ldb,
+                        &(*beta).into(),
+                        C.cast(),
+                        ldc,
+                        batch_count,
+                    )
+                }
+            }
+        }
+
+        impl $crate::rocblas::level3::GemmStridedBatchedType for $t {
+            unsafe fn rocblas_gemm_strided_batched(
+                handle: &$crate::rocblas::Handle,
+                transa: $crate::rocblas::types::Operation,
+                transb: $crate::rocblas::types::Operation,
+                m: i32,
+                n: i32,
+                k: i32,
+                alpha: &Self,
+                A: *const Self,
+                lda: i32,
+                stride_A: i64,
+                B: *const Self,
+                ldb: i32,
+                stride_B: i64,
+                beta: &Self,
+                C: *mut Self,
+                ldc: i32,
+                stride_C: i64,
+                batch_count: i32,
+            ) -> $crate::rocblas::Result<()> {
+                unsafe {
+                    <$raw as $crate::rocblas::level3::GemmStridedBatchedType>::rocblas_gemm_strided_batched(
+                        handle,
+                        transa,
+                        transb,
+                        m,
+                        n,
+                        k,
+                        &(*alpha).into(),
+                        A.cast(),
+                        lda,
+                        stride_A,
+                        B.cast(),
+                        ldb,
+                        stride_B,
+                        &(*beta).into(),
+                        C.cast(),
+                        ldc,
+                        stride_C,
+                        batch_count,
+                    )
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,310 @@
+// src/rocblas/matrix.rs - Column-major matrix and vector types
+
+//! Device matrices and vectors that carry their own layout
+//!
+//! [`BlasMatrix`] and [`BlasVector`] own a [`DeviceMemory`] buffer together
+//! with the [`MatrixDims`] or [`VectorDims`] describing it, so the
+//! shape-checked routines in [`safe`](crate::rocblas::safe) can be called
+//! without passing dimensions around by hand.
+//!
+//! rocBLAS works on column-major data while [`ROCArray`] is row-major. The
+//! `from_rocarray` and `into_rocarray` conversions transpose the storage so
+//! that element `(i, j)` is the same on both sides. They run on the
+//! `ROCArray` stream, so synchronize the handle's stream before converting
+//! a result of an asynchronous rocBLAS call.
+
+use crate::error::{Result, invalid_argument};
+use crate::hip::DeviceMemory;
+use crate::rocarray::kernels::NumericOps;
+use crate::rocarray::{ROCArray, Shape};
+use crate::rocblas::handle::Handle;
+use crate::rocblas::level1::AxpyType;
+use crate::rocblas::level2::GemvType;
+use crate::rocblas::level3::GemmType;
+use crate::rocblas::safe::{self, MatrixDims, VectorDims};
+use crate::rocblas::types::Operation;
+
+/// A column-major matrix in device memory
+pub struct BlasMatrix<T> {
+    data: DeviceMemory<T>,
+    dims: MatrixDims,
+}
+
+impl<T> BlasMatrix<T> {
+    /// A zeroed, densely packed `rows x cols` matrix
+    pub fn new(rows: i32, cols: i32) -> Result<Self> {
+        Self::with_ld(rows, cols, rows.max(1))
+    }
+
+    /// A zeroed `rows x cols` matrix whose columns are `ld` elements apart
+    pub fn with_ld(rows: i32, cols: i32, ld: i32) -> Result<Self> {
+        let dims = MatrixDims::with_ld(rows, cols, ld);
+        if rows < 0 || cols < 0 || ld < rows.max(1) {
+            return Err(invalid_argument("invalid matrix dimensions"));
+        }
+
+        let mut data = DeviceMemory::new(ld as usize * cols as usize)?;
+        data.memset(0)?;
+        Ok(Self { data, dims })
+    }
+
+    /// Use existing device memory laid out as described by `dims`
+    pub fn from_device_memory(data: DeviceMemory<T>, dims: MatrixDims) -> Result<Self> {
+        dims.check_len(data.count())?;
+        Ok(Self { data, dims })
+    }
+
+    /// Number of rows
+    pub fn rows(&self) -> i32 {
+        self.dims.rows
+    }
+
+    /// Number of columns
+    pub fn cols(&self) -> i32 {
+        self.dims.cols
+    }
+
+    /// Leading dimension
+    pub fn ld(&self) -> i32 {
+        self.dims.ld
+    }
+
+    /// The matrix layout
+    pub fn dims(&self) -> MatrixDims {
+        self.dims
+    }
+
+    /// The underlying device memory
+    pub fn device_memory(&self) -> &DeviceMemory<T> {
+        &self.data
+    }
+
+    /// Take the underlying device memory
+    pub fn into_device_memory(self) -> DeviceMemory<T> {
+        self.data
+    }
+}
+
+impl<T: Copy + Default> BlasMatrix<T> {
+    /// Upload a densely packed column-major `rows x cols` matrix
+    pub fn from_host(rows: i32, cols: i32, data: &[T]) -> Result<Self> {
+        let mut matrix = Self::new(rows, cols)?;
+        let dims = MatrixDims::new(rows, cols);
+        safe::set_matrix(data, dims, &mut matrix.data, matrix.dims)?;
+        Ok(matrix)
+    }
+
+    /// Download the matrix, densely packed in column-major order
+    pub fn to_host(&self) -> Result<Vec<T>> {
+        let dims = MatrixDims::new(self.dims.rows, self.dims.cols);
+        let mut host = vec![T::default(); dims.rows as usize * dims.cols as usize];
+        safe::get_matrix(&self.data, self.dims, &mut host, dims)?;
+        Ok(host)
+    }
+}
+
+impl<T: NumericOps> BlasMatrix<T> {
+    /// Copy a 2D row-major array into a column-major matrix
+    pub fn from_rocarray(array: &ROCArray<T>) -> Result<Self> {
+        let &[rows, cols] = array.dims() else {
+            return Err(invalid_argument("expected a 2D array"));
+        };
+
+        // The row-major transpose has the column-major layout of the array
+        let transposed = array.transpose().to_array()?;
+        Self::from_device_memory(
+            transposed.into_device_memory(),
+            MatrixDims::new(rows as i32, cols as i32),
+        )
+    }
+
+    /// Convert into a 2D row-major array of the same shape
+    pub fn into_rocarray(self) -> Result<ROCArray<T>> {
+        let MatrixDims { rows, cols, ld } = self.dims;
+        let (rows, cols, ld) = (rows as usize, cols as usize, ld as usize);
+
+        // Seen row-major, the storage is a `cols x ld` array holding the
+        // transpose plus padding; the last column may be unpadded
+        let mut data = self.data;
+        if data.count() < cols * ld {
+            let mut padded = DeviceMemory::new(cols * ld)?;
+            padded.copy_from_device(&data)?;
+            data = padded;
+        }
+
+        let storage = ROCArray::from_device_memory(data, Shape::new_2d(cols, ld))?;
+        storage.view().narrow(1, 0, rows)?.transpose().to_array()
+    }
+}
+
+impl<T: GemmType> BlasMatrix<T> {
+    /// General matrix-matrix multiplication into this matrix
+    ///
+    /// self := alpha * op(A) * op(B) + beta * self
+    #[allow(clippy::too_many_arguments)]
+    pub fn gemm(
+        &mut self,
+        handle: &Handle,
+        transa: Operation,
+        transb: Operation,
+        alpha: &T,
+        a: &BlasMatrix<T>,
+        b: &BlasMatrix<T>,
+        beta: &T,
+    ) -> Result<()> {
+        Ok(safe::gemm(
+            handle,
+            transa,
+            transb,
+            alpha,
+            &a.data,
+            a.dims,
+            &b.data,
+            b.dims,
+            beta,
+            &mut self.data,
+            self.dims,
+        )?)
+    }
+}
+
+/// A vector in device memory
+pub struct BlasVector<T> {
+    data: DeviceMemory<T>,
+    dims: VectorDims,
+}
+
+impl<T> BlasVector<T> {
+    /// A zeroed contiguous vector of `len` elements
+    pub fn new(len: i32) -> Result<Self> {
+        if len < 0 {
+            return Err(invalid_argument("invalid vector length"));
+        }
+
+        let mut data = DeviceMemory::new(len as usize)?;
+        data.memset(0)?;
+        Ok(Self {
+            data,
+            dims: VectorDims::new(len),
+        })
+    }
+
+    /// Use existing device memory laid out as described by `dims`
+    pub fn from_device_memory(data: DeviceMemory<T>, dims: VectorDims) -> Result<Self> {
+        dims.check_len(data.count())?;
+        Ok(Self { data, dims })
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> i32 {
+        self.dims.len
+    }
+
+    /// Whether the vector has no elements
+    pub fn is_empty(&self) -> bool {
+        self.dims.len == 0
+    }
+
+    /// The vector layout
+    pub fn dims(&self) -> VectorDims {
+        self.dims
+    }
+
+    /// The underlying device memory
+    pub fn device_memory(&self) -> &DeviceMemory<T> {
+        &self.data
+    }
+
+    /// Take the underlying device memory
+    pub fn into_device_memory(self) -> DeviceMemory<T> {
+        self.data
+    }
+}
+
+impl<T: Copy + Default> BlasVector<T> {
+    /// Upload a contiguous vector
+    pub fn from_host(data: &[T]) -> Result<Self> {
+        let len = i32::try_from(data.len()).map_err(|_| invalid_argument("vector too long"))?;
+        let mut vector = Self::new(len)?;
+        safe::set_vector(data, vector.dims, &mut vector.data, vector.dims)?;
+        Ok(vector)
+    }
+
+    /// Download the vector into a contiguous host vector
+    pub fn to_host(&self) -> Result<Vec<T>> {
+        let mut host = vec![T::default(); self.dims.len as usize];
+        safe::get_vector(
+            &self.data,
+            self.dims,
+            &mut host,
+            VectorDims::new(self.dims.len),
+        )?;
+        Ok(host)
+    }
+}
+
+impl<T: NumericOps> BlasVector<T> {
+    /// Copy the elements of an array, in row-major order, into a vector
+    pub fn from_rocarray(array: &ROCArray<T>) -> Result<Self> {
+        let len = i32::try_from(array.len()).map_err(|_| invalid_argument("array too long"))?;
+        let mut data = DeviceMemory::new(array.len())?;
+        data.copy_from_device(array.device_memory())?;
+        Self::from_device_memory(data, VectorDims::new(len))
+    }
+
+    /// Convert into a 1D array
+    ///
+    /// Contiguous vectors keep their memory; strided ones are gathered
+    /// through the host.
+    pub fn into_rocarray(self) -> Result<ROCArray<T>> {
+        if self.dims.inc == 1 {
+            let shape = Shape::new_1d(self.dims.len as usize);
+            return ROCArray::from_device_memory(self.data, shape);
+        }
+        ROCArray::from_vec(self.to_host()?)
+    }
+}
+
+impl<T: GemvType> BlasVector<T> {
+    /// General matrix-vector multiplication into this vector
+    ///
+    /// self := alpha * op(A) * x + beta * self
+    pub fn gemv(
+        &mut self,
+        handle: &Handle,
+        trans: Operation,
+        alpha: &T,
+        a: &BlasMatrix<T>,
+        x: &BlasVector<T>,
+        beta: &T,
+    ) -> Result<()> {
+        Ok(safe::gemv(
+            handle,
+            trans,
+            alpha,
+            &a.data,
+            a.dims,
+            &x.data,
+            x.dims,
+            beta,
+            &mut self.data,
+            self.dims,
+        )?)
+    }
+}
+
+impl<T: AxpyType> BlasVector<T> {
+    /// Add a scaled vector to this one
+    ///
+    /// self := alpha * x + self
+    pub fn axpy(&mut self, handle: &Handle, alpha: &T, x: &BlasVector<T>) -> Result<()> {
+        Ok(safe::axpy(
+            handle,
+            alpha,
+            &x.data,
+            x.dims,
+            &mut self.data,
+            self.dims,
+        )?)
+    }
+}
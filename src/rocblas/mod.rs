@@ -1,11 +1,19 @@
 // src/rocblas/mod.rs
 
 // Private modules
+pub mod buffer;
+pub mod complex;
 pub mod error;
+pub mod half;
 pub mod handle;
+#[cfg(feature = "ilp64")]
+pub mod ilp64;
 pub mod level1;
 pub mod level2;
 pub mod level3;
+pub mod matrix;
+pub mod safe;
+pub mod tuner;
 pub mod types;
 pub mod utils;
 pub(crate) mod macros;
@@ -20,12 +28,14 @@ pub mod ffi;
 
 // Re-export the main components for the public API
 pub use error::{Error, Result};
-pub use handle::Handle;
+pub use handle::{Handle, Workspace, clear_handle_pool, with_handle};
 pub use level1::{
     amax,
+    amax_checked,
     amax_batched,
     amax_strided_batched,
     amin,
+    amin_checked,
     amin_batched,
     amin_strided_batched,
     asum,
@@ -34,12 +44,14 @@ pub use level1::{
     // strided batched variants
     asum_strided_batched,
     axpy,
+    axpy_ex,
     axpy_batched,
     axpy_strided_batched,
     copy,
     copy_batched,
     copy_strided_batched,
     dot,
+    dot_ex,
     dot_batched,
     dot_strided_batched,
     dotc,
@@ -49,6 +61,7 @@ pub use level1::{
     dotu_batched,
     dotu_strided_batched,
     nrm2,
+    nrm2_ex,
     nrm2_batched,
     nrm2_strided_batched,
     rot,
@@ -64,6 +77,7 @@ pub use level1::{
     rotmg_batched,
     rotmg_strided_batched,
     scal,
+    scal_ex,
     scal_batched,
     scal_strided_batched,
     swap,
@@ -82,14 +96,60 @@ pub use level2::{
     hbmv,
     hbmv_batched,
     hbmv_strided_batched,
+    her,
+    her_batched,
+    her_strided_batched,
+    her2,
+    her2_batched,
+    her2_strided_batched,
+    hpmv,
+    hpmv_batched,
+    hpmv_strided_batched,
+    hpr,
+    hpr_batched,
+    hpr_strided_batched,
+    hpr2,
+    hpr2_batched,
+    hpr2_strided_batched,
+    sbmv,
+    sbmv_batched,
+    sbmv_strided_batched,
+    spmv,
+    spmv_batched,
+    spmv_strided_batched,
+    tbmv,
+    tbmv_batched,
+    tbmv_strided_batched,
+    tbsv,
+    tbsv_batched,
+    tbsv_strided_batched,
+    tpmv,
+    tpmv_batched,
+    tpmv_strided_batched,
+    tpsv,
+    tpsv_batched,
+    tpsv_strided_batched,
+    trmv,
+    trmv_batched,
+    trmv_strided_batched,
+    trsv,
+    trsv_batched,
+    trsv_strided_batched,
 };
-pub use level3::{gemm, gemm_batched, gemm_strided_batched};
+pub use level3::{
+    dgmm, dgmm_batched, dgmm_strided_batched, geam, geam_batched, geam_strided_batched, gemm,
+    gemm_batched, gemm_strided_batched, symm, symm_batched, symm_strided_batched, syr2k,
+    syr2k_batched, syr2k_strided_batched, syrk, syrk_batched, syrk_strided_batched, trmm,
+    trmm_batched, trmm_strided_batched, trsm, trsm_batched, trsm_strided_batched,
+};
+pub use matrix::{BlasMatrix, BlasVector};
+pub use tuner::GemmTuner;
 pub use types::{
     rocblas_bfloat16, rocblas_datatype, rocblas_diagonal, rocblas_double_complex, rocblas_fill,
     rocblas_float_complex, rocblas_half, rocblas_operation, rocblas_side,
 };
 pub use utils::{
-    AtomicsMode, GemmAlgo, GemmFlags, LayerMode, MathMode, PerformanceMetric, PointerMode,
+    AtomicsMode, GemmAlgo, GemmFlags, LayerMode, LogConfig, MathMode, PerformanceMetric, PointerMode,
     get_atomics_mode, get_math_mode, get_performance_metric, get_pointer_mode, set_atomics_mode,
     set_math_mode, set_performance_metric, set_pointer_mode,
 };
@@ -0,0 +1,1130 @@
+// src/rocblas/safe.rs - Buffer-checked BLAS wrappers and transfers
+
+//! Safe wrappers over the pointer-based BLAS routines, and over rocBLAS's
+//! strided host/device transfers
+//!
+//! Matrices and vectors are passed as [`DeviceMemory`] buffers together with
+//! a [`MatrixDims`] or [`VectorDims`] describing their column-major layout.
+//! Before anything is launched the dimensions are checked against each other
+//! and against the buffer lengths, so rocBLAS never reads or writes outside
+//! a buffer. Inconsistent arguments are reported as
+//! `rocblas_status_invalid_size`.
+//!
+//! Scalars are passed by host reference. The rotation routines take them
+//! as a [`ScalarArg`] or [`ScalarArgMut`] instead, which may also be device
+//! memory. Each wrapper switches the handle to the
+//! [`PointerMode`](crate::rocblas::PointerMode) its scalars need for the
+//! duration of the call and restores the previous mode afterwards.
+
+use crate::hip::memory::PendingCopy;
+use crate::hip::{DeviceMemory, Stream};
+use crate::rocblas::error::{Error, Result};
+use crate::rocblas::ffi;
+use crate::rocblas::handle::Handle;
+use crate::rocblas::level1::{self, AxpyType, RotType, RotgType, RotmType, RotmgType};
+use crate::rocblas::level2::{GemvType, GerType, GercType, GeruType, HemvType};
+use crate::rocblas::level3::{DgmmType, GeamType, GemmType};
+use crate::rocblas::types::{Fill, Operation, Side};
+use crate::rocblas::utils::PointerMode;
+
+/// Layout of a column-major matrix stored in a buffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatrixDims {
+    /// Number of rows
+    pub rows: i32,
+    /// Number of columns
+    pub cols: i32,
+    /// Leading dimension: distance between the starts of adjacent columns
+    pub ld: i32,
+}
+
+impl MatrixDims {
+    /// A densely packed `rows x cols` matrix (`ld == rows`)
+    pub fn new(rows: i32, cols: i32) -> Self {
+        Self {
+            rows,
+            cols,
+            ld: rows.max(1),
+        }
+    }
+
+    /// A `rows x cols` matrix with an explicit leading dimension
+    pub fn with_ld(rows: i32, cols: i32, ld: i32) -> Self {
+        Self { rows, cols, ld }
+    }
+
+    /// Rows and columns after applying `op`
+    fn op_shape(&self, op: Operation) -> (i32, i32) {
+        match op {
+            Operation::None => (self.rows, self.cols),
+            Operation::Transpose | Operation::ConjugateTranspose => (self.cols, self.rows),
+        }
+    }
+
+    /// Number of elements the layout spans
+    fn span(&self) -> usize {
+        if self.rows == 0 || self.cols == 0 {
+            0
+        } else {
+            self.ld as usize * (self.cols as usize - 1) + self.rows as usize
+        }
+    }
+
+    fn check<T>(&self, buffer: &DeviceMemory<T>) -> Result<()> {
+        self.check_len(buffer.count())
+    }
+
+    pub(crate) fn check_len(&self, len: usize) -> Result<()> {
+        if self.rows < 0 || self.cols < 0 || self.ld < self.rows.max(1) {
+            return Err(invalid_size());
+        }
+        ensure(self.span() <= len)
+    }
+}
+
+/// Layout of a vector stored in a buffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VectorDims {
+    /// Number of elements
+    pub len: i32,
+    /// Stride between consecutive elements; negative strides walk backwards
+    pub inc: i32,
+}
+
+impl VectorDims {
+    /// A contiguous vector (`inc == 1`)
+    pub fn new(len: i32) -> Self {
+        Self { len, inc: 1 }
+    }
+
+    /// A vector with an explicit stride
+    pub fn strided(len: i32, inc: i32) -> Self {
+        Self { len, inc }
+    }
+
+    /// Number of elements the layout spans
+    fn span(&self) -> usize {
+        if self.len == 0 {
+            0
+        } else {
+            (self.len as usize - 1) * self.inc.unsigned_abs() as usize + 1
+        }
+    }
+
+    fn check<T>(&self, buffer: &DeviceMemory<T>) -> Result<()> {
+        self.check_len(buffer.count())
+    }
+
+    pub(crate) fn check_len(&self, len: usize) -> Result<()> {
+        if self.len < 0 || self.inc == 0 {
+            return Err(invalid_size());
+        }
+        ensure(self.span() <= len)
+    }
+}
+
+/// A scalar argument on the host or in device memory
+///
+/// Host scalars are passed by reference (`&T`, or `&[T; N]` for arrays
+/// such as the `param` of [`rotm`]); device scalars as the buffer holding
+/// them, starting at its first element. The wrappers taking scalars this
+/// way pick the pointer mode from the arguments, which must all live on
+/// the same side.
+pub trait ScalarArg<T> {
+    /// Pointer mode the scalar needs
+    fn pointer_mode(&self) -> PointerMode;
+
+    /// Get a pointer to the first of `len` elements, checking they exist
+    fn scalar_ptr(&self, len: usize) -> Result<*const T>;
+}
+
+/// A scalar argument the routine writes to, on the host or in device memory
+///
+/// See [`ScalarArg`]; host scalars are passed as `&mut T` or `&mut [T; N]`.
+pub trait ScalarArgMut<T> {
+    /// Pointer mode the scalar needs
+    fn pointer_mode(&self) -> PointerMode;
+
+    /// Get a pointer to the first of `len` elements, checking they exist
+    fn scalar_mut_ptr(&mut self, len: usize) -> Result<*mut T>;
+}
+
+impl<T> ScalarArg<T> for &T {
+    fn pointer_mode(&self) -> PointerMode {
+        PointerMode::Host
+    }
+
+    fn scalar_ptr(&self, len: usize) -> Result<*const T> {
+        ensure(len <= 1)?;
+        Ok(*self)
+    }
+}
+
+impl<T, const N: usize> ScalarArg<T> for &[T; N] {
+    fn pointer_mode(&self) -> PointerMode {
+        PointerMode::Host
+    }
+
+    fn scalar_ptr(&self, len: usize) -> Result<*const T> {
+        ensure(len <= N)?;
+        Ok(self.as_ptr())
+    }
+}
+
+impl<T> ScalarArg<T> for &DeviceMemory<T> {
+    fn pointer_mode(&self) -> PointerMode {
+        PointerMode::Device
+    }
+
+    fn scalar_ptr(&self, len: usize) -> Result<*const T> {
+        ensure(len <= self.count())?;
+        Ok(self.as_ptr().cast())
+    }
+}
+
+impl<T> ScalarArgMut<T> for &mut T {
+    fn pointer_mode(&self) -> PointerMode {
+        PointerMode::Host
+    }
+
+    fn scalar_mut_ptr(&mut self, len: usize) -> Result<*mut T> {
+        ensure(len <= 1)?;
+        Ok(&mut **self)
+    }
+}
+
+impl<T, const N: usize> ScalarArgMut<T> for &mut [T; N] {
+    fn pointer_mode(&self) -> PointerMode {
+        PointerMode::Host
+    }
+
+    fn scalar_mut_ptr(&mut self, len: usize) -> Result<*mut T> {
+        ensure(len <= N)?;
+        Ok(self.as_mut_ptr())
+    }
+}
+
+impl<T> ScalarArgMut<T> for &mut DeviceMemory<T> {
+    fn pointer_mode(&self) -> PointerMode {
+        PointerMode::Device
+    }
+
+    fn scalar_mut_ptr(&mut self, len: usize) -> Result<*mut T> {
+        ensure(len <= self.count())?;
+        Ok(self.as_ptr().cast())
+    }
+}
+
+/// Get the pointer mode shared by the scalar arguments of one call
+///
+/// rocBLAS reads every scalar of a call the same way, so host and device
+/// scalars can't be mixed.
+fn scalar_mode(modes: &[PointerMode]) -> Result<PointerMode> {
+    match modes.split_first() {
+        Some((&mode, rest)) if rest.iter().all(|&other| other == mode) => Ok(mode),
+        _ => Err(Error::new(
+            ffi::rocblas_status__rocblas_status_invalid_value,
+        )),
+    }
+}
+
+fn invalid_size() -> Error {
+    Error::new(ffi::rocblas_status__rocblas_status_invalid_size)
+}
+
+fn ensure(condition: bool) -> Result<()> {
+    if condition {
+        Ok(())
+    } else {
+        Err(invalid_size())
+    }
+}
+
+//==============================================================================
+// Level 1
+//==============================================================================
+
+/// Add a scaled vector to another
+///
+/// y := alpha * x + y
+pub fn axpy<T>(
+    handle: &Handle,
+    alpha: &T,
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    y: &mut DeviceMemory<T>,
+    y_dims: VectorDims,
+) -> Result<()>
+where
+    T: AxpyType,
+{
+    x_dims.check(x)?;
+    y_dims.check(y)?;
+    ensure(x_dims.len == y_dims.len)?;
+
+    with_pointer_mode(handle, PointerMode::Host, || unsafe {
+        level1::axpy(
+            handle,
+            x_dims.len,
+            alpha,
+            x.as_ptr().cast(),
+            x_dims.inc,
+            y.as_ptr().cast(),
+            y_dims.inc,
+        )
+    })
+}
+
+/// Apply a Givens rotation to a pair of vectors
+///
+/// x_i := c * x_i + s * y_i
+/// y_i := c * y_i - conj(s) * x_i
+///
+/// `c` and `s` are both host values or both in device memory, e.g. as
+/// written by [`rotg`]; the handle is put in the matching pointer mode for
+/// the call.
+pub fn rot<T>(
+    handle: &Handle,
+    x: &mut DeviceMemory<T>,
+    x_dims: VectorDims,
+    y: &mut DeviceMemory<T>,
+    y_dims: VectorDims,
+    c: impl ScalarArg<T::Real>,
+    s: impl ScalarArg<T>,
+) -> Result<()>
+where
+    T: RotType,
+{
+    x_dims.check(x)?;
+    y_dims.check(y)?;
+    ensure(x_dims.len == y_dims.len)?;
+    let mode = scalar_mode(&[c.pointer_mode(), s.pointer_mode()])?;
+    let (c, s) = (c.scalar_ptr(1)?, s.scalar_ptr(1)?);
+
+    with_pointer_mode(handle, mode, || unsafe {
+        level1::rot(
+            handle,
+            x_dims.len,
+            x.as_ptr().cast(),
+            x_dims.inc,
+            y.as_ptr().cast(),
+            y_dims.inc,
+            c,
+            s,
+        )
+    })
+}
+
+/// Construct a Givens rotation
+///
+/// Reads `a` and `b` and overwrites them with r and z, and writes the
+/// rotation to `c` and `s`. The scalars are all on the host or all in
+/// device memory, where the first element of each buffer is used; the
+/// handle is put in the matching pointer mode for the call. Device results
+/// can be passed on to [`rot`] without a host round trip.
+pub fn rotg<T>(
+    handle: &Handle,
+    mut a: impl ScalarArgMut<T>,
+    mut b: impl ScalarArgMut<T>,
+    mut c: impl ScalarArgMut<T::Real>,
+    mut s: impl ScalarArgMut<T>,
+) -> Result<()>
+where
+    T: RotgType,
+{
+    let mode = scalar_mode(&[
+        a.pointer_mode(),
+        b.pointer_mode(),
+        c.pointer_mode(),
+        s.pointer_mode(),
+    ])?;
+    let (a, b) = (a.scalar_mut_ptr(1)?, b.scalar_mut_ptr(1)?);
+    let (c, s) = (c.scalar_mut_ptr(1)?, s.scalar_mut_ptr(1)?);
+
+    with_pointer_mode(handle, mode, || unsafe { level1::rotg(handle, a, b, c, s) })
+}
+
+/// Apply a modified Givens rotation to a pair of vectors
+///
+/// [x_i; y_i] := H * [x_i; y_i]
+///
+/// `param` holds the flag and H as produced by [`rotmg`], either as a host
+/// array or in the first five elements of a device buffer; the handle is
+/// put in the matching pointer mode for the call.
+pub fn rotm<T>(
+    handle: &Handle,
+    x: &mut DeviceMemory<T>,
+    x_dims: VectorDims,
+    y: &mut DeviceMemory<T>,
+    y_dims: VectorDims,
+    param: impl ScalarArg<T>,
+) -> Result<()>
+where
+    T: RotmType,
+{
+    x_dims.check(x)?;
+    y_dims.check(y)?;
+    ensure(x_dims.len == y_dims.len)?;
+    let mode = param.pointer_mode();
+    let param = param.scalar_ptr(5)?;
+
+    with_pointer_mode(handle, mode, || unsafe {
+        level1::rotm(
+            handle,
+            x_dims.len,
+            x.as_ptr().cast(),
+            x_dims.inc,
+            y.as_ptr().cast(),
+            y_dims.inc,
+            param,
+        )
+    })
+}
+
+/// Construct a modified Givens rotation
+///
+/// Finds H such that H * [sqrt(d1) * x1; sqrt(d2) * y1] has a zero second
+/// entry, updating `d1`, `d2` and `x1` and writing the flag and H to the
+/// five elements of `param` for [`rotm`]. The scalars are all on the host
+/// or all in device memory; the handle is put in the matching pointer mode
+/// for the call.
+pub fn rotmg<T>(
+    handle: &Handle,
+    mut d1: impl ScalarArgMut<T>,
+    mut d2: impl ScalarArgMut<T>,
+    mut x1: impl ScalarArgMut<T>,
+    y1: impl ScalarArg<T>,
+    mut param: impl ScalarArgMut<T>,
+) -> Result<()>
+where
+    T: RotmgType,
+{
+    let mode = scalar_mode(&[
+        d1.pointer_mode(),
+        d2.pointer_mode(),
+        x1.pointer_mode(),
+        y1.pointer_mode(),
+        param.pointer_mode(),
+    ])?;
+    let (d1, d2) = (d1.scalar_mut_ptr(1)?, d2.scalar_mut_ptr(1)?);
+    let (x1, y1) = (x1.scalar_mut_ptr(1)?, y1.scalar_ptr(1)?);
+    let param = param.scalar_mut_ptr(5)?;
+
+    with_pointer_mode(handle, mode, || unsafe {
+        level1::rotmg(handle, d1, d2, x1, y1, param)
+    })
+}
+
+/// Run `f` with `handle` in `mode`, then restore the previous mode
+pub(crate) fn with_pointer_mode<R, E: From<Error>>(
+    handle: &Handle,
+    mode: PointerMode,
+    f: impl FnOnce() -> std::result::Result<R, E>,
+) -> std::result::Result<R, E> {
+    let previous = handle.get_pointer_mode()?;
+    let mode = mode.into();
+    if previous == mode {
+        return f();
+    }
+
+    handle.set_pointer_mode(mode)?;
+    let result = f();
+    let restored = handle.set_pointer_mode(previous);
+    let value = result?;
+    restored?;
+    Ok(value)
+}
+
+//==============================================================================
+// Level 2
+//==============================================================================
+
+/// General matrix-vector multiplication
+///
+/// y := alpha * op(A) * x + beta * y
+///
+/// `x` must have as many elements as op(A) has columns and `y` as many as
+/// op(A) has rows.
+#[allow(clippy::too_many_arguments)]
+pub fn gemv<T>(
+    handle: &Handle,
+    trans: Operation,
+    alpha: &T,
+    a: &DeviceMemory<T>,
+    a_dims: MatrixDims,
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    beta: &T,
+    y: &mut DeviceMemory<T>,
+    y_dims: VectorDims,
+) -> Result<()>
+where
+    T: GemvType,
+{
+    a_dims.check(a)?;
+    x_dims.check(x)?;
+    y_dims.check(y)?;
+    let (rows, cols) = a_dims.op_shape(trans);
+    ensure(x_dims.len == cols && y_dims.len == rows)?;
+
+    with_pointer_mode(handle, PointerMode::Host, || unsafe {
+        T::rocblas_gemv(
+            handle,
+            trans,
+            a_dims.rows,
+            a_dims.cols,
+            alpha,
+            a.as_ptr().cast(),
+            a_dims.ld,
+            x.as_ptr().cast(),
+            x_dims.inc,
+            beta,
+            y.as_ptr().cast(),
+            y_dims.inc,
+        )
+    })
+}
+
+/// Hermitian matrix-vector multiplication
+///
+/// y := alpha * A * x + beta * y
+///
+/// Only the `uplo` triangle of the square matrix A is read.
+#[allow(clippy::too_many_arguments)]
+pub fn hemv<T>(
+    handle: &Handle,
+    uplo: Fill,
+    alpha: &T,
+    a: &DeviceMemory<T>,
+    a_dims: MatrixDims,
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    beta: &T,
+    y: &mut DeviceMemory<T>,
+    y_dims: VectorDims,
+) -> Result<()>
+where
+    T: HemvType,
+{
+    a_dims.check(a)?;
+    x_dims.check(x)?;
+    y_dims.check(y)?;
+    let n = a_dims.rows;
+    ensure(a_dims.cols == n && x_dims.len == n && y_dims.len == n)?;
+
+    with_pointer_mode(handle, PointerMode::Host, || unsafe {
+        T::rocblas_hemv(
+            handle,
+            uplo,
+            n,
+            alpha,
+            a.as_ptr().cast(),
+            a_dims.ld,
+            x.as_ptr().cast(),
+            x_dims.inc,
+            beta,
+            y.as_ptr().cast(),
+            y_dims.inc,
+        )
+    })
+}
+
+/// General rank-1 update
+///
+/// A := alpha * x * y^T + A
+///
+/// `x` must have as many elements as A has rows and `y` as many as A has
+/// columns.
+#[allow(clippy::too_many_arguments)]
+pub fn ger<T>(
+    handle: &Handle,
+    alpha: &T,
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    y: &DeviceMemory<T>,
+    y_dims: VectorDims,
+    a: &mut DeviceMemory<T>,
+    a_dims: MatrixDims,
+) -> Result<()>
+where
+    T: GerType,
+{
+    check_rank1(x, x_dims, y, y_dims, a, a_dims)?;
+    with_pointer_mode(handle, PointerMode::Host, || unsafe {
+        T::rocblas_ger(
+            handle,
+            a_dims.rows,
+            a_dims.cols,
+            alpha,
+            x.as_ptr().cast(),
+            x_dims.inc,
+            y.as_ptr().cast(),
+            y_dims.inc,
+            a.as_ptr().cast(),
+            a_dims.ld,
+        )
+    })
+}
+
+/// General rank-1 update of a complex matrix, without conjugation
+///
+/// A := alpha * x * y^T + A
+#[allow(clippy::too_many_arguments)]
+pub fn geru<T>(
+    handle: &Handle,
+    alpha: &T,
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    y: &DeviceMemory<T>,
+    y_dims: VectorDims,
+    a: &mut DeviceMemory<T>,
+    a_dims: MatrixDims,
+) -> Result<()>
+where
+    T: GeruType,
+{
+    check_rank1(x, x_dims, y, y_dims, a, a_dims)?;
+    with_pointer_mode(handle, PointerMode::Host, || unsafe {
+        T::rocblas_geru(
+            handle,
+            a_dims.rows,
+            a_dims.cols,
+            alpha,
+            x.as_ptr().cast(),
+            x_dims.inc,
+            y.as_ptr().cast(),
+            y_dims.inc,
+            a.as_ptr().cast(),
+            a_dims.ld,
+        )
+    })
+}
+
+/// General rank-1 update of a complex matrix, conjugating y
+///
+/// A := alpha * x * y^H + A
+#[allow(clippy::too_many_arguments)]
+pub fn gerc<T>(
+    handle: &Handle,
+    alpha: &T,
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    y: &DeviceMemory<T>,
+    y_dims: VectorDims,
+    a: &mut DeviceMemory<T>,
+    a_dims: MatrixDims,
+) -> Result<()>
+where
+    T: GercType,
+{
+    check_rank1(x, x_dims, y, y_dims, a, a_dims)?;
+    with_pointer_mode(handle, PointerMode::Host, || unsafe {
+        T::rocblas_gerc(
+            handle,
+            a_dims.rows,
+            a_dims.cols,
+            alpha,
+            x.as_ptr().cast(),
+            x_dims.inc,
+            y.as_ptr().cast(),
+            y_dims.inc,
+            a.as_ptr().cast(),
+            a_dims.ld,
+        )
+    })
+}
+
+fn check_rank1<T>(
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    y: &DeviceMemory<T>,
+    y_dims: VectorDims,
+    a: &DeviceMemory<T>,
+    a_dims: MatrixDims,
+) -> Result<()> {
+    a_dims.check(a)?;
+    x_dims.check(x)?;
+    y_dims.check(y)?;
+    ensure(x_dims.len == a_dims.rows && y_dims.len == a_dims.cols)
+}
+
+//==============================================================================
+// Level 3
+//==============================================================================
+
+/// General matrix-matrix multiplication
+///
+/// C := alpha * op(A) * op(B) + beta * C
+///
+/// op(A) must be `m x k`, op(B) `k x n` and C `m x n`; `m`, `n` and `k` are
+/// taken from the dimension structs.
+#[allow(clippy::too_many_arguments)]
+pub fn gemm<T>(
+    handle: &Handle,
+    transa: Operation,
+    transb: Operation,
+    alpha: &T,
+    a: &DeviceMemory<T>,
+    a_dims: MatrixDims,
+    b: &DeviceMemory<T>,
+    b_dims: MatrixDims,
+    beta: &T,
+    c: &mut DeviceMemory<T>,
+    c_dims: MatrixDims,
+) -> Result<()>
+where
+    T: GemmType,
+{
+    let (m, n, k) = check_gemm(transa, transb, a, a_dims, b, b_dims, c, c_dims)?;
+
+    with_pointer_mode(handle, PointerMode::Host, || unsafe {
+        T::rocblas_gemm(
+            handle,
+            transa,
+            transb,
+            m,
+            n,
+            k,
+            alpha,
+            a.as_ptr().cast(),
+            a_dims.ld,
+            b.as_ptr().cast(),
+            b_dims.ld,
+            beta,
+            c.as_ptr().cast(),
+            c_dims.ld,
+        )
+    })
+}
+
+/// Check the operands of a GEMM and return its `(m, n, k)`
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn check_gemm<T>(
+    transa: Operation,
+    transb: Operation,
+    a: &DeviceMemory<T>,
+    a_dims: MatrixDims,
+    b: &DeviceMemory<T>,
+    b_dims: MatrixDims,
+    c: &DeviceMemory<T>,
+    c_dims: MatrixDims,
+) -> Result<(i32, i32, i32)> {
+    a_dims.check(a)?;
+    b_dims.check(b)?;
+    c_dims.check(c)?;
+    let (m, k) = a_dims.op_shape(transa);
+    let (b_rows, n) = b_dims.op_shape(transb);
+    ensure(b_rows == k && c_dims.rows == m && c_dims.cols == n)?;
+    Ok((m, n, k))
+}
+
+/// General matrix-matrix addition and transposition
+///
+/// C := alpha * op(A) + beta * op(B)
+///
+/// op(A), op(B) and C must all have the same shape. With `beta` zero this
+/// is an out-of-place transpose of A; `b` is still checked, so pass A again.
+#[allow(clippy::too_many_arguments)]
+pub fn geam<T>(
+    handle: &Handle,
+    transa: Operation,
+    transb: Operation,
+    alpha: &T,
+    a: &DeviceMemory<T>,
+    a_dims: MatrixDims,
+    beta: &T,
+    b: &DeviceMemory<T>,
+    b_dims: MatrixDims,
+    c: &mut DeviceMemory<T>,
+    c_dims: MatrixDims,
+) -> Result<()>
+where
+    T: GeamType,
+{
+    a_dims.check(a)?;
+    b_dims.check(b)?;
+    c_dims.check(c)?;
+    let (m, n) = (c_dims.rows, c_dims.cols);
+    ensure(a_dims.op_shape(transa) == (m, n) && b_dims.op_shape(transb) == (m, n))?;
+
+    with_pointer_mode(handle, PointerMode::Host, || unsafe {
+        T::rocblas_geam(
+            handle,
+            transa,
+            transb,
+            m,
+            n,
+            alpha,
+            a.as_ptr().cast(),
+            a_dims.ld,
+            beta,
+            b.as_ptr().cast(),
+            b_dims.ld,
+            c.as_ptr().cast(),
+            c_dims.ld,
+        )
+    })
+}
+
+/// Multiplication by a diagonal matrix
+///
+/// C := diag(x) * A   (side = Left)
+/// C := A * diag(x)   (side = Right)
+///
+/// A and C must have the same shape, and `x` must have one element per row
+/// of A for `Side::Left` or per column for `Side::Right`.
+#[allow(clippy::too_many_arguments)]
+pub fn dgmm<T>(
+    handle: &Handle,
+    side: Side,
+    a: &DeviceMemory<T>,
+    a_dims: MatrixDims,
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    c: &mut DeviceMemory<T>,
+    c_dims: MatrixDims,
+) -> Result<()>
+where
+    T: DgmmType,
+{
+    a_dims.check(a)?;
+    x_dims.check(x)?;
+    c_dims.check(c)?;
+    let diagonal_len = match side {
+        Side::Left => a_dims.rows,
+        Side::Right => a_dims.cols,
+        Side::Both => {
+            return Err(Error::new(
+                ffi::rocblas_status__rocblas_status_invalid_value,
+            ));
+        }
+    };
+    ensure((c_dims.rows, c_dims.cols) == (a_dims.rows, a_dims.cols) && x_dims.len == diagonal_len)?;
+
+    unsafe {
+        T::rocblas_dgmm(
+            handle,
+            side,
+            a_dims.rows,
+            a_dims.cols,
+            a.as_ptr().cast(),
+            a_dims.ld,
+            x.as_ptr().cast(),
+            x_dims.inc,
+            c.as_ptr().cast(),
+            c_dims.ld,
+        )
+    }
+}
+
+//==============================================================================
+// Host transfers
+//==============================================================================
+
+/// Copy a strided host vector into a strided device vector
+///
+/// Both layouts must have the same length. Only the addressed elements of
+/// `y` are written, so the gaps of a strided destination are preserved.
+pub fn set_vector<T: Copy>(
+    x: &[T],
+    x_dims: VectorDims,
+    y: &mut DeviceMemory<T>,
+    y_dims: VectorDims,
+) -> Result<()> {
+    x_dims.check_len(x.len())?;
+    y_dims.check(y)?;
+    ensure(x_dims.len == y_dims.len)?;
+
+    let status = unsafe {
+        ffi::rocblas_set_vector(
+            x_dims.len,
+            elem_size::<T>(),
+            x.as_ptr().cast(),
+            x_dims.inc,
+            y.as_ptr(),
+            y_dims.inc,
+        )
+    };
+    check(status)
+}
+
+/// Copy a strided device vector into a strided host vector
+pub fn get_vector<T: Copy>(
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    y: &mut [T],
+    y_dims: VectorDims,
+) -> Result<()> {
+    x_dims.check(x)?;
+    y_dims.check_len(y.len())?;
+    ensure(x_dims.len == y_dims.len)?;
+
+    let status = unsafe {
+        ffi::rocblas_get_vector(
+            x_dims.len,
+            elem_size::<T>(),
+            x.as_ptr(),
+            x_dims.inc,
+            y.as_mut_ptr().cast(),
+            y_dims.inc,
+        )
+    };
+    check(status)
+}
+
+/// Copy a column-major host matrix into a device matrix
+///
+/// Both layouts must have the same rows and columns but may have different
+/// leading dimensions, e.g. to upload a dense host matrix into a padded
+/// device allocation.
+pub fn set_matrix<T: Copy>(
+    a: &[T],
+    a_dims: MatrixDims,
+    b: &mut DeviceMemory<T>,
+    b_dims: MatrixDims,
+) -> Result<()> {
+    a_dims.check_len(a.len())?;
+    b_dims.check(b)?;
+    ensure((a_dims.rows, a_dims.cols) == (b_dims.rows, b_dims.cols))?;
+
+    let status = unsafe {
+        ffi::rocblas_set_matrix(
+            a_dims.rows,
+            a_dims.cols,
+            elem_size::<T>(),
+            a.as_ptr().cast(),
+            a_dims.ld,
+            b.as_ptr(),
+            b_dims.ld,
+        )
+    };
+    check(status)
+}
+
+/// Copy a device matrix into a column-major host matrix
+pub fn get_matrix<T: Copy>(
+    a: &DeviceMemory<T>,
+    a_dims: MatrixDims,
+    b: &mut [T],
+    b_dims: MatrixDims,
+) -> Result<()> {
+    a_dims.check(a)?;
+    b_dims.check_len(b.len())?;
+    ensure((a_dims.rows, a_dims.cols) == (b_dims.rows, b_dims.cols))?;
+
+    let status = unsafe {
+        ffi::rocblas_get_matrix(
+            a_dims.rows,
+            a_dims.cols,
+            elem_size::<T>(),
+            a.as_ptr(),
+            a_dims.ld,
+            b.as_mut_ptr().cast(),
+            b_dims.ld,
+        )
+    };
+    check(status)
+}
+
+/// Queue a strided host-to-device vector copy on `stream`
+///
+/// The host data is moved into the stream and kept alive until the copy
+/// has completed.
+pub fn set_vector_async<T: Copy, I: Into<Vec<T>>>(
+    x: I,
+    x_dims: VectorDims,
+    y: &mut DeviceMemory<T>,
+    y_dims: VectorDims,
+    stream: &Stream,
+) -> Result<()> {
+    let x = x.into();
+    x_dims.check_len(x.len())?;
+    y_dims.check(y)?;
+    ensure(x_dims.len == y_dims.len)?;
+
+    let status = unsafe {
+        ffi::rocblas_set_vector_async(
+            x_dims.len,
+            elem_size::<T>(),
+            x.as_ptr().cast(),
+            x_dims.inc,
+            y.as_ptr(),
+            y_dims.inc,
+            stream.as_raw() as ffi::hipStream_t,
+        )
+    };
+    check(status)?;
+    stream.retain(x);
+    Ok(())
+}
+
+/// Queue a strided device-to-host vector copy into `dest` on `stream`
+///
+/// The returned [`PendingCopy`] hands `dest` back once the copy finished.
+pub fn get_vector_async<T: Copy>(
+    x: &DeviceMemory<T>,
+    x_dims: VectorDims,
+    mut dest: Vec<T>,
+    dest_dims: VectorDims,
+    stream: &Stream,
+) -> Result<PendingCopy<T>> {
+    x_dims.check(x)?;
+    dest_dims.check_len(dest.len())?;
+    ensure(x_dims.len == dest_dims.len)?;
+
+    let status = unsafe {
+        ffi::rocblas_get_vector_async(
+            x_dims.len,
+            elem_size::<T>(),
+            x.as_ptr(),
+            x_dims.inc,
+            dest.as_mut_ptr().cast(),
+            dest_dims.inc,
+            stream.as_raw() as ffi::hipStream_t,
+        )
+    };
+    check(status)?;
+    Ok(PendingCopy::new(dest, stream))
+}
+
+/// Queue a host-to-device matrix copy on `stream`
+///
+/// The host data is moved into the stream and kept alive until the copy
+/// has completed.
+pub fn set_matrix_async<T: Copy, I: Into<Vec<T>>>(
+    a: I,
+    a_dims: MatrixDims,
+    b: &mut DeviceMemory<T>,
+    b_dims: MatrixDims,
+    stream: &Stream,
+) -> Result<()> {
+    let a = a.into();
+    a_dims.check_len(a.len())?;
+    b_dims.check(b)?;
+    ensure((a_dims.rows, a_dims.cols) == (b_dims.rows, b_dims.cols))?;
+
+    let status = unsafe {
+        ffi::rocblas_set_matrix_async(
+            a_dims.rows,
+            a_dims.cols,
+            elem_size::<T>(),
+            a.as_ptr().cast(),
+            a_dims.ld,
+            b.as_ptr(),
+            b_dims.ld,
+            stream.as_raw() as ffi::hipStream_t,
+        )
+    };
+    check(status)?;
+    stream.retain(a);
+    Ok(())
+}
+
+/// Queue a device-to-host matrix copy into `dest` on `stream`
+///
+/// The returned [`PendingCopy`] hands `dest` back once the copy finished.
+pub fn get_matrix_async<T: Copy>(
+    a: &DeviceMemory<T>,
+    a_dims: MatrixDims,
+    mut dest: Vec<T>,
+    dest_dims: MatrixDims,
+    stream: &Stream,
+) -> Result<PendingCopy<T>> {
+    a_dims.check(a)?;
+    dest_dims.check_len(dest.len())?;
+    ensure((a_dims.rows, a_dims.cols) == (dest_dims.rows, dest_dims.cols))?;
+
+    let status = unsafe {
+        ffi::rocblas_get_matrix_async(
+            a_dims.rows,
+            a_dims.cols,
+            elem_size::<T>(),
+            a.as_ptr(),
+            a_dims.ld,
+            dest.as_mut_ptr().cast(),
+            dest_dims.ld,
+            stream.as_raw() as ffi::hipStream_t,
+        )
+    };
+    check(status)?;
+    Ok(PendingCopy::new(dest, stream))
+}
+
+fn elem_size<T>() -> i32 {
+    std::mem::size_of::<T>() as i32
+}
+
+fn check(status: ffi::rocblas_status) -> Result<()> {
+    if status != ffi::rocblas_status__rocblas_status_success {
+        return Err(Error::new(status));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_dims_span() {
+        assert_eq!(MatrixDims::new(3, 4).span(), 12);
+        // The last column only needs its own rows, not a full ld
+        assert_eq!(MatrixDims::with_ld(3, 4, 5).span(), 18);
+        assert_eq!(MatrixDims::new(0, 4).span(), 0);
+        assert_eq!(MatrixDims::new(3, 0).span(), 0);
+    }
+
+    #[test]
+    fn test_matrix_dims_check_len() {
+        assert!(MatrixDims::new(3, 4).check_len(12).is_ok());
+        assert!(MatrixDims::new(3, 4).check_len(11).is_err());
+        assert!(MatrixDims::with_ld(3, 4, 5).check_len(18).is_ok());
+        assert!(MatrixDims::with_ld(3, 4, 5).check_len(17).is_err());
+        assert!(MatrixDims::new(0, 0).check_len(0).is_ok());
+
+        // ld smaller than the row count, or negative sizes
+        assert!(MatrixDims::with_ld(3, 4, 2).check_len(100).is_err());
+        assert!(MatrixDims::with_ld(0, 4, 0).check_len(100).is_err());
+        assert!(MatrixDims::with_ld(-1, 4, 1).check_len(100).is_err());
+        assert!(MatrixDims::with_ld(3, -1, 3).check_len(100).is_err());
+    }
+
+    #[test]
+    fn test_vector_dims_span() {
+        assert_eq!(VectorDims::new(5).span(), 5);
+        assert_eq!(VectorDims::strided(5, 3).span(), 13);
+        assert_eq!(VectorDims::strided(5, -3).span(), 13);
+        assert_eq!(VectorDims::strided(0, 3).span(), 0);
+    }
+
+    #[test]
+    fn test_vector_dims_check_len() {
+        assert!(VectorDims::strided(5, 3).check_len(13).is_ok());
+        assert!(VectorDims::strided(5, 3).check_len(12).is_err());
+        assert!(VectorDims::strided(5, -3).check_len(13).is_ok());
+        assert!(VectorDims::new(0).check_len(0).is_ok());
+        assert!(VectorDims::strided(5, 0).check_len(100).is_err());
+        assert!(VectorDims::new(-1).check_len(100).is_err());
+    }
+
+    #[test]
+    fn test_scalar_mode() {
+        let (host, device) = (PointerMode::Host, PointerMode::Device);
+        assert_eq!(scalar_mode(&[host, host]).ok(), Some(host));
+        assert_eq!(scalar_mode(&[device]).ok(), Some(device));
+        assert!(scalar_mode(&[host, device]).is_err());
+    }
+
+    fn fits<T>(arg: impl ScalarArg<T>, len: usize) -> bool {
+        arg.scalar_ptr(len).is_ok()
+    }
+
+    #[test]
+    fn test_host_scalar_len() {
+        let (c, param) = (1.0f32, [0.0f32; 5]);
+        assert!(fits::<f32>(&c, 1));
+        assert!(!fits::<f32>(&c, 2));
+        assert!(fits::<f32>(&param, 5));
+        assert!(!fits::<f32>(&param, 6));
+    }
+}
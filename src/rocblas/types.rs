@@ -221,6 +221,47 @@ impl From<ffi::rocblas_datatype> for DataType {
     }
 }
 
+/// Element types that rocBLAS can describe with a [`DataType`]
+///
+/// Used by the mixed-precision `_ex` routines to pass each buffer's type
+/// alongside its untyped pointer.
+pub trait ExType {
+    /// The rocBLAS data type of `Self`
+    const DATA_TYPE: DataType;
+}
+
+impl ExType for f32 {
+    const DATA_TYPE: DataType = DataType::F32Real;
+}
+
+impl ExType for f64 {
+    const DATA_TYPE: DataType = DataType::F64Real;
+}
+
+impl ExType for ffi::rocblas_half {
+    const DATA_TYPE: DataType = DataType::F16Real;
+}
+
+impl ExType for ffi::rocblas_bfloat16 {
+    const DATA_TYPE: DataType = DataType::BF16Real;
+}
+
+impl ExType for ffi::rocblas_float_complex {
+    const DATA_TYPE: DataType = DataType::F32Complex;
+}
+
+impl ExType for ffi::rocblas_double_complex {
+    const DATA_TYPE: DataType = DataType::F64Complex;
+}
+
+impl ExType for i8 {
+    const DATA_TYPE: DataType = DataType::I8Real;
+}
+
+impl ExType for i32 {
+    const DATA_TYPE: DataType = DataType::I32Real;
+}
+
 // Re-export the types with their rocblas_ prefixes for compatibility
 pub use ffi::rocblas_datatype;
 pub use ffi::rocblas_diagonal;
@@ -1,6 +1,7 @@
 // src/rocblas/utils.rs
 
-use std::ffi::CStr;
+use std::ffi::{CStr, OsString};
+use std::path::PathBuf;
 
 use crate::rocblas::error::Result;
 use crate::rocblas::ffi;
@@ -132,6 +133,75 @@ impl From<LayerMode> for ffi::rocblas_layer_mode {
     }
 }
 
+/// Logging configuration for handles created with [`Handle::with_logging`]
+///
+/// rocBLAS only takes its logging settings from the `ROCBLAS_LAYER` and
+/// `ROCBLAS_LOG_*_PATH` environment variables. This builds those settings
+/// in code instead; layers without a path log to stderr.
+///
+/// ```ignore
+/// let config = LogConfig::new()
+///     .enable(LayerMode::LogBench)
+///     .bench_path("rocblas_bench.log");
+/// // Before any other threads are started
+/// let handle = unsafe { Handle::with_logging(&config)? };
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogConfig {
+    layers: ffi::rocblas_layer_mode,
+    trace_path: Option<PathBuf>,
+    bench_path: Option<PathBuf>,
+    profile_path: Option<PathBuf>,
+}
+
+impl LogConfig {
+    /// A configuration with logging disabled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable a logging layer; layers can be combined
+    pub fn enable(mut self, mode: LayerMode) -> Self {
+        self.layers |= ffi::rocblas_layer_mode::from(mode);
+        self
+    }
+
+    /// Enable trace logging, written to `path`
+    pub fn trace_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.trace_path = Some(path.into());
+        self.enable(LayerMode::LogTrace)
+    }
+
+    /// Enable bench logging, written to `path`
+    pub fn bench_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.bench_path = Some(path.into());
+        self.enable(LayerMode::LogBench)
+    }
+
+    /// Enable profile logging, written to `path`
+    pub fn profile_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.profile_path = Some(path.into());
+        self.enable(LayerMode::LogProfile)
+    }
+
+    /// Whether `mode` is enabled
+    pub fn is_enabled(&self, mode: LayerMode) -> bool {
+        let bits = ffi::rocblas_layer_mode::from(mode);
+        bits != 0 && self.layers & bits == bits
+    }
+
+    /// The environment rocBLAS reads these settings from
+    pub(crate) fn env_vars(&self) -> Vec<(&'static str, Option<OsString>)> {
+        let path = |path: &Option<PathBuf>| path.clone().map(OsString::from);
+        vec![
+            ("ROCBLAS_LAYER", Some(self.layers.to_string().into())),
+            ("ROCBLAS_LOG_TRACE_PATH", path(&self.trace_path)),
+            ("ROCBLAS_LOG_BENCH_PATH", path(&self.bench_path)),
+            ("ROCBLAS_LOG_PROFILE_PATH", path(&self.profile_path)),
+        ]
+    }
+}
+
 /// Enum for RocBLAS GEMM algorithm
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GemmAlgo {
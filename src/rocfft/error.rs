@@ -1,4 +1,5 @@
 use crate::error;
+use crate::error::ErrorKind;
 use crate::rocfft::bindings;
 use std::error::Error as StdError;
 use std::ffi::NulError;
@@ -67,6 +68,30 @@ impl fmt::Display for Error {
     }
 }
 
+impl Error {
+    /// Get the class of failure this error belongs to
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::InvalidArgValue
+            | Error::InvalidDimensions
+            | Error::InvalidArrayType
+            | Error::InvalidStrides
+            | Error::InvalidDistance
+            | Error::InvalidOffset
+            | Error::InvalidWorkBuffer
+            | Error::NullPointer
+            | Error::InvalidString
+            | Error::IncompatibleTypes
+            | Error::NulError(_) => ErrorKind::InvalidValue,
+            Error::ObjectDestroyed => ErrorKind::InvalidHandle,
+            Error::OutOfMemory => ErrorKind::OutOfMemory,
+            Error::InvalidDevice => ErrorKind::InvalidDevice,
+            Error::UnsupportedConfiguration => ErrorKind::NotSupported,
+            Error::Failure | Error::Unknown(_) => ErrorKind::Other,
+        }
+    }
+}
+
 impl StdError for Error {}
 
 impl From<NulError> for Error {
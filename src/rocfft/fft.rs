@@ -0,0 +1,370 @@
+// src/rocfft/fft.rs
+//
+// Batched complex transforms over ROCArray axes
+
+use crate::error::Error::RocFFT;
+use crate::error::Result;
+use crate::rocarray::{ROCArray, Shape};
+use crate::rocfft::{
+    description::PlanDescription,
+    error,
+    plan::{ArrayType, PlacementType, Plan, Precision, TransformType},
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::mem::size_of;
+
+/// Single precision complex value, laid out as rocFFT's interleaved pairs
+pub use num_complex::Complex32;
+
+/// Direction of a complex transform
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Forward,
+    Inverse,
+}
+
+/// A complex-to-complex transform applied along the axes of a [`ROCArray`]
+///
+/// The transform covers `rank` consecutive axes ending at `axis` (the last
+/// axis by default); every other axis is treated as a batch. Lengths,
+/// strides, batch count and batch distance are derived from the array
+/// shape.
+///
+/// ```no_run
+/// use rocm_rs::rocarray::{ROCArray, Shape};
+/// use rocm_rs::rocfft::fft::{Complex32, Fft};
+///
+/// // 16 signals of length 1024, one per row
+/// let signals = ROCArray::<Complex32>::zeros(Shape::new_2d(16, 1024)).unwrap();
+/// let spectra = Fft::forward().execute_batched(&signals).unwrap();
+///
+/// // Transform down the columns instead
+/// let columns = Fft::forward().axis(0).execute_batched(&signals).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fft {
+    direction: Direction,
+    axis: Option<usize>,
+    rank: usize,
+    normalize: bool,
+}
+
+impl Fft {
+    /// Create a transform in the given direction
+    pub fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            axis: None,
+            rank: 1,
+            normalize: false,
+        }
+    }
+
+    /// Create a forward transform
+    pub fn forward() -> Self {
+        Self::new(Direction::Forward)
+    }
+
+    /// Create an inverse transform
+    pub fn inverse() -> Self {
+        Self::new(Direction::Inverse)
+    }
+
+    /// Set the innermost transformed axis (defaults to the last axis)
+    pub fn axis(mut self, axis: usize) -> Self {
+        self.axis = Some(axis);
+        self
+    }
+
+    /// Set the number of transformed axes, 1 to 3
+    pub fn rank(mut self, rank: usize) -> Self {
+        self.rank = rank;
+        self
+    }
+
+    /// Whether to scale the result by 1/N, where N is the transform size
+    pub fn normalize(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    /// Transform every batch of `input` into a new array of the same shape
+    pub fn execute_batched(&self, input: &ROCArray<Complex32>) -> Result<ROCArray<Complex32>> {
+        let output = ROCArray::new(Shape::new(input.dims().to_vec()))?;
+        if input.is_empty() {
+            return Ok(output);
+        }
+
+        let layout = self.layout(input.shape())?;
+        with_cached_plan(self.plan_key(&layout, PlacementType::NotInPlace), |plan| {
+            for &offset in &layout.offsets {
+                let byte_offset = offset * size_of::<Complex32>();
+                let input_ptr = unsafe { input.as_ptr().byte_add(byte_offset) };
+                let output_ptr = unsafe { output.as_ptr().byte_add(byte_offset) };
+                plan.execute(&[input_ptr], &[output_ptr], None)?;
+            }
+            Ok(())
+        })?;
+
+        Ok(output)
+    }
+
+    /// Transform every batch of `data`, overwriting it with the result
+    pub fn execute_in_place(&self, data: &mut ROCArray<Complex32>) -> Result<()> {
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let layout = self.layout(data.shape())?;
+        with_cached_plan(self.plan_key(&layout, PlacementType::InPlace), |plan| {
+            for &offset in &layout.offsets {
+                let byte_offset = offset * size_of::<Complex32>();
+                let data_ptr = unsafe { data.as_ptr().byte_add(byte_offset) };
+                plan.execute(&[data_ptr], &[], None)?;
+            }
+            Ok(())
+        })
+    }
+
+    fn layout(&self, shape: &Shape) -> Result<BatchLayout> {
+        let axis = self.axis.unwrap_or(shape.ndim().saturating_sub(1));
+        BatchLayout::new(shape, axis, self.rank)
+    }
+
+    fn plan_key(&self, layout: &BatchLayout, placement: PlacementType) -> PlanKey {
+        let scale = if self.normalize {
+            1.0 / layout.lengths.iter().product::<usize>() as f64
+        } else {
+            1.0
+        };
+        let transform_type = match self.direction {
+            Direction::Forward => TransformType::ComplexForward,
+            Direction::Inverse => TransformType::ComplexInverse,
+        };
+        let data = DataLayout {
+            array_type: ArrayType::ComplexInterleaved,
+            strides: layout.strides.clone(),
+            distance: layout.distance,
+        };
+
+        PlanKey {
+            placement,
+            transform_type,
+            lengths: layout.lengths.clone(),
+            batch: layout.batch,
+            input: data.clone(),
+            output: data,
+            scale_bits: scale.to_bits(),
+        }
+    }
+}
+
+/// Real-to-complex forward transform along the last axis of `input`
+///
+/// The result keeps the leading axes and holds the `n / 2 + 1`
+/// non-redundant coefficients of each length-`n` signal.
+pub fn real_forward(input: &ROCArray<f32>) -> Result<ROCArray<Complex32>> {
+    let dims = input.dims();
+    let n = *dims.last().ok_or(RocFFT(error::Error::InvalidDimensions))?;
+
+    let mut output_dims = dims.to_vec();
+    *output_dims.last_mut().unwrap() = n / 2 + 1;
+    let output = ROCArray::new(Shape::new(output_dims))?;
+    if input.is_empty() {
+        return Ok(output);
+    }
+
+    let key = PlanKey {
+        placement: PlacementType::NotInPlace,
+        transform_type: TransformType::RealForward,
+        lengths: vec![n],
+        batch: input.len() / n,
+        input: DataLayout {
+            array_type: ArrayType::Real,
+            strides: vec![1],
+            distance: n,
+        },
+        output: DataLayout {
+            array_type: ArrayType::HermitianInterleaved,
+            strides: vec![1],
+            distance: n / 2 + 1,
+        },
+        scale_bits: 1.0f64.to_bits(),
+    };
+    with_cached_plan(key, |plan| {
+        plan.execute(&[input.as_ptr()], &[output.as_ptr()], None)
+            .map_err(RocFFT)
+    })?;
+
+    Ok(output)
+}
+
+/// Drop every plan cached on the calling thread
+pub fn clear_plan_cache() {
+    PLANS.with(|plans| plans.borrow_mut().clear());
+}
+
+thread_local! {
+    // Plans are not Send, so each thread keeps its own
+    static PLANS: RefCell<HashMap<PlanKey, Plan>> = RefCell::new(HashMap::new());
+}
+
+/// Run `f` with the plan for `key`, creating it on first use
+fn with_cached_plan<R>(key: PlanKey, f: impl FnOnce(&mut Plan) -> Result<R>) -> Result<R> {
+    PLANS.with(|plans| {
+        let mut plans = plans.borrow_mut();
+        let plan = match plans.entry(key) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let plan = entry.key().create()?;
+                entry.insert(plan)
+            }
+        };
+        f(plan)
+    })
+}
+
+/// Memory layout of one side of a transform
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct DataLayout {
+    array_type: ArrayType,
+    strides: Vec<usize>,
+    distance: usize,
+}
+
+/// Everything a single precision plan is created from
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct PlanKey {
+    placement: PlacementType,
+    transform_type: TransformType,
+    lengths: Vec<usize>,
+    batch: usize,
+    input: DataLayout,
+    output: DataLayout,
+    scale_bits: u64,
+}
+
+impl PlanKey {
+    fn create(&self) -> Result<Plan> {
+        let mut description = PlanDescription::new()?;
+        description.set_data_layout(
+            self.input.array_type,
+            self.output.array_type,
+            None,
+            None,
+            Some(&self.input.strides),
+            self.input.distance,
+            Some(&self.output.strides),
+            self.output.distance,
+        )?;
+        let scale = f64::from_bits(self.scale_bits);
+        if scale != 1.0 {
+            description.set_scale_factor(scale)?;
+        }
+
+        Ok(Plan::new(
+            self.placement,
+            self.transform_type,
+            Precision::Single,
+            self.lengths.len(),
+            &self.lengths,
+            self.batch,
+            Some(&description),
+        )?)
+    }
+}
+
+/// rocFFT description of a batched transform over some axes of a
+/// row-major array
+#[derive(Debug, PartialEq, Eq)]
+struct BatchLayout {
+    // Transform lengths and strides, fastest axis first as rocFFT expects
+    lengths: Vec<usize>,
+    strides: Vec<usize>,
+    batch: usize,
+    distance: usize,
+    // Element offsets of the separate executions needed when the batch
+    // axes can't be covered by a single distance
+    offsets: Vec<usize>,
+}
+
+impl BatchLayout {
+    fn new(shape: &Shape, axis: usize, rank: usize) -> Result<Self> {
+        let dims = shape.dims();
+        if error::check_dimensions(rank).is_err() || axis >= dims.len() || rank > axis + 1 {
+            return Err(RocFFT(error::Error::InvalidDimensions));
+        }
+
+        let first = axis + 1 - rank;
+        let lengths: Vec<usize> = dims[first..=axis].iter().rev().copied().collect();
+        let strides: Vec<usize> = shape.strides()[first..=axis]
+            .iter()
+            .rev()
+            .copied()
+            .collect();
+
+        // Axes before the transformed ones are one batch dimension and axes
+        // after them another. The larger is batched by the plan and the
+        // smaller is walked by offsetting the pointers.
+        let outer: usize = dims[..first].iter().product();
+        let inner: usize = dims[axis + 1..].iter().product();
+        let block: usize = dims[first..].iter().product();
+
+        let (batch, distance, offsets) = if outer >= inner {
+            (outer, block, (0..inner).collect())
+        } else {
+            (inner, 1, (0..outer).map(|o| o * block).collect())
+        };
+
+        Ok(Self {
+            lengths,
+            strides,
+            batch,
+            distance,
+            offsets,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_last_axis() {
+        let layout = BatchLayout::new(&Shape::new_2d(16, 1024), 1, 1).unwrap();
+        assert_eq!(layout.lengths, vec![1024]);
+        assert_eq!(layout.strides, vec![1]);
+        assert_eq!((layout.batch, layout.distance), (16, 1024));
+        assert_eq!(layout.offsets, vec![0]);
+    }
+
+    #[test]
+    fn test_layout_first_axis() {
+        let layout = BatchLayout::new(&Shape::new_2d(16, 1024), 0, 1).unwrap();
+        assert_eq!(layout.lengths, vec![16]);
+        assert_eq!(layout.strides, vec![1024]);
+        assert_eq!((layout.batch, layout.distance), (1024, 1));
+        assert_eq!(layout.offsets, vec![0]);
+    }
+
+    #[test]
+    fn test_layout_middle_axes() {
+        // 4 x (8 x 6) x 3, transforming the two middle axes
+        let layout = BatchLayout::new(&Shape::new(vec![4, 8, 6, 3]), 2, 2).unwrap();
+        assert_eq!(layout.lengths, vec![6, 8]);
+        assert_eq!(layout.strides, vec![3, 18]);
+        assert_eq!((layout.batch, layout.distance), (4, 144));
+        assert_eq!(layout.offsets, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_layout_rejects_bad_axes() {
+        let shape = Shape::new_2d(4, 4);
+        assert!(BatchLayout::new(&shape, 2, 1).is_err());
+        assert!(BatchLayout::new(&shape, 0, 2).is_err());
+        assert!(BatchLayout::new(&shape, 1, 0).is_err());
+    }
+}
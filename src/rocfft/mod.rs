@@ -8,6 +8,8 @@ pub mod cache;
 pub mod description;
 pub mod error;
 pub mod execution;
+#[cfg(feature = "rocfft")]
+pub mod fft;
 pub mod ffi;
 pub mod field;
 pub mod plan;
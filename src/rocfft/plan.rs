@@ -12,7 +12,7 @@ use std::marker::PhantomData;
 use std::ptr;
 
 /// The type of transform to be performed
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum TransformType {
     /// Complex forward FFT (typically uses e^(-j*2*pi*n/N))
     ComplexForward,
@@ -44,7 +44,7 @@ impl From<TransformType> for u32 {
 }
 
 /// The numerical precision to be used
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Precision {
     /// Single precision (32-bit floating point)
     Single,
@@ -65,7 +65,7 @@ impl From<Precision> for u32 {
 }
 
 /// Specifies whether the transform is in-place or out-of-place
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum PlacementType {
     /// Input and output buffers are the same (in-place transform)
     InPlace,
@@ -85,7 +85,7 @@ impl From<PlacementType> for u32 {
 }
 
 /// The type and format of data arrays
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum ArrayType {
     /// Complex data stored in interleaved format (real and imaginary parts adjacent in memory)
     ComplexInterleaved,
@@ -1,5 +1,6 @@
 // src/rocrand/error.rs
 
+use crate::error::ErrorKind;
 use crate::rocrand::bindings;
 use std::fmt;
 
@@ -55,6 +56,21 @@ impl Error {
             other => Err(Error::Unknown(other)),
         }
     }
+
+    /// Get the class of failure this error belongs to
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::NotCreated => ErrorKind::InvalidHandle,
+            Error::AllocationFailed => ErrorKind::OutOfMemory,
+            Error::TypeError | Error::OutOfRange | Error::LengthNotMultiple => {
+                ErrorKind::InvalidValue
+            }
+            Error::DoublePrecisionRequired => ErrorKind::NotSupported,
+            Error::LaunchFailure => ErrorKind::LaunchFailure,
+            Error::InternalError => ErrorKind::Internal,
+            Error::VersionMismatch | Error::Unknown(_) => ErrorKind::Other,
+        }
+    }
 }
 
 impl fmt::Display for Error {
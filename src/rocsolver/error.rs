@@ -4,6 +4,9 @@
 //! This module provides error types and result aliases for rocSOLVER operations.
 //! It wraps the underlying rocblas_status codes with a Rust-friendly API.
 
+use crate::error::ErrorKind;
+use crate::hip::DeviceMemory;
+use crate::rocblas::Handle;
 use crate::rocblas::ffi;
 use std::error::Error as StdError;
 use std::fmt;
@@ -63,6 +66,14 @@ impl Error {
         self.code
     }
 
+    /// Get the class of failure this error belongs to.
+    ///
+    /// rocSOLVER reports rocBLAS status codes, so this is the rocBLAS
+    /// classification.
+    pub fn kind(&self) -> ErrorKind {
+        crate::rocblas::Error::new(self.code).kind()
+    }
+
     /// Get the name of the error code.
     pub fn name(&self) -> &'static str {
         match self.code {
@@ -209,3 +220,15 @@ impl From<crate::rocblas::Error> for Error {
         Error::new(err.code())
     }
 }
+
+/// Wait for the work queued on `handle` and read back an `info` value.
+///
+/// rocSOLVER reports numerical failures (a singular or non positive definite
+/// matrix, a solver that did not converge, ...) through `info` rather than
+/// through the status code, so convenience wrappers check it here.
+pub(crate) fn read_info(handle: &Handle, info: &DeviceMemory<i32>) -> crate::error::Result<i32> {
+    handle.get_stream()?.synchronize()?;
+    let mut value = [0];
+    info.copy_to_host(&mut value)?;
+    Ok(value[0])
+}
@@ -76,12 +76,18 @@ pub use super::bindings::{
     rocsolver_cgesvd,
     rocsolver_cgesvd_batched,
     rocsolver_cgesvd_strided_batched,
+    rocsolver_cgesvdj,
+    rocsolver_cgesvdj_batched,
+    rocsolver_cgesvdj_strided_batched,
     rocsolver_cgetrf,
     rocsolver_cgetrf_batched,
     rocsolver_cgetrf_npvt,
     rocsolver_cgetrf_npvt_batched,
     rocsolver_cgetrf_npvt_strided_batched,
     rocsolver_cgetrf_strided_batched,
+    rocsolver_cgetri,
+    rocsolver_cgetri_batched,
+    rocsolver_cgetri_strided_batched,
     rocsolver_cgetrs,
     rocsolver_cgetrs_batched,
     rocsolver_cgetrs_strided_batched,
@@ -89,12 +95,30 @@ pub use super::bindings::{
     rocsolver_cheev,
     rocsolver_cheev_batched,
     rocsolver_cheev_strided_batched,
+    rocsolver_cheevj,
+    rocsolver_cheevj_batched,
+    rocsolver_cheevj_strided_batched,
+    rocsolver_chegvd,
+    rocsolver_chegvd_batched,
+    rocsolver_chegvd_strided_batched,
     rocsolver_cposv,
     rocsolver_cposv_batched,
     rocsolver_cposv_strided_batched,
     rocsolver_cpotrf,
     rocsolver_cpotrf_batched,
     rocsolver_cpotrf_strided_batched,
+    rocsolver_cpotri,
+    rocsolver_cpotri_batched,
+    rocsolver_cpotri_strided_batched,
+    rocsolver_cpotrs,
+    rocsolver_cpotrs_batched,
+    rocsolver_cpotrs_strided_batched,
+    rocsolver_csytrf,
+    rocsolver_csytrf_batched,
+    rocsolver_csytrf_strided_batched,
+    rocsolver_ctrtri,
+    rocsolver_ctrtri_batched,
+    rocsolver_ctrtri_strided_batched,
     // rocSOLVER functions - orthogonal (no batched variants exist)
     rocsolver_cungqr,
     rocsolver_cunmqr,
@@ -113,12 +137,18 @@ pub use super::bindings::{
     rocsolver_dgesvd,
     rocsolver_dgesvd_batched,
     rocsolver_dgesvd_strided_batched,
+    rocsolver_dgesvdj,
+    rocsolver_dgesvdj_batched,
+    rocsolver_dgesvdj_strided_batched,
     rocsolver_dgetrf,
     rocsolver_dgetrf_batched,
     rocsolver_dgetrf_npvt,
     rocsolver_dgetrf_npvt_batched,
     rocsolver_dgetrf_npvt_strided_batched,
     rocsolver_dgetrf_strided_batched,
+    rocsolver_dgetri,
+    rocsolver_dgetri_batched,
+    rocsolver_dgetri_strided_batched,
     rocsolver_dgetrs,
     rocsolver_dgetrs_batched,
     rocsolver_dgetrs_strided_batched,
@@ -130,9 +160,27 @@ pub use super::bindings::{
     rocsolver_dpotrf,
     rocsolver_dpotrf_batched,
     rocsolver_dpotrf_strided_batched,
+    rocsolver_dpotri,
+    rocsolver_dpotri_batched,
+    rocsolver_dpotri_strided_batched,
+    rocsolver_dpotrs,
+    rocsolver_dpotrs_batched,
+    rocsolver_dpotrs_strided_batched,
     rocsolver_dsyev,
     rocsolver_dsyev_batched,
     rocsolver_dsyev_strided_batched,
+    rocsolver_dsyevj,
+    rocsolver_dsyevj_batched,
+    rocsolver_dsyevj_strided_batched,
+    rocsolver_dsygvd,
+    rocsolver_dsygvd_batched,
+    rocsolver_dsygvd_strided_batched,
+    rocsolver_dsytrf,
+    rocsolver_dsytrf_batched,
+    rocsolver_dsytrf_strided_batched,
+    rocsolver_dtrtri,
+    rocsolver_dtrtri_batched,
+    rocsolver_dtrtri_strided_batched,
     rocsolver_sgebrd,
     rocsolver_sgebrd_batched,
     rocsolver_sgebrd_strided_batched,
@@ -148,12 +196,18 @@ pub use super::bindings::{
     rocsolver_sgesvd,
     rocsolver_sgesvd_batched,
     rocsolver_sgesvd_strided_batched,
+    rocsolver_sgesvdj,
+    rocsolver_sgesvdj_batched,
+    rocsolver_sgesvdj_strided_batched,
     rocsolver_sgetrf,
     rocsolver_sgetrf_batched,
     rocsolver_sgetrf_npvt,
     rocsolver_sgetrf_npvt_batched,
     rocsolver_sgetrf_npvt_strided_batched,
     rocsolver_sgetrf_strided_batched,
+    rocsolver_sgetri,
+    rocsolver_sgetri_batched,
+    rocsolver_sgetri_strided_batched,
     rocsolver_sgetrs,
     rocsolver_sgetrs_batched,
     rocsolver_sgetrs_strided_batched,
@@ -165,9 +219,27 @@ pub use super::bindings::{
     rocsolver_spotrf,
     rocsolver_spotrf_batched,
     rocsolver_spotrf_strided_batched,
+    rocsolver_spotri,
+    rocsolver_spotri_batched,
+    rocsolver_spotri_strided_batched,
+    rocsolver_spotrs,
+    rocsolver_spotrs_batched,
+    rocsolver_spotrs_strided_batched,
     rocsolver_ssyev,
     rocsolver_ssyev_batched,
     rocsolver_ssyev_strided_batched,
+    rocsolver_ssyevj,
+    rocsolver_ssyevj_batched,
+    rocsolver_ssyevj_strided_batched,
+    rocsolver_ssygvd,
+    rocsolver_ssygvd_batched,
+    rocsolver_ssygvd_strided_batched,
+    rocsolver_ssytrf,
+    rocsolver_ssytrf_batched,
+    rocsolver_ssytrf_strided_batched,
+    rocsolver_strtri,
+    rocsolver_strtri_batched,
+    rocsolver_strtri_strided_batched,
     rocsolver_zgebrd,
     rocsolver_zgebrd_batched,
     rocsolver_zgebrd_strided_batched,
@@ -183,24 +255,48 @@ pub use super::bindings::{
     rocsolver_zgesvd,
     rocsolver_zgesvd_batched,
     rocsolver_zgesvd_strided_batched,
+    rocsolver_zgesvdj,
+    rocsolver_zgesvdj_batched,
+    rocsolver_zgesvdj_strided_batched,
     rocsolver_zgetrf,
     rocsolver_zgetrf_batched,
     rocsolver_zgetrf_npvt,
     rocsolver_zgetrf_npvt_batched,
     rocsolver_zgetrf_npvt_strided_batched,
     rocsolver_zgetrf_strided_batched,
+    rocsolver_zgetri,
+    rocsolver_zgetri_batched,
+    rocsolver_zgetri_strided_batched,
     rocsolver_zgetrs,
     rocsolver_zgetrs_batched,
     rocsolver_zgetrs_strided_batched,
     rocsolver_zheev,
     rocsolver_zheev_batched,
     rocsolver_zheev_strided_batched,
+    rocsolver_zheevj,
+    rocsolver_zheevj_batched,
+    rocsolver_zheevj_strided_batched,
+    rocsolver_zhegvd,
+    rocsolver_zhegvd_batched,
+    rocsolver_zhegvd_strided_batched,
     rocsolver_zposv,
     rocsolver_zposv_batched,
     rocsolver_zposv_strided_batched,
     rocsolver_zpotrf,
     rocsolver_zpotrf_batched,
     rocsolver_zpotrf_strided_batched,
+    rocsolver_zpotri,
+    rocsolver_zpotri_batched,
+    rocsolver_zpotri_strided_batched,
+    rocsolver_zpotrs,
+    rocsolver_zpotrs_batched,
+    rocsolver_zpotrs_strided_batched,
+    rocsolver_zsytrf,
+    rocsolver_zsytrf_batched,
+    rocsolver_zsytrf_strided_batched,
+    rocsolver_ztrtri,
+    rocsolver_ztrtri_batched,
+    rocsolver_ztrtri_strided_batched,
     rocsolver_zungqr,
     rocsolver_zunmqr,
 };
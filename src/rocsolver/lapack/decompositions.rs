@@ -8,12 +8,15 @@
 //! - **LU without pivoting**: [`getrf_npvt`], [`getrf_npvt_batched`], [`getrf_npvt_strided_batched`]
 //! - **Cholesky factorization**: [`potrf`], [`potrf_batched`], [`potrf_strided_batched`]
 //! - **Bidiagonal reduction**: [`gebrd`], [`gebrd_batched`], [`gebrd_strided_batched`]
+//! - **Triangular inverse**: [`trtri`], [`trtri_batched`], [`trtri_strided_batched`]
+//! - **Symmetric indefinite factorization**: [`sytrf`], [`sytrf_batched`],
+//!   [`sytrf_strided_batched`], with [`sytrf_pivots`] to decode the pivots
 
 use crate::rocblas::Handle;
 use crate::rocblas::ffi as rocblas_ffi;
 use crate::rocsolver::bindings;
 use crate::rocsolver::error::{Error, Result};
-use crate::rocsolver::types::{Complex32, Complex64, Fill};
+use crate::rocsolver::types::{Complex32, Complex64, Diagonal, Fill};
 
 // Type alias for handle - we use rocblas handle but need to cast for rocsolver bindings
 type RocblasHandle = rocblas_ffi::rocblas_handle;
@@ -238,6 +241,86 @@ pub trait GebrdType: Sized + Copy {
     ) -> RocblasStatus;
 }
 
+/// Trait for types that support triangular matrix inversion (trtri).
+pub trait TrtriType: Sized + Copy {
+    /// Compute the inverse of a triangular matrix.
+    unsafe fn trtri(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        diag: rocblas_ffi::rocblas_diagonal,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        info: *mut i32,
+    ) -> RocblasStatus;
+
+    /// Batched trtri.
+    unsafe fn trtri_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        diag: rocblas_ffi::rocblas_diagonal,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+
+    /// Strided batched trtri.
+    unsafe fn trtri_strided_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        diag: rocblas_ffi::rocblas_diagonal,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+}
+
+/// Trait for types that support symmetric indefinite factorization (sytrf).
+pub trait SytrfType: Sized + Copy {
+    /// Compute the Bunch-Kaufman factorization of a symmetric matrix.
+    unsafe fn sytrf(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        info: *mut i32,
+    ) -> RocblasStatus;
+
+    /// Batched sytrf.
+    unsafe fn sytrf_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+
+    /// Strided batched sytrf.
+    unsafe fn sytrf_strided_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+}
+
 // ============================================================================
 // Trait implementations for f32
 // ============================================================================
@@ -553,6 +636,130 @@ impl GebrdType for f32 {
     }
 }
 
+impl TrtriType for f32 {
+    unsafe fn trtri(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        diag: rocblas_ffi::rocblas_diagonal,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_strtri(cast_handle(handle), uplo, diag, n, A, lda, info)
+    }
+
+    unsafe fn trtri_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        diag: rocblas_ffi::rocblas_diagonal,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_strtri_batched(
+            cast_handle(handle),
+            uplo,
+            diag,
+            n,
+            A,
+            lda,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn trtri_strided_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        diag: rocblas_ffi::rocblas_diagonal,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_strtri_strided_batched(
+            cast_handle(handle),
+            uplo,
+            diag,
+            n,
+            A,
+            lda,
+            stride_a,
+            info,
+            batch_count,
+        )
+    }
+}
+
+impl SytrfType for f32 {
+    unsafe fn sytrf(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_ssytrf(cast_handle(handle), uplo, n, A, lda, ipiv, info)
+    }
+
+    unsafe fn sytrf_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_ssytrf_batched(
+            cast_handle(handle),
+            uplo,
+            n,
+            A,
+            lda,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn sytrf_strided_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_ssytrf_strided_batched(
+            cast_handle(handle),
+            uplo,
+            n,
+            A,
+            lda,
+            stride_a,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    }
+}
+
 // ============================================================================
 // Trait implementations for f64
 // ============================================================================
@@ -868,85 +1075,82 @@ impl GebrdType for f64 {
     }
 }
 
-// ============================================================================
-// Trait implementations for Complex32
-// ============================================================================
-
-impl GeqrfType for Complex32 {
-    unsafe fn geqrf(
+impl TrtriType for f64 {
+    unsafe fn trtri(
         handle: RocblasHandle,
-        m: i32,
+        uplo: rocblas_ffi::rocblas_fill,
+        diag: rocblas_ffi::rocblas_diagonal,
         n: i32,
         A: *mut Self,
         lda: i32,
-        ipiv: *mut Self,
+        info: *mut i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_cgeqrf(cast_handle(handle), m, n, A, lda, ipiv)
+        bindings::rocsolver_dtrtri(cast_handle(handle), uplo, diag, n, A, lda, info)
     }
 
-    unsafe fn geqrf_batched(
+    unsafe fn trtri_batched(
         handle: RocblasHandle,
-        m: i32,
+        uplo: rocblas_ffi::rocblas_fill,
+        diag: rocblas_ffi::rocblas_diagonal,
         n: i32,
         A: *const *mut Self,
         lda: i32,
-        ipiv: *mut Self,
-        stride_p: i64,
+        info: *mut i32,
         batch_count: i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_cgeqrf_batched(
+        bindings::rocsolver_dtrtri_batched(
             cast_handle(handle),
-            m,
+            uplo,
+            diag,
             n,
             A,
             lda,
-            ipiv,
-            stride_p,
+            info,
             batch_count,
         )
     }
 
-    unsafe fn geqrf_strided_batched(
+    unsafe fn trtri_strided_batched(
         handle: RocblasHandle,
-        m: i32,
+        uplo: rocblas_ffi::rocblas_fill,
+        diag: rocblas_ffi::rocblas_diagonal,
         n: i32,
         A: *mut Self,
         lda: i32,
         stride_a: i64,
-        ipiv: *mut Self,
-        stride_p: i64,
+        info: *mut i32,
         batch_count: i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_cgeqrf_strided_batched(
+        bindings::rocsolver_dtrtri_strided_batched(
             cast_handle(handle),
-            m,
+            uplo,
+            diag,
             n,
             A,
             lda,
             stride_a,
-            ipiv,
-            stride_p,
+            info,
             batch_count,
         )
     }
 }
 
-impl GetrfType for Complex32 {
-    unsafe fn getrf(
+impl SytrfType for f64 {
+    unsafe fn sytrf(
         handle: RocblasHandle,
-        m: i32,
+        uplo: rocblas_ffi::rocblas_fill,
         n: i32,
         A: *mut Self,
         lda: i32,
         ipiv: *mut i32,
         info: *mut i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_cgetrf(cast_handle(handle), m, n, A, lda, ipiv, info)
+        bindings::rocsolver_dsytrf(cast_handle(handle), uplo, n, A, lda, ipiv, info)
     }
 
-    unsafe fn getrf_batched(
+    unsafe fn sytrf_batched(
         handle: RocblasHandle,
-        m: i32,
+        uplo: rocblas_ffi::rocblas_fill,
         n: i32,
         A: *const *mut Self,
         lda: i32,
@@ -955,9 +1159,9 @@ impl GetrfType for Complex32 {
         info: *mut i32,
         batch_count: i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_cgetrf_batched(
+        bindings::rocsolver_dsytrf_batched(
             cast_handle(handle),
-            m,
+            uplo,
             n,
             A,
             lda,
@@ -968,9 +1172,9 @@ impl GetrfType for Complex32 {
         )
     }
 
-    unsafe fn getrf_strided_batched(
+    unsafe fn sytrf_strided_batched(
         handle: RocblasHandle,
-        m: i32,
+        uplo: rocblas_ffi::rocblas_fill,
         n: i32,
         A: *mut Self,
         lda: i32,
@@ -980,9 +1184,9 @@ impl GetrfType for Complex32 {
         info: *mut i32,
         batch_count: i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_cgetrf_strided_batched(
+        bindings::rocsolver_dsytrf_strided_batched(
             cast_handle(handle),
-            m,
+            uplo,
             n,
             A,
             lda,
@@ -993,65 +1197,192 @@ impl GetrfType for Complex32 {
             batch_count,
         )
     }
+}
 
-    unsafe fn getrf_npvt(
+// ============================================================================
+// Trait implementations for Complex32
+// ============================================================================
+
+impl GeqrfType for Complex32 {
+    unsafe fn geqrf(
         handle: RocblasHandle,
         m: i32,
         n: i32,
         A: *mut Self,
         lda: i32,
-        info: *mut i32,
+        ipiv: *mut Self,
     ) -> RocblasStatus {
-        bindings::rocsolver_cgetrf_npvt(cast_handle(handle), m, n, A, lda, info)
+        bindings::rocsolver_cgeqrf(cast_handle(handle), m, n, A, lda, ipiv)
     }
 
-    unsafe fn getrf_npvt_batched(
+    unsafe fn geqrf_batched(
         handle: RocblasHandle,
         m: i32,
         n: i32,
         A: *const *mut Self,
         lda: i32,
-        info: *mut i32,
+        ipiv: *mut Self,
+        stride_p: i64,
         batch_count: i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_cgetrf_npvt_batched(
+        bindings::rocsolver_cgeqrf_batched(
             cast_handle(handle),
             m,
             n,
             A,
             lda,
-            info,
+            ipiv,
+            stride_p,
             batch_count,
         )
     }
 
-    unsafe fn getrf_npvt_strided_batched(
+    unsafe fn geqrf_strided_batched(
         handle: RocblasHandle,
         m: i32,
         n: i32,
         A: *mut Self,
         lda: i32,
         stride_a: i64,
-        info: *mut i32,
+        ipiv: *mut Self,
+        stride_p: i64,
         batch_count: i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_cgetrf_npvt_strided_batched(
+        bindings::rocsolver_cgeqrf_strided_batched(
             cast_handle(handle),
             m,
             n,
             A,
             lda,
             stride_a,
-            info,
+            ipiv,
+            stride_p,
             batch_count,
         )
     }
 }
 
-impl PotrfType for Complex32 {
-    unsafe fn potrf(
+impl GetrfType for Complex32 {
+    unsafe fn getrf(
         handle: RocblasHandle,
-        uplo: rocblas_ffi::rocblas_fill,
+        m: i32,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cgetrf(cast_handle(handle), m, n, A, lda, ipiv, info)
+    }
+
+    unsafe fn getrf_batched(
+        handle: RocblasHandle,
+        m: i32,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cgetrf_batched(
+            cast_handle(handle),
+            m,
+            n,
+            A,
+            lda,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn getrf_strided_batched(
+        handle: RocblasHandle,
+        m: i32,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cgetrf_strided_batched(
+            cast_handle(handle),
+            m,
+            n,
+            A,
+            lda,
+            stride_a,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn getrf_npvt(
+        handle: RocblasHandle,
+        m: i32,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cgetrf_npvt(cast_handle(handle), m, n, A, lda, info)
+    }
+
+    unsafe fn getrf_npvt_batched(
+        handle: RocblasHandle,
+        m: i32,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cgetrf_npvt_batched(
+            cast_handle(handle),
+            m,
+            n,
+            A,
+            lda,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn getrf_npvt_strided_batched(
+        handle: RocblasHandle,
+        m: i32,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cgetrf_npvt_strided_batched(
+            cast_handle(handle),
+            m,
+            n,
+            A,
+            lda,
+            stride_a,
+            info,
+            batch_count,
+        )
+    }
+}
+
+impl PotrfType for Complex32 {
+    unsafe fn potrf(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
         n: i32,
         A: *mut Self,
         lda: i32,
@@ -1183,6 +1514,130 @@ impl GebrdType for Complex32 {
     }
 }
 
+impl TrtriType for Complex32 {
+    unsafe fn trtri(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        diag: rocblas_ffi::rocblas_diagonal,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_ctrtri(cast_handle(handle), uplo, diag, n, A, lda, info)
+    }
+
+    unsafe fn trtri_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        diag: rocblas_ffi::rocblas_diagonal,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_ctrtri_batched(
+            cast_handle(handle),
+            uplo,
+            diag,
+            n,
+            A,
+            lda,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn trtri_strided_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        diag: rocblas_ffi::rocblas_diagonal,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_ctrtri_strided_batched(
+            cast_handle(handle),
+            uplo,
+            diag,
+            n,
+            A,
+            lda,
+            stride_a,
+            info,
+            batch_count,
+        )
+    }
+}
+
+impl SytrfType for Complex32 {
+    unsafe fn sytrf(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_csytrf(cast_handle(handle), uplo, n, A, lda, ipiv, info)
+    }
+
+    unsafe fn sytrf_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_csytrf_batched(
+            cast_handle(handle),
+            uplo,
+            n,
+            A,
+            lda,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn sytrf_strided_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_csytrf_strided_batched(
+            cast_handle(handle),
+            uplo,
+            n,
+            A,
+            lda,
+            stride_a,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    }
+}
+
 // ============================================================================
 // Trait implementations for Complex64
 // ============================================================================
@@ -1498,6 +1953,130 @@ impl GebrdType for Complex64 {
     }
 }
 
+impl TrtriType for Complex64 {
+    unsafe fn trtri(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        diag: rocblas_ffi::rocblas_diagonal,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_ztrtri(cast_handle(handle), uplo, diag, n, A, lda, info)
+    }
+
+    unsafe fn trtri_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        diag: rocblas_ffi::rocblas_diagonal,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_ztrtri_batched(
+            cast_handle(handle),
+            uplo,
+            diag,
+            n,
+            A,
+            lda,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn trtri_strided_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        diag: rocblas_ffi::rocblas_diagonal,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_ztrtri_strided_batched(
+            cast_handle(handle),
+            uplo,
+            diag,
+            n,
+            A,
+            lda,
+            stride_a,
+            info,
+            batch_count,
+        )
+    }
+}
+
+impl SytrfType for Complex64 {
+    unsafe fn sytrf(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zsytrf(cast_handle(handle), uplo, n, A, lda, ipiv, info)
+    }
+
+    unsafe fn sytrf_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zsytrf_batched(
+            cast_handle(handle),
+            uplo,
+            n,
+            A,
+            lda,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn sytrf_strided_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zsytrf_strided_batched(
+            cast_handle(handle),
+            uplo,
+            n,
+            A,
+            lda,
+            stride_a,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    }
+}
+
 // ============================================================================
 // Public API functions
 // ============================================================================
@@ -1942,3 +2521,320 @@ pub fn gebrd_strided_batched<T: GebrdType>(
     };
     Error::from_status(status)
 }
+
+/// Computes the inverse of a triangular matrix.
+///
+/// Only the `uplo` triangle of A is referenced and overwritten with the
+/// corresponding triangle of inv(A).
+///
+/// # Arguments
+/// * `handle` - rocBLAS handle
+/// * `uplo` - Specifies whether A is upper or lower triangular
+/// * `diag` - Specifies whether A has a unit diagonal
+/// * `n` - Order of matrix A (n >= 0)
+/// * `A` - Device pointer to n-by-n triangular matrix (modified in-place)
+/// * `lda` - Leading dimension of A (lda >= max(1,n))
+/// * `info` - Device pointer to info value (0 = success, i > 0 = A(i,i) is zero, A is singular)
+#[inline]
+pub fn trtri<T: TrtriType>(
+    handle: &Handle,
+    uplo: Fill,
+    diag: Diagonal,
+    n: i32,
+    A: *mut T,
+    lda: i32,
+    info: *mut i32,
+) -> Result<()> {
+    let status = unsafe { T::trtri(handle.as_raw(), uplo.into(), diag.into(), n, A, lda, info) };
+    Error::from_status(status)
+}
+
+/// Computes the batched inverse of triangular matrices.
+#[inline]
+pub fn trtri_batched<T: TrtriType>(
+    handle: &Handle,
+    uplo: Fill,
+    diag: Diagonal,
+    n: i32,
+    A: *const *mut T,
+    lda: i32,
+    info: *mut i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::trtri_batched(
+            handle.as_raw(),
+            uplo.into(),
+            diag.into(),
+            n,
+            A,
+            lda,
+            info,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Computes the strided batched inverse of triangular matrices.
+#[inline]
+pub fn trtri_strided_batched<T: TrtriType>(
+    handle: &Handle,
+    uplo: Fill,
+    diag: Diagonal,
+    n: i32,
+    A: *mut T,
+    lda: i32,
+    stride_a: i64,
+    info: *mut i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::trtri_strided_batched(
+            handle.as_raw(),
+            uplo.into(),
+            diag.into(),
+            n,
+            A,
+            lda,
+            stride_a,
+            info,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Computes the factorization of a symmetric indefinite matrix using
+/// Bunch-Kaufman diagonal pivoting.
+///
+/// The factorization has the form:
+///   A = U * D * U^T  if uplo = Upper
+///   A = L * D * L^T  if uplo = Lower
+/// where U (L) is a product of permutation and unit upper (lower) triangular
+/// matrices, and D is block diagonal with 1-by-1 and 2-by-2 blocks.
+///
+/// For complex types this factors a complex *symmetric* matrix; rocSOLVER
+/// has no Hermitian variant (hetrf).
+///
+/// The pivots are encoded LAPACK-style with 1-based indices; use
+/// [`sytrf_pivots`] to decode them into the blocks of D.
+///
+/// # Arguments
+/// * `handle` - rocBLAS handle
+/// * `uplo` - Specifies whether upper or lower triangle of A is stored
+/// * `n` - Order of matrix A (n >= 0)
+/// * `A` - Device pointer to n-by-n symmetric matrix (overwritten with D and the multipliers)
+/// * `lda` - Leading dimension of A (lda >= max(1,n))
+/// * `ipiv` - Device pointer to pivot indices (n elements)
+/// * `info` - Device pointer to info value (0 = success, i > 0 = D(i,i) is zero, D is singular)
+#[inline]
+pub fn sytrf<T: SytrfType>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    A: *mut T,
+    lda: i32,
+    ipiv: *mut i32,
+    info: *mut i32,
+) -> Result<()> {
+    let status = unsafe { T::sytrf(handle.as_raw(), uplo.into(), n, A, lda, ipiv, info) };
+    Error::from_status(status)
+}
+
+/// Computes the batched symmetric indefinite factorization.
+#[inline]
+pub fn sytrf_batched<T: SytrfType>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    A: *const *mut T,
+    lda: i32,
+    ipiv: *mut i32,
+    stride_p: i64,
+    info: *mut i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::sytrf_batched(
+            handle.as_raw(),
+            uplo.into(),
+            n,
+            A,
+            lda,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Computes the strided batched symmetric indefinite factorization.
+#[inline]
+pub fn sytrf_strided_batched<T: SytrfType>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    A: *mut T,
+    lda: i32,
+    stride_a: i64,
+    ipiv: *mut i32,
+    stride_p: i64,
+    info: *mut i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::sytrf_strided_batched(
+            handle.as_raw(),
+            uplo.into(),
+            n,
+            A,
+            lda,
+            stride_a,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// A diagonal block of D in a [`sytrf`] factorization.
+///
+/// Indices are 0-based.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PivotBlock {
+    /// First row/column of the block.
+    pub start: usize,
+    /// Order of the block (1 or 2).
+    pub size: usize,
+    /// Row/column of the block that was interchanged.
+    pub row: usize,
+    /// Row/column it was interchanged with (equal to `row` if none).
+    pub swapped_with: usize,
+}
+
+/// Decodes the pivot indices computed by [`sytrf`] into the blocks of D.
+///
+/// `ipiv` holds the pivots of one matrix, copied to the host. A positive
+/// entry k marks a 1-by-1 block whose row was interchanged with row k. Two
+/// equal negative entries -k mark a 2-by-2 block; its first row (`Upper`) or
+/// second row (`Lower`) was interchanged with row k. Blocks are returned in
+/// order of their first row.
+///
+/// Returns `rocblas_status_invalid_value` if `uplo` is `Full` or the pivots
+/// are not a valid sytrf encoding.
+pub fn sytrf_pivots(uplo: Fill, ipiv: &[i32]) -> Result<Vec<PivotBlock>> {
+    let invalid = || Error::new(rocblas_ffi::rocblas_status__rocblas_status_invalid_value);
+    let n = ipiv.len();
+    let target = |p: i32| {
+        let k = p.unsigned_abs() as usize;
+        if k == 0 || k > n {
+            Err(invalid())
+        } else {
+            Ok(k - 1)
+        }
+    };
+
+    let mut blocks = Vec::new();
+    match uplo {
+        Fill::Lower => {
+            let mut k = 0;
+            while k < n {
+                let p = ipiv[k];
+                if p > 0 {
+                    blocks.push(PivotBlock {
+                        start: k,
+                        size: 1,
+                        row: k,
+                        swapped_with: target(p)?,
+                    });
+                    k += 1;
+                } else if k + 1 < n && ipiv[k + 1] == p {
+                    blocks.push(PivotBlock {
+                        start: k,
+                        size: 2,
+                        row: k + 1,
+                        swapped_with: target(p)?,
+                    });
+                    k += 2;
+                } else {
+                    return Err(invalid());
+                }
+            }
+        }
+        Fill::Upper => {
+            // Upper factorizations are computed from the last column up
+            let mut k = n;
+            while k > 0 {
+                let p = ipiv[k - 1];
+                if p > 0 {
+                    blocks.push(PivotBlock {
+                        start: k - 1,
+                        size: 1,
+                        row: k - 1,
+                        swapped_with: target(p)?,
+                    });
+                    k -= 1;
+                } else if k >= 2 && ipiv[k - 2] == p {
+                    blocks.push(PivotBlock {
+                        start: k - 2,
+                        size: 2,
+                        row: k - 2,
+                        swapped_with: target(p)?,
+                    });
+                    k -= 2;
+                } else {
+                    return Err(invalid());
+                }
+            }
+            blocks.reverse();
+        }
+        Fill::Full => return Err(invalid()),
+    }
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(start: usize, size: usize, row: usize, swapped_with: usize) -> PivotBlock {
+        PivotBlock {
+            start,
+            size,
+            row,
+            swapped_with,
+        }
+    }
+
+    #[test]
+    fn test_sytrf_pivots_lower() {
+        let blocks = sytrf_pivots(Fill::Lower, &[2, -4, -4, 4]).unwrap();
+        assert_eq!(
+            blocks,
+            vec![block(0, 1, 0, 1), block(1, 2, 2, 3), block(3, 1, 3, 3)]
+        );
+    }
+
+    #[test]
+    fn test_sytrf_pivots_upper() {
+        let blocks = sytrf_pivots(Fill::Upper, &[-3, -3, 3]).unwrap();
+        assert_eq!(blocks, vec![block(0, 2, 0, 2), block(2, 1, 2, 2)]);
+    }
+
+    #[test]
+    fn test_sytrf_pivots_invalid() {
+        assert!(sytrf_pivots(Fill::Full, &[1]).is_err());
+        // An unpaired negative pivot
+        assert!(sytrf_pivots(Fill::Lower, &[1, -2]).is_err());
+        assert!(sytrf_pivots(Fill::Upper, &[-1, 2]).is_err());
+        // Pivots outside 1..=n
+        assert!(sytrf_pivots(Fill::Lower, &[0]).is_err());
+        assert!(sytrf_pivots(Fill::Lower, &[2]).is_err());
+        assert_eq!(sytrf_pivots(Fill::Lower, &[]).unwrap(), vec![]);
+    }
+}
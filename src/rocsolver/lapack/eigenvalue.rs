@@ -5,12 +5,16 @@
 //!
 //! - [`syev`] - Eigenvalues/vectors of a real symmetric matrix
 //! - [`heev`] - Eigenvalues/vectors of a complex Hermitian matrix
+//! - [`syevj`] - Eigenvalues/vectors of a real symmetric matrix (Jacobi)
+//! - [`heevj`] - Eigenvalues/vectors of a complex Hermitian matrix (Jacobi)
+//! - [`sygvd`] - Generalized symmetric-definite eigenproblem `A x = λ B x`
+//! - [`hegvd`] - Generalized Hermitian-definite eigenproblem `A x = λ B x`
 
 use crate::rocblas::Handle;
 use crate::rocblas::ffi as rocblas_ffi;
 use crate::rocsolver::bindings;
 use crate::rocsolver::error::{Error, Result};
-use crate::rocsolver::types::{Complex32, Complex64, Evect, Fill};
+use crate::rocsolver::types::{Complex32, Complex64, Eform, Esort, Evect, Fill, JacobiParams};
 
 // Type alias for handle - we use rocblas handle but need to cast for rocsolver bindings
 type RocblasHandle = rocblas_ffi::rocblas_handle;
@@ -127,6 +131,248 @@ pub trait HeevType: Sized + Copy {
     ) -> RocblasStatus;
 }
 
+/// Trait for types that support Jacobi symmetric eigenvalue decomposition (syevj).
+pub trait SyevjType: Sized + Copy {
+    /// Compute eigenvalues and optionally eigenvectors of a symmetric matrix with the Jacobi method.
+    unsafe fn syevj(
+        handle: RocblasHandle,
+        esort: bindings::rocblas_esort,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        abstol: Self,
+        residual: *mut Self,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        W: *mut Self,
+        info: *mut i32,
+    ) -> RocblasStatus;
+
+    /// Batched syevj.
+    unsafe fn syevj_batched(
+        handle: RocblasHandle,
+        esort: bindings::rocblas_esort,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        abstol: Self,
+        residual: *mut Self,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        W: *mut Self,
+        stride_w: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+
+    /// Strided batched syevj.
+    unsafe fn syevj_strided_batched(
+        handle: RocblasHandle,
+        esort: bindings::rocblas_esort,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        abstol: Self,
+        residual: *mut Self,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        W: *mut Self,
+        stride_w: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+}
+
+/// Trait for types that support Jacobi Hermitian eigenvalue decomposition (heevj).
+pub trait HeevjType: Sized + Copy {
+    /// The real type for eigenvalues.
+    type RealType: Copy;
+
+    /// Compute eigenvalues and optionally eigenvectors of a Hermitian matrix with the Jacobi method.
+    unsafe fn heevj(
+        handle: RocblasHandle,
+        esort: bindings::rocblas_esort,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        W: *mut Self::RealType,
+        info: *mut i32,
+    ) -> RocblasStatus;
+
+    /// Batched heevj.
+    unsafe fn heevj_batched(
+        handle: RocblasHandle,
+        esort: bindings::rocblas_esort,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        W: *mut Self::RealType,
+        stride_w: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+
+    /// Strided batched heevj.
+    unsafe fn heevj_strided_batched(
+        handle: RocblasHandle,
+        esort: bindings::rocblas_esort,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        W: *mut Self::RealType,
+        stride_w: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+}
+
+/// Trait for types that support the generalized symmetric-definite eigenproblem (sygvd).
+pub trait SygvdType: Sized + Copy {
+    /// Solve a generalized symmetric-definite eigenproblem (divide and conquer).
+    unsafe fn sygvd(
+        handle: RocblasHandle,
+        itype: bindings::rocblas_eform,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        B: *mut Self,
+        ldb: i32,
+        D: *mut Self,
+        E: *mut Self,
+        info: *mut i32,
+    ) -> RocblasStatus;
+
+    /// Batched sygvd.
+    unsafe fn sygvd_batched(
+        handle: RocblasHandle,
+        itype: bindings::rocblas_eform,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        B: *const *mut Self,
+        ldb: i32,
+        D: *mut Self,
+        stride_d: i64,
+        E: *mut Self,
+        stride_e: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+
+    /// Strided batched sygvd.
+    unsafe fn sygvd_strided_batched(
+        handle: RocblasHandle,
+        itype: bindings::rocblas_eform,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        B: *mut Self,
+        ldb: i32,
+        stride_b: i64,
+        D: *mut Self,
+        stride_d: i64,
+        E: *mut Self,
+        stride_e: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+}
+
+/// Trait for types that support the generalized Hermitian-definite eigenproblem (hegvd).
+pub trait HegvdType: Sized + Copy {
+    /// The real type for eigenvalues.
+    type RealType: Copy;
+
+    /// Solve a generalized Hermitian-definite eigenproblem (divide and conquer).
+    unsafe fn hegvd(
+        handle: RocblasHandle,
+        itype: bindings::rocblas_eform,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        B: *mut Self,
+        ldb: i32,
+        D: *mut Self::RealType,
+        E: *mut Self::RealType,
+        info: *mut i32,
+    ) -> RocblasStatus;
+
+    /// Batched hegvd.
+    unsafe fn hegvd_batched(
+        handle: RocblasHandle,
+        itype: bindings::rocblas_eform,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        B: *const *mut Self,
+        ldb: i32,
+        D: *mut Self::RealType,
+        stride_d: i64,
+        E: *mut Self::RealType,
+        stride_e: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+
+    /// Strided batched hegvd.
+    unsafe fn hegvd_strided_batched(
+        handle: RocblasHandle,
+        itype: bindings::rocblas_eform,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        B: *mut Self,
+        ldb: i32,
+        stride_b: i64,
+        D: *mut Self::RealType,
+        stride_d: i64,
+        E: *mut Self::RealType,
+        stride_e: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+}
+
 // ============================================================================
 // Trait implementations for f32
 // ============================================================================
@@ -209,130 +455,172 @@ impl SyevType for f32 {
     }
 }
 
-// ============================================================================
-// Trait implementations for f64
-// ============================================================================
-
-impl SyevType for f64 {
-    unsafe fn syev(
+impl SyevjType for f32 {
+    unsafe fn syevj(
         handle: RocblasHandle,
+        esort: bindings::rocblas_esort,
         evect: bindings::rocblas_evect,
         uplo: rocblas_ffi::rocblas_fill,
         n: i32,
         A: *mut Self,
         lda: i32,
-        D: *mut Self,
-        E: *mut Self,
+        abstol: Self,
+        residual: *mut Self,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        W: *mut Self,
         info: *mut i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_dsyev(cast_handle(handle), evect, uplo, n, A, lda, D, E, info)
+        bindings::rocsolver_ssyevj(
+            cast_handle(handle),
+            esort,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            W,
+            info,
+        )
     }
 
-    unsafe fn syev_batched(
+    unsafe fn syevj_batched(
         handle: RocblasHandle,
+        esort: bindings::rocblas_esort,
         evect: bindings::rocblas_evect,
         uplo: rocblas_ffi::rocblas_fill,
         n: i32,
         A: *const *mut Self,
         lda: i32,
-        D: *mut Self,
-        stride_d: i64,
-        E: *mut Self,
-        stride_e: i64,
+        abstol: Self,
+        residual: *mut Self,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        W: *mut Self,
+        stride_w: i64,
         info: *mut i32,
         batch_count: i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_dsyev_batched(
+        bindings::rocsolver_ssyevj_batched(
             cast_handle(handle),
+            esort,
             evect,
             uplo,
             n,
             A,
             lda,
-            D,
-            stride_d,
-            E,
-            stride_e,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            W,
+            stride_w,
             info,
             batch_count,
         )
     }
 
-    unsafe fn syev_strided_batched(
+    unsafe fn syevj_strided_batched(
         handle: RocblasHandle,
+        esort: bindings::rocblas_esort,
         evect: bindings::rocblas_evect,
         uplo: rocblas_ffi::rocblas_fill,
         n: i32,
         A: *mut Self,
         lda: i32,
         stride_a: i64,
-        D: *mut Self,
-        stride_d: i64,
-        E: *mut Self,
-        stride_e: i64,
+        abstol: Self,
+        residual: *mut Self,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        W: *mut Self,
+        stride_w: i64,
         info: *mut i32,
         batch_count: i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_dsyev_strided_batched(
+        bindings::rocsolver_ssyevj_strided_batched(
             cast_handle(handle),
+            esort,
             evect,
             uplo,
             n,
             A,
             lda,
             stride_a,
-            D,
-            stride_d,
-            E,
-            stride_e,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            W,
+            stride_w,
             info,
             batch_count,
         )
     }
 }
 
-// ============================================================================
-// Trait implementations for Complex32
-// ============================================================================
-
-impl HeevType for Complex32 {
-    type RealType = f32;
-
-    unsafe fn heev(
+impl SygvdType for f32 {
+    unsafe fn sygvd(
         handle: RocblasHandle,
+        itype: bindings::rocblas_eform,
         evect: bindings::rocblas_evect,
         uplo: rocblas_ffi::rocblas_fill,
         n: i32,
         A: *mut Self,
         lda: i32,
-        D: *mut Self::RealType,
-        E: *mut Self::RealType,
+        B: *mut Self,
+        ldb: i32,
+        D: *mut Self,
+        E: *mut Self,
         info: *mut i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_cheev(cast_handle(handle), evect, uplo, n, A, lda, D, E, info)
+        bindings::rocsolver_ssygvd(
+            cast_handle(handle),
+            itype,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            B,
+            ldb,
+            D,
+            E,
+            info,
+        )
     }
 
-    unsafe fn heev_batched(
+    unsafe fn sygvd_batched(
         handle: RocblasHandle,
+        itype: bindings::rocblas_eform,
         evect: bindings::rocblas_evect,
         uplo: rocblas_ffi::rocblas_fill,
         n: i32,
         A: *const *mut Self,
         lda: i32,
-        D: *mut Self::RealType,
+        B: *const *mut Self,
+        ldb: i32,
+        D: *mut Self,
         stride_d: i64,
-        E: *mut Self::RealType,
+        E: *mut Self,
         stride_e: i64,
         info: *mut i32,
         batch_count: i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_cheev_batched(
+        bindings::rocsolver_ssygvd_batched(
             cast_handle(handle),
+            itype,
             evect,
             uplo,
             n,
             A,
             lda,
+            B,
+            ldb,
             D,
             stride_d,
             E,
@@ -342,29 +630,37 @@ impl HeevType for Complex32 {
         )
     }
 
-    unsafe fn heev_strided_batched(
+    unsafe fn sygvd_strided_batched(
         handle: RocblasHandle,
+        itype: bindings::rocblas_eform,
         evect: bindings::rocblas_evect,
         uplo: rocblas_ffi::rocblas_fill,
         n: i32,
         A: *mut Self,
         lda: i32,
         stride_a: i64,
-        D: *mut Self::RealType,
+        B: *mut Self,
+        ldb: i32,
+        stride_b: i64,
+        D: *mut Self,
         stride_d: i64,
-        E: *mut Self::RealType,
+        E: *mut Self,
         stride_e: i64,
         info: *mut i32,
         batch_count: i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_cheev_strided_batched(
+        bindings::rocsolver_ssygvd_strided_batched(
             cast_handle(handle),
+            itype,
             evect,
             uplo,
             n,
             A,
             lda,
             stride_a,
+            B,
+            ldb,
+            stride_b,
             D,
             stride_d,
             E,
@@ -376,41 +672,39 @@ impl HeevType for Complex32 {
 }
 
 // ============================================================================
-// Trait implementations for Complex64
+// Trait implementations for f64
 // ============================================================================
 
-impl HeevType for Complex64 {
-    type RealType = f64;
-
-    unsafe fn heev(
+impl SyevType for f64 {
+    unsafe fn syev(
         handle: RocblasHandle,
         evect: bindings::rocblas_evect,
         uplo: rocblas_ffi::rocblas_fill,
         n: i32,
         A: *mut Self,
         lda: i32,
-        D: *mut Self::RealType,
-        E: *mut Self::RealType,
+        D: *mut Self,
+        E: *mut Self,
         info: *mut i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_zheev(cast_handle(handle), evect, uplo, n, A, lda, D, E, info)
+        bindings::rocsolver_dsyev(cast_handle(handle), evect, uplo, n, A, lda, D, E, info)
     }
 
-    unsafe fn heev_batched(
+    unsafe fn syev_batched(
         handle: RocblasHandle,
         evect: bindings::rocblas_evect,
         uplo: rocblas_ffi::rocblas_fill,
         n: i32,
         A: *const *mut Self,
         lda: i32,
-        D: *mut Self::RealType,
+        D: *mut Self,
         stride_d: i64,
-        E: *mut Self::RealType,
+        E: *mut Self,
         stride_e: i64,
         info: *mut i32,
         batch_count: i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_zheev_batched(
+        bindings::rocsolver_dsyev_batched(
             cast_handle(handle),
             evect,
             uplo,
@@ -426,7 +720,7 @@ impl HeevType for Complex64 {
         )
     }
 
-    unsafe fn heev_strided_batched(
+    unsafe fn syev_strided_batched(
         handle: RocblasHandle,
         evect: bindings::rocblas_evect,
         uplo: rocblas_ffi::rocblas_fill,
@@ -434,14 +728,14 @@ impl HeevType for Complex64 {
         A: *mut Self,
         lda: i32,
         stride_a: i64,
-        D: *mut Self::RealType,
+        D: *mut Self,
         stride_d: i64,
-        E: *mut Self::RealType,
+        E: *mut Self,
         stride_e: i64,
         info: *mut i32,
         batch_count: i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_zheev_strided_batched(
+        bindings::rocsolver_dsyev_strided_batched(
             cast_handle(handle),
             evect,
             uplo,
@@ -459,49 +753,1391 @@ impl HeevType for Complex64 {
     }
 }
 
-// ============================================================================
-// Public API functions
-// ============================================================================
-
+impl SyevjType for f64 {
+    unsafe fn syevj(
+        handle: RocblasHandle,
+        esort: bindings::rocblas_esort,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        abstol: Self,
+        residual: *mut Self,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        W: *mut Self,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_dsyevj(
+            cast_handle(handle),
+            esort,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            W,
+            info,
+        )
+    }
+
+    unsafe fn syevj_batched(
+        handle: RocblasHandle,
+        esort: bindings::rocblas_esort,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        abstol: Self,
+        residual: *mut Self,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        W: *mut Self,
+        stride_w: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_dsyevj_batched(
+            cast_handle(handle),
+            esort,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            W,
+            stride_w,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn syevj_strided_batched(
+        handle: RocblasHandle,
+        esort: bindings::rocblas_esort,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        abstol: Self,
+        residual: *mut Self,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        W: *mut Self,
+        stride_w: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_dsyevj_strided_batched(
+            cast_handle(handle),
+            esort,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            stride_a,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            W,
+            stride_w,
+            info,
+            batch_count,
+        )
+    }
+}
+
+impl SygvdType for f64 {
+    unsafe fn sygvd(
+        handle: RocblasHandle,
+        itype: bindings::rocblas_eform,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        B: *mut Self,
+        ldb: i32,
+        D: *mut Self,
+        E: *mut Self,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_dsygvd(
+            cast_handle(handle),
+            itype,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            B,
+            ldb,
+            D,
+            E,
+            info,
+        )
+    }
+
+    unsafe fn sygvd_batched(
+        handle: RocblasHandle,
+        itype: bindings::rocblas_eform,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        B: *const *mut Self,
+        ldb: i32,
+        D: *mut Self,
+        stride_d: i64,
+        E: *mut Self,
+        stride_e: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_dsygvd_batched(
+            cast_handle(handle),
+            itype,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            B,
+            ldb,
+            D,
+            stride_d,
+            E,
+            stride_e,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn sygvd_strided_batched(
+        handle: RocblasHandle,
+        itype: bindings::rocblas_eform,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        B: *mut Self,
+        ldb: i32,
+        stride_b: i64,
+        D: *mut Self,
+        stride_d: i64,
+        E: *mut Self,
+        stride_e: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_dsygvd_strided_batched(
+            cast_handle(handle),
+            itype,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            stride_a,
+            B,
+            ldb,
+            stride_b,
+            D,
+            stride_d,
+            E,
+            stride_e,
+            info,
+            batch_count,
+        )
+    }
+}
+
+// ============================================================================
+// Trait implementations for Complex32
+// ============================================================================
+
+impl HeevType for Complex32 {
+    type RealType = f32;
+
+    unsafe fn heev(
+        handle: RocblasHandle,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        D: *mut Self::RealType,
+        E: *mut Self::RealType,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cheev(cast_handle(handle), evect, uplo, n, A, lda, D, E, info)
+    }
+
+    unsafe fn heev_batched(
+        handle: RocblasHandle,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        D: *mut Self::RealType,
+        stride_d: i64,
+        E: *mut Self::RealType,
+        stride_e: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cheev_batched(
+            cast_handle(handle),
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            D,
+            stride_d,
+            E,
+            stride_e,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn heev_strided_batched(
+        handle: RocblasHandle,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        D: *mut Self::RealType,
+        stride_d: i64,
+        E: *mut Self::RealType,
+        stride_e: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cheev_strided_batched(
+            cast_handle(handle),
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            stride_a,
+            D,
+            stride_d,
+            E,
+            stride_e,
+            info,
+            batch_count,
+        )
+    }
+}
+
+impl HeevjType for Complex32 {
+    type RealType = f32;
+
+    unsafe fn heevj(
+        handle: RocblasHandle,
+        esort: bindings::rocblas_esort,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        W: *mut Self::RealType,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cheevj(
+            cast_handle(handle),
+            esort,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            W,
+            info,
+        )
+    }
+
+    unsafe fn heevj_batched(
+        handle: RocblasHandle,
+        esort: bindings::rocblas_esort,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        W: *mut Self::RealType,
+        stride_w: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cheevj_batched(
+            cast_handle(handle),
+            esort,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            W,
+            stride_w,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn heevj_strided_batched(
+        handle: RocblasHandle,
+        esort: bindings::rocblas_esort,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        W: *mut Self::RealType,
+        stride_w: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cheevj_strided_batched(
+            cast_handle(handle),
+            esort,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            stride_a,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            W,
+            stride_w,
+            info,
+            batch_count,
+        )
+    }
+}
+
+impl HegvdType for Complex32 {
+    type RealType = f32;
+
+    unsafe fn hegvd(
+        handle: RocblasHandle,
+        itype: bindings::rocblas_eform,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        B: *mut Self,
+        ldb: i32,
+        D: *mut Self::RealType,
+        E: *mut Self::RealType,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_chegvd(
+            cast_handle(handle),
+            itype,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            B,
+            ldb,
+            D,
+            E,
+            info,
+        )
+    }
+
+    unsafe fn hegvd_batched(
+        handle: RocblasHandle,
+        itype: bindings::rocblas_eform,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        B: *const *mut Self,
+        ldb: i32,
+        D: *mut Self::RealType,
+        stride_d: i64,
+        E: *mut Self::RealType,
+        stride_e: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_chegvd_batched(
+            cast_handle(handle),
+            itype,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            B,
+            ldb,
+            D,
+            stride_d,
+            E,
+            stride_e,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn hegvd_strided_batched(
+        handle: RocblasHandle,
+        itype: bindings::rocblas_eform,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        B: *mut Self,
+        ldb: i32,
+        stride_b: i64,
+        D: *mut Self::RealType,
+        stride_d: i64,
+        E: *mut Self::RealType,
+        stride_e: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_chegvd_strided_batched(
+            cast_handle(handle),
+            itype,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            stride_a,
+            B,
+            ldb,
+            stride_b,
+            D,
+            stride_d,
+            E,
+            stride_e,
+            info,
+            batch_count,
+        )
+    }
+}
+
+// ============================================================================
+// Trait implementations for Complex64
+// ============================================================================
+
+impl HeevType for Complex64 {
+    type RealType = f64;
+
+    unsafe fn heev(
+        handle: RocblasHandle,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        D: *mut Self::RealType,
+        E: *mut Self::RealType,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zheev(cast_handle(handle), evect, uplo, n, A, lda, D, E, info)
+    }
+
+    unsafe fn heev_batched(
+        handle: RocblasHandle,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        D: *mut Self::RealType,
+        stride_d: i64,
+        E: *mut Self::RealType,
+        stride_e: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zheev_batched(
+            cast_handle(handle),
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            D,
+            stride_d,
+            E,
+            stride_e,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn heev_strided_batched(
+        handle: RocblasHandle,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        D: *mut Self::RealType,
+        stride_d: i64,
+        E: *mut Self::RealType,
+        stride_e: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zheev_strided_batched(
+            cast_handle(handle),
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            stride_a,
+            D,
+            stride_d,
+            E,
+            stride_e,
+            info,
+            batch_count,
+        )
+    }
+}
+
+impl HeevjType for Complex64 {
+    type RealType = f64;
+
+    unsafe fn heevj(
+        handle: RocblasHandle,
+        esort: bindings::rocblas_esort,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        W: *mut Self::RealType,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zheevj(
+            cast_handle(handle),
+            esort,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            W,
+            info,
+        )
+    }
+
+    unsafe fn heevj_batched(
+        handle: RocblasHandle,
+        esort: bindings::rocblas_esort,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        W: *mut Self::RealType,
+        stride_w: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zheevj_batched(
+            cast_handle(handle),
+            esort,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            W,
+            stride_w,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn heevj_strided_batched(
+        handle: RocblasHandle,
+        esort: bindings::rocblas_esort,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        W: *mut Self::RealType,
+        stride_w: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zheevj_strided_batched(
+            cast_handle(handle),
+            esort,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            stride_a,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            W,
+            stride_w,
+            info,
+            batch_count,
+        )
+    }
+}
+
+impl HegvdType for Complex64 {
+    type RealType = f64;
+
+    unsafe fn hegvd(
+        handle: RocblasHandle,
+        itype: bindings::rocblas_eform,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        B: *mut Self,
+        ldb: i32,
+        D: *mut Self::RealType,
+        E: *mut Self::RealType,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zhegvd(
+            cast_handle(handle),
+            itype,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            B,
+            ldb,
+            D,
+            E,
+            info,
+        )
+    }
+
+    unsafe fn hegvd_batched(
+        handle: RocblasHandle,
+        itype: bindings::rocblas_eform,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        B: *const *mut Self,
+        ldb: i32,
+        D: *mut Self::RealType,
+        stride_d: i64,
+        E: *mut Self::RealType,
+        stride_e: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zhegvd_batched(
+            cast_handle(handle),
+            itype,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            B,
+            ldb,
+            D,
+            stride_d,
+            E,
+            stride_e,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn hegvd_strided_batched(
+        handle: RocblasHandle,
+        itype: bindings::rocblas_eform,
+        evect: bindings::rocblas_evect,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        B: *mut Self,
+        ldb: i32,
+        stride_b: i64,
+        D: *mut Self::RealType,
+        stride_d: i64,
+        E: *mut Self::RealType,
+        stride_e: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zhegvd_strided_batched(
+            cast_handle(handle),
+            itype,
+            evect,
+            uplo,
+            n,
+            A,
+            lda,
+            stride_a,
+            B,
+            ldb,
+            stride_b,
+            D,
+            stride_d,
+            E,
+            stride_e,
+            info,
+            batch_count,
+        )
+    }
+}
+
+// ============================================================================
+// Public API functions
+// ============================================================================
+
 /// Computes eigenvalues and optionally eigenvectors of a real symmetric matrix.
 ///
-/// The eigenvalue decomposition is:
-///   A = V * D * V^T
-/// where D is diagonal containing eigenvalues, and V is orthogonal containing eigenvectors.
+/// The eigenvalue decomposition is:
+///   A = V * D * V^T
+/// where D is diagonal containing eigenvalues, and V is orthogonal containing eigenvectors.
+///
+/// # Arguments
+/// * `handle` - rocBLAS handle
+/// * `evect` - Specifies whether to compute eigenvectors (Original, Tridiagonal, None)
+/// * `uplo` - Specifies whether upper or lower triangle of A is stored
+/// * `n` - Order of matrix A
+/// * `A` - Device pointer to n-by-n symmetric matrix (modified; contains eigenvectors if evect=Original)
+/// * `lda` - Leading dimension of A
+/// * `D` - Device pointer to eigenvalues (n elements, ascending order)
+/// * `E` - Device pointer to workspace (n-1 elements)
+/// * `info` - Device pointer to info value
+///
+/// # Returns
+/// `Ok(())` on success, or an error if the operation failed.
+#[inline]
+pub fn syev<T: SyevType>(
+    handle: &Handle,
+    evect: Evect,
+    uplo: Fill,
+    n: i32,
+    A: *mut T,
+    lda: i32,
+    D: *mut T,
+    E: *mut T,
+    info: *mut i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::syev(
+            handle.as_raw(),
+            evect.into(),
+            uplo.into(),
+            n,
+            A,
+            lda,
+            D,
+            E,
+            info,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Batched version of syev.
+#[inline]
+pub fn syev_batched<T: SyevType>(
+    handle: &Handle,
+    evect: Evect,
+    uplo: Fill,
+    n: i32,
+    A: *const *mut T,
+    lda: i32,
+    D: *mut T,
+    stride_d: i64,
+    E: *mut T,
+    stride_e: i64,
+    info: *mut i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::syev_batched(
+            handle.as_raw(),
+            evect.into(),
+            uplo.into(),
+            n,
+            A,
+            lda,
+            D,
+            stride_d,
+            E,
+            stride_e,
+            info,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Strided batched version of syev.
+#[inline]
+pub fn syev_strided_batched<T: SyevType>(
+    handle: &Handle,
+    evect: Evect,
+    uplo: Fill,
+    n: i32,
+    A: *mut T,
+    lda: i32,
+    stride_a: i64,
+    D: *mut T,
+    stride_d: i64,
+    E: *mut T,
+    stride_e: i64,
+    info: *mut i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::syev_strided_batched(
+            handle.as_raw(),
+            evect.into(),
+            uplo.into(),
+            n,
+            A,
+            lda,
+            stride_a,
+            D,
+            stride_d,
+            E,
+            stride_e,
+            info,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Computes eigenvalues and optionally eigenvectors of a complex Hermitian matrix.
+///
+/// The eigenvalue decomposition is:
+///   A = V * D * V^H
+/// where D is real diagonal containing eigenvalues, and V is unitary containing eigenvectors.
+///
+/// # Arguments
+/// * `handle` - rocBLAS handle
+/// * `evect` - Specifies whether to compute eigenvectors
+/// * `uplo` - Specifies whether upper or lower triangle of A is stored
+/// * `n` - Order of matrix A
+/// * `A` - Device pointer to n-by-n Hermitian matrix
+/// * `lda` - Leading dimension of A
+/// * `D` - Device pointer to real eigenvalues (n elements)
+/// * `E` - Device pointer to real workspace (n-1 elements)
+/// * `info` - Device pointer to info value
+#[inline]
+pub fn heev<T: HeevType>(
+    handle: &Handle,
+    evect: Evect,
+    uplo: Fill,
+    n: i32,
+    A: *mut T,
+    lda: i32,
+    D: *mut T::RealType,
+    E: *mut T::RealType,
+    info: *mut i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::heev(
+            handle.as_raw(),
+            evect.into(),
+            uplo.into(),
+            n,
+            A,
+            lda,
+            D,
+            E,
+            info,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Batched version of heev.
+#[inline]
+pub fn heev_batched<T: HeevType>(
+    handle: &Handle,
+    evect: Evect,
+    uplo: Fill,
+    n: i32,
+    A: *const *mut T,
+    lda: i32,
+    D: *mut T::RealType,
+    stride_d: i64,
+    E: *mut T::RealType,
+    stride_e: i64,
+    info: *mut i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::heev_batched(
+            handle.as_raw(),
+            evect.into(),
+            uplo.into(),
+            n,
+            A,
+            lda,
+            D,
+            stride_d,
+            E,
+            stride_e,
+            info,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Strided batched version of heev.
+#[inline]
+pub fn heev_strided_batched<T: HeevType>(
+    handle: &Handle,
+    evect: Evect,
+    uplo: Fill,
+    n: i32,
+    A: *mut T,
+    lda: i32,
+    stride_a: i64,
+    D: *mut T::RealType,
+    stride_d: i64,
+    E: *mut T::RealType,
+    stride_e: i64,
+    info: *mut i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::heev_strided_batched(
+            handle.as_raw(),
+            evect.into(),
+            uplo.into(),
+            n,
+            A,
+            lda,
+            stride_a,
+            D,
+            stride_d,
+            E,
+            stride_e,
+            info,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Computes eigenvalues and optionally eigenvectors of a real symmetric matrix
+/// using the Jacobi method.
+///
+/// Each sweep annihilates all off-diagonal elements in turn. This is
+/// usually much faster than [`syev`] on small matrices, especially in batches.
 ///
 /// # Arguments
 /// * `handle` - rocBLAS handle
-/// * `evect` - Specifies whether to compute eigenvectors (Original, Tridiagonal, None)
+/// * `esort` - Specifies whether to sort the eigenvalues
+/// * `evect` - Specifies whether to compute eigenvectors (Original or None)
 /// * `uplo` - Specifies whether upper or lower triangle of A is stored
 /// * `n` - Order of matrix A
-/// * `A` - Device pointer to n-by-n symmetric matrix (modified; contains eigenvectors if evect=Original)
+/// * `A` - Device pointer to n-by-n symmetric matrix (overwritten with the eigenvectors)
 /// * `lda` - Leading dimension of A
-/// * `D` - Device pointer to eigenvalues (n elements, ascending order)
-/// * `E` - Device pointer to workspace (n-1 elements)
-/// * `info` - Device pointer to info value
+/// * `params` - Tolerance and maximum number of sweeps
+/// * `residual` - Device pointer to the Frobenius norm of the final off-diagonal elements
+/// * `n_sweeps` - Device pointer to the number of sweeps performed
+/// * `W` - Device pointer to real eigenvalues (n elements)
+/// * `info` - Device pointer to info value (0 = success, 1 = did not converge)
+#[inline]
+pub fn syevj<T: SyevjType>(
+    handle: &Handle,
+    esort: Esort,
+    evect: Evect,
+    uplo: Fill,
+    n: i32,
+    A: *mut T,
+    lda: i32,
+    params: JacobiParams<T>,
+    residual: *mut T,
+    n_sweeps: *mut i32,
+    W: *mut T,
+    info: *mut i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::syevj(
+            handle.as_raw(),
+            esort.into(),
+            evect.into(),
+            uplo.into(),
+            n,
+            A,
+            lda,
+            params.abstol,
+            residual,
+            params.max_sweeps,
+            n_sweeps,
+            W,
+            info,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Batched version of syevj.
+#[inline]
+pub fn syevj_batched<T: SyevjType>(
+    handle: &Handle,
+    esort: Esort,
+    evect: Evect,
+    uplo: Fill,
+    n: i32,
+    A: *const *mut T,
+    lda: i32,
+    params: JacobiParams<T>,
+    residual: *mut T,
+    n_sweeps: *mut i32,
+    W: *mut T,
+    stride_w: i64,
+    info: *mut i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::syevj_batched(
+            handle.as_raw(),
+            esort.into(),
+            evect.into(),
+            uplo.into(),
+            n,
+            A,
+            lda,
+            params.abstol,
+            residual,
+            params.max_sweeps,
+            n_sweeps,
+            W,
+            stride_w,
+            info,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Strided batched version of syevj.
+#[inline]
+pub fn syevj_strided_batched<T: SyevjType>(
+    handle: &Handle,
+    esort: Esort,
+    evect: Evect,
+    uplo: Fill,
+    n: i32,
+    A: *mut T,
+    lda: i32,
+    stride_a: i64,
+    params: JacobiParams<T>,
+    residual: *mut T,
+    n_sweeps: *mut i32,
+    W: *mut T,
+    stride_w: i64,
+    info: *mut i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::syevj_strided_batched(
+            handle.as_raw(),
+            esort.into(),
+            evect.into(),
+            uplo.into(),
+            n,
+            A,
+            lda,
+            stride_a,
+            params.abstol,
+            residual,
+            params.max_sweeps,
+            n_sweeps,
+            W,
+            stride_w,
+            info,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Computes eigenvalues and optionally eigenvectors of a complex Hermitian matrix
+/// using the Jacobi method.
 ///
-/// # Returns
-/// `Ok(())` on success, or an error if the operation failed.
+/// Each sweep annihilates all off-diagonal elements in turn. This is
+/// usually much faster than [`heev`] on small matrices, especially in batches.
+///
+/// # Arguments
+/// * `handle` - rocBLAS handle
+/// * `esort` - Specifies whether to sort the eigenvalues
+/// * `evect` - Specifies whether to compute eigenvectors (Original or None)
+/// * `uplo` - Specifies whether upper or lower triangle of A is stored
+/// * `n` - Order of matrix A
+/// * `A` - Device pointer to n-by-n Hermitian matrix (overwritten with the eigenvectors)
+/// * `lda` - Leading dimension of A
+/// * `params` - Tolerance and maximum number of sweeps
+/// * `residual` - Device pointer to the Frobenius norm of the final off-diagonal elements
+/// * `n_sweeps` - Device pointer to the number of sweeps performed
+/// * `W` - Device pointer to real eigenvalues (n elements)
+/// * `info` - Device pointer to info value (0 = success, 1 = did not converge)
 #[inline]
-pub fn syev<T: SyevType>(
+pub fn heevj<T: HeevjType>(
+    handle: &Handle,
+    esort: Esort,
+    evect: Evect,
+    uplo: Fill,
+    n: i32,
+    A: *mut T,
+    lda: i32,
+    params: JacobiParams<T::RealType>,
+    residual: *mut T::RealType,
+    n_sweeps: *mut i32,
+    W: *mut T::RealType,
+    info: *mut i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::heevj(
+            handle.as_raw(),
+            esort.into(),
+            evect.into(),
+            uplo.into(),
+            n,
+            A,
+            lda,
+            params.abstol,
+            residual,
+            params.max_sweeps,
+            n_sweeps,
+            W,
+            info,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Batched version of heevj.
+#[inline]
+pub fn heevj_batched<T: HeevjType>(
+    handle: &Handle,
+    esort: Esort,
+    evect: Evect,
+    uplo: Fill,
+    n: i32,
+    A: *const *mut T,
+    lda: i32,
+    params: JacobiParams<T::RealType>,
+    residual: *mut T::RealType,
+    n_sweeps: *mut i32,
+    W: *mut T::RealType,
+    stride_w: i64,
+    info: *mut i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::heevj_batched(
+            handle.as_raw(),
+            esort.into(),
+            evect.into(),
+            uplo.into(),
+            n,
+            A,
+            lda,
+            params.abstol,
+            residual,
+            params.max_sweeps,
+            n_sweeps,
+            W,
+            stride_w,
+            info,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Strided batched version of heevj.
+#[inline]
+pub fn heevj_strided_batched<T: HeevjType>(
+    handle: &Handle,
+    esort: Esort,
+    evect: Evect,
+    uplo: Fill,
+    n: i32,
+    A: *mut T,
+    lda: i32,
+    stride_a: i64,
+    params: JacobiParams<T::RealType>,
+    residual: *mut T::RealType,
+    n_sweeps: *mut i32,
+    W: *mut T::RealType,
+    stride_w: i64,
+    info: *mut i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::heevj_strided_batched(
+            handle.as_raw(),
+            esort.into(),
+            evect.into(),
+            uplo.into(),
+            n,
+            A,
+            lda,
+            stride_a,
+            params.abstol,
+            residual,
+            params.max_sweeps,
+            n_sweeps,
+            W,
+            stride_w,
+            info,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Solves a generalized symmetric-definite eigenproblem using the divide
+/// and conquer method.
+///
+/// Depending on `itype`, the problem is one of:
+///   A * x = lambda * B * x
+///   A * B * x = lambda * x
+///   B * A * x = lambda * x
+/// where A is symmetric and B is symmetric positive definite. B is reduced with
+/// its Cholesky factorization and the resulting standard problem is solved.
+///
+/// # Arguments
+/// * `handle` - rocBLAS handle
+/// * `itype` - Specifies the form of the problem
+/// * `evect` - Specifies whether to compute eigenvectors (Original or None)
+/// * `uplo` - Specifies whether upper or lower triangles of A and B are stored
+/// * `n` - Order of matrices A and B
+/// * `A` - Device pointer to n-by-n symmetric matrix (overwritten with the eigenvectors)
+/// * `lda` - Leading dimension of A
+/// * `B` - Device pointer to n-by-n symmetric positive definite matrix (overwritten with its Cholesky factor)
+/// * `ldb` - Leading dimension of B
+/// * `D` - Device pointer to real eigenvalues in ascending order (n elements)
+/// * `E` - Device pointer to real workspace (n elements)
+/// * `info` - Device pointer to info value (0 = success, i <= n = did not converge,
+///   i > n = leading minor of order i - n of B is not positive definite)
+#[inline]
+pub fn sygvd<T: SygvdType>(
     handle: &Handle,
+    itype: Eform,
     evect: Evect,
     uplo: Fill,
     n: i32,
     A: *mut T,
     lda: i32,
+    B: *mut T,
+    ldb: i32,
     D: *mut T,
     E: *mut T,
     info: *mut i32,
 ) -> Result<()> {
     let status = unsafe {
-        T::syev(
+        T::sygvd(
             handle.as_raw(),
+            itype.into(),
             evect.into(),
             uplo.into(),
             n,
             A,
             lda,
+            B,
+            ldb,
             D,
             E,
             info,
@@ -510,15 +2146,18 @@ pub fn syev<T: SyevType>(
     Error::from_status(status)
 }
 
-/// Batched version of syev.
+/// Batched version of sygvd.
 #[inline]
-pub fn syev_batched<T: SyevType>(
+pub fn sygvd_batched<T: SygvdType>(
     handle: &Handle,
+    itype: Eform,
     evect: Evect,
     uplo: Fill,
     n: i32,
     A: *const *mut T,
     lda: i32,
+    B: *const *mut T,
+    ldb: i32,
     D: *mut T,
     stride_d: i64,
     E: *mut T,
@@ -527,13 +2166,16 @@ pub fn syev_batched<T: SyevType>(
     batch_count: i32,
 ) -> Result<()> {
     let status = unsafe {
-        T::syev_batched(
+        T::sygvd_batched(
             handle.as_raw(),
+            itype.into(),
             evect.into(),
             uplo.into(),
             n,
             A,
             lda,
+            B,
+            ldb,
             D,
             stride_d,
             E,
@@ -545,16 +2187,20 @@ pub fn syev_batched<T: SyevType>(
     Error::from_status(status)
 }
 
-/// Strided batched version of syev.
+/// Strided batched version of sygvd.
 #[inline]
-pub fn syev_strided_batched<T: SyevType>(
+pub fn sygvd_strided_batched<T: SygvdType>(
     handle: &Handle,
+    itype: Eform,
     evect: Evect,
     uplo: Fill,
     n: i32,
     A: *mut T,
     lda: i32,
     stride_a: i64,
+    B: *mut T,
+    ldb: i32,
+    stride_b: i64,
     D: *mut T,
     stride_d: i64,
     E: *mut T,
@@ -563,14 +2209,18 @@ pub fn syev_strided_batched<T: SyevType>(
     batch_count: i32,
 ) -> Result<()> {
     let status = unsafe {
-        T::syev_strided_batched(
+        T::sygvd_strided_batched(
             handle.as_raw(),
+            itype.into(),
             evect.into(),
             uplo.into(),
             n,
             A,
             lda,
             stride_a,
+            B,
+            ldb,
+            stride_b,
             D,
             stride_d,
             E,
@@ -582,42 +2232,56 @@ pub fn syev_strided_batched<T: SyevType>(
     Error::from_status(status)
 }
 
-/// Computes eigenvalues and optionally eigenvectors of a complex Hermitian matrix.
+/// Solves a generalized Hermitian-definite eigenproblem using the divide
+/// and conquer method.
 ///
-/// The eigenvalue decomposition is:
-///   A = V * D * V^H
-/// where D is real diagonal containing eigenvalues, and V is unitary containing eigenvectors.
+/// Depending on `itype`, the problem is one of:
+///   A * x = lambda * B * x
+///   A * B * x = lambda * x
+///   B * A * x = lambda * x
+/// where A is Hermitian and B is Hermitian positive definite. B is reduced with
+/// its Cholesky factorization and the resulting standard problem is solved.
 ///
 /// # Arguments
 /// * `handle` - rocBLAS handle
-/// * `evect` - Specifies whether to compute eigenvectors
-/// * `uplo` - Specifies whether upper or lower triangle of A is stored
-/// * `n` - Order of matrix A
-/// * `A` - Device pointer to n-by-n Hermitian matrix
+/// * `itype` - Specifies the form of the problem
+/// * `evect` - Specifies whether to compute eigenvectors (Original or None)
+/// * `uplo` - Specifies whether upper or lower triangles of A and B are stored
+/// * `n` - Order of matrices A and B
+/// * `A` - Device pointer to n-by-n Hermitian matrix (overwritten with the eigenvectors)
 /// * `lda` - Leading dimension of A
-/// * `D` - Device pointer to real eigenvalues (n elements)
-/// * `E` - Device pointer to real workspace (n-1 elements)
-/// * `info` - Device pointer to info value
+/// * `B` - Device pointer to n-by-n Hermitian positive definite matrix (overwritten with its Cholesky factor)
+/// * `ldb` - Leading dimension of B
+/// * `D` - Device pointer to real eigenvalues in ascending order (n elements)
+/// * `E` - Device pointer to real workspace (n elements)
+/// * `info` - Device pointer to info value (0 = success, i <= n = did not converge,
+///   i > n = leading minor of order i - n of B is not positive definite)
 #[inline]
-pub fn heev<T: HeevType>(
+pub fn hegvd<T: HegvdType>(
     handle: &Handle,
+    itype: Eform,
     evect: Evect,
     uplo: Fill,
     n: i32,
     A: *mut T,
     lda: i32,
+    B: *mut T,
+    ldb: i32,
     D: *mut T::RealType,
     E: *mut T::RealType,
     info: *mut i32,
 ) -> Result<()> {
     let status = unsafe {
-        T::heev(
+        T::hegvd(
             handle.as_raw(),
+            itype.into(),
             evect.into(),
             uplo.into(),
             n,
             A,
             lda,
+            B,
+            ldb,
             D,
             E,
             info,
@@ -626,15 +2290,18 @@ pub fn heev<T: HeevType>(
     Error::from_status(status)
 }
 
-/// Batched version of heev.
+/// Batched version of hegvd.
 #[inline]
-pub fn heev_batched<T: HeevType>(
+pub fn hegvd_batched<T: HegvdType>(
     handle: &Handle,
+    itype: Eform,
     evect: Evect,
     uplo: Fill,
     n: i32,
     A: *const *mut T,
     lda: i32,
+    B: *const *mut T,
+    ldb: i32,
     D: *mut T::RealType,
     stride_d: i64,
     E: *mut T::RealType,
@@ -643,13 +2310,16 @@ pub fn heev_batched<T: HeevType>(
     batch_count: i32,
 ) -> Result<()> {
     let status = unsafe {
-        T::heev_batched(
+        T::hegvd_batched(
             handle.as_raw(),
+            itype.into(),
             evect.into(),
             uplo.into(),
             n,
             A,
             lda,
+            B,
+            ldb,
             D,
             stride_d,
             E,
@@ -661,16 +2331,20 @@ pub fn heev_batched<T: HeevType>(
     Error::from_status(status)
 }
 
-/// Strided batched version of heev.
+/// Strided batched version of hegvd.
 #[inline]
-pub fn heev_strided_batched<T: HeevType>(
+pub fn hegvd_strided_batched<T: HegvdType>(
     handle: &Handle,
+    itype: Eform,
     evect: Evect,
     uplo: Fill,
     n: i32,
     A: *mut T,
     lda: i32,
     stride_a: i64,
+    B: *mut T,
+    ldb: i32,
+    stride_b: i64,
     D: *mut T::RealType,
     stride_d: i64,
     E: *mut T::RealType,
@@ -679,14 +2353,18 @@ pub fn heev_strided_batched<T: HeevType>(
     batch_count: i32,
 ) -> Result<()> {
     let status = unsafe {
-        T::heev_strided_batched(
+        T::hegvd_strided_batched(
             handle.as_raw(),
+            itype.into(),
             evect.into(),
             uplo.into(),
             n,
             A,
             lda,
             stride_a,
+            B,
+            ldb,
+            stride_b,
             D,
             stride_d,
             E,
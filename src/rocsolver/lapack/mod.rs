@@ -4,8 +4,9 @@
 //! This module provides safe wrappers for rocSOLVER's LAPACK functions, organized
 //! by mathematical category:
 //!
-//! - [`decompositions`] - Matrix factorizations (QR, LU, Cholesky, Bidiagonal)
-//! - [`solvers`] - Linear system solvers
+//! - [`decompositions`] - Matrix factorizations (QR, LU, Cholesky, Bidiagonal,
+//!   symmetric indefinite) and triangular inversion
+//! - [`solvers`] - Linear system solvers and matrix inversion
 //! - [`svd`] - Singular Value Decomposition
 //! - [`eigenvalue`] - Eigenvalue computations
 //! - [`orthogonal`] - Orthogonal/Unitary matrix operations
@@ -18,20 +19,26 @@ pub mod svd;
 
 // Re-export commonly used functions at the lapack module level
 pub use decompositions::{
-    gebrd, gebrd_batched, gebrd_strided_batched, geqrf, geqrf_batched, geqrf_strided_batched,
-    getrf, getrf_batched, getrf_npvt, getrf_npvt_batched, getrf_npvt_strided_batched,
-    getrf_strided_batched, potrf, potrf_batched, potrf_strided_batched,
+    PivotBlock, gebrd, gebrd_batched, gebrd_strided_batched, geqrf, geqrf_batched,
+    geqrf_strided_batched, getrf, getrf_batched, getrf_npvt, getrf_npvt_batched,
+    getrf_npvt_strided_batched, getrf_strided_batched, potrf, potrf_batched, potrf_strided_batched,
+    sytrf, sytrf_batched, sytrf_pivots, sytrf_strided_batched, trtri, trtri_batched,
+    trtri_strided_batched,
 };
 
 pub use solvers::{
-    gels, gels_batched, gels_strided_batched, gesv, gesv_batched, gesv_strided_batched, getrs,
-    getrs_batched, getrs_strided_batched, posv, posv_batched, posv_strided_batched,
+    gels, gels_batched, gels_strided_batched, gesv, gesv_batched, gesv_strided_batched, getri,
+    getri_batched, getri_strided_batched, getrs, getrs_batched, getrs_strided_batched, inverse,
+    posv, posv_batched, posv_strided_batched, potri, potri_batched, potri_strided_batched, potrs,
+    potrs_batched, potrs_strided_batched,
 };
 
-pub use svd::gesvd;
+pub use svd::{gesvd, gesvdj, gesvdj_batched, gesvdj_strided_batched};
 
 pub use eigenvalue::{
-    heev, heev_batched, heev_strided_batched, syev, syev_batched, syev_strided_batched,
+    heev, heev_batched, heev_strided_batched, heevj, heevj_batched, heevj_strided_batched, hegvd,
+    hegvd_batched, hegvd_strided_batched, syev, syev_batched, syev_strided_batched, syevj,
+    syevj_batched, syevj_strided_batched, sygvd, sygvd_batched, sygvd_strided_batched,
 };
 
 pub use orthogonal::{orgqr, ormqr, ungqr, unmqr};
@@ -5,13 +5,20 @@
 //!
 //! - **General solver**: [`gesv`] - Solves A*X = B using LU factorization
 //! - **Triangular solver**: [`getrs`] - Solves using pre-computed LU factors
-//! - **Positive definite solver**: [`posv`] - Solves A*X = B using Cholesky
+//! - **Positive definite solver**: [`posv`] - Solves A*X = B using Cholesky, and
+//!   [`potrs`] which solves with pre-computed Cholesky factors
 //! - **Least squares solver**: [`gels`] - Solves overdetermined/underdetermined systems
+//! - **Matrix inversion**: [`getri`] - Inverts using pre-computed LU factors, and
+//!   [`inverse`] which factorizes and inverts in one call; [`potri`] inverts
+//!   using pre-computed Cholesky factors
 
+use crate::error::{custom_error, invalid_argument};
+use crate::hip::DeviceMemory;
 use crate::rocblas::Handle;
 use crate::rocblas::ffi as rocblas_ffi;
 use crate::rocsolver::bindings;
-use crate::rocsolver::error::{Error, Result};
+use crate::rocsolver::error::{Error, Result, read_info};
+use crate::rocsolver::lapack::decompositions::{GetrfType, getrf};
 use crate::rocsolver::types::{Complex32, Complex64, Fill, Operation};
 
 // Type alias for handle - we use rocblas handle but need to cast for rocsolver bindings
@@ -219,6 +226,123 @@ pub trait GelsType: Sized + Copy {
     ) -> RocblasStatus;
 }
 
+/// Trait for types that support matrix inversion from LU factors (getri).
+pub trait GetriType: Sized + Copy {
+    /// Invert a matrix from its LU factorization.
+    unsafe fn getri(
+        handle: RocblasHandle,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        info: *mut i32,
+    ) -> RocblasStatus;
+
+    /// Batched getri.
+    unsafe fn getri_batched(
+        handle: RocblasHandle,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+
+    /// Strided batched getri.
+    unsafe fn getri_strided_batched(
+        handle: RocblasHandle,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+}
+
+/// Trait for types that support Cholesky solve (potrs).
+pub trait PotrsType: Sized + Copy {
+    /// Solve a system using a pre-computed Cholesky factorization.
+    unsafe fn potrs(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        nrhs: i32,
+        A: *mut Self,
+        lda: i32,
+        B: *mut Self,
+        ldb: i32,
+    ) -> RocblasStatus;
+
+    /// Batched potrs.
+    unsafe fn potrs_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        nrhs: i32,
+        A: *const *mut Self,
+        lda: i32,
+        B: *const *mut Self,
+        ldb: i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+
+    /// Strided batched potrs.
+    unsafe fn potrs_strided_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        nrhs: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        B: *mut Self,
+        ldb: i32,
+        stride_b: i64,
+        batch_count: i32,
+    ) -> RocblasStatus;
+}
+
+/// Trait for types that support Cholesky inversion (potri).
+pub trait PotriType: Sized + Copy {
+    /// Compute the inverse from a Cholesky factorization.
+    unsafe fn potri(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        info: *mut i32,
+    ) -> RocblasStatus;
+
+    /// Batched potri.
+    unsafe fn potri_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+
+    /// Strided batched potri.
+    unsafe fn potri_strided_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+}
+
 // ============================================================================
 // Trait implementations for f32
 // ============================================================================
@@ -524,6 +648,179 @@ impl GelsType for f32 {
     }
 }
 
+impl GetriType for f32 {
+    unsafe fn getri(
+        handle: RocblasHandle,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_sgetri(cast_handle(handle), n, A, lda, ipiv, info)
+    }
+
+    unsafe fn getri_batched(
+        handle: RocblasHandle,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_sgetri_batched(
+            cast_handle(handle),
+            n,
+            A,
+            lda,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn getri_strided_batched(
+        handle: RocblasHandle,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_sgetri_strided_batched(
+            cast_handle(handle),
+            n,
+            A,
+            lda,
+            stride_a,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    }
+}
+
+impl PotrsType for f32 {
+    unsafe fn potrs(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        nrhs: i32,
+        A: *mut Self,
+        lda: i32,
+        B: *mut Self,
+        ldb: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_spotrs(cast_handle(handle), uplo, n, nrhs, A, lda, B, ldb)
+    }
+
+    unsafe fn potrs_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        nrhs: i32,
+        A: *const *mut Self,
+        lda: i32,
+        B: *const *mut Self,
+        ldb: i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_spotrs_batched(
+            cast_handle(handle),
+            uplo,
+            n,
+            nrhs,
+            A,
+            lda,
+            B,
+            ldb,
+            batch_count,
+        )
+    }
+
+    unsafe fn potrs_strided_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        nrhs: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        B: *mut Self,
+        ldb: i32,
+        stride_b: i64,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_spotrs_strided_batched(
+            cast_handle(handle),
+            uplo,
+            n,
+            nrhs,
+            A,
+            lda,
+            stride_a,
+            B,
+            ldb,
+            stride_b,
+            batch_count,
+        )
+    }
+}
+
+impl PotriType for f32 {
+    unsafe fn potri(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_spotri(cast_handle(handle), uplo, n, A, lda, info)
+    }
+
+    unsafe fn potri_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_spotri_batched(cast_handle(handle), uplo, n, A, lda, info, batch_count)
+    }
+
+    unsafe fn potri_strided_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_spotri_strided_batched(
+            cast_handle(handle),
+            uplo,
+            n,
+            A,
+            lda,
+            stride_a,
+            info,
+            batch_count,
+        )
+    }
+}
+
 // ============================================================================
 // Trait implementations for f64
 // ============================================================================
@@ -829,26 +1126,199 @@ impl GelsType for f64 {
     }
 }
 
-// ============================================================================
-// Trait implementations for Complex32
-// ============================================================================
-
-impl GesvType for Complex32 {
-    unsafe fn gesv(
+impl GetriType for f64 {
+    unsafe fn getri(
         handle: RocblasHandle,
         n: i32,
-        nrhs: i32,
         A: *mut Self,
         lda: i32,
         ipiv: *mut i32,
-        B: *mut Self,
-        ldb: i32,
         info: *mut i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_cgesv(cast_handle(handle), n, nrhs, A, lda, ipiv, B, ldb, info)
+        bindings::rocsolver_dgetri(cast_handle(handle), n, A, lda, ipiv, info)
     }
 
-    unsafe fn gesv_batched(
+    unsafe fn getri_batched(
+        handle: RocblasHandle,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_dgetri_batched(
+            cast_handle(handle),
+            n,
+            A,
+            lda,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn getri_strided_batched(
+        handle: RocblasHandle,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_dgetri_strided_batched(
+            cast_handle(handle),
+            n,
+            A,
+            lda,
+            stride_a,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    }
+}
+
+impl PotrsType for f64 {
+    unsafe fn potrs(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        nrhs: i32,
+        A: *mut Self,
+        lda: i32,
+        B: *mut Self,
+        ldb: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_dpotrs(cast_handle(handle), uplo, n, nrhs, A, lda, B, ldb)
+    }
+
+    unsafe fn potrs_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        nrhs: i32,
+        A: *const *mut Self,
+        lda: i32,
+        B: *const *mut Self,
+        ldb: i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_dpotrs_batched(
+            cast_handle(handle),
+            uplo,
+            n,
+            nrhs,
+            A,
+            lda,
+            B,
+            ldb,
+            batch_count,
+        )
+    }
+
+    unsafe fn potrs_strided_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        nrhs: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        B: *mut Self,
+        ldb: i32,
+        stride_b: i64,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_dpotrs_strided_batched(
+            cast_handle(handle),
+            uplo,
+            n,
+            nrhs,
+            A,
+            lda,
+            stride_a,
+            B,
+            ldb,
+            stride_b,
+            batch_count,
+        )
+    }
+}
+
+impl PotriType for f64 {
+    unsafe fn potri(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_dpotri(cast_handle(handle), uplo, n, A, lda, info)
+    }
+
+    unsafe fn potri_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_dpotri_batched(cast_handle(handle), uplo, n, A, lda, info, batch_count)
+    }
+
+    unsafe fn potri_strided_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_dpotri_strided_batched(
+            cast_handle(handle),
+            uplo,
+            n,
+            A,
+            lda,
+            stride_a,
+            info,
+            batch_count,
+        )
+    }
+}
+
+// ============================================================================
+// Trait implementations for Complex32
+// ============================================================================
+
+impl GesvType for Complex32 {
+    unsafe fn gesv(
+        handle: RocblasHandle,
+        n: i32,
+        nrhs: i32,
+        A: *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        B: *mut Self,
+        ldb: i32,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cgesv(cast_handle(handle), n, nrhs, A, lda, ipiv, B, ldb, info)
+    }
+
+    unsafe fn gesv_batched(
         handle: RocblasHandle,
         n: i32,
         nrhs: i32,
@@ -1134,6 +1604,179 @@ impl GelsType for Complex32 {
     }
 }
 
+impl GetriType for Complex32 {
+    unsafe fn getri(
+        handle: RocblasHandle,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cgetri(cast_handle(handle), n, A, lda, ipiv, info)
+    }
+
+    unsafe fn getri_batched(
+        handle: RocblasHandle,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cgetri_batched(
+            cast_handle(handle),
+            n,
+            A,
+            lda,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn getri_strided_batched(
+        handle: RocblasHandle,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cgetri_strided_batched(
+            cast_handle(handle),
+            n,
+            A,
+            lda,
+            stride_a,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    }
+}
+
+impl PotrsType for Complex32 {
+    unsafe fn potrs(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        nrhs: i32,
+        A: *mut Self,
+        lda: i32,
+        B: *mut Self,
+        ldb: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cpotrs(cast_handle(handle), uplo, n, nrhs, A, lda, B, ldb)
+    }
+
+    unsafe fn potrs_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        nrhs: i32,
+        A: *const *mut Self,
+        lda: i32,
+        B: *const *mut Self,
+        ldb: i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cpotrs_batched(
+            cast_handle(handle),
+            uplo,
+            n,
+            nrhs,
+            A,
+            lda,
+            B,
+            ldb,
+            batch_count,
+        )
+    }
+
+    unsafe fn potrs_strided_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        nrhs: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        B: *mut Self,
+        ldb: i32,
+        stride_b: i64,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cpotrs_strided_batched(
+            cast_handle(handle),
+            uplo,
+            n,
+            nrhs,
+            A,
+            lda,
+            stride_a,
+            B,
+            ldb,
+            stride_b,
+            batch_count,
+        )
+    }
+}
+
+impl PotriType for Complex32 {
+    unsafe fn potri(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cpotri(cast_handle(handle), uplo, n, A, lda, info)
+    }
+
+    unsafe fn potri_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cpotri_batched(cast_handle(handle), uplo, n, A, lda, info, batch_count)
+    }
+
+    unsafe fn potri_strided_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cpotri_strided_batched(
+            cast_handle(handle),
+            uplo,
+            n,
+            A,
+            lda,
+            stride_a,
+            info,
+            batch_count,
+        )
+    }
+}
+
 // ============================================================================
 // Trait implementations for Complex64
 // ============================================================================
@@ -1368,71 +2011,244 @@ impl GelsType for Complex64 {
         trans: rocblas_ffi::rocblas_operation,
         m: i32,
         n: i32,
-        nrhs: i32,
+        nrhs: i32,
+        A: *mut Self,
+        lda: i32,
+        B: *mut Self,
+        ldb: i32,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zgels(cast_handle(handle), trans, m, n, nrhs, A, lda, B, ldb, info)
+    }
+
+    unsafe fn gels_batched(
+        handle: RocblasHandle,
+        trans: rocblas_ffi::rocblas_operation,
+        m: i32,
+        n: i32,
+        nrhs: i32,
+        A: *const *mut Self,
+        lda: i32,
+        B: *const *mut Self,
+        ldb: i32,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zgels_batched(
+            cast_handle(handle),
+            trans,
+            m,
+            n,
+            nrhs,
+            A,
+            lda,
+            B,
+            ldb,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn gels_strided_batched(
+        handle: RocblasHandle,
+        trans: rocblas_ffi::rocblas_operation,
+        m: i32,
+        n: i32,
+        nrhs: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        B: *mut Self,
+        ldb: i32,
+        stride_b: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zgels_strided_batched(
+            cast_handle(handle),
+            trans,
+            m,
+            n,
+            nrhs,
+            A,
+            lda,
+            stride_a,
+            B,
+            ldb,
+            stride_b,
+            info,
+            batch_count,
+        )
+    }
+}
+
+impl GetriType for Complex64 {
+    unsafe fn getri(
+        handle: RocblasHandle,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zgetri(cast_handle(handle), n, A, lda, ipiv, info)
+    }
+
+    unsafe fn getri_batched(
+        handle: RocblasHandle,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zgetri_batched(
+            cast_handle(handle),
+            n,
+            A,
+            lda,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn getri_strided_batched(
+        handle: RocblasHandle,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        ipiv: *mut i32,
+        stride_p: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zgetri_strided_batched(
+            cast_handle(handle),
+            n,
+            A,
+            lda,
+            stride_a,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    }
+}
+
+impl PotrsType for Complex64 {
+    unsafe fn potrs(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        nrhs: i32,
+        A: *mut Self,
+        lda: i32,
+        B: *mut Self,
+        ldb: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zpotrs(cast_handle(handle), uplo, n, nrhs, A, lda, B, ldb)
+    }
+
+    unsafe fn potrs_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        nrhs: i32,
+        A: *const *mut Self,
+        lda: i32,
+        B: *const *mut Self,
+        ldb: i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zpotrs_batched(
+            cast_handle(handle),
+            uplo,
+            n,
+            nrhs,
+            A,
+            lda,
+            B,
+            ldb,
+            batch_count,
+        )
+    }
+
+    unsafe fn potrs_strided_batched(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
+        nrhs: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        B: *mut Self,
+        ldb: i32,
+        stride_b: i64,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zpotrs_strided_batched(
+            cast_handle(handle),
+            uplo,
+            n,
+            nrhs,
+            A,
+            lda,
+            stride_a,
+            B,
+            ldb,
+            stride_b,
+            batch_count,
+        )
+    }
+}
+
+impl PotriType for Complex64 {
+    unsafe fn potri(
+        handle: RocblasHandle,
+        uplo: rocblas_ffi::rocblas_fill,
+        n: i32,
         A: *mut Self,
         lda: i32,
-        B: *mut Self,
-        ldb: i32,
         info: *mut i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_zgels(cast_handle(handle), trans, m, n, nrhs, A, lda, B, ldb, info)
+        bindings::rocsolver_zpotri(cast_handle(handle), uplo, n, A, lda, info)
     }
 
-    unsafe fn gels_batched(
+    unsafe fn potri_batched(
         handle: RocblasHandle,
-        trans: rocblas_ffi::rocblas_operation,
-        m: i32,
+        uplo: rocblas_ffi::rocblas_fill,
         n: i32,
-        nrhs: i32,
         A: *const *mut Self,
         lda: i32,
-        B: *const *mut Self,
-        ldb: i32,
         info: *mut i32,
         batch_count: i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_zgels_batched(
-            cast_handle(handle),
-            trans,
-            m,
-            n,
-            nrhs,
-            A,
-            lda,
-            B,
-            ldb,
-            info,
-            batch_count,
-        )
+        bindings::rocsolver_zpotri_batched(cast_handle(handle), uplo, n, A, lda, info, batch_count)
     }
 
-    unsafe fn gels_strided_batched(
+    unsafe fn potri_strided_batched(
         handle: RocblasHandle,
-        trans: rocblas_ffi::rocblas_operation,
-        m: i32,
+        uplo: rocblas_ffi::rocblas_fill,
         n: i32,
-        nrhs: i32,
         A: *mut Self,
         lda: i32,
         stride_a: i64,
-        B: *mut Self,
-        ldb: i32,
-        stride_b: i64,
         info: *mut i32,
         batch_count: i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_zgels_strided_batched(
+        bindings::rocsolver_zpotri_strided_batched(
             cast_handle(handle),
-            trans,
-            m,
+            uplo,
             n,
-            nrhs,
             A,
             lda,
             stride_a,
-            B,
-            ldb,
-            stride_b,
             info,
             batch_count,
         )
@@ -1749,6 +2565,95 @@ pub fn posv_strided_batched<T: PosvType>(
     Error::from_status(status)
 }
 
+/// Solves a system of linear equations A*X = B using the Cholesky
+/// factorization computed by potrf.
+///
+/// # Arguments
+/// * `handle` - rocBLAS handle
+/// * `uplo` - Specifies whether the factor is upper (U^H*U) or lower (L*L^H) triangular
+/// * `n` - Order of matrix A
+/// * `nrhs` - Number of right-hand sides
+/// * `A` - Device pointer to the Cholesky factor (from potrf)
+/// * `lda` - Leading dimension of A
+/// * `B` - Device pointer to right-hand side matrix (overwritten with solution)
+/// * `ldb` - Leading dimension of B
+#[inline]
+pub fn potrs<T: PotrsType>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    nrhs: i32,
+    A: *mut T,
+    lda: i32,
+    B: *mut T,
+    ldb: i32,
+) -> Result<()> {
+    let status = unsafe { T::potrs(handle.as_raw(), uplo.into(), n, nrhs, A, lda, B, ldb) };
+    Error::from_status(status)
+}
+
+/// Batched version of potrs.
+#[inline]
+pub fn potrs_batched<T: PotrsType>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    nrhs: i32,
+    A: *const *mut T,
+    lda: i32,
+    B: *const *mut T,
+    ldb: i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::potrs_batched(
+            handle.as_raw(),
+            uplo.into(),
+            n,
+            nrhs,
+            A,
+            lda,
+            B,
+            ldb,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Strided batched version of potrs.
+#[inline]
+pub fn potrs_strided_batched<T: PotrsType>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    nrhs: i32,
+    A: *mut T,
+    lda: i32,
+    stride_a: i64,
+    B: *mut T,
+    ldb: i32,
+    stride_b: i64,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::potrs_strided_batched(
+            handle.as_raw(),
+            uplo.into(),
+            n,
+            nrhs,
+            A,
+            lda,
+            stride_a,
+            B,
+            ldb,
+            stride_b,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
+
 /// Solves overdetermined or underdetermined linear systems using QR/LQ.
 ///
 /// - If m >= n: solves the least squares problem min ||B - A*X||
@@ -1864,3 +2769,205 @@ pub fn gels_strided_batched<T: GelsType>(
     };
     Error::from_status(status)
 }
+
+/// Computes the inverse of a general n-by-n matrix from its LU factorization.
+///
+/// A must have been factorized by getrf; on exit it is overwritten with its
+/// inverse.
+///
+/// # Arguments
+/// * `handle` - rocBLAS handle
+/// * `n` - Order of matrix A
+/// * `A` - Device pointer to LU factors (from getrf), replaced by inv(A)
+/// * `lda` - Leading dimension of A
+/// * `ipiv` - Device pointer to pivot indices (from getrf)
+/// * `info` - Device pointer to info value (0 = success, i > 0 = U(i,i) is zero, A is singular)
+#[inline]
+pub fn getri<T: GetriType>(
+    handle: &Handle,
+    n: i32,
+    A: *mut T,
+    lda: i32,
+    ipiv: *mut i32,
+    info: *mut i32,
+) -> Result<()> {
+    let status = unsafe { T::getri(handle.as_raw(), n, A, lda, ipiv, info) };
+    Error::from_status(status)
+}
+
+/// Batched version of getri.
+#[inline]
+pub fn getri_batched<T: GetriType>(
+    handle: &Handle,
+    n: i32,
+    A: *const *mut T,
+    lda: i32,
+    ipiv: *mut i32,
+    stride_p: i64,
+    info: *mut i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::getri_batched(
+            handle.as_raw(),
+            n,
+            A,
+            lda,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Strided batched version of getri.
+#[inline]
+pub fn getri_strided_batched<T: GetriType>(
+    handle: &Handle,
+    n: i32,
+    A: *mut T,
+    lda: i32,
+    stride_a: i64,
+    ipiv: *mut i32,
+    stride_p: i64,
+    info: *mut i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::getri_strided_batched(
+            handle.as_raw(),
+            n,
+            A,
+            lda,
+            stride_a,
+            ipiv,
+            stride_p,
+            info,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Computes the inverse of a symmetric/Hermitian positive definite matrix
+/// from its Cholesky factorization.
+///
+/// A must have been factorized by potrf; on exit the `uplo` triangle is
+/// overwritten with the corresponding triangle of the inverse.
+///
+/// # Arguments
+/// * `handle` - rocBLAS handle
+/// * `uplo` - Specifies whether the factor is upper (U^H*U) or lower (L*L^H) triangular
+/// * `n` - Order of matrix A
+/// * `A` - Device pointer to the Cholesky factor (from potrf), replaced by inv(A)
+/// * `lda` - Leading dimension of A
+/// * `info` - Device pointer to info value (0 = success, i > 0 = the factor is singular)
+#[inline]
+pub fn potri<T: PotriType>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    A: *mut T,
+    lda: i32,
+    info: *mut i32,
+) -> Result<()> {
+    let status = unsafe { T::potri(handle.as_raw(), uplo.into(), n, A, lda, info) };
+    Error::from_status(status)
+}
+
+/// Batched version of potri.
+#[inline]
+pub fn potri_batched<T: PotriType>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    A: *const *mut T,
+    lda: i32,
+    info: *mut i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status =
+        unsafe { T::potri_batched(handle.as_raw(), uplo.into(), n, A, lda, info, batch_count) };
+    Error::from_status(status)
+}
+
+/// Strided batched version of potri.
+#[inline]
+pub fn potri_strided_batched<T: PotriType>(
+    handle: &Handle,
+    uplo: Fill,
+    n: i32,
+    A: *mut T,
+    lda: i32,
+    stride_a: i64,
+    info: *mut i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::potri_strided_batched(
+            handle.as_raw(),
+            uplo.into(),
+            n,
+            A,
+            lda,
+            stride_a,
+            info,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Inverts a general n-by-n matrix in place.
+///
+/// Runs getrf followed by getri on a densely packed column-major matrix
+/// (`lda = n`), allocating the pivots and info value internally. Waits for
+/// the result, and fails with a custom error if A is singular.
+///
+/// # Example
+/// ```rust,no_run
+/// use rocm_rs::{hip::DeviceMemory, rocblas::Handle, rocsolver};
+///
+/// let handle = Handle::new().unwrap();
+/// let mut a = DeviceMemory::<f64>::new(9).unwrap();
+/// a.copy_from_host(&[4.0, 2.0, 0.0, 2.0, 5.0, 1.0, 0.0, 1.0, 3.0]).unwrap();
+///
+/// rocsolver::inverse(&handle, &mut a, 3).unwrap();
+/// ```
+pub fn inverse<T: GetrfType + GetriType>(
+    handle: &Handle,
+    a: &mut DeviceMemory<T>,
+    n: i32,
+) -> crate::error::Result<()> {
+    if n < 0 {
+        return Err(invalid_argument("matrix order must not be negative"));
+    }
+    if a.count() < n as usize * n as usize {
+        return Err(invalid_argument("buffer too small for an n-by-n matrix"));
+    }
+    if n == 0 {
+        return Ok(());
+    }
+
+    let ipiv = DeviceMemory::<i32>::new(n as usize)?;
+    let info = DeviceMemory::<i32>::new(1)?;
+    let a_ptr = a.as_ptr().cast::<T>();
+    let (ipiv_ptr, info_ptr) = (ipiv.as_ptr().cast(), info.as_ptr().cast());
+
+    let singular = |i: i32| custom_error(format!("matrix is singular: U({i},{i}) is exactly zero"));
+
+    getrf(handle, n, n, a_ptr, n, ipiv_ptr, info_ptr)?;
+    let i = read_info(handle, &info)?;
+    if i != 0 {
+        return Err(singular(i));
+    }
+
+    getri(handle, n, a_ptr, n, ipiv_ptr, info_ptr)?;
+    let i = read_info(handle, &info)?;
+    if i != 0 {
+        return Err(singular(i));
+    }
+    Ok(())
+}
@@ -4,14 +4,15 @@
 //! This module provides safe wrappers for SVD computations:
 //!
 //! - [`gesvd`] - Full SVD computation
+//! - [`gesvdj`] - SVD with the Jacobi method, including batched variants
 //!
-//! Note: Batched variants are not yet implemented due to complex stride requirements.
+//! Note: Batched variants of gesvd are not yet implemented due to complex stride requirements.
 
 use crate::rocblas::Handle;
 use crate::rocblas::ffi as rocblas_ffi;
 use crate::rocsolver::bindings;
 use crate::rocsolver::error::{Error, Result};
-use crate::rocsolver::types::{Complex32, Complex64, Svect, Workmode};
+use crate::rocsolver::types::{Complex32, Complex64, JacobiParams, Svect, Workmode};
 
 // Type alias for handle - we use rocblas handle but need to cast for rocsolver bindings
 type RocblasHandle = rocblas_ffi::rocblas_handle;
@@ -52,14 +53,504 @@ pub trait GesvdType: Sized + Copy {
     ) -> RocblasStatus;
 }
 
+/// Trait for types that support Jacobi SVD (gesvdj).
+pub trait GesvdjType: Sized + Copy {
+    /// The real type for singular values.
+    type RealType: Copy;
+
+    /// Compute the SVD of a general matrix with the Jacobi method.
+    unsafe fn gesvdj(
+        handle: RocblasHandle,
+        left_svect: bindings::rocblas_svect,
+        right_svect: bindings::rocblas_svect,
+        m: i32,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        S: *mut Self::RealType,
+        U: *mut Self,
+        ldu: i32,
+        V: *mut Self,
+        ldv: i32,
+        info: *mut i32,
+    ) -> RocblasStatus;
+
+    /// Batched gesvdj.
+    unsafe fn gesvdj_batched(
+        handle: RocblasHandle,
+        left_svect: bindings::rocblas_svect,
+        right_svect: bindings::rocblas_svect,
+        m: i32,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        S: *mut Self::RealType,
+        stride_s: i64,
+        U: *mut Self,
+        ldu: i32,
+        stride_u: i64,
+        V: *mut Self,
+        ldv: i32,
+        stride_v: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+
+    /// Strided batched gesvdj.
+    unsafe fn gesvdj_strided_batched(
+        handle: RocblasHandle,
+        left_svect: bindings::rocblas_svect,
+        right_svect: bindings::rocblas_svect,
+        m: i32,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        S: *mut Self::RealType,
+        stride_s: i64,
+        U: *mut Self,
+        ldu: i32,
+        stride_u: i64,
+        V: *mut Self,
+        ldv: i32,
+        stride_v: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus;
+}
+
 // ============================================================================
 // Trait implementations for f32
 // ============================================================================
 
-impl GesvdType for f32 {
+impl GesvdType for f32 {
+    type RealType = f32;
+
+    unsafe fn gesvd(
+        handle: RocblasHandle,
+        left_svect: bindings::rocblas_svect,
+        right_svect: bindings::rocblas_svect,
+        m: i32,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        S: *mut Self::RealType,
+        U: *mut Self,
+        ldu: i32,
+        V: *mut Self,
+        ldv: i32,
+        E: *mut Self::RealType,
+        fast_alg: bindings::rocblas_workmode,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_sgesvd(
+            cast_handle(handle),
+            left_svect,
+            right_svect,
+            m,
+            n,
+            A,
+            lda,
+            S,
+            U,
+            ldu,
+            V,
+            ldv,
+            E,
+            fast_alg,
+            info,
+        )
+    }
+}
+
+impl GesvdjType for f32 {
+    type RealType = f32;
+
+    unsafe fn gesvdj(
+        handle: RocblasHandle,
+        left_svect: bindings::rocblas_svect,
+        right_svect: bindings::rocblas_svect,
+        m: i32,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        S: *mut Self::RealType,
+        U: *mut Self,
+        ldu: i32,
+        V: *mut Self,
+        ldv: i32,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_sgesvdj(
+            cast_handle(handle),
+            left_svect,
+            right_svect,
+            m,
+            n,
+            A,
+            lda,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            S,
+            U,
+            ldu,
+            V,
+            ldv,
+            info,
+        )
+    }
+
+    unsafe fn gesvdj_batched(
+        handle: RocblasHandle,
+        left_svect: bindings::rocblas_svect,
+        right_svect: bindings::rocblas_svect,
+        m: i32,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        S: *mut Self::RealType,
+        stride_s: i64,
+        U: *mut Self,
+        ldu: i32,
+        stride_u: i64,
+        V: *mut Self,
+        ldv: i32,
+        stride_v: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_sgesvdj_batched(
+            cast_handle(handle),
+            left_svect,
+            right_svect,
+            m,
+            n,
+            A,
+            lda,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            S,
+            stride_s,
+            U,
+            ldu,
+            stride_u,
+            V,
+            ldv,
+            stride_v,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn gesvdj_strided_batched(
+        handle: RocblasHandle,
+        left_svect: bindings::rocblas_svect,
+        right_svect: bindings::rocblas_svect,
+        m: i32,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        S: *mut Self::RealType,
+        stride_s: i64,
+        U: *mut Self,
+        ldu: i32,
+        stride_u: i64,
+        V: *mut Self,
+        ldv: i32,
+        stride_v: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_sgesvdj_strided_batched(
+            cast_handle(handle),
+            left_svect,
+            right_svect,
+            m,
+            n,
+            A,
+            lda,
+            stride_a,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            S,
+            stride_s,
+            U,
+            ldu,
+            stride_u,
+            V,
+            ldv,
+            stride_v,
+            info,
+            batch_count,
+        )
+    }
+}
+
+// ============================================================================
+// Trait implementations for f64
+// ============================================================================
+
+impl GesvdType for f64 {
+    type RealType = f64;
+
+    unsafe fn gesvd(
+        handle: RocblasHandle,
+        left_svect: bindings::rocblas_svect,
+        right_svect: bindings::rocblas_svect,
+        m: i32,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        S: *mut Self::RealType,
+        U: *mut Self,
+        ldu: i32,
+        V: *mut Self,
+        ldv: i32,
+        E: *mut Self::RealType,
+        fast_alg: bindings::rocblas_workmode,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_dgesvd(
+            cast_handle(handle),
+            left_svect,
+            right_svect,
+            m,
+            n,
+            A,
+            lda,
+            S,
+            U,
+            ldu,
+            V,
+            ldv,
+            E,
+            fast_alg,
+            info,
+        )
+    }
+}
+
+impl GesvdjType for f64 {
+    type RealType = f64;
+
+    unsafe fn gesvdj(
+        handle: RocblasHandle,
+        left_svect: bindings::rocblas_svect,
+        right_svect: bindings::rocblas_svect,
+        m: i32,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        S: *mut Self::RealType,
+        U: *mut Self,
+        ldu: i32,
+        V: *mut Self,
+        ldv: i32,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_dgesvdj(
+            cast_handle(handle),
+            left_svect,
+            right_svect,
+            m,
+            n,
+            A,
+            lda,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            S,
+            U,
+            ldu,
+            V,
+            ldv,
+            info,
+        )
+    }
+
+    unsafe fn gesvdj_batched(
+        handle: RocblasHandle,
+        left_svect: bindings::rocblas_svect,
+        right_svect: bindings::rocblas_svect,
+        m: i32,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        S: *mut Self::RealType,
+        stride_s: i64,
+        U: *mut Self,
+        ldu: i32,
+        stride_u: i64,
+        V: *mut Self,
+        ldv: i32,
+        stride_v: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_dgesvdj_batched(
+            cast_handle(handle),
+            left_svect,
+            right_svect,
+            m,
+            n,
+            A,
+            lda,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            S,
+            stride_s,
+            U,
+            ldu,
+            stride_u,
+            V,
+            ldv,
+            stride_v,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn gesvdj_strided_batched(
+        handle: RocblasHandle,
+        left_svect: bindings::rocblas_svect,
+        right_svect: bindings::rocblas_svect,
+        m: i32,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        S: *mut Self::RealType,
+        stride_s: i64,
+        U: *mut Self,
+        ldu: i32,
+        stride_u: i64,
+        V: *mut Self,
+        ldv: i32,
+        stride_v: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_dgesvdj_strided_batched(
+            cast_handle(handle),
+            left_svect,
+            right_svect,
+            m,
+            n,
+            A,
+            lda,
+            stride_a,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            S,
+            stride_s,
+            U,
+            ldu,
+            stride_u,
+            V,
+            ldv,
+            stride_v,
+            info,
+            batch_count,
+        )
+    }
+}
+
+// ============================================================================
+// Trait implementations for Complex32
+// ============================================================================
+
+impl GesvdType for Complex32 {
+    type RealType = f32;
+
+    unsafe fn gesvd(
+        handle: RocblasHandle,
+        left_svect: bindings::rocblas_svect,
+        right_svect: bindings::rocblas_svect,
+        m: i32,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        S: *mut Self::RealType,
+        U: *mut Self,
+        ldu: i32,
+        V: *mut Self,
+        ldv: i32,
+        E: *mut Self::RealType,
+        fast_alg: bindings::rocblas_workmode,
+        info: *mut i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cgesvd(
+            cast_handle(handle),
+            left_svect,
+            right_svect,
+            m,
+            n,
+            A,
+            lda,
+            S,
+            U,
+            ldu,
+            V,
+            ldv,
+            E,
+            fast_alg,
+            info,
+        )
+    }
+}
+
+impl GesvdjType for Complex32 {
     type RealType = f32;
 
-    unsafe fn gesvd(
+    unsafe fn gesvdj(
         handle: RocblasHandle,
         left_svect: bindings::rocblas_svect,
         right_svect: bindings::rocblas_svect,
@@ -67,16 +558,18 @@ impl GesvdType for f32 {
         n: i32,
         A: *mut Self,
         lda: i32,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
         S: *mut Self::RealType,
         U: *mut Self,
         ldu: i32,
         V: *mut Self,
         ldv: i32,
-        E: *mut Self::RealType,
-        fast_alg: bindings::rocblas_workmode,
         info: *mut i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_sgesvd(
+        bindings::rocsolver_cgesvdj(
             cast_handle(handle),
             left_svect,
             right_svect,
@@ -84,26 +577,68 @@ impl GesvdType for f32 {
             n,
             A,
             lda,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
             S,
             U,
             ldu,
             V,
             ldv,
-            E,
-            fast_alg,
             info,
         )
     }
-}
-
-// ============================================================================
-// Trait implementations for f64
-// ============================================================================
 
-impl GesvdType for f64 {
-    type RealType = f64;
+    unsafe fn gesvdj_batched(
+        handle: RocblasHandle,
+        left_svect: bindings::rocblas_svect,
+        right_svect: bindings::rocblas_svect,
+        m: i32,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        S: *mut Self::RealType,
+        stride_s: i64,
+        U: *mut Self,
+        ldu: i32,
+        stride_u: i64,
+        V: *mut Self,
+        ldv: i32,
+        stride_v: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_cgesvdj_batched(
+            cast_handle(handle),
+            left_svect,
+            right_svect,
+            m,
+            n,
+            A,
+            lda,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            S,
+            stride_s,
+            U,
+            ldu,
+            stride_u,
+            V,
+            ldv,
+            stride_v,
+            info,
+            batch_count,
+        )
+    }
 
-    unsafe fn gesvd(
+    unsafe fn gesvdj_strided_batched(
         handle: RocblasHandle,
         left_svect: bindings::rocblas_svect,
         right_svect: bindings::rocblas_svect,
@@ -111,16 +646,23 @@ impl GesvdType for f64 {
         n: i32,
         A: *mut Self,
         lda: i32,
+        stride_a: i64,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
         S: *mut Self::RealType,
+        stride_s: i64,
         U: *mut Self,
         ldu: i32,
+        stride_u: i64,
         V: *mut Self,
         ldv: i32,
-        E: *mut Self::RealType,
-        fast_alg: bindings::rocblas_workmode,
+        stride_v: i64,
         info: *mut i32,
+        batch_count: i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_dgesvd(
+        bindings::rocsolver_cgesvdj_strided_batched(
             cast_handle(handle),
             left_svect,
             right_svect,
@@ -128,24 +670,31 @@ impl GesvdType for f64 {
             n,
             A,
             lda,
+            stride_a,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
             S,
+            stride_s,
             U,
             ldu,
+            stride_u,
             V,
             ldv,
-            E,
-            fast_alg,
+            stride_v,
             info,
+            batch_count,
         )
     }
 }
 
 // ============================================================================
-// Trait implementations for Complex32
+// Trait implementations for Complex64
 // ============================================================================
 
-impl GesvdType for Complex32 {
-    type RealType = f32;
+impl GesvdType for Complex64 {
+    type RealType = f64;
 
     unsafe fn gesvd(
         handle: RocblasHandle,
@@ -164,7 +713,7 @@ impl GesvdType for Complex32 {
         fast_alg: bindings::rocblas_workmode,
         info: *mut i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_cgesvd(
+        bindings::rocsolver_zgesvd(
             cast_handle(handle),
             left_svect,
             right_svect,
@@ -184,14 +733,10 @@ impl GesvdType for Complex32 {
     }
 }
 
-// ============================================================================
-// Trait implementations for Complex64
-// ============================================================================
-
-impl GesvdType for Complex64 {
+impl GesvdjType for Complex64 {
     type RealType = f64;
 
-    unsafe fn gesvd(
+    unsafe fn gesvdj(
         handle: RocblasHandle,
         left_svect: bindings::rocblas_svect,
         right_svect: bindings::rocblas_svect,
@@ -199,16 +744,18 @@ impl GesvdType for Complex64 {
         n: i32,
         A: *mut Self,
         lda: i32,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
         S: *mut Self::RealType,
         U: *mut Self,
         ldu: i32,
         V: *mut Self,
         ldv: i32,
-        E: *mut Self::RealType,
-        fast_alg: bindings::rocblas_workmode,
         info: *mut i32,
     ) -> RocblasStatus {
-        bindings::rocsolver_zgesvd(
+        bindings::rocsolver_zgesvdj(
             cast_handle(handle),
             left_svect,
             right_svect,
@@ -216,16 +763,116 @@ impl GesvdType for Complex64 {
             n,
             A,
             lda,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
             S,
             U,
             ldu,
             V,
             ldv,
-            E,
-            fast_alg,
             info,
         )
     }
+
+    unsafe fn gesvdj_batched(
+        handle: RocblasHandle,
+        left_svect: bindings::rocblas_svect,
+        right_svect: bindings::rocblas_svect,
+        m: i32,
+        n: i32,
+        A: *const *mut Self,
+        lda: i32,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        S: *mut Self::RealType,
+        stride_s: i64,
+        U: *mut Self,
+        ldu: i32,
+        stride_u: i64,
+        V: *mut Self,
+        ldv: i32,
+        stride_v: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zgesvdj_batched(
+            cast_handle(handle),
+            left_svect,
+            right_svect,
+            m,
+            n,
+            A,
+            lda,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            S,
+            stride_s,
+            U,
+            ldu,
+            stride_u,
+            V,
+            ldv,
+            stride_v,
+            info,
+            batch_count,
+        )
+    }
+
+    unsafe fn gesvdj_strided_batched(
+        handle: RocblasHandle,
+        left_svect: bindings::rocblas_svect,
+        right_svect: bindings::rocblas_svect,
+        m: i32,
+        n: i32,
+        A: *mut Self,
+        lda: i32,
+        stride_a: i64,
+        abstol: Self::RealType,
+        residual: *mut Self::RealType,
+        max_sweeps: i32,
+        n_sweeps: *mut i32,
+        S: *mut Self::RealType,
+        stride_s: i64,
+        U: *mut Self,
+        ldu: i32,
+        stride_u: i64,
+        V: *mut Self,
+        ldv: i32,
+        stride_v: i64,
+        info: *mut i32,
+        batch_count: i32,
+    ) -> RocblasStatus {
+        bindings::rocsolver_zgesvdj_strided_batched(
+            cast_handle(handle),
+            left_svect,
+            right_svect,
+            m,
+            n,
+            A,
+            lda,
+            stride_a,
+            abstol,
+            residual,
+            max_sweeps,
+            n_sweeps,
+            S,
+            stride_s,
+            U,
+            ldu,
+            stride_u,
+            V,
+            ldv,
+            stride_v,
+            info,
+            batch_count,
+        )
+    }
 }
 
 // ============================================================================
@@ -310,3 +957,176 @@ pub fn gesvd<T: GesvdType>(
     };
     Error::from_status(status)
 }
+
+/// Computes the Singular Value Decomposition (SVD) of a general m-by-n matrix A
+/// using the one-sided Jacobi method.
+///
+/// Computes the same factorization as [`gesvd`], usually much faster for
+/// small matrices and batches of them. Only `Svect::All`, `Svect::Singular`
+/// and `Svect::None` are supported.
+///
+/// # Arguments
+/// * `handle` - rocBLAS handle
+/// * `left_svect` - Specifies how to compute U
+/// * `right_svect` - Specifies how to compute V
+/// * `m` - Number of rows of A
+/// * `n` - Number of columns of A
+/// * `A` - Device pointer to m-by-n matrix (destroyed on output)
+/// * `lda` - Leading dimension of A (>= max(1,m))
+/// * `params` - Tolerance and maximum number of sweeps
+/// * `residual` - Device pointer to the Frobenius norm of the final off-diagonal elements
+/// * `n_sweeps` - Device pointer to the number of sweeps performed
+/// * `S` - Device pointer to min(m,n) singular values in decreasing order
+/// * `U` - Device pointer to m-by-m (or m-by-min(m,n)) matrix U
+/// * `ldu` - Leading dimension of U
+/// * `V` - Device pointer to n-by-n (or min(m,n)-by-n) matrix V
+/// * `ldv` - Leading dimension of V
+/// * `info` - Device pointer to convergence info (0 = success, 1 = did not converge)
+#[inline]
+pub fn gesvdj<T: GesvdjType>(
+    handle: &Handle,
+    left_svect: Svect,
+    right_svect: Svect,
+    m: i32,
+    n: i32,
+    A: *mut T,
+    lda: i32,
+    params: JacobiParams<T::RealType>,
+    residual: *mut T::RealType,
+    n_sweeps: *mut i32,
+    S: *mut T::RealType,
+    U: *mut T,
+    ldu: i32,
+    V: *mut T,
+    ldv: i32,
+    info: *mut i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::gesvdj(
+            handle.as_raw(),
+            left_svect.into(),
+            right_svect.into(),
+            m,
+            n,
+            A,
+            lda,
+            params.abstol,
+            residual,
+            params.max_sweeps,
+            n_sweeps,
+            S,
+            U,
+            ldu,
+            V,
+            ldv,
+            info,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Batched version of gesvdj.
+#[inline]
+pub fn gesvdj_batched<T: GesvdjType>(
+    handle: &Handle,
+    left_svect: Svect,
+    right_svect: Svect,
+    m: i32,
+    n: i32,
+    A: *const *mut T,
+    lda: i32,
+    params: JacobiParams<T::RealType>,
+    residual: *mut T::RealType,
+    n_sweeps: *mut i32,
+    S: *mut T::RealType,
+    stride_s: i64,
+    U: *mut T,
+    ldu: i32,
+    stride_u: i64,
+    V: *mut T,
+    ldv: i32,
+    stride_v: i64,
+    info: *mut i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::gesvdj_batched(
+            handle.as_raw(),
+            left_svect.into(),
+            right_svect.into(),
+            m,
+            n,
+            A,
+            lda,
+            params.abstol,
+            residual,
+            params.max_sweeps,
+            n_sweeps,
+            S,
+            stride_s,
+            U,
+            ldu,
+            stride_u,
+            V,
+            ldv,
+            stride_v,
+            info,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
+
+/// Strided batched version of gesvdj.
+#[inline]
+pub fn gesvdj_strided_batched<T: GesvdjType>(
+    handle: &Handle,
+    left_svect: Svect,
+    right_svect: Svect,
+    m: i32,
+    n: i32,
+    A: *mut T,
+    lda: i32,
+    stride_a: i64,
+    params: JacobiParams<T::RealType>,
+    residual: *mut T::RealType,
+    n_sweeps: *mut i32,
+    S: *mut T::RealType,
+    stride_s: i64,
+    U: *mut T,
+    ldu: i32,
+    stride_u: i64,
+    V: *mut T,
+    ldv: i32,
+    stride_v: i64,
+    info: *mut i32,
+    batch_count: i32,
+) -> Result<()> {
+    let status = unsafe {
+        T::gesvdj_strided_batched(
+            handle.as_raw(),
+            left_svect.into(),
+            right_svect.into(),
+            m,
+            n,
+            A,
+            lda,
+            stride_a,
+            params.abstol,
+            residual,
+            params.max_sweeps,
+            n_sweeps,
+            S,
+            stride_s,
+            U,
+            ldu,
+            stride_u,
+            V,
+            ldv,
+            stride_v,
+            info,
+            batch_count,
+        )
+    };
+    Error::from_status(status)
+}
@@ -0,0 +1,332 @@
+// src/rocsolver/linalg.rs
+//! High-level linear algebra on [`ROCArray`]s.
+//!
+//! The routines here take row-major [`ROCArray`]s and take care of everything
+//! the LAPACK-style wrappers leave to the caller: converting to and from
+//! rocSOLVER's column-major layout, allocating Householder scalars,
+//! workspaces and info values, and checking the results. They run on the
+//! calling thread's pooled handle (see [`with_handle`]) and wait for the
+//! result.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use rocm_rs::rocarray::ROCArray;
+//! use rocm_rs::rocsolver::linalg;
+//!
+//! let a = ROCArray::from_vec(vec![1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+//! let a = a.reshaped(vec![3, 2]).unwrap().to_array().unwrap();
+//!
+//! let (q, r) = linalg::qr(&a).unwrap();
+//!
+//! let b = ROCArray::from_vec(vec![1.0f64, 2.0, 2.0]).unwrap();
+//! let x = linalg::lstsq(&a, &b).unwrap();
+//! ```
+
+use crate::error::{Result, custom_error, invalid_argument};
+use crate::hip::DeviceMemory;
+use crate::rocarray::kernels::NumericOps;
+use crate::rocarray::{PadMode, ROCArray};
+use crate::rocblas::safe::MatrixDims;
+use crate::rocblas::types::Operation;
+use crate::rocblas::{BlasMatrix, Handle, with_handle};
+use crate::rocsolver::error::read_info;
+use crate::rocsolver::lapack::decompositions::{GeqrfType, geqrf};
+use crate::rocsolver::lapack::orthogonal::{orgqr, ungqr};
+use crate::rocsolver::lapack::solvers::{GelsType, gels};
+use crate::rocsolver::lapack::svd::{GesvdType, gesvd};
+use crate::rocsolver::types::{Svect, Workmode};
+
+/// Element types supported by the [`linalg`](self) routines.
+///
+/// Maps each [`ROCArray`] element type to the rocSOLVER type with the same
+/// layout, and picks between the real and complex variants of routines such
+/// as orgqr/ungqr.
+pub trait LinalgType: NumericOps {
+    /// The rocSOLVER element type with the same layout.
+    type Raw: GeqrfType + GelsType + GesvdType<RealType = Self::Real>;
+
+    /// The real type of norms and singular values.
+    type Real: NumericOps;
+
+    /// Generates the Q factor of a QR factorization (orgqr or ungqr).
+    #[doc(hidden)]
+    fn generate_q(
+        handle: &Handle,
+        m: i32,
+        n: i32,
+        k: i32,
+        a: *mut Self::Raw,
+        lda: i32,
+        tau: *mut Self::Raw,
+    ) -> crate::rocsolver::Result<()>;
+}
+
+impl LinalgType for f32 {
+    type Raw = f32;
+    type Real = f32;
+
+    fn generate_q(
+        handle: &Handle,
+        m: i32,
+        n: i32,
+        k: i32,
+        a: *mut f32,
+        lda: i32,
+        tau: *mut f32,
+    ) -> crate::rocsolver::Result<()> {
+        orgqr(handle, m, n, k, a, lda, tau)
+    }
+}
+
+impl LinalgType for f64 {
+    type Raw = f64;
+    type Real = f64;
+
+    fn generate_q(
+        handle: &Handle,
+        m: i32,
+        n: i32,
+        k: i32,
+        a: *mut f64,
+        lda: i32,
+        tau: *mut f64,
+    ) -> crate::rocsolver::Result<()> {
+        orgqr(handle, m, n, k, a, lda, tau)
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl LinalgType for num_complex::Complex32 {
+    type Raw = crate::rocsolver::Complex32;
+    type Real = f32;
+
+    fn generate_q(
+        handle: &Handle,
+        m: i32,
+        n: i32,
+        k: i32,
+        a: *mut Self::Raw,
+        lda: i32,
+        tau: *mut Self::Raw,
+    ) -> crate::rocsolver::Result<()> {
+        ungqr(handle, m, n, k, a, lda, tau)
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl LinalgType for num_complex::Complex64 {
+    type Raw = crate::rocsolver::Complex64;
+    type Real = f64;
+
+    fn generate_q(
+        handle: &Handle,
+        m: i32,
+        n: i32,
+        k: i32,
+        a: *mut Self::Raw,
+        lda: i32,
+        tau: *mut Self::Raw,
+    ) -> crate::rocsolver::Result<()> {
+        ungqr(handle, m, n, k, a, lda, tau)
+    }
+}
+
+/// Computes the reduced QR factorization of an m-by-n matrix.
+///
+/// Returns `(Q, R)` with `A = Q * R`, where Q is m-by-k with orthonormal
+/// columns, R is k-by-n upper triangular and k = min(m, n).
+pub fn qr<T: LinalgType>(a: &ROCArray<T>) -> Result<(ROCArray<T>, ROCArray<T>)> {
+    let matrix = to_column_major(a)?;
+    let dims = matrix.dims();
+    let (m, n, ld) = (dims.rows, dims.cols, dims.ld);
+    let k = m.min(n);
+
+    let data = matrix.into_device_memory();
+    let tau = DeviceMemory::<T>::new(k.max(1) as usize)?;
+
+    with_handle(move |handle| {
+        geqrf(handle, m, n, raw(&data), ld, raw(&tau))?;
+        handle.get_stream()?.synchronize()?;
+
+        // R is the upper triangle of the leading k rows; Q overwrites them
+        let mut factored = DeviceMemory::new(data.count())?;
+        factored.copy_from_device(&data)?;
+        let r = BlasMatrix::from_device_memory(factored, dims)?.into_rocarray()?;
+        let r = r.view().narrow(0, 0, k as usize)?.to_array()?.triu(0)?;
+
+        T::generate_q(handle, m, k, k, raw(&data), ld, raw(&tau))?;
+        handle.get_stream()?.synchronize()?;
+        let q = BlasMatrix::from_device_memory(data, MatrixDims::with_ld(m, k, ld))?;
+
+        Ok((q.into_rocarray()?, r))
+    })
+}
+
+/// The singular value decomposition `A = U * S * Vt` of an m-by-n matrix.
+///
+/// The factors stay on the device in rocSOLVER's column-major layout.
+pub struct Svd<T: LinalgType> {
+    /// The m-by-m orthogonal/unitary matrix of left singular vectors.
+    pub u: BlasMatrix<T>,
+    /// The min(m,n) singular values, in decreasing order.
+    pub s: DeviceMemory<T::Real>,
+    /// The n-by-n orthogonal/unitary matrix of right singular vectors,
+    /// transposed (conjugate transposed for complex types).
+    pub vt: BlasMatrix<T>,
+}
+
+/// Computes the full singular value decomposition of an m-by-n matrix.
+///
+/// Runs gesvd with all singular vectors requested, allocating the
+/// superdiagonal workspace and info value internally. Fails if the
+/// algorithm did not converge.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rocm_rs::rocarray::ROCArray;
+/// use rocm_rs::rocsolver::linalg;
+///
+/// let a = ROCArray::from_vec(vec![3.0f32, 2.0, 2.0, 3.0]).unwrap();
+/// let a = a.reshaped(vec![2, 2]).unwrap().to_array().unwrap();
+///
+/// let svd = linalg::svd(&a).unwrap();
+/// let mut s = vec![0.0f32; 2];
+/// svd.s.copy_to_host(&mut s).unwrap();
+/// ```
+pub fn svd<T: LinalgType>(a: &ROCArray<T>) -> Result<Svd<T>> {
+    let matrix = to_column_major(a)?;
+    let MatrixDims {
+        rows: m,
+        cols: n,
+        ld,
+    } = matrix.dims();
+    let k = m.min(n).max(1) as usize;
+
+    let data = matrix.into_device_memory();
+    let u = BlasMatrix::<T>::new(m, m)?;
+    let vt = BlasMatrix::<T>::new(n, n)?;
+    let s = DeviceMemory::<T::Real>::new(k)?;
+    let e = DeviceMemory::<T::Real>::new(k)?;
+    let info = DeviceMemory::<i32>::new(1)?;
+
+    with_handle(|handle| {
+        gesvd(
+            handle,
+            Svect::All,
+            Svect::All,
+            m,
+            n,
+            raw(&data),
+            ld,
+            s.as_ptr().cast(),
+            raw(u.device_memory()),
+            u.ld(),
+            raw(vt.device_memory()),
+            vt.ld(),
+            e.as_ptr().cast(),
+            Workmode::OutOfPlace,
+            info.as_ptr().cast(),
+        )?;
+
+        let i = read_info(handle, &info)?;
+        if i != 0 {
+            return Err(custom_error(format!(
+                "SVD did not converge: {i} superdiagonals did not reach zero"
+            )));
+        }
+        Ok(())
+    })?;
+
+    Ok(Svd { u, s, vt })
+}
+
+/// Solves the linear least squares problem `min ||A * X - B||` for a
+/// full-rank m-by-n matrix A.
+///
+/// If m < n the system is underdetermined and the minimum norm solution is
+/// returned instead. `b` is either a vector of length m, giving a vector of
+/// length n, or an m-by-nrhs matrix, giving an n-by-nrhs matrix. Neither
+/// input is modified. Fails if A does not have full rank.
+pub fn lstsq<T: LinalgType>(a: &ROCArray<T>, b: &ROCArray<T>) -> Result<ROCArray<T>> {
+    let matrix = to_column_major(a)?;
+    let MatrixDims {
+        rows: m,
+        cols: n,
+        ld,
+    } = matrix.dims();
+
+    let (rhs, vector) = match *b.dims() {
+        [len] => (b.reshaped(vec![len, 1])?.to_array()?, true),
+        [_, _] => (b.clone_array()?, false),
+        _ => return Err(invalid_argument("expected a 1D or 2D right-hand side")),
+    };
+    if rhs.dims()[0] != m as usize {
+        return Err(invalid_argument(format!(
+            "right-hand side has {} rows, expected {m}",
+            rhs.dims()[0]
+        )));
+    }
+
+    // gels overwrites B with the solution, which has n rows, so B needs
+    // room for max(m, n)
+    let ldb = m.max(n);
+    let rhs = rhs.pad(
+        &[(0, (ldb - m) as usize), (0, 0)],
+        PadMode::Constant(T::default()),
+    )?;
+    let rhs = to_column_major(&rhs)?;
+    let nrhs = rhs.cols();
+
+    let data = matrix.into_device_memory();
+    let solution = rhs.into_device_memory();
+    let info = DeviceMemory::<i32>::new(1)?;
+
+    with_handle(|handle| {
+        gels(
+            handle,
+            Operation::None,
+            m,
+            n,
+            nrhs,
+            raw(&data),
+            ld,
+            raw(&solution),
+            ldb,
+            info.as_ptr().cast(),
+        )?;
+
+        let i = read_info(handle, &info)?;
+        if i > 0 {
+            return Err(custom_error(format!(
+                "matrix does not have full rank: diagonal element {i} of the triangular factor is zero"
+            )));
+        }
+        Ok(())
+    })?;
+
+    let x = BlasMatrix::from_device_memory(solution, MatrixDims::with_ld(n, nrhs, ldb))?;
+    let mut x = x.into_rocarray()?;
+    if vector {
+        x.reshape(vec![n as usize])?;
+    }
+    Ok(x)
+}
+
+/// Copies a 2D array into a column-major matrix for rocSOLVER.
+fn to_column_major<T: NumericOps>(a: &ROCArray<T>) -> Result<BlasMatrix<T>> {
+    if a.dims().len() != 2 {
+        return Err(invalid_argument("expected a 2D array"));
+    }
+    if a.dims().iter().any(|&d| d > i32::MAX as usize) {
+        return Err(invalid_argument("matrix too large for rocSOLVER"));
+    }
+
+    BlasMatrix::from_rocarray(a)
+}
+
+/// Device pointer to `memory` as the rocSOLVER element type.
+fn raw<T: LinalgType>(memory: &DeviceMemory<T>) -> *mut T::Raw {
+    memory.as_ptr().cast()
+}
@@ -16,6 +16,7 @@
 //! - [`types`] - Type-safe enums for rocSOLVER parameters
 //! - [`ffi`] - Raw FFI bindings (for advanced use)
 //! - [`lapack`] - LAPACK-style linear algebra operations
+//! - [`linalg`] - High-level operations on [`ROCArray`](crate::rocarray::ROCArray)s
 //!
 //! # Supported Operations
 //!
@@ -24,19 +25,28 @@
 //! - [`getrf`] - LU factorization with partial pivoting
 //! - [`potrf`] - Cholesky factorization for symmetric positive definite matrices
 //! - [`gebrd`] - Bidiagonal reduction
+//! - [`trtri`] - Inverse of a triangular matrix
+//! - [`sytrf`] - Symmetric indefinite (Bunch-Kaufman) factorization
 //!
 //! ## Linear System Solvers ([`lapack::solvers`])
 //! - [`gesv`] - Solve A*X=B using LU factorization
 //! - [`getrs`] - Solve using pre-computed LU factors
 //! - [`posv`] - Solve A*X=B for symmetric positive definite matrices
+//! - [`potrs`] - Solve using pre-computed Cholesky factors
 //! - [`gels`] - Least squares solver
+//! - [`getri`] - Invert using pre-computed LU factors
+//! - [`inverse`] - Invert a matrix in place (getrf + getri)
+//! - [`potri`] - Invert using pre-computed Cholesky factors
 //!
 //! ## Singular Value Decomposition ([`lapack::svd`])
 //! - [`gesvd`] - Compute singular value decomposition
+//! - [`gesvdj`] - Singular value decomposition with the Jacobi method
 //!
 //! ## Eigenvalue Computations ([`lapack::eigenvalue`])
 //! - [`syev`] - Eigenvalues of real symmetric matrices
 //! - [`heev`] - Eigenvalues of complex Hermitian matrices
+//! - [`syevj`] / [`heevj`] - Jacobi eigensolvers, fast for small (batched) matrices
+//! - [`sygvd`] / [`hegvd`] - Generalized eigenproblem `A x = λ B x`
 //!
 //! ## Orthogonal/Unitary Operations ([`lapack::orthogonal`])
 //! - [`orgqr`] / [`ungqr`] - Generate Q matrix from QR factorization
@@ -90,6 +100,7 @@ pub mod bindings;
 pub mod error;
 pub mod ffi;
 pub mod lapack;
+pub mod linalg;
 pub mod types;
 
 // Re-export Handle from rocBLAS for convenience
@@ -101,8 +112,8 @@ pub use error::{Error, Result};
 
 // Re-export type-safe enums
 pub use types::{
-    AlgMode, Complex32, Complex64, Direct, Eform, Eorder, Erange, Esort, Evect, Srange, Storev,
-    Svect, Workmode,
+    AlgMode, Complex32, Complex64, Direct, Eform, Eorder, Erange, Esort, Evect, JacobiParams,
+    Srange, Storev, Svect, Workmode,
 };
 
 // Re-export all LAPACK functions at the module level for convenience
@@ -111,31 +122,40 @@ pub use types::{
 pub use lapack::decompositions::{
     gebrd, gebrd_batched, gebrd_strided_batched, geqrf, geqrf_batched, geqrf_strided_batched,
     getrf, getrf_batched, getrf_npvt, getrf_npvt_batched, getrf_npvt_strided_batched,
-    getrf_strided_batched, potrf, potrf_batched, potrf_strided_batched,
+    getrf_strided_batched, potrf, potrf_batched, potrf_strided_batched, sytrf, sytrf_batched,
+    sytrf_pivots, sytrf_strided_batched, trtri, trtri_batched, trtri_strided_batched,
 };
 
 // Trait re-exports for generic programming
-pub use lapack::decompositions::{GebrdType, GeqrfType, GetrfType, PotrfType};
+pub use lapack::decompositions::{
+    GebrdType, GeqrfType, GetrfType, PivotBlock, PotrfType, SytrfType, TrtriType,
+};
 
 // Solvers
 pub use lapack::solvers::{
-    gels, gels_batched, gels_strided_batched, gesv, gesv_batched, gesv_strided_batched, getrs,
-    getrs_batched, getrs_strided_batched, posv, posv_batched, posv_strided_batched,
+    gels, gels_batched, gels_strided_batched, gesv, gesv_batched, gesv_strided_batched, getri,
+    getri_batched, getri_strided_batched, getrs, getrs_batched, getrs_strided_batched, inverse,
+    posv, posv_batched, posv_strided_batched, potri, potri_batched, potri_strided_batched, potrs,
+    potrs_batched, potrs_strided_batched,
 };
 
-pub use lapack::solvers::{GelsType, GesvType, GetrsType, PosvType};
+pub use lapack::solvers::{
+    GelsType, GesvType, GetriType, GetrsType, PosvType, PotriType, PotrsType,
+};
 
-// SVD (batched variants not yet implemented due to complex stride requirements)
-pub use lapack::svd::gesvd;
+// SVD (gesvd batched variants not yet implemented due to complex stride requirements)
+pub use lapack::svd::{gesvd, gesvdj, gesvdj_batched, gesvdj_strided_batched};
 
-pub use lapack::svd::GesvdType;
+pub use lapack::svd::{GesvdType, GesvdjType};
 
 // Eigenvalue
 pub use lapack::eigenvalue::{
-    heev, heev_batched, heev_strided_batched, syev, syev_batched, syev_strided_batched,
+    heev, heev_batched, heev_strided_batched, heevj, heevj_batched, heevj_strided_batched, hegvd,
+    hegvd_batched, hegvd_strided_batched, syev, syev_batched, syev_strided_batched, syevj,
+    syevj_batched, syevj_strided_batched, sygvd, sygvd_batched, sygvd_strided_batched,
 };
 
-pub use lapack::eigenvalue::{HeevType, SyevType};
+pub use lapack::eigenvalue::{HeevType, HeevjType, HegvdType, SyevType, SyevjType, SygvdType};
 
 // Orthogonal/Unitary (no batched variants available in rocSOLVER)
 pub use lapack::orthogonal::{orgqr, ormqr, ungqr, unmqr};
@@ -281,6 +281,34 @@ impl From<ffi::rocblas_esort> for Esort {
     }
 }
 
+/// Convergence settings for the Jacobi solvers (syevj, heevj, gesvdj).
+///
+/// `T` is the real type of the matrix elements (`f32` for `f32` and
+/// [`Complex32`] matrices, `f64` otherwise).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JacobiParams<T> {
+    /// Absolute tolerance on the off-diagonal elements. Values <= 0 select a
+    /// default based on machine precision.
+    pub abstol: T,
+    /// Maximum number of sweeps.
+    pub max_sweeps: i32,
+}
+
+impl<T> JacobiParams<T> {
+    /// Create settings with the given tolerance and sweep limit.
+    #[inline]
+    pub fn new(abstol: T, max_sweeps: i32) -> Self {
+        Self { abstol, max_sweeps }
+    }
+}
+
+impl<T: Default> Default for JacobiParams<T> {
+    /// The machine-precision tolerance and at most 100 sweeps.
+    fn default() -> Self {
+        Self::new(T::default(), 100)
+    }
+}
+
 /// Specifies the algorithm mode for rocSOLVER functions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AlgMode {
@@ -0,0 +1,581 @@
+// src/session.rs - End-to-end inference for small models
+//
+// An InferenceSession owns a model's weights, its activation buffers and
+// the streams it runs on. Each run uploads a batch on one stream, executes
+// preprocessing, the layers and postprocessing on another (replayed from a
+// captured graph), and downloads the result on a third.
+
+use crate::error::{Result, custom_error};
+use crate::hip::kernel::AsKernelArg;
+use crate::hip::{
+    CaptureMode, DeviceMemory, Dim3, Event, Graph, GraphExec, Stream, calculate_grid_1d,
+};
+use crate::kernel_args;
+use crate::nn::get_kernel_function;
+use crate::rocblas::{self, types::Operation};
+use std::fs;
+use std::path::Path;
+
+const BLOCK_SIZE: u32 = 256;
+
+/// Activation applied after a layer
+///
+/// The discriminants are passed to the kernel and must match the
+/// `ACTIVATION_*` defines in nn/kernels.hip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Activation {
+    #[default]
+    None = 0,
+    Relu = 1,
+    Sigmoid = 2,
+    Tanh = 3,
+}
+
+/// Resources available to a layer while it enqueues its work
+pub struct LayerContext<'a> {
+    /// Stream all work must be queued on
+    pub stream: &'a Stream,
+    /// rocBLAS handle bound to `stream`
+    pub blas: &'a rocblas::Handle,
+    /// Number of rows in the batch
+    pub batch: usize,
+}
+
+/// A stage of a model operating on row-major `batch x features` f32 data
+///
+/// Implement this to run custom kernels, MIOpen convolutions or other
+/// library calls inside a session. `forward` must only queue work on
+/// `ctx.stream` and must not synchronize or allocate, since it is recorded
+/// into a graph and replayed.
+pub trait Layer {
+    /// Number of input features per row
+    fn input_features(&self) -> usize;
+
+    /// Number of output features per row
+    fn output_features(&self) -> usize;
+
+    /// Queue the layer's work, reading `input` and writing `output`
+    fn forward(
+        &self,
+        ctx: &LayerContext<'_>,
+        input: &DeviceMemory<f32>,
+        output: &mut DeviceMemory<f32>,
+    ) -> Result<()>;
+}
+
+/// A fully connected layer computed with rocBLAS
+pub struct Dense {
+    in_features: usize,
+    out_features: usize,
+    activation: Activation,
+    // Row-major out_features x in_features
+    weights: DeviceMemory<f32>,
+    // Empty if the layer has no bias
+    bias: DeviceMemory<f32>,
+}
+
+impl Dense {
+    /// Create a layer from row-major `out_features x in_features` weights
+    pub fn new(
+        weights: &[f32],
+        bias: Option<&[f32]>,
+        in_features: usize,
+        out_features: usize,
+        activation: Activation,
+    ) -> Result<Self> {
+        if weights.len() != in_features * out_features {
+            return Err(custom_error(format!(
+                "Expected {}x{} weights, got {} values",
+                out_features,
+                in_features,
+                weights.len()
+            )));
+        }
+        if let Some(bias) = bias
+            && bias.len() != out_features
+        {
+            return Err(custom_error(format!(
+                "Expected {} bias values, got {}",
+                out_features,
+                bias.len()
+            )));
+        }
+
+        let mut device_weights = DeviceMemory::new(weights.len())?;
+        device_weights.copy_from_host(weights)?;
+        let bias = match bias {
+            Some(bias) => {
+                let mut device_bias = DeviceMemory::new(bias.len())?;
+                device_bias.copy_from_host(bias)?;
+                device_bias
+            }
+            None => DeviceMemory::new(0)?,
+        };
+
+        Ok(Self {
+            in_features,
+            out_features,
+            activation,
+            weights: device_weights,
+            bias,
+        })
+    }
+
+    /// Load a layer from a file of little-endian f32 values
+    ///
+    /// The file holds the row-major weights followed by `out_features`
+    /// bias values.
+    pub fn from_file<P: AsRef<Path>>(
+        path: P,
+        in_features: usize,
+        out_features: usize,
+        activation: Activation,
+    ) -> Result<Self> {
+        let values = read_f32_file(path.as_ref())?;
+        let weight_count = in_features * out_features;
+        if values.len() != weight_count + out_features {
+            return Err(custom_error(format!(
+                "{} holds {} values, expected {} weights and {} biases",
+                path.as_ref().display(),
+                values.len(),
+                weight_count,
+                out_features
+            )));
+        }
+
+        let (weights, bias) = values.split_at(weight_count);
+        Self::new(weights, Some(bias), in_features, out_features, activation)
+    }
+}
+
+impl Layer for Dense {
+    fn input_features(&self) -> usize {
+        self.in_features
+    }
+
+    fn output_features(&self) -> usize {
+        self.out_features
+    }
+
+    fn forward(
+        &self,
+        ctx: &LayerContext<'_>,
+        input: &DeviceMemory<f32>,
+        output: &mut DeviceMemory<f32>,
+    ) -> Result<()> {
+        // In column-major terms output^T = W * input^T, with the row-major
+        // weights read as their transpose
+        unsafe {
+            rocblas::gemm(
+                ctx.blas,
+                Operation::Transpose,
+                Operation::None,
+                self.out_features as i32,
+                ctx.batch as i32,
+                self.in_features as i32,
+                &1.0f32,
+                self.weights.as_ptr() as *const f32,
+                self.in_features as i32,
+                input.as_ptr() as *const f32,
+                self.in_features as i32,
+                &0.0f32,
+                output.as_ptr() as *mut f32,
+                self.out_features as i32,
+            )?;
+        }
+
+        if self.bias.count() == 0 && self.activation == Activation::None {
+            return Ok(());
+        }
+
+        let n = (ctx.batch * self.out_features) as u32;
+        let features = self.out_features as u32;
+        let activation = self.activation as u32;
+        get_kernel_function("session_bias_activation")?.launch(
+            calculate_grid_1d(n, BLOCK_SIZE),
+            Dim3::new_1d(BLOCK_SIZE),
+            0,
+            Some(ctx.stream),
+            kernel_args!(output, self.bias, features, n, activation),
+        )?;
+        Ok(())
+    }
+}
+
+/// Preparation of raw input on the device
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Preprocess {
+    /// Use the input as is
+    #[default]
+    None,
+    /// Subtract a per-feature mean and divide by a per-feature deviation
+    Normalize { mean: Vec<f32>, std: Vec<f32> },
+}
+
+/// Transformation of the last layer's output on the device
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Postprocess {
+    /// Return the raw output
+    #[default]
+    None,
+    /// Turn each row into probabilities
+    Softmax,
+}
+
+/// Builder for an [`InferenceSession`]
+pub struct SessionBuilder {
+    batch_size: usize,
+    layers: Vec<Box<dyn Layer>>,
+    preprocess: Preprocess,
+    postprocess: Postprocess,
+    use_graph: bool,
+}
+
+impl SessionBuilder {
+    /// Start a session that processes `batch_size` rows per run
+    pub fn new(batch_size: usize) -> Self {
+        Self {
+            batch_size,
+            layers: Vec::new(),
+            preprocess: Preprocess::None,
+            postprocess: Postprocess::None,
+            use_graph: true,
+        }
+    }
+
+    /// Append a layer
+    pub fn layer(mut self, layer: impl Layer + 'static) -> Self {
+        self.layers.push(Box::new(layer));
+        self
+    }
+
+    /// Set the preprocessing applied to uploaded input
+    pub fn preprocess(mut self, preprocess: Preprocess) -> Self {
+        self.preprocess = preprocess;
+        self
+    }
+
+    /// Set the postprocessing applied before download
+    pub fn postprocess(mut self, postprocess: Postprocess) -> Self {
+        self.postprocess = postprocess;
+        self
+    }
+
+    /// Whether to replay the compute stage from a captured graph (the
+    /// default) instead of enqueuing it on every run
+    pub fn use_graph(mut self, use_graph: bool) -> Self {
+        self.use_graph = use_graph;
+        self
+    }
+
+    /// Allocate buffers, warm up the kernels and capture the compute graph
+    pub fn build(self) -> Result<InferenceSession> {
+        if self.batch_size == 0 {
+            return Err(custom_error("Batch size must be non-zero".to_string()));
+        }
+        let Some(first) = self.layers.first() else {
+            return Err(custom_error(
+                "A session needs at least one layer".to_string(),
+            ));
+        };
+
+        for pair in self.layers.windows(2) {
+            if pair[0].output_features() != pair[1].input_features() {
+                return Err(custom_error(format!(
+                    "Layer producing {} features feeds a layer expecting {}",
+                    pair[0].output_features(),
+                    pair[1].input_features()
+                )));
+            }
+        }
+
+        let input_features = first.input_features();
+        let preprocess = match self.preprocess {
+            Preprocess::None => None,
+            Preprocess::Normalize { mean, std } => {
+                if mean.len() != input_features || std.len() != input_features {
+                    return Err(custom_error(format!(
+                        "Normalization needs {} means and deviations, got {} and {}",
+                        input_features,
+                        mean.len(),
+                        std.len()
+                    )));
+                }
+                let inv_std: Vec<f32> = std.iter().map(|s| 1.0 / s).collect();
+                let mut device_mean = DeviceMemory::new(mean.len())?;
+                device_mean.copy_from_host(&mean)?;
+                let mut device_inv_std = DeviceMemory::new(inv_std.len())?;
+                device_inv_std.copy_from_host(&inv_std)?;
+                Some((device_mean, device_inv_std))
+            }
+        };
+
+        let mut activations = vec![DeviceMemory::new(self.batch_size * input_features)?];
+        for layer in &self.layers {
+            activations.push(DeviceMemory::new(
+                self.batch_size * layer.output_features(),
+            )?);
+        }
+
+        let compute_stream = Stream::with_name("session-compute")?;
+        let blas = rocblas::Handle::new()?;
+        blas.set_stream(&compute_stream)?;
+
+        let mut session = InferenceSession {
+            pipeline: Pipeline {
+                batch_size: self.batch_size,
+                layers: self.layers,
+                preprocess,
+                postprocess: self.postprocess,
+                activations,
+            },
+            upload_stream: Stream::with_name("session-upload")?,
+            download_stream: Stream::with_name("session-download")?,
+            compute_stream,
+            uploaded: Event::new()?,
+            computed: Event::new()?,
+            blas,
+            graph: None,
+        };
+
+        // Load kernels and let rocBLAS set up its workspace outside of the
+        // capture, where neither is allowed
+        session
+            .pipeline
+            .enqueue(&session.compute_stream, &session.blas)?;
+        session.compute_stream.synchronize()?;
+
+        if self.use_graph {
+            let graph = Graph::capture(
+                &session.compute_stream,
+                CaptureMode::ThreadLocal,
+                |stream| session.pipeline.enqueue(stream, &session.blas),
+            )?;
+            session.graph = Some(graph.instantiate()?);
+        }
+
+        Ok(session)
+    }
+}
+
+/// The device-side stages of a session and the buffers between them
+struct Pipeline {
+    batch_size: usize,
+    layers: Vec<Box<dyn Layer>>,
+    preprocess: Option<(DeviceMemory<f32>, DeviceMemory<f32>)>,
+    postprocess: Postprocess,
+    // Input followed by each layer's output
+    activations: Vec<DeviceMemory<f32>>,
+}
+
+impl Pipeline {
+    fn input_features(&self) -> usize {
+        self.layers[0].input_features()
+    }
+
+    fn output_features(&self) -> usize {
+        self.layers.last().unwrap().output_features()
+    }
+
+    /// Queue preprocessing, the layers and postprocessing on `stream`
+    fn enqueue(&mut self, stream: &Stream, blas: &rocblas::Handle) -> Result<()> {
+        if let Some((mean, inv_std)) = &self.preprocess {
+            let input = &self.activations[0];
+            let n = input.count() as u32;
+            let features = self.input_features() as u32;
+            get_kernel_function("session_normalize")?.launch(
+                calculate_grid_1d(n, BLOCK_SIZE),
+                Dim3::new_1d(BLOCK_SIZE),
+                0,
+                Some(stream),
+                kernel_args!(input, mean, inv_std, features, n),
+            )?;
+        }
+
+        let ctx = LayerContext {
+            stream,
+            blas,
+            batch: self.batch_size,
+        };
+        for (i, layer) in self.layers.iter().enumerate() {
+            let (inputs, outputs) = self.activations.split_at_mut(i + 1);
+            layer.forward(&ctx, &inputs[i], &mut outputs[0])?;
+        }
+
+        if self.postprocess == Postprocess::Softmax {
+            let rows = self.batch_size as u32;
+            let cols = self.output_features() as u32;
+            let output = self.activations.last().unwrap();
+            get_kernel_function("session_softmax_rows")?.launch(
+                calculate_grid_1d(rows, BLOCK_SIZE),
+                Dim3::new_1d(BLOCK_SIZE),
+                0,
+                Some(stream),
+                kernel_args!(output, rows, cols),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A small model deployed on the GPU, ready to run batches
+///
+/// ```ignore
+/// let mut session = SessionBuilder::new(32)
+///     .preprocess(Preprocess::Normalize { mean, std })
+///     .layer(Dense::from_file("fc1.bin", 784, 128, Activation::Relu)?)
+///     .layer(Dense::from_file("fc2.bin", 128, 10, Activation::None)?)
+///     .postprocess(Postprocess::Softmax)
+///     .build()?;
+/// let probabilities = session.run(&batch)?;
+/// ```
+pub struct InferenceSession {
+    pipeline: Pipeline,
+    upload_stream: Stream,
+    compute_stream: Stream,
+    download_stream: Stream,
+    uploaded: Event,
+    computed: Event,
+    blas: rocblas::Handle,
+    graph: Option<GraphExec>,
+}
+
+impl InferenceSession {
+    /// Run one batch of `batch_size x input_features` row-major values
+    ///
+    /// Returns `batch_size x output_features` values.
+    pub fn run(&mut self, input: &[f32]) -> Result<Vec<f32>> {
+        let input_buffer = &self.pipeline.activations[0];
+        if input.len() != input_buffer.count() {
+            return Err(custom_error(format!(
+                "Expected {} input values ({} rows of {}), got {}",
+                input_buffer.count(),
+                self.batch_size(),
+                self.input_features(),
+                input.len()
+            )));
+        }
+
+        input_buffer.copy_from_host_async(input, &self.upload_stream)?;
+        self.uploaded.record(&self.upload_stream)?;
+
+        self.compute_stream.wait_event(&self.uploaded, 0)?;
+        match &self.graph {
+            Some(graph) => graph.launch(&self.compute_stream)?,
+            None => self.pipeline.enqueue(&self.compute_stream, &self.blas)?,
+        }
+        self.computed.record(&self.compute_stream)?;
+
+        self.download_stream.wait_event(&self.computed, 0)?;
+        let output = self.pipeline.activations.last().unwrap();
+        let pending =
+            output.copy_to_host_async(vec![0.0; output.count()], &self.download_stream)?;
+        let output = self.download_stream.synchronize_memory(pending)?;
+
+        // The upload finished before the download could start, so this
+        // doesn't wait; it releases the copy of `input` the stream holds
+        self.upload_stream.synchronize()?;
+        Ok(output)
+    }
+
+    /// Get the number of rows per batch
+    pub fn batch_size(&self) -> usize {
+        self.pipeline.batch_size
+    }
+
+    /// Get the number of input features per row
+    pub fn input_features(&self) -> usize {
+        self.pipeline.input_features()
+    }
+
+    /// Get the number of output features per row
+    pub fn output_features(&self) -> usize {
+        self.pipeline.output_features()
+    }
+
+    /// Whether the compute stage is replayed from a captured graph
+    pub fn uses_graph(&self) -> bool {
+        self.graph.is_some()
+    }
+}
+
+fn read_f32_file(path: &Path) -> Result<Vec<f32>> {
+    let bytes = fs::read(path)?;
+    if !bytes.len().is_multiple_of(4) {
+        return Err(custom_error(format!(
+            "{} is not a whole number of f32 values",
+            path.display()
+        )));
+    }
+
+    Ok(bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reference(input: &[f32], weights: &[f32], bias: &[f32], out: usize) -> Vec<f32> {
+        let inp = weights.len() / out;
+        input
+            .chunks(inp)
+            .flat_map(|row| {
+                (0..out).map(move |o| {
+                    let dot: f32 = (0..inp).map(|i| row[i] * weights[o * inp + i]).sum();
+                    (dot + bias[o]).max(0.0)
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_dense_relu_session() {
+        let weights: Vec<f32> = (0..12).map(|i| (i as f32 - 6.0) * 0.25).collect();
+        let bias = [0.5f32, -0.5, 1.0];
+        let input: Vec<f32> = (0..8).map(|i| i as f32 * 0.5).collect();
+
+        for use_graph in [false, true] {
+            let mut session = SessionBuilder::new(2)
+                .layer(Dense::new(&weights, Some(&bias), 4, 3, Activation::Relu).unwrap())
+                .use_graph(use_graph)
+                .build()
+                .unwrap();
+
+            let output = session.run(&input).unwrap();
+            let expected = reference(&input, &weights, &bias, 3);
+            for (a, b) in output.iter().zip(&expected) {
+                assert!((a - b).abs() < 1e-4);
+            }
+            // Runs are repeatable
+            assert_eq!(session.run(&input).unwrap(), output);
+        }
+    }
+
+    #[test]
+    fn test_softmax_rows_sum_to_one() {
+        let weights: Vec<f32> = (0..6).map(|i| i as f32 * 0.1).collect();
+        let mut session = SessionBuilder::new(3)
+            .layer(Dense::new(&weights, None, 2, 3, Activation::None).unwrap())
+            .postprocess(Postprocess::Softmax)
+            .build()
+            .unwrap();
+
+        let output = session.run(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        for row in output.chunks(3) {
+            assert!((row.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_mismatched_layers_rejected() {
+        let result = SessionBuilder::new(1)
+            .layer(Dense::new(&[0.0; 6], None, 2, 3, Activation::None).unwrap())
+            .layer(Dense::new(&[0.0; 8], None, 4, 2, Activation::None).unwrap())
+            .build();
+        assert!(result.is_err());
+    }
+}